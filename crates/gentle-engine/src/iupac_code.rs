@@ -147,6 +147,17 @@ impl IupacCode {
             _ => b' ',
         }
     }
+
+    /// Code of the complementary bases, e.g. `R` <-> `Y`; `N` stays `N`.
+    pub fn complement(&self) -> Self {
+        let swap = |from: u8, to: u8| if self.0 & from != 0 { to } else { 0 };
+        Self(
+            swap(DNA_BITMASK_A, DNA_BITMASK_T)
+                | swap(DNA_BITMASK_C, DNA_BITMASK_G)
+                | swap(DNA_BITMASK_G, DNA_BITMASK_C)
+                | swap(DNA_BITMASK_T, DNA_BITMASK_A),
+        )
+    }
 }
 
 #[cfg(test)]
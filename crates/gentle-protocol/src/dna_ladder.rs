@@ -109,6 +109,26 @@ impl Ladder {
         }
     }
 
+    /// Build one ladder from explicit `(length, relative_strength)` bands.
+    pub fn from_bands(
+        name: &str,
+        loading_hint: Option<f64>,
+        bands: impl IntoIterator<Item = (f64, Option<f64>)>,
+    ) -> Self {
+        Self {
+            name: name.to_owned(),
+            loading_hint,
+            bands: bands
+                .into_iter()
+                .filter(|(length, _)| length.is_finite() && *length > 0.0)
+                .map(|(length, relative_strength)| LadderBand {
+                    length,
+                    relative_strength,
+                })
+                .collect(),
+        }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -171,6 +191,11 @@ impl LadderCatalog {
         self.ladders.get(name)
     }
 
+    /// Add or replace one ladder, keyed by its name.
+    pub fn insert(&mut self, ladder: Ladder) {
+        self.ladders.insert(ladder.name().to_string(), ladder);
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Ladder> {
         self.ladders.values()
     }
@@ -590,10 +590,12 @@ impl ProtocolCartoonKind {
 /// Strand-contextual anchor extension side.
 ///
 /// Interpretation is biological 5'/3' relative to anchor strand, not absolute
-/// genomic coordinate direction.
+/// genomic coordinate direction. `Both` extends 5' and 3' by the same length
+/// in one operation.
 pub enum GenomeAnchorSide {
     FivePrime,
     ThreePrime,
+    Both,
 }
 
 impl GenomeAnchorSide {
@@ -601,6 +603,7 @@ impl GenomeAnchorSide {
         match self {
             Self::FivePrime => "5prime",
             Self::ThreePrime => "3prime",
+            Self::Both => "both",
         }
     }
 }
@@ -642,6 +645,31 @@ impl GenomeGeneExtractMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+/// How a gene query is matched against gene names/ids in a prepared reference.
+///
+/// Leaving the mode unset keeps the legacy behavior: exact matches first,
+/// falling back to substring matches when none are exact.
+pub enum GeneMatchMode {
+    /// Case-insensitive equality with the gene name or id.
+    Exact,
+    /// Case-insensitive substring of the gene name or id.
+    Contains,
+    /// Case-insensitive regular expression searched in the gene name or id.
+    Regex,
+}
+
+impl GeneMatchMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Contains => "contains",
+            Self::Regex => "regex",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 /// External track source format used by tracked-import subscriptions.
@@ -676,7 +704,9 @@ impl GenomeTrackSource {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
 /// Persisted genome-track import subscription shared by GUI and shell routes.
-#[derive(Default)]
+///
+/// Subscriptions are unique by `source` + `path` + `track_name`; disabled
+/// subscriptions stay persisted but are skipped by tracked-anchor sync.
 pub struct GenomeTrackSubscription {
     pub source: GenomeTrackSource,
     pub path: String,
@@ -684,6 +714,21 @@ pub struct GenomeTrackSubscription {
     pub min_score: Option<f64>,
     pub max_score: Option<f64>,
     pub clear_existing: bool,
+    pub enabled: bool,
+}
+
+impl Default for GenomeTrackSubscription {
+    fn default() -> Self {
+        Self {
+            source: GenomeTrackSource::default(),
+            path: String::new(),
+            track_name: None,
+            min_score: None,
+            max_score: None,
+            clear_existing: false,
+            enabled: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -1038,6 +1083,26 @@ impl CandidateSetOperator {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+/// Identity used to match guides across sets in `GuideSetOp`.
+pub enum GuideSetMatchKey {
+    /// Same `guide_id`.
+    #[default]
+    GuideId,
+    /// Same `seq_id`, start, end, and strand.
+    Coordinates,
+}
+
+impl GuideSetMatchKey {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::GuideId => "guide_id",
+            Self::Coordinates => "coordinates",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CandidateObjectiveDirection {
@@ -1055,6 +1120,28 @@ impl CandidateObjectiveDirection {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+/// How weighted-objective scoring treats candidates lacking an objective metric.
+pub enum MissingValuePolicy {
+    /// Leave the candidate unscored (no output metric is written).
+    SkipCandidate,
+    /// Use `0.0` as the raw metric value.
+    TreatAsZero,
+    /// Use the worst observed value of that metric for the term's direction.
+    TreatAsWorst,
+}
+
+impl MissingValuePolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SkipCandidate => "skip_candidate",
+            Self::TreatAsZero => "treat_as_zero",
+            Self::TreatAsWorst => "treat_as_worst",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// One objective dimension for Pareto-frontier ranking.
 pub struct CandidateObjectiveSpec {
@@ -1075,6 +1162,9 @@ pub struct CandidateWeightedObjectiveTerm {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 /// Stable tie-breaker used after objective scores compare equal.
+///
+/// `Seeded` orders ties by a shuffle drawn from the project `random_seed`
+/// parameter instead of by candidate fields.
 pub enum CandidateTieBreakPolicy {
     #[default]
     SeqStartEnd,
@@ -1082,6 +1172,7 @@ pub enum CandidateTieBreakPolicy {
     LengthAscending,
     LengthDescending,
     SequenceLexicographic,
+    Seeded,
 }
 
 impl CandidateTieBreakPolicy {
@@ -1092,6 +1183,7 @@ impl CandidateTieBreakPolicy {
             Self::LengthAscending => "length_ascending",
             Self::LengthDescending => "length_descending",
             Self::SequenceLexicographic => "sequence_lexicographic",
+            Self::Seeded => "seeded",
         }
     }
 }
@@ -4677,6 +4769,8 @@ pub struct GenomeTrackImportProgress {
 pub struct Capabilities {
     pub protocol_version: String,
     pub supported_operations: Vec<String>,
+    #[serde(default)]
+    pub supported_queries: Vec<String>,
     pub supported_export_formats: Vec<String>,
     pub deterministic_operation_log: bool,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -5122,6 +5216,131 @@ const PUBLIC_ENGINE_OPERATION_NAMES: &[&str] = &[
     "AnnotateTfbs",
 ];
 
+/// Protocol-owned names of read-only `GentleEngine` query methods (inspection,
+/// listing, and summary APIs that never record an operation). Kept in sync
+/// with the engine by `public_engine_query_names_match_engine_methods` in
+/// `tests/capability_registry_parity.rs`.
+const PUBLIC_ENGINE_QUERY_NAMES: &[&str] = &[
+    "ambiguity_report",
+    "ancestors_of",
+    "consensus",
+    "descendants_of",
+    "describe_helper_genome_sources",
+    "describe_reference_genome_sources",
+    "describe_sequence_genome_anchor",
+    "estimate_operation_output",
+    "feature_overlaps",
+    "find_cpg_islands",
+    "find_inverted_repeats",
+    "find_tandem_repeats",
+    "get_annotation_profile",
+    "get_candidate_macro_template",
+    "get_cutrun_read_report",
+    "get_dotplot_view",
+    "get_ensembl_gene_entry",
+    "get_ensembl_protein_entry",
+    "get_flexibility_track",
+    "get_guide_oligo_set",
+    "get_guide_practical_filter_report",
+    "get_oligo_order_form",
+    "get_primer_design_report",
+    "get_protein_derivation_report",
+    "get_qpcr_design_report",
+    "get_restriction_cloning_pcr_handoff",
+    "get_reverse_translation_report",
+    "get_rna_read_report",
+    "get_sequencing_confirmation_report",
+    "get_sequencing_trace",
+    "get_uniprot_entry",
+    "get_uniprot_genome_projection",
+    "get_uniprot_projection_audit_parity_report",
+    "get_uniprot_projection_audit_report",
+    "get_workflow_macro_template",
+    "inspect_candidate_set_page",
+    "inspect_cutrun_gene_set_regulatory_support",
+    "inspect_cutrun_regulatory_support",
+    "inspect_dna_ladders",
+    "inspect_feature_expert",
+    "inspect_guide_set_page",
+    "inspect_helper_genome_prepare_activity",
+    "inspect_helper_genome_prepared_compatibility",
+    "inspect_microarray_track_manifest",
+    "inspect_prepared_cache_roots",
+    "inspect_probe_region_output",
+    "inspect_protease_catalog",
+    "inspect_protease_entry",
+    "inspect_reference_genome_prepare_activity",
+    "inspect_reference_genome_prepared_compatibility",
+    "inspect_rna_ladders",
+    "inspect_rna_read_alignment_detail",
+    "inspect_rna_read_alignments",
+    "inspect_rna_read_alignments_with_subset",
+    "inspect_rna_read_concatemers",
+    "inspect_rna_read_gene_support",
+    "inspect_rna_structure",
+    "inspect_sequence_context_view",
+    "inspect_splicing_attract_evidence",
+    "ligation_compatibility",
+    "list_annotation_profiles",
+    "list_candidate_macro_templates",
+    "list_candidate_set_metrics",
+    "list_candidate_sets",
+    "list_construct_reasoning_graph_summaries",
+    "list_cutrun_datasets",
+    "list_cutrun_read_reports",
+    "list_dotplot_views",
+    "list_ensembl_gene_entries",
+    "list_ensembl_protein_entries",
+    "list_flexibility_tracks",
+    "list_genome_track_subscriptions",
+    "list_guide_oligo_sets",
+    "list_guide_sets",
+    "list_helper_catalog_entries",
+    "list_helper_genome_features",
+    "list_helper_genomes",
+    "list_helper_semantics_vocabulary_terms",
+    "list_helper_vector_cards",
+    "list_host_profile_catalog_entries",
+    "list_oligo_order_forms",
+    "list_planning_suggestions",
+    "list_primer_design_reports",
+    "list_protein_derivation_reports",
+    "list_qpcr_design_reports",
+    "list_reference_catalog_entries",
+    "list_reference_genome_genes",
+    "list_reference_genomes",
+    "list_restriction_cloning_pcr_handoffs",
+    "list_reverse_translation_reports",
+    "list_rna_read_reports",
+    "list_sequence_genome_anchor_summaries",
+    "list_sequences_with_genome_anchor",
+    "list_sequencing_confirmation_reports",
+    "list_sequencing_traces",
+    "list_uniprot_entries",
+    "list_uniprot_genome_projections",
+    "list_uniprot_projection_audit_parity_reports",
+    "list_uniprot_projection_audit_reports",
+    "list_workflow_macro_templates",
+    "non_cutters",
+    "query_protein_residue_genomic_coordinates",
+    "query_sequence_features",
+    "query_sequence_repeat_overlaps",
+    "query_uniprot_feature_coding_dna",
+    "screen_against_helper_genomes",
+    "search_subsequence",
+    "sequence_anchor_prepared_genome_options",
+    "sequence_distance",
+    "sequence_fingerprint",
+    "sequence_genome_anchor_summary",
+    "sequence_stats",
+    "sequence_tags",
+    "sequences_with_tag",
+    "summarize_rna_read_gene_support",
+    "summarize_state",
+    "summarize_tfbs_region",
+    "summarize_uniprot_projection_transcript_accounting",
+];
+
 const MCP_TOOL_NAMES: &[(&str, &str, &str, CapabilityMutation)] = &[
     (
         "capabilities",
@@ -5386,6 +5605,11 @@ pub fn public_engine_operation_names() -> &'static [&'static str] {
     PUBLIC_ENGINE_OPERATION_NAMES
 }
 
+/// Protocol-owned names for public read-only engine query methods.
+pub fn public_engine_query_names() -> &'static [&'static str] {
+    PUBLIC_ENGINE_QUERY_NAMES
+}
+
 /// Shared capability registry projected by adapters.
 pub fn capability_registry() -> &'static [CapabilityDescriptor] {
     &CAPABILITY_REGISTRY
@@ -8600,6 +8824,7 @@ pub struct RnaReadConcatemerInspection {
 pub enum TranscriptProteinTranslationTableSource {
     #[default]
     StandardDefault,
+    ExplicitOperationOverride,
     ExplicitCdsQualifier,
     ExplicitTranscriptQualifier,
     ExplicitSourceQualifier,
@@ -8615,6 +8840,7 @@ impl TranscriptProteinTranslationTableSource {
     pub fn as_str(self) -> &'static str {
         match self {
             Self::StandardDefault => "standard_default",
+            Self::ExplicitOperationOverride => "explicit_operation_override",
             Self::ExplicitCdsQualifier => "explicit_cds_qualifier",
             Self::ExplicitTranscriptQualifier => "explicit_transcript_qualifier",
             Self::ExplicitSourceQualifier => "explicit_source_qualifier",
@@ -6,7 +6,7 @@ use gentle_protocol::{
 use std::collections::BTreeSet;
 use std::sync::LazyLock;
 use svg::Document;
-use svg::node::element::{Line, Rectangle, Text};
+use svg::node::element::{Circle, Line, Rectangle, Text};
 
 const SVG_WIDTH: f32 = 1320.0;
 const SVG_HEIGHT: f32 = 760.0;
@@ -65,22 +65,87 @@ pub struct GelSampleInput {
     pub members: Vec<GelSampleMember>,
 }
 
-impl PoolGelLayout {
-    pub fn y_for_bp(&self, bp: usize, top: f32, bottom: f32) -> f32 {
-        let min_bp = self.range_min_bp.max(1) as f64;
-        let max_bp = self.range_max_bp.max(self.range_min_bp + 1) as f64;
-        let bp = bp.clamp(self.range_min_bp.max(1), self.range_max_bp.max(2)) as f64;
-        let log_min = min_bp.log10();
-        let log_max = max_bp.log10();
-        let denom = (log_max - log_min).max(1e-6);
-        let f = ((log_max - bp.log10()) / denom) as f32;
+/// Geometry of one virtual gel used to map fragment lengths to migration
+/// distances.
+///
+/// The band model is log-linear: a fragment's fractional position between
+/// the `range_max_bp` band (distance 0, nearest the wells) and the
+/// `range_min_bp` band (distance `run_length`) is
+/// `f = (log10(max) - log10(len)) / (log10(max) - log10(min))`, then curved
+/// as `f^k`. The exponent `k = 1 + 0.18 * (agarose% - 1)` (plus 0.05 for TBE,
+/// clamped to `0.72..=1.55`) makes denser gels compress long fragments and
+/// spread short ones. Lengths outside the range clamp to the gel ends.
+#[derive(Clone, Debug)]
+pub struct GelGeometry {
+    pub range_min_bp: f64,
+    pub range_max_bp: f64,
+    /// Distance between the wells and the `range_min_bp` band, in the
+    /// caller's units (pixels, millimetres, ...).
+    pub run_length: f64,
+    pub conditions: GelRunConditions,
+}
+
+impl GelGeometry {
+    /// Geometry of `layout` drawn over a gel `run_length` units tall.
+    pub fn from_layout(layout: &PoolGelLayout, run_length: f64) -> Self {
+        Self {
+            range_min_bp: layout.range_min_bp as f64,
+            range_max_bp: layout.range_max_bp as f64,
+            run_length,
+            conditions: layout.conditions.clone(),
+        }
+    }
+
+    fn log_bounds(&self) -> (f64, f64) {
+        let min_bp = self.range_min_bp.max(1.0);
+        let max_bp = self.range_max_bp.max(min_bp + 1.0);
+        (min_bp.log10(), max_bp.log10())
+    }
+
+    fn curve_exponent(&self) -> f64 {
         let conditions = self.conditions.normalized();
-        let mut exponent = 1.0 + (conditions.agarose_percent - 1.0) * 0.18;
+        let mut exponent = 1.0 + (conditions.agarose_percent as f64 - 1.0) * 0.18;
         if matches!(conditions.buffer_model, GelBufferModel::Tbe) {
             exponent += 0.05;
         }
-        let curved = f.clamp(0.0, 1.0).powf(exponent.clamp(0.72, 1.55));
-        top + curved * (bottom - top)
+        exponent.clamp(0.72, 1.55)
+    }
+}
+
+/// Predicted migration distance (from the wells) of a `length_bp` fragment.
+///
+/// This is the mapping the gel renderer uses for band placement; it decreases
+/// monotonically with length. Non-finite or non-positive lengths land at the
+/// bottom of the gel, like the shortest fragment. See [`GelGeometry`] for
+/// the model.
+pub fn migration_distance(length_bp: f64, gel: &GelGeometry) -> f64 {
+    let (log_min, log_max) = gel.log_bounds();
+    let log_len = if length_bp.is_finite() && length_bp > 0.0 {
+        length_bp.log10().clamp(log_min, log_max)
+    } else {
+        log_min
+    };
+    let f = (log_max - log_len) / (log_max - log_min).max(1e-6);
+    f.clamp(0.0, 1.0).powf(gel.curve_exponent()) * gel.run_length
+}
+
+/// Inverse of [`migration_distance`]: the fragment length that migrates
+/// `distance` units. Distances outside the gel clamp to the range ends.
+pub fn length_from_distance(distance: f64, gel: &GelGeometry) -> f64 {
+    let (log_min, log_max) = gel.log_bounds();
+    let curved = if gel.run_length.abs() > f64::EPSILON && distance.is_finite() {
+        (distance / gel.run_length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let f = curved.powf(1.0 / gel.curve_exponent());
+    10f64.powf(log_max - f * (log_max - log_min).max(1e-6))
+}
+
+impl PoolGelLayout {
+    pub fn y_for_bp(&self, bp: usize, top: f32, bottom: f32) -> f32 {
+        let gel = GelGeometry::from_layout(self, (bottom - top) as f64);
+        top + migration_distance(bp as f64, &gel) as f32
     }
 }
 
@@ -88,8 +153,13 @@ fn normalize_ladder_name(name: &str) -> String {
     name.trim().to_ascii_lowercase()
 }
 
-fn resolve_ladder_names(requested: &[String], min_bp: usize, max_bp: usize) -> Vec<String> {
-    let names = DNA_LADDERS.names_sorted();
+fn resolve_ladder_names(
+    catalog: &LadderCatalog,
+    requested: &[String],
+    min_bp: usize,
+    max_bp: usize,
+) -> Vec<String> {
+    let names = catalog.names_sorted();
     if names.is_empty() {
         return vec![];
     }
@@ -125,7 +195,7 @@ fn resolve_ladder_names(requested: &[String], min_bp: usize, max_bp: usize) -> V
         }
     }
 
-    DNA_LADDERS.choose_for_range(min_bp, max_bp, 2)
+    catalog.choose_for_range(min_bp, max_bp, 2)
 }
 
 fn apparent_bp_for_member(member: &GelSampleMember, conditions: &GelRunConditions) -> usize {
@@ -298,6 +368,17 @@ pub fn build_serial_gel_layout(
     samples: &[GelSampleInput],
     requested_ladders: &[String],
     conditions: Option<&GelRunConditions>,
+) -> Result<PoolGelLayout, String> {
+    build_serial_gel_layout_with_catalog(samples, requested_ladders, conditions, &DNA_LADDERS)
+}
+
+/// Same as [`build_serial_gel_layout`], but resolves ladders from `catalog`
+/// (e.g. built-ins extended with project-defined ladders).
+pub fn build_serial_gel_layout_with_catalog(
+    samples: &[GelSampleInput],
+    requested_ladders: &[String],
+    conditions: Option<&GelRunConditions>,
+    catalog: &LadderCatalog,
 ) -> Result<PoolGelLayout, String> {
     if samples.is_empty() {
         return Err("Serial gel needs at least one sample lane".to_string());
@@ -342,7 +423,7 @@ pub fn build_serial_gel_layout(
         .map(|member| member.bp)
         .max()
         .unwrap_or(pool_min);
-    let selected_ladders = resolve_ladder_names(requested_ladders, pool_min, pool_max);
+    let selected_ladders = resolve_ladder_names(catalog, requested_ladders, pool_min, pool_max);
     if selected_ladders.is_empty() {
         return Err("No DNA ladders available for pool-gel rendering".to_string());
     }
@@ -366,7 +447,7 @@ pub fn build_serial_gel_layout(
         .collect::<Vec<_>>();
 
     for ladder_name in &left_ladders {
-        let Some(ladder) = DNA_LADDERS.get(ladder_name) else {
+        let Some(ladder) = catalog.get(ladder_name) else {
             continue;
         };
         let mut parts = ladder.bands().clone();
@@ -418,7 +499,7 @@ pub fn build_serial_gel_layout(
     normalize_sample_lane_intensities(&mut lanes);
 
     for ladder_name in &right_ladders {
-        let Some(ladder) = DNA_LADDERS.get(ladder_name) else {
+        let Some(ladder) = catalog.get(ladder_name) else {
             continue;
         };
         let mut parts = ladder.bands().clone();
@@ -1159,12 +1240,259 @@ pub fn export_pool_gel_svg(layout: &PoolGelLayout) -> String {
     doc.to_string()
 }
 
+/// One lane container's primary sample to place on a plate.
+#[derive(Clone, Debug)]
+pub struct PlateSampleInput {
+    pub container_id: String,
+    pub seq_id: String,
+    /// Human-readable well label (sequence name, falling back to its id).
+    pub label: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct PlateWellAssignment {
+    /// 0-based plate index; samples beyond one plate continue on the next.
+    pub plate_index: usize,
+    pub well: String,
+    pub container_id: String,
+    pub seq_id: String,
+    pub label: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct PlateLayout {
+    pub title: String,
+    pub rows: usize,
+    pub columns: usize,
+    pub plate_count: usize,
+    pub wells: Vec<PlateWellAssignment>,
+}
+
+/// Rows x columns of the supported plate formats (96 and 384 wells).
+pub fn plate_dimensions(well_count: usize) -> Option<(usize, usize)> {
+    match well_count {
+        96 => Some((8, 12)),
+        384 => Some((16, 24)),
+        _ => None,
+    }
+}
+
+/// Assign samples to wells row-major (`A1`, `A2`, ...), opening a new plate
+/// whenever the current one is full.
+pub fn build_plate_layout(
+    title: &str,
+    samples: &[PlateSampleInput],
+    well_count: usize,
+) -> Result<PlateLayout, String> {
+    let (rows, columns) = plate_dimensions(well_count)
+        .ok_or_else(|| format!("Unsupported plate format {well_count}; use 96 or 384 wells"))?;
+    if samples.is_empty() {
+        return Err("Plate layout requires at least one sample".to_string());
+    }
+    let capacity = rows * columns;
+    let wells = samples
+        .iter()
+        .enumerate()
+        .map(|(idx, sample)| {
+            let slot = idx % capacity;
+            PlateWellAssignment {
+                plate_index: idx / capacity,
+                well: format!(
+                    "{}{}",
+                    (b'A' + (slot / columns) as u8) as char,
+                    slot % columns + 1
+                ),
+                container_id: sample.container_id.clone(),
+                seq_id: sample.seq_id.clone(),
+                label: sample.label.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(PlateLayout {
+        title: title.to_string(),
+        rows,
+        columns,
+        plate_count: samples.len().div_ceil(capacity),
+        wells,
+    })
+}
+
+/// Draw one well grid per plate, stacked vertically, with each occupied well
+/// labeled by its sample's human-readable id.
+pub fn export_plate_layout_svg(layout: &PlateLayout) -> String {
+    const MARGIN: f32 = 48.0;
+    const HEADER: f32 = 36.0;
+    const PLATE_GAP: f32 = 40.0;
+    let pitch: f32 = if layout.columns > 12 { 30.0 } else { 56.0 };
+    let radius = pitch * 0.42;
+    let label_size = if layout.columns > 12 { 6.0 } else { 9.0 };
+    let grid_width = pitch * layout.columns as f32;
+    let grid_height = pitch * layout.rows as f32;
+    let plate_height = HEADER + grid_height + PLATE_GAP;
+    let width = MARGIN * 2.0 + grid_width + 24.0;
+    let height = MARGIN + plate_height * layout.plate_count.max(1) as f32;
+
+    let mut doc = Document::new()
+        .set("viewBox", (0, 0, width, height))
+        .set("width", width)
+        .set("height", height)
+        .add(
+            Rectangle::new()
+                .set("x", 0)
+                .set("y", 0)
+                .set("width", width)
+                .set("height", height)
+                .set("fill", "#ffffff"),
+        );
+    for plate_index in 0..layout.plate_count.max(1) {
+        let top = MARGIN + plate_height * plate_index as f32;
+        let grid_left = MARGIN + 24.0;
+        let grid_top = top + HEADER;
+        doc = doc.add(
+            Text::new(format!(
+                "{} - plate {} of {} ({} wells)",
+                layout.title,
+                plate_index + 1,
+                layout.plate_count.max(1),
+                layout.rows * layout.columns
+            ))
+            .set("x", MARGIN)
+            .set("y", top + 14.0)
+            .set("font-family", "sans-serif")
+            .set("font-size", 14)
+            .set("font-weight", "bold")
+            .set("fill", "#0f172a"),
+        );
+        doc = doc.add(
+            Rectangle::new()
+                .set("x", grid_left - 6.0)
+                .set("y", grid_top - 6.0)
+                .set("width", grid_width + 12.0)
+                .set("height", grid_height + 12.0)
+                .set("rx", 10)
+                .set("fill", "#f8fafc")
+                .set("stroke", "#94a3b8"),
+        );
+        for column in 0..layout.columns {
+            doc = doc.add(
+                Text::new((column + 1).to_string())
+                    .set("x", grid_left + pitch * (column as f32 + 0.5))
+                    .set("y", grid_top - 10.0)
+                    .set("text-anchor", "middle")
+                    .set("font-family", "monospace")
+                    .set("font-size", 10)
+                    .set("fill", "#475569"),
+            );
+        }
+        for row in 0..layout.rows {
+            doc = doc.add(
+                Text::new(((b'A' + row as u8) as char).to_string())
+                    .set("x", grid_left - 14.0)
+                    .set("y", grid_top + pitch * (row as f32 + 0.5) + 4.0)
+                    .set("text-anchor", "middle")
+                    .set("font-family", "monospace")
+                    .set("font-size", 10)
+                    .set("fill", "#475569"),
+            );
+            for column in 0..layout.columns {
+                let well = format!("{}{}", (b'A' + row as u8) as char, column + 1);
+                let cx = grid_left + pitch * (column as f32 + 0.5);
+                let cy = grid_top + pitch * (row as f32 + 0.5);
+                let assignment = layout
+                    .wells
+                    .iter()
+                    .find(|entry| entry.plate_index == plate_index && entry.well == well);
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", cx)
+                        .set("cy", cy)
+                        .set("r", radius)
+                        .set(
+                            "fill",
+                            if assignment.is_some() {
+                                "#bfdbfe"
+                            } else {
+                                "#ffffff"
+                            },
+                        )
+                        .set("stroke", "#64748b")
+                        .set("data-well", well.clone()),
+                );
+                if let Some(assignment) = assignment {
+                    doc = doc.add(
+                        Text::new(assignment.label.clone())
+                            .set("x", cx)
+                            .set("y", cy + label_size / 3.0)
+                            .set("text-anchor", "middle")
+                            .set("font-family", "sans-serif")
+                            .set("font-size", label_size)
+                            .set("fill", "#1e3a8a"),
+                    );
+                }
+            }
+        }
+    }
+    doc.to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     #[cfg(feature = "snapshot-tests")]
     use std::fs;
 
+    #[test]
+    fn test_build_plate_layout_overflows_to_second_plate() {
+        let samples = (0..100)
+            .map(|idx| PlateSampleInput {
+                container_id: format!("c-{idx}"),
+                seq_id: format!("s{idx}"),
+                label: format!("S{idx}"),
+            })
+            .collect::<Vec<_>>();
+        let layout = build_plate_layout("run", &samples, 96).unwrap();
+        assert_eq!(layout.plate_count, 2);
+        assert_eq!(layout.wells[95].well, "H12");
+        assert_eq!(layout.wells[95].plate_index, 0);
+        assert_eq!(layout.wells[96].well, "A1");
+        assert_eq!(layout.wells[96].plate_index, 1);
+        let svg = export_plate_layout_svg(&layout);
+        assert!(svg.contains("plate 2 of 2"));
+        assert!(build_plate_layout("run", &samples, 48).is_err());
+    }
+
+    #[test]
+    fn test_migration_distance_decreases_with_length_and_inverts() {
+        let gel = GelGeometry {
+            range_min_bp: 100.0,
+            range_max_bp: 10_000.0,
+            run_length: 500.0,
+            conditions: GelRunConditions {
+                agarose_percent: 2.0,
+                buffer_model: GelBufferModel::Tbe,
+                topology_aware: true,
+            },
+        };
+        let lengths = [100.0, 250.0, 500.0, 1_000.0, 3_000.0, 10_000.0];
+        let distances = lengths
+            .iter()
+            .map(|len| migration_distance(*len, &gel))
+            .collect::<Vec<_>>();
+        assert!((distances[0] - 500.0).abs() < 1e-9);
+        assert!(distances[5].abs() < 1e-9);
+        assert!(distances.windows(2).all(|pair| pair[0] > pair[1]));
+        for (len, distance) in lengths.iter().zip(&distances) {
+            let round_trip = length_from_distance(*distance, &gel);
+            assert!(
+                (round_trip - len).abs() / len < 1e-9,
+                "{len} -> {round_trip}"
+            );
+        }
+        assert_eq!(migration_distance(50.0, &gel), distances[0]);
+        assert_eq!(migration_distance(0.0, &gel), distances[0]);
+        assert_eq!(migration_distance(f64::NAN, &gel), distances[0]);
+    }
+
     #[test]
     fn test_build_pool_gel_layout_auto_ladders() {
         let members = vec![
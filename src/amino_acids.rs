@@ -23,6 +23,42 @@ pub struct CodonTable {
     pub organism: String,
 }
 
+impl CodonTable {
+    /// Translates one codon with this table. Ambiguous (IUPAC) codons resolve
+    /// only when every expansion encodes the same amino acid.
+    pub fn codon2aa(&self, codon: [u8; 3]) -> char {
+        // This will return an out-of-range error if a codon base is not A, C, G, or T
+        let pos = AminoAcids::acgt(codon[0]) * 16
+            + AminoAcids::acgt(codon[1]) * 4
+            + AminoAcids::acgt(codon[2]);
+        if pos < 64 {
+            self.sequence.chars().nth(pos).unwrap_or(UNKNOWN_CODON)
+        } else {
+            let result: HashSet<char> = AminoAcids::base2bases(codon[0])
+                .into_iter()
+                .cartesian_product(AminoAcids::base2bases(codon[1]))
+                .cartesian_product(AminoAcids::base2bases(codon[2]))
+                .map(|((c1, c2), c3)| {
+                    AminoAcids::acgt(c1) * 16 + AminoAcids::acgt(c2) * 4 + AminoAcids::acgt(c3)
+                })
+                .map(|pos| self.sequence.chars().nth(pos).unwrap_or(UNKNOWN_CODON))
+                .collect();
+            if result.len() == 1 {
+                // Only one possible amino acid
+                *result.iter().next().unwrap()
+            } else {
+                UNKNOWN_CODON // More than one possible amino acid
+            }
+        }
+    }
+}
+
+/// Looks up an NCBI genetic code by table number, e.g. 1 (standard),
+/// 2 (vertebrate mitochondrial), or 11 (bacterial).
+pub fn codon_table(table_id: u8) -> Option<&'static CodonTable> {
+    crate::AMINO_ACIDS.codon_tables.get(&(table_id as usize))
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AminoAcidHydrophobicity {
     pub kyle_doolittle: f32,
@@ -139,29 +175,9 @@ impl AminoAcids {
 
     pub fn codon2aa(&self, codon: [u8; 3], translation_table: Option<usize>) -> char {
         let translation_table = translation_table.unwrap_or(DEFAULT_TRANSLATION_TABLE);
-        let tt = match self.codon_tables.get(&translation_table) {
-            Some(tt) => tt,
-            None => return UNKNOWN_CODON,
-        };
-
-        // This will return an out-of-range error if a codon base is not A, C, G, or T
-        let pos = Self::acgt(codon[0]) * 16 + Self::acgt(codon[1]) * 4 + Self::acgt(codon[2]);
-        if pos < 64 {
-            tt.sequence.chars().nth(pos).unwrap_or(UNKNOWN_CODON)
-        } else {
-            let result: HashSet<char> = Self::base2bases(codon[0])
-                .into_iter()
-                .cartesian_product(Self::base2bases(codon[1]))
-                .cartesian_product(Self::base2bases(codon[2]))
-                .map(|((c1, c2), c3)| Self::acgt(c1) * 16 + Self::acgt(c2) * 4 + Self::acgt(c3))
-                .map(|pos| tt.sequence.chars().nth(pos).unwrap_or(UNKNOWN_CODON))
-                .collect();
-            if result.len() == 1 {
-                // Only one possible amino acid
-                *result.iter().next().unwrap()
-            } else {
-                UNKNOWN_CODON // More than one possible amino acid
-            }
+        match self.codon_tables.get(&translation_table) {
+            Some(tt) => tt.codon2aa(codon),
+            None => UNKNOWN_CODON,
         }
     }
 
@@ -313,6 +329,21 @@ mod tests {
         assert_eq!(aas.codon2aa([b'G', b'A', b'N'], None), UNKNOWN_CODON);
     }
 
+    #[test]
+    fn test_codon_table_honors_genetic_code_variants() {
+        let standard = codon_table(1).expect("standard code");
+        let mito = codon_table(2).expect("vertebrate mitochondrial code");
+        let bacterial = codon_table(11).expect("bacterial code");
+        assert_eq!(standard.codon2aa(*b"TGA"), STOP_CODON);
+        assert_eq!(mito.codon2aa(*b"TGA"), 'W');
+        assert_eq!(standard.codon2aa(*b"AGA"), 'R');
+        assert_eq!(mito.codon2aa(*b"AGA"), STOP_CODON);
+        assert_eq!(mito.codon2aa(*b"ATA"), 'M');
+        assert_eq!(bacterial.codon2aa(*b"TGA"), STOP_CODON);
+        assert_eq!(bacterial.codon2aa(*b"GCN"), 'A');
+        assert!(codon_table(7).is_none());
+    }
+
     #[test]
     fn protein_isoelectric_point_distinguishes_basic_and_acidic_sequences() {
         let aas = AminoAcids::default();
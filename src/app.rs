@@ -3155,6 +3155,11 @@ impl GENtleApp {
             );
         target.show_gc_contents = source.show_gc_contents;
         target.gc_content_bin_size_bp = source.gc_content_bin_size_bp;
+        target.show_feature_density_when_zoomed_out = source.show_feature_density_when_zoomed_out;
+        target.feature_density_bin_bp = source.feature_density_bin_bp;
+        target.circular_tick_interval_bp = source.circular_tick_interval_bp;
+        target.collapse_introns_over_bp = source.collapse_introns_over_bp;
+        target.feature_color_overrides = source.feature_color_overrides.clone();
         target.show_open_reading_frames = source.show_open_reading_frames;
         target.show_methylation_sites = source.show_methylation_sites;
         target.feature_details_font_size =
@@ -6406,6 +6411,8 @@ Error: `{err}`"
         let op = Operation::LoadFile {
             path: path.to_string(),
             as_id: None,
+            skip_duplicates: None,
+            uppercase: None,
         };
         let load_result = {
             let mut engine = self.engine.write().unwrap();
@@ -6577,6 +6584,13 @@ Error: `{err}`"
         }
         display.show_gc_contents.hash(&mut hasher);
         display.gc_content_bin_size_bp.hash(&mut hasher);
+        display
+            .show_feature_density_when_zoomed_out
+            .hash(&mut hasher);
+        display.feature_density_bin_bp.hash(&mut hasher);
+        display.circular_tick_interval_bp.hash(&mut hasher);
+        display.collapse_introns_over_bp.hash(&mut hasher);
+        display.feature_color_overrides.hash(&mut hasher);
         display.show_open_reading_frames.hash(&mut hasher);
         display.show_methylation_sites.hash(&mut hasher);
         display
@@ -7137,6 +7151,32 @@ Error: `{err}`"
                     percent,
                 )
             }
+            OperationProgress::CandidateGeneration {
+                produced,
+                total_windows,
+            } => Self::tutorial_project_progress_message(
+                chapter_id,
+                chapter_title,
+                "execute_workflow",
+                &format!(
+                    "Candidate generation: {produced} candidate(s), {total_windows} window(s)"
+                ),
+                None,
+            ),
+            OperationProgress::ContainerOp { processed, total } => {
+                let percent = if total > 0 {
+                    Some((processed as f32 / total as f32).clamp(0.0, 1.0))
+                } else {
+                    None
+                };
+                Self::tutorial_project_progress_message(
+                    chapter_id,
+                    chapter_title,
+                    "execute_workflow",
+                    &format!("Container operation: {processed} / {total}"),
+                    percent,
+                )
+            }
         };
         if message.phase == "execute_workflow" {
             message.percent = Self::tutorial_project_scale_workflow_percent(message.percent);
@@ -9443,6 +9483,7 @@ Error: `{err}`"
             min_score,
             max_score,
             clear_existing: self.genome_track_clear_existing,
+            enabled: true,
         })
     }
 
@@ -10620,7 +10661,7 @@ Error: `{err}`"
     fn format_op_result_status(
         prefix: &str,
         created: &[String],
-        warnings: &[String],
+        warnings: &[impl AsRef<str>],
         messages: &[String],
     ) -> String {
         let created_text = if created.is_empty() {
@@ -10631,7 +10672,11 @@ Error: `{err}`"
         let warnings_text = if warnings.is_empty() {
             "-".to_string()
         } else {
-            warnings.join(" | ")
+            warnings
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<&str>>()
+                .join(" | ")
         };
         let messages_text = if messages.is_empty() {
             "-".to_string()
@@ -11401,7 +11446,7 @@ Error: `{err}`"
                 op_id: "background-prepare-genome".to_string(),
                 created_seq_ids: vec![],
                 changed_seq_ids: vec![],
-                warnings: report.warnings.clone(),
+                warnings: report.warnings.iter().cloned().map(Into::into).collect(),
                 messages: vec![GentleEngine::format_prepare_genome_message(
                     &genome_id,
                     cache_dir.as_deref(),
@@ -12771,6 +12816,9 @@ Error: `{err}`"
             hits,
             track_name,
             clear_existing: Some(self.genome_blast_import_clear_existing),
+            min_identity_percent: None,
+            max_evalue: None,
+            min_bit_score: None,
             blast_provenance: Some(BlastInvocationProvenance {
                 genome_id: result.report.genome_id.clone(),
                 query_label: result.query_label.clone(),
@@ -13104,6 +13152,7 @@ Error: `{err}`"
             genome_id,
             gene_query,
             occurrence: Some(occurrence),
+            match_mode: None,
             output_id,
             extract_mode: Some(extract_mode),
             promoter_upstream_bp: matches!(extract_mode, GenomeGeneExtractMode::CodingWithPromoter)
@@ -13248,7 +13297,11 @@ Error: `{err}`"
                 ui.small("Preview differs from the saved arrangement until you click 'Save to Arrangement'.");
             }
         });
-        let ladder_names = GentleEngine::inspect_dna_ladders(None)
+        let ladder_names = self
+            .engine
+            .read()
+            .map(|engine| engine.inspect_dna_ladders(None))
+            .unwrap_or_else(|_| GentleEngine::new().inspect_dna_ladders(None))
             .ladders
             .into_iter()
             .map(|ladder| ladder.name)
@@ -16670,7 +16723,9 @@ Error: `{err}`"
                             );
                         }
                     }
-                    Operation::RenderSequenceSvg { seq_id, mode, path } => {
+                    Operation::RenderSequenceSvg {
+                        seq_id, mode, path, ..
+                    } => {
                         let mode_label = match mode {
                             RenderSvgMode::Linear => "linear",
                             RenderSvgMode::Circular => "circular",
@@ -20123,6 +20178,13 @@ Error: `{err}`"
             defaults.preferred_restriction_enzymes.clone();
         self.configuration_graphics.show_gc_contents = defaults.show_gc_contents;
         self.configuration_graphics.gc_content_bin_size_bp = defaults.gc_content_bin_size_bp;
+        self.configuration_graphics
+            .show_feature_density_when_zoomed_out = defaults.show_feature_density_when_zoomed_out;
+        self.configuration_graphics.feature_density_bin_bp = defaults.feature_density_bin_bp;
+        self.configuration_graphics.circular_tick_interval_bp = defaults.circular_tick_interval_bp;
+        self.configuration_graphics.collapse_introns_over_bp = defaults.collapse_introns_over_bp;
+        self.configuration_graphics.feature_color_overrides =
+            defaults.feature_color_overrides.clone();
         self.configuration_graphics.show_open_reading_frames = defaults.show_open_reading_frames;
         self.configuration_graphics.show_methylation_sites = defaults.show_methylation_sites;
         self.configuration_graphics.feature_details_font_size = defaults.feature_details_font_size;
@@ -21111,6 +21173,68 @@ Error: `{err}`"
                 changed = true;
             }
         });
+        changed |= ui
+            .checkbox(
+                &mut self
+                    .configuration_graphics
+                    .show_feature_density_when_zoomed_out,
+                crate::i18n::tr("configuration.graphics.show_feature_density"),
+            )
+            .on_hover_text(
+                "Replace individual feature glyphs with a coverage heatmap in wide linear views",
+            )
+            .changed();
+        ui.horizontal(|ui| {
+            ui.label(crate::i18n::tr("sequence.feature_density_bin_size"));
+            if ui
+                .add(
+                    egui::DragValue::new(&mut self.configuration_graphics.feature_density_bin_bp)
+                        .range(1..=5_000_000)
+                        .speed(10.0)
+                        .suffix(" bp"),
+                )
+                .on_hover_text("Window size used to bin feature coverage in the density heatmap")
+                .changed()
+            {
+                changed = true;
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(crate::i18n::tr(
+                "configuration.graphics.circular_tick_interval",
+            ));
+            if ui
+                .add(
+                    egui::DragValue::new(
+                        &mut self.configuration_graphics.circular_tick_interval_bp,
+                    )
+                    .range(0..=5_000_000)
+                    .speed(10.0)
+                    .suffix(" bp"),
+                )
+                .on_hover_text("Ruler tick spacing on circular maps (0 = automatic)")
+                .changed()
+            {
+                changed = true;
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(crate::i18n::tr("configuration.graphics.collapse_introns_over"));
+            if ui
+                .add(
+                    egui::DragValue::new(&mut self.configuration_graphics.collapse_introns_over_bp)
+                        .range(0..=5_000_000)
+                        .speed(100.0)
+                        .suffix(" bp"),
+                )
+                .on_hover_text(
+                    "Draw feature-free stretches longer than this as fixed-width breaks in the linear map (0 = off)",
+                )
+                .changed()
+            {
+                changed = true;
+            }
+        });
         changed |= ui
             .checkbox(
                 &mut self.configuration_graphics.show_open_reading_frames,
@@ -22955,10 +23079,18 @@ Error: `{err}`"
 
     fn summarize_operation(op: &Operation) -> String {
         match op {
-            Operation::LoadFile { path, as_id } => match as_id {
+            Operation::LoadFile { path, as_id, .. } => match as_id {
                 Some(id) => format!("Load file: path={path}, as_id={id}"),
                 None => format!("Load file: path={path}"),
             },
+            Operation::ImportDirectory {
+                directory,
+                recursive,
+                container_name,
+            } => format!(
+                "Import directory: directory={directory}, recursive={recursive}, container_name={}",
+                container_name.as_deref().unwrap_or("-")
+            ),
             Operation::CreateSequenceFromText {
                 output_id,
                 name,
@@ -23089,6 +23221,25 @@ Error: `{err}`"
                 reverse_primer,
                 unique.unwrap_or(false)
             ),
+            Operation::MultiplexPcr {
+                template,
+                primers,
+                max_amplicon_bp,
+                ..
+            } => format!(
+                "Multiplex PCR: template={template}, primers={}, max_amplicon_bp={max_amplicon_bp}",
+                primers.len()
+            ),
+            Operation::NestedPcr {
+                template,
+                outer_forward,
+                outer_reverse,
+                inner_forward,
+                inner_reverse,
+                ..
+            } => format!(
+                "Nested PCR: template={template}, outer={outer_forward}/{outer_reverse}, inner={inner_forward}/{inner_reverse}"
+            ),
             Operation::PcrAdvanced {
                 template,
                 forward_primer,
@@ -23181,6 +23332,67 @@ Error: `{err}`"
                 "Branch: input={input}, output_id={}",
                 output_id.clone().unwrap_or_else(|| "-".to_string())
             ),
+            Operation::StripAnnotations {
+                input,
+                keep_kinds,
+                output_id,
+            } => format!(
+                "Strip annotations: input={input}, keep_kinds={}, output_id={}",
+                if keep_kinds.is_empty() {
+                    "-".to_string()
+                } else {
+                    keep_kinds.join(",")
+                },
+                output_id.clone().unwrap_or_else(|| "-".to_string())
+            ),
+            Operation::RenameSequence { from, to } => {
+                format!("Rename sequence: from={from}, to={to}")
+            }
+            Operation::SetSequenceDescription {
+                seq_id,
+                description,
+            } => format!("Set sequence description: seq_id={seq_id}, description={description}"),
+            Operation::MakeConsensus {
+                seq_ids,
+                threshold,
+                output_id,
+            } => format!(
+                "Make consensus: seq_ids=[{}], threshold={threshold}, output_id={}",
+                seq_ids.join(", "),
+                output_id.clone().unwrap_or_else(|| "-".to_string())
+            ),
+            Operation::Dephosphorylate { input, output_id } => format!(
+                "Dephosphorylate: input={input}, output_id={}",
+                output_id.clone().unwrap_or_else(|| "-".to_string())
+            ),
+            Operation::AnnotateDifferences { reference, variant } => {
+                format!("Annotate differences: reference={reference}, variant={variant}")
+            }
+            Operation::ReplaceSubsequence {
+                seq_id,
+                find,
+                replace,
+                max_replacements,
+                output_id,
+            } => format!(
+                "Replace subsequence: seq_id={seq_id}, find={find}, replace={replace}, max_replacements={}, output_id={}",
+                max_replacements
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "all".to_string()),
+                output_id.clone().unwrap_or_else(|| "-".to_string())
+            ),
+            Operation::TagSequence {
+                seq_id,
+                add,
+                remove,
+            } => format!(
+                "Tag sequence: seq_id={seq_id}, add=[{}], remove=[{}]",
+                add.join(", "),
+                remove.join(", ")
+            ),
+            Operation::DeleteSequence { seq_id, cascade } => {
+                format!("Delete sequence: seq_id={seq_id}, cascade={cascade}")
+            }
             Operation::SetDisplayVisibility { target, visible } => {
                 let target_name = match target {
                     DisplayTarget::SequencePanel => "Sequence panel",
@@ -23203,6 +23415,19 @@ Error: `{err}`"
             Operation::SetLinearViewport { start_bp, span_bp } => {
                 format!("Set linear viewport: start_bp={start_bp}, span_bp={span_bp}")
             }
+            Operation::ZoomViewport {
+                seq_id,
+                factor,
+                center_bp,
+            } => match center_bp {
+                Some(center_bp) => format!(
+                    "Zoom linear viewport: seq_id={seq_id}, factor={factor}, center_bp={center_bp}"
+                ),
+                None => format!("Zoom linear viewport: seq_id={seq_id}, factor={factor}"),
+            },
+            Operation::PanViewport { seq_id, delta_bp } => {
+                format!("Pan linear viewport: seq_id={seq_id}, delta_bp={delta_bp}")
+            }
             Operation::SetTopology { seq_id, circular } => {
                 if *circular {
                     format!("Set topology: seq_id={seq_id}, topology=circular")
@@ -23441,10 +23666,34 @@ Error: `{err}`"
                 seq_id,
                 path,
                 format,
+                ..
             } => format!("Save file: seq_id={seq_id}, path={path}, format={format:?}"),
-            Operation::RenderSequenceSvg { seq_id, mode, path } => {
-                format!("Render sequence SVG: seq_id={seq_id}, mode={mode:?}, path={path}")
-            }
+            Operation::ExportAll {
+                directory,
+                format,
+                filename_template,
+            } => format!(
+                "Export all sequences: directory={directory}, format={format:?}, filename_template={}",
+                filename_template.as_deref().unwrap_or("{id}")
+            ),
+            Operation::RenderSequenceSvg {
+                seq_id,
+                mode,
+                path,
+                viewport,
+            } => match viewport {
+                Some((start_bp, span_bp)) => format!(
+                    "Render sequence SVG: seq_id={seq_id}, mode={mode:?}, path={path}, start_bp={start_bp}, span_bp={span_bp}"
+                ),
+                None => format!("Render sequence SVG: seq_id={seq_id}, mode={mode:?}, path={path}"),
+            },
+            Operation::RenderThumbnails {
+                directory,
+                mode,
+                max_width_px,
+            } => format!(
+                "Render thumbnails: directory={directory}, mode={mode:?}, max_width_px={max_width_px}"
+            ),
             Operation::RenderDotplotSvg {
                 seq_id,
                 dotplot_id,
@@ -23482,6 +23731,14 @@ Error: `{err}`"
             Operation::RenderLineageSvg { path } => {
                 format!("Render lineage SVG: path={path}")
             }
+            Operation::RenderPlateLayoutSvg {
+                arrangement_id,
+                path,
+                plate_wells,
+            } => format!(
+                "Render plate layout SVG: arrangement_id={arrangement_id}, path={path}, plate_wells={}",
+                plate_wells.unwrap_or(96)
+            ),
             Operation::RenderPoolGelSvg {
                 inputs,
                 path,
@@ -23518,8 +23775,9 @@ Error: `{err}`"
                 arrangement_id,
                 name,
                 ladders,
+                replicate_count,
             } => format!(
-                "Create serial arrangement: containers={}, arrangement_id={}, name={}, ladders={}",
+                "Create serial arrangement: containers={}, arrangement_id={}, name={}, ladders={}, replicates={}",
                 container_ids.join(", "),
                 arrangement_id
                     .as_deref()
@@ -23534,7 +23792,8 @@ Error: `{err}`"
                     .as_ref()
                     .map(|v| v.join(", "))
                     .filter(|s| !s.is_empty())
-                    .unwrap_or_else(|| "auto".to_string())
+                    .unwrap_or_else(|| "auto".to_string()),
+                replicate_count.unwrap_or(1)
             ),
             Operation::SetArrangementLadders {
                 arrangement_id,
@@ -23723,6 +23982,11 @@ Error: `{err}`"
                 template.as_str(),
                 path
             ),
+            Operation::DefineDnaLadder { name, bands, .. } => format!(
+                "Define DNA ladder: name={}, bands={}",
+                name.trim(),
+                bands.len()
+            ),
             Operation::ExportDnaLadders { path, name_filter } => format!(
                 "Export DNA ladders: path={}, filter={}",
                 path,
@@ -23819,6 +24083,7 @@ Error: `{err}`"
                 genome_id,
                 gene_query,
                 occurrence,
+                match_mode,
                 output_id,
                 extract_mode,
                 promoter_upstream_bp,
@@ -23828,12 +24093,15 @@ Error: `{err}`"
                 catalog_path,
                 cache_dir,
             } => format!(
-                "Extract genome gene: genome_id={}, gene_query={}, occurrence={}, output_id={}, extract_mode={}, promoter_upstream_bp={}, annotation_scope={}, max_annotation_features={}, include_genomic_annotation={}, catalog_path={}, cache_dir={}",
+                "Extract genome gene: genome_id={}, gene_query={}, occurrence={}, match_mode={}, output_id={}, extract_mode={}, promoter_upstream_bp={}, annotation_scope={}, max_annotation_features={}, include_genomic_annotation={}, catalog_path={}, cache_dir={}",
                 genome_id,
                 gene_query,
                 occurrence
                     .map(|v| v.to_string())
                     .unwrap_or_else(|| "-".to_string()),
+                match_mode
+                    .map(|mode| mode.as_str().to_string())
+                    .unwrap_or_else(|| "-".to_string()),
                 output_id.clone().unwrap_or_else(|| "-".to_string()),
                 extract_mode
                     .map(|mode| mode.as_str().to_string())
@@ -23986,6 +24254,7 @@ Error: `{err}`"
                 track_name,
                 clear_existing,
                 blast_provenance,
+                ..
             } => {
                 let invocation = blast_provenance
                     .as_ref()
@@ -772,6 +772,8 @@ impl GENtleApp {
                 .trim()
                 .to_string(),
             as_id: Some(self.evidence_preparation_panel.seq_id.trim().to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         }
     }
 
@@ -850,6 +852,7 @@ impl GENtleApp {
                     .sequence_svg_path
                     .trim()
                     .to_string(),
+                viewport: None,
             },
             Operation::RenderFeatureExpertSvg {
                 seq_id: self.evidence_seq_id(),
@@ -1675,6 +1678,8 @@ mod tests {
             .apply(Operation::LoadFile {
                 path: defaults.sequence_path,
                 as_id: Some(defaults.seq_id.clone()),
+                skip_duplicates: None,
+                uppercase: None,
             })
             .expect("direct load");
         direct.apply(project).expect("direct project");
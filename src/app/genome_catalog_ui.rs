@@ -3526,6 +3526,7 @@ impl GENtleApp {
 
         let mut apply_now_index: Option<usize> = None;
         let mut remove_index: Option<usize> = None;
+        let mut toggle_enabled: Option<(usize, bool)> = None;
         if self.genome_bed_track_subscriptions.is_empty() {
             ui.small("No tracked files yet.");
         } else {
@@ -3554,6 +3555,7 @@ impl GENtleApp {
                     ui.strong("Track");
                     ui.strong("Score Range");
                     ui.strong("Clear Existing");
+                    ui.strong("Enabled");
                     ui.strong("Actions");
                     ui.end_row();
                     for (index, subscription) in filtered_rows {
@@ -3581,6 +3583,7 @@ impl GENtleApp {
                         } else {
                             "no"
                         });
+                        ui.label(if subscription.enabled { "yes" } else { "no" });
                         ui.horizontal(|ui| {
                             let apply_resp = self.track_hover_status(
                                 ui.add_enabled(!import_running, egui::Button::new("Apply now"))
@@ -3604,6 +3607,23 @@ impl GENtleApp {
                             {
                                 remove_index = Some(index);
                             }
+                            let toggle_resp = self.track_hover_status(
+                                ui.add_enabled(
+                                    !import_running,
+                                    egui::Button::new(if subscription.enabled {
+                                        "Disable"
+                                    } else {
+                                        "Enable"
+                                    }),
+                                )
+                                .on_hover_text(
+                                    "Keep this tracked subscription but toggle whether sync applies it to new anchors.",
+                                ),
+                                "Genome Tracks > Toggle Tracked File",
+                            );
+                            if toggle_resp.clicked() {
+                                toggle_enabled = Some((index, !subscription.enabled));
+                            }
                         });
                         ui.end_row();
                     }
@@ -3613,6 +3633,32 @@ impl GENtleApp {
         if let Some(index) = apply_now_index {
             self.apply_tracked_bed_subscription_to_all_anchored(index);
         }
+        if let Some((index, enabled)) = toggle_enabled {
+            let result = self
+                .engine
+                .write()
+                .unwrap()
+                .set_genome_track_subscription_enabled(index, enabled);
+            match result {
+                Ok(updated) => {
+                    self.load_bed_track_subscriptions_from_state();
+                    self.genome_track_status = format!(
+                        "{} tracked {} '{}'",
+                        if updated.enabled {
+                            "Enabled"
+                        } else {
+                            "Disabled"
+                        },
+                        updated.source.label(),
+                        updated.path
+                    );
+                }
+                Err(e) => {
+                    self.genome_track_status =
+                        format!("Could not update tracked file: {}", e.message);
+                }
+            }
+        }
         if let Some(index) = remove_index {
             let result = self
                 .engine
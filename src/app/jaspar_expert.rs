@@ -295,7 +295,12 @@ impl GENtleApp {
                     status_parts.push(result.messages.join(" "));
                 }
                 if !result.warnings.is_empty() {
-                    status_parts.push(format!("warnings: {}", result.warnings.join(" | ")));
+                    let warnings = result
+                        .warnings
+                        .iter()
+                        .map(|warning| warning.message.as_str())
+                        .collect::<Vec<_>>();
+                    status_parts.push(format!("warnings: {}", warnings.join(" | ")));
                 }
                 self.jaspar_expert_status = if status_parts.is_empty() {
                     format!("Loaded JASPAR expert view for '{}'.", motif)
@@ -1396,7 +1396,12 @@ impl GENtleApp {
                         if result.warnings.is_empty() {
                             "-".to_string()
                         } else {
-                            result.warnings.join(" | ")
+                            result
+                                .warnings
+                                .iter()
+                                .map(|warning| warning.message.as_str())
+                                .collect::<Vec<_>>()
+                                .join(" | ")
                         },
                         if result.messages.is_empty() {
                             "-".to_string()
@@ -1493,7 +1498,12 @@ impl GENtleApp {
                         if result.warnings.is_empty() {
                             "-".to_string()
                         } else {
-                            result.warnings.join(" | ")
+                            result
+                                .warnings
+                                .iter()
+                                .map(|warning| warning.message.as_str())
+                                .collect::<Vec<_>>()
+                                .join(" | ")
                         },
                         if result.messages.is_empty() {
                             "-".to_string()
@@ -13302,6 +13302,7 @@ fn refresh_lineage_cache_includes_protein_derivation_analysis_nodes() {
                 scope: None,
                 output_prefix: Some("tp73_protein".to_string()),
                 report_id: None,
+                translation_table: None,
             })
             .expect("derive proteins")
             .op_id
@@ -13657,6 +13658,7 @@ fn refresh_lineage_cache_includes_svg_export_analysis_nodes() {
                 seq_id: "seq_a".to_string(),
                 mode: RenderSvgMode::Linear,
                 path: sequence_svg_path_text.clone(),
+                viewport: None,
             })
             .expect("render sequence svg");
         let render_dotplot_result = engine
@@ -13822,6 +13824,9 @@ fn summarize_operation_blast_import_includes_invocation_preview() {
         }],
         track_name: Some("blast_hits".to_string()),
         clear_existing: Some(true),
+        min_identity_percent: None,
+        max_evalue: None,
+        min_bit_score: None,
         blast_provenance: Some(BlastInvocationProvenance {
             genome_id: "grch38".to_string(),
             query_label: "query".to_string(),
@@ -29,7 +29,7 @@ use gentle::{
     engine::{
         DEFAULT_HOST_PROFILE_CATALOG_PATH, DEFAULT_JASPAR_PRESENTATION_RANDOM_SEED,
         DEFAULT_JASPAR_PRESENTATION_RANDOM_SEQUENCE_LENGTH_BP, DbSnpFetchProgress, Engine,
-        EngineStateSummary, GenomeAnnotationScope, GenomeGeneExtractMode,
+        EngineStateSummary, GeneMatchMode, GenomeAnnotationScope, GenomeGeneExtractMode,
         GenomeTrackImportProgress, GentleEngine, Operation, OperationProgress,
         PrimerDesignProgress, ProjectState, ReporterConstraints, ReporterCorpusExportFormat,
         RnaReadInterpretProgress, SharedAssetActivityStatus, TfbsProgress,
@@ -943,6 +943,15 @@ impl ProgressPrinter {
             OperationProgress::PrimerDesign(p) => self.on_primer_design_progress(p),
             OperationProgress::ReadAcquisition(p) => self.on_read_acquisition_progress(p),
             OperationProgress::RnaReadInterpret(p) => self.on_rna_read_interpret_progress(p),
+            OperationProgress::CandidateGeneration {
+                produced,
+                total_windows,
+            } => self.print_line(&format!(
+                "progress candidates produced={produced} total_windows={total_windows}"
+            )),
+            OperationProgress::ContainerOp { processed, total } => self.print_line(&format!(
+                "progress container processed={processed} total={total}"
+            )),
         }
     }
 }
@@ -261,6 +261,7 @@ fn handle_arrange_serial(args: &[String], cmd_idx: usize, state_path: &str) -> R
             arrangement_id,
             name,
             ladders,
+            replicate_count: None,
         })
         .map_err(|e| e.to_string())?;
     save_state_and_print_first_message(&engine, state_path, &result.messages)
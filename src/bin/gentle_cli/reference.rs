@@ -949,12 +949,13 @@ pub(super) fn handle_reference_family(
             if args.len() <= cmd_idx + 3 {
                 usage();
                 return Err(format!(
-                    "{label} extract-gene requires GENOME_ID QUERY [--occurrence N] [--output-id ID] [--extract-mode gene|coding_with_promoter] [--promoter-upstream-bp N] [--annotation-scope none|core|full] [--max-annotation-features N] [--include-genomic-annotation|--no-include-genomic-annotation] [--catalog PATH] [--cache-dir PATH]"
+                    "{label} extract-gene requires GENOME_ID QUERY [--occurrence N] [--match-mode exact|contains|regex] [--output-id ID] [--extract-mode gene|coding_with_promoter] [--promoter-upstream-bp N] [--annotation-scope none|core|full] [--max-annotation-features N] [--include-genomic-annotation|--no-include-genomic-annotation] [--catalog PATH] [--cache-dir PATH]"
                 ));
             }
             let genome_id = args[cmd_idx + 2].clone();
             let gene_query = args[cmd_idx + 3].clone();
             let mut occurrence: Option<usize> = None;
+            let mut match_mode: Option<GeneMatchMode> = None;
             let mut output_id: Option<String> = None;
             let mut extract_mode: Option<GenomeGeneExtractMode> = None;
             let mut promoter_upstream_bp: Option<usize> = None;
@@ -981,6 +982,26 @@ pub(super) fn handle_reference_family(
                         occurrence = Some(occ);
                         idx += 2;
                     }
+                    "--match-mode" => {
+                        if idx + 1 >= args.len() {
+                            return Err(format!(
+                                "Missing VALUE after --match-mode for {label} extract-gene"
+                            ));
+                        }
+                        let parsed = match args[idx + 1].trim().to_ascii_lowercase().as_str() {
+                            "exact" => GeneMatchMode::Exact,
+                            "contains" => GeneMatchMode::Contains,
+                            "regex" => GeneMatchMode::Regex,
+                            other => {
+                                return Err(format!(
+                                    "Invalid --match-mode value '{}' for {label} extract-gene (expected exact|contains|regex)",
+                                    other
+                                ));
+                            }
+                        };
+                        match_mode = Some(parsed);
+                        idx += 2;
+                    }
                     "--output-id" => {
                         if idx + 1 >= args.len() {
                             return Err(format!(
@@ -1129,6 +1150,7 @@ pub(super) fn handle_reference_family(
                     genome_id,
                     gene_query,
                     occurrence,
+                    match_mode,
                     output_id,
                     extract_mode,
                     promoter_upstream_bp,
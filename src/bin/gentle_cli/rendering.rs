@@ -163,6 +163,7 @@ fn handle_render_svg(args: &[String], cmd_idx: usize, state_path: &str) -> Resul
             seq_id: seq_id.to_string(),
             mode,
             path: output.to_string(),
+            viewport: None,
         })
         .map_err(|e| e.to_string())?;
     save_state_and_print_first_message(&engine, state_path, &result.messages)
@@ -2,7 +2,7 @@
 
 use crate::{
     engine::{
-        ConstructReasoningGraph, ConstructRole, EditableStatus, EvidenceClass,
+        ConstructReasoningGraph, ConstructRole, DisplaySettings, EditableStatus, EvidenceClass,
         LinearSequenceLetterLayoutMode, RestrictionEnzymeDisplayMode,
     },
     enzymes::default_preferred_restriction_enzyme_names,
@@ -337,6 +337,11 @@ pub struct DnaDisplay {
     vcf_display_criteria: VcfDisplayCriteria,
     show_gc_contents: bool,
     gc_content_bin_size_bp: usize,
+    show_feature_density_when_zoomed_out: bool,
+    feature_density_bin_bp: usize,
+    circular_tick_interval_bp: usize,
+    collapse_introns_over_bp: usize,
+    feature_color_overrides: BTreeMap<String, String>,
     show_methylation_sites: bool,
     update_layout: UpdateLayoutParts,
     aa_letters: AminoAcidLetters,
@@ -451,6 +456,10 @@ impl DnaDisplay {
         value.clamp(1, 5_000_000)
     }
 
+    fn clamp_feature_density_bin_bp(value: usize) -> usize {
+        value.clamp(1, 5_000_000)
+    }
+
     fn clamp_linear_sequence_helical_max_view_span_bp(value: usize) -> usize {
         value.min(5_000_000)
     }
@@ -910,6 +919,64 @@ impl DnaDisplay {
         }
     }
 
+    pub fn show_feature_density_when_zoomed_out(&self) -> bool {
+        self.show_feature_density_when_zoomed_out
+    }
+
+    pub fn set_show_feature_density_when_zoomed_out(&mut self, value: bool) {
+        if self.show_feature_density_when_zoomed_out != value {
+            self.show_feature_density_when_zoomed_out = value;
+            self.mark_layout_dirty();
+        }
+    }
+
+    pub fn feature_density_bin_bp(&self) -> usize {
+        Self::clamp_feature_density_bin_bp(self.feature_density_bin_bp)
+    }
+
+    pub fn set_feature_density_bin_bp(&mut self, value: usize) {
+        let value = Self::clamp_feature_density_bin_bp(value);
+        if self.feature_density_bin_bp != value {
+            self.feature_density_bin_bp = value;
+            self.mark_layout_dirty();
+        }
+    }
+
+    /// Circular ruler tick spacing; `0` selects a length-based interval.
+    pub fn circular_tick_interval_bp(&self) -> usize {
+        self.circular_tick_interval_bp
+    }
+
+    pub fn set_circular_tick_interval_bp(&mut self, value: usize) {
+        if self.circular_tick_interval_bp != value {
+            self.circular_tick_interval_bp = value;
+            self.mark_layout_dirty();
+        }
+    }
+
+    /// Linear intron-collapse threshold; `0` keeps the map fully proportional.
+    pub fn collapse_introns_over_bp(&self) -> usize {
+        self.collapse_introns_over_bp
+    }
+
+    pub fn set_collapse_introns_over_bp(&mut self, value: usize) {
+        if self.collapse_introns_over_bp != value {
+            self.collapse_introns_over_bp = value;
+            self.mark_layout_dirty();
+        }
+    }
+
+    pub fn feature_color_overrides(&self) -> &BTreeMap<String, String> {
+        &self.feature_color_overrides
+    }
+
+    pub fn set_feature_color_overrides(&mut self, overrides: BTreeMap<String, String>) {
+        if self.feature_color_overrides != overrides {
+            self.feature_color_overrides = overrides;
+            self.mark_layout_dirty();
+        }
+    }
+
     pub fn show_methylation_sites(&self) -> bool {
         self.show_methylation_sites
     }
@@ -1218,6 +1285,11 @@ impl Default for DnaDisplay {
             vcf_display_criteria: VcfDisplayCriteria::default(),
             show_gc_contents: true,
             gc_content_bin_size_bp: DEFAULT_SECTION_SIZE_BP,
+            show_feature_density_when_zoomed_out: false,
+            feature_density_bin_bp: DisplaySettings::default_feature_density_bin_bp(),
+            circular_tick_interval_bp: 0,
+            collapse_introns_over_bp: 0,
+            feature_color_overrides: BTreeMap::new(),
             show_methylation_sites: false,
             update_layout: UpdateLayoutParts::default(),
             aa_letters: AminoAcidLetters::Single,
@@ -14,11 +14,14 @@ use gb_io::seq::{Feature, Seq, Topology};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use sha1::{Digest, Sha1};
 use std::{
+    borrow::Cow,
     collections::HashMap,
     fmt,
     fs::File,
-    ops::{Range, RangeInclusive},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut, Range, RangeInclusive},
     path::Path,
 };
 
@@ -127,12 +130,29 @@ impl SyntheticMoleculeType {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// Hit/miss counters of the computed-feature cache (see
+/// `DNAsequence::update_computed_features`). A miss is one full recomputation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputedFeatureCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Whole-sequence restriction-site scans; a miss skips the scan when
+    /// `recompute_sites_in_range` already brought the sites up to date.
+    pub full_site_scans: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DNAoverhang {
     pub forward_3: DNAstring,
     pub forward_5: DNAstring,
     pub reverse_3: DNAstring,
     pub reverse_5: DNAstring,
+    /// The forward strand's 5' terminus (left end) has lost its phosphate.
+    #[serde(default)]
+    pub forward_5_dephosphorylated: bool,
+    /// The reverse strand's 5' terminus (right end) has lost its phosphate.
+    #[serde(default)]
+    pub reverse_5_dephosphorylated: bool,
 }
 
 impl DNAoverhang {
@@ -167,6 +187,108 @@ impl fmt::Display for DNAoverhang {
     }
 }
 
+/// Which strand protrudes at one end of a double-stranded molecule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverhangPolarity {
+    #[default]
+    Blunt,
+    FivePrime,
+    ThreePrime,
+}
+
+impl OverhangPolarity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Blunt => "blunt",
+            Self::FivePrime => "5prime",
+            Self::ThreePrime => "3prime",
+        }
+    }
+}
+
+/// End chemistry of one molecule end.
+///
+/// `overhang` holds the single-stranded bases read 5'->3' on the protruding
+/// strand (uppercase); it is empty for blunt ends. `dephosphorylated` marks
+/// the one 5' terminus at this end (forward strand on the left, reverse
+/// strand on the right) as lacking its phosphate; ends are phosphorylated by
+/// default, as left by restriction digests.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceEnd {
+    pub polarity: OverhangPolarity,
+    pub overhang: DNAstring,
+    #[serde(default)]
+    pub dephosphorylated: bool,
+}
+
+impl SequenceEnd {
+    fn from_strands(top: &[u8], bottom_aligned: &[u8], top_polarity: OverhangPolarity) -> Self {
+        let bottom_polarity = match top_polarity {
+            OverhangPolarity::FivePrime => OverhangPolarity::ThreePrime,
+            _ => OverhangPolarity::FivePrime,
+        };
+        if !top.is_empty() {
+            Self {
+                polarity: top_polarity,
+                overhang: top.to_ascii_uppercase(),
+                dephosphorylated: false,
+            }
+        } else if !bottom_aligned.is_empty() {
+            // The bottom strand is stored aligned to the top strand; read it 5'->3'.
+            Self {
+                polarity: bottom_polarity,
+                overhang: bottom_aligned
+                    .iter()
+                    .rev()
+                    .map(u8::to_ascii_uppercase)
+                    .collect(),
+                dephosphorylated: false,
+            }
+        } else {
+            Self::default()
+        }
+    }
+
+    pub fn is_blunt(&self) -> bool {
+        self.polarity == OverhangPolarity::Blunt
+    }
+
+    /// Whether this end, as the right end of one molecule, can be ligated to
+    /// `next`, the left end of the following molecule.
+    ///
+    /// Blunt ends join blunt ends; sticky ends need the same polarity and
+    /// reverse-complementary overhangs.
+    pub fn ligates_to(&self, next: &SequenceEnd) -> bool {
+        if self.polarity != next.polarity {
+            return false;
+        }
+        if self.is_blunt() {
+            return true;
+        }
+        self.overhang.len() == next.overhang.len()
+            && self
+                .overhang
+                .iter()
+                .rev()
+                .map(|b| IupacCode::letter_complement(*b))
+                .eq(next.overhang.iter().copied())
+    }
+
+    /// Whether ligase can seal a junction of this end with `next`: at least
+    /// one of the two 5' termini meeting there must carry a phosphate.
+    pub fn has_junction_phosphate(&self, next: &SequenceEnd) -> bool {
+        !(self.dephosphorylated && next.dephosphorylated)
+    }
+}
+
+/// Left (5' of the forward strand) and right end chemistry of a molecule.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceEnds {
+    pub left: SequenceEnd,
+    pub right: SequenceEnd,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SequenceEqualityError {
     BiotypeMismatch {
@@ -232,17 +354,204 @@ impl std::error::Error for SequenceEqualityError {}
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DNAsequence {
-    seq: Seq,
+    #[serde(
+        serialize_with = "serialize_seq_store",
+        deserialize_with = "deserialize_seq_store"
+    )]
+    seq: SeqStore,
     overhang: DNAoverhang,
     restriction_enzymes: Vec<RestrictionEnzyme>,
     restriction_enzyme_sites: Vec<RestrictionEnzymeSite>,
-    #[serde_as(as = "Vec<(_, _)>")]
+    #[serde_as(deserialize_as = "Vec<(_, _)>")]
+    #[serde(serialize_with = "serialize_restriction_enzyme_groups")]
     restriction_enzyme_groups: HashMap<RestrictionEnzymeKey, Vec<String>>,
     max_restriction_enzyme_sites: Option<usize>,
     open_reading_frames: Vec<OpenReadingFrame>,
     methylation_sites: MethylationSites,
     methylation_mode: MethylationMode,
     gc_content: GcContents,
+    /// Fingerprint of the inputs the computed features were derived from.
+    #[serde(skip)]
+    computed_cache_key: Option<u64>,
+    /// Fingerprint the restriction sites are current for; may run ahead of
+    /// `computed_cache_key` after an incremental rescan.
+    #[serde(skip)]
+    restriction_sites_key: Option<u64>,
+    #[serde(skip)]
+    computed_cache_stats: ComputedFeatureCacheStats,
+}
+
+/// GenBank record whose bases may, with `mmap-backing`, still live in a
+/// memory-mapped FASTA region. While mapped, `record.seq` is empty: read
+/// bases through `forward`/`bases`/`read_range` and edit them through
+/// `bases_mut`, which copies them into `record.seq` first (copy-on-write).
+/// Metadata and features are reached through `Deref`.
+#[derive(Clone, Debug)]
+struct SeqStore {
+    record: Seq,
+    #[cfg(feature = "mmap-backing")]
+    mapped: Option<std::sync::Arc<crate::mapped_sequence::MappedFasta>>,
+    /// Copy of the mapped bases, made on the first `forward` call.
+    #[cfg(feature = "mmap-backing")]
+    mapped_bases: std::sync::OnceLock<Vec<u8>>,
+}
+
+impl From<Seq> for SeqStore {
+    fn from(record: Seq) -> Self {
+        Self {
+            record,
+            #[cfg(feature = "mmap-backing")]
+            mapped: None,
+            #[cfg(feature = "mmap-backing")]
+            mapped_bases: std::sync::OnceLock::new(),
+        }
+    }
+}
+
+impl Deref for SeqStore {
+    type Target = Seq;
+
+    fn deref(&self) -> &Seq {
+        &self.record
+    }
+}
+
+impl DerefMut for SeqStore {
+    fn deref_mut(&mut self) -> &mut Seq {
+        &mut self.record
+    }
+}
+
+impl SeqStore {
+    /// All bases, kept in memory once read.
+    fn forward(&self) -> &Vec<u8> {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = &self.mapped {
+            return self.mapped_bases.get_or_init(|| mapped.to_vec());
+        }
+        &self.record.seq
+    }
+
+    /// All bases; a mapped region is read into a temporary copy instead of
+    /// being kept in memory.
+    fn bases(&self) -> Cow<'_, [u8]> {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = &self.mapped
+            && self.mapped_bases.get().is_none()
+        {
+            return Cow::Owned(mapped.to_vec());
+        }
+        Cow::Borrowed(self.forward())
+    }
+
+    /// Bases `range` (non-wrapping, inside the sequence).
+    fn read_range(&self, range: Range<usize>) -> Vec<u8> {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = &self.mapped
+            && self.mapped_bases.get().is_none()
+        {
+            return mapped.read_range(range);
+        }
+        self.forward()[range].to_vec()
+    }
+
+    fn base(&self, i: usize) -> Option<u8> {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = &self.mapped
+            && self.mapped_bases.get().is_none()
+        {
+            return mapped.read_range(i..i + 1).first().copied();
+        }
+        self.forward().get(i).copied()
+    }
+
+    fn base_count(&self) -> usize {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = &self.mapped {
+            return mapped.len();
+        }
+        self.record.seq.len()
+    }
+
+    /// Mutable bases, copying mapped bases into the record first.
+    fn bases_mut(&mut self) -> &mut Vec<u8> {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = self.mapped.take() {
+            self.record.seq = self.mapped_bases.take().unwrap_or_else(|| mapped.to_vec());
+        }
+        &mut self.record.seq
+    }
+
+    /// Replace the bases, dropping any mapping.
+    fn set_bases(&mut self, bases: Vec<u8>) {
+        #[cfg(feature = "mmap-backing")]
+        {
+            self.mapped = None;
+            self.mapped_bases = std::sync::OnceLock::new();
+        }
+        self.record.seq = bases;
+    }
+
+    /// The record with its bases present (a copy for mapped sequences).
+    fn record(&self) -> Cow<'_, Seq> {
+        #[cfg(feature = "mmap-backing")]
+        if self.mapped.is_some() {
+            let mut record = self.record.clone();
+            record.seq = self.bases().into_owned();
+            return Cow::Owned(record);
+        }
+        Cow::Borrowed(&self.record)
+    }
+
+    /// Hash the bases, or for a mapped region what identifies them, so
+    /// fingerprinting never reads a mapped region.
+    fn hash_bases<H: Hasher>(&self, state: &mut H) {
+        #[cfg(feature = "mmap-backing")]
+        if let Some(mapped) = &self.mapped {
+            mapped.hash_identity(state);
+            return;
+        }
+        self.record.seq.hash(state);
+    }
+
+    fn is_mapped(&self) -> bool {
+        #[cfg(feature = "mmap-backing")]
+        if self.mapped.is_some() {
+            return true;
+        }
+        false
+    }
+}
+
+/// Serialized as the plain GenBank record, with mapped bases written out.
+fn serialize_seq_store<S: serde::Serializer>(
+    store: &SeqStore,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    store.record().serialize(serializer)
+}
+
+fn deserialize_seq_store<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<SeqStore, D::Error> {
+    Seq::deserialize(deserializer).map(SeqStore::from)
+}
+
+/// Restriction-enzyme groups as `(key, names)` pairs ordered by recognition
+/// span, then cut positions, so serialized state does not depend on hash
+/// iteration order.
+fn serialize_restriction_enzyme_groups<S: serde::Serializer>(
+    groups: &HashMap<RestrictionEnzymeKey, Vec<String>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let mut sorted = groups.iter().collect::<Vec<_>>();
+    sorted.sort_by(|(a, a_names), (b, b_names)| {
+        (a.from(), a.to(), a.pos(), a.mate_pos(), a.cut_size())
+            .cmp(&(b.from(), b.to(), b.pos(), b.mate_pos(), b.cut_size()))
+            .then_with(|| a.number_of_cuts().cmp(&b.number_of_cuts()))
+            .then_with(|| a_names.cmp(b_names))
+    });
+    sorted.serialize(serializer)
 }
 
 impl DNAsequence {
@@ -297,33 +606,46 @@ impl DNAsequence {
     }
 
     pub fn to_genbank_string(&self) -> Result<String> {
+        let seq = self.genbank_write_record(|_| Ok(()))?;
         let mut buffer = Vec::new();
-        let mut seq = self.seq.clone();
-        for feature in &mut seq.features {
-            let location_text = feature.location.to_gb_format();
-            feature.location = gb_io::seq::Location::from_gb_format(&location_text).map_err(|e| {
-                anyhow::anyhow!(
-                    "Could not canonicalize feature location '{location_text}' before GenBank write: {e}"
-                )
-            })?;
-        }
         gb_io::writer::write(&mut buffer, &seq)?;
         Ok(String::from_utf8(buffer)?)
     }
 
     pub fn write_genbank_file(&self, filename: &str) -> Result<()> {
+        self.write_genbank_file_with(filename, |_| Ok(()))
+    }
+
+    /// Write GenBank after `adjust` has edited a copy of the record header
+    /// (LOCUS/DEFINITION/ACCESSION fields); the stored record is unchanged.
+    pub fn write_genbank_file_with<F>(&self, filename: &str, adjust: F) -> Result<()>
+    where
+        F: FnOnce(&mut gb_io::seq::Seq) -> Result<()>,
+    {
+        let seq = self.genbank_write_record(adjust)?;
         let file = File::create(filename)?;
-        let mut seq = self.seq.clone();
+        gb_io::writer::write(file, &seq)?;
+        Ok(())
+    }
+
+    /// Copy of the record as it will be written: `adjust` applied, feature
+    /// locations normalized to the shapes GenBank text can carry, then
+    /// canonicalized through the GenBank location grammar.
+    fn genbank_write_record<F>(&self, adjust: F) -> Result<Seq>
+    where
+        F: FnOnce(&mut gb_io::seq::Seq) -> Result<()>,
+    {
+        let mut seq = self.seq.record().into_owned();
+        adjust(&mut seq)?;
         for feature in &mut seq.features {
-            let location_text = feature.location.to_gb_format();
+            let location_text = normalize_genbank_location(feature.location.clone()).to_gb_format();
             feature.location = gb_io::seq::Location::from_gb_format(&location_text).map_err(|e| {
                 anyhow::anyhow!(
                     "Could not canonicalize feature location '{location_text}' before GenBank write: {e}"
                 )
             })?;
         }
-        gb_io::writer::write(file, &seq)?;
-        Ok(())
+        Ok(seq)
     }
 
     pub fn calculate_restriction_enzyme_sites(
@@ -344,7 +666,7 @@ impl DNAsequence {
         } else {
             i
         };
-        self.forward().get(i).copied()
+        self.seq.base(i)
     }
 
     #[inline(always)]
@@ -354,7 +676,7 @@ impl DNAsequence {
         } else {
             i
         };
-        self.forward().get(i).unwrap_or(&b'N').to_owned()
+        self.get_base_safe(i).unwrap_or(b'N')
     }
 
     pub fn get_inclusive_range_safe(&self, range: RangeInclusive<usize>) -> Option<DNAstring> {
@@ -383,18 +705,14 @@ impl DNAsequence {
         }
         if start > end {
             if self.is_circular() {
-                Some(
-                    self.forward()[start..]
-                        .iter()
-                        .chain(self.forward()[..=end].iter())
-                        .copied()
-                        .collect(),
-                )
+                let mut wrapped = self.seq.read_range(start..self.len());
+                wrapped.extend(self.seq.read_range(0..end + 1));
+                Some(wrapped)
             } else {
                 None
             }
         } else {
-            Some(self.forward()[start..=end].to_vec())
+            Some(self.seq.read_range(start..end + 1))
         }
     }
 
@@ -413,18 +731,25 @@ impl DNAsequence {
             return None;
         }
 
-        let mut seq = if self.is_circular() {
+        let mut seq = if self.seq.is_mapped() && self.features().is_empty() {
+            // Nothing to carry over, so only the extracted bases are read.
+            let mut seq = Self::from_u8(&extracted).seq.record;
+            seq.name = self.seq.name.clone();
+            seq.molecule_type = self.seq.molecule_type.clone();
+            seq
+        } else if self.is_circular() {
             let start = from % self.len();
-            let rotated = self.seq.set_origin(start as i64);
+            let rotated = self.seq.record().set_origin(start as i64);
             rotated.extract_range(0, extracted_len as i64)
         } else {
             self.seq
+                .record()
                 .extract_range(from as i64, from.saturating_add(extracted_len) as i64)
         };
         seq.topology = Topology::Linear;
 
         Some(Self {
-            seq,
+            seq: seq.into(),
             overhang: DNAoverhang::default(),
             restriction_enzymes: vec![],
             restriction_enzyme_sites: vec![],
@@ -434,12 +759,31 @@ impl DNAsequence {
             methylation_sites: MethylationSites::default(),
             methylation_mode: self.methylation_mode.clone(),
             gc_content: GcContents::default(),
+            computed_cache_key: None,
+            restriction_sites_key: None,
+            computed_cache_stats: ComputedFeatureCacheStats::default(),
         })
     }
 
     #[inline(always)]
     fn forward(&self) -> &Vec<u8> {
-        &self.seq.seq
+        self.seq.forward()
+    }
+
+    /// Whether the bases are still backed by a memory-mapped FASTA region.
+    pub fn is_mapped(&self) -> bool {
+        self.seq.is_mapped()
+    }
+
+    /// A sequence backed by a memory-mapped FASTA region instead of an owned
+    /// buffer, otherwise like `from_sequence` of the region's bases. Reads go
+    /// to the map; the first edit copies the bases (copy-on-write).
+    #[cfg(feature = "mmap-backing")]
+    pub fn from_mapped_fasta(mapped: crate::mapped_sequence::MappedFasta) -> Self {
+        let mut ret = Self::from_u8(&[]);
+        ret.seq.len = Some(mapped.len());
+        ret.seq.mapped = Some(std::sync::Arc::new(mapped));
+        ret
     }
 
     pub fn forward_bytes(&self) -> &[u8] {
@@ -448,16 +792,25 @@ impl DNAsequence {
 
     #[inline(always)]
     pub fn len(&self) -> usize {
-        self.forward().len()
+        self.seq.base_count()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.forward().is_empty()
+        self.len() == 0
+    }
+
+    /// Declared record length (GenBank `LOCUS` / EMBL `ID`) and parsed base
+    /// count when they disagree, e.g. after a truncated download. The declared
+    /// length is then reset to the parsed count so exports stay consistent.
+    pub fn reconcile_declared_length(&mut self) -> Option<(usize, usize)> {
+        let parsed = self.len();
+        let declared = self.seq.len.replace(parsed)?;
+        (declared != parsed).then_some((declared, parsed))
     }
 
     pub fn from_genbank_seq(seq: Seq) -> Self {
         Self {
-            seq,
+            seq: seq.into(),
             overhang: DNAoverhang::default(),
             restriction_enzymes: vec![],
             restriction_enzyme_sites: vec![],
@@ -467,6 +820,9 @@ impl DNAsequence {
             methylation_sites: MethylationSites::default(),
             methylation_mode: MethylationMode::default(),
             gc_content: GcContents::default(),
+            computed_cache_key: None,
+            restriction_sites_key: None,
+            computed_cache_stats: ComputedFeatureCacheStats::default(),
         }
     }
 
@@ -554,7 +910,7 @@ impl DNAsequence {
 
         if matches!(molecule, SyntheticMoleculeType::Rna) {
             // Normalize RNA imports to U when users provide T in FASTA.
-            for nt in &mut self.seq.seq {
+            for nt in self.seq.bases_mut() {
                 *nt = match nt.to_ascii_uppercase() {
                     b'T' => b'U',
                     other => other,
@@ -630,7 +986,7 @@ impl DNAsequence {
         };
 
         Self {
-            seq,
+            seq: seq.into(),
             overhang: DNAoverhang::default(),
             restriction_enzymes: vec![],
             restriction_enzyme_sites: vec![],
@@ -640,6 +996,9 @@ impl DNAsequence {
             methylation_sites: MethylationSites::default(),
             methylation_mode: MethylationMode::default(), // TODO default?
             gc_content: GcContents::default(),
+            computed_cache_key: None,
+            restriction_sites_key: None,
+            computed_cache_stats: ComputedFeatureCacheStats::default(),
         }
     }
 
@@ -680,6 +1039,7 @@ impl DNAsequence {
     }
 
     fn update_restriction_enyzme_sites(&mut self) {
+        self.computed_cache_stats.full_site_scans += 1;
         self.restriction_enzyme_sites = self
             .restriction_enzymes
             .par_iter()
@@ -687,20 +1047,55 @@ impl DNAsequence {
             .collect();
     }
 
-    fn update_open_reading_frames(&mut self) {
-        self.open_reading_frames = OpenReadingFrame::find_orfs(self.forward(), self.is_circular());
+    fn update_open_reading_frames(&mut self, bases: &[u8]) {
+        self.open_reading_frames = OpenReadingFrame::find_orfs(bases, self.is_circular());
     }
 
-    fn update_methylation_sites(&mut self) {
+    fn update_methylation_sites(&mut self, bases: &[u8]) {
         let mode = self.methylation_mode.to_owned();
-        self.methylation_sites = MethylationSites::new_from_sequence(self.forward(), mode);
+        self.methylation_sites = MethylationSites::new_from_sequence(bases, mode);
+    }
+
+    fn update_gc_content(&mut self, bases: &[u8]) {
+        self.gc_content = GcContents::new_from_sequence(bases);
+    }
+
+    /// Fingerprint of everything the computed features depend on: bases,
+    /// topology, overhangs, molecule type, enzyme set, site cap and
+    /// methylation mode. Annotation does not contribute.
+    fn computed_features_fingerprint(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.seq.hash_bases(&mut hasher);
+        self.is_circular().hash(&mut hasher);
+        self.overhang.hash(&mut hasher);
+        self.molecule_type().hash(&mut hasher);
+        for enzyme in &self.restriction_enzymes {
+            enzyme.name.hash(&mut hasher);
+            enzyme.sequence.hash(&mut hasher);
+            enzyme.cut.hash(&mut hasher);
+            enzyme.overlap.hash(&mut hasher);
+        }
+        self.max_restriction_enzyme_sites.hash(&mut hasher);
+        self.methylation_mode.dam().hash(&mut hasher);
+        self.methylation_mode.dcm().hash(&mut hasher);
+        self.methylation_mode.cpg().hash(&mut hasher);
+        hasher.finish()
     }
 
-    fn update_gc_content(&mut self) {
-        self.gc_content = GcContents::new_from_sequence(self.forward());
+    pub fn computed_feature_cache_stats(&self) -> ComputedFeatureCacheStats {
+        self.computed_cache_stats
     }
 
+    /// Recompute restriction sites, ORFs, methylation sites and GC content,
+    /// unless nothing they depend on changed since the last computation.
     pub fn update_computed_features(&mut self) {
+        let key = self.computed_features_fingerprint();
+        if self.computed_cache_key == Some(key) {
+            self.computed_cache_stats.hits += 1;
+            return;
+        }
+        self.computed_cache_stats.misses += 1;
+        self.computed_cache_key = Some(key);
         if self.is_protein_sequence() {
             self.restriction_enzyme_sites.clear();
             self.restriction_enzyme_groups.clear();
@@ -709,11 +1104,16 @@ impl DNAsequence {
             self.gc_content = GcContents::default();
             return;
         }
-        self.update_restriction_enyzme_sites();
-        self.update_restriction_enzyme_groups();
-        self.update_open_reading_frames();
-        self.update_methylation_sites();
-        self.update_gc_content();
+        if self.restriction_sites_key != Some(key) {
+            self.update_restriction_enyzme_sites();
+            self.update_restriction_enzyme_groups();
+            self.restriction_sites_key = Some(key);
+        }
+        // One pass over the bases; a mapped region is read once and dropped.
+        let bases = self.seq.bases().into_owned();
+        self.update_open_reading_frames(&bases);
+        self.update_methylation_sites(&bases);
+        self.update_gc_content(&bases);
         // TODO amino acids
         // TODO protease sites
     }
@@ -727,7 +1127,7 @@ impl DNAsequence {
     }
 
     pub(crate) fn clone_seq_record(&self) -> Seq {
-        self.seq.clone()
+        self.seq.record().into_owned()
     }
 
     pub fn name(&self) -> &Option<String> {
@@ -779,6 +1179,18 @@ impl DNAsequence {
         self.seq.definition.as_deref()
     }
 
+    /// Set the free-text sequence description written as the GenBank
+    /// DEFINITION line; blank text clears it.
+    pub fn set_definition<S: Into<String>>(&mut self, definition: S) {
+        let raw = definition.into();
+        let trimmed = raw.trim();
+        self.seq.definition = if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed.to_string())
+        };
+    }
+
     pub fn accession(&self) -> Option<&str> {
         self.seq.accession.as_deref()
     }
@@ -788,13 +1200,226 @@ impl DNAsequence {
     }
 
     pub fn get_forward_string(&self) -> String {
-        String::from_utf8_lossy(self.forward()).into_owned()
+        String::from_utf8_lossy(&self.seq.bases()).into_owned()
+    }
+
+    /// SHA-1 hex digest of the forward-strand bases, canonicalized to uppercase.
+    ///
+    /// Soft-masked and uppercase copies share one fingerprint; topology, name
+    /// and annotation do not contribute.
+    pub fn content_sha1(&self) -> String {
+        let digest = Sha1::digest(self.seq.bases().to_ascii_uppercase());
+        format!("{digest:x}")
     }
 
     pub fn overhang(&self) -> &DNAoverhang {
         &self.overhang
     }
 
+    /// End chemistry derived from the stored overhangs (blunt for circular
+    /// molecules).
+    pub fn ends(&self) -> SequenceEnds {
+        if self.is_circular() {
+            return SequenceEnds::default();
+        }
+        SequenceEnds {
+            left: SequenceEnd {
+                dephosphorylated: self.overhang.forward_5_dephosphorylated,
+                ..SequenceEnd::from_strands(
+                    &self.overhang.forward_5,
+                    &self.overhang.reverse_3,
+                    OverhangPolarity::FivePrime,
+                )
+            },
+            right: SequenceEnd {
+                dephosphorylated: self.overhang.reverse_5_dephosphorylated,
+                ..SequenceEnd::from_strands(
+                    &self.overhang.forward_3,
+                    &self.overhang.reverse_5,
+                    OverhangPolarity::ThreePrime,
+                )
+            },
+        }
+    }
+
+    /// Klenow-style fill-in: recessed 3' ends are extended across 5'
+    /// overhangs, so those ends become blunt and the filled bases join the
+    /// forward strand. Other ends are left unchanged.
+    pub fn fill_in_five_prime_overhangs(&self) -> Self {
+        let ends = self.ends();
+        let left_fill = match ends.left.polarity {
+            OverhangPolarity::FivePrime => self.overhang.forward_5.clone(),
+            _ => vec![],
+        };
+        let right_fill: DNAstring = match ends.right.polarity {
+            OverhangPolarity::FivePrime => self
+                .overhang
+                .reverse_5
+                .iter()
+                .map(|c| IupacCode::letter_complement(*c))
+                .collect(),
+            _ => vec![],
+        };
+        let mut ret = self.clone();
+        if !left_fill.is_empty() {
+            let shift = left_fill.len() as i64;
+            ret.seq.features = self
+                .features()
+                .iter()
+                .filter_map(|feature| {
+                    self.seq
+                        .record
+                        .relocate_feature(feature.clone(), shift)
+                        .ok()
+                })
+                .collect();
+            ret.overhang.forward_5.clear();
+        }
+        if !right_fill.is_empty() {
+            ret.overhang.reverse_5.clear();
+        }
+        let mut bases = left_fill;
+        bases.extend_from_slice(&self.seq.bases());
+        bases.extend(right_fill);
+        ret.seq.len = Some(bases.len());
+        ret.seq.set_bases(bases);
+        ret
+    }
+
+    /// T4-style chew-back: 3' overhangs are removed, so those ends become
+    /// blunt. The double-stranded forward sequence is unchanged.
+    pub fn chew_back_three_prime_overhangs(&self) -> Self {
+        let mut ret = self.clone();
+        if !self.is_circular() {
+            ret.overhang.forward_3.clear();
+            ret.overhang.reverse_3.clear();
+        }
+        ret
+    }
+
+    /// CIP/SAP-style dephosphorylation: both 5' termini lose their phosphate,
+    /// so the copy cannot be ligated to another dephosphorylated end. Bases
+    /// and overhangs are unchanged; circular molecules have no ends and are
+    /// returned as is.
+    pub fn dephosphorylate_five_prime_ends(&self) -> Self {
+        let mut ret = self.clone();
+        if !self.is_circular() {
+            ret.overhang.forward_5_dephosphorylated = true;
+            ret.overhang.reverse_5_dephosphorylated = true;
+        }
+        ret
+    }
+
+    /// Maximal runs of lowercase (soft-masked) bases, as 0-based ranges.
+    pub fn soft_masked_ranges(&self) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = vec![];
+        for (pos, base) in self.seq.bases().iter().enumerate() {
+            if !base.is_ascii_lowercase() {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if last.end == pos => last.end = pos + 1,
+                _ => ranges.push(pos..pos + 1),
+            }
+        }
+        ranges
+    }
+
+    /// Upper-case every base, dropping soft-masking.
+    pub fn uppercase_bases(&mut self) {
+        self.seq.bases_mut().make_ascii_uppercase();
+    }
+
+    /// Replaces single forward-strand bases in place, keeping length and
+    /// features. Positions outside the sequence are ignored. Returns the
+    /// edited span, to pass to `recompute_sites_in_range` when the output
+    /// keeps its restriction sites.
+    pub fn substitute_bases(&mut self, edits: &[(usize, u8)]) -> Option<Range<usize>> {
+        let mut edited: Option<Range<usize>> = None;
+        for (pos, base) in edits {
+            if let Some(slot) = self.seq.bases_mut().get_mut(*pos) {
+                *slot = base.to_ascii_uppercase();
+                edited = Some(match edited {
+                    Some(span) => span.start.min(*pos)..span.end.max(*pos + 1),
+                    None => *pos..*pos + 1,
+                });
+            }
+        }
+        edited
+    }
+
+    /// Refresh restriction sites after a length-preserving edit of `range`,
+    /// while the sites still describe the sequence as it was before the edit.
+    ///
+    /// Only recognition starts that overlap `range` (i.e. from
+    /// `range.start - (longest recognition length - 1)` up to `range.end`,
+    /// wrapping on circular molecules) are rescanned; every other site is
+    /// kept. `range` is clamped to the sequence. Falls back to a full scan
+    /// when sites were never computed or a site cap is set, since the cap
+    /// applies to whole-sequence counts. The next `update_computed_features`
+    /// reuses the refreshed sites instead of scanning again.
+    pub fn recompute_sites_in_range(&mut self, range: Range<usize>) {
+        let len = self.len();
+        let range = range.start.min(len)..range.end.min(len);
+        if self.restriction_enzymes.is_empty() || range.is_empty() {
+            return;
+        }
+        let context = self
+            .restriction_enzymes
+            .iter()
+            .map(|enzyme| enzyme.sequence.len())
+            .max()
+            .unwrap_or(1)
+            .saturating_sub(1);
+        let window = range.len() + context;
+        if self.restriction_sites_key.is_none()
+            || self.max_restriction_enzyme_sites.is_some()
+            || window >= len
+        {
+            self.update_restriction_enyzme_sites();
+            self.update_restriction_enzyme_groups();
+            self.restriction_sites_key = Some(self.computed_features_fingerprint());
+            return;
+        }
+        let starts: Vec<usize> = if self.is_circular() {
+            (0..window)
+                .map(|step| (range.start + len - context + step) % len)
+                .collect()
+        } else {
+            (range.start.saturating_sub(context)..range.end).collect()
+        };
+        let rescanned = starts
+            .iter()
+            .copied()
+            .collect::<std::collections::HashSet<_>>();
+        let mut sites = std::mem::take(&mut self.restriction_enzyme_sites);
+        sites.retain(
+            |site| !matches!(usize::try_from(site.offset), Ok(offset) if rescanned.contains(&offset)),
+        );
+        for enzyme in &self.restriction_enzymes {
+            sites.extend(enzyme.get_sites_at(self, starts.iter().copied()));
+        }
+        // Match the full scan's order: catalog order, then offset.
+        let enzyme_rank = self
+            .restriction_enzymes
+            .iter()
+            .enumerate()
+            .map(|(rank, enzyme)| (enzyme.name.as_str(), rank))
+            .collect::<HashMap<_, _>>();
+        sites.sort_by_key(|site| {
+            (
+                enzyme_rank
+                    .get(site.enzyme.name.as_str())
+                    .copied()
+                    .unwrap_or(usize::MAX),
+                site.offset,
+            )
+        });
+        self.restriction_enzyme_sites = sites;
+        self.update_restriction_enzyme_groups();
+        self.restriction_sites_key = Some(self.computed_features_fingerprint());
+    }
+
     pub fn assert_sequence_equality(&self, other: &Self) -> Result<(), SequenceEqualityError> {
         let left_biotype = self.molecule_type().map(ToString::to_string);
         let right_biotype = other.molecule_type().map(ToString::to_string);
@@ -916,7 +1541,7 @@ impl DNAsequence {
         let right = pos1.max(pos2) + 1;
 
         // Rotate so that position 0 is now the sequence after the cut
-        let seq = self.seq.set_origin(right as i64);
+        let seq = self.seq.record().set_origin(right as i64);
 
         // Cut off the overhanging part of the sequence, and keep it around
         let new_size = self.len() as i64 - site.enzyme.overlap.abs() as i64;
@@ -931,7 +1556,7 @@ impl DNAsequence {
         seq.topology = Topology::Linear;
 
         let mut ret = Self::from_u8(self.forward());
-        ret.seq = seq;
+        ret.seq = seq.into();
 
         // Add overhangs
         if site.enzyme.overlap > 0 {
@@ -952,18 +1577,22 @@ impl DNAsequence {
         let left = pos1;
         let right = pos1.max(pos2) + 1;
 
-        let overhang = self.seq.seq[pos1 as usize..(pos2 + 1) as usize].to_owned();
+        let overhang = self.seq.read_range(pos1 as usize..(pos2 + 1) as usize);
         let overhang_rc: Vec<u8> = overhang
             .iter()
             .map(|c| IupacCode::letter_complement(*c))
             .collect();
 
         let mut seq1 = Self::from_u8(self.forward());
-        seq1.seq = self.seq.extract_range(0, left as i64);
+        seq1.seq = self.seq.record().extract_range(0, left as i64).into();
         seq1.overhang = self.overhang.clone();
 
         let mut seq2 = Self::from_u8(self.forward());
-        seq2.seq = self.seq.extract_range(right as i64, self.len() as i64);
+        seq2.seq = self
+            .seq
+            .record()
+            .extract_range(right as i64, self.len() as i64)
+            .into();
         seq2.overhang = self.overhang.clone();
 
         // Add overhangs
@@ -1097,6 +1726,7 @@ fn parse_embl_record(lines: &[String]) -> Result<Seq> {
     let mut accession: Option<String> = None;
     let mut version: Option<String> = None;
     let mut definition_lines: Vec<String> = vec![];
+    let mut declared_len: Option<usize> = None;
     let mut sequence_started = false;
     let mut current_feature: Option<PendingEmblFeature> = None;
 
@@ -1124,6 +1754,13 @@ fn parse_embl_record(lines: &[String]) -> Result<Seq> {
             {
                 seq.name = Some(name.to_string());
             }
+            declared_len = id.split(';').find_map(|field| {
+                field
+                    .trim()
+                    .trim_end_matches('.')
+                    .strip_suffix("BP")
+                    .and_then(|count| count.trim().parse::<usize>().ok())
+            });
             let lower = id.to_ascii_lowercase();
             if lower.contains("circular") {
                 seq.topology = Topology::Circular;
@@ -1215,7 +1852,7 @@ fn parse_embl_record(lines: &[String]) -> Result<Seq> {
         (_, Some(raw)) => Some(raw),
         _ => None,
     };
-    seq.len = Some(seq.seq.len());
+    seq.len = Some(declared_len.unwrap_or(seq.seq.len()));
     if seq.seq.is_empty() {
         return Err(anyhow::anyhow!(
             "Could not parse EMBL record '{}': missing sequence data",
@@ -1225,6 +1862,107 @@ fn parse_embl_record(lines: &[String]) -> Result<Seq> {
     Ok(seq)
 }
 
+/// Rewrite shapes that GenBank text cannot round-trip: nested `join`s are
+/// flattened, single-part `join`s unwrapped, and double complements cancelled.
+fn normalize_genbank_location(location: gb_io::seq::Location) -> gb_io::seq::Location {
+    use gb_io::seq::Location;
+    match location {
+        Location::Complement(inner) => match normalize_genbank_location(*inner) {
+            Location::Complement(twice) => *twice,
+            inner => Location::Complement(Box::new(inner)),
+        },
+        Location::Join(parts) => {
+            let mut flat = vec![];
+            for part in parts {
+                match normalize_genbank_location(part) {
+                    Location::Join(nested) => flat.extend(nested),
+                    part => flat.push(part),
+                }
+            }
+            if flat.len() == 1 {
+                flat.remove(0)
+            } else {
+                Location::Join(flat)
+            }
+        }
+        other => other,
+    }
+}
+
+/// Differences between `original` and the record read back from its GenBank
+/// text: sequence (case-insensitive, as the writer lowercases bases),
+/// topology, and per feature the kind, location, and ordered qualifier list
+/// including repeated keys and valueless flags. Locations are compared after
+/// the writer's normalization. Empty when the round trip is lossless.
+pub fn genbank_roundtrip_diff(original: &DNAsequence) -> Vec<String> {
+    let text = match original.to_genbank_string() {
+        Ok(text) => text,
+        Err(e) => return vec![format!("write failed: {e}")],
+    };
+    let reread = match gb_io::reader::SeqReader::new(text.as_bytes()).next() {
+        Some(Ok(seq)) => seq,
+        Some(Err(e)) => return vec![format!("read failed: {e}")],
+        None => return vec!["read failed: no record in written text".to_string()],
+    };
+    let mut diffs = vec![];
+    if !original.seq.bases().eq_ignore_ascii_case(&reread.seq) {
+        diffs.push(format!(
+            "sequence: {} bp written, {} bp read back with different bases",
+            original.len(),
+            reread.seq.len()
+        ));
+    }
+    if original.seq.topology != reread.topology {
+        diffs.push(format!(
+            "topology: {:?} -> {:?}",
+            original.seq.topology, reread.topology
+        ));
+    }
+    if original.seq.features.len() != reread.features.len() {
+        diffs.push(format!(
+            "feature count: {} -> {}",
+            original.seq.features.len(),
+            reread.features.len()
+        ));
+    }
+    for (idx, (before, after)) in original
+        .seq
+        .features
+        .iter()
+        .zip(reread.features.iter())
+        .enumerate()
+    {
+        if before.kind != after.kind {
+            diffs.push(format!(
+                "feature {idx}: kind {} -> {}",
+                before.kind, after.kind
+            ));
+        }
+        let expected = normalize_genbank_location(before.location.clone());
+        if expected != after.location {
+            diffs.push(format!(
+                "feature {idx}: location {} -> {}",
+                expected.to_gb_format(),
+                after.location.to_gb_format()
+            ));
+        }
+        let qualifiers = |feature: &Feature| {
+            feature
+                .qualifiers
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect::<Vec<_>>()
+        };
+        let (before_qualifiers, after_qualifiers) = (qualifiers(before), qualifiers(after));
+        if before_qualifiers != after_qualifiers {
+            diffs.push(format!(
+                "feature {idx}: qualifiers {before_qualifiers:?} -> {after_qualifiers:?}"
+            ));
+        }
+    }
+    diffs
+}
+
 fn canonicalize_location(location: gb_io::seq::Location) -> Result<gb_io::seq::Location> {
     let value = serde_json::to_value(&location)?;
     Ok(serde_json::from_value(value)?)
@@ -1240,7 +1978,7 @@ fn normalize_embl_qualifier_value(raw: &str) -> String {
 
 impl fmt::Display for DNAsequence {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", String::from_utf8_lossy(self.forward()))
+        write!(f, "{}", String::from_utf8_lossy(&self.seq.bases()))
     }
 }
 
@@ -1263,6 +2001,16 @@ mod tests {
         assert_eq!(seq.get_forward_string(), "A\u{FFFD}C");
     }
 
+    #[test]
+    fn test_content_sha1_ignores_case() {
+        let upper = DNAsequence::from_sequence("ACGTACGTNN").unwrap();
+        let mixed = DNAsequence::from_sequence("acgtACGTnn").unwrap();
+        let other = DNAsequence::from_sequence("ACGTACGTNA").unwrap();
+        assert_eq!(upper.content_sha1(), mixed.content_sha1());
+        assert_ne!(upper.content_sha1(), other.content_sha1());
+        assert_eq!(upper.content_sha1().len(), 40);
+    }
+
     #[test]
     fn test_overhang_display_handles_non_utf8_lossy() {
         let overhang = DNAoverhang {
@@ -1270,6 +2018,7 @@ mod tests {
             forward_5: vec![b'A', 0xFF],
             reverse_3: vec![b'G'],
             reverse_5: vec![0xFE, b'C'],
+            ..Default::default()
         };
         let rendered = format!("{overhang}");
         assert!(rendered.contains('\u{FFFD}'));
@@ -1344,6 +2093,88 @@ mod tests {
         assert_eq!(seqs[1].overhang.reverse_3, "".as_bytes());
     }
 
+    #[test]
+    fn test_ecori_digest_fragment_ends_report_five_prime_aatt() {
+        let seq = DNAsequence::from_sequence("TTTGAATTCAAACCCGAATTCGGG").unwrap();
+        let enzymes = Enzymes::default();
+        let fragments =
+            seq.restriction_enzymes_full_digest(enzymes.restriction_enzymes_by_name(&["EcoRI"]));
+        assert_eq!(fragments.len(), 3);
+        let aatt = SequenceEnd {
+            polarity: OverhangPolarity::FivePrime,
+            overhang: b"AATT".to_vec(),
+            ..Default::default()
+        };
+
+        let middle = fragments[1].ends();
+        assert_eq!(fragments[1].get_forward_string(), "CAAACCCG");
+        assert_eq!(middle.left, aatt);
+        assert_eq!(middle.right, aatt);
+        assert!(fragments[0].ends().left.is_blunt());
+        assert_eq!(fragments[0].ends().right, aatt);
+        assert_eq!(fragments[2].ends().left, aatt);
+        assert!(fragments[2].ends().right.is_blunt());
+
+        assert!(
+            fragments[0]
+                .ends()
+                .right
+                .ligates_to(&fragments[1].ends().left)
+        );
+        assert!(middle.right.ligates_to(&middle.left));
+        assert!(
+            !fragments[0]
+                .ends()
+                .right
+                .ligates_to(&fragments[0].ends().left)
+        );
+    }
+
+    #[test]
+    fn test_polish_ends_fill_in_and_chew_back() {
+        let seq = DNAsequence::from_sequence("TTTGAATTCAAACCCGAATTCGGG").unwrap();
+        let enzymes = Enzymes::default();
+        let fragments =
+            seq.restriction_enzymes_full_digest(enzymes.restriction_enzymes_by_name(&["EcoRI"]));
+        let filled = fragments[1].fill_in_five_prime_overhangs();
+        assert_eq!(filled.get_forward_string(), "AATTCAAACCCGAATT");
+        assert_eq!(filled.ends(), SequenceEnds::default());
+        assert_eq!(filled.len(), 16);
+
+        let mut three_prime = DNAsequence::from_sequence("ACGTACGT").unwrap();
+        three_prime.overhang.forward_3 = b"TGCA".to_vec();
+        three_prime.overhang.reverse_3 = b"ACGT".to_vec();
+        assert_eq!(
+            three_prime.ends().right.polarity,
+            OverhangPolarity::ThreePrime
+        );
+        let chewed = three_prime.chew_back_three_prime_overhangs();
+        assert_eq!(chewed.get_forward_string(), "ACGTACGT");
+        assert_eq!(chewed.ends(), SequenceEnds::default());
+        assert_eq!(
+            three_prime.fill_in_five_prime_overhangs().ends(),
+            three_prime.ends()
+        );
+    }
+
+    #[test]
+    fn test_sequence_end_requires_matching_polarity() {
+        let five = SequenceEnd {
+            polarity: OverhangPolarity::FivePrime,
+            overhang: b"TGCA".to_vec(),
+            ..Default::default()
+        };
+        let three = SequenceEnd {
+            polarity: OverhangPolarity::ThreePrime,
+            overhang: b"TGCA".to_vec(),
+            ..Default::default()
+        };
+        assert!(five.ligates_to(&five));
+        assert!(three.ligates_to(&three));
+        assert!(!five.ligates_to(&three));
+        assert!(SequenceEnd::default().ligates_to(&SequenceEnd::default()));
+    }
+
     #[test]
     fn test_restriction_enzymes_full_digest() {
         let seq = load_from_file("test_files/pGEX-3X.gb").unwrap();
@@ -1386,6 +2217,64 @@ mod tests {
         assert_eq!(names, &vec!["EcoRI".to_string()]);
     }
 
+    #[test]
+    fn test_recompute_sites_in_range_matches_full_scan_after_point_edits() {
+        let site_keys = |dna: &DNAsequence| {
+            dna.restriction_enzyme_sites()
+                .iter()
+                .map(|site| (site.enzyme.name.clone(), site.offset, site.forward_strand))
+                .collect::<Vec<_>>()
+        };
+        for (circular, bases, edits) in [
+            // Destroy EcoRI, create BamHI (GGATCC) from GGATCA.
+            (
+                false,
+                "AAGAATTCTTTTGGATCATTTTAAGCTTAAA",
+                vec![(4usize, b'C'), (17, b'C')],
+            ),
+            // EcoRI spans the origin; edit its first base across the wrap.
+            (
+                true,
+                "ATTCTTTTGGATCCTTTTTTTTTTTTTTTTTTTTTTTTGA",
+                vec![(39usize, b'T')],
+            ),
+        ] {
+            let mut dna = DNAsequence::from_sequence(bases).unwrap();
+            dna.set_circular(circular);
+            *dna.restriction_enzymes_mut() = Enzymes::default().restriction_enzymes().clone();
+            dna.update_computed_features();
+            let before = site_keys(&dna);
+
+            let span = dna
+                .substitute_bases(&edits)
+                .expect("edits inside the sequence");
+            dna.recompute_sites_in_range(span);
+            let mut full = DNAsequence::from_sequence(&dna.get_forward_string()).unwrap();
+            full.set_circular(circular);
+            *full.restriction_enzymes_mut() = dna.restriction_enzymes().clone();
+            full.update_computed_features();
+
+            assert_ne!(before, site_keys(&full));
+            assert_eq!(site_keys(&dna), site_keys(&full));
+            assert_eq!(
+                dna.restriction_enzyme_groups().len(),
+                full.restriction_enzyme_groups().len()
+            );
+
+            // The refreshed sites are reused instead of rescanned.
+            let scans = dna.computed_feature_cache_stats().full_site_scans;
+            dna.update_computed_features();
+            assert_eq!(dna.computed_feature_cache_stats().full_site_scans, scans);
+            assert_eq!(site_keys(&dna), site_keys(&full));
+
+            // Out-of-range spans are clamped rather than overflowing.
+            let len = dna.len();
+            dna.recompute_sites_in_range(len + 5..len + 9);
+            dna.recompute_sites_in_range(len - 1..len + 9);
+            assert_eq!(site_keys(&dna), site_keys(&full));
+        }
+    }
+
     #[test]
     fn test_pgex_3x_fasta() {
         let seq = DNAsequence::from_fasta_file("test_files/pGEX_3X.fa").unwrap();
@@ -1903,6 +2792,64 @@ SQ   Sequence 40 BP; 10 A; 10 C; 10 G; 10 T; 0 other;\n\
         );
     }
 
+    #[test]
+    fn test_genbank_roundtrip_keeps_reverse_strand_multi_exon_cds() {
+        use gb_io::seq::Location;
+        let mut dna = DNAsequence::from_sequence(&"ATGCGTACGTTAGCCATGGA".repeat(8)).unwrap();
+        dna.features_mut().push(Feature {
+            kind: "CDS".into(),
+            location: Location::Complement(Box::new(Location::Join(vec![
+                Location::simple_range(9, 30),
+                Location::simple_range(49, 70),
+                Location::simple_range(89, 120),
+            ]))),
+            qualifiers: vec![
+                ("gene".into(), Some("rev_gene".to_string())),
+                ("note".into(), Some("exon set A".to_string())),
+                ("note".into(), Some("exon set B".to_string())),
+                ("pseudo".into(), None),
+            ],
+        });
+        dna.features_mut().push(Feature {
+            kind: "misc_feature".into(),
+            location: Location::Join(vec![
+                Location::Complement(Box::new(Location::simple_range(139, 150))),
+                Location::Complement(Box::new(Location::simple_range(124, 130))),
+            ]),
+            qualifiers: vec![("label".into(), Some("split_rev".to_string()))],
+        });
+        assert_eq!(genbank_roundtrip_diff(&dna), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_genbank_write_flattens_nested_join_and_double_complement() {
+        use gb_io::seq::Location;
+        let mut dna = DNAsequence::from_sequence(&"ACGT".repeat(20)).unwrap();
+        dna.features_mut().push(Feature {
+            kind: "misc_feature".into(),
+            location: Location::Complement(Box::new(Location::Complement(Box::new(
+                Location::Join(vec![
+                    Location::Join(vec![
+                        Location::simple_range(0, 5),
+                        Location::simple_range(10, 15),
+                    ]),
+                    Location::simple_range(20, 25),
+                ]),
+            )))),
+            qualifiers: vec![],
+        });
+        dna.features_mut().push(Feature {
+            kind: "misc_feature".into(),
+            location: Location::Join(vec![Location::simple_range(30, 40)]),
+            qualifiers: vec![],
+        });
+        assert_eq!(genbank_roundtrip_diff(&dna), Vec::<String>::new());
+        let text = dna.to_genbank_string().unwrap();
+        assert!(text.contains("join(1..5,11..15,21..25)"), "{text}");
+        assert!(text.contains("31..40"), "{text}");
+        assert!(!text.contains("complement"), "{text}");
+    }
+
     #[test]
     fn test_fasta_header_sets_ssdna_molecule_type() {
         let record = fasta::Record::with_attrs("oligo_ss", Some("molecule=ssdna"), b"ATGCATGC");
@@ -2117,4 +3064,87 @@ SQ   Sequence 40 BP; 10 A; 10 C; 10 G; 10 T; 0 other;\n\
             .expect("gene should be preserved");
         assert_eq!(gene.location.find_bounds().expect("gene bounds"), (0, 1));
     }
+
+    #[cfg(feature = "mmap-backing")]
+    fn mapped_fixture(
+        dir: &Path,
+        region: Range<usize>,
+    ) -> (std::path::PathBuf, DNAsequence, DNAsequence) {
+        use crate::mapped_sequence::{FastaRecordLayout, MappedFasta};
+        let bases = (0..1_250)
+            .map(|i| b"ATGAAACCCGGGtttTAAgatcCG"[(i * 5 + i / 7) % 24])
+            .collect::<Vec<_>>();
+        let path = dir.join("chrM.fa");
+        let mut text = ">chrM\n".to_string();
+        for line in bases.chunks(70) {
+            text.push_str(std::str::from_utf8(line).unwrap());
+            text.push('\n');
+        }
+        fs::write(&path, text).unwrap();
+        let layout = FastaRecordLayout {
+            length: bases.len() as u64,
+            offset: 6,
+            line_bases: 70,
+            line_bytes: 71,
+        };
+        let owned = DNAsequence::from_u8(&bases[region.clone()].to_ascii_uppercase());
+        let mapped = MappedFasta::open(&path, "chrM", layout, region).unwrap();
+        (path, DNAsequence::from_mapped_fasta(mapped), owned)
+    }
+
+    #[cfg(feature = "mmap-backing")]
+    #[test]
+    fn test_mapped_sequence_reads_like_owned_copy() {
+        let td = tempfile::tempdir().unwrap();
+        let (_, mapped, owned) = mapped_fixture(td.path(), 33..1_190);
+        assert!(mapped.is_mapped());
+        assert_eq!(mapped.len(), owned.len());
+        for range in [0..1, 10..95, 68..212, 1_100..1_157] {
+            assert_eq!(
+                mapped.get_range_safe(range.clone()),
+                owned.get_range_safe(range)
+            );
+        }
+        assert_eq!(mapped.get_range_safe(1_150..1_158), None);
+        assert_eq!(mapped.get_base_safe(1_156), owned.get_base_safe(1_156));
+        assert_eq!(mapped.get_base_safe(1_157), None);
+        assert_eq!(mapped.get_forward_string(), owned.get_forward_string());
+        assert_eq!(
+            serde_json::to_value(&mapped).unwrap(),
+            serde_json::to_value(&owned).unwrap()
+        );
+        let region = mapped.extract_region_preserving_features(100, 200).unwrap();
+        assert!(!region.is_mapped());
+        assert_eq!(
+            region.get_forward_string(),
+            owned.get_forward_string()[100..200]
+        );
+    }
+
+    #[cfg(feature = "mmap-backing")]
+    #[test]
+    fn test_mapped_sequence_computes_features_without_caching_bases() {
+        let td = tempfile::tempdir().unwrap();
+        let (_, mut mapped, mut owned) = mapped_fixture(td.path(), 0..1_250);
+        mapped.update_computed_features();
+        owned.update_computed_features();
+        assert!(mapped.seq.mapped_bases.get().is_none());
+        assert_eq!(mapped.open_reading_frames(), owned.open_reading_frames());
+        assert_eq!(mapped.gc_content().regions(), owned.gc_content().regions());
+        mapped.update_computed_features();
+        assert_eq!(mapped.computed_feature_cache_stats().hits, 1);
+    }
+
+    #[cfg(feature = "mmap-backing")]
+    #[test]
+    fn test_mapped_sequence_copies_bases_on_first_edit() {
+        let td = tempfile::tempdir().unwrap();
+        let (path, mut mapped, owned) = mapped_fixture(td.path(), 70..140);
+        let before = fs::read(&path).unwrap();
+        assert_eq!(mapped.substitute_bases(&[(0, b'c')]), Some(0..1));
+        assert!(!mapped.is_mapped());
+        assert_eq!(mapped.get_base_safe(0), Some(b'C'));
+        assert_eq!(mapped.get_range_safe(1..70), owned.get_range_safe(1..70));
+        assert_eq!(fs::read(&path).unwrap(), before);
+    }
 }
@@ -24,8 +24,8 @@
 
 use crate::{
     DNA_LADDERS, RNA_LADDERS,
-    amino_acids::{STOP_CODON, UNKNOWN_CODON},
-    dna_sequence::DNAsequence,
+    amino_acids::{STOP_CODON, UNKNOWN_CODON, codon_table},
+    dna_sequence::{DNAsequence, OverhangPolarity, SequenceEnd},
     ensembl_protein::EnsemblProteinEntry,
     enzymes::{
         active_proteases, active_restriction_enzymes, default_preferred_restriction_enzyme_names,
@@ -33,12 +33,12 @@ use crate::{
     feature_location::{collect_location_ranges_usize, feature_is_reverse},
     genomes::{
         BlastExternalBinaryPreflightReport, DEFAULT_HELPER_CATALOG_DISCOVERY_TOKEN,
-        DEFAULT_REFERENCE_CATALOG_DISCOVERY_TOKEN, EnsemblCatalogUpdatePreview,
-        EnsemblCatalogUpdateReport, EnsemblInstallableGenomeCatalog,
+        DEFAULT_REFERENCE_CATALOG_DISCOVERY_TOKEN, DownloadRetryPolicy,
+        EnsemblCatalogUpdatePreview, EnsemblCatalogUpdateReport, EnsemblInstallableGenomeCatalog,
         EnsemblQuickInstallCatalogWriteReport, EnsemblQuickInstallPreview,
         EnsemblQuickInstallReport, GenomeBlastReport, GenomeCatalog,
-        GenomeCatalogEntryRemovalReport, GenomeCatalogListEntry, GenomeGeneRecord,
-        GenomeSourcePlan, GenomeTranscriptRecord, HelperConstructInterpretation,
+        GenomeCatalogEntryRemovalReport, GenomeCatalogListEntry, GenomeCatalogReport,
+        GenomeGeneRecord, GenomeSourcePlan, GenomeTranscriptRecord, HelperConstructInterpretation,
         HelperConstructVocabularyDoctorReport, HelperConstructVocabularyTerm,
         HelperVectorCardReport, HelperVectorCatalogDoctorReport, PrepareGenomeActivityStatus,
         PrepareGenomePlan, PrepareGenomeProgress, PrepareGenomeReport, PreparedCacheCleanupReport,
@@ -56,10 +56,14 @@ use crate::{
     pool_gel::{GelSampleInput, export_pool_gel_svg},
     protease::{Protease, normalize_protease_name_token},
     protocol_cartoon::ProtocolCartoonTemplateBindings,
-    render_export::{export_circular_svg, export_linear_svg},
+    render_export::{MapLegendEntry, export_circular_svg, export_linear_svg, map_legend_entries},
     render_feature_expert::render_feature_expert_svg,
     restriction_enzyme::{RestrictionEnzyme, RestrictionEnzymeKey},
-    rna_structure::{self, RnaStructureError, RnaStructureSvgReport, RnaStructureTextReport},
+    rna_structure::{
+        self, RnaStructureConstraints, RnaStructureError, RnaStructureSvgReport,
+        RnaStructureTextReport,
+    },
+    seeded_rng::{DEFAULT_RANDOM_SEED, SeededRng},
     tf_motifs,
     uniprot::{
         UniprotAaGenomicSegment, UniprotEntry, UniprotEntrySummary, UniprotGenomeProjection,
@@ -624,6 +628,8 @@ const SEQUENCING_CONFIRMATION_REPORTS_SCHEMA: &str = "gentle.sequencing_confirma
 pub const SEQUENCING_CONFIRMATION_REPORT_SCHEMA: &str = "gentle.sequencing_confirmation_report.v1";
 pub const GENE_SET_ARTIFACTS_METADATA_KEY: &str = "gene_set_artifacts";
 const GENE_SET_ARTIFACTS_SCHEMA: &str = "gentle.gene_set_artifacts.v1";
+pub const USER_DNA_LADDERS_METADATA_KEY: &str = "user_dna_ladders";
+const USER_DNA_LADDERS_SCHEMA: &str = "gentle.user_dna_ladders.v1";
 pub const SEQUENCING_PRIMER_OVERLAY_REPORT_SCHEMA: &str =
     "gentle.sequencing_primer_overlay_report.v1";
 pub const SEQUENCING_CONFIRMATION_SUPPORT_TSV_SCHEMA: &str =
@@ -655,6 +661,10 @@ const WORKFLOW_MACRO_TEMPLATES_SCHEMA: &str = "gentle.workflow_macro_templates.v
 pub const CLONING_MACRO_TEMPLATE_SCHEMA: &str = "gentle.cloning_macro_template.v1";
 pub const CANDIDATE_MACRO_TEMPLATES_METADATA_KEY: &str = "candidate_macro_templates";
 const CANDIDATE_MACRO_TEMPLATES_SCHEMA: &str = "gentle.candidate_macro_templates.v1";
+pub const ANNOTATION_PROFILES_METADATA_KEY: &str = "annotation_profiles";
+const ANNOTATION_PROFILES_SCHEMA: &str = "gentle.annotation_profiles.v1";
+pub const SEQUENCE_TAGS_METADATA_KEY: &str = "sequence_tags";
+const SEQUENCE_TAGS_SCHEMA: &str = "gentle.sequence_tags.v1";
 const GENOME_BED_TRACK_GENERATED_TAG: &str = "genome_bed_track";
 const GENOME_BIGWIG_TRACK_GENERATED_TAG: &str = "genome_bigwig_track";
 const GENOME_VCF_TRACK_GENERATED_TAG: &str = "genome_vcf_track";
@@ -721,6 +731,7 @@ const DOTPLOT_ANALYSIS_SCHEMA: &str = "gentle.dotplot_analysis_store.v1";
 const DOTPLOT_VIEW_SCHEMA: &str = "gentle.dotplot_view.v3";
 const FLEXIBILITY_TRACK_SCHEMA: &str = "gentle.flexibility_track.v1";
 const SEQUENCE_ALIGNMENT_REPORT_SCHEMA: &str = "gentle.sequence_alignment_report.v1";
+const LIGATION_ASSEMBLY_SCHEMA: &str = "gentle.ligation_assembly.v1";
 pub const RNA_READ_REPORTS_METADATA_KEY: &str = "rna_read_reports";
 pub const CUTRUN_READ_REPORTS_METADATA_KEY: &str = "cutrun_read_reports";
 const RNA_READ_REPORTS_SCHEMA: &str = "gentle.rna_read_reports.v1";
@@ -921,28 +932,74 @@ struct ResolvedGenomePromoterSlice {
 // contracts stay in this file; heavy helpers and operation families live in the
 // corresponding `src/engine/*` module so future edits can land in one focused
 // area without changing adapter-visible APIs.
+#[path = "engine/analysis/ambiguity.rs"]
+mod ambiguity;
+#[path = "engine/ops/annotation_profiles.rs"]
+mod annotation_profiles;
+#[path = "engine/io/candidate_export.rs"]
+mod candidate_export;
 #[path = "engine/ops/candidate_guides.rs"]
 mod candidate_guides;
+#[path = "engine/io/candidate_import.rs"]
+mod candidate_import;
 #[path = "engine/analysis/candidate_metrics.rs"]
 mod candidate_metrics;
+#[path = "engine/analysis/cds_integrity.rs"]
+mod cds_integrity;
+#[path = "engine/analysis/consensus.rs"]
+mod consensus;
+#[path = "engine/analysis/cpg_islands.rs"]
+mod cpg_islands;
 #[path = "engine/cutrun.rs"]
 mod cutrun;
+#[path = "engine/io/design_package.rs"]
+mod design_package;
+#[path = "engine/ops/difference_annotation.rs"]
+mod difference_annotation;
+#[path = "engine/io/export_all.rs"]
+mod export_all;
 #[path = "engine/state/feature_coordinate_formulas.rs"]
 mod feature_coordinate_formulas;
+#[path = "engine/ops/feature_edits.rs"]
+mod feature_edits;
 #[path = "engine/analysis/feature_expert_ops.rs"]
 mod feature_expert_ops;
+#[path = "engine/ops/feature_library.rs"]
+mod feature_library;
+#[path = "engine/analysis/feature_overlaps.rs"]
+mod feature_overlaps;
+#[path = "engine/ops/feature_transfer.rs"]
+mod feature_transfer;
 #[path = "engine/analysis/gene_sets.rs"]
 mod gene_sets;
+#[path = "engine/state/genome_anchor_relocation.rs"]
+mod genome_anchor_relocation;
+#[path = "engine/ops/genome_gene_batch.rs"]
+mod genome_gene_batch;
 #[path = "engine/io/genome_tracks.rs"]
 mod genome_tracks;
+#[path = "engine/analysis/helper_screen.rs"]
+mod helper_screen;
 #[path = "engine/io/import_anchors.rs"]
 mod import_anchors;
+#[path = "engine/io/import_directory.rs"]
+mod import_directory;
+#[path = "engine/analysis/inverted_repeats.rs"]
+mod inverted_repeats;
 #[path = "engine/analysis/jaspar.rs"]
 mod jaspar;
 #[path = "engine/state/lab_assistant_export.rs"]
 mod lab_assistant_export;
+#[path = "engine/analysis/ligation_compatibility.rs"]
+mod ligation_compatibility;
 #[path = "engine/state/lineage_containers.rs"]
 mod lineage_containers;
+#[path = "engine/state/lineage_queries.rs"]
+mod lineage_queries;
+#[path = "engine/io/map_legend.rs"]
+mod map_legend;
+#[path = "engine/analysis/methylation_features.rs"]
+mod methylation_features;
 #[path = "engine/io/microarray_tracks.rs"]
 mod microarray_tracks;
 #[path = "engine/analysis/motif_statistics.rs"]
@@ -958,26 +1015,65 @@ mod probe_region_evidence_svg;
 mod probe_region_glen_adapter;
 #[path = "engine/io/probe_regions.rs"]
 mod probe_regions;
+#[path = "engine/state/project_diff.rs"]
+mod project_diff;
 #[path = "engine/analysis/promoter_design.rs"]
 mod promoter_design;
 #[path = "engine/analysis/protein_handoff.rs"]
 mod protein_handoff;
+#[path = "engine/analysis/proteome_export.rs"]
+mod proteome_export;
+#[path = "engine/state/provenance_report.rs"]
+mod provenance_report;
 #[path = "engine/io/read_acquisition.rs"]
 mod read_acquisition;
 #[path = "engine/analysis/repeat_cohort.rs"]
 mod repeat_cohort;
+#[cfg(any(test, feature = "parity-check"))]
+#[path = "engine/state/replay_parity.rs"]
+mod replay_parity;
 #[path = "engine/ops/reporter_ops.rs"]
 mod reporter_ops;
+#[path = "engine/io/restriction_map.rs"]
+mod restriction_map;
 #[path = "engine/analysis/rna_reads.rs"]
 mod rna_reads;
+#[path = "engine/analysis/sequence_distance.rs"]
+mod sequence_distance;
+#[path = "engine/state/sequence_lifecycle.rs"]
+mod sequence_lifecycle;
 #[path = "engine/state/sequence_ops.rs"]
 mod sequence_ops;
+#[path = "engine/analysis/sequence_stats.rs"]
+mod sequence_stats;
+#[path = "engine/state/sequence_tags.rs"]
+mod sequence_tags;
 #[path = "engine/analysis/sequencing_confirmation.rs"]
 mod sequencing_confirmation;
 #[path = "engine/io/sequencing_traces.rs"]
 mod sequencing_traces;
+#[path = "engine/state/session.rs"]
+mod session;
+#[path = "engine/ops/silent_site.rs"]
+mod silent_site;
+#[path = "engine/analysis/soft_masking.rs"]
+mod soft_masking;
+#[path = "engine/ops/subsequence_replace.rs"]
+mod subsequence_replace;
+#[path = "engine/analysis/subsequence_search.rs"]
+mod subsequence_search;
+#[path = "engine/analysis/tandem_repeats.rs"]
+mod tandem_repeats;
+#[path = "engine/io/thumbnails.rs"]
+mod thumbnails;
+#[path = "engine/io/track_csv.rs"]
+mod track_csv;
+#[path = "engine/state/user_dna_ladders.rs"]
+mod user_dna_ladders;
 #[path = "engine/analysis/variant_promoter.rs"]
 mod variant_promoter;
+#[path = "engine/ops/viewport_navigation.rs"]
+mod viewport_navigation;
 #[path = "engine/io/zip_store.rs"]
 mod zip_store;
 
@@ -1467,6 +1563,17 @@ pub struct EngineParameters {
     pub genome_anchor_prepared_fallback_policy: GenomeAnchorPreparedFallbackPolicy,
     pub primer_design_backend: PrimerDesignBackend,
     pub primer3_executable: String,
+    /// Seed for every sampling/shuffling path that has no explicit per-call
+    /// seed (see `crate::seeded_rng`).
+    pub random_seed: u64,
+    /// Worker cap for per-candidate metric scoring (`0` = rayon default,
+    /// `1` = serial).
+    pub candidate_scoring_threads: usize,
+    /// Total attempts (incl. the first) for each HTTP(S) source download in
+    /// `PrepareGenome`.
+    pub genome_download_max_attempts: usize,
+    /// Base backoff before the first download retry; doubles per retry.
+    pub genome_download_retry_base_delay_ms: u64,
 }
 
 impl Default for EngineParameters {
@@ -1478,6 +1585,11 @@ impl Default for EngineParameters {
                 GenomeAnchorPreparedFallbackPolicy::SingleCompatible,
             primer_design_backend: PrimerDesignBackend::Auto,
             primer3_executable: "primer3_core".to_string(),
+            random_seed: DEFAULT_RANDOM_SEED,
+            candidate_scoring_threads: 0,
+            genome_download_max_attempts: crate::genomes::DEFAULT_DOWNLOAD_RETRY_ATTEMPTS,
+            genome_download_retry_base_delay_ms:
+                crate::genomes::DEFAULT_DOWNLOAD_RETRY_BASE_DELAY_MS,
         }
     }
 }
@@ -1573,21 +1685,48 @@ impl ProjectState {
         Ok(state)
     }
 
+    /// Rebuild every JSON object with its keys in sorted order, so state held
+    /// in hash maps (metadata, lineage, containers) serializes the same way on
+    /// every run.
+    pub(crate) fn sort_json_object_keys(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let sorted = map
+                    .into_iter()
+                    .map(|(key, value)| (key, Self::sort_json_object_keys(value)))
+                    .collect::<BTreeMap<_, _>>();
+                serde_json::Value::Object(sorted.into_iter().collect())
+            }
+            serde_json::Value::Array(values) => serde_json::Value::Array(
+                values
+                    .into_iter()
+                    .map(Self::sort_json_object_keys)
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
     /// Save project state JSON to disk using atomic replacement semantics.
     ///
     /// Candidate-set sidecar data is staged/committed in lockstep to avoid
-    /// partially-written project metadata.
+    /// partially-written project metadata. Object keys are written in sorted
+    /// order (see `sort_json_object_keys`), so saving an unchanged state is
+    /// byte-stable.
     pub fn save_to_path(&self, path: &str) -> Result<(), EngineError> {
         let mut state_for_disk = self.clone();
         let project_path = Path::new(path);
         let sidecar_tx =
             state_for_disk.prepare_candidate_store_sidecar_transaction(project_path)?;
-        let text = serde_json::to_string_pretty(&state_for_disk).map_err(|e| EngineError {
-            code: ErrorCode::Internal,
-            message: format!("Could not serialize state: {e}"),
+        let text = serde_json::to_value(&state_for_disk)
+            .map(Self::sort_json_object_keys)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .map_err(|e| EngineError {
+                code: ErrorCode::Internal,
+                message: format!("Could not serialize state: {e}"),
 
-            cause_chain: vec![],
-        })?;
+                cause_chain: vec![],
+            })?;
         let committed = Self::commit_candidate_store_transaction(sidecar_tx)?;
         if let Err(write_err) = Self::write_text_file_atomically(project_path, &text) {
             if let Err(rollback_err) = Self::rollback_candidate_store_transaction(committed) {
@@ -2050,24 +2189,7 @@ impl ProjectState {
             })?;
             let mut writer = BufWriter::new(records_file);
             for candidate in &set.candidates {
-                serde_json::to_writer(&mut writer, candidate).map_err(|e| EngineError {
-                    code: ErrorCode::Internal,
-                    message: format!(
-                        "Could not serialize candidate record for set '{}': {e}",
-                        set_name
-                    ),
-
-                    cause_chain: vec![],
-                })?;
-                writer.write_all(b"\n").map_err(|e| EngineError {
-                    code: ErrorCode::Io,
-                    message: format!(
-                        "Could not write candidate record for set '{}': {e}",
-                        set_name
-                    ),
-
-                    cause_chain: vec![],
-                })?;
+                write_candidate_record_jsonl_line(&mut writer, candidate, set_name)?;
             }
             writer.flush().map_err(|e| EngineError {
                 code: ErrorCode::Io,
@@ -2415,6 +2537,55 @@ pub struct CandidateRecord {
     pub metrics: HashMap<String, f64>,
 }
 
+/// On-disk JSONL line for one `CandidateRecord`: same fields, with metrics
+/// in key order so record files are byte-stable across runs.
+#[derive(Serialize)]
+struct CandidateRecordJsonlLine<'a> {
+    seq_id: &'a str,
+    start_0based: usize,
+    end_0based: usize,
+    sequence: &'a str,
+    metrics: BTreeMap<&'a str, f64>,
+}
+
+/// Append one candidate as a JSONL line; shared by the project sidecar writer
+/// and `StreamCandidateSetJsonl`.
+fn write_candidate_record_jsonl_line(
+    writer: &mut impl Write,
+    candidate: &CandidateRecord,
+    set_name: &str,
+) -> Result<(), EngineError> {
+    let line = CandidateRecordJsonlLine {
+        seq_id: &candidate.seq_id,
+        start_0based: candidate.start_0based,
+        end_0based: candidate.end_0based,
+        sequence: &candidate.sequence,
+        metrics: candidate
+            .metrics
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+            .collect(),
+    };
+    serde_json::to_writer(&mut *writer, &line).map_err(|e| EngineError {
+        code: ErrorCode::Internal,
+        message: format!(
+            "Could not serialize candidate record for set '{}': {e}",
+            set_name
+        ),
+
+        cause_chain: vec![],
+    })?;
+    writer.write_all(b"\n").map_err(|e| EngineError {
+        code: ErrorCode::Io,
+        message: format!(
+            "Could not write candidate record for set '{}': {e}",
+            set_name
+        ),
+
+        cause_chain: vec![],
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// Lightweight listing row for one candidate set without materializing records.
 pub struct CandidateSetSummary {
@@ -2806,6 +2977,22 @@ struct CandidateMacroTemplateStore {
     templates: HashMap<String, CandidateMacroTemplate>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+struct AnnotationProfileStore {
+    schema: String,
+    updated_at_unix_ms: u128,
+    profiles: HashMap<String, AnnotationProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+struct SequenceTagStore {
+    schema: String,
+    updated_at_unix_ms: u128,
+    tags: BTreeMap<SeqId, BTreeSet<String>>,
+}
+
 impl BlastThresholdOptions {
     fn merge_from(&mut self, other: &Self) {
         if other.max_evalue.is_some() {
@@ -2851,6 +3038,18 @@ pub enum Operation {
     LoadFile {
         path: String,
         as_id: Option<SeqId>,
+        /// Skip the import when an existing sequence has the same content fingerprint.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        skip_duplicates: Option<bool>,
+        /// `true` upper-cases every base. Unset or `false` keeps the file's
+        /// case and reports lowercase (soft-masked) runs.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        uppercase: Option<bool>,
+    },
+    ImportDirectory {
+        directory: String,
+        recursive: bool,
+        container_name: Option<String>,
     },
     CreateSequenceFromText {
         sequence_text: String,
@@ -2864,11 +3063,38 @@ pub enum Operation {
         seq_id: SeqId,
         path: String,
         format: ExportFormat,
+        /// LOCUS/header overrides; only used for `GenBank`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        genbank_options: Option<GenBankExportOptions>,
+    },
+    ExportAll {
+        directory: String,
+        format: ExportFormat,
+        /// File stem with `{id}`/`{name}` placeholders; defaults to `{id}`.
+        filename_template: Option<String>,
+    },
+    /// Bundle GenBank files, candidate/guide CSVs, map SVGs, and the
+    /// provenance report into one zip for handoff.
+    ExportDesignPackage {
+        path: String,
+        #[serde(default)]
+        include: DesignPackageContents,
     },
     RenderSequenceSvg {
         seq_id: SeqId,
         mode: RenderSvgMode,
         path: String,
+        /// Linear `(start_bp, span_bp)` to render; `None` uses the shared
+        /// `linear_view_*` display settings.
+        #[serde(default)]
+        viewport: Option<(usize, usize)>,
+    },
+    /// One whole-sequence map SVG per project sequence in `directory`, named
+    /// `<sanitized seq_id>.svg` and scaled to at most `max_width_px` wide.
+    RenderThumbnails {
+        directory: String,
+        mode: RenderSvgMode,
+        max_width_px: usize,
     },
     RenderDotplotSvg {
         seq_id: SeqId,
@@ -2912,6 +3138,39 @@ pub enum Operation {
         target: FeatureExpertTarget,
         path: String,
     },
+    ExportRestrictionMap {
+        seq_id: SeqId,
+        path: String,
+        format: RestrictionMapFormat,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        enzyme_subset: Option<Vec<String>>,
+        #[serde(default)]
+        unique_cutters_only: bool,
+    },
+    ExportTrackCsv {
+        seq_id: SeqId,
+        track: TrackKind,
+        window_bp: usize,
+        step_bp: usize,
+        path: String,
+    },
+    ExportMapLegend {
+        seq_id: SeqId,
+        path: String,
+        mode: RenderSvgMode,
+        #[serde(default)]
+        format: LegendFormat,
+    },
+    ExportSequenceStats {
+        seq_id: SeqId,
+        path: String,
+    },
+    ExportProteome {
+        seq_id: SeqId,
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        translation_table: Option<usize>,
+    },
     RenderIsoformArchitectureSvg {
         seq_id: SeqId,
         panel_id: String,
@@ -2923,6 +3182,17 @@ pub enum Operation {
         seq_id: SeqId,
         path: String,
     },
+    RenderRnaStructureFromDotBracket {
+        seq_id: SeqId,
+        dot_bracket: String,
+        path: String,
+    },
+    RenderRnaStructureSvgConstrained {
+        seq_id: SeqId,
+        path: String,
+        #[serde(default)]
+        constraints: RnaStructureConstraints,
+    },
     RenderLineageSvg {
         path: String,
     },
@@ -2937,6 +3207,13 @@ pub enum Operation {
         #[serde(default)]
         conditions: Option<GelRunConditions>,
     },
+    RenderPlateLayoutSvg {
+        arrangement_id: String,
+        path: String,
+        /// Plate format, 96 (default) or 384 wells.
+        #[serde(default)]
+        plate_wells: Option<usize>,
+    },
     RenderProteinGelSvg {
         report_id: String,
         path: String,
@@ -2998,6 +3275,10 @@ pub enum Operation {
         name: Option<String>,
         #[serde(default)]
         ladders: Option<Vec<String>>,
+        /// Technical replicates per lane container (default 1); each
+        /// container occupies this many consecutive lanes.
+        #[serde(default)]
+        replicate_count: Option<usize>,
     },
     SetArrangementLadders {
         arrangement_id: String,
@@ -3108,6 +3389,12 @@ pub enum Operation {
         #[serde(default)]
         template: RackPhysicalTemplateKind,
     },
+    DefineDnaLadder {
+        name: String,
+        bands: Vec<DnaLadderBandInfo>,
+        #[serde(default)]
+        loading_hint: Option<f64>,
+    },
     ExportDnaLadders {
         path: String,
         #[serde(default)]
@@ -3167,6 +3454,8 @@ pub enum Operation {
         genome_id: String,
         gene_query: String,
         occurrence: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        match_mode: Option<GeneMatchMode>,
         output_id: Option<SeqId>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         extract_mode: Option<GenomeGeneExtractMode>,
@@ -3182,6 +3471,16 @@ pub enum Operation {
         catalog_path: Option<String>,
         cache_dir: Option<String>,
     },
+    ExtractGenomeGenes {
+        genome_id: String,
+        gene_queries: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        match_mode: Option<GeneMatchMode>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        output_prefix: Option<String>,
+        catalog_path: Option<String>,
+        cache_dir: Option<String>,
+    },
     ExtractGenomePromoterSlice {
         genome_id: String,
         gene_query: String,
@@ -3220,6 +3519,13 @@ pub enum Operation {
         #[serde(default)]
         prepared_genome_id: Option<String>,
     },
+    RelocateGenomeAnchor {
+        seq_id: SeqId,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        new_catalog_path: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        new_cache_dir: Option<String>,
+    },
     ImportGenomeBedTrack {
         seq_id: SeqId,
         path: String,
@@ -3750,6 +4056,12 @@ pub enum Operation {
         hits: Vec<BlastHitFeatureInput>,
         track_name: Option<String>,
         clear_existing: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_identity_percent: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_evalue: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_bit_score: Option<f64>,
         #[serde(default)]
         blast_provenance: Option<BlastInvocationProvenance>,
     },
@@ -3778,6 +4090,19 @@ pub enum Operation {
         unique: bool,
         output_prefix: Option<String>,
     },
+    FilterContainerByGc {
+        container_id: ContainerId,
+        gc_min: f64,
+        gc_max: f64,
+        unique: bool,
+        output_prefix: Option<String>,
+    },
+    ContainerSetOp {
+        op: CandidateSetOperator,
+        left_container: ContainerId,
+        right_container: ContainerId,
+        output_prefix: Option<String>,
+    },
     Digest {
         input: SeqId,
         enzymes: Vec<String>,
@@ -3868,6 +4193,34 @@ pub enum Operation {
         output_prefix: Option<String>,
         unique: Option<bool>,
     },
+    PolishEnds {
+        input: SeqId,
+        mode: PolishMode,
+        output_id: Option<SeqId>,
+    },
+    /// Remove both 5' phosphates (CIP/SAP) in a derived copy, so its ends no
+    /// longer ligate to other dephosphorylated ends.
+    Dephosphorylate {
+        input: SeqId,
+        output_id: Option<SeqId>,
+    },
+    EngineerSilentSite {
+        seq_id: SeqId,
+        feature_id: usize,
+        enzyme: String,
+        mode: SiteEngineerMode,
+        output_id: Option<SeqId>,
+    },
+    /// Replace occurrences of an IUPAC motif with literal bases in a derived
+    /// copy, shifting features when the lengths differ.
+    ReplaceSubsequence {
+        seq_id: SeqId,
+        find: String,
+        replace: String,
+        #[serde(default)]
+        max_replacements: Option<usize>,
+        output_id: Option<SeqId>,
+    },
     MergeContainers {
         inputs: Vec<SeqId>,
         output_prefix: Option<String>,
@@ -3879,6 +4232,20 @@ pub enum Operation {
         output_id: Option<SeqId>,
         unique: Option<bool>,
     },
+    NestedPcr {
+        template: SeqId,
+        outer_forward: String,
+        outer_reverse: String,
+        inner_forward: String,
+        inner_reverse: String,
+        output_id: Option<SeqId>,
+    },
+    MultiplexPcr {
+        template: SeqId,
+        primers: Vec<String>,
+        max_amplicon_bp: usize,
+        output_prefix: Option<String>,
+    },
     PcrAdvanced {
         template: SeqId,
         forward_primer: PcrPrimerSpec,
@@ -4125,6 +4492,8 @@ pub enum Operation {
         output_prefix: Option<String>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         report_id: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        translation_table: Option<usize>,
     },
     ReverseTranslateProteinSequence {
         seq_id: SeqId,
@@ -4273,6 +4642,19 @@ pub enum Operation {
         #[serde(default = "default_pairwise_gap_extend")]
         gap_extend: i32,
     },
+    /// Progressive-alignment consensus of `seq_ids`, stored as a new
+    /// sequence; see `GentleEngine::consensus`.
+    MakeConsensus {
+        seq_ids: Vec<SeqId>,
+        threshold: f64,
+        output_id: Option<SeqId>,
+    },
+    /// Annotate substitutions and indels of `variant` relative to
+    /// `reference` as `variation` features on `variant`.
+    AnnotateDifferences {
+        reference: SeqId,
+        variant: SeqId,
+    },
     ImportSequencingTrace {
         path: String,
         #[serde(default)]
@@ -5080,6 +5462,22 @@ pub enum Operation {
     DeleteCandidateSet {
         set_name: String,
     },
+    ImportCandidateSetBed {
+        set_name: String,
+        path: String,
+        seq_id: SeqId,
+    },
+    ImportCandidateSetCsv {
+        set_name: String,
+        path: String,
+        seq_id: SeqId,
+    },
+    /// Write one candidate set as JSONL (the project sidecar record format),
+    /// one candidate at a time.
+    StreamCandidateSetJsonl {
+        set_name: String,
+        path: String,
+    },
     UpsertGuideSet {
         guide_set_id: String,
         guides: Vec<GuideCandidate>,
@@ -5094,6 +5492,13 @@ pub enum Operation {
         #[serde(default)]
         output_guide_set_id: Option<String>,
     },
+    RankGuides {
+        guide_set_id: String,
+        #[serde(default)]
+        weights: GuideRankWeights,
+        #[serde(default)]
+        output_guide_set_id: Option<String>,
+    },
     GenerateGuideOligos {
         guide_set_id: String,
         template_id: String,
@@ -5120,6 +5525,8 @@ pub enum Operation {
         path: String,
         #[serde(default)]
         include_qc_checklist: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reagents: Option<ReagentPlan>,
     },
     ExportFeaturesBed {
         query: SequenceFeatureQuery,
@@ -5171,6 +5578,21 @@ pub enum Operation {
         metric: String,
         expression: String,
     },
+    ScoreCandidateSetTm {
+        set_name: String,
+        metric: String,
+    },
+    ScoreCandidateSetSelfStructure {
+        set_name: String,
+        metric: String,
+    },
+    ScoreCandidateSetMotifCount {
+        set_name: String,
+        metric: String,
+        motif: String,
+        #[serde(default)]
+        both_strands: bool,
+    },
     ScoreCandidateSetDistance {
         set_name: String,
         metric: String,
@@ -5199,6 +5621,14 @@ pub enum Operation {
         right_set: String,
         output_set: String,
     },
+    GuideSetOp {
+        op: CandidateSetOperator,
+        left_guide_set: String,
+        right_guide_set: String,
+        output_guide_set: String,
+        #[serde(default)]
+        match_by: GuideSetMatchKey,
+    },
     ScoreCandidateSetWeightedObjective {
         set_name: String,
         metric: String,
@@ -5206,6 +5636,10 @@ pub enum Operation {
         objectives: Vec<CandidateWeightedObjectiveTerm>,
         #[serde(default)]
         normalize_metrics: Option<bool>,
+        /// Handling of candidates lacking an objective metric; `None` rejects
+        /// the operation on the first missing value.
+        #[serde(default)]
+        missing_policy: Option<MissingValuePolicy>,
     },
     TopKCandidateSet {
         input_set: String,
@@ -5226,6 +5660,10 @@ pub enum Operation {
         max_candidates: Option<usize>,
         #[serde(default)]
         tie_break: Option<CandidateTieBreakPolicy>,
+        /// Epsilon-dominance tolerance as a fraction of each objective's
+        /// observed range; `None`/`0` keeps the strict frontier.
+        #[serde(default)]
+        epsilon: Option<f64>,
     },
     UpsertWorkflowMacroTemplate {
         name: String,
@@ -5255,6 +5693,14 @@ pub enum Operation {
     DeleteCandidateMacroTemplate {
         name: String,
     },
+    SaveAnnotationProfile {
+        name: String,
+        payload: AnnotationProfilePayload,
+    },
+    ApplyAnnotationProfile {
+        name: String,
+        seq_ids: Vec<SeqId>,
+    },
     Reverse {
         input: SeqId,
         output_id: Option<SeqId>,
@@ -5271,6 +5717,33 @@ pub enum Operation {
         input: SeqId,
         output_id: Option<SeqId>,
     },
+    StripAnnotations {
+        input: SeqId,
+        #[serde(default)]
+        keep_kinds: Vec<String>,
+        output_id: Option<SeqId>,
+    },
+    RenameSequence {
+        from: SeqId,
+        to: SeqId,
+    },
+    /// Set the free-text description (GenBank DEFINITION); blank clears it.
+    SetSequenceDescription {
+        seq_id: SeqId,
+        description: String,
+    },
+    /// Add/remove free-form tags (trimmed, lowercased) on one sequence.
+    TagSequence {
+        seq_id: SeqId,
+        #[serde(default)]
+        add: Vec<String>,
+        #[serde(default)]
+        remove: Vec<String>,
+    },
+    DeleteSequence {
+        seq_id: SeqId,
+        cascade: bool,
+    },
     SetDisplayVisibility {
         target: DisplayTarget,
         visible: bool,
@@ -5279,6 +5752,16 @@ pub enum Operation {
         start_bp: usize,
         span_bp: usize,
     },
+    ZoomViewport {
+        seq_id: SeqId,
+        factor: f64,
+        #[serde(default)]
+        center_bp: Option<usize>,
+    },
+    PanViewport {
+        seq_id: SeqId,
+        delta_bp: i64,
+    },
     SetTopology {
         seq_id: SeqId,
         circular: bool,
@@ -5286,6 +5769,35 @@ pub enum Operation {
     RecomputeFeatures {
         seq_id: SeqId,
     },
+    EditFeatureQualifier {
+        seq_id: SeqId,
+        feature_id: usize,
+        key: String,
+        value: Option<String>,
+    },
+    AddFeature {
+        seq_id: SeqId,
+        kind: String,
+        start_0based: usize,
+        end_0based: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        strand: Option<char>,
+        #[serde(default)]
+        qualifiers: HashMap<String, String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        segments: Option<Vec<SequenceRange0Based>>,
+    },
+    DeleteFeature {
+        seq_id: SeqId,
+        feature_id: usize,
+    },
+    TransferFeatures {
+        from: SeqId,
+        to: SeqId,
+        min_identity: f64,
+        #[serde(default)]
+        flank_bp: usize,
+    },
     SetParameter {
         name: String,
         value: serde_json::Value,
@@ -5301,6 +5813,56 @@ pub enum Operation {
         #[serde(default)]
         max_hits: Option<usize>,
     },
+    AnnotateInvertedRepeats {
+        seq_id: SeqId,
+        min_stem: usize,
+        max_loop: usize,
+        #[serde(default)]
+        max_mismatches: usize,
+        clear_existing: Option<bool>,
+    },
+    AnnotateTandemRepeats {
+        seq_id: SeqId,
+        min_unit: usize,
+        max_unit: usize,
+        min_copies: usize,
+        clear_existing: Option<bool>,
+    },
+    MaskAmbiguousRegions {
+        seq_id: SeqId,
+        clear_existing: Option<bool>,
+    },
+    AnnotateSoftMaskedRegions {
+        seq_id: SeqId,
+        clear_existing: Option<bool>,
+    },
+    AnnotateSearchHits {
+        query: String,
+        #[serde(default)]
+        max_mismatches: usize,
+        #[serde(default)]
+        both_strands: bool,
+        clear_existing: Option<bool>,
+    },
+    AnnotateFromLibrary {
+        seq_id: SeqId,
+        #[serde(default)]
+        library: FeatureLibrary,
+        min_identity: f64,
+        clear_existing: Option<bool>,
+    },
+    AnnotateMethylationSites {
+        seq_id: SeqId,
+        mode: MethylationMode,
+        clear_existing: Option<bool>,
+    },
+    AnnotateCpgIslands {
+        seq_id: SeqId,
+        window_bp: usize,
+        gc_min: f64,
+        obs_exp_min: f64,
+        clear_existing: Option<bool>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -5344,7 +5906,7 @@ struct GenomeSequenceAnchor {
     cache_dir: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct EngineHistoryCheckpoint {
     state: ProjectState,
     journal: Vec<OperationRecord>,
@@ -5388,7 +5950,7 @@ struct GenomeBedTrackImportReport {
     skipped_outside_score_range: usize,
     truncated_at_limit: bool,
     cancelled: bool,
-    warnings: Vec<String>,
+    warnings: Vec<Warning>,
     skipped_wrong_chromosome_examples: Vec<String>,
 }
 
@@ -5490,6 +6052,22 @@ struct FeatureDistanceTarget {
     strand: Option<char>,
 }
 
+/// Filtered feature-distance targets laid out for O(log n) nearest queries.
+///
+/// Built once per sequence and filter set; read-only afterwards, so it can be
+/// shared across scoring threads.
+#[derive(Debug, Clone, Default)]
+struct FeatureDistanceIndex {
+    /// Target `(start, end)` intervals sorted by start.
+    by_start: Vec<(usize, usize)>,
+    /// Running maximum of `end` over `by_start[..=i]`.
+    max_end_prefix: Vec<usize>,
+    /// Target `(start, end)` intervals sorted by end.
+    by_end: Vec<(usize, usize)>,
+    /// Distinct `feature_index` values among the indexed targets.
+    feature_count: usize,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct FeatureLocationSegment {
     start_0based: usize,
@@ -5826,36 +6404,170 @@ impl GentleEngine {
         for edge in &self.state.lineage.edges {
             consider(&edge.op_id);
         }
-        for instance in &self.state.lineage.macro_instances {
-            for op_id in &instance.expanded_op_ids {
-                consider(op_id);
-            }
+        for instance in &self.state.lineage.macro_instances {
+            for op_id in &instance.expanded_op_ids {
+                consider(op_id);
+            }
+        }
+        for container in self.state.container_state.containers.values() {
+            if let Some(op_id) = container.created_by_op.as_deref() {
+                consider(op_id);
+            }
+        }
+        for arrangement in self.state.container_state.arrangements.values() {
+            if let Some(op_id) = arrangement.created_by_op.as_deref() {
+                consider(op_id);
+            }
+        }
+        self.op_counter = max_seen;
+    }
+
+    /// Borrow the canonical mutable-independent project snapshot.
+    pub fn state(&self) -> &ProjectState {
+        &self.state
+    }
+
+    /// Mutably borrow the canonical project snapshot.
+    ///
+    /// Direct mutation is intended for tightly controlled internal call sites;
+    /// adapter code should normally prefer `apply`/`apply_workflow` so lineage,
+    /// journaling, and parity guarantees remain intact.
+    pub fn state_mut(&mut self) -> &mut ProjectState {
+        &mut self.state
+    }
+
+    /// Case-insensitive SHA-1 fingerprint of one sequence's bases.
+    pub fn sequence_fingerprint(&self, seq_id: &str) -> Result<String, EngineError> {
+        self.state
+            .sequences
+            .get(seq_id)
+            .map(DNAsequence::content_sha1)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })
+    }
+
+    /// Worst-case product count of a combinatorial operation, computed
+    /// without executing it.
+    ///
+    /// Digests count recognition sites per input (sites + 1 for linear
+    /// inputs), ligations count ordered end pairs that pass the protocol's
+    /// compatibility check, and merges count inputs. Everything else is
+    /// `OutputEstimate::Unknown`.
+    pub fn estimate_operation_output(&self, op: &Operation) -> Result<OutputEstimate, EngineError> {
+        let max_products = match op {
+            Operation::Digest { input, enzymes, .. } => {
+                self.estimate_digest_products(std::slice::from_ref(input), enzymes)?
+            }
+            Operation::DigestContainer {
+                container_id,
+                enzymes,
+                ..
+            } => self.estimate_digest_products(&self.container_members(container_id)?, enzymes)?,
+            Operation::Ligation {
+                inputs, protocol, ..
+            } => self.estimate_ligation_products(inputs, protocol)?,
+            Operation::LigationContainer {
+                container_id,
+                protocol,
+                ..
+            } => {
+                self.estimate_ligation_products(&self.container_members(container_id)?, protocol)?
+            }
+            Operation::MergeContainers { inputs, .. } => inputs.len(),
+            Operation::MergeContainersById { container_ids, .. } => {
+                let mut total = 0usize;
+                for container_id in container_ids {
+                    total += self.container_members(container_id)?.len();
+                }
+                total
+            }
+            _ => return Ok(OutputEstimate::Unknown),
+        };
+        Ok(OutputEstimate::Bounded {
+            max_products,
+            exceeds_limit: max_products > self.max_fragments_per_container(),
+        })
+    }
+
+    fn estimate_digest_products(
+        &self,
+        inputs: &[SeqId],
+        enzymes: &[String],
+    ) -> Result<usize, EngineError> {
+        let (found, _missing) = self.resolve_enzymes(enzymes)?;
+        let mut total = 0usize;
+        for input in inputs {
+            let dna = self.state.sequences.get(input).ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{input}' not found"),
+                cause_chain: vec![],
+            })?;
+            let sites: usize = found
+                .iter()
+                .map(|enzyme| enzyme.get_sites(dna, None).len())
+                .sum();
+            total += if dna.is_circular() {
+                sites.max(1)
+            } else {
+                sites + 1
+            };
+        }
+        Ok(total)
+    }
+
+    fn estimate_ligation_products(
+        &self,
+        inputs: &[SeqId],
+        protocol: &LigationProtocol,
+    ) -> Result<usize, EngineError> {
+        if inputs.len() < 2 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Ligation requires at least two input sequences".to_string(),
+                cause_chain: vec![],
+            });
         }
-        for container in self.state.container_state.containers.values() {
-            if let Some(op_id) = container.created_by_op.as_deref() {
-                consider(op_id);
-            }
+        let mut dnas = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            dnas.push(self.state.sequences.get(input).ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{input}' not found"),
+                cause_chain: vec![],
+            })?);
         }
-        for arrangement in self.state.container_state.arrangements.values() {
-            if let Some(op_id) = arrangement.created_by_op.as_deref() {
-                consider(op_id);
+        let mut total = 0usize;
+        for (i, left) in dnas.iter().enumerate() {
+            for (j, right) in dnas.iter().enumerate() {
+                if i != j
+                    && Self::ligation_ends_compatible(left, right, protocol)
+                    && left.ends().right.has_junction_phosphate(&right.ends().left)
+                {
+                    total += 1;
+                }
             }
         }
-        self.op_counter = max_seen;
-    }
-
-    /// Borrow the canonical mutable-independent project snapshot.
-    pub fn state(&self) -> &ProjectState {
-        &self.state
+        Ok(total)
     }
 
-    /// Mutably borrow the canonical project snapshot.
-    ///
-    /// Direct mutation is intended for tightly controlled internal call sites;
-    /// adapter code should normally prefer `apply`/`apply_workflow` so lineage,
-    /// journaling, and parity guarantees remain intact.
-    pub fn state_mut(&mut self) -> &mut ProjectState {
-        &mut self.state
+    /// First existing sequence id (sorted) whose content fingerprint matches `dna`.
+    fn find_sequence_with_same_content(&self, dna: &DNAsequence) -> Option<SeqId> {
+        let mut fingerprint: Option<String> = None;
+        let mut seq_ids: Vec<&SeqId> = self
+            .state
+            .sequences
+            .iter()
+            .filter(|(_, existing)| existing.len() == dna.len())
+            .map(|(seq_id, _)| seq_id)
+            .collect();
+        seq_ids.sort_unstable();
+        seq_ids.into_iter().find_map(|seq_id| {
+            let existing = self.state.sequences.get(seq_id)?;
+            let fingerprint = fingerprint.get_or_insert_with(|| dna.content_sha1());
+            (existing.content_sha1() == *fingerprint).then(|| seq_id.clone())
+        })
     }
 
     pub fn list_sequences_with_genome_anchor(&self) -> Vec<String> {
@@ -5967,12 +6679,42 @@ impl GentleEngine {
         if subscriptions.iter().any(|existing| existing == &normalized) {
             return Ok(false);
         }
+        // Same source/path/track name replaces the existing entry, so
+        // options such as `clear_existing` update instead of duplicating.
+        let key = Self::track_subscription_key(&normalized);
+        subscriptions.retain(|existing| Self::track_subscription_key(existing) != key);
         subscriptions.push(normalized);
         Self::sort_track_subscriptions(&mut subscriptions);
         self.write_track_subscriptions_to_metadata(&subscriptions)?;
         Ok(true)
     }
 
+    /// Enable or disable the tracked subscription at `index` without
+    /// removing it; disabled subscriptions are skipped by sync.
+    pub fn set_genome_track_subscription_enabled(
+        &mut self,
+        index: usize,
+        enabled: bool,
+    ) -> Result<GenomeTrackSubscription, EngineError> {
+        let mut subscriptions = self.list_genome_track_subscriptions();
+        let len = subscriptions.len();
+        let Some(subscription) = subscriptions.get_mut(index) else {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Track subscription index {} is out of bounds (len={})",
+                    index, len
+                ),
+
+                cause_chain: vec![],
+            });
+        };
+        subscription.enabled = enabled;
+        let updated = subscription.clone();
+        self.write_track_subscriptions_to_metadata(&subscriptions)?;
+        Ok(updated)
+    }
+
     pub fn remove_genome_track_subscription(
         &mut self,
         index: usize,
@@ -6070,7 +6812,11 @@ impl GentleEngine {
         &mut self,
         only_new_anchors: bool,
     ) -> Result<GenomeTrackSyncReport, EngineError> {
-        let subscriptions = self.list_genome_track_subscriptions();
+        let subscriptions: Vec<GenomeTrackSubscription> = self
+            .list_genome_track_subscriptions()
+            .into_iter()
+            .filter(|subscription| subscription.enabled)
+            .collect();
         let current_anchors = self.list_sequences_with_genome_anchor();
         let current_set: BTreeSet<String> = current_anchors.iter().cloned().collect();
         let target_seq_ids: Vec<String> = if only_new_anchors {
@@ -6128,58 +6874,27 @@ impl GentleEngine {
                 .iter()
                 .map(|name| (*name).to_string())
                 .collect(),
+            supported_queries: gentle_protocol::public_engine_query_names()
+                .iter()
+                .map(|name| (*name).to_string())
+                .collect(),
             supported_export_formats: vec!["GenBank".to_string(), "Fasta".to_string()],
             deterministic_operation_log: true,
             capability_registry: gentle_protocol::capability_registry().to_vec(),
         }
     }
 
-    pub fn inspect_dna_ladders(name_filter: Option<&str>) -> DnaLadderCatalog {
-        let filter = name_filter
-            .map(str::trim)
-            .filter(|v| !v.is_empty())
-            .map(|v| v.to_ascii_lowercase());
-
-        let mut ladders: Vec<DnaLadderInfo> = vec![];
-        for name in DNA_LADDERS.names_sorted() {
-            if let Some(filter_text) = &filter
-                && !name.to_ascii_lowercase().contains(filter_text)
-            {
-                continue;
-            }
-            let Some(ladder) = DNA_LADDERS.get(&name) else {
-                continue;
-            };
-            let bands = ladder
-                .bands()
-                .iter()
-                .map(|band| DnaLadderBandInfo {
-                    length_bp: band.length_bp(),
-                    relative_strength: band.relative_strength,
-                })
-                .collect::<Vec<_>>();
-            ladders.push(DnaLadderInfo {
-                name,
-                loading_hint: ladder.loading_hint(),
-                min_bp: ladder.min_bp(),
-                max_bp: ladder.max_bp(),
-                band_count: bands.len(),
-                bands,
-            });
-        }
-
-        DnaLadderCatalog {
-            schema: "gentle.dna_ladders.v1".to_string(),
-            ladder_count: ladders.len(),
-            ladders,
-        }
+    /// Built-in DNA ladders plus the ladders defined in this project.
+    pub fn inspect_dna_ladders(&self, name_filter: Option<&str>) -> DnaLadderCatalog {
+        Self::summarize_dna_ladder_catalog(&self.project_dna_ladder_catalog(), name_filter)
     }
 
     pub fn export_dna_ladders(
+        &self,
         path: &str,
         name_filter: Option<&str>,
     ) -> Result<DnaLadderExportReport, EngineError> {
-        let catalog = Self::inspect_dna_ladders(name_filter);
+        let catalog = self.inspect_dna_ladders(name_filter);
         let text = serde_json::to_string_pretty(&catalog).map_err(|e| EngineError {
             code: ErrorCode::Internal,
             message: format!("Could not serialize DNA ladders JSON: {e}"),
@@ -6427,13 +7142,14 @@ impl GentleEngine {
 
     fn map_rna_structure_error(err: RnaStructureError) -> EngineError {
         match err {
-            RnaStructureError::UnsupportedBiotype { .. } | RnaStructureError::EmptySequence => {
-                EngineError {
-                    code: ErrorCode::InvalidInput,
-                    message: err.to_string(),
-                    cause_chain: vec![],
-                }
-            }
+            RnaStructureError::UnsupportedBiotype { .. }
+            | RnaStructureError::EmptySequence
+            | RnaStructureError::InvalidStructure { .. }
+            | RnaStructureError::InvalidConstraint { .. } => EngineError {
+                code: ErrorCode::InvalidInput,
+                message: err.to_string(),
+                cause_chain: vec![],
+            },
             RnaStructureError::ToolNotFound { .. } => EngineError {
                 code: ErrorCode::Unsupported,
                 message: err.to_string(),
@@ -6466,7 +7182,7 @@ impl GentleEngine {
 
                 cause_chain: vec![],
             })?;
-        rna_structure::inspect_text(dna).map_err(Self::map_rna_structure_error)
+        rna_structure::inspect_text(dna, None).map_err(Self::map_rna_structure_error)
     }
 
     pub fn render_rna_structure_svg_to_path(
@@ -6484,7 +7200,57 @@ impl GentleEngine {
 
                 cause_chain: vec![],
             })?;
-        rna_structure::render_svg(dna, path).map_err(Self::map_rna_structure_error)
+        rna_structure::render_svg(dna, path, None).map_err(Self::map_rna_structure_error)
+    }
+
+    pub fn render_rna_structure_svg_constrained(
+        &self,
+        seq_id: &str,
+        path: &str,
+        constraints: &RnaStructureConstraints,
+    ) -> Result<RnaStructureSvgReport, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+
+                cause_chain: vec![],
+            })?;
+        rna_structure::render_svg(dna, path, Some(constraints))
+            .map_err(Self::map_rna_structure_error)
+    }
+
+    pub fn render_rna_structure_svg_from_dot_bracket(
+        &self,
+        seq_id: &str,
+        dot_bracket: &str,
+        path: &str,
+    ) -> Result<RnaStructureSvgReport, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+
+                cause_chain: vec![],
+            })?;
+        match rna_structure::render_svg_from_dot_bracket(dna, dot_bracket, path) {
+            Err(RnaStructureError::ToolNotFound { executable }) => Err(EngineError {
+                code: ErrorCode::Unsupported,
+                message: format!(
+                    "Rendering a dot-bracket structure requires rnapkin (RNAfold is not needed); could not find '{}'. Install rnapkin or set {}",
+                    executable,
+                    rna_structure::RNAPKIN_ENV_BIN
+                ),
+                cause_chain: vec![],
+            }),
+            other => other.map_err(Self::map_rna_structure_error),
+        }
     }
 
     fn open_catalog_with_default_mode(
@@ -6624,6 +7390,15 @@ impl GentleEngine {
         Ok(catalog.list_genomes())
     }
 
+    /// Check every reference catalog entry's declared sources and prepared
+    /// status without downloading anything.
+    pub fn validate_genome_catalog(
+        catalog_path: Option<&str>,
+    ) -> Result<GenomeCatalogReport, EngineError> {
+        let (catalog, resolved_path) = Self::open_reference_genome_catalog(catalog_path)?;
+        Ok(catalog.validate_sources(resolved_path, None))
+    }
+
     pub fn list_reference_catalog_entries(
         catalog_path: Option<&str>,
         filter: Option<&str>,
@@ -7975,6 +8750,7 @@ impl GentleEngine {
         cache_dir: Option<&str>,
         timeout_seconds: Option<u64>,
         mode: PrepareReferenceGenomeMode,
+        download_retry: &DownloadRetryPolicy,
         on_progress: &mut dyn FnMut(PrepareGenomeProgress) -> bool,
     ) -> Result<PrepareGenomeReport, EngineError> {
         let (catalog, _) = Self::open_reference_genome_catalog(catalog_path)?;
@@ -7997,9 +8773,10 @@ impl GentleEngine {
         };
         let result = match mode {
             PrepareReferenceGenomeMode::PrepareOrReuse => catalog
-                .prepare_genome_once_with_progress(
+                .prepare_genome_once_with_retry_and_progress(
                     genome_id,
                     cache_dir.map(str::trim).filter(|v| !v.is_empty()),
+                    download_retry,
                     &mut guarded_progress,
                 ),
             PrepareReferenceGenomeMode::ReindexCachedFiles => catalog
@@ -8009,9 +8786,10 @@ impl GentleEngine {
                     &mut guarded_progress,
                 ),
             PrepareReferenceGenomeMode::RefreshFromSources => catalog
-                .reinstall_genome_once_with_progress(
+                .reinstall_genome_once_with_retry_and_progress(
                     genome_id,
                     cache_dir.map(str::trim).filter(|v| !v.is_empty()),
+                    download_retry,
                     &mut guarded_progress,
                 ),
         };
@@ -8086,6 +8864,29 @@ impl GentleEngine {
             cache_dir,
             timeout_seconds,
             PrepareReferenceGenomeMode::PrepareOrReuse,
+            &DownloadRetryPolicy::default(),
+            on_progress,
+        )
+    }
+
+    /// Same as [`Self::prepare_reference_genome_once`], retrying flaky source
+    /// downloads per `download_retry`; backoff waits still honor
+    /// `timeout_seconds` and callback cancellation.
+    pub fn prepare_reference_genome_once_with_retry(
+        genome_id: &str,
+        catalog_path: Option<&str>,
+        cache_dir: Option<&str>,
+        timeout_seconds: Option<u64>,
+        download_retry: &DownloadRetryPolicy,
+        on_progress: &mut dyn FnMut(PrepareGenomeProgress) -> bool,
+    ) -> Result<PrepareGenomeReport, EngineError> {
+        Self::prepare_reference_genome_once_with_options(
+            genome_id,
+            catalog_path,
+            cache_dir,
+            timeout_seconds,
+            PrepareReferenceGenomeMode::PrepareOrReuse,
+            download_retry,
             on_progress,
         )
     }
@@ -8103,6 +8904,7 @@ impl GentleEngine {
             cache_dir,
             timeout_seconds,
             PrepareReferenceGenomeMode::ReindexCachedFiles,
+            &DownloadRetryPolicy::default(),
             on_progress,
         )
     }
@@ -8120,6 +8922,7 @@ impl GentleEngine {
             cache_dir,
             timeout_seconds,
             PrepareReferenceGenomeMode::RefreshFromSources,
+            &DownloadRetryPolicy::default(),
             on_progress,
         )
     }
@@ -8296,8 +9099,9 @@ impl GentleEngine {
 
     /// Return session-local multi-level undo/redo availability.
     ///
-    /// History checkpoints are intentionally not serialized with project state;
-    /// this summary describes only the currently running engine instance.
+    /// History checkpoints are intentionally not serialized with project state
+    /// (only `save_session` persists them); this summary describes the
+    /// currently running engine instance.
     pub fn history_summary(&self) -> EngineHistorySummary {
         EngineHistorySummary {
             schema: "gentle.engine_history_summary.v1".to_string(),
@@ -8345,15 +9149,21 @@ impl GentleEngine {
         !matches!(
             op,
             Operation::SaveFile { .. }
+                | Operation::ExportAll { .. }
+                | Operation::ExportDesignPackage { .. }
                 | Operation::RenderSequenceSvg { .. }
+                | Operation::RenderThumbnails { .. }
                 | Operation::RenderDotplotSvg { .. }
                 | Operation::RenderTfbsScoreTracksSvg { .. }
                 | Operation::RenderTfbsScoreTrackCorrelationSvg { .. }
                 | Operation::RenderFeatureExpertSvg { .. }
                 | Operation::RenderIsoformArchitectureSvg { .. }
                 | Operation::RenderRnaStructureSvg { .. }
+                | Operation::RenderRnaStructureFromDotBracket { .. }
+                | Operation::RenderRnaStructureSvgConstrained { .. }
                 | Operation::RenderLineageSvg { .. }
                 | Operation::RenderPoolGelSvg { .. }
+                | Operation::RenderPlateLayoutSvg { .. }
                 | Operation::RenderProteinGelSvg { .. }
                 | Operation::RenderProteinGelReportsSvg { .. }
                 | Operation::RenderProteaseDigestGelSvg { .. }
@@ -8378,6 +9188,10 @@ impl GentleEngine {
                 | Operation::ExportProcessRunBundle { .. }
                 | Operation::ExportLabAssistantInstructions { .. }
                 | Operation::ExportFeaturesBed { .. }
+                | Operation::ExportRestrictionMap { .. }
+                | Operation::ExportTrackCsv { .. }
+                | Operation::ExportMapLegend { .. }
+                | Operation::ExportSequenceStats { .. }
                 | Operation::InspectSequenceContextView { .. }
                 | Operation::ExportSequenceContextBundle { .. }
                 | Operation::ListCutRunDatasets { .. }
@@ -8621,6 +9435,64 @@ impl GentleEngine {
                 .unwrap_or(false)
     }
 
+    /// Resolve `query` against `genes` with `match_mode`, keeping index order.
+    ///
+    /// Without a mode, exact matches win and substring matches are the
+    /// fallback. Returns the matches plus the label of the rule that produced
+    /// them (`exact`, `fuzzy`, `contains`, or `regex`).
+    fn select_genome_gene_matches<'a>(
+        genes: &'a [GenomeGeneRecord],
+        query: &str,
+        match_mode: Option<GeneMatchMode>,
+    ) -> Result<(Vec<&'a GenomeGeneRecord>, &'static str), EngineError> {
+        let query_lower = query.to_ascii_lowercase();
+        let exact = || -> Vec<&'a GenomeGeneRecord> {
+            genes
+                .iter()
+                .filter(|record| Self::genome_gene_matches_exact(record, query))
+                .collect()
+        };
+        let contains = || -> Vec<&'a GenomeGeneRecord> {
+            genes
+                .iter()
+                .filter(|record| Self::genome_gene_matches_contains(record, &query_lower))
+                .collect()
+        };
+        Ok(match match_mode {
+            None => {
+                let exact_matches = exact();
+                if exact_matches.is_empty() {
+                    (contains(), "fuzzy")
+                } else {
+                    (exact_matches, "exact")
+                }
+            }
+            Some(GeneMatchMode::Exact) => (exact(), "exact"),
+            Some(GeneMatchMode::Contains) => (contains(), "contains"),
+            Some(GeneMatchMode::Regex) => {
+                let regex = RegexBuilder::new(query)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| EngineError {
+                        code: ErrorCode::InvalidInput,
+                        message: format!("Invalid gene_query regex '{}': {}", query, e),
+
+                        cause_chain: vec![],
+                    })?;
+                let matches = genes
+                    .iter()
+                    .filter(|record| {
+                        [record.gene_name.as_deref(), record.gene_id.as_deref()]
+                            .into_iter()
+                            .flatten()
+                            .any(|value| regex.is_match(value))
+                    })
+                    .collect();
+                (matches, "regex")
+            }
+        })
+    }
+
     fn genome_gene_display_label(record: &GenomeGeneRecord) -> String {
         let label = record
             .gene_name
@@ -9883,7 +10755,7 @@ impl GentleEngine {
             result.warnings.push(format!(
                 "Could not auto-detect canonical MCS motif for helper genome '{}' on extracted sequence '{}'",
                 genome_id, seq_id
-            ));
+            ).into());
             return;
         };
         let motif_len = Self::helper_mcs_sequence_by_preset(detected_preset)
@@ -9896,7 +10768,7 @@ impl GentleEngine {
             result.warnings.push(format!(
                 "Found {match_count} canonical MCS motif matches on '{}'; expected exactly one, so no MCS fallback annotation was applied.",
                 seq_id
-            ));
+            ).into());
             return;
         }
         let end_0based_exclusive = start_0based.saturating_add(motif_len);
@@ -9933,7 +10805,7 @@ impl GentleEngine {
             result.warnings.push(format!(
                 "Helper genome '{}' suggests {} but matched {} MCS orientation; using detected orientation.",
                 genome_id, preferred_preset, detected_preset
-            ));
+            ).into());
         }
     }
 
@@ -10374,18 +11246,48 @@ impl GentleEngine {
         Some(feature)
     }
 
+    /// Load a 1-based genome region as a sequence; with `mmap-backing` the
+    /// bases stay in the prepared FASTA until first edited.
+    fn load_genome_slice_sequence(
+        catalog: &GenomeCatalog,
+        genome_id: &str,
+        chromosome: &str,
+        start_1based: usize,
+        end_1based: usize,
+        cache_dir: Option<&str>,
+    ) -> Result<DNAsequence, String> {
+        #[cfg(feature = "mmap-backing")]
+        {
+            catalog
+                .map_sequence_region_with_cache(
+                    genome_id,
+                    chromosome,
+                    start_1based,
+                    end_1based,
+                    cache_dir,
+                )
+                .map(DNAsequence::from_mapped_fasta)
+        }
+        #[cfg(not(feature = "mmap-backing"))]
+        {
+            let sequence = catalog.get_sequence_region_with_cache(
+                genome_id,
+                chromosome,
+                start_1based,
+                end_1based,
+                cache_dir,
+            )?;
+            DNAsequence::from_sequence(&sequence)
+                .map_err(|e| format!("Could not construct DNA sequence from genome slice: {e}"))
+        }
+    }
+
     fn import_genome_slice_sequence(
         &mut self,
         result: &mut OpResult,
-        sequence: String,
+        mut dna: DNAsequence,
         default_id: String,
     ) -> Result<SeqId, EngineError> {
-        let mut dna = DNAsequence::from_sequence(&sequence).map_err(|e| EngineError {
-            code: ErrorCode::Internal,
-            message: format!("Could not construct DNA sequence from genome slice: {e}"),
-
-            cause_chain: vec![],
-        })?;
         let seq_id = self.unique_seq_id(&default_id);
         dna.set_name(seq_id.clone());
         Self::prepare_sequence(&mut dna);
@@ -10500,6 +11402,16 @@ impl GentleEngine {
         Ok(subscription)
     }
 
+    fn track_subscription_key(
+        subscription: &GenomeTrackSubscription,
+    ) -> (GenomeTrackSource, &str, Option<&str>) {
+        (
+            subscription.source,
+            subscription.path.as_str(),
+            subscription.track_name.as_deref(),
+        )
+    }
+
     fn sort_track_subscriptions(subscriptions: &mut Vec<GenomeTrackSubscription>) {
         subscriptions.sort_by(|a, b| {
             a.source
@@ -10523,8 +11435,27 @@ impl GentleEngine {
             .and_then(|v| serde_json::from_value::<Vec<GenomeTrackSubscription>>(v).ok())
             .unwrap_or_default();
         subscriptions.retain(|subscription| !subscription.path.trim().is_empty());
-        Self::sort_track_subscriptions(&mut subscriptions);
-        subscriptions
+        // Older projects may hold duplicates that differ only in options.
+        // The stored list carries no add time (older versions kept it sorted
+        // and skipped exact re-adds), so keep the entry stored last for each
+        // source + path + track name, which is the last appended option set.
+        let mut seen = HashSet::new();
+        let mut deduped: Vec<GenomeTrackSubscription> = subscriptions
+            .iter()
+            .rev()
+            .filter(|subscription| {
+                let (source, path, track_name) = Self::track_subscription_key(subscription);
+                seen.insert((
+                    source.label(),
+                    path.to_string(),
+                    track_name.map(str::to_string),
+                ))
+            })
+            .cloned()
+            .collect();
+        deduped.reverse();
+        Self::sort_track_subscriptions(&mut deduped);
+        deduped
     }
 
     fn write_track_subscriptions_to_metadata(
@@ -26167,6 +27098,20 @@ impl GentleEngine {
                 cause_chain: vec![],
             });
         }
+        if let Some(score) = guide.off_target_score
+            && !(score.is_finite() && (0.0..=1.0).contains(&score))
+        {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Guide {} off_target_score ({}) must be between 0.0 and 1.0",
+                    index + 1,
+                    score
+                ),
+
+                cause_chain: vec![],
+            });
+        }
         if guide.cut_offset_from_protospacer_start >= protospacer.len() {
             return Err(EngineError {
                 code: ErrorCode::InvalidInput,
@@ -26195,6 +27140,7 @@ impl GentleEngine {
             },
             cut_offset_from_protospacer_start: guide.cut_offset_from_protospacer_start,
             rank: guide.rank,
+            off_target_score: guide.off_target_score,
         })
     }
 
@@ -26247,30 +27193,39 @@ impl GentleEngine {
         best
     }
 
+    /// Number of consecutive copies of the `unit_len`-bp unit starting at
+    /// `start`; 0 when that unit contains non-canonical bases.
+    fn tandem_repeat_units_at(sequence: &[u8], start: usize, unit_len: usize) -> usize {
+        if unit_len == 0 || start + unit_len > sequence.len() {
+            return 0;
+        }
+        let unit = &sequence[start..start + unit_len];
+        if !unit
+            .iter()
+            .all(|b| matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T'))
+        {
+            return 0;
+        }
+        let mut units = 1usize;
+        let mut idx = start + unit_len;
+        while idx + unit_len <= sequence.len()
+            && sequence[idx..idx + unit_len].eq_ignore_ascii_case(unit)
+        {
+            units += 1;
+            idx += unit_len;
+        }
+        units
+    }
+
     fn max_dinucleotide_repeat_units(sequence: &[u8]) -> usize {
         if sequence.len() < 2 {
             return 0;
         }
-        let canonical = |b: u8| matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T');
-        let mut best = 1usize;
-        for start in 0..(sequence.len() - 1) {
-            let b0 = sequence[start].to_ascii_uppercase();
-            let b1 = sequence[start + 1].to_ascii_uppercase();
-            if !canonical(b0) || !canonical(b1) {
-                continue;
-            }
-            let mut units = 1usize;
-            let mut idx = start + 2;
-            while idx + 1 < sequence.len()
-                && sequence[idx].to_ascii_uppercase() == b0
-                && sequence[idx + 1].to_ascii_uppercase() == b1
-            {
-                units += 1;
-                idx += 2;
-            }
-            best = best.max(units);
-        }
-        best
+        (0..(sequence.len() - 1))
+            .map(|start| Self::tandem_repeat_units_at(sequence, start, 2))
+            .max()
+            .unwrap_or(0)
+            .max(1)
     }
 
     fn normalize_practical_filter_config(
@@ -27281,10 +28236,19 @@ impl GentleEngine {
             .map(|(chrom, count)| format!("{chrom} ({count})"))
             .collect::<Vec<_>>()
             .join(", ");
-        report.warnings.push(format!(
-            "{} record(s) in {} input did not match anchor chromosome '{}' (examples: {})",
-            report.skipped_wrong_chromosome, source_label, anchor_chromosome, seen
-        ));
+        report.warnings.push(
+            Warning::new(
+                WarningCode::TrackImportRecordsSkipped,
+                format!(
+                    "{} record(s) in {} input did not match anchor chromosome '{}' (examples: {})",
+                    report.skipped_wrong_chromosome, source_label, anchor_chromosome, seen
+                ),
+            )
+            .with_context(json!({
+                "skipped_wrong_chromosome": report.skipped_wrong_chromosome,
+                "anchor_chromosome": anchor_chromosome,
+            })),
+        );
     }
 }
 
@@ -0,0 +1,126 @@
+//! Ambiguous-base (N/IUPAC) reporting and masking.
+//!
+//! `ambiguity_report` lists maximal runs of bases whose `IupacCode` stands for
+//! more than one nucleotide, so assembly gaps (`NNNN`) and isolated ambiguity
+//! calls (`R`, `Y`, ...) show up before primers are placed over them.
+//! `MaskAmbiguousRegions` materializes the runs as generated `misc_feature`s.
+
+use super::*;
+
+impl GentleEngine {
+    fn is_ambiguous_base(base: u8) -> bool {
+        IupacCode::from_letter(base).to_vec().len() > 1
+    }
+
+    pub(super) fn scan_ambiguous_regions(bases: &[u8]) -> Vec<AmbiguousRegion> {
+        let mut regions = vec![];
+        let mut pos = 0usize;
+        while pos < bases.len() {
+            if !Self::is_ambiguous_base(bases[pos]) {
+                pos += 1;
+                continue;
+            }
+            let start = pos;
+            while pos < bases.len() && Self::is_ambiguous_base(bases[pos]) {
+                pos += 1;
+            }
+            let mut letters = bases[start..pos]
+                .iter()
+                .map(|base| base.to_ascii_uppercase())
+                .collect::<Vec<_>>();
+            letters.sort_unstable();
+            letters.dedup();
+            regions.push(AmbiguousRegion {
+                start_1based: start + 1,
+                end_1based: pos,
+                length_bp: pos - start,
+                letters: String::from_utf8_lossy(&letters).to_string(),
+            });
+        }
+        regions
+    }
+
+    pub fn ambiguity_report(&self, seq_id: &str) -> Result<AmbiguityReport, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let regions = Self::scan_ambiguous_regions(dna.forward_bytes());
+        Ok(AmbiguityReport {
+            seq_id: seq_id.to_string(),
+            length_bp: dna.len(),
+            ambiguous_bp: regions.iter().map(|region| region.length_bp).sum(),
+            regions,
+        })
+    }
+
+    fn is_generated_ambiguous_region_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("ambiguous_region"))
+    }
+
+    fn build_ambiguous_region_feature(region: &AmbiguousRegion) -> gb_io::seq::Feature {
+        gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location: gb_io::seq::Location::simple_range(
+                region.start_1based as i64 - 1,
+                region.end_1based as i64,
+            ),
+            qualifiers: vec![
+                (
+                    "label".into(),
+                    Some(format!("ambiguous {} bp", region.length_bp)),
+                ),
+                (
+                    "note".into(),
+                    Some(format!("ambiguous bases: {}", region.letters)),
+                ),
+                (
+                    "gentle_generated".into(),
+                    Some("ambiguous_region".to_string()),
+                ),
+            ],
+        }
+    }
+
+    pub(super) fn mask_ambiguous_regions(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        let report = self.ambiguity_report(seq_id)?;
+        let _ = self.ensure_lineage_node(seq_id);
+        let Some(dna) = self.state.sequences.get_mut(seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_ambiguous_region_feature(feature));
+        }
+        dna.features_mut().extend(
+            report
+                .regions
+                .iter()
+                .map(Self::build_ambiguous_region_feature),
+        );
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Masked {} ambiguous region(s) ({} bp) on '{}'",
+            report.regions.len(),
+            report.ambiguous_bp,
+            seq_id
+        ));
+        Ok(())
+    }
+}
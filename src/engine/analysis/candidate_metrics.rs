@@ -481,6 +481,9 @@ impl GentleEngine {
         }
     }
 
+    /// Naive linear scan over every target; the reference that
+    /// `FeatureDistanceIndex::nearest_distance` must reproduce exactly.
+    #[cfg(test)]
     pub(super) fn nearest_feature_distance(
         candidate_start: usize,
         candidate_end: usize,
@@ -504,19 +507,44 @@ impl GentleEngine {
             .min()
     }
 
-    pub(super) fn matching_feature_count(
+    /// Index the targets passing the kind/label/strand filters for repeated
+    /// nearest-distance queries.
+    pub(super) fn build_feature_distance_index(
         features: &[FeatureDistanceTarget],
         kind_filter_upper: &[String],
         label_regex: Option<&Regex>,
         strand_relation: CandidateFeatureStrandRelation,
-    ) -> usize {
-        features
+    ) -> FeatureDistanceIndex {
+        let matching = features
             .iter()
             .filter(|feature| Self::feature_matches_filter(feature, kind_filter_upper, label_regex))
             .filter(|feature| Self::feature_matches_strand_relation(feature, strand_relation))
+            .collect::<Vec<_>>();
+        let feature_count = matching
+            .iter()
             .map(|feature| feature.feature_index)
             .collect::<HashSet<_>>()
-            .len()
+            .len();
+        let mut by_start = matching
+            .iter()
+            .map(|feature| (feature.start_0based, feature.end_0based))
+            .collect::<Vec<_>>();
+        by_start.sort_unstable();
+        let max_end_prefix = by_start
+            .iter()
+            .scan(0usize, |max_end, (_, end)| {
+                *max_end = (*max_end).max(*end);
+                Some(*max_end)
+            })
+            .collect();
+        let mut by_end = by_start.clone();
+        by_end.sort_unstable_by_key(|(start, end)| (*end, *start));
+        FeatureDistanceIndex {
+            by_start,
+            max_end_prefix,
+            by_end,
+            feature_count,
+        }
     }
 
     pub(super) fn compute_candidate_metrics(
@@ -895,3 +923,50 @@ impl GentleEngine {
         }
     }
 }
+
+impl FeatureDistanceIndex {
+    /// Number of distinct features behind the indexed targets.
+    pub(super) fn feature_count(&self) -> usize {
+        self.feature_count
+    }
+
+    /// Smallest `GentleEngine::interval_distance` from `[start, end)` to any
+    /// indexed target, or `None` when the index is empty.
+    pub(super) fn nearest_distance(&self, start: usize, end: usize) -> Option<usize> {
+        self.nearest_distance_counted(start, end, &mut 0)
+    }
+
+    /// `nearest_distance`, adding the number of target comparisons made to
+    /// `comparisons` (a hook for checking the query stays logarithmic).
+    pub(super) fn nearest_distance_counted(
+        &self,
+        start: usize,
+        end: usize,
+        comparisons: &mut usize,
+    ) -> Option<usize> {
+        if self.by_start.is_empty() {
+            return None;
+        }
+        // Targets starting before `end`: any of them reaching past `start`
+        // overlaps the candidate.
+        let starts_before_end = self.by_start.partition_point(|(s, _)| {
+            *comparisons += 1;
+            *s < end
+        });
+        if starts_before_end > 0 && self.max_end_prefix[starts_before_end - 1] > start {
+            return Some(0);
+        }
+        // Otherwise the nearest target is the first one starting at/after
+        // `end` or the last one ending at/before `start`.
+        let right = self.by_start.get(starts_before_end);
+        let ends_by_start = self.by_end.partition_point(|(_, e)| {
+            *comparisons += 1;
+            *e <= start
+        });
+        let left = ends_by_start.checked_sub(1).map(|idx| &self.by_end[idx]);
+        left.into_iter()
+            .chain(right)
+            .map(|(s, e)| GentleEngine::interval_distance(start, end, *s, *e))
+            .min()
+    }
+}
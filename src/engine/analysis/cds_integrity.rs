@@ -0,0 +1,161 @@
+//! Reading-frame integrity of CDS features across sequence edits.
+//!
+//! `check_cds_integrity` flags CDS features whose coding length is not a
+//! multiple of three or that carry an in-frame stop codon before their last
+//! codon. Mutagenesis operations run it on the template with their edit
+//! applied and only report problems the edit introduced, so an already
+//! partial CDS does not warn on every run.
+
+use super::*;
+use gb_io::seq::Location;
+
+impl GentleEngine {
+    pub(super) fn check_cds_integrity(dna: &DNAsequence) -> Vec<String> {
+        let bases = dna.forward_bytes();
+        let mut warnings = vec![];
+        for (feature_id, feature) in dna.features().iter().enumerate() {
+            if !feature.kind.to_string().eq_ignore_ascii_case("CDS") {
+                continue;
+            }
+            let label = Self::feature_display_label(feature, feature_id);
+            let reverse = feature_is_reverse(feature);
+            let mut ranges = vec![];
+            collect_location_ranges_usize(&feature.location, &mut ranges);
+            ranges.sort_unstable();
+            let mut coding_positions = ranges
+                .into_iter()
+                .flat_map(|(start, end)| start..end.min(bases.len()))
+                .collect::<Vec<_>>();
+            if reverse {
+                coding_positions.reverse();
+            }
+            let offset = Self::construct_reasoning_feature_codon_start_offset(feature);
+            let coding = coding_positions.get(offset..).unwrap_or_default();
+            if !coding.len().is_multiple_of(3) {
+                warnings.push(format!(
+                    "CDS '{label}' is {} bp, not a multiple of 3 (frameshift)",
+                    coding.len()
+                ));
+            }
+            let translation_table = Self::construct_reasoning_feature_translation_table(feature);
+            let codons = coding.chunks_exact(3).collect::<Vec<_>>();
+            let premature_stop =
+                codons[..codons.len().saturating_sub(1)]
+                    .iter()
+                    .position(|chunk| {
+                        let forward = [bases[chunk[0]], bases[chunk[1]], bases[chunk[2]]]
+                            .map(|base| base.to_ascii_uppercase());
+                        let codon = if reverse {
+                            forward.map(IupacCode::letter_complement)
+                        } else {
+                            forward
+                        };
+                        AMINO_ACIDS.codon2aa(codon, Some(translation_table)) == '*'
+                    });
+            if let Some(index) = premature_stop {
+                warnings.push(format!(
+                    "CDS '{label}' has a premature stop codon at codon {} (position {})",
+                    index + 1,
+                    codons[index][0] + 1
+                ));
+            }
+        }
+        warnings
+    }
+
+    /// Copy of `dna` with `edit_start..edit_end` replaced by `insert`, keeping
+    /// only its CDS features mapped across the edit. CDS features with a
+    /// boundary inside the replaced span are dropped.
+    pub(super) fn cds_integrity_edit_preview(
+        dna: &DNAsequence,
+        edit_start: usize,
+        edit_end: usize,
+        insert: &str,
+    ) -> Option<DNAsequence> {
+        let text = dna.get_forward_string();
+        let edited = format!("{}{}{}", &text[..edit_start], insert, &text[edit_end..]);
+        let mut preview = DNAsequence::from_sequence(&edited).ok()?;
+        let delta = insert.len() as i64 - (edit_end - edit_start) as i64;
+        *preview.features_mut() = dna
+            .features()
+            .iter()
+            .filter(|feature| feature.kind.to_string().eq_ignore_ascii_case("CDS"))
+            .filter_map(|feature| {
+                let location = Self::remap_location_across_edit(
+                    &feature.location,
+                    edit_start as i64,
+                    edit_end as i64,
+                    delta,
+                    false,
+                )?;
+                Some(gb_io::seq::Feature {
+                    location,
+                    ..feature.clone()
+                })
+            })
+            .collect();
+        Some(preview)
+    }
+
+    /// Map `location` across replacing `edit_start..edit_end` with
+    /// `edit_end - edit_start + delta` bases. A boundary strictly inside the
+    /// replaced span yields `None`, or with `snap_inside` is moved to the
+    /// matching edge of the replacement.
+    pub(super) fn remap_location_across_edit(
+        location: &Location,
+        edit_start: i64,
+        edit_end: i64,
+        delta: i64,
+        snap_inside: bool,
+    ) -> Option<Location> {
+        let remap = |pos: i64, inside: i64| {
+            if pos <= edit_start {
+                Some(pos)
+            } else if pos >= edit_end {
+                Some(pos + delta)
+            } else {
+                snap_inside.then_some(inside)
+            }
+        };
+        let remap_part = |part: &Location| {
+            Self::remap_location_across_edit(part, edit_start, edit_end, delta, snap_inside)
+        };
+        match location {
+            Location::Range((start, before), (end, after)) => Some(Location::Range(
+                (remap(*start, edit_start)?, *before),
+                (remap(*end, edit_end + delta)?, *after),
+            )),
+            Location::Complement(inner) => {
+                remap_part(inner).map(|inner| Location::Complement(Box::new(inner)))
+            }
+            Location::Join(parts) => parts
+                .iter()
+                .map(remap_part)
+                .collect::<Option<Vec<_>>>()
+                .map(Location::Join),
+            Location::Order(parts) => parts
+                .iter()
+                .map(remap_part)
+                .collect::<Option<Vec<_>>>()
+                .map(Location::Order),
+            _ => None,
+        }
+    }
+
+    /// Warn about CDS problems present in `edited` but not in `template`.
+    pub(super) fn push_cds_integrity_warnings(
+        result: &mut OpResult,
+        template_id: &str,
+        template: &DNAsequence,
+        edited: &DNAsequence,
+    ) {
+        let existing = Self::check_cds_integrity(template);
+        for warning in Self::check_cds_integrity(edited) {
+            if !existing.contains(&warning) {
+                result
+                    .warnings
+                    .push(format!("Edit of '{template_id}': {warning}").into());
+            }
+        }
+    }
+}
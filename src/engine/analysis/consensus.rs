@@ -0,0 +1,220 @@
+//! Consensus sequence of a related sequence set.
+//!
+//! Sequences are aligned progressively in the given order: the first one
+//! seeds the alignment, and each further sequence is globally aligned (same
+//! `bio` aligner and default scores as `AlignSequences`) against the current
+//! column profile, whose most frequent base stands in for each column. Gaps
+//! the new sequence opens are inserted into every earlier row.
+//!
+//! Each column then yields one consensus letter: the column is dropped when
+//! more rows have a gap than a base, a base reaching `threshold` of the
+//! non-gap rows is called as is, and otherwise the IUPAC code of every base
+//! seen in the column is emitted.
+
+use super::*;
+
+const CONSENSUS_GAP: u8 = b'-';
+const CONSENSUS_BASES: [u8; 4] = [b'A', b'C', b'G', b'T'];
+
+impl GentleEngine {
+    /// Per-base weights of one alignment column plus its gap count; an
+    /// ambiguous input letter splits its weight over the bases it stands for.
+    fn consensus_column_counts(rows: &[Vec<u8>], column: usize) -> ([f64; 4], usize) {
+        let mut counts = [0.0; 4];
+        let mut gaps = 0usize;
+        for row in rows {
+            let letter = row[column];
+            if letter == CONSENSUS_GAP {
+                gaps += 1;
+                continue;
+            }
+            let bases = IupacCode::from_letter(letter).to_vec();
+            for base in &bases {
+                if let Some(idx) = CONSENSUS_BASES.iter().position(|b| b == base) {
+                    counts[idx] += 1.0 / bases.len() as f64;
+                }
+            }
+        }
+        (counts, gaps)
+    }
+
+    /// Most frequent base per column (ties: A < C < G < T), used as the
+    /// profile the next sequence is aligned against.
+    fn consensus_profile(rows: &[Vec<u8>]) -> Vec<u8> {
+        (0..rows[0].len())
+            .map(|column| {
+                let (counts, _) = Self::consensus_column_counts(rows, column);
+                let best = (1..CONSENSUS_BASES.len()).fold(0, |best, idx| {
+                    if counts[idx] > counts[best] {
+                        idx
+                    } else {
+                        best
+                    }
+                });
+                CONSENSUS_BASES[best]
+            })
+            .collect()
+    }
+
+    /// Gapped rows of the progressive alignment, one per input, all of equal
+    /// length.
+    fn consensus_alignment_rows(sequences: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        let score = |a: u8, b: u8| {
+            if IupacCode::from_letter(a)
+                .subset(IupacCode::from_letter(b))
+                .is_empty()
+            {
+                default_pairwise_mismatch_score()
+            } else {
+                default_pairwise_match_score()
+            }
+        };
+        let mut rows = vec![sequences[0].clone()];
+        for sequence in &sequences[1..] {
+            let profile = Self::consensus_profile(&rows);
+            let mut aligner = bio::alignment::pairwise::Aligner::new(
+                default_pairwise_gap_open(),
+                default_pairwise_gap_extend(),
+                &score,
+            );
+            let alignment = aligner.global(sequence, &profile);
+            let mut merged = vec![vec![]; rows.len()];
+            let mut new_row = vec![];
+            let (mut x, mut y) = (0usize, 0usize);
+            for op in &alignment.operations {
+                match op {
+                    bio::alignment::AlignmentOperation::Match
+                    | bio::alignment::AlignmentOperation::Subst => {
+                        for (target, row) in merged.iter_mut().zip(&rows) {
+                            target.push(row[y]);
+                        }
+                        new_row.push(sequence[x]);
+                        x += 1;
+                        y += 1;
+                    }
+                    // Base only in the new sequence: a new column, gapped in
+                    // every earlier row.
+                    bio::alignment::AlignmentOperation::Ins => {
+                        for target in &mut merged {
+                            target.push(CONSENSUS_GAP);
+                        }
+                        new_row.push(sequence[x]);
+                        x += 1;
+                    }
+                    bio::alignment::AlignmentOperation::Del => {
+                        for (target, row) in merged.iter_mut().zip(&rows) {
+                            target.push(row[y]);
+                        }
+                        new_row.push(CONSENSUS_GAP);
+                        y += 1;
+                    }
+                    bio::alignment::AlignmentOperation::Xclip(_)
+                    | bio::alignment::AlignmentOperation::Yclip(_) => {}
+                }
+            }
+            merged.push(new_row);
+            rows = merged;
+        }
+        rows
+    }
+
+    fn consensus_letter(rows: &[Vec<u8>], column: usize, threshold: f64) -> Option<u8> {
+        let (counts, gaps) = Self::consensus_column_counts(rows, column);
+        let bases = rows.len() - gaps;
+        if gaps > bases {
+            return None;
+        }
+        let total = counts.iter().sum::<f64>();
+        if total <= 0.0 {
+            return Some(b'N');
+        }
+        if let Some(idx) = counts
+            .iter()
+            .position(|count| count / total + 1e-9 >= threshold)
+        {
+            return Some(CONSENSUS_BASES[idx]);
+        }
+        let mask = counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0.0)
+            .fold(0u8, |mask, (idx, _)| mask | (1 << idx));
+        Some(IupacCode::new(mask).to_letter())
+    }
+
+    /// Consensus of `seq_ids` (at least two), aligned progressively in the
+    /// given order. `threshold` is the fraction of non-gap rows a base needs
+    /// to be called alone; it must be in `(0.5, 1]` so at most one base
+    /// qualifies.
+    pub fn consensus(&self, seq_ids: &[SeqId], threshold: f64) -> Result<String, EngineError> {
+        if !threshold.is_finite() || threshold <= 0.5 || threshold > 1.0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Consensus threshold must be in (0.5, 1], got {threshold}"),
+                cause_chain: vec![],
+            });
+        }
+        if seq_ids.len() < 2 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Consensus requires at least two sequences".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let mut sequences = Vec::with_capacity(seq_ids.len());
+        for seq_id in seq_ids {
+            let dna = self
+                .state
+                .sequences
+                .get(seq_id)
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::NotFound,
+                    message: format!("Sequence '{seq_id}' not found"),
+                    cause_chain: vec![],
+                })?;
+            if dna.is_empty() {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("Sequence '{seq_id}' is empty"),
+                    cause_chain: vec![],
+                });
+            }
+            sequences.push(dna.forward_bytes().to_ascii_uppercase());
+        }
+        let rows = Self::consensus_alignment_rows(&sequences);
+        let consensus = (0..rows[0].len())
+            .filter_map(|column| Self::consensus_letter(&rows, column, threshold))
+            .collect::<Vec<_>>();
+        Ok(String::from_utf8_lossy(&consensus).into_owned())
+    }
+
+    pub(super) fn make_consensus(
+        &mut self,
+        result: &mut OpResult,
+        seq_ids: &[SeqId],
+        threshold: f64,
+        output_id: Option<SeqId>,
+    ) -> Result<(), EngineError> {
+        let consensus = self.consensus(seq_ids, threshold)?;
+        let ambiguous = consensus
+            .bytes()
+            .filter(|b| !CONSENSUS_BASES.contains(b))
+            .count();
+        let mut dna = DNAsequence::from_sequence(&consensus).map_err(|e| EngineError {
+            code: ErrorCode::Internal,
+            message: format!("Could not build consensus sequence: {e}"),
+            cause_chain: vec![],
+        })?;
+        Self::prepare_sequence(&mut dna);
+        let output_id = self.unique_seq_id(&output_id.unwrap_or_else(|| "consensus".to_string()));
+        self.state.sequences.insert(output_id.clone(), dna);
+        self.add_lineage_node(&output_id, SequenceOrigin::Derived, Some(&result.op_id));
+        result.created_seq_ids.push(output_id.clone());
+        result.messages.push(format!(
+            "Created consensus '{output_id}' of {} sequence(s): {} bp, {ambiguous} ambiguous position(s) (threshold={threshold})",
+            seq_ids.len(),
+            consensus.len()
+        ));
+        Ok(())
+    }
+}
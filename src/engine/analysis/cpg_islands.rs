@@ -0,0 +1,213 @@
+//! CpG island detection.
+//!
+//! `find_cpg_islands` applies the Gardiner-Garden/Frommer criteria to every
+//! `window_bp` window: GC fraction of at least `gc_min` and an
+//! observed/expected CpG ratio (`CpG * window / (C * G)`) of at least
+//! `obs_exp_min`. Overlapping or abutting qualifying windows are merged into
+//! one island, whose statistics are recomputed over the merged span. Windows
+//! are counted from prefix sums, so the scan is linear in sequence length.
+//! `AnnotateCpgIslands` materializes the islands as generated `misc_feature`s.
+
+use super::*;
+
+impl GentleEngine {
+    fn validate_cpg_island_params(
+        window_bp: usize,
+        gc_min: f64,
+        obs_exp_min: f64,
+    ) -> Result<(), EngineError> {
+        if window_bp < 2 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("CpG island window_bp must be at least 2 (got {window_bp})"),
+                cause_chain: vec![],
+            });
+        }
+        if !gc_min.is_finite() || !(0.0..=1.0).contains(&gc_min) {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("CpG island gc_min must be within 0..1 (got {gc_min})"),
+                cause_chain: vec![],
+            });
+        }
+        if !obs_exp_min.is_finite() || obs_exp_min < 0.0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "CpG island obs_exp_min must be a non-negative number (got {obs_exp_min})"
+                ),
+                cause_chain: vec![],
+            });
+        }
+        Ok(())
+    }
+
+    /// `(gc_fraction, cpg_count, obs_exp)` of `bases[start..end]` from
+    /// prefix counts of C, G, and CpG dinucleotide starts.
+    fn cpg_window_stats(
+        c_prefix: &[usize],
+        g_prefix: &[usize],
+        cpg_prefix: &[usize],
+        start: usize,
+        end: usize,
+    ) -> (f64, usize, f64) {
+        let len = end - start;
+        let c = c_prefix[end] - c_prefix[start];
+        let g = g_prefix[end] - g_prefix[start];
+        // A CpG starting at end - 1 would reach past the window.
+        let cpg = cpg_prefix[end - 1] - cpg_prefix[start];
+        let obs_exp = if c == 0 || g == 0 {
+            0.0
+        } else {
+            (cpg * len) as f64 / (c * g) as f64
+        };
+        ((c + g) as f64 / len as f64, cpg, obs_exp)
+    }
+
+    pub(super) fn scan_cpg_islands(
+        bases: &[u8],
+        window_bp: usize,
+        gc_min: f64,
+        obs_exp_min: f64,
+    ) -> Vec<CpgIsland> {
+        let n = bases.len();
+        if window_bp == 0 || n < window_bp {
+            return vec![];
+        }
+        let upper = bases
+            .iter()
+            .map(|base| base.to_ascii_uppercase())
+            .collect::<Vec<_>>();
+        let mut c_prefix = vec![0usize; n + 1];
+        let mut g_prefix = vec![0usize; n + 1];
+        let mut cpg_prefix = vec![0usize; n + 1];
+        for (idx, base) in upper.iter().enumerate() {
+            c_prefix[idx + 1] = c_prefix[idx] + usize::from(*base == b'C');
+            g_prefix[idx + 1] = g_prefix[idx] + usize::from(*base == b'G');
+            cpg_prefix[idx + 1] =
+                cpg_prefix[idx] + usize::from(*base == b'C' && upper.get(idx + 1) == Some(&b'G'));
+        }
+
+        let mut spans: Vec<(usize, usize)> = vec![];
+        for start in 0..=n - window_bp {
+            let end = start + window_bp;
+            let (gc, _, obs_exp) =
+                Self::cpg_window_stats(&c_prefix, &g_prefix, &cpg_prefix, start, end);
+            if gc < gc_min || obs_exp < obs_exp_min {
+                continue;
+            }
+            match spans.last_mut() {
+                Some(last) if start <= last.1 => last.1 = end,
+                _ => spans.push((start, end)),
+            }
+        }
+        spans
+            .into_iter()
+            .map(|(start, end)| {
+                let (gc_fraction, cpg_count, cpg_obs_exp) =
+                    Self::cpg_window_stats(&c_prefix, &g_prefix, &cpg_prefix, start, end);
+                CpgIsland {
+                    start_1based: start + 1,
+                    end_1based: end,
+                    length_bp: end - start,
+                    gc_fraction,
+                    cpg_count,
+                    cpg_obs_exp,
+                }
+            })
+            .collect()
+    }
+
+    /// CpG islands of `seq_id` by the Gardiner-Garden/Frommer criteria,
+    /// ordered by start. Sequences shorter than `window_bp` have none.
+    pub fn find_cpg_islands(
+        &self,
+        seq_id: &str,
+        window_bp: usize,
+        gc_min: f64,
+        obs_exp_min: f64,
+    ) -> Result<Vec<CpgIsland>, EngineError> {
+        Self::validate_cpg_island_params(window_bp, gc_min, obs_exp_min)?;
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        Ok(Self::scan_cpg_islands(
+            dna.forward_bytes(),
+            window_bp,
+            gc_min,
+            obs_exp_min,
+        ))
+    }
+
+    fn is_generated_cpg_island_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("cpg_island"))
+    }
+
+    fn build_cpg_island_feature(island: &CpgIsland) -> gb_io::seq::Feature {
+        gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location: gb_io::seq::Location::simple_range(
+                island.start_1based as i64 - 1,
+                island.end_1based as i64,
+            ),
+            qualifiers: vec![
+                (
+                    "label".into(),
+                    Some(format!("CpG island {} bp", island.length_bp)),
+                ),
+                (
+                    "note".into(),
+                    Some(format!(
+                        "CpG island: GC {:.2}, CpG obs/exp {:.2}, {} CpG",
+                        island.gc_fraction, island.cpg_obs_exp, island.cpg_count
+                    )),
+                ),
+                ("gentle_generated".into(), Some("cpg_island".to_string())),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_cpg_islands(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        window_bp: usize,
+        gc_min: f64,
+        obs_exp_min: f64,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        let islands = self.find_cpg_islands(seq_id, window_bp, gc_min, obs_exp_min)?;
+        let _ = self.ensure_lineage_node(seq_id);
+        let Some(dna) = self.state.sequences.get_mut(seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_cpg_island_feature(feature));
+        }
+        dna.features_mut()
+            .extend(islands.iter().map(Self::build_cpg_island_feature));
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Annotated {} CpG island(s) on '{}' (window={} bp, gc_min={}, obs_exp_min={})",
+            islands.len(),
+            seq_id,
+            window_bp,
+            gc_min,
+            obs_exp_min
+        ));
+        Ok(())
+    }
+}
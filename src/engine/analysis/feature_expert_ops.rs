@@ -4040,14 +4040,17 @@ impl GentleEngine {
         result.created_seq_ids.push(seq_id.clone());
         let expected_len = entry.end_1based - entry.start_1based + 1;
         if entry.sequence_length != expected_len {
-            result.warnings.push(format!(
-                "Ensembl region {}:{}-{} returned {} bp; requested interval length is {} bp",
-                entry.chromosome,
-                entry.start_1based,
-                entry.end_1based,
-                entry.sequence_length,
-                expected_len
-            ));
+            result.warnings.push(
+                format!(
+                    "Ensembl region {}:{}-{} returned {} bp; requested interval length is {} bp",
+                    entry.chromosome,
+                    entry.start_1based,
+                    entry.end_1based,
+                    entry.sequence_length,
+                    expected_len
+                )
+                .into(),
+            );
         }
         let sequence_sha1 = format!("{:x}", Sha1::digest(entry.sequence.as_bytes()));
         self.append_genome_extraction_provenance(GenomeExtractionProvenance {
@@ -5466,6 +5469,7 @@ impl GentleEngine {
                 seq.features(),
                 feature_idx,
                 seq_id,
+                None,
             );
             let (
                 derived_transcript,
@@ -5671,6 +5675,7 @@ impl GentleEngine {
             source_features,
             source_feature_id,
             source_seq_id,
+            None,
         )?;
         let derivation = match annotated_derivation {
             Some(derivation) => Some(derivation),
@@ -5682,6 +5687,7 @@ impl GentleEngine {
                 source_features,
                 &transcript_id,
                 &transcript_label,
+                None,
             )?,
         };
         let local_cds_ranges_0based =
@@ -0,0 +1,118 @@
+//! Pairwise feature overlap (collision) report.
+//!
+//! Features are reduced to their location segments with
+//! `collect_location_segments`, so joined CDS/mRNA locations only collide
+//! where an actual segment overlaps, not across their introns. `source`
+//! features span the whole record and are skipped.
+
+use super::*;
+
+struct FeatureOverlapEntry {
+    feature_id: usize,
+    kind: String,
+    label: String,
+    segments: Vec<(usize, usize)>,
+    span: (usize, usize),
+}
+
+impl GentleEngine {
+    /// Pairs of features in `seq_id` whose segments overlap, one row per
+    /// merged overlap interval, ordered by overlap start, then feature ids.
+    pub fn feature_overlaps(&self, seq_id: &str) -> Result<Vec<FeatureOverlap>, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+
+        let mut entries = vec![];
+        for (feature_id, feature) in dna.features().iter().enumerate() {
+            if feature.kind.to_string().eq_ignore_ascii_case("SOURCE") {
+                continue;
+            }
+            let mut raw = vec![];
+            Self::collect_location_segments(&feature.location, dna.len(), false, &mut raw);
+            let mut segments = raw
+                .iter()
+                .map(|segment| (segment.start_0based, segment.end_0based))
+                .collect::<Vec<_>>();
+            segments.sort_unstable();
+            let (Some(start), Some(end)) = (
+                segments.iter().map(|(start, _)| *start).min(),
+                segments.iter().map(|(_, end)| *end).max(),
+            ) else {
+                continue;
+            };
+            entries.push(FeatureOverlapEntry {
+                feature_id,
+                kind: feature.kind.to_string(),
+                label: Self::feature_display_label(feature, feature_id),
+                segments,
+                span: (start, end),
+            });
+        }
+        entries.sort_by_key(|entry| (entry.span.0, entry.feature_id));
+
+        let mut rows = vec![];
+        for (idx, first) in entries.iter().enumerate() {
+            for second in entries[idx + 1..]
+                .iter()
+                .take_while(|second| second.span.0 < first.span.1)
+            {
+                let (a, b) = if first.feature_id < second.feature_id {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                let nested = (a.span.0 <= b.span.0 && b.span.1 <= a.span.1)
+                    || (b.span.0 <= a.span.0 && a.span.1 <= b.span.1);
+                for (start, end) in Self::merged_segment_intersections(&a.segments, &b.segments) {
+                    rows.push(FeatureOverlap {
+                        feature_a: a.feature_id,
+                        kind_a: a.kind.clone(),
+                        label_a: a.label.clone(),
+                        feature_b: b.feature_id,
+                        kind_b: b.kind.clone(),
+                        label_b: b.label.clone(),
+                        overlap_start_0based: start,
+                        overlap_end_0based_exclusive: end,
+                        nested,
+                    });
+                }
+            }
+        }
+        rows.sort_by_key(|row| (row.overlap_start_0based, row.feature_a, row.feature_b));
+        Ok(rows)
+    }
+
+    /// Intersections of two segment lists (half-open), with touching or
+    /// overlapping pieces merged.
+    fn merged_segment_intersections(
+        left: &[(usize, usize)],
+        right: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
+        let mut pieces = left
+            .iter()
+            .flat_map(|&(a_start, a_end)| {
+                right.iter().filter_map(move |&(b_start, b_end)| {
+                    let start = a_start.max(b_start);
+                    let end = a_end.min(b_end);
+                    (start < end).then_some((start, end))
+                })
+            })
+            .collect::<Vec<_>>();
+        pieces.sort_unstable();
+        let mut merged: Vec<(usize, usize)> = vec![];
+        for (start, end) in pieces {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+}
@@ -595,14 +595,6 @@ impl GentleEngine {
         hash
     }
 
-    fn splitmix64_next(state: &mut u64) -> u64 {
-        *state = state.wrapping_add(0x9e3779b97f4a7c15);
-        let mut z = *state;
-        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
-        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
-        z ^ (z >> 31)
-    }
-
     fn gene_set_resolve_random(
         genome_id: &str,
         count: usize,
@@ -636,11 +628,7 @@ impl GentleEngine {
                 count
             ));
         }
-        let mut state = random_seed ^ Self::stable_hash64(gene_index_source);
-        for idx in (1..universe.len()).rev() {
-            let swap_idx = (Self::splitmix64_next(&mut state) as usize) % (idx + 1);
-            universe.swap(idx, swap_idx);
-        }
+        SeededRng::new(random_seed ^ Self::stable_hash64(gene_index_source)).shuffle(&mut universe);
         let mut chosen = universe.into_iter().take(count).collect::<Vec<_>>();
         Self::gene_set_sort_indexed_genes(&mut chosen);
         let members = chosen
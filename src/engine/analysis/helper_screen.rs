@@ -0,0 +1,87 @@
+//! Contamination screen of a project sequence against helper genomes.
+//!
+//! Each requested helper (common vector backbones, host genomes) is searched
+//! with the same BLAST path as `blast_helper_genome`, including the project's
+//! BLAST option layers; hits with an e-value above
+//! `HELPER_SCREEN_MAX_EVALUE` are dropped as not significant.
+
+use super::*;
+
+/// Largest e-value still reported as a helper-genome hit.
+const HELPER_SCREEN_MAX_EVALUE: f64 = 1e-10;
+
+impl GentleEngine {
+    /// BLAST `seq_id` against every helper in `genome_ids` (default helper
+    /// catalog/cache) and return the significant hits, grouped by helper in
+    /// request order and sorted by descending bit score within a helper.
+    pub fn screen_against_helper_genomes(
+        &self,
+        seq_id: &str,
+        genome_ids: &[String],
+    ) -> Result<Vec<HelperScreenHit>, EngineError> {
+        self.screen_against_helper_genomes_with(seq_id, genome_ids, |genome_id, query| {
+            self.blast_helper_genome_with_project_and_request_options(
+                genome_id, query, None, None, None, None, None,
+            )
+        })
+    }
+
+    /// `screen_against_helper_genomes` with the BLAST search injected, so the
+    /// screening rules can be exercised without a prepared BLAST database.
+    pub(crate) fn screen_against_helper_genomes_with(
+        &self,
+        seq_id: &str,
+        genome_ids: &[String],
+        mut search: impl FnMut(&str, &str) -> Result<GenomeBlastReport, EngineError>,
+    ) -> Result<Vec<HelperScreenHit>, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+
+                cause_chain: vec![],
+            })?;
+        let genome_ids = genome_ids
+            .iter()
+            .map(|id| id.trim())
+            .filter(|id| !id.is_empty())
+            .collect::<Vec<_>>();
+        if genome_ids.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Helper screen requires at least one helper genome id".to_string(),
+
+                cause_chain: vec![],
+            });
+        }
+        let query = dna.get_forward_string();
+        let mut out = vec![];
+        for genome_id in genome_ids {
+            let report = search(genome_id, &query)?;
+            let mut hits = report
+                .hits
+                .into_iter()
+                .filter(|hit| hit.evalue <= HELPER_SCREEN_MAX_EVALUE)
+                .map(|hit| HelperScreenHit {
+                    genome_id: genome_id.to_string(),
+                    subject_id: hit.subject_id,
+                    query_start_1based: hit.query_start,
+                    query_end_1based: hit.query_end,
+                    subject_start_1based: hit.subject_start,
+                    subject_end_1based: hit.subject_end,
+                    alignment_length: hit.alignment_length,
+                    identity_percent: hit.identity_percent,
+                    bit_score: hit.bit_score,
+                    evalue: hit.evalue,
+                    query_coverage_percent: hit.query_coverage_percent,
+                })
+                .collect::<Vec<_>>();
+            hits.sort_by(|a, b| b.bit_score.total_cmp(&a.bit_score));
+            out.extend(hits);
+        }
+        Ok(out)
+    }
+}
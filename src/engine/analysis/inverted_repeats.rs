@@ -0,0 +1,205 @@
+//! Inverted-repeat (stem-loop) scanning.
+//!
+//! `find_inverted_repeats` looks for reverse-complementary stems separated by
+//! a loop of at most `max_loop` bases, the cruciform/hairpin candidates that
+//! can destabilize inserts. Stems may carry up to `max_mismatches` unpaired
+//! positions but always start and end on a paired base. Candidates sharing
+//! the same outer span are collapsed to the longest stem.
+//! `AnnotateInvertedRepeats` materializes the hits as generated
+//! `misc_feature`s.
+
+use super::*;
+
+impl GentleEngine {
+    fn inverted_repeat_bases_pair(left: u8, right: u8) -> bool {
+        matches!(
+            (left.to_ascii_uppercase(), right.to_ascii_uppercase()),
+            (b'A', b'T') | (b'T', b'A') | (b'C', b'G') | (b'G', b'C')
+        )
+    }
+
+    pub(super) fn scan_inverted_repeats(
+        bases: &[u8],
+        min_stem: usize,
+        max_loop: usize,
+        max_mismatches: usize,
+    ) -> Vec<InvertedRepeat> {
+        let n = bases.len();
+        // Outer span (left start, right end exclusive) -> best candidate.
+        let mut best: BTreeMap<(usize, usize), InvertedRepeat> = BTreeMap::new();
+        for loop_len in 0..=max_loop {
+            // `loop_start` is the first loop base; the innermost pair is
+            // (loop_start - 1, loop_start + loop_len).
+            for loop_start in 1..n.saturating_sub(loop_len) {
+                let inner_left = loop_start - 1;
+                let inner_right = loop_start + loop_len;
+                if !Self::inverted_repeat_bases_pair(bases[inner_left], bases[inner_right]) {
+                    continue;
+                }
+                let mut stem = 0usize;
+                let mut mismatches = 0usize;
+                let mut paired_stem = 0usize;
+                let mut paired_mismatches = 0usize;
+                while stem <= inner_left && inner_right + stem < n {
+                    if Self::inverted_repeat_bases_pair(
+                        bases[inner_left - stem],
+                        bases[inner_right + stem],
+                    ) {
+                        stem += 1;
+                        paired_stem = stem;
+                        paired_mismatches = mismatches;
+                    } else if mismatches < max_mismatches {
+                        stem += 1;
+                        mismatches += 1;
+                    } else {
+                        break;
+                    }
+                }
+                if paired_stem < min_stem {
+                    continue;
+                }
+                let left_start = loop_start - paired_stem;
+                let right_end = inner_right + paired_stem;
+                let candidate = InvertedRepeat {
+                    left_stem_start_1based: left_start + 1,
+                    left_stem_end_1based: loop_start,
+                    right_stem_start_1based: inner_right + 1,
+                    right_stem_end_1based: right_end,
+                    stem_length_bp: paired_stem,
+                    loop_length_bp: loop_len,
+                    mismatches: paired_mismatches,
+                };
+                let replace = match best.get(&(left_start, right_end)) {
+                    Some(current) => {
+                        (candidate.stem_length_bp, current.mismatches)
+                            > (current.stem_length_bp, candidate.mismatches)
+                    }
+                    None => true,
+                };
+                if replace {
+                    best.insert((left_start, right_end), candidate);
+                }
+            }
+        }
+        best.into_values().collect()
+    }
+
+    pub fn find_inverted_repeats(
+        &self,
+        seq_id: &str,
+        min_stem: usize,
+        max_loop: usize,
+        max_mismatches: usize,
+    ) -> Result<Vec<InvertedRepeat>, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        if min_stem == 0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Inverted repeat scan requires min_stem >= 1".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        Ok(Self::scan_inverted_repeats(
+            dna.forward_bytes(),
+            min_stem,
+            max_loop,
+            max_mismatches,
+        ))
+    }
+
+    fn is_generated_inverted_repeat_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("inverted_repeat"))
+    }
+
+    fn build_inverted_repeat_feature(repeat: &InvertedRepeat) -> gb_io::seq::Feature {
+        gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location: gb_io::seq::Location::simple_range(
+                repeat.left_stem_start_1based as i64 - 1,
+                repeat.right_stem_end_1based as i64,
+            ),
+            qualifiers: vec![
+                (
+                    "label".into(),
+                    Some(format!(
+                        "IR stem {} bp, loop {} bp",
+                        repeat.stem_length_bp, repeat.loop_length_bp
+                    )),
+                ),
+                (
+                    "left_stem".into(),
+                    Some(format!(
+                        "{}..{}",
+                        repeat.left_stem_start_1based, repeat.left_stem_end_1based
+                    )),
+                ),
+                (
+                    "right_stem".into(),
+                    Some(format!(
+                        "{}..{}",
+                        repeat.right_stem_start_1based, repeat.right_stem_end_1based
+                    )),
+                ),
+                (
+                    "stem_length_bp".into(),
+                    Some(repeat.stem_length_bp.to_string()),
+                ),
+                (
+                    "loop_length_bp".into(),
+                    Some(repeat.loop_length_bp.to_string()),
+                ),
+                ("mismatches".into(), Some(repeat.mismatches.to_string())),
+                (
+                    "gentle_generated".into(),
+                    Some("inverted_repeat".to_string()),
+                ),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_inverted_repeats(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        min_stem: usize,
+        max_loop: usize,
+        max_mismatches: usize,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        let repeats = self.find_inverted_repeats(seq_id, min_stem, max_loop, max_mismatches)?;
+        let _ = self.ensure_lineage_node(seq_id);
+        let Some(dna) = self.state.sequences.get_mut(seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_inverted_repeat_feature(feature));
+        }
+        dna.features_mut()
+            .extend(repeats.iter().map(Self::build_inverted_repeat_feature));
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Annotated {} inverted repeat(s) on '{}' (min_stem={}, max_loop={}, max_mismatches={})",
+            repeats.len(),
+            seq_id,
+            min_stem,
+            max_loop,
+            max_mismatches
+        ));
+        Ok(())
+    }
+}
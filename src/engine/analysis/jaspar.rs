@@ -62,19 +62,14 @@ impl GentleEngine {
 
     pub(crate) fn deterministic_random_dna_bytes(length_bp: usize, seed: u64) -> Vec<u8> {
         const BASES: [u8; 4] = [b'A', b'C', b'G', b'T'];
-        let mut state = if seed == 0 {
+        let mut rng = SeededRng::new(if seed == 0 {
             DEFAULT_JASPAR_PRESENTATION_RANDOM_SEED
         } else {
             seed
-        };
-        let mut out = Vec::with_capacity(length_bp);
-        for _ in 0..length_bp {
-            state = state
-                .wrapping_mul(6364136223846793005)
-                .wrapping_add(1442695040888963407);
-            out.push(BASES[((state >> 62) & 0b11) as usize]);
-        }
-        out
+        });
+        (0..length_bp)
+            .map(|_| BASES[(rng.next_u64() >> 62) as usize])
+            .collect()
     }
 
     pub(crate) fn scan_single_jaspar_scores(
@@ -0,0 +1,65 @@
+//! Pairwise end-compatibility matrix for ligation planning.
+//!
+//! Uses the same end chemistry as `Ligation`: the right end of one fragment
+//! joins the left end of the next when both are blunt or their overhangs
+//! anneal (`SequenceEnd::ligates_to`), unless both 5' termini at the junction
+//! are dephosphorylated. Circular inputs have no free ends and are rejected.
+
+use super::*;
+
+impl GentleEngine {
+    /// Which fragment ends in `seq_ids` can be ligated to each other.
+    pub fn ligation_compatibility(&self, seq_ids: &[SeqId]) -> Result<LigationMatrix, EngineError> {
+        if seq_ids.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Ligation compatibility requires at least one sequence".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let mut ends = Vec::with_capacity(seq_ids.len());
+        for seq_id in seq_ids {
+            let dna = self
+                .state
+                .sequences
+                .get(seq_id)
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::NotFound,
+                    message: format!("Sequence '{seq_id}' not found"),
+                    cause_chain: vec![],
+                })?;
+            if dna.is_circular() {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("Sequence '{seq_id}' is circular and has no free ends"),
+                    cause_chain: vec![],
+                });
+            }
+            ends.push(dna.ends());
+        }
+
+        let mut compatible = vec![vec![false; seq_ids.len()]; seq_ids.len()];
+        let mut junctions = vec![];
+        for (i, left) in ends.iter().enumerate() {
+            for (j, right) in ends.iter().enumerate() {
+                if !left.right.ligates_to(&right.left)
+                    || !left.right.has_junction_phosphate(&right.left)
+                {
+                    continue;
+                }
+                compatible[i][j] = true;
+                junctions.push(Self::ligation_junction(
+                    &seq_ids[i],
+                    &left.right,
+                    &seq_ids[j],
+                    &right.left,
+                ));
+            }
+        }
+        Ok(LigationMatrix {
+            seq_ids: seq_ids.to_vec(),
+            compatible,
+            junctions,
+        })
+    }
+}
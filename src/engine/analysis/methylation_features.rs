@@ -0,0 +1,121 @@
+//! Methylation sites as sequence features.
+//!
+//! `AnnotateMethylationSites` writes one generated `modified_base` feature per
+//! methylated top-strand base for the Dam/Dcm/CpG classes enabled in the given
+//! `MethylationMode`. Sites are keyed by their methylated base, so classes that
+//! would mark the same base share one feature (listing both classes) and
+//! re-running without `clear_existing` does not duplicate bases that already
+//! carry a generated methylation feature.
+
+use super::*;
+use crate::methylation_sites::{MethylationSite, MethylationSites};
+
+impl GentleEngine {
+    fn is_generated_methylation_site_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("methylation_site"))
+    }
+
+    fn build_methylation_site_feature(
+        base: usize,
+        sites: &[MethylationSite],
+        mode: &MethylationMode,
+    ) -> gb_io::seq::Feature {
+        let kinds = sites
+            .iter()
+            .map(|site| site.kind.as_str())
+            .collect::<Vec<_>>()
+            .join("+");
+        let motifs = sites
+            .iter()
+            .map(|site| {
+                format!(
+                    "{} site {} at {}..{}",
+                    site.kind.as_str(),
+                    site.kind.motif(),
+                    site.motif_start + 1,
+                    site.motif_end
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        gb_io::seq::Feature {
+            kind: "modified_base".into(),
+            location: gb_io::seq::Location::simple_range(base as i64, base as i64 + 1),
+            qualifiers: vec![
+                ("label".into(), Some(format!("{kinds} methylation"))),
+                (
+                    "mod_base".into(),
+                    Some(sites[0].kind.mod_base().to_string()),
+                ),
+                (
+                    "note".into(),
+                    Some(format!("{motifs} (mode={})", mode.describe())),
+                ),
+                (
+                    "gentle_generated".into(),
+                    Some("methylation_site".to_string()),
+                ),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_methylation_sites(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        mode: &MethylationMode,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        if !mode.dam() && !mode.dcm() && !mode.cpg() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "AnnotateMethylationSites requires at least one of dam, dcm, or cpg"
+                    .to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let _ = self.ensure_lineage_node(seq_id);
+        let Some(dna) = self.state.sequences.get_mut(seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_methylation_site_feature(feature));
+        }
+        let existing_bases = dna
+            .features()
+            .iter()
+            .filter(|feature| Self::is_generated_methylation_site_feature(feature))
+            .filter_map(|feature| feature.location.find_bounds().ok())
+            .map(|(from, _)| from as usize)
+            .collect::<HashSet<_>>();
+
+        let mut sites_by_base: BTreeMap<usize, Vec<MethylationSite>> = BTreeMap::new();
+        for site in MethylationSites::find_sites(dna.forward_bytes(), mode) {
+            sites_by_base
+                .entry(site.methylated_base)
+                .or_default()
+                .push(site);
+        }
+        sites_by_base.retain(|base, _| !existing_bases.contains(base));
+        dna.features_mut().extend(
+            sites_by_base
+                .iter()
+                .map(|(base, sites)| Self::build_methylation_site_feature(*base, sites, mode)),
+        );
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Annotated {} methylated base(s) on '{}' (mode={})",
+            sites_by_base.len(),
+            seq_id,
+            mode.describe()
+        ));
+        Ok(())
+    }
+}
@@ -469,6 +469,7 @@ impl GentleEngine {
             &source_features,
             source.transcript_feature_id,
             seq_id,
+            None,
         )?;
         let coding_sequence = Self::extract_ranges_1based_from_sequence(
             &derived_transcript.get_forward_string(),
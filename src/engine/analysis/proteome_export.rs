@@ -0,0 +1,110 @@
+//! Batch CDS translation.
+//!
+//! `ExportProteome` translates every `CDS` feature of one sequence and writes
+//! the proteins as multi-FASTA. Joined locations are spliced in genomic order
+//! and reverse-strand features are reverse-complemented before translation;
+//! `/codon_start` (or a GFF `phase`) is honored. The genetic code is the
+//! explicit override, else the feature's `/transl_table`, else table 1.
+//! Translation is shared with transcript protein derivation; a CDS with a
+//! partial trailing codon, an internal stop or ambiguous codons is still
+//! written (up to the first stop) but raises a `cds_translation_issue`
+//! warning.
+
+use super::*;
+
+impl GentleEngine {
+    /// Spliced coding bases of `feature`, 5'->3' on the coding strand,
+    /// starting at its `/codon_start` (or GFF `phase`) offset.
+    fn cds_coding_bases(bases: &[u8], feature: &gb_io::seq::Feature) -> Vec<u8> {
+        let mut ranges = vec![];
+        collect_location_ranges_usize(&feature.location, &mut ranges);
+        let reverse = feature_is_reverse(feature);
+        if reverse {
+            ranges.sort_unstable();
+        }
+        let mut coding = vec![];
+        for (start, end) in ranges {
+            if start < end && end <= bases.len() {
+                coding.extend_from_slice(&bases[start..end]);
+            }
+        }
+        if reverse {
+            coding = Self::reverse_complement_bytes(&coding);
+        }
+        let offset = Self::construct_reasoning_feature_codon_start_offset(feature);
+        coding.drain(..offset.min(coding.len()));
+        coding
+    }
+
+    pub(super) fn export_proteome(
+        &self,
+        seq_id: &str,
+        path: &str,
+        translation_table: Option<usize>,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let bases = dna.forward_bytes();
+        let mut fasta = String::new();
+        let mut written = 0usize;
+        for (feature_id, feature) in dna.features().iter().enumerate() {
+            if !feature.kind.to_string().eq_ignore_ascii_case("CDS") {
+                continue;
+            }
+            let header = ["label", "gene", "locus_tag"]
+                .iter()
+                .find_map(|key| Self::feature_qualifier_text(feature, key))
+                .map(|value| value.trim().replace(char::is_whitespace, "_"))
+                .filter(|value| !value.is_empty())
+                .unwrap_or_else(|| format!("{seq_id}_cds_{feature_id}"));
+            let translation_table = translation_table
+                .unwrap_or_else(|| Self::construct_reasoning_feature_translation_table(feature));
+            let coding = Self::cds_coding_bases(bases, feature);
+            let (mut protein, _, issues) = Self::translate_cds_bytes(&coding, translation_table);
+            if let Some(stop) = protein.find('*') {
+                protein.truncate(stop);
+            }
+            for issue in issues {
+                result.warnings.push(
+                    Warning::new(
+                        WarningCode::CdsTranslationIssue,
+                        format!("CDS '{header}' (feature {feature_id}) on '{seq_id}': {issue}"),
+                    )
+                    .with_context(json!({
+                        "seq_id": seq_id,
+                        "feature_id": feature_id,
+                        "label": header,
+                    })),
+                );
+            }
+            fasta.push('>');
+            fasta.push_str(&header);
+            fasta.push('\n');
+            for chunk in protein.as_bytes().chunks(80) {
+                fasta.push_str(&String::from_utf8_lossy(chunk));
+                fasta.push('\n');
+            }
+            written += 1;
+        }
+        if written == 0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Sequence '{seq_id}' has no CDS features to translate"),
+                cause_chain: vec![],
+            });
+        }
+        self.write_text_file(path, &fasta, "proteome FASTA")?;
+        result.messages.push(format!(
+            "Wrote {written} protein(s) translated from '{seq_id}' to '{path}'"
+        ));
+        Ok(())
+    }
+}
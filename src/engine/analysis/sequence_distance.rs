@@ -0,0 +1,91 @@
+//! Edit distances between two project sequences.
+//!
+//! `sequence_distance` compares forward strands case-insensitively, base by
+//! base (no IUPAC expansion). Levenshtein distance uses a diagonal band of
+//! width `2 * max + 1` over two rows sized by the shorter sequence, so the
+//! cost is `O(len * max)` time and `O(len)` memory even for long sequences.
+
+use super::*;
+
+impl GentleEngine {
+    /// Levenshtein distance of `a` and `b`, or `max + 1` when it exceeds `max`.
+    fn bounded_levenshtein(a: &[u8], b: &[u8], max: usize) -> usize {
+        let cap = max.saturating_add(1);
+        let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        if long.len() - short.len() > max {
+            return cap;
+        }
+        let m = short.len();
+        let mut prev = (0..=m).map(|j| j.min(cap)).collect::<Vec<_>>();
+        let mut cur = vec![cap; m + 1];
+        for (i, long_base) in long.iter().enumerate().map(|(i, base)| (i + 1, base)) {
+            let lo = i.saturating_sub(max).max(1);
+            let hi = i.saturating_add(max).min(m);
+            cur[0] = i.min(cap);
+            if lo > 1 {
+                cur[lo - 1] = cap;
+            }
+            let mut row_min = if lo == 1 { cur[0] } else { cap };
+            for j in lo..=hi {
+                let substitution =
+                    prev[j - 1].saturating_add(usize::from(*long_base != short[j - 1]));
+                let value = substitution
+                    .min(prev[j].saturating_add(1))
+                    .min(cur[j - 1].saturating_add(1))
+                    .min(cap);
+                cur[j] = value;
+                row_min = row_min.min(value);
+            }
+            // The next row's band reaches one column further right.
+            if hi < m {
+                cur[hi + 1] = cap;
+            }
+            if row_min >= cap {
+                return cap;
+            }
+            std::mem::swap(&mut prev, &mut cur);
+        }
+        prev[m].min(cap)
+    }
+
+    /// Distance between sequences `a` and `b` under `metric`. Read-only.
+    pub fn sequence_distance(
+        &self,
+        a: &str,
+        b: &str,
+        metric: DistanceMetric,
+    ) -> Result<usize, EngineError> {
+        let bases = |seq_id: &str| {
+            self.state
+                .sequences
+                .get(seq_id)
+                .map(|dna| dna.forward_bytes().to_ascii_uppercase())
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::NotFound,
+                    message: format!("Sequence '{seq_id}' not found"),
+                    cause_chain: vec![],
+                })
+        };
+        let left = bases(a)?;
+        let right = bases(b)?;
+        match metric {
+            DistanceMetric::Hamming => {
+                if left.len() != right.len() {
+                    return Err(EngineError {
+                        code: ErrorCode::InvalidInput,
+                        message: format!(
+                            "Hamming distance requires equal lengths, but '{a}' is {} bp and '{b}' is {} bp",
+                            left.len(),
+                            right.len()
+                        ),
+                        cause_chain: vec![],
+                    });
+                }
+                Ok(left.iter().zip(&right).filter(|(x, y)| x != y).count())
+            }
+            DistanceMetric::Levenshtein { max } => {
+                Ok(Self::bounded_levenshtein(&left, &right, max))
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Per-sequence summary statistics.
+//!
+//! `sequence_stats` aggregates existing helpers (canonical-base GC, ORF
+//! detection, unique-cutter restriction rows) into one read-only record;
+//! `ExportSequenceStats` writes that record as JSON.
+
+use super::*;
+
+impl GentleEngine {
+    pub fn sequence_stats(&self, seq_id: &str) -> Result<SequenceStats, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let bases = dna.forward_bytes();
+        let mut base_counts: BTreeMap<String, usize> = BTreeMap::new();
+        for base in bases {
+            *base_counts
+                .entry((base.to_ascii_uppercase() as char).to_string())
+                .or_default() += 1;
+        }
+        let mut feature_counts_by_kind: BTreeMap<String, usize> = BTreeMap::new();
+        for feature in dna.features() {
+            *feature_counts_by_kind
+                .entry(feature.kind.to_string())
+                .or_default() += 1;
+        }
+        // ORFs are computed features; work on a prepared copy so the stored
+        // sequence stays untouched.
+        let mut prepared = dna.clone();
+        Self::prepare_sequence(&mut prepared);
+        let (unique_cutters, _) = self.restriction_map_rows(seq_id, None, true)?;
+        Ok(SequenceStats {
+            seq_id: seq_id.to_string(),
+            length_bp: bases.len(),
+            topology: if dna.is_circular() {
+                "circular"
+            } else {
+                "linear"
+            }
+            .to_string(),
+            gc_percent: Self::sequence_gc_fraction(bases)
+                .map(|fraction| fraction * 100.0)
+                .unwrap_or(0.0),
+            n_count: base_counts.get("N").copied().unwrap_or(0),
+            base_counts,
+            feature_counts_by_kind,
+            orf_count: prepared.open_reading_frames().len(),
+            unique_cutter_count: unique_cutters.len(),
+        })
+    }
+
+    pub(super) fn export_sequence_stats(
+        &self,
+        seq_id: &str,
+        path: &str,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let stats = self.sequence_stats(seq_id)?;
+        self.write_pretty_json_file(&stats, path, "sequence stats")?;
+        result.messages.push(format!(
+            "Wrote sequence stats for '{}' ({} bp, {:.1}% GC, {} feature(s)) to '{}'",
+            seq_id,
+            stats.length_bp,
+            stats.gc_percent,
+            stats.feature_counts_by_kind.values().sum::<usize>(),
+            path
+        ));
+        Ok(())
+    }
+}
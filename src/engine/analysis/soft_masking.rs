@@ -0,0 +1,68 @@
+//! Soft-masked (lowercase) region annotation.
+//!
+//! Genomic FASTA marks repeat-masked bases in lowercase. `LoadFile` keeps that
+//! case unless `uppercase=true`; `AnnotateSoftMaskedRegions` then
+//! materializes each lowercase run as a generated `misc_feature`. Scanning and
+//! matching stay case-insensitive either way.
+
+use super::*;
+
+impl GentleEngine {
+    fn is_generated_soft_masked_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("soft_masked_region"))
+    }
+
+    fn build_soft_masked_feature(range: &std::ops::Range<usize>) -> gb_io::seq::Feature {
+        gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location: gb_io::seq::Location::simple_range(range.start as i64, range.end as i64),
+            qualifiers: vec![
+                (
+                    "label".into(),
+                    Some(format!("soft-masked {} bp", range.len())),
+                ),
+                (
+                    "note".into(),
+                    Some("soft-masked (lowercase) bases".to_string()),
+                ),
+                (
+                    "gentle_generated".into(),
+                    Some("soft_masked_region".to_string()),
+                ),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_soft_masked_regions(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        let _ = self.ensure_lineage_node(seq_id);
+        let Some(dna) = self.state.sequences.get_mut(seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        let ranges = dna.soft_masked_ranges();
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_soft_masked_feature(feature));
+        }
+        dna.features_mut()
+            .extend(ranges.iter().map(Self::build_soft_masked_feature));
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Annotated {} soft-masked region(s) ({} bp) on '{}'",
+            ranges.len(),
+            ranges.iter().map(|range| range.len()).sum::<usize>(),
+            seq_id
+        ));
+        Ok(())
+    }
+}
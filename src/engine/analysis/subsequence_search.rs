@@ -0,0 +1,258 @@
+//! Project-wide probe search with mismatches.
+//!
+//! `search_subsequence` scans every project sequence for an IUPAC query,
+//! allowing up to `max_mismatches` substitutions, optionally on both strands.
+//! Queries up to 64 bases use a bit-parallel shift-add scan (one pass per
+//! strand, `O(n * (max_mismatches + 1))` word operations); longer queries fall
+//! back to a position-wise scan with early exit. Circular sequences also
+//! report hits spanning the origin. `AnnotateSearchHits` materializes the hits
+//! as generated `misc_binding` features.
+
+use super::*;
+
+impl GentleEngine {
+    /// Per-text-byte bitmask of the query positions that byte is compatible
+    /// with under IUPAC subset semantics.
+    fn subsequence_match_masks(pattern: &[u8]) -> [u64; 256] {
+        let mut masks = [0u64; 256];
+        for (byte, mask) in masks.iter_mut().enumerate() {
+            let text = IupacCode::from_letter(byte as u8);
+            if text.is_empty() {
+                continue;
+            }
+            for (idx, letter) in pattern.iter().enumerate() {
+                if !text.subset(IupacCode::from_letter(*letter)).is_empty() {
+                    *mask |= 1u64 << idx;
+                }
+            }
+        }
+        masks
+    }
+
+    /// `(start, mismatches)` for every window of `text` matching `pattern`
+    /// with at most `max_mismatches` substitutions.
+    pub(super) fn scan_subsequence_with_mismatches(
+        text: &[u8],
+        pattern: &[u8],
+        max_mismatches: usize,
+    ) -> Vec<(usize, usize)> {
+        let m = pattern.len();
+        let mut hits = vec![];
+        if m == 0 || text.len() < m {
+            return hits;
+        }
+        let max_mismatches = max_mismatches.min(m);
+        if m <= 64 {
+            let masks = Self::subsequence_match_masks(pattern);
+            let accept = 1u64 << (m - 1);
+            // rows[j]: bit i set when pattern[..=i] ends here with <= j mismatches.
+            let mut rows = vec![0u64; max_mismatches + 1];
+            for (pos, byte) in text.iter().enumerate() {
+                let mask = masks[*byte as usize];
+                let mut previous = rows[0];
+                rows[0] = ((rows[0] << 1) | 1) & mask;
+                for row in rows.iter_mut().skip(1) {
+                    let current = *row;
+                    *row = (((current << 1) | 1) & mask) | ((previous << 1) | 1);
+                    previous = current;
+                }
+                if pos + 1 >= m
+                    && let Some(mismatches) = rows.iter().position(|row| row & accept != 0)
+                {
+                    hits.push((pos + 1 - m, mismatches));
+                }
+            }
+        } else {
+            let codes = pattern
+                .iter()
+                .map(|letter| IupacCode::from_letter(*letter))
+                .collect::<Vec<_>>();
+            for start in 0..=text.len() - m {
+                let mut mismatches = 0usize;
+                for (offset, code) in codes.iter().enumerate() {
+                    if IupacCode::from_letter(text[start + offset])
+                        .subset(*code)
+                        .is_empty()
+                    {
+                        mismatches += 1;
+                        if mismatches > max_mismatches {
+                            break;
+                        }
+                    }
+                }
+                if mismatches <= max_mismatches {
+                    hits.push((start, mismatches));
+                }
+            }
+        }
+        hits
+    }
+
+    /// Find every binding site of an IUPAC `query` across all project
+    /// sequences with at most `max_mismatches` substitutions.
+    ///
+    /// Reverse-strand hits (`both_strands`) match the query's reverse
+    /// complement and are reported in forward coordinates with strand `-`;
+    /// palindromic queries are scanned once. Hits are sorted by sequence id,
+    /// start, and strand. An empty or non-IUPAC query yields no hits.
+    pub fn search_subsequence(
+        &self,
+        query: &str,
+        max_mismatches: usize,
+        both_strands: bool,
+    ) -> Vec<SubsequenceHit> {
+        let query = query.trim().to_ascii_uppercase().into_bytes();
+        if query.is_empty() || !query.iter().all(|b| IupacCode::is_valid_letter(*b)) {
+            return vec![];
+        }
+        let reverse_query = query
+            .iter()
+            .rev()
+            .map(|b| IupacCode::letter_complement(*b))
+            .collect::<Vec<_>>();
+        let mut patterns = vec![("+", query.clone())];
+        if both_strands && reverse_query != query {
+            patterns.push(("-", reverse_query));
+        }
+
+        let mut seq_ids = self.state.sequences.keys().collect::<Vec<_>>();
+        seq_ids.sort();
+        let mut hits = vec![];
+        for seq_id in seq_ids {
+            let dna = &self.state.sequences[seq_id];
+            let bases = dna.forward_bytes();
+            let len = bases.len();
+            if len == 0 {
+                continue;
+            }
+            // Circular sequences scan across the origin once.
+            let text = if dna.is_circular() && query.len() > 1 {
+                let wrap = (query.len() - 1).min(len);
+                let mut text = bases.to_vec();
+                text.extend_from_slice(&bases[..wrap]);
+                std::borrow::Cow::Owned(text)
+            } else {
+                std::borrow::Cow::Borrowed(bases)
+            };
+            for (strand, pattern) in &patterns {
+                for (start, mismatches) in
+                    Self::scan_subsequence_with_mismatches(&text, pattern, max_mismatches)
+                {
+                    if start >= len {
+                        continue;
+                    }
+                    hits.push(SubsequenceHit {
+                        seq_id: seq_id.clone(),
+                        start_1based: start + 1,
+                        end_1based: (start + pattern.len() - 1) % len + 1,
+                        strand: strand.to_string(),
+                        mismatches,
+                    });
+                }
+            }
+        }
+        hits.sort_by(|a, b| {
+            (&a.seq_id, a.start_1based, &a.strand).cmp(&(&b.seq_id, b.start_1based, &b.strand))
+        });
+        hits
+    }
+
+    fn is_generated_search_hit_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("search_hit"))
+    }
+
+    fn build_search_hit_feature(
+        hit: &SubsequenceHit,
+        query: &str,
+        sequence_len: usize,
+    ) -> gb_io::seq::Feature {
+        let start = hit.start_1based as i64 - 1;
+        let end = hit.end_1based as i64;
+        let mut location = if hit.end_1based >= hit.start_1based {
+            gb_io::seq::Location::simple_range(start, end)
+        } else {
+            gb_io::seq::Location::Join(vec![
+                gb_io::seq::Location::simple_range(start, sequence_len as i64),
+                gb_io::seq::Location::simple_range(0, end),
+            ])
+        };
+        if hit.strand == "-" {
+            location = gb_io::seq::Location::Complement(Box::new(location));
+        }
+        gb_io::seq::Feature {
+            kind: "misc_binding".into(),
+            location,
+            qualifiers: vec![
+                (
+                    "label".into(),
+                    Some(format!("{query} ({} mm)", hit.mismatches)),
+                ),
+                ("query".into(), Some(query.to_string())),
+                ("mismatches".into(), Some(hit.mismatches.to_string())),
+                ("gentle_generated".into(), Some("search_hit".to_string())),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_search_hits(
+        &mut self,
+        result: &mut OpResult,
+        query: &str,
+        max_mismatches: usize,
+        both_strands: bool,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        let normalized = query.trim().to_ascii_uppercase();
+        if normalized.is_empty() || !normalized.bytes().all(IupacCode::is_valid_letter) {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "AnnotateSearchHits query '{query}' must be a non-empty IUPAC DNA sequence"
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let hits = self.search_subsequence(&normalized, max_mismatches, both_strands);
+        let mut hits_by_seq: BTreeMap<SeqId, Vec<SubsequenceHit>> = BTreeMap::new();
+        for hit in hits {
+            hits_by_seq.entry(hit.seq_id.clone()).or_default().push(hit);
+        }
+        let mut seq_ids = self.state.sequences.keys().cloned().collect::<Vec<_>>();
+        seq_ids.sort();
+        let mut total = 0usize;
+        for seq_id in seq_ids {
+            let seq_hits = hits_by_seq.remove(&seq_id).unwrap_or_default();
+            let Some(dna) = self.state.sequences.get_mut(&seq_id) else {
+                continue;
+            };
+            let before = dna.features().len();
+            if clear_existing {
+                dna.features_mut()
+                    .retain(|feature| !Self::is_generated_search_hit_feature(feature));
+            }
+            if seq_hits.is_empty() && dna.features().len() == before {
+                continue;
+            }
+            let sequence_len = dna.len();
+            dna.features_mut().extend(
+                seq_hits
+                    .iter()
+                    .map(|hit| Self::build_search_hit_feature(hit, &normalized, sequence_len)),
+            );
+            total += seq_hits.len();
+            let _ = self.ensure_lineage_node(&seq_id);
+            result.changed_seq_ids.push(seq_id);
+        }
+        result.messages.push(format!(
+            "Annotated {} search hit(s) for '{}' on {} sequence(s) (max_mismatches={}, both_strands={})",
+            total,
+            normalized,
+            result.changed_seq_ids.len(),
+            max_mismatches,
+            both_strands
+        ));
+        Ok(())
+    }
+}
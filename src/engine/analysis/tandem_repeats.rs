@@ -0,0 +1,163 @@
+//! Short tandem repeat (microsatellite) scanning.
+//!
+//! `find_tandem_repeats` generalizes the dinucleotide repeat counter used for
+//! guide filtering to any unit length in `min_unit..=max_unit`. Only
+//! primitive units are reported, so `(CAG)n` is not also listed as
+//! `(CAGCAG)n`, and a repeat is reported once from its first full copy
+//! rather than once per phase. `AnnotateTandemRepeats` materializes the hits
+//! as generated `repeat_region` features.
+
+use super::*;
+
+impl GentleEngine {
+    /// True when `unit` is not itself a repeat of a shorter unit.
+    fn tandem_repeat_unit_is_primitive(unit: &[u8]) -> bool {
+        (1..unit.len()).all(|period| {
+            !unit.len().is_multiple_of(period)
+                || unit
+                    .chunks(period)
+                    .any(|chunk| !chunk.eq_ignore_ascii_case(&unit[..period]))
+        })
+    }
+
+    pub(super) fn scan_tandem_repeats(
+        bases: &[u8],
+        min_unit: usize,
+        max_unit: usize,
+        min_copies: usize,
+    ) -> Vec<TandemRepeat> {
+        let mut repeats = vec![];
+        for unit_len in min_unit..=max_unit {
+            let mut start = 0usize;
+            while start + unit_len <= bases.len() {
+                let copies = Self::tandem_repeat_units_at(bases, start, unit_len);
+                let unit = &bases[start..start + unit_len];
+                if copies >= min_copies && Self::tandem_repeat_unit_is_primitive(unit) {
+                    let end = start + copies * unit_len;
+                    repeats.push(TandemRepeat {
+                        unit: String::from_utf8_lossy(unit).to_ascii_uppercase(),
+                        unit_length_bp: unit_len,
+                        copies,
+                        start_1based: start + 1,
+                        end_1based: end,
+                    });
+                    // Skip the phase-shifted copies of the same repeat.
+                    start = end - unit_len + 1;
+                } else {
+                    start += 1;
+                }
+            }
+        }
+        repeats.sort_by_key(|repeat| (repeat.start_1based, repeat.unit_length_bp));
+        repeats
+    }
+
+    pub fn find_tandem_repeats(
+        &self,
+        seq_id: &str,
+        min_unit: usize,
+        max_unit: usize,
+        min_copies: usize,
+    ) -> Result<Vec<TandemRepeat>, EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        if min_unit == 0 || max_unit < min_unit {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Tandem repeat scan requires 1 <= min_unit <= max_unit, got {min_unit}..{max_unit}"
+                ),
+                cause_chain: vec![],
+            });
+        }
+        if min_copies < 2 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Tandem repeat scan requires min_copies >= 2, got {min_copies}"),
+                cause_chain: vec![],
+            });
+        }
+        Ok(Self::scan_tandem_repeats(
+            dna.forward_bytes(),
+            min_unit,
+            max_unit,
+            min_copies,
+        ))
+    }
+
+    fn is_generated_tandem_repeat_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("tandem_repeat"))
+    }
+
+    fn build_tandem_repeat_feature(repeat: &TandemRepeat) -> gb_io::seq::Feature {
+        gb_io::seq::Feature {
+            kind: "repeat_region".into(),
+            location: gb_io::seq::Location::simple_range(
+                repeat.start_1based as i64 - 1,
+                repeat.end_1based as i64,
+            ),
+            qualifiers: vec![
+                (
+                    "label".into(),
+                    Some(format!("({}){}", repeat.unit, repeat.copies)),
+                ),
+                ("rpt_type".into(), Some("tandem".to_string())),
+                (
+                    "rpt_unit_seq".into(),
+                    Some(repeat.unit.to_ascii_lowercase()),
+                ),
+                (
+                    "unit_length_bp".into(),
+                    Some(repeat.unit_length_bp.to_string()),
+                ),
+                ("copies".into(), Some(repeat.copies.to_string())),
+                ("gentle_generated".into(), Some("tandem_repeat".to_string())),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_tandem_repeats(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        min_unit: usize,
+        max_unit: usize,
+        min_copies: usize,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        let repeats = self.find_tandem_repeats(seq_id, min_unit, max_unit, min_copies)?;
+        let _ = self.ensure_lineage_node(seq_id);
+        let Some(dna) = self.state.sequences.get_mut(seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_tandem_repeat_feature(feature));
+        }
+        dna.features_mut()
+            .extend(repeats.iter().map(Self::build_tandem_repeat_feature));
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Annotated {} tandem repeat(s) on '{}' (unit {}..{} bp, min_copies={})",
+            repeats.len(),
+            seq_id,
+            min_unit,
+            max_unit,
+            min_copies
+        ));
+        Ok(())
+    }
+}
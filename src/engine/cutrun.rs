@@ -1335,7 +1335,9 @@ impl GentleEngine {
                         None,
                     )?;
                     report.projected_peak_features = import_report.imported_features;
-                    report.warnings.extend(import_report.warnings);
+                    report
+                        .warnings
+                        .extend(import_report.warnings.into_iter().map(String::from));
                     cleared_once |= clear_existing;
                     projected_any |=
                         import_report.imported_features > 0 || import_report.parsed_records > 0;
@@ -1359,7 +1361,9 @@ impl GentleEngine {
                         None,
                     )?;
                     report.projected_signal_features = import_report.imported_features;
-                    report.warnings.extend(import_report.warnings);
+                    report
+                        .warnings
+                        .extend(import_report.warnings.into_iter().map(String::from));
                     projected_any |=
                         import_report.imported_features > 0 || import_report.parsed_records > 0;
                 } else {
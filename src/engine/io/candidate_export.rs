@@ -0,0 +1,67 @@
+//! Stream persisted candidate sets to JSONL files.
+//!
+//! Records are read one at a time from the candidate-set metadata and written
+//! through a `BufWriter` in the project sidecar record format, so exporting a
+//! very large set never materializes a second copy of the whole store.
+
+use super::*;
+
+impl GentleEngine {
+    pub(super) fn op_stream_candidate_set_jsonl(
+        &self,
+        set_name: String,
+        path: String,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let set_name = Self::normalize_candidate_set_name(&set_name)?;
+        let candidates = self
+            .state
+            .metadata
+            .get(CANDIDATE_SETS_METADATA_KEY)
+            .and_then(|store| store.get("sets"))
+            .and_then(|sets| sets.get(&set_name))
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Candidate set '{}' not found", set_name),
+
+                cause_chain: vec![],
+            })?
+            .get("candidates")
+            .and_then(|candidates| candidates.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let file = File::create(&path).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not create candidate JSONL file '{path}': {e}"),
+
+            cause_chain: vec![],
+        })?;
+        let mut writer = BufWriter::new(file);
+        for (idx, value) in candidates.iter().enumerate() {
+            let candidate = CandidateRecord::deserialize(value).map_err(|e| EngineError {
+                code: ErrorCode::Internal,
+                message: format!(
+                    "Could not read candidate {} of set '{}': {e}",
+                    idx, set_name
+                ),
+
+                cause_chain: vec![],
+            })?;
+            write_candidate_record_jsonl_line(&mut writer, &candidate, &set_name)?;
+        }
+        writer.flush().map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not flush candidate JSONL file '{path}': {e}"),
+
+            cause_chain: vec![],
+        })?;
+        result.messages.push(format!(
+            "Streamed {} candidate(s) from set '{}' to '{}'",
+            candidates.len(),
+            set_name,
+            path
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+//! Import external intervals (BED / CSV) into persisted candidate sets.
+//!
+//! Intervals are resolved against one project sequence; baseline metrics are
+//! computed exactly as for generated windows so imported sets can be scored,
+//! filtered, and ranked with the regular candidate operations.
+
+use super::*;
+
+impl GentleEngine {
+    fn read_candidate_bed_intervals(path: &str) -> Result<Vec<(usize, usize)>, EngineError> {
+        let reader = Self::open_text_reader(path)?;
+        let mut intervals = vec![];
+        for (line_idx, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| EngineError {
+                code: ErrorCode::Io,
+                message: format!("Could not read BED file '{path}': {e}"),
+
+                cause_chain: vec![],
+            })?;
+            let trimmed = line.trim();
+            if trimmed.is_empty()
+                || trimmed.starts_with('#')
+                || trimmed.starts_with("track")
+                || trimmed.starts_with("browser")
+            {
+                continue;
+            }
+            let record = Self::parse_bed_record(trimmed).map_err(|e| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("BED file '{path}' line {}: {e}", line_idx + 1),
+
+                cause_chain: vec![],
+            })?;
+            intervals.push((record.start_0based, record.end_0based));
+        }
+        Ok(intervals)
+    }
+
+    fn read_candidate_csv_intervals(path: &str) -> Result<Vec<(usize, usize)>, EngineError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_path(path)
+            .map_err(|e| EngineError {
+                code: ErrorCode::Io,
+                message: format!("Could not open candidate CSV '{path}': {e}"),
+
+                cause_chain: vec![],
+            })?;
+        let headers = reader
+            .headers()
+            .map_err(|e| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Could not read candidate CSV header '{path}': {e}"),
+
+                cause_chain: vec![],
+            })?
+            .clone();
+        let column = |names: &[&str]| {
+            headers
+                .iter()
+                .position(|header| names.iter().any(|name| header.eq_ignore_ascii_case(name)))
+        };
+        let (Some(start_idx), Some(end_idx)) = (
+            column(&["start_0based", "start"]),
+            column(&["end_0based", "end"]),
+        ) else {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Candidate CSV '{path}' needs 'start_0based' and 'end_0based' (or 'start'/'end') columns"
+                ),
+
+                cause_chain: vec![],
+            });
+        };
+        let mut intervals = vec![];
+        for (row_idx, row) in reader.records().enumerate() {
+            let row = row.map_err(|e| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Candidate CSV '{path}' row {}: {e}", row_idx + 1),
+
+                cause_chain: vec![],
+            })?;
+            let parse = |idx: usize, label: &str| {
+                let raw = row.get(idx).unwrap_or_default();
+                raw.parse::<usize>().map_err(|e| EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "Candidate CSV '{path}' row {}: invalid {label} '{raw}': {e}",
+                        row_idx + 1
+                    ),
+
+                    cause_chain: vec![],
+                })
+            };
+            let start = parse(start_idx, "start")?;
+            let end = parse(end_idx, "end")?;
+            if end <= start {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "Candidate CSV '{path}' row {}: end {end} must be > start {start}",
+                        row_idx + 1
+                    ),
+
+                    cause_chain: vec![],
+                });
+            }
+            intervals.push((start, end));
+        }
+        Ok(intervals)
+    }
+
+    /// Import BED intervals (0-based, end-exclusive) as one candidate set.
+    pub(super) fn op_import_candidate_set_bed(
+        &mut self,
+        set_name: String,
+        path: String,
+        seq_id: SeqId,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let intervals = Self::read_candidate_bed_intervals(&path)?;
+        self.import_candidate_intervals(set_name, &path, seq_id, intervals, result)
+    }
+
+    /// Import CSV rows with `start_0based`/`end_0based` columns as one
+    /// candidate set.
+    pub(super) fn op_import_candidate_set_csv(
+        &mut self,
+        set_name: String,
+        path: String,
+        seq_id: SeqId,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let intervals = Self::read_candidate_csv_intervals(&path)?;
+        self.import_candidate_intervals(set_name, &path, seq_id, intervals, result)
+    }
+
+    fn import_candidate_intervals(
+        &mut self,
+        set_name: String,
+        path: &str,
+        seq_id: SeqId,
+        intervals: Vec<(usize, usize)>,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let set_name = Self::normalize_candidate_set_name(&set_name)?;
+        let dna = self
+            .state
+            .sequences
+            .get(&seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{}' not found", seq_id),
+
+                cause_chain: vec![],
+            })?;
+        let source_len = dna.len();
+        let record_count = intervals.len();
+        let mut skipped_out_of_bounds = 0usize;
+        let mut candidates = vec![];
+        for (start, end) in intervals {
+            let fragment = if end <= source_len {
+                dna.get_range_safe(start..end)
+            } else {
+                None
+            };
+            let Some(fragment) = fragment else {
+                skipped_out_of_bounds += 1;
+                continue;
+            };
+            candidates.push(CandidateRecord {
+                seq_id: seq_id.clone(),
+                start_0based: start,
+                end_0based: end,
+                sequence: String::from_utf8_lossy(&fragment).to_string(),
+                metrics: Self::compute_candidate_metrics(&fragment, start, end, source_len, None),
+            });
+        }
+        if candidates.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "No interval in '{}' lies within sequence '{}' ({} record(s), {} out of bounds)",
+                    path, seq_id, record_count, skipped_out_of_bounds
+                ),
+
+                cause_chain: vec![],
+            });
+        }
+        let imported = candidates.len();
+        let mut store = self.read_candidate_store();
+        let replaced_existing = store
+            .sets
+            .insert(
+                set_name.clone(),
+                CandidateSet {
+                    name: set_name.clone(),
+                    created_at_unix_ms: Self::now_unix_ms(),
+                    source_seq_ids: vec![seq_id.clone()],
+                    candidates,
+                },
+            )
+            .is_some();
+        self.write_candidate_store(store)?;
+        result.messages.push(format!(
+            "Imported candidate set '{}' from '{}' on '{}' ({} candidate(s), {} out-of-bounds record(s) skipped)",
+            set_name, path, seq_id, imported, skipped_out_of_bounds
+        ));
+        if skipped_out_of_bounds > 0 {
+            result.warnings.push(
+                format!(
+                    "Skipped {} record(s) in '{}' outside sequence '{}' (0..{})",
+                    skipped_out_of_bounds, path, seq_id, source_len
+                )
+                .into(),
+            );
+        }
+        if replaced_existing {
+            result
+                .warnings
+                .push(format!("Candidate set '{}' replaced existing set", set_name).into());
+        }
+        Ok(())
+    }
+}
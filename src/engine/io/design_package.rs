@@ -0,0 +1,249 @@
+//! Single-file design handoff package.
+//!
+//! `ExportDesignPackage` collects GenBank files, candidate/guide set CSVs, map
+//! SVGs, and the Markdown provenance report into one stored ZIP. The archive
+//! is assembled in memory, written to a temporary file next to the target, and
+//! only then moved into place, so readers never see a half-written package.
+
+use super::*;
+use crate::engine::zip_store::{StoredZipMember, build_stored_zip_archive};
+
+impl GentleEngine {
+    /// `<dir>/<sanitized stem>.<extension>`, suffixed `_2`, `_3`, ... when two
+    /// ids sanitize to the same member name.
+    fn design_package_member_name(
+        used_names: &mut HashSet<String>,
+        dir: &str,
+        raw_stem: &str,
+        extension: &str,
+    ) -> String {
+        let stem = Self::sanitize_export_file_stem(raw_stem);
+        let mut name = format!("{dir}/{stem}.{extension}");
+        let mut suffix = 2usize;
+        while !used_names.insert(name.to_ascii_lowercase()) {
+            name = format!("{dir}/{stem}_{suffix}.{extension}");
+            suffix += 1;
+        }
+        name
+    }
+
+    fn design_package_candidate_set_csv(set: &CandidateSet) -> String {
+        let metric_names = set
+            .candidates
+            .iter()
+            .flat_map(|candidate| candidate.metrics.keys().cloned())
+            .collect::<BTreeSet<_>>();
+        let mut header = vec![
+            "seq_id".to_string(),
+            "start_0based".to_string(),
+            "end_0based".to_string(),
+            "sequence".to_string(),
+        ];
+        header.extend(metric_names.iter().map(|name| Self::csv_escape(name)));
+        let mut rows = vec![header.join(",")];
+        for candidate in &set.candidates {
+            let mut row = vec![
+                Self::csv_escape(&candidate.seq_id),
+                candidate.start_0based.to_string(),
+                candidate.end_0based.to_string(),
+                Self::csv_escape(&candidate.sequence),
+            ];
+            row.extend(metric_names.iter().map(|name| {
+                candidate
+                    .metrics
+                    .get(name)
+                    .map(|value| value.to_string())
+                    .unwrap_or_default()
+            }));
+            rows.push(row.join(","));
+        }
+        rows.join("\n") + "\n"
+    }
+
+    fn design_package_guide_set_csv(set: &GuideSet) -> String {
+        let mut rows = vec![
+            "guide_id,rank,seq_id,start_0based,end_0based_exclusive,strand,protospacer,pam,nuclease,cut_offset_from_protospacer_start,off_target_score"
+                .to_string(),
+        ];
+        for guide in &set.guides {
+            rows.push(format!(
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                Self::csv_escape(&guide.guide_id),
+                guide.rank.map(|v| v.to_string()).unwrap_or_default(),
+                Self::csv_escape(&guide.seq_id),
+                guide.start_0based,
+                guide.end_0based_exclusive,
+                Self::csv_escape(&guide.strand),
+                Self::csv_escape(&guide.protospacer),
+                Self::csv_escape(&guide.pam),
+                Self::csv_escape(&guide.nuclease),
+                guide.cut_offset_from_protospacer_start,
+                guide
+                    .off_target_score
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+            ));
+        }
+        rows.join("\n") + "\n"
+    }
+
+    fn design_package_members(
+        &self,
+        include: &DesignPackageContents,
+    ) -> Result<Vec<StoredZipMember>, EngineError> {
+        let seq_ids = if include.seq_ids.is_empty() {
+            let mut ids = self.state.sequences.keys().cloned().collect::<Vec<_>>();
+            ids.sort_unstable();
+            ids
+        } else {
+            let mut ids = vec![];
+            for seq_id in &include.seq_ids {
+                if !self.state.sequences.contains_key(seq_id) {
+                    return Err(EngineError {
+                        code: ErrorCode::NotFound,
+                        message: format!("Sequence '{seq_id}' not found"),
+                        cause_chain: vec![],
+                    });
+                }
+                if !ids.contains(seq_id) {
+                    ids.push(seq_id.clone());
+                }
+            }
+            ids
+        };
+
+        let mut members = vec![];
+        let mut used_names = HashSet::new();
+        if include.sequences {
+            for seq_id in &seq_ids {
+                let dna = &self.state.sequences[seq_id];
+                let text = dna.to_genbank_string().map_err(|e| EngineError {
+                    code: ErrorCode::Io,
+                    message: format!("Could not render GenBank for '{seq_id}': {e}"),
+                    cause_chain: vec![],
+                })?;
+                let name =
+                    Self::design_package_member_name(&mut used_names, "sequences", seq_id, "gb");
+                members.push(StoredZipMember::new(name, text));
+            }
+        }
+        if include.candidate_sets {
+            let store = self.read_candidate_store();
+            let mut set_names = store.sets.keys().collect::<Vec<_>>();
+            set_names.sort_unstable();
+            for set_name in set_names {
+                let name = Self::design_package_member_name(
+                    &mut used_names,
+                    "candidates",
+                    set_name,
+                    "csv",
+                );
+                members.push(StoredZipMember::new(
+                    name,
+                    Self::design_package_candidate_set_csv(&store.sets[set_name]),
+                ));
+            }
+        }
+        if include.guide_sets {
+            let store = self.read_guide_design_store();
+            let mut guide_set_ids = store.guide_sets.keys().collect::<Vec<_>>();
+            guide_set_ids.sort_unstable();
+            for guide_set_id in guide_set_ids {
+                let name = Self::design_package_member_name(
+                    &mut used_names,
+                    "guides",
+                    guide_set_id,
+                    "csv",
+                );
+                members.push(StoredZipMember::new(
+                    name,
+                    Self::design_package_guide_set_csv(&store.guide_sets[guide_set_id]),
+                ));
+            }
+        }
+        if include.map_svg {
+            for seq_id in &seq_ids {
+                let dna = &self.state.sequences[seq_id];
+                let svg = if dna.is_circular() {
+                    export_circular_svg(dna, &self.state.display)
+                } else {
+                    export_linear_svg(dna, &self.state.display)
+                };
+                let name = Self::design_package_member_name(&mut used_names, "maps", seq_id, "svg");
+                members.push(StoredZipMember::new(name, svg));
+            }
+        }
+        if include.provenance_report {
+            members.push(StoredZipMember::new(
+                "provenance_report.md",
+                self.format_provenance_report_markdown(),
+            ));
+        }
+        Ok(members)
+    }
+
+    pub(super) fn export_design_package(
+        &self,
+        path: &str,
+        include: &DesignPackageContents,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        if path.trim().is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "ExportDesignPackage requires non-empty path".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let members = self.design_package_members(include)?;
+        if members.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "ExportDesignPackage selection produced no entries".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let bytes = build_stored_zip_archive(&members).map_err(|e| EngineError {
+            code: ErrorCode::Internal,
+            message: format!("Could not build design package: {e}"),
+            cause_chain: vec![],
+        })?;
+
+        Self::ensure_output_parent_dir(path)?;
+        let target = Path::new(path);
+        let parent = target
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let mut tmp = NamedTempFile::new_in(parent).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!(
+                "Could not create temporary design package in '{}': {e}",
+                parent.display()
+            ),
+            cause_chain: vec![],
+        })?;
+        tmp.write_all(&bytes)
+            .and_then(|_| tmp.as_file().sync_all())
+            .map_err(|e| EngineError {
+                code: ErrorCode::Io,
+                message: format!("Could not write temporary design package for '{path}': {e}"),
+                cause_chain: vec![],
+            })?;
+        tmp.persist(target).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not move design package into '{path}': {}", e.error),
+            cause_chain: vec![],
+        })?;
+
+        for member in &members {
+            result.messages.push(format!("Packaged '{}'", member.name));
+        }
+        result.messages.push(format!(
+            "Wrote design package with {} entr{} to '{path}'",
+            members.len(),
+            if members.len() == 1 { "y" } else { "ies" }
+        ));
+        Ok(())
+    }
+}
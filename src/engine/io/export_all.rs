@@ -0,0 +1,136 @@
+//! Whole-project sequence export.
+//!
+//! `ExportAll` writes every project sequence through the same writers as
+//! `SaveFile`. Files are first written into a staging directory inside the
+//! target and only moved into place once every sequence has been written, so
+//! a failing sequence leaves no partial export behind.
+
+use super::*;
+
+const EXPORT_ALL_DEFAULT_TEMPLATE: &str = "{id}";
+
+impl GentleEngine {
+    fn export_all_extension(format: &ExportFormat) -> &'static str {
+        match format {
+            ExportFormat::GenBank => "gb",
+            ExportFormat::Fasta => "fa",
+        }
+    }
+
+    /// Keep ASCII alphanumerics, `-`, `_`, and `.`; everything else becomes
+    /// `_` so template output cannot escape the export directory.
+    pub(super) fn sanitize_export_file_stem(raw: &str) -> String {
+        let out = raw
+            .chars()
+            .map(|ch| {
+                if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                    ch
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        let trimmed = out.trim_start_matches('.').trim_matches('_');
+        if trimmed.is_empty() {
+            "sequence".to_string()
+        } else {
+            trimmed.to_string()
+        }
+    }
+
+    pub(super) fn export_all_sequences(
+        &self,
+        result: &mut OpResult,
+        directory: &str,
+        format: &ExportFormat,
+        filename_template: Option<&str>,
+    ) -> Result<(), EngineError> {
+        let template = filename_template
+            .map(str::trim)
+            .filter(|template| !template.is_empty())
+            .unwrap_or(EXPORT_ALL_DEFAULT_TEMPLATE);
+        let extension = Self::export_all_extension(format);
+        let target_dir = Path::new(directory);
+        std::fs::create_dir_all(target_dir).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not create export directory '{directory}': {e}"),
+            cause_chain: vec![],
+        })?;
+
+        let mut seq_ids = self.state.sequences.keys().collect::<Vec<_>>();
+        seq_ids.sort_unstable();
+        let mut planned: Vec<(&SeqId, String)> = vec![];
+        let mut used_names = HashSet::new();
+        for seq_id in seq_ids {
+            let dna = &self.state.sequences[seq_id];
+            let name = dna.name().clone().unwrap_or_else(|| seq_id.clone());
+            let stem = Self::sanitize_export_file_stem(
+                &template.replace("{id}", seq_id).replace("{name}", &name),
+            );
+            let stem = stem
+                .strip_suffix(&format!(".{extension}"))
+                .unwrap_or(&stem)
+                .to_string();
+            let mut file_name = format!("{stem}.{extension}");
+            let mut suffix = 2usize;
+            while !used_names.insert(file_name.to_ascii_lowercase()) {
+                file_name = format!("{stem}_{suffix}.{extension}");
+                suffix += 1;
+            }
+            if target_dir.join(&file_name).exists() {
+                result.warnings.push(
+                    format!(
+                        "Overwriting existing file '{}'",
+                        target_dir.join(&file_name).display()
+                    )
+                    .into(),
+                );
+            }
+            planned.push((seq_id, file_name));
+        }
+
+        let staging = tempfile::Builder::new()
+            .prefix(".gentle_export_all_")
+            .tempdir_in(target_dir)
+            .map_err(|e| EngineError {
+                code: ErrorCode::Io,
+                message: format!("Could not create staging directory in '{directory}': {e}"),
+                cause_chain: vec![],
+            })?;
+        for (seq_id, file_name) in &planned {
+            let dna = &self.state.sequences[*seq_id];
+            let staged = staging.path().join(file_name).to_string_lossy().to_string();
+            match format {
+                ExportFormat::GenBank => {
+                    dna.write_genbank_file(&staged).map_err(|e| EngineError {
+                        code: ErrorCode::Io,
+                        message: format!("Could not write GenBank file for '{seq_id}': {e}"),
+                        cause_chain: vec![],
+                    })?;
+                }
+                ExportFormat::Fasta => Self::save_as_fasta(seq_id, dna, &staged)?,
+            }
+        }
+        for (seq_id, file_name) in &planned {
+            let final_path = target_dir.join(file_name);
+            std::fs::rename(staging.path().join(file_name), &final_path).map_err(|e| {
+                EngineError {
+                    code: ErrorCode::Io,
+                    message: format!(
+                        "Could not move exported '{seq_id}' into '{}': {e}",
+                        final_path.display()
+                    ),
+                    cause_chain: vec![],
+                }
+            })?;
+            result
+                .messages
+                .push(format!("Wrote '{seq_id}' to '{}'", final_path.display()));
+        }
+        result.messages.push(format!(
+            "Exported {} sequence(s) as {format:?} to '{directory}'",
+            planned.len()
+        ));
+        Ok(())
+    }
+}
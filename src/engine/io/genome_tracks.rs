@@ -772,9 +772,13 @@ impl GentleEngine {
                     report.skipped_records += 1;
                     report.skipped_invalid += 1;
                     if report.warnings.len() < 20 {
-                        report
-                            .warnings
-                            .push(format!("BED line {} skipped: {}", line_no, e));
+                        report.warnings.push(
+                            Warning::new(
+                                WarningCode::TrackImportRecordsSkipped,
+                                format!("BED line {} skipped: {}", line_no, e),
+                            )
+                            .with_context(json!({ "line": line_no })),
+                        );
                     }
                     continue;
                 }
@@ -858,10 +862,20 @@ impl GentleEngine {
             &mismatch_counts,
         );
         if report.cancelled {
-            report.warnings.push(format!(
-                "BED import cancelled after parsed={}, imported={}, skipped={}",
-                report.parsed_records, report.imported_features, report.skipped_records
-            ));
+            report.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "BED import cancelled after parsed={}, imported={}, skipped={}",
+                        report.parsed_records, report.imported_features, report.skipped_records
+                    ),
+                )
+                .with_context(json!({
+                    "parsed_records": report.parsed_records,
+                    "imported_features": report.imported_features,
+                    "skipped_records": report.skipped_records,
+                })),
+            );
         }
         if let Some(cb) = progress_cb.as_mut() {
             let _ = (**cb)(
@@ -1005,9 +1019,13 @@ impl GentleEngine {
                     report.skipped_records += 1;
                     report.skipped_invalid += 1;
                     if report.warnings.len() < 20 {
-                        report
-                            .warnings
-                            .push(format!("bedGraph line {} skipped: {}", line_no, e));
+                        report.warnings.push(
+                            Warning::new(
+                                WarningCode::TrackImportRecordsSkipped,
+                                format!("bedGraph line {} skipped: {}", line_no, e),
+                            )
+                            .with_context(json!({ "line": line_no })),
+                        );
                     }
                     continue;
                 }
@@ -1087,10 +1105,20 @@ impl GentleEngine {
             &mismatch_counts,
         );
         if report.cancelled {
-            report.warnings.push(format!(
-                "BigWig import cancelled after parsed={}, imported={}, skipped={}",
-                report.parsed_records, report.imported_features, report.skipped_records
-            ));
+            report.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "BigWig import cancelled after parsed={}, imported={}, skipped={}",
+                        report.parsed_records, report.imported_features, report.skipped_records
+                    ),
+                )
+                .with_context(json!({
+                    "parsed_records": report.parsed_records,
+                    "imported_features": report.imported_features,
+                    "skipped_records": report.skipped_records,
+                })),
+            );
         }
         if let Some(cb) = progress_cb.as_mut() {
             let _ = (**cb)(
@@ -1189,9 +1217,13 @@ impl GentleEngine {
                     report.skipped_records += 1;
                     report.skipped_invalid += 1;
                     if report.warnings.len() < 20 {
-                        report
-                            .warnings
-                            .push(format!("VCF line {} skipped: {}", line_no, e));
+                        report.warnings.push(
+                            Warning::new(
+                                WarningCode::TrackImportRecordsSkipped,
+                                format!("VCF line {} skipped: {}", line_no, e),
+                            )
+                            .with_context(json!({ "line": line_no })),
+                        );
                     }
                     continue;
                 }
@@ -1282,10 +1314,20 @@ impl GentleEngine {
             &mismatch_counts,
         );
         if report.cancelled {
-            report.warnings.push(format!(
-                "VCF import cancelled after parsed={}, imported={}, skipped={}",
-                report.parsed_records, report.imported_features, report.skipped_records
-            ));
+            report.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "VCF import cancelled after parsed={}, imported={}, skipped={}",
+                        report.parsed_records, report.imported_features, report.skipped_records
+                    ),
+                )
+                .with_context(json!({
+                    "parsed_records": report.parsed_records,
+                    "imported_features": report.imported_features,
+                    "skipped_records": report.skipped_records,
+                })),
+            );
         }
         if let Some(cb) = progress_cb.as_mut() {
             let _ = (**cb)(
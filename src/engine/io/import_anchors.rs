@@ -215,6 +215,31 @@ impl GentleEngine {
         }
     }
 
+    /// Warn when a loaded record's declared length disagrees with its parsed
+    /// bases; the sequence is kept as parsed.
+    pub(super) fn warn_on_declared_length_mismatch(
+        dna: &mut DNAsequence,
+        path: &str,
+        result: &mut OpResult,
+    ) {
+        let Some((declared_bp, parsed_bp)) = dna.reconcile_declared_length() else {
+            return;
+        };
+        result.warnings.push(
+            Warning::new(
+                WarningCode::DeclaredLengthMismatch,
+                format!(
+                    "'{path}' declares {declared_bp} bp but contains {parsed_bp} bp; the file may be truncated"
+                ),
+            )
+            .with_context(json!({
+                "path": path,
+                "declared_bp": declared_bp,
+                "parsed_bp": parsed_bp,
+            })),
+        );
+    }
+
     pub(super) fn classify_import_origin(path: &str, dna: &DNAsequence) -> SequenceOrigin {
         let lower = path.to_ascii_lowercase();
         if lower.ends_with(".fa")
@@ -0,0 +1,122 @@
+//! Bulk import of sequence files from a directory.
+//!
+//! `ImportDirectory` is the counterpart of `ExportAll`: every file with a
+//! FASTA, GenBank, or EMBL extension is loaded like `LoadFile` would load it,
+//! and the imported ids are grouped into one `Pool` container. A file that
+//! fails to parse is reported as a warning and skipped so one bad file does
+//! not abort the whole import.
+
+use super::*;
+
+const IMPORT_DIRECTORY_EXTENSIONS: &[&str] = &[
+    "fa", "fasta", "fna", "fas", "gb", "gbk", "genbank", "embl", "emb",
+];
+
+impl GentleEngine {
+    fn collect_importable_sequence_files(
+        dir: &Path,
+        recursive: bool,
+        files: &mut Vec<PathBuf>,
+    ) -> Result<(), EngineError> {
+        let entries = std::fs::read_dir(dir).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not read directory '{}': {e}", dir.display()),
+            cause_chain: vec![],
+        })?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if recursive {
+                    Self::collect_importable_sequence_files(&path, recursive, files)?;
+                }
+                continue;
+            }
+            let recognized = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    IMPORT_DIRECTORY_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str())
+                });
+            if recognized {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn import_sequence_directory(
+        &mut self,
+        result: &mut OpResult,
+        directory: &str,
+        recursive: bool,
+        container_name: Option<&str>,
+    ) -> Result<(), EngineError> {
+        let dir = Path::new(directory);
+        if !dir.is_dir() {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Directory '{directory}' not found"),
+                cause_chain: vec![],
+            });
+        }
+        let mut files = vec![];
+        Self::collect_importable_sequence_files(dir, recursive, &mut files)?;
+        files.sort();
+
+        for file in &files {
+            let path = file.to_string_lossy().to_string();
+            let mut dna = match crate::dna_sequence::load_from_file(&path) {
+                Ok(dna) => dna,
+                Err(e) => {
+                    result.warnings.push(
+                        format!("Skipped '{path}': could not load sequence file: {e}").into(),
+                    );
+                    continue;
+                }
+            };
+            Self::warn_on_declared_length_mismatch(&mut dna, &path, result);
+            Self::prepare_sequence(&mut dna);
+            let seq_id = self.unique_seq_id(&Self::derive_seq_id(&path));
+            if let Some(existing_id) = self.find_sequence_with_same_content(&dna) {
+                result.warnings.push(format!(
+                    "Loaded sequence '{seq_id}' from '{path}' duplicates existing sequence '{existing_id}'"
+                ).into());
+            }
+            let origin = Self::classify_import_origin(&path, &dna);
+            self.state.sequences.insert(seq_id.clone(), dna);
+            self.add_lineage_node(&seq_id, origin, Some(&result.op_id));
+            result.created_seq_ids.push(seq_id);
+        }
+
+        if result.created_seq_ids.is_empty() {
+            result.warnings.push(
+                format!(
+                    "No sequence files imported from '{directory}' ({} candidate file(s))",
+                    files.len()
+                )
+                .into(),
+            );
+            return Ok(());
+        }
+        let name = container_name
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("Imported directory {}", Self::derive_seq_id(directory)));
+        let container_id = self.add_container(
+            &result.created_seq_ids,
+            ContainerKind::Pool,
+            Some(name.clone()),
+            Some(&result.op_id),
+        );
+        result.messages.push(format!(
+            "Imported {} of {} sequence file(s) from '{directory}' into pool '{name}'{}",
+            result.created_seq_ids.len(),
+            files.len(),
+            container_id
+                .map(|id| format!(" ({id})"))
+                .unwrap_or_default()
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+//! Feature legend export for sequence maps.
+//!
+//! `ExportMapLegend` lists every feature the SVG map export draws for the
+//! chosen mode (name, kind, 1-based coordinates, strand, color), using the
+//! same feature selection and color assignment as the renderer so the legend
+//! and the image cannot drift apart.
+
+use super::*;
+
+impl GentleEngine {
+    pub(super) fn format_map_legend(
+        seq_id: &str,
+        mode: RenderSvgMode,
+        entries: &[MapLegendEntry],
+        format: LegendFormat,
+    ) -> String {
+        let strand = |entry: &MapLegendEntry| if entry.is_reverse { "-" } else { "+" };
+        let mut out = String::new();
+        match format {
+            LegendFormat::Csv => {
+                out.push_str("name,kind,start_1based,end_1based,strand,color\n");
+                for entry in entries {
+                    out.push_str(&format!(
+                        "{},{},{},{},{},{}\n",
+                        Self::csv_escape(&entry.name),
+                        entry.kind,
+                        entry.start_1based,
+                        entry.end_1based,
+                        strand(entry),
+                        entry.color
+                    ));
+                }
+            }
+            LegendFormat::Markdown => {
+                out.push_str(&format!(
+                    "# Map legend: {} ({})\n\n",
+                    seq_id,
+                    match mode {
+                        RenderSvgMode::Linear => "linear",
+                        RenderSvgMode::Circular => "circular",
+                    }
+                ));
+                out.push_str("| Feature | Kind | Start | End | Strand | Color |\n");
+                out.push_str("|---|---|---:|---:|:---:|---|\n");
+                for entry in entries {
+                    out.push_str(&format!(
+                        "| {} | {} | {} | {} | {} | `{}` |\n",
+                        entry.name.replace('|', "\\|"),
+                        entry.kind,
+                        entry.start_1based,
+                        entry.end_1based,
+                        strand(entry),
+                        entry.color
+                    ));
+                }
+            }
+        }
+        out
+    }
+
+    pub(super) fn export_map_legend(
+        &self,
+        seq_id: &str,
+        path: &str,
+        mode: RenderSvgMode,
+        format: LegendFormat,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let entries = map_legend_entries(dna, &self.state.display, mode);
+        let text = Self::format_map_legend(seq_id, mode, &entries, format);
+        Self::ensure_output_parent_dir(path)?;
+        std::fs::write(path, text).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not write map legend '{}': {e}", path),
+            cause_chain: vec![],
+        })?;
+        result.messages.push(format!(
+            "Wrote {:?} map legend for '{}' with {} feature(s) to '{}' (format={})",
+            mode,
+            seq_id,
+            entries.len(),
+            path,
+            format.as_str()
+        ));
+        Ok(())
+    }
+}
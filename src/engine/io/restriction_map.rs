@@ -0,0 +1,269 @@
+//! Tabular restriction-map export.
+//!
+//! `ExportRestrictionMap` summarizes per-enzyme cut positions and single-digest
+//! fragment sizes from a sequence's restriction-enzyme groups, written as CSV
+//! or as a Markdown table. Cut positions are 1-based: a value `p` means the
+//! forward strand is cut after base `p`. `non_cutters` answers the inverse
+//! question from the same groups: which enzymes have no site at all.
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RestrictionMapRow {
+    pub(super) enzyme: String,
+    pub(super) recognition_site: String,
+    pub(super) cut_positions: Vec<usize>,
+    pub(super) fragment_sizes_bp: Vec<usize>,
+}
+
+impl GentleEngine {
+    /// Fragment sizes of a single digest with forward-strand cuts after the
+    /// given (sorted, deduplicated) positions.
+    pub(super) fn restriction_map_fragment_sizes(
+        cuts: &[usize],
+        seq_len: usize,
+        circular: bool,
+    ) -> Vec<usize> {
+        if cuts.is_empty() {
+            return vec![seq_len];
+        }
+        let mut sizes = cuts.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+        if circular {
+            sizes.push(seq_len - cuts[cuts.len() - 1] + cuts[0]);
+        } else {
+            sizes.insert(0, cuts[0]);
+            sizes.push(seq_len - cuts[cuts.len() - 1]);
+        }
+        sizes
+    }
+
+    pub(super) fn restriction_map_rows(
+        &self,
+        seq_id: &str,
+        enzyme_subset: Option<&[String]>,
+        unique_cutters_only: bool,
+    ) -> Result<(Vec<RestrictionMapRow>, Vec<String>), EngineError> {
+        let mut dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?
+            .clone();
+        Self::prepare_sequence(&mut dna);
+        let seq_len = dna.len();
+        let circular = dna.is_circular();
+
+        let mut cuts_by_enzyme: BTreeMap<String, (usize, Vec<usize>)> = BTreeMap::new();
+        for (key, names) in dna.restriction_enzyme_groups() {
+            let cut = if circular && seq_len > 0 {
+                key.pos().rem_euclid(seq_len as isize) as usize
+            } else if key.pos() > 0 && (key.pos() as usize) < seq_len {
+                key.pos() as usize
+            } else {
+                continue;
+            };
+            for name in names {
+                let entry = cuts_by_enzyme
+                    .entry(name.clone())
+                    .or_insert_with(|| (key.number_of_cuts(), vec![]));
+                entry.1.push(cut);
+            }
+        }
+
+        let mut warnings = vec![];
+        if let Some(subset) = enzyme_subset {
+            let wanted = subset
+                .iter()
+                .map(|name| name.trim())
+                .filter(|name| !name.is_empty())
+                .collect::<Vec<_>>();
+            for name in &wanted {
+                if !cuts_by_enzyme
+                    .keys()
+                    .any(|enzyme| enzyme.eq_ignore_ascii_case(name))
+                {
+                    warnings.push(format!("Enzyme '{name}' does not cut '{seq_id}'"));
+                }
+            }
+            cuts_by_enzyme
+                .retain(|enzyme, _| wanted.iter().any(|name| enzyme.eq_ignore_ascii_case(name)));
+        }
+
+        let mut rows = vec![];
+        for (enzyme, (number_of_cuts, mut cuts)) in cuts_by_enzyme {
+            if unique_cutters_only && number_of_cuts != 1 {
+                continue;
+            }
+            cuts.sort_unstable();
+            cuts.dedup();
+            let recognition_site = dna
+                .restriction_enzymes()
+                .iter()
+                .find(|candidate| candidate.name == enzyme)
+                .map(|candidate| candidate.sequence.clone())
+                .unwrap_or_default();
+            rows.push(RestrictionMapRow {
+                fragment_sizes_bp: Self::restriction_map_fragment_sizes(&cuts, seq_len, circular),
+                enzyme,
+                recognition_site,
+                cut_positions: cuts,
+            });
+        }
+        Ok((rows, warnings))
+    }
+
+    /// Enzymes of the active catalog (or of `enzyme_subset`, matched
+    /// case-insensitively) with no recognition site in `seq_id`, sorted by
+    /// name. Candidates for adapters/linkers that must leave the insert
+    /// intact; subset names missing from the catalog are rejected because
+    /// their absence cannot be checked.
+    pub fn non_cutters(
+        &self,
+        seq_id: &str,
+        enzyme_subset: Option<&[String]>,
+    ) -> Result<Vec<String>, EngineError> {
+        let mut dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?
+            .clone();
+        Self::prepare_sequence(&mut dna);
+        let cutting = dna
+            .restriction_enzyme_groups()
+            .values()
+            .flatten()
+            .map(|name| name.to_ascii_uppercase())
+            .collect::<HashSet<_>>();
+        let catalog = dna
+            .restriction_enzymes()
+            .iter()
+            .map(|enzyme| enzyme.name.clone())
+            .collect::<BTreeSet<_>>();
+
+        let candidates = match enzyme_subset {
+            None => catalog,
+            Some(subset) => {
+                let mut wanted = BTreeSet::new();
+                for name in subset.iter().map(|name| name.trim()) {
+                    if name.is_empty() {
+                        continue;
+                    }
+                    let known = catalog
+                        .iter()
+                        .find(|enzyme| enzyme.eq_ignore_ascii_case(name))
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!("Unknown restriction enzyme '{name}'"),
+                            cause_chain: vec![],
+                        })?;
+                    wanted.insert(known.clone());
+                }
+                wanted
+            }
+        };
+        Ok(candidates
+            .into_iter()
+            .filter(|name| !cutting.contains(&name.to_ascii_uppercase()))
+            .collect())
+    }
+
+    pub(super) fn format_restriction_map(
+        seq_id: &str,
+        seq_len: usize,
+        circular: bool,
+        rows: &[RestrictionMapRow],
+        format: RestrictionMapFormat,
+    ) -> String {
+        let join = |values: &[usize], sep: &str| {
+            values
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(sep)
+        };
+        let mut out = String::new();
+        match format {
+            RestrictionMapFormat::Csv => {
+                out.push_str(
+                    "enzyme,recognition_site,number_of_cuts,cut_positions,fragment_sizes_bp\n",
+                );
+                for row in rows {
+                    out.push_str(&format!(
+                        "{},{},{},{},{}\n",
+                        Self::csv_escape(&row.enzyme),
+                        Self::csv_escape(&row.recognition_site),
+                        row.cut_positions.len(),
+                        join(&row.cut_positions, ";"),
+                        join(&row.fragment_sizes_bp, ";")
+                    ));
+                }
+            }
+            RestrictionMapFormat::Markdown => {
+                out.push_str(&format!(
+                    "# Restriction map: {}\n\n{} bp, {}\n\n",
+                    seq_id,
+                    seq_len,
+                    if circular { "circular" } else { "linear" }
+                ));
+                out.push_str(
+                    "| Enzyme | Recognition site | Cuts | Cut positions | Fragment sizes (bp) |\n",
+                );
+                out.push_str("|---|---|---:|---|---|\n");
+                for row in rows {
+                    out.push_str(&format!(
+                        "| {} | {} | {} | {} | {} |\n",
+                        row.enzyme,
+                        row.recognition_site,
+                        row.cut_positions.len(),
+                        join(&row.cut_positions, ", "),
+                        join(&row.fragment_sizes_bp, ", ")
+                    ));
+                }
+            }
+        }
+        out
+    }
+
+    pub(super) fn export_restriction_map(
+        &self,
+        seq_id: &str,
+        path: &str,
+        format: RestrictionMapFormat,
+        enzyme_subset: Option<&[String]>,
+        unique_cutters_only: bool,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let (rows, warnings) =
+            self.restriction_map_rows(seq_id, enzyme_subset, unique_cutters_only)?;
+        let dna = &self.state.sequences[seq_id];
+        let text =
+            Self::format_restriction_map(seq_id, dna.len(), dna.is_circular(), &rows, format);
+        Self::ensure_output_parent_dir(path)?;
+        std::fs::write(path, text).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not write restriction map '{}': {e}", path),
+            cause_chain: vec![],
+        })?;
+        result
+            .warnings
+            .extend(warnings.into_iter().map(Warning::from));
+        result.messages.push(format!(
+            "Wrote restriction map for '{}' with {} enzyme(s) to '{}' (format={}, unique_cutters_only={})",
+            seq_id,
+            rows.len(),
+            path,
+            format.as_str(),
+            unique_cutters_only
+        ));
+        Ok(())
+    }
+}
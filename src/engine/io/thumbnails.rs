@@ -0,0 +1,146 @@
+//! Batch map thumbnails.
+//!
+//! `RenderThumbnails` renders one small map SVG per project sequence with the
+//! regular `export_linear_svg`/`export_circular_svg` renderers over the whole
+//! sequence, then shrinks the root `width`/`height` while keeping the
+//! `viewBox`, so the drawing scales down instead of being cropped. Files are
+//! staged like `ExportAll` and moved into place only once all rendered.
+
+use super::*;
+
+impl GentleEngine {
+    /// Rewrite the `width`/`height` attributes of the root `<svg>` tag so the
+    /// width is at most `max_width_px`, keeping the aspect ratio. Returns the
+    /// rewritten document and its final `(width, height)`.
+    fn scale_svg_root_size(svg: &str, max_width_px: usize) -> (String, f64, f64) {
+        let Some(tag_start) = svg.find("<svg") else {
+            return (svg.to_string(), 0.0, 0.0);
+        };
+        let Some(tag_len) = svg[tag_start..].find('>') else {
+            return (svg.to_string(), 0.0, 0.0);
+        };
+        let tag = &svg[tag_start..tag_start + tag_len];
+        let attribute = |name: &str| -> Option<f64> {
+            let needle = format!(" {name}=\"");
+            let value_start = tag.find(&needle)? + needle.len();
+            let value_len = tag[value_start..].find('"')?;
+            tag[value_start..value_start + value_len].parse().ok()
+        };
+        let (Some(width), Some(height)) = (attribute("width"), attribute("height")) else {
+            return (svg.to_string(), 0.0, 0.0);
+        };
+        if width <= max_width_px as f64 {
+            return (svg.to_string(), width, height);
+        }
+        let new_width = max_width_px as f64;
+        let new_height = (height * new_width / width).round().max(1.0);
+        let mut new_tag = tag.to_string();
+        for (name, old, new) in [("width", width, new_width), ("height", height, new_height)] {
+            new_tag = new_tag.replacen(
+                &format!(" {name}=\"{old}\""),
+                &format!(" {name}=\"{new}\""),
+                1,
+            );
+        }
+        let out = format!(
+            "{}{}{}",
+            &svg[..tag_start],
+            new_tag,
+            &svg[tag_start + tag_len..]
+        );
+        (out, new_width, new_height)
+    }
+
+    pub(super) fn render_thumbnails(
+        &self,
+        result: &mut OpResult,
+        directory: &str,
+        mode: &RenderSvgMode,
+        max_width_px: usize,
+    ) -> Result<(), EngineError> {
+        if directory.trim().is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "RenderThumbnails requires non-empty directory".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        if max_width_px == 0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "RenderThumbnails max_width_px must be >= 1".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let target_dir = Path::new(directory);
+        std::fs::create_dir_all(target_dir).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not create thumbnail directory '{directory}': {e}"),
+            cause_chain: vec![],
+        })?;
+
+        // Thumbnails always show the whole sequence, not the shared viewport.
+        let mut display = self.state.display.clone();
+        display.linear_view_start_bp = 0;
+        display.linear_view_span_bp = 0;
+
+        let mut seq_ids = self.state.sequences.keys().collect::<Vec<_>>();
+        seq_ids.sort_unstable();
+        let mut used_names = HashSet::new();
+        let mut planned: Vec<(&SeqId, String, String, f64, f64)> = vec![];
+        for seq_id in seq_ids {
+            let dna = &self.state.sequences[seq_id];
+            let stem = Self::sanitize_export_file_stem(seq_id);
+            let mut file_name = format!("{stem}.svg");
+            let mut suffix = 2usize;
+            while !used_names.insert(file_name.to_ascii_lowercase()) {
+                file_name = format!("{stem}_{suffix}.svg");
+                suffix += 1;
+            }
+            let svg = match mode {
+                RenderSvgMode::Linear => export_linear_svg(dna, &display),
+                RenderSvgMode::Circular => export_circular_svg(dna, &display),
+            };
+            let (svg, width, height) = Self::scale_svg_root_size(&svg, max_width_px);
+            planned.push((seq_id, file_name, svg, width, height));
+        }
+
+        let staging = tempfile::Builder::new()
+            .prefix(".gentle_thumbnails_")
+            .tempdir_in(target_dir)
+            .map_err(|e| EngineError {
+                code: ErrorCode::Io,
+                message: format!("Could not create staging directory in '{directory}': {e}"),
+                cause_chain: vec![],
+            })?;
+        for (seq_id, file_name, svg, _, _) in &planned {
+            std::fs::write(staging.path().join(file_name), svg).map_err(|e| EngineError {
+                code: ErrorCode::Io,
+                message: format!("Could not write thumbnail for '{seq_id}': {e}"),
+                cause_chain: vec![],
+            })?;
+        }
+        for (seq_id, file_name, _, width, height) in &planned {
+            let final_path = target_dir.join(file_name);
+            std::fs::rename(staging.path().join(file_name), &final_path).map_err(|e| {
+                EngineError {
+                    code: ErrorCode::Io,
+                    message: format!(
+                        "Could not move thumbnail for '{seq_id}' into '{}': {e}",
+                        final_path.display()
+                    ),
+                    cause_chain: vec![],
+                }
+            })?;
+            result.messages.push(format!(
+                "Wrote {mode:?} thumbnail for '{seq_id}' to '{}' ({width}x{height} px)",
+                final_path.display()
+            ));
+        }
+        result.messages.push(format!(
+            "Rendered {} thumbnail(s) to '{directory}' (max_width_px={max_width_px})",
+            planned.len()
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! Sliding-window track export.
+//!
+//! `ExportTrackCsv` writes the raw windowed series behind sequence signal
+//! tracks (GC fraction, GC skew, melting temperature) as CSV for external
+//! plotting. Windows start at base 1 and advance by `step_bp`; a trailing
+//! window shorter than `window_bp` is not emitted.
+
+use super::*;
+
+impl GentleEngine {
+    /// Value of `track` over one window, or `None` when it is undefined
+    /// (e.g. no canonical bases for GC fraction).
+    fn track_window_value(track: TrackKind, window: &[u8]) -> Option<f64> {
+        match track {
+            TrackKind::GcFraction => Self::sequence_gc_fraction(window),
+            TrackKind::GcSkew => {
+                let g = window
+                    .iter()
+                    .filter(|b| b.eq_ignore_ascii_case(&b'G'))
+                    .count();
+                let c = window
+                    .iter()
+                    .filter(|b| b.eq_ignore_ascii_case(&b'C'))
+                    .count();
+                Some(if g + c == 0 {
+                    0.0
+                } else {
+                    (g as f64 - c as f64) / (g + c) as f64
+                })
+            }
+            TrackKind::MeltingTemp => Some(Self::estimate_primer_tm_c(window)),
+        }
+    }
+
+    pub(super) fn export_track_csv(
+        &self,
+        seq_id: &str,
+        track: TrackKind,
+        window_bp: usize,
+        step_bp: usize,
+        path: &str,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        if step_bp == 0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "ExportTrackCsv requires step_bp >= 1".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        if window_bp == 0 || window_bp > dna.len() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "ExportTrackCsv window_bp={} must be between 1 and the length of '{}' ({} bp)",
+                    window_bp,
+                    seq_id,
+                    dna.len()
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let bases = dna.forward_bytes();
+        let mut text = format!("start_1based,end_1based,{}\n", track.as_str());
+        let mut rows = 0usize;
+        for start in (0..=bases.len() - window_bp).step_by(step_bp) {
+            let value = Self::track_window_value(track, &bases[start..start + window_bp])
+                .map(|value| format!("{value:.6}"))
+                .unwrap_or_default();
+            text.push_str(&format!("{},{},{}\n", start + 1, start + window_bp, value));
+            rows += 1;
+        }
+        Self::ensure_output_parent_dir(path)?;
+        std::fs::write(path, text).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not write track CSV '{}': {e}", path),
+            cause_chain: vec![],
+        })?;
+        result.messages.push(format!(
+            "Wrote {} {} window(s) for '{}' (window_bp={}, step_bp={}) to '{}'",
+            rows,
+            track.as_str(),
+            seq_id,
+            window_bp,
+            step_bp,
+            path
+        ));
+        Ok(())
+    }
+}
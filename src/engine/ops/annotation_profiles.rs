@@ -0,0 +1,196 @@
+//! Reusable annotation profiles.
+//!
+//! `SaveAnnotationProfile` stores a typed bundle of annotation settings (TFBS
+//! motifs and thresholds, methylation mode) under a name in project metadata;
+//! `ApplyAnnotationProfile` replays that bundle onto several sequences in one
+//! operation. Profiles are the typed, annotation-only counterpart of workflow
+//! macro templates.
+
+use super::*;
+
+impl GentleEngine {
+    fn normalize_annotation_profile_name(raw: &str) -> Result<String, EngineError> {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Annotation profile name cannot be empty".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        Ok(trimmed.to_string())
+    }
+
+    fn read_annotation_profile_store(&self) -> AnnotationProfileStore {
+        let mut store = self
+            .state
+            .metadata
+            .get(ANNOTATION_PROFILES_METADATA_KEY)
+            .cloned()
+            .and_then(|v| serde_json::from_value::<AnnotationProfileStore>(v).ok())
+            .unwrap_or_default();
+        if store.schema.trim().is_empty() {
+            store.schema = ANNOTATION_PROFILES_SCHEMA.to_string();
+        }
+        store
+    }
+
+    fn write_annotation_profile_store(
+        &mut self,
+        mut store: AnnotationProfileStore,
+    ) -> Result<(), EngineError> {
+        if store.profiles.is_empty() {
+            self.state.metadata.remove(ANNOTATION_PROFILES_METADATA_KEY);
+            return Ok(());
+        }
+        store.schema = ANNOTATION_PROFILES_SCHEMA.to_string();
+        store.updated_at_unix_ms = Self::now_unix_ms();
+        let value = serde_json::to_value(store).map_err(|e| EngineError {
+            code: ErrorCode::Internal,
+            message: format!("Could not serialize annotation profile metadata: {e}"),
+            cause_chain: vec![],
+        })?;
+        self.state
+            .metadata
+            .insert(ANNOTATION_PROFILES_METADATA_KEY.to_string(), value);
+        Ok(())
+    }
+
+    /// Stored annotation profiles, sorted by name.
+    pub fn list_annotation_profiles(&self) -> Vec<AnnotationProfile> {
+        let mut profiles = self
+            .read_annotation_profile_store()
+            .profiles
+            .into_values()
+            .collect::<Vec<_>>();
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+        profiles
+    }
+
+    pub fn get_annotation_profile(&self, name: &str) -> Result<AnnotationProfile, EngineError> {
+        let name = Self::normalize_annotation_profile_name(name)?;
+        self.read_annotation_profile_store()
+            .profiles
+            .remove(&name)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Annotation profile '{name}' not found"),
+                cause_chain: vec![],
+            })
+    }
+
+    pub(super) fn op_save_annotation_profile(
+        &mut self,
+        name: String,
+        payload: AnnotationProfilePayload,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let name = Self::normalize_annotation_profile_name(&name)?;
+        if payload.tfbs.is_none() && payload.methylation.is_none() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Annotation profile '{name}' must configure at least one section"),
+                cause_chain: vec![],
+            });
+        }
+        if let Some(tfbs) = &payload.tfbs {
+            if tfbs.motifs.iter().all(|motif| motif.trim().is_empty()) {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("Annotation profile '{name}' TFBS section has no motifs"),
+                    cause_chain: vec![],
+                });
+            }
+            Self::validate_tf_thresholds(tfbs.min_llr_quantile.unwrap_or(0.0))?;
+        }
+
+        let now = Self::now_unix_ms();
+        let mut store = self.read_annotation_profile_store();
+        let created_at_unix_ms = store
+            .profiles
+            .get(&name)
+            .map(|profile| profile.created_at_unix_ms)
+            .unwrap_or(now);
+        let replaced = store
+            .profiles
+            .insert(
+                name.clone(),
+                AnnotationProfile {
+                    name: name.clone(),
+                    payload,
+                    created_at_unix_ms,
+                    updated_at_unix_ms: now,
+                },
+            )
+            .is_some();
+        self.write_annotation_profile_store(store)?;
+        result.messages.push(format!(
+            "{} annotation profile '{}'",
+            if replaced { "Updated" } else { "Added" },
+            name
+        ));
+        Ok(())
+    }
+
+    pub(super) fn op_apply_annotation_profile(
+        &mut self,
+        name: String,
+        seq_ids: Vec<SeqId>,
+        result: &mut OpResult,
+        on_progress: &mut dyn FnMut(OperationProgress) -> bool,
+    ) -> Result<(), EngineError> {
+        let profile = self.get_annotation_profile(&name)?;
+        if seq_ids.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "ApplyAnnotationProfile requires at least one seq_id".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        // Fail before touching anything when a target is missing.
+        for seq_id in &seq_ids {
+            if !self.state.sequences.contains_key(seq_id) {
+                return Err(EngineError {
+                    code: ErrorCode::NotFound,
+                    message: format!("Sequence '{seq_id}' not found"),
+                    cause_chain: vec![],
+                });
+            }
+        }
+
+        for seq_id in &seq_ids {
+            if let Some(tfbs) = &profile.payload.tfbs {
+                self.annotate_tfbs(
+                    result,
+                    seq_id.clone(),
+                    tfbs.motifs.clone(),
+                    tfbs.min_llr_bits,
+                    tfbs.min_llr_quantile,
+                    tfbs.per_tf_thresholds.clone(),
+                    tfbs.clear_existing,
+                    tfbs.max_hits,
+                    on_progress,
+                )?;
+            }
+            if let Some(methylation) = profile.payload.methylation {
+                let _ = self.ensure_lineage_node(seq_id);
+                if let Some(dna) = self.state.sequences.get_mut(seq_id) {
+                    let mut mode = MethylationMode::default();
+                    mode.set_dam(methylation.dam);
+                    mode.set_dcm(methylation.dcm);
+                    dna.set_methylation_mode(mode);
+                    dna.update_computed_features();
+                }
+                if !result.changed_seq_ids.contains(seq_id) {
+                    result.changed_seq_ids.push(seq_id.clone());
+                }
+            }
+        }
+        result.messages.push(format!(
+            "Applied annotation profile '{}' to {} sequence(s)",
+            profile.name,
+            seq_ids.len()
+        ));
+        Ok(())
+    }
+}
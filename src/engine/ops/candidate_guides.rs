@@ -10,8 +10,74 @@
 //!   helpers in `analysis/candidate_metrics.rs`
 
 use super::*;
+use rayon::prelude::*;
+
+const CANDIDATE_GENERATION_PROGRESS_INTERVAL: usize = 1024;
+/// Sets smaller than this are scored serially; thread startup would dominate.
+const CANDIDATE_PARALLEL_SCORING_MIN: usize = 512;
+/// Longest loop considered by the candidate self-structure penalty; loop 0
+/// covers self-complementary (dimer-forming) stretches.
+const CANDIDATE_SELF_STRUCTURE_MAX_LOOP: usize = 8;
 
 impl GentleEngine {
+    /// Score candidates `0..count` with `score`, in parallel for large sets.
+    ///
+    /// Output keeps candidate order, and the reported error is the one for the
+    /// lowest failing index, so results match a serial run exactly.
+    /// `EngineParameters::candidate_scoring_threads` caps the worker count
+    /// (`0` = rayon default, `1` = serial).
+    fn score_candidates<T, F>(&self, count: usize, score: F) -> Result<Vec<T>, EngineError>
+    where
+        T: Send,
+        F: Fn(usize) -> Result<T, EngineError> + Sync + Send,
+    {
+        let threads = self.state.parameters.candidate_scoring_threads;
+        if threads == 1 || count < CANDIDATE_PARALLEL_SCORING_MIN {
+            return (0..count).map(score).collect();
+        }
+        let run = || (0..count).into_par_iter().map(&score).collect::<Vec<_>>();
+        let scored = if threads == 0 {
+            run()
+        } else {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| EngineError {
+                    code: ErrorCode::Internal,
+                    message: format!("Could not start candidate scoring thread pool: {e}"),
+
+                    cause_chain: vec![],
+                })?
+                .install(run)
+        };
+        scored.into_iter().collect()
+    }
+
+    /// Report window progress every `CANDIDATE_GENERATION_PROGRESS_INTERVAL`
+    /// windows; returns `true` once the caller asked to stop.
+    fn candidate_generation_cancelled(
+        on_progress: &mut dyn FnMut(OperationProgress) -> bool,
+        considered: usize,
+        produced: usize,
+        total_windows: usize,
+    ) -> bool {
+        considered > 0
+            && considered % CANDIDATE_GENERATION_PROGRESS_INTERVAL == 0
+            && !on_progress(OperationProgress::CandidateGeneration {
+                produced,
+                total_windows,
+            })
+    }
+
+    fn candidate_generation_cancelled_error(op_name: &str) -> EngineError {
+        EngineError {
+            code: ErrorCode::InvalidInput,
+            message: format!("{op_name} was cancelled before any candidate was produced"),
+
+            cause_chain: vec![],
+        }
+    }
+
     pub(super) fn op_generate_candidate_set(
         &mut self,
         set_name: String,
@@ -26,6 +92,7 @@ impl GentleEngine {
         feature_strand_relation: Option<CandidateFeatureStrandRelation>,
         limit: Option<usize>,
         result: &mut OpResult,
+        on_progress: &mut dyn FnMut(OperationProgress) -> bool,
     ) -> Result<(), EngineError> {
         let set_name = Self::normalize_candidate_set_name(&set_name)?;
         if length_bp == 0 {
@@ -99,7 +166,7 @@ impl GentleEngine {
         {
             result.warnings.push(
                 "feature_boundary_mode is ignored unless feature_geometry_mode=feature_boundaries"
-                    .to_string(),
+                    .into(),
             );
         }
         let feature_targets = Self::collect_feature_distance_targets(
@@ -107,12 +174,19 @@ impl GentleEngine {
             feature_geometry_mode,
             effective_boundary_mode,
         );
-        let matching_feature_count = Self::matching_feature_count(
+        let any_feature_index = Self::build_feature_distance_index(
+            &feature_targets,
+            &[],
+            None,
+            CandidateFeatureStrandRelation::Any,
+        );
+        let filtered_feature_index = Self::build_feature_distance_index(
             &feature_targets,
             &kind_filter_upper,
             label_regex.as_ref(),
             feature_strand_relation,
         );
+        let matching_feature_count = filtered_feature_index.feature_count();
         let has_feature_filter = !kind_filter_upper.is_empty()
             || label_regex.is_some()
             || max_distance_bp.is_some()
@@ -130,27 +204,24 @@ impl GentleEngine {
         let mut candidates = vec![];
         let mut considered = 0usize;
         let mut truncated = false;
+        let mut cancelled = false;
         let upper = dna.len().saturating_sub(length_bp);
+        let total_windows = upper / step_bp + 1;
         let mut start = 0usize;
         while start <= upper {
+            if Self::candidate_generation_cancelled(
+                on_progress,
+                considered,
+                candidates.len(),
+                total_windows,
+            ) {
+                cancelled = true;
+                break;
+            }
             let end = start + length_bp;
             considered += 1;
-            let distance_any = Self::nearest_feature_distance(
-                start,
-                end,
-                &feature_targets,
-                &[],
-                None,
-                CandidateFeatureStrandRelation::Any,
-            );
-            let distance_filtered = Self::nearest_feature_distance(
-                start,
-                end,
-                &feature_targets,
-                &kind_filter_upper,
-                label_regex.as_ref(),
-                feature_strand_relation,
-            );
+            let distance_any = any_feature_index.nearest_distance(start, end);
+            let distance_filtered = filtered_feature_index.nearest_distance(start, end);
             let selected_distance = if !kind_filter_upper.is_empty()
                 || label_regex.is_some()
                 || feature_strand_relation != CandidateFeatureStrandRelation::Any
@@ -196,6 +267,15 @@ impl GentleEngine {
             }
             start = start.saturating_add(step_bp);
         }
+        let _ = on_progress(OperationProgress::CandidateGeneration {
+            produced: candidates.len(),
+            total_windows,
+        });
+        if candidates.is_empty() && cancelled {
+            return Err(Self::candidate_generation_cancelled_error(
+                "GenerateCandidateSet",
+            ));
+        }
         if candidates.is_empty() {
             return Err(EngineError {
                 code: ErrorCode::InvalidInput,
@@ -229,16 +309,36 @@ impl GentleEngine {
             set_name, seq_id, generated, considered
         ));
         if truncated {
-            result.warnings.push(format!(
-                "Candidate generation for '{}' was truncated at limit={}",
-                set_name, limit
-            ));
+            result.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "Candidate generation for '{}' was truncated at limit={}",
+                        set_name, limit
+                    ),
+                )
+                .with_context(json!({ "limit": limit })),
+            );
+        }
+        if cancelled {
+            result.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "Candidate generation for '{}' was cancelled after {} of {} windows; results are truncated",
+                        set_name, considered, total_windows
+                    ),
+                )
+                .with_context(json!({
+                    "considered_windows": considered,
+                    "total_windows": total_windows,
+                })),
+            );
         }
         if replaced_existing {
-            result.warnings.push(format!(
-                "Candidate set '{}' replaced existing set",
-                set_name
-            ));
+            result
+                .warnings
+                .push(format!("Candidate set '{}' replaced existing set", set_name).into());
         }
         if !metric_names.is_empty() {
             result.messages.push(format!(
@@ -275,6 +375,7 @@ impl GentleEngine {
         step_bp: usize,
         limit: Option<usize>,
         result: &mut OpResult,
+        on_progress: &mut dyn FnMut(OperationProgress) -> bool,
     ) -> Result<(), EngineError> {
         let set_name = Self::normalize_candidate_set_name(&set_name)?;
         if length_bp == 0 {
@@ -345,9 +446,20 @@ impl GentleEngine {
         let mut candidates = vec![];
         let mut considered = 0usize;
         let mut truncated = false;
+        let mut cancelled = false;
         let upper = right.saturating_sub(length_bp);
+        let total_windows = (upper - left) / step_bp + 1;
         let mut start = left;
         while start <= upper {
+            if Self::candidate_generation_cancelled(
+                on_progress,
+                considered,
+                candidates.len(),
+                total_windows,
+            ) {
+                cancelled = true;
+                break;
+            }
             let end = start + length_bp;
             considered += 1;
             let Some(fragment) = dna.get_range_safe(start..end) else {
@@ -399,7 +511,16 @@ impl GentleEngine {
             }
             start = start.saturating_add(step_bp);
         }
+        let _ = on_progress(OperationProgress::CandidateGeneration {
+            produced: candidates.len(),
+            total_windows,
+        });
 
+        if candidates.is_empty() && cancelled {
+            return Err(Self::candidate_generation_cancelled_error(
+                "GenerateCandidateSetBetweenAnchors",
+            ));
+        }
         if candidates.is_empty() {
             return Err(EngineError {
                 code: ErrorCode::InvalidInput,
@@ -439,16 +560,36 @@ impl GentleEngine {
             seq_id, anchor_a_pos, anchor_b_pos
         ));
         if truncated {
-            result.warnings.push(format!(
-                "Candidate generation for '{}' was truncated at limit={}",
-                set_name, limit
-            ));
+            result.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "Candidate generation for '{}' was truncated at limit={}",
+                        set_name, limit
+                    ),
+                )
+                .with_context(json!({ "limit": limit })),
+            );
+        }
+        if cancelled {
+            result.warnings.push(
+                Warning::new(
+                    WarningCode::ResultTruncated,
+                    format!(
+                        "Candidate generation for '{}' was cancelled after {} of {} windows; results are truncated",
+                        set_name, considered, total_windows
+                    ),
+                )
+                .with_context(json!({
+                    "considered_windows": considered,
+                    "total_windows": total_windows,
+                })),
+            );
         }
         if replaced_existing {
-            result.warnings.push(format!(
-                "Candidate set '{}' replaced existing set",
-                set_name
-            ));
+            result
+                .warnings
+                .push(format!("Candidate set '{}' replaced existing set", set_name).into());
         }
         if !metric_names.is_empty() {
             result.messages.push(format!(
@@ -460,7 +601,7 @@ impl GentleEngine {
         if is_circular {
             result.warnings.push(
                 "GenerateCandidateSetBetweenAnchors currently uses linearized anchor interval semantics on circular sequences"
-                    .to_string(),
+                    .into()
             );
         }
         Ok(())
@@ -527,10 +668,9 @@ impl GentleEngine {
             guides.len()
         ));
         if replaced_existing {
-            result.warnings.push(format!(
-                "Guide set '{}' replaced existing content",
-                guide_set_id
-            ));
+            result
+                .warnings
+                .push(format!("Guide set '{}' replaced existing content", guide_set_id).into());
         }
         Ok(())
     }
@@ -574,7 +714,7 @@ impl GentleEngine {
         } else {
             result
                 .warnings
-                .push(format!("Guide set '{}' was not present", guide_set_id));
+                .push(format!("Guide set '{}' was not present", guide_set_id).into());
         }
         Ok(())
     }
@@ -807,10 +947,13 @@ impl GentleEngine {
                 passed_guides.len()
             ));
             if replaced {
-                result.warnings.push(format!(
-                    "Guide set '{}' replaced existing content",
-                    output_guide_set_id
-                ));
+                result.warnings.push(
+                    format!(
+                        "Guide set '{}' replaced existing content",
+                        output_guide_set_id
+                    )
+                    .into(),
+                );
             }
         }
 
@@ -831,6 +974,292 @@ impl GentleEngine {
         Ok(())
     }
 
+    pub(super) fn guide_rank_score(guide: &GuideCandidate, weights: &GuideRankWeights) -> f64 {
+        let spacer = guide
+            .protospacer
+            .as_bytes()
+            .iter()
+            .map(|b| match b.to_ascii_uppercase() {
+                b'U' => b'T',
+                other => other,
+            })
+            .collect::<Vec<_>>();
+        let gc_term = Self::sequence_gc_fraction(&spacer)
+            .map(|gc| 1.0 - 2.0 * (gc - 0.5).abs())
+            .unwrap_or(0.0);
+        let excess_run =
+            Self::max_homopolymer_run(&spacer).saturating_sub(weights.homopolymer_free_run) as f64;
+        let mut score = weights.gc * gc_term - weights.homopolymer * excess_run;
+        if let Some(off_target) = guide.off_target_score {
+            score += weights.off_target * off_target;
+        }
+        score
+    }
+
+    pub(super) fn op_rank_guides(
+        &mut self,
+        guide_set_id: String,
+        weights: GuideRankWeights,
+        output_guide_set_id: Option<String>,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let guide_set_id = Self::normalize_guide_set_id(&guide_set_id)?;
+        let output_guide_set_id = output_guide_set_id
+            .as_deref()
+            .map(Self::normalize_guide_set_id)
+            .transpose()?
+            .unwrap_or_else(|| guide_set_id.clone());
+        for (name, value) in [
+            ("gc", weights.gc),
+            ("homopolymer", weights.homopolymer),
+            ("off_target", weights.off_target),
+        ] {
+            if !value.is_finite() {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("RankGuides weight '{}' must be finite", name),
+
+                    cause_chain: vec![],
+                });
+            }
+        }
+        let mut store = self.read_guide_design_store();
+        let guide_set = store
+            .guide_sets
+            .get(&guide_set_id)
+            .cloned()
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Guide set '{}' not found", guide_set_id),
+
+                cause_chain: vec![],
+            })?;
+        if guide_set.guides.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Guide set '{}' is empty", guide_set_id),
+
+                cause_chain: vec![],
+            });
+        }
+
+        let mut scored = guide_set
+            .guides
+            .into_iter()
+            .map(|guide| (Self::guide_rank_score(&guide, &weights), guide))
+            .collect::<Vec<_>>();
+        scored.sort_by(|(score_a, a), (score_b, b)| {
+            score_b
+                .total_cmp(score_a)
+                .then_with(|| a.guide_id.cmp(&b.guide_id))
+        });
+        let with_off_target = scored
+            .iter()
+            .filter(|(_, guide)| guide.off_target_score.is_some())
+            .count();
+        let guides = scored
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (_, mut guide))| {
+                guide.rank = Some(idx + 1);
+                guide
+            })
+            .collect::<Vec<_>>();
+
+        let now = Self::now_unix_ms();
+        let created_at_unix_ms = store
+            .guide_sets
+            .get(&output_guide_set_id)
+            .map(|set| set.created_at_unix_ms)
+            .unwrap_or(now);
+        let replaced = store
+            .guide_sets
+            .insert(
+                output_guide_set_id.clone(),
+                GuideSet {
+                    guide_set_id: output_guide_set_id.clone(),
+                    guides: guides.clone(),
+                    created_at_unix_ms,
+                    updated_at_unix_ms: now,
+                },
+            )
+            .is_some();
+        if replaced && output_guide_set_id != guide_set_id {
+            result.warnings.push(
+                format!(
+                    "Guide set '{}' replaced existing content",
+                    output_guide_set_id
+                )
+                .into(),
+            );
+        }
+        Self::append_guide_design_audit(
+            &mut store,
+            "RankGuides",
+            &guide_set_id,
+            json!({
+                "output_guide_set_id": output_guide_set_id,
+                "guide_count": guides.len(),
+                "with_off_target_score": with_off_target,
+                "weights": weights
+            }),
+        );
+        self.write_guide_design_store(store)?;
+        result.messages.push(format!(
+            "Ranked {} guide(s) from '{}' into '{}' ({} with off-target score)",
+            guides.len(),
+            guide_set_id,
+            output_guide_set_id,
+            with_off_target
+        ));
+        Ok(())
+    }
+
+    fn guide_set_match_key(guide: &GuideCandidate, match_by: GuideSetMatchKey) -> String {
+        match match_by {
+            GuideSetMatchKey::GuideId => guide.guide_id.clone(),
+            GuideSetMatchKey::Coordinates => format!(
+                "{}:{}-{}:{}",
+                guide.seq_id, guide.start_0based, guide.end_0based_exclusive, guide.strand
+            ),
+        }
+    }
+
+    /// Union/intersect/subtract two guide sets. Every output guide keeps its
+    /// record, including `rank`, from the set it was taken from (the left set
+    /// when present in both); output order is the usual guide-set order
+    /// (rank, then `guide_id`).
+    pub(super) fn op_guide_set_op(
+        &mut self,
+        op: CandidateSetOperator,
+        left_guide_set: String,
+        right_guide_set: String,
+        output_guide_set: String,
+        match_by: GuideSetMatchKey,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let left_guide_set = Self::normalize_guide_set_id(&left_guide_set)?;
+        let right_guide_set = Self::normalize_guide_set_id(&right_guide_set)?;
+        let output_guide_set = Self::normalize_guide_set_id(&output_guide_set)?;
+        let mut store = self.read_guide_design_store();
+        let load = |guide_set_id: &str| {
+            store
+                .guide_sets
+                .get(guide_set_id)
+                .map(|set| set.guides.clone())
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::NotFound,
+                    message: format!("Guide set '{}' not found", guide_set_id),
+
+                    cause_chain: vec![],
+                })
+        };
+        let left = load(&left_guide_set)?;
+        let right = load(&right_guide_set)?;
+        let right_keys = right
+            .iter()
+            .map(|guide| Self::guide_set_match_key(guide, match_by))
+            .collect::<HashSet<_>>();
+        let guides = match op {
+            CandidateSetOperator::Union => {
+                let mut seen = HashSet::new();
+                left.iter()
+                    .chain(right.iter())
+                    .filter(|guide| seen.insert(Self::guide_set_match_key(guide, match_by)))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            }
+            CandidateSetOperator::Intersect => left
+                .into_iter()
+                .filter(|guide| right_keys.contains(&Self::guide_set_match_key(guide, match_by)))
+                .collect(),
+            CandidateSetOperator::Subtract => left
+                .into_iter()
+                .filter(|guide| !right_keys.contains(&Self::guide_set_match_key(guide, match_by)))
+                .collect(),
+        };
+        if guides.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "GuideSetOp {} of '{}' and '{}' (match_by={}) left no guides; guide sets cannot be empty",
+                    op.as_str(),
+                    left_guide_set,
+                    right_guide_set,
+                    match_by.as_str()
+                ),
+
+                cause_chain: vec![],
+            });
+        }
+        // Coordinate matching can union two different guides sharing an id.
+        let guides = Self::normalize_guide_candidates(guides).map_err(|e| EngineError {
+            code: ErrorCode::InvalidInput,
+            message: format!(
+                "GuideSetOp {} of '{}' and '{}' (match_by={}) produced an invalid guide set: {}",
+                op.as_str(),
+                left_guide_set,
+                right_guide_set,
+                match_by.as_str(),
+                e.message
+            ),
+
+            cause_chain: vec![],
+        })?;
+
+        let now = Self::now_unix_ms();
+        let created_at_unix_ms = store
+            .guide_sets
+            .get(&output_guide_set)
+            .map(|set| set.created_at_unix_ms)
+            .unwrap_or(now);
+        let guide_count = guides.len();
+        let replaced = store
+            .guide_sets
+            .insert(
+                output_guide_set.clone(),
+                GuideSet {
+                    guide_set_id: output_guide_set.clone(),
+                    guides,
+                    created_at_unix_ms,
+                    updated_at_unix_ms: now,
+                },
+            )
+            .is_some();
+        if replaced {
+            result.warnings.push(
+                format!(
+                    "GuideSetOp output '{}' replaced existing guide set",
+                    output_guide_set
+                )
+                .into(),
+            );
+        }
+        Self::append_guide_design_audit(
+            &mut store,
+            "GuideSetOp",
+            &output_guide_set,
+            json!({
+                "op": op.as_str(),
+                "left_guide_set": left_guide_set,
+                "right_guide_set": right_guide_set,
+                "match_by": match_by.as_str(),
+                "guide_count": guide_count
+            }),
+        );
+        self.write_guide_design_store(store)?;
+        result.messages.push(format!(
+            "Guide set {} '{}' and '{}' into '{}' by {} ({} guides)",
+            op.as_str(),
+            left_guide_set,
+            right_guide_set,
+            output_guide_set,
+            match_by.as_str(),
+            guide_count
+        ));
+        Ok(())
+    }
+
     pub(super) fn op_generate_guide_oligos(
         &mut self,
         guide_set_id: String,
@@ -1159,12 +1588,121 @@ impl GentleEngine {
         Ok(())
     }
 
+    fn validate_reagent_plan(plan: &ReagentPlan) -> Result<(), EngineError> {
+        let invalid = |what: String| EngineError {
+            code: ErrorCode::InvalidInput,
+            message: format!("ExportGuideProtocolText reagent plan: {what}"),
+
+            cause_chain: vec![],
+        };
+        let volumes = [
+            (
+                "oligo_stock_concentration_um",
+                plan.oligo_stock_concentration_um,
+            ),
+            ("oligo_volume_ul", plan.oligo_volume_ul),
+            ("duplex_volume_ul", plan.duplex_volume_ul),
+            ("overage_fraction", plan.overage_fraction),
+        ];
+        for (name, value) in volumes {
+            if !value.is_finite() || value < 0.0 {
+                return Err(invalid(format!(
+                    "{name} must be a finite value >= 0 (got {value})"
+                )));
+            }
+        }
+        if !plan.duplex_dilution_factor.is_finite() || plan.duplex_dilution_factor < 1.0 {
+            return Err(invalid(format!(
+                "duplex_dilution_factor must be >= 1 (got {})",
+                plan.duplex_dilution_factor
+            )));
+        }
+        for component in plan.annealing_mix.iter().chain(&plan.ligation_mix) {
+            if component.name.trim().is_empty() {
+                return Err(invalid("mix components need a name".to_string()));
+            }
+            if !component.volume_ul.is_finite() || component.volume_ul < 0.0 {
+                return Err(invalid(format!(
+                    "volume for '{}' must be a finite value >= 0 (got {})",
+                    component.name, component.volume_ul
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reagent section of the guide protocol: per-guide annealing volumes,
+    /// then annealing/ligation master mixes scaled to the guide count with
+    /// the plan's overage.
+    fn format_guide_reagent_plan(plan: &ReagentPlan, records: &[GuideOligoRecord]) -> String {
+        let guide_count = records.len();
+        let scale = guide_count as f64 * (1.0 + plan.overage_fraction);
+        let annealing_mix_ul: f64 = plan.annealing_mix.iter().map(|c| c.volume_ul).sum();
+        let annealing_total_ul = 2.0 * plan.oligo_volume_ul + annealing_mix_ul;
+        let ligation_mix_ul: f64 = plan.ligation_mix.iter().map(|c| c.volume_ul).sum();
+        let oligo_label = format!("{} uM", plan.oligo_stock_concentration_um);
+
+        let mut text = String::new();
+        text.push_str(&format!("Reagent plan: {}\n", plan.recipe_name));
+        text.push_str(&format!(
+            "Guides: {} (master mixes sized for {:.2} reactions, {:.0}% overage)\n\n",
+            guide_count,
+            scale,
+            plan.overage_fraction * 100.0
+        ));
+        text.push_str(&format!(
+            "Per-guide oligo annealing ({:.1} uL per reaction):\n",
+            annealing_total_ul
+        ));
+        text.push_str(&format!(
+            "guide_id\tforward oligo ({oligo_label}) uL\treverse oligo ({oligo_label}) uL\tannealing mix uL\ttotal uL\n"
+        ));
+        for record in records {
+            text.push_str(&format!(
+                "{}\t{:.1}\t{:.1}\t{:.1}\t{:.1}\n",
+                record.guide_id,
+                plan.oligo_volume_ul,
+                plan.oligo_volume_ul,
+                annealing_mix_ul,
+                annealing_total_ul
+            ));
+        }
+        let mut push_master_mix = |title: &str, components: &[ReagentComponent]| {
+            text.push_str(&format!(
+                "\n{} master mix ({} reaction(s) + {:.0}% overage):\n",
+                title,
+                guide_count,
+                plan.overage_fraction * 100.0
+            ));
+            text.push_str("component\tper reaction uL\ttotal uL\n");
+            for component in components {
+                text.push_str(&format!(
+                    "{}\t{:.1}\t{:.1}\n",
+                    component.name,
+                    component.volume_ul,
+                    component.volume_ul * scale
+                ));
+            }
+        };
+        push_master_mix("Annealing", &plan.annealing_mix);
+        push_master_mix("Ligation", &plan.ligation_mix);
+        text.push_str(&format!(
+            "Per guide: {:.1} uL ligation master mix + {:.1} uL annealed duplex (diluted 1:{}) = {:.1} uL\n",
+            ligation_mix_ul,
+            plan.duplex_volume_ul,
+            plan.duplex_dilution_factor,
+            ligation_mix_ul + plan.duplex_volume_ul
+        ));
+        text
+    }
+
     pub(super) fn op_export_guide_protocol_text(
         &mut self,
         guide_set_id: String,
         oligo_set_id: Option<String>,
         path: String,
         include_qc_checklist: Option<bool>,
+        reagents: Option<ReagentPlan>,
         result: &mut OpResult,
     ) -> Result<(), EngineError> {
         let guide_set_id = Self::normalize_guide_set_id(&guide_set_id)?;
@@ -1176,6 +1714,8 @@ impl GentleEngine {
                 cause_chain: vec![],
             });
         }
+        let reagents = reagents.unwrap_or_default();
+        Self::validate_reagent_plan(&reagents)?;
         let mut store = self.read_guide_design_store();
         if !store.guide_sets.contains_key(&guide_set_id) {
             return Err(EngineError {
@@ -1221,6 +1761,11 @@ impl GentleEngine {
                 text.push_str(&format!("  notes: {}\n", record.notes.join("; ")));
             }
         }
+        text.push('\n');
+        text.push_str(&Self::format_guide_reagent_plan(
+            &reagents,
+            &oligo_set.records,
+        ));
         if include_qc {
             text.push_str("\nQC checklist:\n");
             text.push_str("- Confirm oligo lengths and overhang sequences.\n");
@@ -1244,6 +1789,7 @@ impl GentleEngine {
             oligo_set_id: oligo_set.oligo_set_id.clone(),
             path: path.clone(),
             guide_count: oligo_set.records.len(),
+            reagent_recipe: reagents.recipe_name.clone(),
         };
         Self::append_guide_design_audit(
             &mut store,
@@ -1253,43 +1799,156 @@ impl GentleEngine {
         );
         self.write_guide_design_store(store)?;
         result.messages.push(format!(
-            "Exported guide protocol text for '{}' to '{}'",
-            guide_set_id, report.path
+            "Exported guide protocol text for '{}' to '{}'",
+            guide_set_id, report.path
+        ));
+        Ok(())
+    }
+
+    pub(super) fn op_delete_candidate_set(
+        &mut self,
+        set_name: String,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let set_name = Self::normalize_candidate_set_name(&set_name)?;
+        let mut store = self.read_candidate_store();
+        let removed = store.sets.remove(&set_name).is_some();
+        self.write_candidate_store(store)?;
+        if removed {
+            result
+                .messages
+                .push(format!("Deleted candidate set '{}'", set_name));
+        } else {
+            result
+                .warnings
+                .push(format!("Candidate set '{}' was not present", set_name).into());
+        }
+        Ok(())
+    }
+
+    pub(super) fn op_score_candidate_set_expression(
+        &mut self,
+        set_name: String,
+        metric: String,
+        expression: String,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let set_name = Self::normalize_candidate_set_name(&set_name)?;
+        let metric_name = Self::normalize_metric_name(&metric);
+        let expr = Self::parse_metric_expression(&expression)?;
+        let mut store = self.read_candidate_store();
+        let set = store.sets.get_mut(&set_name).ok_or_else(|| EngineError {
+            code: ErrorCode::NotFound,
+            message: format!("Candidate set '{}' not found", set_name),
+
+            cause_chain: vec![],
+        })?;
+        if set.candidates.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Candidate set '{}' is empty", set_name),
+
+                cause_chain: vec![],
+            });
+        }
+        let candidates = &set.candidates;
+        let values = self.score_candidates(candidates.len(), |idx| {
+            Self::evaluate_metric_expression(&expr, &candidates[idx].metrics).map_err(|e| {
+                EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "Could not evaluate expression for candidate {} in '{}': {}",
+                        idx, set_name, e.message
+                    ),
+
+                    cause_chain: vec![],
+                }
+            })
+        })?;
+        for (candidate, value) in set.candidates.iter_mut().zip(values.iter()) {
+            candidate.metrics.insert(metric_name.clone(), *value);
+        }
+        let min_value = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max_value = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        self.write_candidate_store(store)?;
+        result.messages.push(format!(
+            "Scored candidate set '{}' with metric '{}' from expression '{}'",
+            set_name, metric_name, expression
+        ));
+        result.messages.push(format!(
+            "Metric '{}' range in '{}': [{:.6}, {:.6}]",
+            metric_name, set_name, min_value, max_value
         ));
         Ok(())
     }
 
-    pub(super) fn op_delete_candidate_set(
+    /// Store the shared primer Tm estimate (nearest-neighbor, falling back to
+    /// the 2/4 rule for short or ambiguous sequences) for every candidate.
+    pub(super) fn op_score_candidate_set_tm(
         &mut self,
         set_name: String,
+        metric: String,
         result: &mut OpResult,
     ) -> Result<(), EngineError> {
         let set_name = Self::normalize_candidate_set_name(&set_name)?;
+        let metric_name = Self::normalize_metric_name(&metric);
         let mut store = self.read_candidate_store();
-        let removed = store.sets.remove(&set_name).is_some();
+        let set = store.sets.get_mut(&set_name).ok_or_else(|| EngineError {
+            code: ErrorCode::NotFound,
+            message: format!("Candidate set '{}' not found", set_name),
+
+            cause_chain: vec![],
+        })?;
+        if set.candidates.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Candidate set '{}' is empty", set_name),
+
+                cause_chain: vec![],
+            });
+        }
+        let mut fallback_count = 0usize;
+        let mut values = Vec::with_capacity(set.candidates.len());
+        for candidate in &mut set.candidates {
+            let (tm_c, nearest_neighbor) =
+                Self::estimate_primer_tm_with_model_c(candidate.sequence.as_bytes());
+            if !nearest_neighbor {
+                fallback_count += 1;
+            }
+            candidate.metrics.insert(metric_name.clone(), tm_c);
+            values.push(tm_c);
+        }
+        let min_value = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max_value = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
         self.write_candidate_store(store)?;
-        if removed {
-            result
-                .messages
-                .push(format!("Deleted candidate set '{}'", set_name));
-        } else {
-            result
-                .warnings
-                .push(format!("Candidate set '{}' was not present", set_name));
+        result.messages.push(format!(
+            "Scored candidate set '{}' with Tm metric '{}' (nearest-neighbor model)",
+            set_name, metric_name
+        ));
+        result.messages.push(format!(
+            "Metric '{}' range in '{}': [{:.6}, {:.6}]",
+            metric_name, set_name, min_value, max_value
+        ));
+        if fallback_count > 0 {
+            result.warnings.push(format!(
+                "{} candidate(s) in '{}' were too short or ambiguous for the nearest-neighbor model; used the Wallace 2/4 rule",
+                fallback_count, set_name
+            ).into());
         }
         Ok(())
     }
 
-    pub(super) fn op_score_candidate_set_expression(
+    /// Store the longest perfect inverted-repeat stem (loop of at most
+    /// `CANDIDATE_SELF_STRUCTURE_MAX_LOOP` bases) inside each candidate as a
+    /// self-folding penalty; `0` means no paired stem at all.
+    pub(super) fn op_score_candidate_set_self_structure(
         &mut self,
         set_name: String,
         metric: String,
-        expression: String,
         result: &mut OpResult,
     ) -> Result<(), EngineError> {
         let set_name = Self::normalize_candidate_set_name(&set_name)?;
         let metric_name = Self::normalize_metric_name(&metric);
-        let expr = Self::parse_metric_expression(&expression)?;
         let mut store = self.read_candidate_store();
         let set = store.sets.get_mut(&set_name).ok_or_else(|| EngineError {
             code: ErrorCode::NotFound,
@@ -1306,30 +1965,26 @@ impl GentleEngine {
             });
         }
         let mut values = Vec::with_capacity(set.candidates.len());
-        for (idx, candidate) in set.candidates.iter().enumerate() {
-            let value =
-                Self::evaluate_metric_expression(&expr, &candidate.metrics).map_err(|e| {
-                    EngineError {
-                        code: ErrorCode::InvalidInput,
-                        message: format!(
-                            "Could not evaluate expression for candidate {} in '{}': {}",
-                            idx, set_name, e.message
-                        ),
-
-                        cause_chain: vec![],
-                    }
-                })?;
-            values.push(value);
-        }
-        for (candidate, value) in set.candidates.iter_mut().zip(values.iter()) {
-            candidate.metrics.insert(metric_name.clone(), *value);
+        for candidate in &mut set.candidates {
+            let penalty = Self::scan_inverted_repeats(
+                candidate.sequence.as_bytes(),
+                1,
+                CANDIDATE_SELF_STRUCTURE_MAX_LOOP,
+                0,
+            )
+            .iter()
+            .map(|repeat| repeat.stem_length_bp)
+            .max()
+            .unwrap_or(0) as f64;
+            candidate.metrics.insert(metric_name.clone(), penalty);
+            values.push(penalty);
         }
         let min_value = values.iter().copied().fold(f64::INFINITY, f64::min);
         let max_value = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
         self.write_candidate_store(store)?;
         result.messages.push(format!(
-            "Scored candidate set '{}' with metric '{}' from expression '{}'",
-            set_name, metric_name, expression
+            "Scored candidate set '{}' with self-structure penalty '{}' (max perfect stem, loop <= {} bp)",
+            set_name, metric_name, CANDIDATE_SELF_STRUCTURE_MAX_LOOP
         ));
         result.messages.push(format!(
             "Metric '{}' range in '{}': [{:.6}, {:.6}]",
@@ -1338,6 +1993,93 @@ impl GentleEngine {
         Ok(())
     }
 
+    /// Count IUPAC-aware motif matches per candidate.
+    ///
+    /// Every start position that matches counts once, so overlapping matches
+    /// are all counted (`AA` occurs 3 times in `AAAA`). With `both_strands`,
+    /// a start position counts when either the motif or its reverse
+    /// complement matches there; palindromic sites are not double counted.
+    pub(super) fn op_score_candidate_set_motif_count(
+        &mut self,
+        set_name: String,
+        metric: String,
+        motif: String,
+        both_strands: bool,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let set_name = Self::normalize_candidate_set_name(&set_name)?;
+        let metric_name = Self::normalize_metric_name(&metric);
+        let motif = motif.trim().to_ascii_uppercase().into_bytes();
+        if motif.is_empty() || !motif.iter().all(|b| IupacCode::is_valid_letter(*b)) {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "ScoreCandidateSetMotifCount motif '{}' must be a non-empty IUPAC DNA sequence",
+                    String::from_utf8_lossy(&motif)
+                ),
+
+                cause_chain: vec![],
+            });
+        }
+        let reverse_motif = motif
+            .iter()
+            .rev()
+            .map(|b| IupacCode::letter_complement(*b))
+            .collect::<Vec<_>>();
+        let palindromic = reverse_motif == motif;
+
+        let mut store = self.read_candidate_store();
+        let set = store.sets.get_mut(&set_name).ok_or_else(|| EngineError {
+            code: ErrorCode::NotFound,
+            message: format!("Candidate set '{}' not found", set_name),
+
+            cause_chain: vec![],
+        })?;
+        if set.candidates.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Candidate set '{}' is empty", set_name),
+
+                cause_chain: vec![],
+            });
+        }
+        let mut total_matches = 0usize;
+        for candidate in &mut set.candidates {
+            let sequence = candidate.sequence.to_ascii_uppercase().into_bytes();
+            let count = if sequence.len() < motif.len() {
+                0
+            } else {
+                (0..=sequence.len() - motif.len())
+                    .filter(|start| {
+                        Self::iupac_match_at(&sequence, &motif, *start)
+                            || (both_strands
+                                && Self::iupac_match_at(&sequence, &reverse_motif, *start))
+                    })
+                    .count()
+            };
+            total_matches += count;
+            candidate.metrics.insert(metric_name.clone(), count as f64);
+        }
+        let candidate_count = set.candidates.len();
+        self.write_candidate_store(store)?;
+        result.messages.push(format!(
+            "Scored candidate set '{}' with motif count metric '{}' (motif={}, both_strands={}, palindromic={})",
+            set_name,
+            metric_name,
+            String::from_utf8_lossy(&motif),
+            both_strands,
+            palindromic
+        ));
+        result.messages.push(format!(
+            "Motif '{}' matched {} time(s) across {} candidate(s) in '{}'",
+            String::from_utf8_lossy(&motif),
+            total_matches,
+            candidate_count,
+            set_name
+        ));
+        Ok(())
+    }
+
     pub(super) fn op_score_candidate_set_distance(
         &mut self,
         set_name: String,
@@ -1374,7 +2116,7 @@ impl GentleEngine {
         {
             result.warnings.push(
                 "feature_boundary_mode is ignored unless feature_geometry_mode=feature_boundaries"
-                    .to_string(),
+                    .into(),
             );
         }
 
@@ -1394,7 +2136,7 @@ impl GentleEngine {
             });
         }
 
-        let mut feature_cache: HashMap<String, Vec<FeatureDistanceTarget>> = HashMap::new();
+        let mut feature_cache: HashMap<String, FeatureDistanceIndex> = HashMap::new();
         for seq_id in set
             .candidates
             .iter()
@@ -1412,19 +2154,27 @@ impl GentleEngine {
                     cause_chain: vec![],
                 });
             };
+            let targets = Self::collect_feature_distance_targets(
+                dna,
+                feature_geometry_mode,
+                effective_boundary_mode,
+            );
             feature_cache.insert(
                 seq_id.clone(),
-                Self::collect_feature_distance_targets(
-                    dna,
-                    feature_geometry_mode,
-                    effective_boundary_mode,
+                Self::build_feature_distance_index(
+                    &targets,
+                    &kind_filter_upper,
+                    label_regex.as_ref(),
+                    feature_strand_relation,
                 ),
             );
         }
 
-        let mut values = Vec::with_capacity(set.candidates.len());
-        for (idx, candidate) in set.candidates.iter().enumerate() {
-            let features = feature_cache
+        // Indexes are built once per sequence above and only read here.
+        let candidates = &set.candidates;
+        let values = self.score_candidates(candidates.len(), |idx| {
+            let candidate = &candidates[idx];
+            let index = feature_cache
                 .get(&candidate.seq_id)
                 .ok_or_else(|| EngineError {
                     code: ErrorCode::Internal,
@@ -1435,25 +2185,19 @@ impl GentleEngine {
 
                     cause_chain: vec![],
                 })?;
-            let distance = Self::nearest_feature_distance(
-                candidate.start_0based,
-                candidate.end_0based,
-                features,
-                &kind_filter_upper,
-                label_regex.as_ref(),
-                feature_strand_relation,
-            )
-            .ok_or_else(|| EngineError {
-                code: ErrorCode::InvalidInput,
-                message: format!(
-                    "No matching features found for candidate {} (seq='{}')",
-                    idx, candidate.seq_id
-                ),
+            let distance = index
+                .nearest_distance(candidate.start_0based, candidate.end_0based)
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "No matching features found for candidate {} (seq='{}')",
+                        idx, candidate.seq_id
+                    ),
 
-                cause_chain: vec![],
-            })?;
-            values.push(distance as f64);
-        }
+                    cause_chain: vec![],
+                })?;
+            Ok(distance as f64)
+        })?;
 
         for (candidate, value) in set.candidates.iter_mut().zip(values.iter()) {
             candidate.metrics.insert(metric_name.clone(), *value);
@@ -1643,10 +2387,13 @@ impl GentleEngine {
             input_set, metric_name, output_set, kept, dropped
         ));
         if replaced_existing {
-            result.warnings.push(format!(
-                "FilterCandidateSet output '{}' replaced existing candidate set",
-                output_set
-            ));
+            result.warnings.push(
+                format!(
+                    "FilterCandidateSet output '{}' replaced existing candidate set",
+                    output_set
+                )
+                .into(),
+            );
         }
         Ok(())
     }
@@ -1746,10 +2493,13 @@ impl GentleEngine {
             output_count
         ));
         if replaced_existing {
-            result.warnings.push(format!(
-                "CandidateSetOp output '{}' replaced existing candidate set",
-                output_set
-            ));
+            result.warnings.push(
+                format!(
+                    "CandidateSetOp output '{}' replaced existing candidate set",
+                    output_set
+                )
+                .into(),
+            );
         }
         Ok(())
     }
@@ -1792,15 +2542,41 @@ impl GentleEngine {
                 .then(a.seq_id.cmp(&b.seq_id))
                 .then(a.start_0based.cmp(&b.start_0based))
                 .then(a.end_0based.cmp(&b.end_0based)),
+            // Ties keep the order laid down by `seed_candidate_tie_break_order`.
+            CandidateTieBreakPolicy::Seeded => Ordering::Equal,
         }
     }
 
+    /// Lay `items` out for a stable sort under `policy`. For `Seeded`, the
+    /// canonical `SeqStartEnd` order is shuffled with `SeededRng` so ties
+    /// depend only on `random_seed`, not on the input order; other policies
+    /// compare candidate fields and need no preparation.
+    fn seed_candidate_tie_break_order<T>(
+        items: &mut [T],
+        policy: CandidateTieBreakPolicy,
+        random_seed: u64,
+        candidate: impl Fn(&T) -> &CandidateRecord,
+    ) {
+        if policy != CandidateTieBreakPolicy::Seeded {
+            return;
+        }
+        items.sort_by(|a, b| {
+            Self::compare_candidates_by_tie_break(
+                candidate(a),
+                candidate(b),
+                CandidateTieBreakPolicy::SeqStartEnd,
+            )
+        });
+        SeededRng::new(random_seed).shuffle(items);
+    }
+
     pub(super) fn op_score_candidate_set_weighted_objective(
         &mut self,
         set_name: String,
         metric: String,
         objectives: Vec<CandidateWeightedObjectiveTerm>,
         normalize_metrics: Option<bool>,
+        missing_policy: Option<MissingValuePolicy>,
         result: &mut OpResult,
     ) -> Result<(), EngineError> {
         let set_name = Self::normalize_candidate_set_name(&set_name)?;
@@ -1849,26 +2625,17 @@ impl GentleEngine {
             });
         }
 
-        let mut bounds = Vec::with_capacity(compiled.len());
-        for (metric_name, _, _) in &compiled {
-            let mut min_value = f64::INFINITY;
-            let mut max_value = f64::NEG_INFINITY;
-            for (idx, candidate) in set.candidates.iter().enumerate() {
-                let value =
-                    candidate
-                        .metrics
-                        .get(metric_name)
-                        .copied()
-                        .ok_or_else(|| EngineError {
-                            code: ErrorCode::InvalidInput,
-                            message: format!(
-                                "Candidate {} in '{}' is missing metric '{}'",
-                                idx, set_name, metric_name
-                            ),
-
-                            cause_chain: vec![],
-                        })?;
-                if !value.is_finite() {
+        // Resolve raw term values per candidate; `None` marks candidates that
+        // the missing-value policy leaves unscored.
+        let mut raw_rows: Vec<Option<Vec<Option<f64>>>> = Vec::with_capacity(set.candidates.len());
+        let mut affected = 0usize;
+        for (idx, candidate) in set.candidates.iter().enumerate() {
+            let mut row = Vec::with_capacity(compiled.len());
+            for (metric_name, _, _) in &compiled {
+                let value = candidate.metrics.get(metric_name).copied();
+                if let Some(value) = value
+                    && !value.is_finite()
+                {
                     return Err(EngineError {
                         code: ErrorCode::InvalidInput,
                         message: format!(
@@ -1879,32 +2646,80 @@ impl GentleEngine {
                         cause_chain: vec![],
                     });
                 }
+                if value.is_none() && missing_policy.is_none() {
+                    return Err(EngineError {
+                        code: ErrorCode::InvalidInput,
+                        message: format!(
+                            "Candidate {} in '{}' is missing metric '{}' (set missing_policy to skip_candidate, treat_as_zero, or treat_as_worst)",
+                            idx, set_name, metric_name
+                        ),
+
+                        cause_chain: vec![],
+                    });
+                }
+                row.push(value);
+            }
+            let has_missing = row.iter().any(Option::is_none);
+            if has_missing {
+                affected += 1;
+            }
+            if has_missing && missing_policy == Some(MissingValuePolicy::SkipCandidate) {
+                raw_rows.push(None);
+            } else {
+                raw_rows.push(Some(row));
+            }
+        }
+
+        let mut bounds = Vec::with_capacity(compiled.len());
+        for (term_idx, (metric_name, _, direction)) in compiled.iter().enumerate() {
+            let present = raw_rows
+                .iter()
+                .flatten()
+                .filter_map(|row| row[term_idx])
+                .collect::<Vec<_>>();
+            let Some(worst_fallback) = (match direction {
+                CandidateObjectiveDirection::Maximize => present.iter().copied().reduce(f64::min),
+                CandidateObjectiveDirection::Minimize => present.iter().copied().reduce(f64::max),
+            }) else {
+                if missing_policy == Some(MissingValuePolicy::TreatAsZero) {
+                    bounds.push((0.0, 0.0, 0.0));
+                    continue;
+                }
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "No scorable candidate in '{}' has metric '{}'",
+                        set_name, metric_name
+                    ),
+
+                    cause_chain: vec![],
+                });
+            };
+            let fill = match missing_policy {
+                Some(MissingValuePolicy::TreatAsZero) => 0.0,
+                _ => worst_fallback,
+            };
+            let mut min_value = f64::INFINITY;
+            let mut max_value = f64::NEG_INFINITY;
+            for row in raw_rows.iter().flatten() {
+                let value = row[term_idx].unwrap_or(fill);
                 min_value = min_value.min(value);
                 max_value = max_value.max(value);
             }
-            bounds.push((min_value, max_value));
+            bounds.push((min_value, max_value, fill));
         }
 
         let mut combined_values = Vec::with_capacity(set.candidates.len());
-        for candidate in &mut set.candidates {
+        for (candidate, row) in set.candidates.iter_mut().zip(raw_rows) {
+            let Some(row) = row else {
+                candidate.metrics.remove(&metric_name);
+                continue;
+            };
             let mut combined = 0.0f64;
-            for ((metric_name, weight, direction), (min_value, max_value)) in
-                compiled.iter().zip(bounds.iter())
+            for (((_, weight, direction), (min_value, max_value, fill)), raw_value) in
+                compiled.iter().zip(bounds.iter()).zip(row)
             {
-                let raw_value =
-                    candidate
-                        .metrics
-                        .get(metric_name)
-                        .copied()
-                        .ok_or_else(|| EngineError {
-                            code: ErrorCode::InvalidInput,
-                            message: format!(
-                                "Candidate in '{}' is missing metric '{}'",
-                                set_name, metric_name
-                            ),
-
-                            cause_chain: vec![],
-                        })?;
+                let raw_value = raw_value.unwrap_or(*fill);
                 let objective_value = if normalize_metrics {
                     let scaled = if *max_value > *min_value {
                         (raw_value - *min_value) / (*max_value - *min_value)
@@ -1926,6 +2741,17 @@ impl GentleEngine {
             candidate.metrics.insert(metric_name.clone(), combined);
             combined_values.push(combined);
         }
+        if combined_values.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "No candidate in '{}' could be scored under missing_policy=skip_candidate",
+                    set_name
+                ),
+
+                cause_chain: vec![],
+            });
+        }
 
         let min_combined = combined_values
             .iter()
@@ -1944,6 +2770,14 @@ impl GentleEngine {
             "Weighted objective mode for '{}': normalize_metrics={}",
             set_name, normalize_metrics
         ));
+        if let Some(policy) = missing_policy {
+            result.messages.push(format!(
+                "Missing-value policy '{}' affected {} candidate(s) in '{}'",
+                policy.as_str(),
+                affected,
+                set_name
+            ));
+        }
         result.messages.push(format!(
             "Metric '{}' range in '{}': [{:.6}, {:.6}]",
             metric_name, set_name, min_combined, max_combined
@@ -2023,6 +2857,12 @@ impl GentleEngine {
             }
             scored.push((candidate, value));
         }
+        Self::seed_candidate_tie_break_order(
+            &mut scored,
+            tie_break,
+            self.state.parameters.random_seed,
+            |(candidate, _)| candidate,
+        );
         scored.sort_by(|(left, left_value), (right, right_value)| {
             let primary = match direction {
                 CandidateObjectiveDirection::Maximize => right_value
@@ -2068,14 +2908,80 @@ impl GentleEngine {
             tie_break.as_str()
         ));
         if replaced_existing {
-            result.warnings.push(format!(
-                "TopKCandidateSet output '{}' replaced existing candidate set",
-                output_set
-            ));
+            result.warnings.push(
+                format!(
+                    "TopKCandidateSet output '{}' replaced existing candidate set",
+                    output_set
+                )
+                .into(),
+            );
         }
         Ok(())
     }
 
+    /// Thin a strict Pareto frontier with additive epsilon-dominance.
+    ///
+    /// Objectives are rescaled to `[0, 1]` over the observed range (oriented so
+    /// larger is better); survivors are visited in tie-break order and kept
+    /// unless an already kept candidate is within `epsilon` of them or better
+    /// on every objective.
+    fn apply_candidate_epsilon_dominance(
+        candidates: &[CandidateRecord],
+        objective_values: &[Vec<f64>],
+        objectives: &[CandidateObjectiveSpec],
+        epsilon: f64,
+        tie_break: CandidateTieBreakPolicy,
+        random_seed: u64,
+        dominated: &mut [bool],
+    ) {
+        let ranges = (0..objectives.len())
+            .map(|idx| {
+                objective_values
+                    .iter()
+                    .map(|row| row[idx])
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), value| {
+                        (lo.min(value), hi.max(value))
+                    })
+            })
+            .collect::<Vec<_>>();
+        let scaled = |row: &[f64], idx: usize| {
+            let (lo, hi) = ranges[idx];
+            if hi <= lo {
+                return 0.0;
+            }
+            match objectives[idx].direction {
+                CandidateObjectiveDirection::Maximize => (row[idx] - lo) / (hi - lo),
+                CandidateObjectiveDirection::Minimize => (hi - row[idx]) / (hi - lo),
+            }
+        };
+        let mut order = candidates
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !dominated[*idx])
+            .collect::<Vec<_>>();
+        Self::seed_candidate_tie_break_order(
+            &mut order,
+            tie_break,
+            random_seed,
+            |(_, candidate)| candidate,
+        );
+        order.sort_by(|(_, a), (_, b)| Self::compare_candidates_by_tie_break(a, b, tie_break));
+        let mut kept: Vec<usize> = vec![];
+        for (idx, _) in order {
+            let covered = kept.iter().any(|kept_idx| {
+                (0..objectives.len()).all(|obj| {
+                    scaled(&objective_values[*kept_idx], obj) + epsilon
+                        >= scaled(&objective_values[idx], obj)
+                })
+            });
+            if covered {
+                dominated[idx] = true;
+            } else {
+                kept.push(idx);
+            }
+        }
+    }
+
     pub(super) fn candidate_dominates(
         left_values: &[f64],
         right_values: &[f64],
@@ -2114,6 +3020,7 @@ impl GentleEngine {
         objectives: Vec<CandidateObjectiveSpec>,
         max_candidates: Option<usize>,
         tie_break: Option<CandidateTieBreakPolicy>,
+        epsilon: Option<f64>,
         result: &mut OpResult,
     ) -> Result<(), EngineError> {
         let input_set = Self::normalize_candidate_set_name(&input_set)?;
@@ -2134,6 +3041,17 @@ impl GentleEngine {
                 cause_chain: vec![],
             });
         }
+        let epsilon = epsilon.unwrap_or(0.0);
+        if !epsilon.is_finite() || epsilon < 0.0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "ParetoFrontierCandidateSet epsilon must be finite and >= 0 (got {epsilon})"
+                ),
+
+                cause_chain: vec![],
+            });
+        }
         let tie_break = tie_break.unwrap_or_default();
 
         let mut compiled = Vec::with_capacity(objectives.len());
@@ -2214,6 +3132,26 @@ impl GentleEngine {
             }
         }
 
+        let strict_frontier_count = dominated.iter().filter(|d| !**d).count();
+        if epsilon > 0.0 {
+            Self::apply_candidate_epsilon_dominance(
+                &input.candidates,
+                &objective_values,
+                &compiled,
+                epsilon,
+                tie_break,
+                self.state.parameters.random_seed,
+                &mut dominated,
+            );
+            result.messages.push(format!(
+                "Epsilon-dominance (epsilon={}) reduced Pareto frontier for '{}' from {} to {} candidate(s)",
+                epsilon,
+                input_set,
+                strict_frontier_count,
+                dominated.iter().filter(|d| !**d).count()
+            ));
+        }
+
         let mut frontier = input
             .candidates
             .into_iter()
@@ -2226,15 +3164,24 @@ impl GentleEngine {
         if let Some(limit) = max_candidates
             && frontier.len() > limit
         {
+            Self::seed_candidate_tie_break_order(
+                &mut frontier,
+                tie_break,
+                self.state.parameters.random_seed,
+                |candidate| candidate,
+            );
             frontier.sort_by(|a, b| Self::compare_candidates_by_tie_break(a, b, tie_break));
             frontier.truncate(limit);
-            result.warnings.push(format!(
+            result.warnings.push(Warning::new(
+                WarningCode::ResultTruncated,
+                format!(
                     "Pareto frontier for '{}' had {} candidates and was truncated to {} by tie-break policy '{}'",
                     input_set,
                     raw_frontier_count,
                     limit,
                     tie_break.as_str()
-                ));
+                ),
+            ));
         }
 
         let output_count = frontier.len();
@@ -2263,10 +3210,13 @@ impl GentleEngine {
                 .join(", ")
         ));
         if replaced_existing {
-            result.warnings.push(format!(
-                "ParetoFrontierCandidateSet output '{}' replaced existing candidate set",
-                output_set
-            ));
+            result.warnings.push(
+                format!(
+                    "ParetoFrontierCandidateSet output '{}' replaced existing candidate set",
+                    output_set
+                )
+                .into(),
+            );
         }
         Ok(())
     }
@@ -2485,10 +3435,9 @@ impl GentleEngine {
                 .messages
                 .push(format!("Deleted workflow macro template '{}'", name));
         } else {
-            result.warnings.push(format!(
-                "Workflow macro template '{}' was not present",
-                name
-            ));
+            result
+                .warnings
+                .push(format!("Workflow macro template '{}' was not present", name).into());
         }
         Ok(())
     }
@@ -2623,10 +3572,9 @@ impl GentleEngine {
                 .messages
                 .push(format!("Deleted candidate macro template '{}'", name));
         } else {
-            result.warnings.push(format!(
-                "Candidate macro template '{}' was not present",
-                name
-            ));
+            result
+                .warnings
+                .push(format!("Candidate macro template '{}' was not present", name).into());
         }
         Ok(())
     }
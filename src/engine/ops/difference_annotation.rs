@@ -0,0 +1,169 @@
+//! Variant annotation from a reference/variant alignment.
+//!
+//! `AnnotateDifferences` globally aligns a reference and a variant (same
+//! aligner and default scores as `AlignSequences`) and adds one `variation`
+//! feature per difference to the variant. Substitutions are reported base by
+//! base as `A123G`; each insertion or deletion run becomes one feature, noted
+//! HGVS-style as `123_124insGG` or `123_125delACG` (`123delA` for one base).
+//! Positions in notes are 1-based reference coordinates. An insertion spans
+//! the inserted variant bases; a deletion leaves no variant bases, so it spans
+//! the two variant bases flanking the gap. Sequences are aligned linearly,
+//! also when circular.
+
+use super::*;
+use bio::alignment::AlignmentOperation;
+
+impl GentleEngine {
+    /// `(variant_start_0based, variant_end_0based, note)` per difference
+    /// along the global alignment of `reference` (x) and `variant` (y).
+    fn sequence_differences(
+        reference: &[u8],
+        variant: &[u8],
+        operations: &[AlignmentOperation],
+    ) -> Vec<(usize, usize, String)> {
+        let mut out = vec![];
+        let (mut x, mut y) = (0usize, 0usize);
+        let mut idx = 0usize;
+        while idx < operations.len() {
+            let op = operations[idx];
+            let run = operations[idx..]
+                .iter()
+                .take_while(|next| **next == op)
+                .count();
+            match op {
+                AlignmentOperation::Match => {
+                    x += run;
+                    y += run;
+                }
+                AlignmentOperation::Subst => {
+                    for offset in 0..run {
+                        out.push((
+                            y + offset,
+                            y + offset + 1,
+                            format!(
+                                "{}{}{}",
+                                reference[x + offset] as char,
+                                x + offset + 1,
+                                variant[y + offset] as char
+                            ),
+                        ));
+                    }
+                    x += run;
+                    y += run;
+                }
+                // Reference bases missing from the variant.
+                AlignmentOperation::Ins => {
+                    let bases = String::from_utf8_lossy(&reference[x..x + run]);
+                    let note = if run == 1 {
+                        format!("{}del{bases}", x + 1)
+                    } else {
+                        format!("{}_{}del{bases}", x + 1, x + run)
+                    };
+                    out.push((y.saturating_sub(1), (y + 1).min(variant.len()), note));
+                    x += run;
+                }
+                // Variant bases absent from the reference.
+                AlignmentOperation::Del => {
+                    let bases = String::from_utf8_lossy(&variant[y..y + run]);
+                    out.push((y, y + run, format!("{x}_{}ins{bases}", x + 1)));
+                    y += run;
+                }
+                AlignmentOperation::Xclip(_) | AlignmentOperation::Yclip(_) => {}
+            }
+            idx += run;
+        }
+        out
+    }
+
+    pub(super) fn annotate_differences(
+        &mut self,
+        result: &mut OpResult,
+        reference: &str,
+        variant: &str,
+    ) -> Result<(), EngineError> {
+        if reference == variant {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "AnnotateDifferences requires distinct reference and variant sequences"
+                    .to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let bases = |seq_id: &str| {
+            self.state
+                .sequences
+                .get(seq_id)
+                .map(|dna| dna.get_forward_string().to_ascii_uppercase())
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::NotFound,
+                    message: format!("Sequence '{seq_id}' not found"),
+                    cause_chain: vec![],
+                })
+        };
+        let reference_text = bases(reference)?;
+        let variant_text = bases(variant)?;
+        let alignment = Self::compute_pairwise_alignment_report(
+            reference,
+            &reference_text,
+            None,
+            None,
+            variant,
+            &variant_text,
+            None,
+            None,
+            PairwiseAlignmentMode::Global,
+            default_pairwise_match_score(),
+            default_pairwise_mismatch_score(),
+            default_pairwise_gap_open(),
+            default_pairwise_gap_extend(),
+        )?;
+        let differences = Self::sequence_differences(
+            reference_text.as_bytes(),
+            variant_text.as_bytes(),
+            &alignment.operations,
+        );
+
+        let _ = self.ensure_lineage_node(variant);
+        let dna = self
+            .state
+            .sequences
+            .get_mut(variant)
+            .expect("variant sequence checked above");
+        let mut added = 0usize;
+        let mut existing = 0usize;
+        for (start, end, note) in &differences {
+            if start >= end {
+                result.warnings.push(
+                    format!("Difference '{note}' has no variant bases to anchor a feature on")
+                        .into(),
+                );
+                continue;
+            }
+            let feature = gb_io::seq::Feature {
+                kind: "variation".into(),
+                location: gb_io::seq::Location::simple_range(*start as i64, *end as i64),
+                qualifiers: vec![("note".into(), Some(note.clone()))],
+            };
+            let duplicate = dna.features().iter().any(|present| {
+                present.kind == feature.kind
+                    && present.location == feature.location
+                    && present.qualifiers == feature.qualifiers
+            });
+            if duplicate {
+                existing += 1;
+                continue;
+            }
+            dna.features_mut().push(feature);
+            added += 1;
+        }
+        if added > 0 {
+            dna.update_computed_features();
+            result.changed_seq_ids.push(variant.to_string());
+        }
+        result.messages.push(format!(
+            "Annotated {added} difference(s) of '{variant}' against '{reference}' (already_present={existing}, identity={:.3}, cigar={})",
+            alignment.report.identity_fraction, alignment.report.cigar
+        ));
+        Ok(())
+    }
+}
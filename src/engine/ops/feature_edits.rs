@@ -0,0 +1,202 @@
+//! Manual feature curation primitives.
+//!
+//! `AddFeature` writes one feature with a simple or joined location and
+//! caller-supplied qualifiers; `DeleteFeature` removes one feature by index.
+//! Feature ids are positions in the sequence's feature list, so a deletion
+//! shifts the ids of every later feature down by one.
+
+use super::*;
+
+impl GentleEngine {
+    fn feature_segments_location(
+        seq_id: &str,
+        sequence_len: usize,
+        is_circular: bool,
+        start_0based: usize,
+        end_0based: usize,
+        segments: Option<Vec<SequenceRange0Based>>,
+    ) -> Result<gb_io::seq::Location, EngineError> {
+        let segments = segments
+            .filter(|segments| !segments.is_empty())
+            .unwrap_or_else(|| {
+                vec![SequenceRange0Based {
+                    start_0based,
+                    end_0based_exclusive: end_0based,
+                }]
+            });
+        for segment in &segments {
+            if segment.start_0based >= segment.end_0based_exclusive
+                || segment.end_0based_exclusive > sequence_len
+            {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "Feature segment {}..{} is empty or outside sequence '{}' ({} bp)",
+                        segment.start_0based, segment.end_0based_exclusive, seq_id, sequence_len
+                    ),
+                    cause_chain: vec![],
+                });
+            }
+        }
+        // Segments run 5'->3' without overlap; circular sequences may cross
+        // the origin once.
+        let mut origin_crossings = 0usize;
+        for pair in segments.windows(2) {
+            if pair[1].start_0based < pair[0].end_0based_exclusive {
+                origin_crossings += 1;
+                if !is_circular || origin_crossings > 1 {
+                    return Err(EngineError {
+                        code: ErrorCode::InvalidInput,
+                        message: format!(
+                            "Feature segments on '{seq_id}' must be ordered and non-overlapping"
+                        ),
+                        cause_chain: vec![],
+                    });
+                }
+            }
+        }
+        let first = &segments[0];
+        let last = &segments[segments.len() - 1];
+        if first.start_0based != start_0based || last.end_0based_exclusive != end_0based {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Feature span {}..{} does not match its segments ({}..{})",
+                    start_0based, end_0based, first.start_0based, last.end_0based_exclusive
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let mut parts = segments
+            .iter()
+            .map(|segment| {
+                gb_io::seq::Location::simple_range(
+                    segment.start_0based as i64,
+                    segment.end_0based_exclusive as i64,
+                )
+            })
+            .collect::<Vec<_>>();
+        Ok(if parts.len() == 1 {
+            parts.remove(0)
+        } else {
+            gb_io::seq::Location::Join(parts)
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn op_add_feature(
+        &mut self,
+        seq_id: SeqId,
+        kind: String,
+        start_0based: usize,
+        end_0based: usize,
+        strand: Option<char>,
+        qualifiers: HashMap<String, String>,
+        segments: Option<Vec<SequenceRange0Based>>,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let kind = kind.trim().to_string();
+        if kind.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "AddFeature requires a non-empty kind".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let reverse = match strand.unwrap_or('+') {
+            '+' => false,
+            '-' => true,
+            other => {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("AddFeature strand must be '+' or '-' (got '{other}')"),
+                    cause_chain: vec![],
+                });
+            }
+        };
+        let dna = self
+            .state
+            .sequences
+            .get(&seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let mut location = Self::feature_segments_location(
+            &seq_id,
+            dna.len(),
+            dna.is_circular(),
+            start_0based,
+            end_0based,
+            segments,
+        )?;
+        if reverse {
+            location = gb_io::seq::Location::Complement(Box::new(location));
+        }
+        let mut qualifiers = qualifiers
+            .into_iter()
+            .filter(|(key, _)| !key.trim().is_empty())
+            .map(|(key, value)| (key.trim().to_string(), value))
+            .collect::<Vec<_>>();
+        qualifiers.sort();
+
+        let _ = self.ensure_lineage_node(&seq_id);
+        let Some(dna) = self.state.sequences.get_mut(&seq_id) else {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        };
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: kind.as_str().into(),
+            location,
+            qualifiers: qualifiers
+                .into_iter()
+                .map(|(key, value)| (key.as_str().into(), Some(value)))
+                .collect(),
+        });
+        let feature_id = dna.features().len() - 1;
+        result.changed_seq_ids.push(seq_id.clone());
+        result
+            .messages
+            .push(format!("Added {kind} feature {feature_id} to '{seq_id}'"));
+        Ok(())
+    }
+
+    pub(super) fn op_delete_feature(
+        &mut self,
+        seq_id: SeqId,
+        feature_id: usize,
+        result: &mut OpResult,
+    ) -> Result<(), EngineError> {
+        let _ = self.ensure_lineage_node(&seq_id);
+        let dna = self
+            .state
+            .sequences
+            .get_mut(&seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        if feature_id >= dna.features().len() {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!(
+                    "Feature id '{}' was not found in sequence '{}'",
+                    feature_id, seq_id
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let removed = dna.features_mut().remove(feature_id);
+        result.changed_seq_ids.push(seq_id.clone());
+        result.messages.push(format!(
+            "Deleted {} feature {} from '{}'",
+            removed.kind, feature_id, seq_id
+        ));
+        Ok(())
+    }
+}
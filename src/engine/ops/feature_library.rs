@@ -0,0 +1,254 @@
+//! Library-based annotation of common vector elements.
+//!
+//! `AnnotateFromLibrary` searches each element of a `FeatureLibrary` against
+//! one sequence on both strands with the mismatch-tolerant scan from
+//! `subsequence_search`, allowing `floor((1 - min_identity) * len)`
+//! substitutions. Overlapping hits of the same element on the same strand are
+//! reduced to the best one; an element found more than once gets `#1`, `#2`,
+//! ... label suffixes in sequence order so the copies stay distinguishable.
+
+use super::*;
+
+/// `(name, kind, sequence)` of the built-in `FeatureLibrary::CommonVector`.
+/// The origin entry is the conserved core of the pMB1/ColE1 origin rather
+/// than the full annotated region.
+const COMMON_VECTOR_FEATURES: &[(&str, &str, &str)] = &[
+    (
+        "ori",
+        "rep_origin",
+        "TTGAGATCCTTTTTTTCTGCGCGTAATCTGCTGCTTGCAAACAAAAAAACCACCGCTACCAGCGGTGGTTTGTTTGCCGGATCAAGAGCTACCAACTCTTTTTCCGAAGGTAACTGGCTTCAGCAGAGCGCAGATACCAAATACTGT",
+    ),
+    (
+        "AmpR",
+        "CDS",
+        "ATGAGTATTCAACATTTCCGTGTCGCCCTTATTCCCTTTTTTGCGGCATTTTGCCTTCCTGTTTTTGCTCACCCAGAAACGCTGGTGAAAGTAAAAGATGCTGAAGATCAGTTGGGTGCACGAGTGGGTTACATCGAACTGGATCTCAACAGCGGTAAGATCCTTGAGAGTTTTCGCCCCGAAGAACGTTTTCCAATGATGAGCACTTTTAAAGTTCTGCTATGTGGCGCGGTATTATCCCGTATTGACGCCGGGCAAGAGCAACTCGGTCGCCGCATACACTATTCTCAGAATGACTTGGTTGAGTACTCACCAGTCACAGAAAAGCATCTTACGGATGGCATGACAGTAAGAGAATTATGCAGTGCTGCCATAACCATGAGTGATAACACTGCGGCCAACTTACTTCTGACAACGATCGGAGGACCGAAGGAGCTAACCGCTTTTTTGCACAACATGGGGGATCATGTAACTCGCCTTGATCGTTGGGAACCGGAGCTGAATGAAGCCATACCAAACGACGAGCGTGACACCACGATGCCTGTAGCAATGGCAACAACGTTGCGCAAACTATTAACTGGCGAACTACTTACTCTAGCTTCCCGGCAACAATTAATAGACTGGATGGAGGCGGATAAAGTTGCAGGACCACTTCTGCGCTCGGCCCTTCCGGCTGGCTGGTTTATTGCTGATAAATCTGGAGCCGGTGAGCGTGGGTCTCGCGGTATCATTGCAGCACTGGGGCCAGATGGTAAGCCCTCCCGTATCGTAGTTATCTACACGACGGGGAGTCAGGCAACTATGGATGAACGAAATAGACAGATCGCTGAGATAGGTGCCTCACTGATTAAGCATTGGTAA",
+    ),
+    (
+        "lacZ-alpha",
+        "CDS",
+        "CTGGCCGTCGTTTTACAACGTCGTGACTGGGAAAACCCTGGCGTTACCCAACTTAATCGCCTTGCAGCACATCCCCCTTTCGCCAGCTGGCGTAATAGCGAAGAGGCCCGCACCGATCGCCCTTCCCAACAGTTGCGCAGCCTGAATGGCGAATGG",
+    ),
+    ("lac operator", "protein_bind", "TTGTGAGCGGATAACAA"),
+    ("T7 promoter", "promoter", "TAATACGACTCACTATAG"),
+    ("T3 promoter", "promoter", "AATTAACCCTCACTAAAG"),
+    ("SP6 promoter", "promoter", "ATTTAGGTGACACTATAG"),
+    (
+        "T7 terminator",
+        "terminator",
+        "CTAGCATAACCCCTTGGGGCCTCTAAACGGGTCTTGAGGGGTTTTTTG",
+    ),
+    ("M13 fwd", "primer_bind", "GTAAAACGACGGCCAGT"),
+    ("M13 rev", "primer_bind", "CAGGAAACAGCTATGAC"),
+    ("6xHis", "CDS", "CATCACCATCACCATCAC"),
+    ("FLAG", "CDS", "GACTACAAAGACGATGACGACAAG"),
+    ("HA", "CDS", "TACCCATACGATGTTCCAGATTACGCT"),
+    ("Myc", "CDS", "GAACAAAAACTCATCTCAGAAGAGGATCTG"),
+    ("V5", "CDS", "GGTAAGCCTATCCCTAACCCTCTCCTCGGTCTCGATTCTACG"),
+];
+
+struct LibraryHit {
+    start: usize,
+    len: usize,
+    reverse: bool,
+    mismatches: usize,
+}
+
+impl GentleEngine {
+    fn feature_library_entries(library: &FeatureLibrary) -> Vec<FeatureLibraryEntry> {
+        match library {
+            FeatureLibrary::CommonVector => COMMON_VECTOR_FEATURES
+                .iter()
+                .map(|(name, kind, sequence)| FeatureLibraryEntry {
+                    name: name.to_string(),
+                    kind: kind.to_string(),
+                    sequence: sequence.to_string(),
+                })
+                .collect(),
+            FeatureLibrary::Custom { entries } => entries.clone(),
+        }
+    }
+
+    fn is_generated_library_feature(feature: &gb_io::seq::Feature) -> bool {
+        feature
+            .qualifier_values("gentle_generated")
+            .any(|v| v.eq_ignore_ascii_case("library_feature"))
+    }
+
+    /// Non-overlapping hits of `pattern` per strand, best (fewest mismatches,
+    /// then leftmost) first, returned in sequence order.
+    fn feature_library_hits(
+        text: &[u8],
+        sequence_len: usize,
+        pattern: &[u8],
+        max_mismatches: usize,
+    ) -> Vec<LibraryHit> {
+        let reverse_pattern = pattern
+            .iter()
+            .rev()
+            .map(|b| IupacCode::letter_complement(*b))
+            .collect::<Vec<_>>();
+        let mut strands = vec![(false, pattern.to_vec())];
+        if reverse_pattern != pattern {
+            strands.push((true, reverse_pattern));
+        }
+        let mut hits = vec![];
+        for (reverse, strand_pattern) in strands {
+            let mut candidates =
+                Self::scan_subsequence_with_mismatches(text, &strand_pattern, max_mismatches)
+                    .into_iter()
+                    .filter(|(start, _)| *start < sequence_len)
+                    .collect::<Vec<_>>();
+            candidates.sort_by_key(|(start, mismatches)| (*mismatches, *start));
+            let mut kept: Vec<LibraryHit> = vec![];
+            for (start, mismatches) in candidates {
+                let end = start + pattern.len();
+                if kept
+                    .iter()
+                    .any(|hit| start < hit.start + hit.len && hit.start < end)
+                {
+                    continue;
+                }
+                kept.push(LibraryHit {
+                    start,
+                    len: pattern.len(),
+                    reverse,
+                    mismatches,
+                });
+            }
+            hits.extend(kept);
+        }
+        hits.sort_by_key(|hit| (hit.start, hit.reverse));
+        hits
+    }
+
+    fn build_library_feature(
+        entry: &FeatureLibraryEntry,
+        hit: &LibraryHit,
+        label: String,
+        sequence_len: usize,
+    ) -> gb_io::seq::Feature {
+        let start = hit.start as i64;
+        let end = (hit.start + hit.len) as i64;
+        let mut location = if hit.start + hit.len <= sequence_len {
+            gb_io::seq::Location::simple_range(start, end)
+        } else {
+            gb_io::seq::Location::Join(vec![
+                gb_io::seq::Location::simple_range(start, sequence_len as i64),
+                gb_io::seq::Location::simple_range(0, end - sequence_len as i64),
+            ])
+        };
+        if hit.reverse {
+            location = gb_io::seq::Location::Complement(Box::new(location));
+        }
+        let identity = (hit.len - hit.mismatches) as f64 / hit.len as f64;
+        gb_io::seq::Feature {
+            kind: entry.kind.clone().into(),
+            location,
+            qualifiers: vec![
+                ("label".into(), Some(label)),
+                ("library_element".into(), Some(entry.name.clone())),
+                ("identity".into(), Some(format!("{identity:.3}"))),
+                ("mismatches".into(), Some(hit.mismatches.to_string())),
+                (
+                    "gentle_generated".into(),
+                    Some("library_feature".to_string()),
+                ),
+            ],
+        }
+    }
+
+    pub(super) fn annotate_from_library(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        library: &FeatureLibrary,
+        min_identity: f64,
+        clear_existing: bool,
+    ) -> Result<(), EngineError> {
+        if !min_identity.is_finite() || min_identity <= 0.0 || min_identity > 1.0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "AnnotateFromLibrary min_identity must be in (0, 1], got {min_identity}"
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let entries = Self::feature_library_entries(library);
+        let mut patterns = Vec::with_capacity(entries.len());
+        for entry in &entries {
+            let pattern = entry.sequence.trim().to_ascii_uppercase().into_bytes();
+            if entry.name.trim().is_empty()
+                || entry.kind.trim().is_empty()
+                || pattern.is_empty()
+                || !pattern.iter().all(|b| IupacCode::is_valid_letter(*b))
+            {
+                return Err(EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!(
+                        "Feature library entry '{}' needs a name, a kind, and a non-empty IUPAC DNA sequence",
+                        entry.name
+                    ),
+                    cause_chain: vec![],
+                });
+            }
+            patterns.push(pattern);
+        }
+
+        let dna = self
+            .state
+            .sequences
+            .get_mut(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let bases = dna.forward_bytes().to_vec();
+        let sequence_len = bases.len();
+        let is_circular = dna.is_circular();
+
+        let mut features = vec![];
+        for (entry, pattern) in entries.iter().zip(&patterns) {
+            if sequence_len == 0 {
+                break;
+            }
+            // Circular sequences also report elements spanning the origin.
+            let mut text = bases.clone();
+            if is_circular && pattern.len() > 1 {
+                let wrap = (pattern.len() - 1).min(sequence_len);
+                text.extend_from_slice(&bases[..wrap]);
+            }
+            let max_mismatches =
+                ((1.0 - min_identity) * pattern.len() as f64 + 1e-9).floor() as usize;
+            let hits = Self::feature_library_hits(&text, sequence_len, pattern, max_mismatches);
+            let numbered = hits.len() > 1;
+            for (idx, hit) in hits.iter().enumerate() {
+                let label = if numbered {
+                    format!("{} #{}", entry.name, idx + 1)
+                } else {
+                    entry.name.clone()
+                };
+                features.push(Self::build_library_feature(entry, hit, label, sequence_len));
+            }
+        }
+
+        let before = dna.features().len();
+        if clear_existing {
+            dna.features_mut()
+                .retain(|feature| !Self::is_generated_library_feature(feature));
+        }
+        let added = features.len();
+        let changed = added > 0 || dna.features().len() != before;
+        dna.features_mut().extend(features);
+        if changed {
+            let _ = self.ensure_lineage_node(seq_id);
+            result.changed_seq_ids.push(seq_id.to_string());
+        }
+        result.messages.push(format!(
+            "Annotated {added} library element match(es) on '{seq_id}' from {} element(s) (min_identity={min_identity})",
+            entries.len()
+        ));
+        Ok(())
+    }
+}
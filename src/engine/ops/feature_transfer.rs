@@ -0,0 +1,454 @@
+//! Feature re-annotation by transferring features between related sequences.
+//!
+//! `TransferFeatures` copies annotations from a reference sequence onto a
+//! regenerated or edited construct by locating each feature's bases (plus an
+//! optional flank for specificity) in the target via semiglobal alignment.
+//! Only unique matches are applied; ambiguous or missing matches are reported
+//! as warnings so the operation stays deterministic and reviewable.
+
+use super::*;
+use gb_io::seq::{After, Before, Feature, Location};
+
+const FEATURE_TRANSFER_MATCH_SCORE: i32 = 1;
+const FEATURE_TRANSFER_MISMATCH_SCORE: i32 = -1;
+const FEATURE_TRANSFER_GAP_OPEN: i32 = -5;
+const FEATURE_TRANSFER_GAP_EXTEND: i32 = -1;
+const FEATURE_TRANSFER_NOTE: &str = "transferred";
+
+#[derive(Debug, Clone)]
+struct FeatureTransferHit {
+    reverse: bool,
+    target_start_0based: usize,
+    target_end_0based: usize,
+    identity_fraction: f64,
+    ungapped_feature_span: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct FeatureTransferOutcome {
+    pub(super) transferred: usize,
+    pub(super) skipped_ambiguous: usize,
+    pub(super) skipped_unmatched: usize,
+    pub(super) skipped_unsupported: usize,
+    pub(super) skipped_existing: usize,
+    pub(super) warnings: Vec<String>,
+}
+
+impl GentleEngine {
+    pub(super) fn transfer_features_between_sequences(
+        &mut self,
+        from: &str,
+        to: &str,
+        min_identity: f64,
+        flank_bp: usize,
+    ) -> Result<FeatureTransferOutcome, EngineError> {
+        if !min_identity.is_finite() || min_identity <= 0.0 || min_identity > 1.0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "TransferFeatures min_identity must be within (0, 1], got {min_identity}"
+                ),
+                cause_chain: vec![],
+            });
+        }
+        if from == to {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "TransferFeatures requires distinct 'from' and 'to' sequences".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let source = self
+            .state
+            .sequences
+            .get(from)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{from}' not found"),
+                cause_chain: vec![],
+            })?
+            .clone();
+        let target_text = self
+            .state
+            .sequences
+            .get(to)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{to}' not found"),
+                cause_chain: vec![],
+            })?
+            .get_forward_string()
+            .to_ascii_uppercase();
+        let source_text = source.get_forward_string().to_ascii_uppercase();
+        let source_bytes = source_text.as_bytes();
+        let target_bytes = target_text.as_bytes();
+
+        let mut outcome = FeatureTransferOutcome::default();
+        let mut transferred_features: Vec<Feature> = vec![];
+        for (feature_id, feature) in source.features().iter().enumerate() {
+            if feature.kind.to_string().eq_ignore_ascii_case("source") {
+                continue;
+            }
+            let label = Self::feature_display_label(feature, feature_id);
+            let mut ranges = vec![];
+            collect_location_ranges_usize(&feature.location, &mut ranges);
+            let Some(span_start) = ranges.iter().map(|(start, _)| *start).min() else {
+                outcome.skipped_unsupported += 1;
+                outcome
+                    .warnings
+                    .push(format!("Skipped feature '{label}': location has no ranges"));
+                continue;
+            };
+            let span_end = ranges
+                .iter()
+                .map(|(_, end)| *end)
+                .max()
+                .unwrap_or(span_start);
+            if span_end <= span_start || span_end > source_bytes.len() {
+                outcome.skipped_unsupported += 1;
+                outcome.warnings.push(format!(
+                    "Skipped feature '{label}': location {}..{} is not a linear span of '{from}'",
+                    span_start, span_end
+                ));
+                continue;
+            }
+            let query_start = span_start.saturating_sub(flank_bp);
+            let query_end = (span_end + flank_bp).min(source_bytes.len());
+            let query = &source_bytes[query_start..query_end];
+            let feature_offset = span_start - query_start;
+            let feature_len = span_end - span_start;
+
+            let hits = Self::feature_transfer_hits(
+                query,
+                feature_offset,
+                feature_len,
+                target_bytes,
+                min_identity,
+            );
+            let hit = match hits.as_slice() {
+                [] => {
+                    outcome.skipped_unmatched += 1;
+                    outcome.warnings.push(format!(
+                        "Skipped feature '{label}': no match in '{to}' at identity >= {:.3}",
+                        min_identity
+                    ));
+                    continue;
+                }
+                [hit] => hit.clone(),
+                _ => {
+                    outcome.skipped_ambiguous += 1;
+                    outcome.warnings.push(format!(
+                        "Skipped feature '{label}': ambiguous ({} matches in '{to}')",
+                        hits.len()
+                    ));
+                    continue;
+                }
+            };
+            let Some(location) = Self::feature_transfer_location(
+                &feature.location,
+                &ranges,
+                span_start,
+                span_end,
+                &hit,
+            ) else {
+                outcome.skipped_unsupported += 1;
+                outcome.warnings.push(format!(
+                    "Skipped feature '{label}': split location cannot be projected across an indel"
+                ));
+                continue;
+            };
+            let mut transferred = feature.clone();
+            transferred.location = location;
+            transferred
+                .qualifiers
+                .push(("note".into(), Some(FEATURE_TRANSFER_NOTE.to_string())));
+            transferred_features.push(transferred);
+        }
+
+        let target = self
+            .state
+            .sequences
+            .get_mut(to)
+            .expect("target sequence checked above");
+        for feature in transferred_features {
+            let duplicate = target.features().iter().any(|existing| {
+                existing.kind == feature.kind
+                    && existing.location == feature.location
+                    && Self::feature_labels(existing) == Self::feature_labels(&feature)
+            });
+            if duplicate {
+                outcome.skipped_existing += 1;
+                continue;
+            }
+            target.features_mut().push(feature);
+            outcome.transferred += 1;
+        }
+        if outcome.transferred > 0 {
+            target.update_computed_features();
+        }
+        Ok(outcome)
+    }
+
+    fn feature_transfer_hits(
+        query: &[u8],
+        feature_offset: usize,
+        feature_len: usize,
+        target: &[u8],
+        min_identity: f64,
+    ) -> Vec<FeatureTransferHit> {
+        let mut hits: Vec<FeatureTransferHit> = vec![];
+        if query.is_empty() || target.len() < query.len() / 2 {
+            return hits;
+        }
+        let target_len = target.len();
+        let reverse_query = Self::reverse_complement_bytes(query);
+        for reverse in [false, true] {
+            let (strand_query, strand_offset) = if reverse {
+                (
+                    reverse_query.as_slice(),
+                    query.len() - feature_offset - feature_len,
+                )
+            } else {
+                (query, feature_offset)
+            };
+            // Mask each accepted hit and re-align so a second copy of the
+            // feature is seen as ambiguity instead of silently ignored.
+            let mut masked = target.to_vec();
+            for _ in 0..2 {
+                let Some((aligned_span, mut hit)) = Self::feature_transfer_best_hit(
+                    strand_query,
+                    strand_offset,
+                    feature_len,
+                    &masked,
+                    min_identity,
+                ) else {
+                    break;
+                };
+                for base in &mut masked[aligned_span] {
+                    *base = b'N';
+                }
+                if hit.target_end_0based <= hit.target_start_0based
+                    || hit.target_end_0based > target_len
+                {
+                    continue;
+                }
+                let same_locus = hits.iter().any(|existing| {
+                    existing.target_start_0based == hit.target_start_0based
+                        && existing.target_end_0based == hit.target_end_0based
+                });
+                if !same_locus {
+                    hit.reverse = reverse;
+                    hits.push(hit);
+                }
+            }
+        }
+        hits.sort_by(|a, b| {
+            b.identity_fraction
+                .partial_cmp(&a.identity_fraction)
+                .unwrap_or(Ordering::Equal)
+                .then(a.target_start_0based.cmp(&b.target_start_0based))
+        });
+        hits
+    }
+
+    /// Best semiglobal placement of `query` in `target`, returned together with
+    /// the full aligned target span so callers can mask it before re-aligning.
+    fn feature_transfer_best_hit(
+        query: &[u8],
+        feature_offset: usize,
+        feature_len: usize,
+        target: &[u8],
+        min_identity: f64,
+    ) -> Option<(std::ops::Range<usize>, FeatureTransferHit)> {
+        let score = |a: u8, b: u8| {
+            if a == b && a != b'N' {
+                FEATURE_TRANSFER_MATCH_SCORE
+            } else {
+                FEATURE_TRANSFER_MISMATCH_SCORE
+            }
+        };
+        let mut aligner = bio::alignment::pairwise::Aligner::new(
+            FEATURE_TRANSFER_GAP_OPEN,
+            FEATURE_TRANSFER_GAP_EXTEND,
+            &score,
+        );
+        let alignment = aligner.semiglobal(query, target);
+        let mut matches = 0usize;
+        let mut columns = 0usize;
+        let mut query_to_target: Vec<Option<usize>> = vec![None; query.len() + 1];
+        let mut gapped_inside_feature = false;
+        let mut i = alignment.xstart;
+        let mut j = alignment.ystart;
+        let feature_end = feature_offset + feature_len;
+        for op in &alignment.operations {
+            match op {
+                bio::alignment::AlignmentOperation::Match => {
+                    query_to_target[i] = Some(j);
+                    matches += 1;
+                    columns += 1;
+                    i += 1;
+                    j += 1;
+                }
+                bio::alignment::AlignmentOperation::Subst => {
+                    query_to_target[i] = Some(j);
+                    columns += 1;
+                    i += 1;
+                    j += 1;
+                }
+                bio::alignment::AlignmentOperation::Ins => {
+                    query_to_target[i] = Some(j);
+                    if (feature_offset..feature_end).contains(&i) {
+                        gapped_inside_feature = true;
+                    }
+                    columns += 1;
+                    i += 1;
+                }
+                bio::alignment::AlignmentOperation::Del => {
+                    if i > feature_offset && i < feature_end {
+                        gapped_inside_feature = true;
+                    }
+                    columns += 1;
+                    j += 1;
+                }
+                bio::alignment::AlignmentOperation::Xclip(_)
+                | bio::alignment::AlignmentOperation::Yclip(_) => {}
+            }
+        }
+        query_to_target[query.len()] = Some(j);
+        let identity = if columns == 0 {
+            0.0
+        } else {
+            matches as f64 / columns.max(query.len()) as f64
+        };
+        if identity < min_identity {
+            return None;
+        }
+        let feature_start = query_to_target[feature_offset]?;
+        let feature_end_target = query_to_target[feature_end - 1].map(|pos| pos + 1)?;
+        Some((
+            alignment.ystart..alignment.yend,
+            FeatureTransferHit {
+                reverse: false,
+                target_start_0based: feature_start,
+                target_end_0based: feature_end_target,
+                identity_fraction: identity,
+                ungapped_feature_span: !gapped_inside_feature
+                    && feature_end_target.saturating_sub(feature_start) == feature_len,
+            },
+        ))
+    }
+
+    fn feature_transfer_location(
+        location: &Location,
+        ranges: &[(usize, usize)],
+        span_start: usize,
+        span_end: usize,
+        hit: &FeatureTransferHit,
+    ) -> Option<Location> {
+        if ranges.len() == 1 {
+            let simple = Location::simple_range(
+                hit.target_start_0based as i64,
+                hit.target_end_0based as i64,
+            );
+            let reverse = matches!(location, Location::Complement(_)) != hit.reverse;
+            return Some(if reverse {
+                Location::Complement(Box::new(simple))
+            } else {
+                simple
+            });
+        }
+        if !hit.ungapped_feature_span {
+            return None;
+        }
+        if hit.reverse {
+            Self::reflect_transferred_location(
+                location,
+                span_end as i64,
+                hit.target_start_0based as i64,
+            )
+        } else {
+            Self::shift_transferred_location(
+                location,
+                hit.target_start_0based as i64 - span_start as i64,
+            )
+        }
+    }
+
+    fn shift_transferred_location(location: &Location, delta: i64) -> Option<Location> {
+        match location {
+            Location::Range((start, before), (end, after)) => Some(Location::Range(
+                (start.checked_add(delta)?, *before),
+                (end.checked_add(delta)?, *after),
+            )),
+            Location::Complement(inner) => Self::shift_transferred_location(inner, delta)
+                .map(|inner| Location::Complement(Box::new(inner))),
+            Location::Join(parts) => parts
+                .iter()
+                .map(|part| Self::shift_transferred_location(part, delta))
+                .collect::<Option<Vec<_>>>()
+                .map(Location::Join),
+            Location::Order(parts) => parts
+                .iter()
+                .map(|part| Self::shift_transferred_location(part, delta))
+                .collect::<Option<Vec<_>>>()
+                .map(Location::Order),
+            _ => None,
+        }
+    }
+
+    /// Mirror a location spanning `..span_end` in the source onto the reverse
+    /// strand of the target, where the source span end lands at `target_start`.
+    fn reflect_transferred_location(
+        location: &Location,
+        span_end: i64,
+        target_start: i64,
+    ) -> Option<Location> {
+        match location {
+            Location::Range((start, before), (end, after)) => {
+                let reflected = Location::Range(
+                    (target_start + (span_end - end), Before(after.0)),
+                    (target_start + (span_end - start), After(before.0)),
+                );
+                Some(Location::Complement(Box::new(reflected)))
+            }
+            Location::Complement(inner) => {
+                match Self::reflect_transferred_location(inner, span_end, target_start)? {
+                    Location::Complement(reflected) => Some(*reflected),
+                    other => Some(Location::Complement(Box::new(other))),
+                }
+            }
+            Location::Join(parts) | Location::Order(parts) => {
+                let mut reflected = parts
+                    .iter()
+                    .rev()
+                    .map(|part| Self::reflect_transferred_location(part, span_end, target_start))
+                    .collect::<Option<Vec<_>>>()?;
+                // Reflecting every part yields complement(a), complement(b), ...;
+                // keep the conventional complement(join(...)) shape instead.
+                let all_complement = reflected
+                    .iter()
+                    .all(|part| matches!(part, Location::Complement(_)));
+                if all_complement {
+                    reflected = reflected
+                        .into_iter()
+                        .map(|part| match part {
+                            Location::Complement(inner) => *inner,
+                            other => other,
+                        })
+                        .collect();
+                    let joined = if matches!(location, Location::Order(_)) {
+                        Location::Order(reflected)
+                    } else {
+                        Location::Join(reflected)
+                    };
+                    Some(Location::Complement(Box::new(joined)))
+                } else if matches!(location, Location::Order(_)) {
+                    Some(Location::Order(reflected))
+                } else {
+                    Some(Location::Join(reflected))
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Batch gene extraction from one prepared reference genome.
+//!
+//! `ExtractGenomeGenes` resolves several gene queries against a single opened
+//! catalog and gene index with the same `match_mode` rules as
+//! `ExtractGenomeGene`, extracting each gene interval with core annotation
+//! projection. Queries that do not resolve, and genes whose interval cannot be
+//! loaded, are reported as warnings so one bad query does not abort the rest
+//! of the batch.
+
+use super::*;
+
+impl GentleEngine {
+    pub(super) fn extract_genome_genes_batch(
+        &mut self,
+        result: &mut OpResult,
+        genome_id: &str,
+        gene_queries: &[String],
+        match_mode: Option<GeneMatchMode>,
+        output_prefix: Option<&str>,
+        catalog_path: Option<String>,
+        cache_dir: Option<String>,
+    ) -> Result<(), EngineError> {
+        let queries = gene_queries
+            .iter()
+            .map(|query| query.trim())
+            .filter(|query| !query.is_empty())
+            .collect::<Vec<_>>();
+        if queries.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "ExtractGenomeGenes requires at least one non-empty gene query"
+                    .to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let output_prefix = output_prefix
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        let catalog_path =
+            catalog_path.unwrap_or_else(|| default_catalog_discovery_token(false).to_string());
+        let (catalog, _) = Self::open_reference_genome_catalog(Some(&catalog_path))?;
+        let genes = catalog
+            .list_gene_regions(genome_id, cache_dir.as_deref())
+            .map_err(|e| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Could not load gene index for genome '{genome_id}': {e}"),
+                cause_chain: vec![],
+            })?;
+        let source_plan = catalog.source_plan(genome_id, cache_dir.as_deref()).ok();
+        let inspection = catalog
+            .inspect_prepared_genome(genome_id, cache_dir.as_deref())
+            .ok()
+            .flatten();
+
+        let total = queries.len();
+        let mut missing: Vec<String> = vec![];
+        let mut failed = 0usize;
+        let mut extracted = 0usize;
+        for query in queries {
+            let (matches, _) = Self::select_genome_gene_matches(&genes, query, match_mode)?;
+            let Some(selected_gene) = matches.first().copied() else {
+                missing.push(query.to_string());
+                continue;
+            };
+            if matches.len() > 1 {
+                result.warnings.push(
+                    format!(
+                        "Gene query '{}' matched {} records in '{}'; using first match.",
+                        query,
+                        matches.len(),
+                        genome_id
+                    )
+                    .into(),
+                );
+            }
+            let start_1based = selected_gene.start_1based;
+            let end_1based = selected_gene.end_1based;
+            let sequence = match Self::load_genome_slice_sequence(
+                &catalog,
+                genome_id,
+                &selected_gene.chromosome,
+                start_1based,
+                end_1based,
+                cache_dir.as_deref(),
+            ) {
+                Ok(sequence) => sequence,
+                Err(e) => {
+                    result.warnings.push(
+                        format!(
+                            "Could not load gene interval {}:{}-{} for query '{}': {}",
+                            selected_gene.chromosome, start_1based, end_1based, query, e
+                        )
+                        .into(),
+                    );
+                    failed += 1;
+                    continue;
+                }
+            };
+            let default_id = match output_prefix {
+                Some(prefix) => {
+                    let label = selected_gene
+                        .gene_name
+                        .as_deref()
+                        .or(selected_gene.gene_id.as_deref())
+                        .unwrap_or(query);
+                    format!("{prefix}_{}", Self::normalize_id_token(label))
+                }
+                None => Self::default_extract_genome_gene_output_id(
+                    genome_id,
+                    selected_gene,
+                    GenomeGeneExtractMode::Gene,
+                    0,
+                ),
+            };
+            let seq_id = self.import_genome_slice_sequence(result, sequence, default_id)?;
+
+            let transcript_records = catalog
+                .list_gene_transcript_records(
+                    genome_id,
+                    &selected_gene.chromosome,
+                    start_1based,
+                    end_1based,
+                    selected_gene.gene_id.as_deref(),
+                    selected_gene.gene_name.as_deref(),
+                    cache_dir.as_deref(),
+                )
+                .unwrap_or_default();
+            let projection = Self::build_extract_region_annotation_projection(
+                std::slice::from_ref(selected_gene),
+                &transcript_records,
+                start_1based,
+                end_1based,
+                GenomeAnnotationScope::Core,
+            );
+            if projection.feature_count() > 0
+                && let Some(dna) = self.state.sequences.get_mut(&seq_id)
+            {
+                dna.features_mut().extend(projection.features);
+                Self::prepare_sequence(dna);
+            }
+
+            let (
+                sequence_source_type,
+                annotation_source_type,
+                sequence_source,
+                annotation_source,
+                sequence_sha1,
+                annotation_sha1,
+            ) = Self::genome_source_snapshot(source_plan.as_ref(), inspection.as_ref());
+            self.append_genome_extraction_provenance(GenomeExtractionProvenance {
+                seq_id: seq_id.clone(),
+                recorded_at_unix_ms: Self::now_unix_ms(),
+                operation: "ExtractGenomeGenes".to_string(),
+                genome_id: genome_id.to_string(),
+                catalog_path: catalog_path.clone(),
+                cache_dir: cache_dir.clone(),
+                chromosome: Some(selected_gene.chromosome.clone()),
+                start_1based: Some(start_1based),
+                end_1based: Some(end_1based),
+                gene_query: Some(query.to_string()),
+                occurrence: Some(1),
+                gene_extract_mode: Some(GenomeGeneExtractMode::Gene.as_str().to_string()),
+                transcript_id: None,
+                tss_1based: None,
+                promoter_upstream_bp: None,
+                promoter_downstream_bp: None,
+                gene_id: selected_gene.gene_id.clone(),
+                gene_name: selected_gene.gene_name.clone(),
+                strand: selected_gene.strand,
+                anchor_strand: Some('+'),
+                anchor_verified: Some(true),
+                sequence_source_type,
+                annotation_source_type,
+                sequence_source,
+                annotation_source,
+                sequence_sha1,
+                annotation_sha1,
+            });
+            result.messages.push(format!(
+                "Extracted genome gene '{}' as '{}' from '{}' ({}:{}-{})",
+                query, seq_id, genome_id, selected_gene.chromosome, start_1based, end_1based
+            ));
+            extracted += 1;
+        }
+
+        for query in &missing {
+            result
+                .warnings
+                .push(format!("No genes in '{genome_id}' match query '{query}'").into());
+        }
+        result.messages.push(format!(
+            "Extracted {} of {} gene(s) from '{}' ({} not found, {} failed to load)",
+            extracted,
+            total,
+            genome_id,
+            missing.len(),
+            failed
+        ));
+        Ok(())
+    }
+}
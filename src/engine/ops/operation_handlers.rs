@@ -29,6 +29,8 @@ use crate::{
     uniprot::UniprotNucleotideXref,
 };
 
+const CONTAINER_OP_PROGRESS_INTERVAL: usize = 256;
+
 #[derive(Debug, Clone)]
 pub(crate) struct TranslationSpeedProfileResolution {
     pub profile: TranslationSpeedProfile,
@@ -191,6 +193,7 @@ impl GentleEngine {
             arrangement_name,
             Some(ladders),
             Some(role_labels),
+            1,
             None,
         )?;
         Ok(Some(arrangement_id))
@@ -2169,23 +2172,23 @@ impl GentleEngine {
         let catalog_path =
             catalog_path.unwrap_or_else(|| default_catalog_discovery_token(false).to_string());
         let (catalog, _) = Self::open_reference_genome_catalog(Some(&catalog_path))?;
-        let sequence = catalog
-            .get_sequence_region_with_cache(
-                genome_id,
-                chromosome,
-                start_1based,
-                end_1based,
-                cache_dir.as_deref(),
-            )
-            .map_err(|e| EngineError {
-                code: ErrorCode::NotFound,
-                message: format!(
-                    "Could not load genome region {}:{}-{} from '{}': {}",
-                    chromosome, start_1based, end_1based, genome_id, e
-                ),
+        let sequence = Self::load_genome_slice_sequence(
+            &catalog,
+            genome_id,
+            chromosome,
+            start_1based,
+            end_1based,
+            cache_dir.as_deref(),
+        )
+        .map_err(|e| EngineError {
+            code: ErrorCode::NotFound,
+            message: format!(
+                "Could not load genome region {}:{}-{} from '{}': {}",
+                chromosome, start_1based, end_1based, genome_id, e
+            ),
 
-                cause_chain: vec![],
-            })?;
+            cause_chain: vec![],
+        })?;
         let default_id = format!(
             "{}_{}_{}_{}",
             Self::normalize_id_token(genome_id),
@@ -2219,10 +2222,13 @@ impl GentleEngine {
                         })
                         .collect();
                 }
-                Err(e) => result.warnings.push(format!(
-                    "Could not inspect gene records for extracted region '{}': {}",
-                    seq_id, e
-                )),
+                Err(e) => result.warnings.push(
+                    format!(
+                        "Could not inspect gene records for extracted region '{}': {}",
+                        seq_id, e
+                    )
+                    .into(),
+                ),
             }
             match catalog.list_gene_transcript_records(
                 genome_id,
@@ -2239,7 +2245,7 @@ impl GentleEngine {
                 Err(e) => result.warnings.push(format!(
                     "Could not inspect transcript/exon annotation for extracted region '{}': {}",
                     seq_id, e
-                )),
+                ).into()),
             }
         }
         let mut effective_scope = requested_scope;
@@ -2319,7 +2325,7 @@ impl GentleEngine {
             ));
         }
         if let Some(reason) = fallback_reason.as_ref() {
-            result.warnings.push(reason.clone());
+            result.warnings.push(reason.clone().into());
         }
         result.genome_annotation_projection = Some(GenomeAnnotationProjectionTelemetry {
             requested_scope: requested_scope.as_str().to_string(),
@@ -4111,8 +4117,16 @@ impl GentleEngine {
         source_context_feature: Option<&gb_io::seq::Feature>,
         organism: Option<&str>,
         organelle: Option<&str>,
+        translation_table_override: Option<usize>,
     ) -> (usize, TranscriptProteinTranslationTableSource, Vec<String>) {
         let warnings: Vec<String> = vec![];
+        if let Some(value) = translation_table_override {
+            return (
+                value,
+                TranscriptProteinTranslationTableSource::ExplicitOperationOverride,
+                warnings,
+            );
+        }
         if let Some(value) = cds_feature
             .and_then(|feature| Self::qualifier_usize_for_derivation(feature, "transl_table"))
         {
@@ -4146,7 +4160,6 @@ impl GentleEngine {
         cds_ranges_0based: &[(usize, usize)],
         translation_table: usize,
     ) -> (String, bool, Vec<String>) {
-        let mut warnings: Vec<String> = vec![];
         let bytes = derived_sequence.as_bytes();
         let mut cds_bytes: Vec<u8> = vec![];
         for (start, end) in cds_ranges_0based {
@@ -4154,6 +4167,17 @@ impl GentleEngine {
                 cds_bytes.extend_from_slice(&bytes[*start..*end]);
             }
         }
+        Self::translate_cds_bytes(&cds_bytes, translation_table)
+    }
+
+    /// Translate spliced coding bases (5'->3' on the coding strand), trimming
+    /// one terminal stop. Returns the protein, whether a stop was trimmed, and
+    /// warnings about partial codons, internal stops and ambiguous codons.
+    pub(super) fn translate_cds_bytes(
+        cds_bytes: &[u8],
+        translation_table: usize,
+    ) -> (String, bool, Vec<String>) {
+        let mut warnings: Vec<String> = vec![];
         if cds_bytes.is_empty() {
             return (String::new(), false, warnings);
         }
@@ -4168,7 +4192,12 @@ impl GentleEngine {
             if codon.len() < 3 {
                 break;
             }
-            let aa = AMINO_ACIDS.codon2aa([codon[0], codon[1], codon[2]], Some(translation_table));
+            let codon = [
+                codon[0].to_ascii_uppercase(),
+                codon[1].to_ascii_uppercase(),
+                codon[2].to_ascii_uppercase(),
+            ];
+            let aa = AMINO_ACIDS.codon2aa(codon, Some(translation_table));
             protein.push(match aa {
                 STOP_CODON => '*',
                 UNKNOWN_CODON => 'X',
@@ -4205,6 +4234,7 @@ impl GentleEngine {
         is_reverse: bool,
         transcript_id: &str,
         transcript_label: &str,
+        translation_table_override: Option<usize>,
     ) -> Result<Option<TranscriptProteinDerivation>, EngineError> {
         let matching_cds_features = Self::collect_matching_cds_features_for_derivation(
             source_features,
@@ -4244,6 +4274,7 @@ impl GentleEngine {
                 source_context_feature,
                 organism.as_deref(),
                 organelle.as_deref(),
+                translation_table_override,
             );
         if let Some(speed_profile_resolution) = speed_profile_resolution.as_ref() {
             warnings.extend(speed_profile_resolution.warnings.iter().cloned());
@@ -4367,6 +4398,7 @@ impl GentleEngine {
         source_features: &[gb_io::seq::Feature],
         transcript_id: &str,
         transcript_label: &str,
+        translation_table_override: Option<usize>,
     ) -> Result<Option<TranscriptProteinDerivation>, EngineError> {
         if derived_sequence.len() < 3 {
             return Ok(None);
@@ -4388,6 +4420,7 @@ impl GentleEngine {
                 source_context_feature,
                 organism.as_deref(),
                 organelle.as_deref(),
+                translation_table_override,
             );
         if let Some(speed_profile_resolution) = speed_profile_resolution.as_ref() {
             warnings.extend(speed_profile_resolution.warnings.iter().cloned());
@@ -6491,6 +6524,7 @@ impl GentleEngine {
             &source_features,
             plan.transcript_feature_id,
             &plan.seq_id,
+            None,
         )?;
         let protein_derivation_inferred_without_annotation = if protein_derivation.is_none() {
             let cdna_sequence_upper = cdna_dna.get_forward_string().to_ascii_uppercase();
@@ -6502,6 +6536,7 @@ impl GentleEngine {
                 &source_features,
                 &plan.transcript_id,
                 &transcript_label,
+                None,
             )?;
             protein_derivation.is_some()
         } else {
@@ -6667,7 +6702,9 @@ impl GentleEngine {
                 warnings.push(message.clone());
             }
         }
-        result.warnings.extend(warnings.clone());
+        result
+            .warnings
+            .extend(warnings.iter().cloned().map(Warning::from));
         result.messages.push(format!(
             "Materialized exon-skip plan '{}' as cDNA '{}' and genomic annotation '{}'.",
             plan.plan_id, cdna_seq_id, genomic_seq_id
@@ -6695,6 +6732,7 @@ impl GentleEngine {
         source_features: &[gb_io::seq::Feature],
         source_feature_id: usize,
         source_seq_id: &str,
+        translation_table_override: Option<usize>,
     ) -> Result<
         (
             DNAsequence,
@@ -6831,6 +6869,7 @@ impl GentleEngine {
             is_reverse,
             &transcript_id,
             &transcript_label,
+            translation_table_override,
         )?;
         let representative_cds_feature = Self::collect_matching_cds_features_for_derivation(
             source_features,
@@ -7226,6 +7265,7 @@ impl GentleEngine {
 
             cause_chain: vec![],
         })?;
+        Self::warn_on_declared_length_mismatch(&mut dna, &source_url, result);
         Self::prepare_sequence(&mut dna);
         let base = as_id.unwrap_or_else(|| accession_trimmed.to_string());
         let seq_id = self.unique_seq_id(&base);
@@ -7272,14 +7312,14 @@ impl GentleEngine {
                                 anchor.start_1based,
                                 anchor.end_1based,
                                 default_catalog_label
-                            ));
+                            ).into());
                         }
                     }
                     Err(err) => {
                         result.warnings.push(format!(
                             "Could not verify imported GenBank anchor '{}' against catalog '{}': {}",
                             seq_id, default_catalog_label, err
-                        ));
+                        ).into());
                     }
                 }
             }
@@ -11614,7 +11654,7 @@ impl GentleEngine {
                 product_gel_ladders,
             )?;
             for warning in &materialization.warnings {
-                result.warnings.push(warning.clone());
+                result.warnings.push(warning.clone().into());
                 result.messages.push(warning.clone());
             }
             result.cdna_assay_product_materialization = Some(Box::new(materialization));
@@ -12921,7 +12961,9 @@ impl GentleEngine {
                     )?;
                 (assays, rejection_summary, backend, None, warnings)
             };
-        result.warnings.extend(backend_warnings);
+        result
+            .warnings
+            .extend(backend_warnings.into_iter().map(Warning::from));
 
         let (best_assay_probe_placement, best_assay_summary) =
             Self::best_qpcr_assay_probe_placement_and_summary(
@@ -12970,7 +13012,7 @@ impl GentleEngine {
                     .rejection_summary
                     .primer_pair
                     .pair_evaluation_limit_skipped
-            ));
+            ).into());
         }
         let mut store = self.read_primer_design_store();
         let replaced = store
@@ -13024,18 +13066,24 @@ impl GentleEngine {
             };
             let advisories = Self::primer_pair_heuristic_advisories(&pair_like);
             if !advisories.is_empty() {
-                result.warnings.push(format!(
-                    "Top qPCR primer pair in report '{}' has heuristic advisories: {}",
-                    report.report_id,
-                    advisories.join("; ")
-                ));
+                result.warnings.push(
+                    format!(
+                        "Top qPCR primer pair in report '{}' has heuristic advisories: {}",
+                        report.report_id,
+                        advisories.join("; ")
+                    )
+                    .into(),
+                );
             }
         }
         if report.assay_count == 0 {
-            result.warnings.push(format!(
-                "No qPCR assays satisfied constraints for report '{}'",
-                report.report_id
-            ));
+            result.warnings.push(
+                format!(
+                    "No qPCR assays satisfied constraints for report '{}'",
+                    report.report_id
+                )
+                .into(),
+            );
         }
         Ok(())
     }
@@ -13425,7 +13473,7 @@ impl GentleEngine {
                         result.warnings.push(format!(
                             "Primer3 backend unavailable in auto mode: {}. Falling back to internal primer design backend.",
                             err.message
-                        ));
+                        ).into());
                         Self::emit_operation_primer_design_progress(
                             on_progress,
                             PrimerDesignProgress {
@@ -13515,7 +13563,7 @@ impl GentleEngine {
             result.warnings.push(format!(
                 "Internal primer-pair search reached its evaluation limit and skipped {} candidate combinations; narrow ROI/constraints for a more exhaustive run",
                 report.rejection_summary.pair_evaluation_limit_skipped
-            ));
+            ).into());
         }
         let mut store = self.read_primer_design_store();
         let replaced = store
@@ -13543,7 +13591,7 @@ impl GentleEngine {
                     "{} pair(s) in report '{}' require non-compensable downstream anchor shifts; review insertion compensation rows",
                     context.uncompensable_pair_count,
                     report.report_id
-                ));
+                ).into());
             }
             if context.out_of_shift_budget_pair_count > 0 {
                 result.warnings.push(format!(
@@ -13551,7 +13599,7 @@ impl GentleEngine {
                     context.out_of_shift_budget_pair_count,
                     report.report_id,
                     context.max_anchor_shift_bp
-                ));
+                ).into());
             }
         }
         if !report.pairs.is_empty() {
@@ -13679,29 +13727,38 @@ impl GentleEngine {
         if let Some(top_pair) = report.pairs.first() {
             let advisories = Self::primer_pair_heuristic_advisories(top_pair);
             if !advisories.is_empty() {
-                result.warnings.push(format!(
-                    "Top primer pair in report '{}' has heuristic advisories: {}",
-                    report.report_id,
-                    advisories.join("; ")
-                ));
+                result.warnings.push(
+                    format!(
+                        "Top primer pair in report '{}' has heuristic advisories: {}",
+                        report.report_id,
+                        advisories.join("; ")
+                    )
+                    .into(),
+                );
             }
         }
         if report.pair_count == 0 {
-            result.warnings.push(format!(
-                "No primer pairs satisfied constraints for report '{}'",
-                report.report_id
-            ));
+            result.warnings.push(
+                format!(
+                    "No primer pairs satisfied constraints for report '{}'",
+                    report.report_id
+                )
+                .into(),
+            );
             if let Some(explain) = report.backend.primer3_explain.as_deref() {
-                result.warnings.push(format!(
-                    "Primer3 explain for report '{}': {}",
-                    report.report_id, explain
-                ));
+                result.warnings.push(
+                    format!(
+                        "Primer3 explain for report '{}': {}",
+                        report.report_id, explain
+                    )
+                    .into(),
+                );
             }
             if report.backend.primer3_request_boulder_io.is_some() {
                 result.warnings.push(format!(
                     "Primer3 request payload was captured for report '{}' and can be exported for local reruns",
                     report.report_id
-                ));
+                ).into());
             }
         }
         Ok(())
@@ -14117,10 +14174,13 @@ impl GentleEngine {
         let advisories = Self::primer_pair_heuristic_advisories(&tailed_pair);
         for advisory in advisories {
             compatibility.warnings.push(advisory.clone());
-            result.warnings.push(format!(
-                "Restriction-cloning handoff '{}' advisory: {}",
-                primer_report_id, advisory
-            ));
+            result.warnings.push(
+                format!(
+                    "Restriction-cloning handoff '{}' advisory: {}",
+                    primer_report_id, advisory
+                )
+                .into(),
+            );
         }
         if !compatibility.blocking_errors.is_empty() {
             compatibility.status = "blocked".to_string();
@@ -14847,7 +14907,7 @@ impl GentleEngine {
         if evaluation_limited {
             result.warnings.push(format!(
                 "Overlap-extension mutagenesis candidate search reached evaluation limit ({max_evaluations}); narrow primer windows or use tighter constraints for a more exhaustive search",
-            ));
+            ).into());
         }
         let Some(best_design) = best_design else {
             return Err(EngineError {
@@ -14857,6 +14917,15 @@ impl GentleEngine {
                 cause_chain: vec![],});
         };
 
+        if let Some(edited) = Self::cds_integrity_edit_preview(
+            &dna,
+            edit_start_0based,
+            edit_end_0based_exclusive,
+            &insert_sequence,
+        ) {
+            Self::push_cds_integrity_warnings(result, &template, &dna, &edited);
+        }
+
         parent_seq_ids.push(template.clone());
         let base_prefix = output_prefix
             .as_deref()
@@ -15226,6 +15295,7 @@ impl GentleEngine {
                 arrangement_id,
                 name,
                 ladders,
+                replicate_count,
             } => {
                 let created_id = self.add_serial_arrangement(
                     &container_ids,
@@ -15233,6 +15303,7 @@ impl GentleEngine {
                     name,
                     ladders,
                     None,
+                    replicate_count.unwrap_or(1),
                     Some(&result.op_id),
                 )?;
                 result.messages.push(format!(
@@ -15389,13 +15460,13 @@ impl GentleEngine {
                     if !inputs.is_empty() {
                         result.warnings.push(
                             "RenderPoolGelSvg ignored 'inputs' because arrangement_id was provided"
-                                .to_string(),
+                                .into(),
                         );
                     }
                     if container_ids.as_ref().is_some_and(|ids| !ids.is_empty()) {
                         result.warnings.push(
                             "RenderPoolGelSvg ignored 'container_ids' because arrangement_id was provided"
-                                .to_string(),
+                                .into()
                         );
                     }
                     if arrangement_id.is_empty() {
@@ -15410,7 +15481,7 @@ impl GentleEngine {
                     if !inputs.is_empty() {
                         result.warnings.push(
                             "RenderPoolGelSvg ignored 'inputs' because container_ids were provided"
-                                .to_string(),
+                                .into(),
                         );
                     }
                     if container_ids.is_empty() {
@@ -15451,8 +15522,46 @@ impl GentleEngine {
                     layout.conditions.describe()
                 ));
             }
+            Operation::RenderPlateLayoutSvg {
+                arrangement_id,
+                path,
+                plate_wells,
+            } => {
+                let samples = self.plate_samples_from_arrangement(arrangement_id.trim())?;
+                let layout = crate::pool_gel::build_plate_layout(
+                    arrangement_id.trim(),
+                    &samples,
+                    plate_wells.unwrap_or(96),
+                )
+                .map_err(|e| EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: e,
+                    cause_chain: vec![],
+                })?;
+                let svg = crate::pool_gel::export_plate_layout_svg(&layout);
+                Self::ensure_engine_output_parent_dir(&path, "plate layout SVG")?;
+                std::fs::write(&path, svg).map_err(|e| EngineError {
+                    code: ErrorCode::Io,
+                    message: format!("Could not write SVG output '{path}': {e}"),
+                    cause_chain: vec![],
+                })?;
+                result.messages.push(format!(
+                    "Wrote plate layout SVG for {} sample(s) on {} {}-well plate(s) to '{}'",
+                    layout.wells.len(),
+                    layout.plate_count,
+                    layout.rows * layout.columns,
+                    path
+                ));
+            }
+            Operation::DefineDnaLadder {
+                name,
+                bands,
+                loading_hint,
+            } => {
+                self.define_dna_ladder(result, &name, &bands, loading_hint)?;
+            }
             Operation::ExportDnaLadders { path, name_filter } => {
-                let report = Self::export_dna_ladders(&path, name_filter.as_deref())?;
+                let report = self.export_dna_ladders(&path, name_filter.as_deref())?;
                 let filter_text = name_filter
                     .as_deref()
                     .map(str::trim)
@@ -15568,11 +15677,14 @@ impl GentleEngine {
                     report.scan_end_0based_exclusive,
                 ));
                 if !report.skipped_enzyme_names_due_to_max_sites.is_empty() {
-                    result.warnings.push(format!(
-                        "Skipped enzyme(s) due to max_sites_per_enzyme cap {}: {}",
-                        report.max_sites_per_enzyme.unwrap_or_default(),
-                        report.skipped_enzyme_names_due_to_max_sites.join(", ")
-                    ));
+                    result.warnings.push(
+                        format!(
+                            "Skipped enzyme(s) due to max_sites_per_enzyme cap {}: {}",
+                            report.max_sites_per_enzyme.unwrap_or_default(),
+                            report.skipped_enzyme_names_due_to_max_sites.join(", ")
+                        )
+                        .into(),
+                    );
                 }
                 result.restriction_site_scan = Some(report);
             }
@@ -15595,13 +15707,16 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 if report.malformed_line_count > 0 {
-                    result.warnings.push(format!(
-                        "RepeatMasker parser skipped {} malformed line(s)",
-                        report.malformed_line_count
-                    ));
+                    result.warnings.push(
+                        format!(
+                            "RepeatMasker parser skipped {} malformed line(s)",
+                            report.malformed_line_count
+                        )
+                        .into(),
+                    );
                 }
                 result.messages.push(format!(
                     "Repeat annotation query for '{}' matched {} of {} parsed rmsk row(s), returned {}",
@@ -15637,7 +15752,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "Repeat-overlap lookup for '{}' returned {} of {} matched interval(s)",
@@ -15672,7 +15787,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "Materialized {} UCSC rmsk repeat feature(s) on '{}' (matched={}, skipped_existing={}, removed_existing={})",
@@ -15720,7 +15835,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "Repeat environment cohort for '{}' returned {} row(s) with geometry={} and flanks {} / {} bp",
@@ -15759,7 +15874,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "Window-cohort TFBS summary for '{}' scored {} window(s) and {} per-window motif row(s)",
@@ -15901,7 +16016,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "Multi-gene promoter TFBS summary for '{}' covered {} gene(s) and {} per-gene motif row(s) with score_kind={} clip_negative={}",
@@ -15946,7 +16061,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "TFBS track similarity for '{}' ranked {} candidate motif(s) against '{}' over {}..{} (metric={}, score_kind={}, clip_negative={})",
@@ -16012,7 +16127,7 @@ impl GentleEngine {
                     ));
                 }
                 for warning in &report.warnings {
-                    result.warnings.push(warning.clone());
+                    result.warnings.push(warning.clone().into());
                 }
                 result.messages.push(format!(
                     "Promoter cohort comparison '{}' resolved {} promoter(s), {} TFBS summary row(s), and {} pairwise similarity row(s)",
@@ -16059,10 +16174,13 @@ impl GentleEngine {
                     report.scan_end_0based_exclusive,
                 ));
                 if report.truncated_at_max_hits {
-                    result.warnings.push(format!(
-                        "TFBS hit scan reached max_hits={} and truncated remaining hits",
-                        report.max_hits.unwrap_or_default()
-                    ));
+                    result.warnings.push(
+                        format!(
+                            "TFBS hit scan reached max_hits={} and truncated remaining hits",
+                            report.max_hits.unwrap_or_default()
+                        )
+                        .into(),
+                    );
                 }
                 result.tfbs_hit_scan = Some(report);
             }
@@ -16351,6 +16469,249 @@ impl GentleEngine {
         Ok((report, created_seq_ids, warnings))
     }
 
+    /// Report container enumeration progress every
+    /// `CONTAINER_OP_PROGRESS_INTERVAL` steps; returns `true` once the caller
+    /// asked to stop.
+    fn container_op_cancelled(
+        on_progress: &mut dyn FnMut(OperationProgress) -> bool,
+        processed: usize,
+        total: usize,
+    ) -> bool {
+        processed > 0
+            && processed % CONTAINER_OP_PROGRESS_INTERVAL == 0
+            && !on_progress(OperationProgress::ContainerOp { processed, total })
+    }
+
+    /// Shared body of `AnnotateTfbs`, also used when applying annotation
+    /// profiles.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn annotate_tfbs(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: SeqId,
+        motifs: Vec<String>,
+        min_llr_bits: Option<f64>,
+        min_llr_quantile: Option<f64>,
+        per_tf_thresholds: Vec<TfThresholdOverride>,
+        clear_existing: Option<bool>,
+        max_hits: Option<usize>,
+        on_progress: &mut dyn FnMut(OperationProgress) -> bool,
+    ) -> Result<(), EngineError> {
+        const DEFAULT_MAX_TFBS_HITS: usize = 500;
+        let motifs = if motifs.len() == 1
+            && matches!(motifs[0].trim().to_ascii_uppercase().as_str(), "ALL" | "*")
+        {
+            tf_motifs::all_motif_ids()
+        } else {
+            motifs
+        };
+
+        if motifs.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "AnnotateTfbs requires at least one motif".to_string(),
+
+                cause_chain: vec![],
+            });
+        }
+        let default_min_llr_bits = min_llr_bits.unwrap_or(f64::NEG_INFINITY);
+        let default_min_llr_quantile = min_llr_quantile.unwrap_or(0.0);
+        Self::validate_tf_thresholds(default_min_llr_quantile)?;
+
+        let mut override_map: HashMap<String, (Option<f64>, Option<f64>)> = HashMap::new();
+        for o in &per_tf_thresholds {
+            let key = o.tf.trim().to_ascii_uppercase();
+            if key.is_empty() {
+                continue;
+            }
+            if let Some(q) = o.min_llr_quantile {
+                Self::validate_tf_thresholds(q)?;
+            }
+            override_map.insert(key, (o.min_llr_bits, o.min_llr_quantile));
+        }
+
+        let _ = self.ensure_lineage_node(&seq_id);
+        let dna = self
+            .state
+            .sequences
+            .get_mut(&seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+
+                cause_chain: vec![],
+            })?;
+        let seq_text = dna.get_forward_string();
+        let seq_bytes = seq_text.as_bytes();
+
+        if clear_existing.unwrap_or(true) {
+            Self::remove_generated_tfbs_features(dna.features_mut());
+        }
+
+        let mut added = 0usize;
+        let max_hits = match max_hits {
+            Some(0) => None,
+            Some(v) => Some(v),
+            None => Some(DEFAULT_MAX_TFBS_HITS),
+        };
+        let motif_count = motifs.len();
+        let mut motifs_scanned = 0usize;
+        let mut cap_reached = false;
+        'motif_loop: for (motif_idx, token) in motifs.into_iter().enumerate() {
+            let token_key = token.trim().to_ascii_uppercase();
+            if token_key.is_empty() {
+                continue;
+            }
+            let (tf_id, tf_name, _consensus, matrix_counts) =
+                Self::resolve_tf_motif_for_scoring(&token)?;
+            let (llr_matrix, true_log_odds_matrix) = Self::prepare_scoring_matrices(&matrix_counts);
+            if llr_matrix.is_empty() || llr_matrix.len() > seq_bytes.len() {
+                result.warnings.push(
+                    format!(
+                        "TF '{}' skipped: motif length {} exceeds sequence length {}",
+                        tf_id,
+                        llr_matrix.len(),
+                        seq_bytes.len()
+                    )
+                    .into(),
+                );
+                continue;
+            }
+
+            let mut eff_bits = default_min_llr_bits;
+            let mut eff_quantile = default_min_llr_quantile;
+            let id_key = tf_id.to_ascii_uppercase();
+            let name_key = tf_name
+                .as_ref()
+                .map(|n| n.trim().to_ascii_uppercase())
+                .unwrap_or_default();
+            for key in [token_key.as_str(), id_key.as_str(), name_key.as_str()] {
+                if key.is_empty() {
+                    continue;
+                }
+                if let Some((b, q)) = override_map.get(key) {
+                    if let Some(v) = b {
+                        eff_bits = *v;
+                    }
+                    if let Some(v) = q {
+                        eff_quantile = *v;
+                    }
+                    break;
+                }
+            }
+
+            motifs_scanned += 1;
+            let seq_id_for_progress = seq_id.clone();
+            let tf_id_for_progress = tf_id.clone();
+            let motif_index = motif_idx + 1;
+            let hits = Self::scan_tf_scores(
+                seq_bytes,
+                &llr_matrix,
+                &true_log_odds_matrix,
+                |scanned_steps, total_steps| {
+                    let motif_fraction = if total_steps == 0 {
+                        1.0
+                    } else {
+                        (scanned_steps as f64 / total_steps as f64).clamp(0.0, 1.0)
+                    };
+                    let total_fraction = if motif_count == 0 {
+                        1.0
+                    } else {
+                        ((motif_index - 1) as f64 + motif_fraction) / motif_count as f64
+                    }
+                    .clamp(0.0, 1.0);
+                    on_progress(OperationProgress::Tfbs(TfbsProgress {
+                        seq_id: seq_id_for_progress.clone(),
+                        motif_id: tf_id_for_progress.clone(),
+                        motif_index,
+                        motif_count,
+                        scanned_steps,
+                        total_steps,
+                        motif_percent: motif_fraction * 100.0,
+                        total_percent: total_fraction * 100.0,
+                        task_kind: Some("annotation".to_string()),
+                        stage_label: Some("scan".to_string()),
+                        detail: None,
+                        stage_percent: Some(motif_fraction * 100.0),
+                    }));
+                },
+            );
+            let mut kept = 0usize;
+            for (
+                start,
+                reverse,
+                llr_bits,
+                llr_quantile,
+                true_log_odds_bits,
+                true_log_odds_quantile,
+            ) in hits
+            {
+                if llr_bits < eff_bits || llr_quantile < eff_quantile {
+                    continue;
+                }
+                let end = start + llr_matrix.len();
+                dna.features_mut().push(Self::build_tfbs_feature(
+                    start,
+                    end,
+                    reverse,
+                    llr_matrix.len(),
+                    &tf_id,
+                    tf_name.as_deref(),
+                    llr_bits,
+                    llr_quantile,
+                    true_log_odds_bits,
+                    true_log_odds_quantile,
+                ));
+                kept += 1;
+                added += 1;
+                if let Some(limit) = max_hits
+                    && added >= limit
+                {
+                    cap_reached = true;
+                    break;
+                }
+            }
+            result.messages.push(format!(
+                "TF '{}' annotated {} hit(s){}",
+                tf_id,
+                kept,
+                Self::format_tf_threshold_summary(eff_bits, eff_quantile)
+            ));
+            on_progress(OperationProgress::Tfbs(TfbsProgress {
+                seq_id: seq_id.clone(),
+                motif_id: tf_id,
+                motif_index,
+                motif_count,
+                scanned_steps: 1,
+                total_steps: 1,
+                motif_percent: 100.0,
+                total_percent: (motif_index as f64 / motif_count.max(1) as f64) * 100.0,
+                task_kind: Some("annotation".to_string()),
+                stage_label: Some("scan".to_string()),
+                detail: None,
+                stage_percent: Some(100.0),
+            }));
+            if cap_reached {
+                if let Some(limit) = max_hits {
+                    result.warnings.push(format!(
+                    "TFBS hit cap ({limit}) reached after scanning {motifs_scanned}/{motif_count} motif(s); skipping remaining motif scans"
+                ).into());
+                }
+                break 'motif_loop;
+            }
+        }
+        result.messages.push(format!(
+            "TFBS motif scan coverage: {motifs_scanned}/{motif_count} motif(s)"
+        ));
+
+        result.changed_seq_ids.push(seq_id.clone());
+        result.messages.push(format!(
+            "Annotated {} TFBS feature(s) on '{}'",
+            added, seq_id
+        ));
+        Ok(())
+    }
+
     pub(super) fn apply_internal(
         &mut self,
         op: Operation,
@@ -16398,6 +16759,23 @@ impl GentleEngine {
                 unique,
                 output_prefix,
             },
+            Operation::FilterContainerByGc {
+                container_id,
+                gc_min,
+                gc_max,
+                unique,
+                output_prefix,
+            } => Operation::FilterByDesignConstraints {
+                inputs: self.container_members(&container_id)?,
+                gc_min: Some(gc_min),
+                gc_max: Some(gc_max),
+                max_homopolymer_run: None,
+                reject_ambiguous_bases: Some(false),
+                avoid_u6_terminator_tttt: Some(false),
+                forbidden_motifs: vec![],
+                unique,
+                output_prefix: output_prefix.or_else(|| Some("gc_filter".to_string())),
+            },
             other => other,
         };
         let op_id = self.next_op_id();
@@ -16531,6 +16909,8 @@ impl GentleEngine {
                 | Operation::MoveRackSamples { .. }
                 | Operation::MoveRackArrangementBlocks { .. }
                 | Operation::RenderPoolGelSvg { .. }
+                | Operation::RenderPlateLayoutSvg { .. }
+                | Operation::DefineDnaLadder { .. }
                 | Operation::ExportDnaLadders { .. }
                 | Operation::ExportRnaLadders { .. }
         ) {
@@ -16543,7 +16923,24 @@ impl GentleEngine {
                 | Operation::SummarizePromoterCohortComparison { .. } => {
                     unreachable!("repeat cohort feature-scan operations are handled above")
                 }
-                Operation::LoadFile { path, as_id } => {
+                Operation::ImportDirectory {
+                    directory,
+                    recursive,
+                    container_name,
+                } => {
+                    self.import_sequence_directory(
+                        &mut result,
+                        &directory,
+                        recursive,
+                        container_name.as_deref(),
+                    )?;
+                }
+                Operation::LoadFile {
+                    path,
+                    as_id,
+                    skip_duplicates,
+                    uppercase,
+                } => {
                     let mut dna =
                         crate::dna_sequence::load_from_file(&path).map_err(|e| EngineError {
                             code: ErrorCode::InvalidInput,
@@ -16551,122 +16948,158 @@ impl GentleEngine {
 
                             cause_chain: vec![],
                         })?;
+                    Self::warn_on_declared_length_mismatch(&mut dna, &path, &mut result);
+                    if uppercase.unwrap_or(false) {
+                        dna.uppercase_bases();
+                    } else {
+                        let masked = dna.soft_masked_ranges();
+                        if !masked.is_empty() {
+                            result.messages.push(format!(
+                                "Kept {} soft-masked run(s) ({} bp) in '{}'",
+                                masked.len(),
+                                masked.iter().map(|range| range.len()).sum::<usize>(),
+                                path
+                            ));
+                        }
+                    }
                     Self::prepare_sequence(&mut dna);
 
                     let base = as_id.unwrap_or_else(|| Self::derive_seq_id(&path));
                     let seq_id = self.unique_seq_id(&base);
-                    self.state.sequences.insert(seq_id.clone(), dna);
-                    self.add_lineage_node(
-                        &seq_id,
-                        Self::classify_import_origin(
-                            &path,
-                            self.state
-                                .sequences
-                                .get(&seq_id)
-                                .expect("sequence just inserted"),
-                        ),
-                        Some(&result.op_id),
-                    );
-                    let imported_anchor = self
-                        .state
-                        .sequences
-                        .get(&seq_id)
-                        .and_then(|loaded| Self::infer_imported_genbank_anchor(&path, loaded));
-                    if let Some(anchor) = imported_anchor {
-                        let mut anchor_verified: Option<bool> = None;
-                        let default_catalog_path = default_catalog_discovery_token(false);
-                        let default_catalog_label = default_catalog_discovery_label(false);
-                        if let Some(loaded) = self.state.sequences.get(&seq_id) {
-                            match Self::verify_anchor_sequence_against_catalog(
-                                loaded,
-                                &anchor,
-                                default_catalog_path,
-                                None,
-                            ) {
-                                Ok(is_match) => {
-                                    anchor_verified = Some(is_match);
-                                    if is_match {
-                                        result.messages.push(format!(
-                                        "Verified imported GenBank anchor '{}' against catalog '{}' ({}:{}-{})",
-                                        seq_id,
-                                        default_catalog_label,
-                                        anchor.genome_id,
-                                        anchor.chromosome,
-                                        anchor.start_1based
-                                    ));
-                                    } else {
+                    let duplicate_of = self.find_sequence_with_same_content(&dna);
+                    if let Some(existing_id) = duplicate_of
+                        .as_ref()
+                        .filter(|_| skip_duplicates.unwrap_or(false))
+                    {
+                        result.warnings.push(
+                            format!(
+                                "Skipped loading '{}': content duplicates existing sequence '{}'",
+                                path, existing_id
+                            )
+                            .into(),
+                        );
+                    } else {
+                        if let Some(existing_id) = duplicate_of {
+                            result.warnings.push(
+                                format!(
+                                    "Loaded sequence '{}' from '{}' duplicates existing sequence '{}'",
+                                    seq_id, path, existing_id
+                                )
+                                .into(),
+                            );
+                        }
+                        self.state.sequences.insert(seq_id.clone(), dna);
+                        self.add_lineage_node(
+                            &seq_id,
+                            Self::classify_import_origin(
+                                &path,
+                                self.state
+                                    .sequences
+                                    .get(&seq_id)
+                                    .expect("sequence just inserted"),
+                            ),
+                            Some(&result.op_id),
+                        );
+                        let imported_anchor =
+                            self.state.sequences.get(&seq_id).and_then(|loaded| {
+                                Self::infer_imported_genbank_anchor(&path, loaded)
+                            });
+                        if let Some(anchor) = imported_anchor {
+                            let mut anchor_verified: Option<bool> = None;
+                            let default_catalog_path = default_catalog_discovery_token(false);
+                            let default_catalog_label = default_catalog_discovery_label(false);
+                            if let Some(loaded) = self.state.sequences.get(&seq_id) {
+                                match Self::verify_anchor_sequence_against_catalog(
+                                    loaded,
+                                    &anchor,
+                                    default_catalog_path,
+                                    None,
+                                ) {
+                                    Ok(is_match) => {
+                                        anchor_verified = Some(is_match);
+                                        if is_match {
+                                            result.messages.push(format!(
+                                            "Verified imported GenBank anchor '{}' against catalog '{}' ({}:{}-{})",
+                                            seq_id,
+                                            default_catalog_label,
+                                            anchor.genome_id,
+                                            anchor.chromosome,
+                                            anchor.start_1based
+                                        ));
+                                        } else {
+                                            result.warnings.push(format!(
+                                            "Imported GenBank anchor '{}' does not match catalog sequence at {}:{}:{}-{} (catalog='{}')",
+                                            seq_id,
+                                            anchor.genome_id,
+                                            anchor.chromosome,
+                                            anchor.start_1based,
+                                            anchor.end_1based,
+                                            default_catalog_label
+                                        ).into());
+                                        }
+                                    }
+                                    Err(err) => {
                                         result.warnings.push(format!(
-                                        "Imported GenBank anchor '{}' does not match catalog sequence at {}:{}:{}-{} (catalog='{}')",
-                                        seq_id,
-                                        anchor.genome_id,
-                                        anchor.chromosome,
-                                        anchor.start_1based,
-                                        anchor.end_1based,
-                                        default_catalog_label
-                                    ));
+                                        "Could not verify imported GenBank anchor '{}' against catalog '{}': {}",
+                                        seq_id, default_catalog_label, err
+                                    ).into());
                                     }
                                 }
-                                Err(err) => {
-                                    result.warnings.push(format!(
-                                    "Could not verify imported GenBank anchor '{}' against catalog '{}': {}",
-                                    seq_id, default_catalog_label, err
-                                ));
-                                }
                             }
+                            self.append_genome_extraction_provenance(GenomeExtractionProvenance {
+                                seq_id: seq_id.clone(),
+                                recorded_at_unix_ms: Self::now_unix_ms(),
+                                operation: "LoadFileGenBankRegion".to_string(),
+                                genome_id: anchor.genome_id.clone(),
+                                // Imported GenBank files are sequence sources, not catalog JSON.
+                                // Keep the default catalog path so later anchor-extension flows
+                                // resolve against real genome catalogs instead of the .gb file.
+                                catalog_path: default_catalog_path.to_string(),
+                                cache_dir: None,
+                                chromosome: Some(anchor.chromosome.clone()),
+                                start_1based: Some(anchor.start_1based),
+                                end_1based: Some(anchor.end_1based),
+                                gene_query: None,
+                                occurrence: None,
+                                gene_extract_mode: None,
+                                transcript_id: None,
+                                tss_1based: None,
+                                promoter_upstream_bp: None,
+                                promoter_downstream_bp: None,
+                                gene_id: None,
+                                gene_name: None,
+                                strand: None,
+                                anchor_strand: anchor.strand,
+                                anchor_verified,
+                                sequence_source_type: Some("genbank_file".to_string()),
+                                annotation_source_type: Some("genbank_file".to_string()),
+                                sequence_source: Some(path.clone()),
+                                annotation_source: Some(path.clone()),
+                                sequence_sha1: None,
+                                annotation_sha1: None,
+                            });
+                            let strand = anchor.strand.unwrap_or('+');
+                            let verification_label = match anchor_verified {
+                                Some(true) => "verified",
+                                Some(false) => "unverified",
+                                None => "verification n/a",
+                            };
+                            result.messages.push(format!(
+                                "Detected GenBank genome anchor for '{}': {}:{}-{} ({}, strand {}, {})",
+                                seq_id,
+                                anchor.chromosome,
+                                anchor.start_1based,
+                                anchor.end_1based,
+                                anchor.genome_id,
+                                strand,
+                                verification_label
+                            ));
                         }
-                        self.append_genome_extraction_provenance(GenomeExtractionProvenance {
-                            seq_id: seq_id.clone(),
-                            recorded_at_unix_ms: Self::now_unix_ms(),
-                            operation: "LoadFileGenBankRegion".to_string(),
-                            genome_id: anchor.genome_id.clone(),
-                            // Imported GenBank files are sequence sources, not catalog JSON.
-                            // Keep the default catalog path so later anchor-extension flows
-                            // resolve against real genome catalogs instead of the .gb file.
-                            catalog_path: default_catalog_path.to_string(),
-                            cache_dir: None,
-                            chromosome: Some(anchor.chromosome.clone()),
-                            start_1based: Some(anchor.start_1based),
-                            end_1based: Some(anchor.end_1based),
-                            gene_query: None,
-                            occurrence: None,
-                            gene_extract_mode: None,
-                            transcript_id: None,
-                            tss_1based: None,
-                            promoter_upstream_bp: None,
-                            promoter_downstream_bp: None,
-                            gene_id: None,
-                            gene_name: None,
-                            strand: None,
-                            anchor_strand: anchor.strand,
-                            anchor_verified,
-                            sequence_source_type: Some("genbank_file".to_string()),
-                            annotation_source_type: Some("genbank_file".to_string()),
-                            sequence_source: Some(path.clone()),
-                            annotation_source: Some(path.clone()),
-                            sequence_sha1: None,
-                            annotation_sha1: None,
-                        });
-                        let strand = anchor.strand.unwrap_or('+');
-                        let verification_label = match anchor_verified {
-                            Some(true) => "verified",
-                            Some(false) => "unverified",
-                            None => "verification n/a",
-                        };
-                        result.messages.push(format!(
-                            "Detected GenBank genome anchor for '{}': {}:{}-{} ({}, strand {}, {})",
-                            seq_id,
-                            anchor.chromosome,
-                            anchor.start_1based,
-                            anchor.end_1based,
-                            anchor.genome_id,
-                            strand,
-                            verification_label
-                        ));
+                        result.created_seq_ids.push(seq_id.clone());
+                        result
+                            .messages
+                            .push(format!("Loaded '{path}' as '{seq_id}'"));
                     }
-                    result.created_seq_ids.push(seq_id.clone());
-                    result
-                        .messages
-                        .push(format!("Loaded '{path}' as '{seq_id}'"));
                 }
                 Operation::CreateSequenceFromText {
                     sequence_text,
@@ -16727,6 +17160,7 @@ impl GentleEngine {
                     seq_id,
                     path,
                     format,
+                    genbank_options,
                 } => {
                     let dna = self
                         .state
@@ -16741,12 +17175,7 @@ impl GentleEngine {
 
                     match format {
                         ExportFormat::GenBank => {
-                            dna.write_genbank_file(&path).map_err(|e| EngineError {
-                                code: ErrorCode::Io,
-                                message: format!("Could not write GenBank file '{path}': {e}"),
-
-                                cause_chain: vec![],
-                            })?;
+                            Self::save_as_genbank(dna, &path, genbank_options.as_ref())?
                         }
                         ExportFormat::Fasta => Self::save_as_fasta(&seq_id, dna, &path)?,
                     }
@@ -16756,7 +17185,27 @@ impl GentleEngine {
                         .messages
                         .push(format!("Wrote '{seq_id}' to '{path}'"));
                 }
-                Operation::RenderSequenceSvg { seq_id, mode, path } => {
+                Operation::ExportAll {
+                    directory,
+                    format,
+                    filename_template,
+                } => {
+                    self.export_all_sequences(
+                        &mut result,
+                        &directory,
+                        &format,
+                        filename_template.as_deref(),
+                    )?;
+                }
+                Operation::ExportDesignPackage { path, include } => {
+                    self.export_design_package(&path, &include, &mut result)?;
+                }
+                Operation::RenderSequenceSvg {
+                    seq_id,
+                    mode,
+                    path,
+                    viewport,
+                } => {
                     let dna = self
                         .state
                         .sequences
@@ -16767,9 +17216,28 @@ impl GentleEngine {
 
                             cause_chain: vec![],
                         })?;
+                    let display = match viewport {
+                        Some((start_bp, span_bp)) => {
+                            if span_bp == 0 || start_bp >= dna.len() {
+                                return Err(EngineError {
+                                    code: ErrorCode::InvalidInput,
+                                    message: format!(
+                                        "RenderSequenceSvg viewport ({start_bp}, {span_bp}) must have span_bp >= 1 and start inside '{seq_id}' ({} bp)",
+                                        dna.len()
+                                    ),
+                                    cause_chain: vec![],
+                                });
+                            }
+                            let mut display = self.state.display.clone();
+                            display.linear_view_start_bp = start_bp;
+                            display.linear_view_span_bp = span_bp;
+                            std::borrow::Cow::Owned(display)
+                        }
+                        None => std::borrow::Cow::Borrowed(&self.state.display),
+                    };
                     let svg = match mode {
-                        RenderSvgMode::Linear => export_linear_svg(dna, &self.state.display),
-                        RenderSvgMode::Circular => export_circular_svg(dna, &self.state.display),
+                        RenderSvgMode::Linear => export_linear_svg(dna, &display),
+                        RenderSvgMode::Circular => export_circular_svg(dna, &display),
                     };
                     std::fs::write(&path, svg).map_err(|e| EngineError {
                         code: ErrorCode::Io,
@@ -16782,6 +17250,13 @@ impl GentleEngine {
                         mode, seq_id, path
                     ));
                 }
+                Operation::RenderThumbnails {
+                    directory,
+                    mode,
+                    max_width_px,
+                } => {
+                    self.render_thumbnails(&mut result, &directory, &mode, max_width_px)?;
+                }
                 Operation::RenderDotplotSvg {
                     seq_id,
                     dotplot_id,
@@ -16906,7 +17381,7 @@ impl GentleEngine {
                         cause_chain: vec![],
                     })?;
                     for warning in &report.warnings {
-                        result.warnings.push(warning.clone());
+                        result.warnings.push(warning.clone().into());
                     }
                     result.messages.push(format!(
                         "Wrote multi-gene promoter TFBS SVG for '{}' ({} gene(s), {} per-gene motif row(s), score_kind={}, clip_negative={}) to '{}'",
@@ -17005,6 +17480,36 @@ impl GentleEngine {
                         seq_id, path, report.tool
                     ));
                 }
+                Operation::RenderRnaStructureSvgConstrained {
+                    seq_id,
+                    path,
+                    constraints,
+                } => {
+                    let report =
+                        self.render_rna_structure_svg_constrained(&seq_id, &path, &constraints)?;
+                    result.messages.push(format!(
+                        "Wrote constrained RNA structure SVG for '{}' to '{}' using RNAfold/{} (constraint={})",
+                        seq_id,
+                        path,
+                        report.tool,
+                        report.constraint.as_deref().unwrap_or("-")
+                    ));
+                }
+                Operation::RenderRnaStructureFromDotBracket {
+                    seq_id,
+                    dot_bracket,
+                    path,
+                } => {
+                    let report = self.render_rna_structure_svg_from_dot_bracket(
+                        &seq_id,
+                        &dot_bracket,
+                        &path,
+                    )?;
+                    result.messages.push(format!(
+                        "Wrote RNA structure SVG for '{}' to '{}' from provided dot-bracket structure using {}",
+                        seq_id, path, report.tool
+                    ));
+                }
                 Operation::RenderLineageSvg { path } => {
                     let svg = export_lineage_svg(&self.state, self.operation_log());
                     std::fs::write(&path, svg).map_err(|e| EngineError {
@@ -17172,7 +17677,9 @@ impl GentleEngine {
                             false,
                             &result.op_id,
                         )?;
-                    result.warnings.extend(warnings);
+                    result
+                        .warnings
+                        .extend(warnings.into_iter().map(Warning::from));
                     if report.peptides.is_empty() {
                         return Err(EngineError {
                             code: ErrorCode::NotFound,
@@ -17575,7 +18082,14 @@ impl GentleEngine {
                         })?;
                     let execution = derive_gibson_execution_plan(self, &plan)?;
                     parent_seq_ids = execution.parent_seq_ids.clone();
-                    result.warnings.extend(execution.preview.warnings.clone());
+                    result.warnings.extend(
+                        execution
+                            .preview
+                            .warnings
+                            .iter()
+                            .cloned()
+                            .map(Warning::from),
+                    );
                     let mut assembled_product_seq_id: Option<String> = None;
                     for output in execution.outputs {
                         let seq_id = self.unique_seq_id(&output.base_seq_id);
@@ -17677,10 +18191,13 @@ impl GentleEngine {
                             }
                             Ok(None) => {}
                             Err(err) => {
-                                result.warnings.push(format!(
-                                    "Gibson arrangement was not created automatically: {}",
-                                    err.message
-                                ));
+                                result.warnings.push(
+                                    format!(
+                                        "Gibson arrangement was not created automatically: {}",
+                                        err.message
+                                    )
+                                    .into(),
+                                );
                             }
                         }
                     }
@@ -17703,6 +18220,8 @@ impl GentleEngine {
                 | op @ Operation::MoveRackSamples { .. }
                 | op @ Operation::MoveRackArrangementBlocks { .. }
                 | op @ Operation::RenderPoolGelSvg { .. }
+                | op @ Operation::RenderPlateLayoutSvg { .. }
+                | op @ Operation::DefineDnaLadder { .. }
                 | op @ Operation::ExportDnaLadders { .. }
                 | op @ Operation::ExportRnaLadders { .. } => {
                     self.apply_arrangement_rack_and_ladder_operation(op, &mut result)?;
@@ -17778,11 +18297,16 @@ impl GentleEngine {
                     cache_dir,
                     timeout_seconds,
                 } => {
-                    let report = Self::prepare_reference_genome_once(
+                    let download_retry = DownloadRetryPolicy {
+                        max_attempts: self.state.parameters.genome_download_max_attempts,
+                        base_delay_ms: self.state.parameters.genome_download_retry_base_delay_ms,
+                    };
+                    let report = Self::prepare_reference_genome_once_with_retry(
                         &genome_id,
                         catalog_path.as_deref(),
                         cache_dir.as_deref(),
                         timeout_seconds,
+                        &download_retry,
                         &mut |p| on_progress(OperationProgress::GenomePrepare(p)),
                     )?;
                     result.messages.push(Self::format_prepare_genome_message(
@@ -17791,7 +18315,9 @@ impl GentleEngine {
                         &report,
                     ));
                     if !report.warnings.is_empty() {
-                        result.warnings.extend(report.warnings.clone());
+                        result
+                            .warnings
+                            .extend(report.warnings.iter().cloned().map(Warning::from));
                     }
                 }
                 Operation::ExtractGenomeRegion {
@@ -18017,7 +18543,7 @@ impl GentleEngine {
                             seq_id,
                             local_start_0based + 1,
                             dna.len()
-                        ));
+                        ).into());
                         }
                     }
                 }
@@ -18025,6 +18551,7 @@ impl GentleEngine {
                     genome_id,
                     gene_query,
                     occurrence,
+                    match_mode,
                     output_id,
                     extract_mode,
                     promoter_upstream_bp,
@@ -18057,22 +18584,8 @@ impl GentleEngine {
 
                             cause_chain: vec![],
                         })?;
-                    let mut exact_matches: Vec<&GenomeGeneRecord> = genes
-                        .iter()
-                        .filter(|record| Self::genome_gene_matches_exact(record, query))
-                        .collect();
-                    let used_fuzzy = if exact_matches.is_empty() {
-                        let query_lower = query.to_ascii_lowercase();
-                        exact_matches = genes
-                            .iter()
-                            .filter(|record| {
-                                Self::genome_gene_matches_contains(record, &query_lower)
-                            })
-                            .collect();
-                        true
-                    } else {
-                        false
-                    };
+                    let (exact_matches, match_label) =
+                        Self::select_genome_gene_matches(&genes, query, match_mode)?;
                     if exact_matches.is_empty() {
                         return Err(EngineError {
                             code: ErrorCode::NotFound,
@@ -18097,8 +18610,25 @@ impl GentleEngine {
                         query,
                         exact_matches.len(),
                         genome_id
-                    ));
+                    ).into());
                     }
+                    result.messages.push(format!(
+                        "Gene query '{}' ({} match) matched {} record(s) in '{}': {}",
+                        query,
+                        match_label,
+                        exact_matches.len(),
+                        genome_id,
+                        exact_matches
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, record)| format!(
+                                "#{} {}",
+                                idx + 1,
+                                Self::genome_gene_display_label(record)
+                            ))
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    ));
                     let Some(selected_gene) = exact_matches.get(occurrence - 1) else {
                         return Err(EngineError {
                             code: ErrorCode::NotFound,
@@ -18142,7 +18672,7 @@ impl GentleEngine {
                                 "Could not inspect transcript/exon annotation for gene '{}': {}",
                                 Self::genome_gene_display_label(selected_gene),
                                 e
-                            ));
+                            ).into());
                             vec![]
                         }
                     };
@@ -18198,16 +18728,19 @@ impl GentleEngine {
                                     "Gene-scoped transcript filter returned no records for '{}'; using overlap fallback with {} transcript candidate(s).",
                                     Self::genome_gene_display_label(selected_gene),
                                     records.len()
-                                ));
+                                ).into());
                                     transcript_records = records;
                                 }
                             }
                             Err(e) => {
-                                result.warnings.push(format!(
-                                    "Could not run transcript fallback for gene '{}': {}",
-                                    Self::genome_gene_display_label(selected_gene),
-                                    e
-                                ));
+                                result.warnings.push(
+                                    format!(
+                                        "Could not run transcript fallback for gene '{}': {}",
+                                        Self::genome_gene_display_label(selected_gene),
+                                        e
+                                    )
+                                    .into(),
+                                );
                             }
                         }
                     }
@@ -18225,27 +18758,27 @@ impl GentleEngine {
 
                             cause_chain: vec![],
                         })?;
-                    let sequence = catalog
-                        .get_sequence_region_with_cache(
-                            &genome_id,
-                            &selected_gene.chromosome,
+                    let sequence = Self::load_genome_slice_sequence(
+                        &catalog,
+                        &genome_id,
+                        &selected_gene.chromosome,
+                        extract_start_1based,
+                        extract_end_1based,
+                        cache_dir.as_deref(),
+                    )
+                    .map_err(|e| EngineError {
+                        code: ErrorCode::NotFound,
+                        message: format!(
+                            "Could not load gene extraction interval {}:{}-{} from '{}': {}",
+                            selected_gene.chromosome,
                             extract_start_1based,
                             extract_end_1based,
-                            cache_dir.as_deref(),
-                        )
-                        .map_err(|e| EngineError {
-                            code: ErrorCode::NotFound,
-                            message: format!(
-                                "Could not load gene extraction interval {}:{}-{} from '{}': {}",
-                                selected_gene.chromosome,
-                                extract_start_1based,
-                                extract_end_1based,
-                                genome_id,
-                                e
-                            ),
+                            genome_id,
+                            e
+                        ),
 
-                            cause_chain: vec![],
-                        })?;
+                        cause_chain: vec![],
+                    })?;
                     let default_id = Self::default_extract_genome_gene_output_id(
                         &genome_id,
                         selected_gene,
@@ -18494,10 +19027,10 @@ impl GentleEngine {
                         result.warnings.push(format!(
                         "No overlapping genomic annotation features were attached to extracted gene '{}'",
                         seq_id
-                    ));
+                    ).into());
                     }
                     if let Some(reason) = fallback_reason.as_ref() {
-                        result.warnings.push(reason.clone());
+                        result.warnings.push(reason.clone().into());
                     }
                     result.genome_annotation_projection =
                         Some(GenomeAnnotationProjectionTelemetry {
@@ -18562,11 +19095,10 @@ impl GentleEngine {
                         sequence_sha1,
                         annotation_sha1,
                     });
-                    let match_mode = if used_fuzzy { "fuzzy" } else { "exact" };
                     result.messages.push(format!(
                     "Extracted genome gene '{}' [{} match, occurrence {}] as '{}' from '{}' ({}, extract_mode={}, promoter_upstream_bp={})",
                     query,
-                    match_mode,
+                    match_label,
                     occurrence,
                     seq_id,
                     genome_id,
@@ -18575,6 +19107,24 @@ impl GentleEngine {
                     promoter_upstream_bp
                 ));
                 }
+                Operation::ExtractGenomeGenes {
+                    genome_id,
+                    gene_queries,
+                    match_mode,
+                    output_prefix,
+                    catalog_path,
+                    cache_dir,
+                } => {
+                    self.extract_genome_genes_batch(
+                        &mut result,
+                        &genome_id,
+                        &gene_queries,
+                        match_mode,
+                        output_prefix.as_deref(),
+                        catalog_path,
+                        cache_dir,
+                    )?;
+                }
                 Operation::ExtractGenomePromoterSlice {
                     genome_id,
                     gene_query,
@@ -18602,7 +19152,9 @@ impl GentleEngine {
                         downstream_bp,
                         cache_dir.as_deref(),
                     )?;
-                    result.warnings.extend(promoter.warnings.clone());
+                    result
+                        .warnings
+                        .extend(promoter.warnings.iter().cloned().map(Warning::from));
 
                     let default_id = Self::default_extract_genome_promoter_slice_output_id(
                         &genome_id,
@@ -18768,28 +19320,21 @@ impl GentleEngine {
                         }
                     };
                     if let Some(warning) = catalog_fallback_warning {
-                        result.warnings.push(warning);
+                        result.warnings.push(warning.into());
                     }
 
                     let anchor_is_reverse = anchor.strand == Some('-');
-                    let (new_start_1based, new_end_1based) = match (anchor_is_reverse, side) {
-                        (false, GenomeAnchorSide::FivePrime) => (
-                            anchor.start_1based.saturating_sub(length_bp).max(1),
-                            anchor.end_1based,
-                        ),
-                        (false, GenomeAnchorSide::ThreePrime) => (
-                            anchor.start_1based,
-                            anchor.end_1based.saturating_add(length_bp),
-                        ),
-                        (true, GenomeAnchorSide::FivePrime) => (
-                            anchor.start_1based,
-                            anchor.end_1based.saturating_add(length_bp),
-                        ),
-                        (true, GenomeAnchorSide::ThreePrime) => (
-                            anchor.start_1based.saturating_sub(length_bp).max(1),
-                            anchor.end_1based,
-                        ),
+                    // Requested growth toward lower/upper genomic coordinates.
+                    let (lower_request_bp, upper_request_bp) = match (anchor_is_reverse, side) {
+                        (false, GenomeAnchorSide::FivePrime)
+                        | (true, GenomeAnchorSide::ThreePrime) => (length_bp, 0),
+                        (false, GenomeAnchorSide::ThreePrime)
+                        | (true, GenomeAnchorSide::FivePrime) => (0, length_bp),
+                        (_, GenomeAnchorSide::Both) => (length_bp, length_bp),
                     };
+                    let new_start_1based =
+                        anchor.start_1based.saturating_sub(lower_request_bp).max(1);
+                    let mut new_end_1based = anchor.end_1based.saturating_add(upper_request_bp);
                     let preferred_prepared = prepared_genome_id
                         .as_deref()
                         .map(str::trim)
@@ -18820,9 +19365,28 @@ impl GentleEngine {
                             cause_chain: vec![],
                         })?;
                     if let Some(warning) = prepared_resolution.fallback_warning {
-                        result.warnings.push(warning);
+                        result.warnings.push(warning.into());
                     }
                     let effective_genome_id = prepared_resolution.resolved_genome_id;
+                    let mut upper_clip_length_bp: Option<usize> = None;
+                    if upper_request_bp > 0
+                        && let Some(chromosome_length_bp) = catalog
+                            .list_chromosome_lengths(
+                                &effective_genome_id,
+                                resolved_cache_dir.as_deref(),
+                            )
+                            .ok()
+                            .and_then(|records| {
+                                records
+                                    .into_iter()
+                                    .find(|record| record.chromosome == anchor.chromosome)
+                                    .map(|record| record.length_bp)
+                            })
+                        && new_end_1based > chromosome_length_bp
+                    {
+                        new_end_1based = chromosome_length_bp.max(anchor.end_1based);
+                        upper_clip_length_bp = Some(chromosome_length_bp);
+                    }
                     let mut sequence = catalog
                         .get_sequence_region_with_cache(
                             &effective_genome_id,
@@ -18922,6 +19486,7 @@ impl GentleEngine {
                     let side_label = match side {
                         GenomeAnchorSide::FivePrime => "5'",
                         GenomeAnchorSide::ThreePrime => "3'",
+                        GenomeAnchorSide::Both => "5' and 3'",
                     };
                     let anchor_strand = anchor.strand.unwrap_or('+');
                     result.messages.push(format!(
@@ -18936,18 +19501,55 @@ impl GentleEngine {
                     new_end_1based,
                     extended_seq_id
                 ));
-                    let lower_bound_clipped = matches!(
-                        (anchor_is_reverse, side),
-                        (false, GenomeAnchorSide::FivePrime) | (true, GenomeAnchorSide::ThreePrime)
-                    ) && new_start_1based == 1
-                        && anchor.start_1based <= length_bp;
-                    if lower_bound_clipped {
-                        result.warnings.push(format!(
-                        "Requested {} bp {} extension for '{}' clipped at chromosome start position 1",
-                        length_bp, side_label, seq_id
-                    ));
+                    let lower_added_bp = anchor.start_1based.saturating_sub(new_start_1based);
+                    let upper_added_bp = new_end_1based.saturating_sub(anchor.end_1based);
+                    let (lower_side_label, upper_side_label) = if anchor_is_reverse {
+                        ("3'", "5'")
+                    } else {
+                        ("5'", "3'")
+                    };
+                    if matches!(side, GenomeAnchorSide::Both) {
+                        let (five_prime_added_bp, three_prime_added_bp) = if anchor_is_reverse {
+                            (upper_added_bp, lower_added_bp)
+                        } else {
+                            (lower_added_bp, upper_added_bp)
+                        };
+                        result.messages.push(format!(
+                            "Added {} bp on 5' and {} bp on 3' of '{}' (requested {} bp per side)",
+                            five_prime_added_bp, three_prime_added_bp, seq_id, length_bp
+                        ));
+                    }
+                    if lower_added_bp < lower_request_bp {
+                        result.warnings.push(Warning::new(
+                            WarningCode::FeatureClipped,
+                            format!(
+                                "Requested {} bp {} extension for '{}' clipped at chromosome start position 1",
+                                length_bp, lower_side_label, seq_id
+                            ),
+                        ));
+                    }
+                    if let Some(chromosome_length_bp) = upper_clip_length_bp {
+                        result.warnings.push(Warning::new(
+                            WarningCode::FeatureClipped,
+                            format!(
+                                "Requested {} bp {} extension for '{}' clipped at chromosome end position {}",
+                                length_bp, upper_side_label, seq_id, chromosome_length_bp
+                            ),
+                        ));
                     }
                 }
+                Operation::RelocateGenomeAnchor {
+                    seq_id,
+                    new_catalog_path,
+                    new_cache_dir,
+                } => {
+                    self.apply_genome_anchor_relocation(
+                        &mut result,
+                        &seq_id,
+                        new_catalog_path,
+                        new_cache_dir,
+                    )?;
+                }
                 Operation::VerifyGenomeAnchor {
                     seq_id,
                     catalog_path,
@@ -19032,7 +19634,7 @@ impl GentleEngine {
                         }
                     };
                     if let Some(warning) = catalog_fallback_warning {
-                        result.warnings.push(warning);
+                        result.warnings.push(warning.into());
                     }
 
                     let preferred_prepared = prepared_genome_id
@@ -19065,7 +19667,7 @@ impl GentleEngine {
                             cause_chain: vec![],
                         })?;
                     if let Some(warning) = prepared_resolution.fallback_warning {
-                        result.warnings.push(warning);
+                        result.warnings.push(warning.into());
                     }
                     let effective_genome_id = prepared_resolution.resolved_genome_id;
                     let anchor_for_verification = GenomeSequenceAnchor {
@@ -19150,7 +19752,7 @@ impl GentleEngine {
                         result.warnings.push(format!(
                             "Genome anchor for '{}' is unverified against '{}' via prepared '{}'",
                             seq_id, resolved_catalog_path, effective_genome_id
-                        ));
+                        ).into());
                         result.messages.push(format!(
                             "Recorded anchor verification status for '{}' as unverified",
                             seq_id
@@ -19248,10 +19850,18 @@ impl GentleEngine {
                     report.skipped_records
                 ));
                     if report.skipped_missing_score > 0 {
-                        result.warnings.push(format!(
-                        "{} BED record(s) were skipped because score filters were set but the BED score column was missing",
-                        report.skipped_missing_score
-                    ));
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::TrackImportRecordsSkipped,
+                                format!(
+                                    "{} BED record(s) were skipped because score filters were set but the BED score column was missing",
+                                    report.skipped_missing_score
+                                ),
+                            )
+                            .with_context(json!({
+                                "skipped_missing_score": report.skipped_missing_score,
+                            })),
+                        );
                     }
                     if report.skipped_outside_score_range > 0 {
                         result.messages.push(format!(
@@ -19260,10 +19870,19 @@ impl GentleEngine {
                         ));
                     }
                     if report.truncated_at_limit {
-                        result.warnings.push(format!(
-                            "BED import was truncated after {} features (limit={})",
-                            report.imported_features, MAX_IMPORTED_SIGNAL_FEATURES
-                        ));
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::ResultTruncated,
+                                format!(
+                                    "BED import was truncated after {} features (limit={})",
+                                    report.imported_features, MAX_IMPORTED_SIGNAL_FEATURES
+                                ),
+                            )
+                            .with_context(json!({
+                                "imported_features": report.imported_features,
+                                "limit": MAX_IMPORTED_SIGNAL_FEATURES,
+                            })),
+                        );
                     }
                 }
                 Operation::ImportGenomeBigWigTrack {
@@ -19357,10 +19976,18 @@ impl GentleEngine {
                     report.skipped_records
                 ));
                     if report.skipped_missing_score > 0 {
-                        result.warnings.push(format!(
-                        "{} converted bedGraph record(s) were skipped because score filters were set but no value was available",
-                        report.skipped_missing_score
-                    ));
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::TrackImportRecordsSkipped,
+                                format!(
+                                    "{} converted bedGraph record(s) were skipped because score filters were set but no value was available",
+                                    report.skipped_missing_score
+                                ),
+                            )
+                            .with_context(json!({
+                                "skipped_missing_score": report.skipped_missing_score,
+                            })),
+                        );
                     }
                     if report.skipped_outside_score_range > 0 {
                         result.messages.push(format!(
@@ -19369,10 +19996,19 @@ impl GentleEngine {
                         ));
                     }
                     if report.truncated_at_limit {
-                        result.warnings.push(format!(
-                            "BigWig import was truncated after {} features (limit={})",
-                            report.imported_features, MAX_IMPORTED_SIGNAL_FEATURES
-                        ));
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::ResultTruncated,
+                                format!(
+                                    "BigWig import was truncated after {} features (limit={})",
+                                    report.imported_features, MAX_IMPORTED_SIGNAL_FEATURES
+                                ),
+                            )
+                            .with_context(json!({
+                                "imported_features": report.imported_features,
+                                "limit": MAX_IMPORTED_SIGNAL_FEATURES,
+                            })),
+                        );
                     }
                 }
                 Operation::ImportGenomeVcfTrack {
@@ -19466,22 +20102,39 @@ impl GentleEngine {
                     report.skipped_records
                 ));
                     if report.skipped_missing_score > 0 {
-                        result.warnings.push(format!(
-                        "{} VCF record(s) were skipped because QUAL-based score filters were set but QUAL was missing",
-                        report.skipped_missing_score
-                    ));
-                    }
-                    if report.skipped_outside_score_range > 0 {
-                        result.messages.push(format!(
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::TrackImportRecordsSkipped,
+                                format!(
+                                    "{} VCF record(s) were skipped because QUAL-based score filters were set but QUAL was missing",
+                                    report.skipped_missing_score
+                                ),
+                            )
+                            .with_context(json!({
+                                "skipped_missing_score": report.skipped_missing_score,
+                            })),
+                        );
+                    }
+                    if report.skipped_outside_score_range > 0 {
+                        result.messages.push(format!(
                             "{} VCF record(s) were outside QUAL score filter bounds",
                             report.skipped_outside_score_range
                         ));
                     }
                     if report.truncated_at_limit {
-                        result.warnings.push(format!(
-                            "VCF import was truncated after {} features (limit={})",
-                            report.imported_features, MAX_IMPORTED_SIGNAL_FEATURES
-                        ));
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::ResultTruncated,
+                                format!(
+                                    "VCF import was truncated after {} features (limit={})",
+                                    report.imported_features, MAX_IMPORTED_SIGNAL_FEATURES
+                                ),
+                            )
+                            .with_context(json!({
+                                "imported_features": report.imported_features,
+                                "limit": MAX_IMPORTED_SIGNAL_FEATURES,
+                            })),
+                        );
                     }
                 }
                 Operation::ProjectMicroarrayTrack {
@@ -19530,7 +20183,9 @@ impl GentleEngine {
                     )?;
 
                     result.changed_seq_ids.push(seq_id.clone());
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Projected {} microarray feature(s) into '{}' from '{}' (dataset={}, platform={}, contrasts={}, anchor={} {}:{}-{} strand {}, parsed={}, skipped={})",
                         report.imported_features,
@@ -19583,7 +20238,9 @@ impl GentleEngine {
                     )?;
 
                     result.changed_seq_ids.push(seq_id.clone());
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Projected {} probe-region helper feature(s) into '{}' from '{}' (platform={}, level={}, contrasts={}, anchor={} {}:{}-{} strand {}, parsed={}, skipped={})",
                         report.imported_features,
@@ -19646,7 +20303,9 @@ impl GentleEngine {
                             cause_chain: vec![],
                         })?;
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Interpreted {} projected probe-region feature(s) on '{}' against {} transcript model(s) (level={}, gene={})",
                         report.array_feature_count,
@@ -19693,7 +20352,9 @@ impl GentleEngine {
                             report.intervals.len()
                         ));
                     } else {
-                        result.warnings.extend(report.warnings.clone());
+                        result
+                            .warnings
+                            .extend(report.warnings.iter().cloned().map(Warning::from));
                     }
                     result.genome_coordinate_projection = Some(report);
                 }
@@ -19723,7 +20384,9 @@ impl GentleEngine {
                         "Inspected CUT&RUN dataset '{}' status",
                         status.dataset_id
                     ));
-                    result.warnings.extend(status.warnings.clone());
+                    result
+                        .warnings
+                        .extend(status.warnings.iter().cloned().map(Warning::from));
                     result.cutrun_dataset_status = Some(status);
                 }
                 Operation::PrepareCutRunDataset {
@@ -19752,7 +20415,9 @@ impl GentleEngine {
                         "Prepared CUT&RUN dataset '{}' ({prepared_label})",
                         status.dataset_id
                     ));
-                    result.warnings.extend(status.warnings.clone());
+                    result
+                        .warnings
+                        .extend(status.warnings.iter().cloned().map(Warning::from));
                     result.cutrun_dataset_status = Some(status);
                 }
                 Operation::ProjectCutRunDataset {
@@ -19774,7 +20439,9 @@ impl GentleEngine {
                         cache_dir.as_deref(),
                     )?;
                     result.changed_seq_ids.push(seq_id.clone());
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Projected CUT&RUN dataset '{}' onto '{}' (peaks={}, signal={})",
                         report.dataset_id,
@@ -19829,7 +20496,9 @@ impl GentleEngine {
                     report.fragment_count,
                     report.concordant_pair_count
                 ));
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.cutrun_read_report = Some(report);
                 }
                 Operation::ListCutRunReadReports { seq_id } => {
@@ -19854,7 +20523,9 @@ impl GentleEngine {
                         report.mapped_units,
                         report.fragment_count
                     ));
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.cutrun_read_report = Some(report);
                 }
                 Operation::ExportCutRunReadCoverage {
@@ -19900,7 +20571,9 @@ impl GentleEngine {
                             report.seq_id, path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "CUT&RUN regulatory support for '{}' merged {} evidence source(s), {} support window(s), {} confirmed TFBS, {} motif-absent strong window(s)",
                         report.seq_id,
@@ -19939,7 +20612,9 @@ impl GentleEngine {
                             .messages
                             .push(format!("Wrote gene-set resolution report to '{}'", path));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Resolved gene set from {}: {} member(s), {} unresolved",
                         report.request.source_kind_label(),
@@ -19997,7 +20672,9 @@ impl GentleEngine {
                             path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     let query_label = report
                         .query_metadata
                         .as_ref()
@@ -20067,7 +20744,9 @@ impl GentleEngine {
                             path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     let query_label = report
                         .query_metadata
                         .as_ref()
@@ -20149,7 +20828,9 @@ impl GentleEngine {
                             path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     let query_label = report
                         .query_metadata
                         .as_ref()
@@ -20217,7 +20898,9 @@ impl GentleEngine {
                             genome_id, path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Built gene-set promoter cohort for '{}' with {} window(s)",
                         genome_id, report.returned_window_count
@@ -20302,7 +20985,9 @@ impl GentleEngine {
                             path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "CUT&RUN gene-set regulatory support evaluated {} of {} member(s)",
                         report.aggregate.evaluated_member_count, report.aggregate.member_count
@@ -20353,7 +21038,9 @@ impl GentleEngine {
                             path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Resolved ortholog promoter cohort for '{}' in '{}' with {} promoter row(s) and {} unresolved target(s)",
                         report.request.anchor_gene_query,
@@ -20432,7 +21119,9 @@ impl GentleEngine {
                             path
                         ));
                     }
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Ortholog promoter comparison scored {} motif(s) across {} promoter row(s), with {} pairwise TFBS row(s)",
                         report.motifs_requested.len(),
@@ -20479,7 +21168,9 @@ impl GentleEngine {
                         resource: resource.clone(),
                     })?;
                     result.changed_seq_ids.push(seq_id.clone());
-                    result.warnings.extend(preview.warnings.clone());
+                    result
+                        .warnings
+                        .extend(preview.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Imported isoform panel '{}' for '{}' (isoforms={}, strict={}) from '{}'",
                         panel_id,
@@ -20754,9 +21445,13 @@ impl GentleEngine {
                     projection.run_id = Some(run_id.to_string());
                     let projection_id = projection.projection_id.clone();
                     let transcript_count = projection.transcript_projections.len();
-                    result.warnings.extend(projection.warnings.clone());
+                    result
+                        .warnings
+                        .extend(projection.warnings.iter().cloned().map(Warning::from));
                     for transcript in &projection.transcript_projections {
-                        result.warnings.extend(transcript.warnings.clone());
+                        result
+                            .warnings
+                            .extend(transcript.warnings.iter().cloned().map(Warning::from));
                     }
                     self.upsert_uniprot_projection(projection)?;
                     result.messages.push(format!(
@@ -20776,7 +21471,9 @@ impl GentleEngine {
                         residue_start_1based,
                         residue_end_1based,
                     )?;
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "Mapped protein residue(s) {}..{} on '{}' back to genomic codon coordinates (matches={})",
                         report.residue_start_1based,
@@ -20883,6 +21580,9 @@ impl GentleEngine {
                     hits,
                     track_name,
                     clear_existing,
+                    min_identity_percent,
+                    max_evalue,
+                    min_bit_score,
                     blast_provenance,
                 } => {
                     if hits.is_empty() {
@@ -20893,6 +21593,20 @@ impl GentleEngine {
                             cause_chain: vec![],
                         });
                     }
+                    for (name, value) in [
+                        ("min_identity_percent", min_identity_percent),
+                        ("max_evalue", max_evalue),
+                        ("min_bit_score", min_bit_score),
+                    ] {
+                        if value.is_some_and(|v| !v.is_finite()) {
+                            return Err(EngineError {
+                                code: ErrorCode::InvalidInput,
+                                message: format!("ImportBlastHitsTrack {name} must be finite"),
+
+                                cause_chain: vec![],
+                            });
+                        }
+                    }
                     let selected_track_name = track_name
                         .as_deref()
                         .map(str::trim)
@@ -20934,30 +21648,54 @@ impl GentleEngine {
 
                     let mut imported_count = 0usize;
                     let mut skipped_count = 0usize;
+                    let mut skipped_low_identity = 0usize;
+                    let mut skipped_high_evalue = 0usize;
+                    let mut skipped_low_bit_score = 0usize;
                     for (idx, hit) in hits.iter().enumerate() {
+                        if min_identity_percent.is_some_and(|v| hit.identity_percent < v) {
+                            skipped_count += 1;
+                            skipped_low_identity += 1;
+                            continue;
+                        }
+                        if max_evalue.is_some_and(|v| hit.evalue > v) {
+                            skipped_count += 1;
+                            skipped_high_evalue += 1;
+                            continue;
+                        }
+                        if min_bit_score.is_some_and(|v| hit.bit_score < v) {
+                            skipped_count += 1;
+                            skipped_low_bit_score += 1;
+                            continue;
+                        }
                         let start = hit.query_start_1based.min(hit.query_end_1based);
                         let end = hit.query_start_1based.max(hit.query_end_1based);
                         if start == 0 || end == 0 {
                             skipped_count += 1;
                             if result.warnings.len() < 20 {
-                                result.warnings.push(format!(
-                                "BLAST hit {} skipped because query coordinates are invalid: {}..{}",
-                                idx + 1,
-                                hit.query_start_1based,
-                                hit.query_end_1based
-                            ));
+                                result.warnings.push(Warning::new(
+                                    WarningCode::TrackImportRecordsSkipped,
+                                    format!(
+                                        "BLAST hit {} skipped because query coordinates are invalid: {}..{}",
+                                        idx + 1,
+                                        hit.query_start_1based,
+                                        hit.query_end_1based
+                                    ),
+                                ));
                             }
                             continue;
                         }
                         if start > seq_len {
                             skipped_count += 1;
                             if result.warnings.len() < 20 {
-                                result.warnings.push(format!(
-                                "BLAST hit {} skipped because query start {} is outside sequence length {}",
-                                idx + 1,
-                                start,
-                                seq_len
-                            ));
+                                result.warnings.push(Warning::new(
+                                    WarningCode::TrackImportRecordsSkipped,
+                                    format!(
+                                        "BLAST hit {} skipped because query start {} is outside sequence length {}",
+                                        idx + 1,
+                                        start,
+                                        seq_len
+                                    ),
+                                ));
                             }
                             continue;
                         }
@@ -20967,15 +21705,25 @@ impl GentleEngine {
                             continue;
                         }
                         if end_clamped < end && result.warnings.len() < 20 {
-                            result.warnings.push(format!(
-                            "BLAST hit {} query range {}..{} was clamped to {}..{} for sequence length {}",
-                            idx + 1,
-                            start,
-                            end,
-                            start,
-                            end_clamped,
-                            seq_len
-                        ));
+                            result.warnings.push(
+                                Warning::new(
+                                    WarningCode::FeatureClipped,
+                                    format!(
+                                        "BLAST hit {} query range {}..{} was clamped to {}..{} for sequence length {}",
+                                        idx + 1,
+                                        start,
+                                        end,
+                                        start,
+                                        end_clamped,
+                                        seq_len
+                                    ),
+                                )
+                                .with_context(json!({
+                                    "hit_index": idx + 1,
+                                    "requested_end_1based": end,
+                                    "clamped_end_1based": end_clamped,
+                                })),
+                            );
                         }
                         let local_start_0based = start.saturating_sub(1);
                         let local_end_0based_exclusive = end_clamped;
@@ -21010,6 +21758,27 @@ impl GentleEngine {
                     skipped_count,
                     removed_count
                 ));
+                    if min_identity_percent.is_some()
+                        || max_evalue.is_some()
+                        || min_bit_score.is_some()
+                    {
+                        result.messages.push(format!(
+                            "BLAST hit thresholds skipped {} hit(s): identity<{}={}, evalue>{}={}, bit_score<{}={}",
+                            skipped_low_identity + skipped_high_evalue + skipped_low_bit_score,
+                            min_identity_percent
+                                .map(|v| format!("{v}"))
+                                .unwrap_or_else(|| "-".to_string()),
+                            skipped_low_identity,
+                            max_evalue
+                                .map(|v| format!("{v}"))
+                                .unwrap_or_else(|| "-".to_string()),
+                            skipped_high_evalue,
+                            min_bit_score
+                                .map(|v| format!("{v}"))
+                                .unwrap_or_else(|| "-".to_string()),
+                            skipped_low_bit_score
+                        ));
+                    }
                     if let Some(provenance) = blast_provenance {
                         let command_line = if provenance.command_line.trim().is_empty() {
                             if provenance.command.is_empty() {
@@ -21057,7 +21826,7 @@ impl GentleEngine {
                     if !missing.is_empty() {
                         result
                             .warnings
-                            .push(format!("Unknown enzymes ignored: {}", missing.join(",")));
+                            .push(format!("Unknown enzymes ignored: {}", missing.join(",")).into());
                     }
 
                     let fragments =
@@ -21094,10 +21863,30 @@ impl GentleEngine {
                     if !missing.is_empty() {
                         result
                             .warnings
-                            .push(format!("Unknown enzymes ignored: {}", missing.join(",")));
+                            .push(format!("Unknown enzymes ignored: {}", missing.join(",")).into());
                     }
                     let prefix = output_prefix.unwrap_or_else(|| format!("{container_id}_digest"));
+                    let total_inputs = inputs.len();
+                    let mut processed_inputs = 0usize;
                     for input in inputs {
+                        if Self::container_op_cancelled(on_progress, processed_inputs, total_inputs)
+                        {
+                            result.warnings.push(
+                                Warning::new(
+                                    WarningCode::ResultTruncated,
+                                    format!(
+                                        "Digest container '{}' was cancelled after {} of {} member(s); results are truncated",
+                                        container_id, processed_inputs, total_inputs
+                                    ),
+                                )
+                                .with_context(json!({
+                                    "processed": processed_inputs,
+                                    "total": total_inputs,
+                                })),
+                            );
+                            break;
+                        }
+                        processed_inputs += 1;
                         let dna = self
                             .state
                             .sequences
@@ -21140,6 +21929,10 @@ impl GentleEngine {
                         }
                     }
 
+                    let _ = on_progress(OperationProgress::ContainerOp {
+                        processed: processed_inputs,
+                        total: total_inputs,
+                    });
                     result.messages.push(format!(
                     "Digest container '{}' created {} fragment(s); feature recomputation deferred",
                     container_id,
@@ -21148,9 +21941,41 @@ impl GentleEngine {
                 }
                 Operation::MergeContainersById { .. }
                 | Operation::LigationContainer { .. }
-                | Operation::FilterContainerByMolecularWeight { .. } => {
+                | Operation::FilterContainerByMolecularWeight { .. }
+                | Operation::FilterContainerByGc { .. } => {
                     unreachable!("container operation variants are normalized before execution")
                 }
+                Operation::ContainerSetOp {
+                    op,
+                    left_container,
+                    right_container,
+                    output_prefix,
+                } => {
+                    let op_id = result.op_id.clone();
+                    let (container_id, members) = self.container_set_op(
+                        op,
+                        &left_container,
+                        &right_container,
+                        output_prefix.as_deref(),
+                        &op_id,
+                    )?;
+                    match container_id {
+                        Some(container_id) => result.messages.push(format!(
+                            "Created container '{}' with {} member(s) from {} of '{}' and '{}'",
+                            container_id,
+                            members.len(),
+                            op.as_str(),
+                            left_container.trim(),
+                            right_container.trim()
+                        )),
+                        None => result.warnings.push(format!(
+                            "Container {} of '{}' and '{}' has no members; no container was created",
+                            op.as_str(),
+                            left_container.trim(),
+                            right_container.trim()
+                        ).into()),
+                    }
+                }
                 Operation::MergeContainers {
                     inputs,
                     output_prefix,
@@ -21233,12 +22058,25 @@ impl GentleEngine {
                             cause_chain: vec![],
                         });
                     }
-                    let mut accepted: Vec<(String, String, String)> = vec![];
-                    for (i, left_id) in inputs.iter().enumerate() {
+                    let mut accepted: Vec<(LigationJunction, usize, String)> = vec![];
+                    let mut dephosphorylated_pairs: Vec<String> = vec![];
+                    let total_pairs = inputs.len() * (inputs.len() - 1);
+                    let mut processed_pairs = 0usize;
+                    let mut cancelled = false;
+                    'pairs: for (i, left_id) in inputs.iter().enumerate() {
                         for (j, right_id) in inputs.iter().enumerate() {
                             if i == j {
                                 continue;
                             }
+                            if Self::container_op_cancelled(
+                                on_progress,
+                                processed_pairs,
+                                total_pairs,
+                            ) {
+                                cancelled = true;
+                                break 'pairs;
+                            }
+                            processed_pairs += 1;
                             let left =
                                 self.state
                                     .sequences
@@ -21260,22 +22098,28 @@ impl GentleEngine {
                                         cause_chain: vec![],
                                     })?;
 
-                            let ok = match protocol {
-                                LigationProtocol::Sticky => Self::sticky_compatible(left, right),
-                                LigationProtocol::Blunt => {
-                                    Self::right_end_is_blunt(left) && Self::left_end_is_blunt(right)
-                                }
-                            };
-                            if !ok {
+                            if !Self::ligation_ends_compatible(left, right, &protocol) {
+                                continue;
+                            }
+                            if !left.ends().right.has_junction_phosphate(&right.ends().left) {
+                                dephosphorylated_pairs.push(format!("'{left_id}' + '{right_id}'"));
                                 continue;
                             }
 
+                            let junction = Self::ligation_junction(
+                                left_id,
+                                &left.ends().right,
+                                right_id,
+                                &right.ends().left,
+                            );
+                            let left_forward = left.get_forward_string();
                             let product = format!(
-                                "{}{}",
-                                left.get_forward_string(),
+                                "{}{}{}",
+                                left_forward,
+                                junction.overhang,
                                 right.get_forward_string()
                             );
-                            accepted.push((left_id.clone(), right_id.clone(), product));
+                            accepted.push((junction, left_forward.len(), product));
                             if accepted.len() > self.max_fragments_per_container() {
                                 return Err(EngineError {
                                     code: ErrorCode::InvalidInput,
@@ -21290,12 +22134,47 @@ impl GentleEngine {
                         }
                     }
 
+                    let _ = on_progress(OperationProgress::ContainerOp {
+                        processed: processed_pairs,
+                        total: total_pairs,
+                    });
+                    if cancelled {
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::ResultTruncated,
+                                format!(
+                                    "Ligation was cancelled after {} of {} end pair(s); results are truncated",
+                                    processed_pairs, total_pairs
+                                ),
+                            )
+                            .with_context(json!({
+                                "processed": processed_pairs,
+                                "total": total_pairs,
+                            })),
+                        );
+                    }
+                    for pair in &dephosphorylated_pairs {
+                        result.warnings.push(
+                            format!(
+                                "Ligation of {pair} skipped: both 5' ends at the junction are dephosphorylated"
+                            )
+                            .into(),
+                        );
+                    }
                     if accepted.is_empty() {
                         return Err(EngineError {
                             code: ErrorCode::InvalidInput,
                             message: format!(
-                                "No ligation products found for protocol '{:?}'",
-                                protocol
+                                "No ligation products found for protocol '{:?}'{}",
+                                protocol,
+                                if dephosphorylated_pairs.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!(
+                                        " ({} compatible end pair(s) skipped because both 5' ends are dephosphorylated)",
+                                        dephosphorylated_pairs.len()
+                                    )
+                                }
                             ),
 
                             cause_chain: vec![],
@@ -21324,7 +22203,58 @@ impl GentleEngine {
                     }
 
                     let prefix = output_prefix.unwrap_or_else(|| "ligation".to_string());
-                    for (idx, (left_id, right_id, merged)) in accepted.into_iter().enumerate() {
+                    for (idx, (junction, left_len, mut merged)) in accepted.into_iter().enumerate()
+                    {
+                        let (left_id, right_id) =
+                            (junction.left_seq_id.clone(), junction.right_seq_id.clone());
+                        // Circularizing seals a second junction: the right end
+                        // of `right_id` onto the left end of `left_id`.
+                        let closing_left = self.state.sequences[&right_id].ends().right;
+                        let closing_right = self.state.sequences[&left_id].ends().left;
+                        let closing_matches = closing_left.ligates_to(&closing_right);
+                        let closes = circularize_if_possible
+                            && closing_matches
+                            && closing_left.has_junction_phosphate(&closing_right);
+                        if circularize_if_possible && !closes {
+                            let reason = if closing_matches {
+                                "both 5' ends at the closing junction are dephosphorylated"
+                            } else {
+                                "the closing ends are not compatible"
+                            };
+                            result.warnings.push(
+                                format!(
+                                    "Ligation product of '{left_id}' + '{right_id}' left linear: {reason}"
+                                )
+                                .into(),
+                            );
+                        }
+                        let closing = closes.then(|| {
+                            Self::ligation_junction(
+                                &right_id,
+                                &closing_left,
+                                &left_id,
+                                &closing_right,
+                            )
+                        });
+                        // The closing overhang sits between the end of
+                        // `right_id` and the origin.
+                        let closing_start = merged.len();
+                        if let Some(closing) = &closing {
+                            merged.push_str(&closing.overhang);
+                        }
+                        let product_len = merged.len();
+                        let mut markers = vec![Self::ligation_junction_marker(
+                            junction,
+                            left_len,
+                            product_len,
+                        )];
+                        if let Some(closing) = closing {
+                            markers.push(Self::ligation_junction_marker(
+                                closing,
+                                closing_start,
+                                product_len,
+                            ));
+                        }
                         let mut product =
                             DNAsequence::from_sequence(&merged).map_err(|e| EngineError {
                                 code: ErrorCode::Internal,
@@ -21332,7 +22262,12 @@ impl GentleEngine {
 
                                 cause_chain: vec![],
                             })?;
-                        product.set_circular(circularize_if_possible);
+                        product.set_circular(closes);
+                        for marker in &markers {
+                            product
+                                .features_mut()
+                                .push(Self::ligation_junction_feature(marker, product_len));
+                        }
                         Self::prepare_sequence(&mut product);
 
                         let seq_id = if idx == 0 {
@@ -21355,7 +22290,160 @@ impl GentleEngine {
                             "Ligation product '{}' from '{}' + '{}'",
                             seq_id, left_id, right_id
                         ));
+                        let assembly = LigationAssembly {
+                            schema: LIGATION_ASSEMBLY_SCHEMA.to_string(),
+                            product_seq_id: seq_id,
+                            fragment_seq_ids: vec![left_id, right_id],
+                            circular: closes,
+                            junctions: markers,
+                        };
+                        let assembly_json =
+                            serde_json::to_string(&assembly).map_err(|e| EngineError {
+                                code: ErrorCode::Internal,
+                                message: format!("Could not serialize ligation assembly: {e}"),
+                                cause_chain: vec![],
+                            })?;
+                        result.messages.push(assembly_json);
+                    }
+                }
+                Operation::PolishEnds {
+                    input,
+                    mode,
+                    output_id,
+                } => {
+                    parent_seq_ids.push(input.clone());
+                    let dna = self
+                        .state
+                        .sequences
+                        .get(&input)
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::NotFound,
+                            message: format!("Sequence '{input}' not found"),
+                            cause_chain: vec![],
+                        })?;
+                    if dna.is_circular() {
+                        return Err(EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!(
+                                "PolishEnds requires a linear sequence with defined ends; '{input}' is circular"
+                            ),
+                            cause_chain: vec![],
+                        });
+                    }
+                    let (target, mut polished) = match mode {
+                        PolishMode::FillIn5Overhang => (
+                            OverhangPolarity::FivePrime,
+                            dna.fill_in_five_prime_overhangs(),
+                        ),
+                        PolishMode::ChewBack3Overhang => (
+                            OverhangPolarity::ThreePrime,
+                            dna.chew_back_three_prime_overhangs(),
+                        ),
+                    };
+                    let ends = dna.ends();
+                    let polished_ends = [ends.left, ends.right]
+                        .iter()
+                        .filter(|end| end.polarity == target)
+                        .count();
+                    if polished_ends == 0 {
+                        result.warnings.push(format!(
+                            "Sequence '{}' has no {} overhang to polish; output is an unchanged copy",
+                            input,
+                            target.as_str()
+                        ).into());
+                    }
+                    Self::prepare_sequence(&mut polished);
+                    let seq_id = self
+                        .unique_seq_id(&output_id.unwrap_or_else(|| format!("{input}_polished")));
+                    let polished_len = polished.len();
+                    self.state.sequences.insert(seq_id.clone(), polished);
+                    self.add_lineage_node(&seq_id, SequenceOrigin::Derived, Some(&result.op_id));
+                    result.created_seq_ids.push(seq_id.clone());
+                    result.messages.push(format!(
+                        "Polished {} end(s) of '{}' ({}) into '{}' ({} bp)",
+                        polished_ends,
+                        input,
+                        mode.as_str(),
+                        seq_id,
+                        polished_len
+                    ));
+                }
+                Operation::Dephosphorylate { input, output_id } => {
+                    parent_seq_ids.push(input.clone());
+                    let dna = self
+                        .state
+                        .sequences
+                        .get(&input)
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::NotFound,
+                            message: format!("Sequence '{input}' not found"),
+                            cause_chain: vec![],
+                        })?;
+                    if dna.is_circular() {
+                        return Err(EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!(
+                                "Dephosphorylate requires a linear sequence with defined ends; '{input}' is circular"
+                            ),
+                            cause_chain: vec![],
+                        });
+                    }
+                    let ends = dna.ends();
+                    if ends.left.dephosphorylated && ends.right.dephosphorylated {
+                        result.warnings.push(
+                            format!(
+                                "Sequence '{input}' is already dephosphorylated; output is an unchanged copy"
+                            )
+                            .into(),
+                        );
                     }
+                    let mut dephosphorylated = dna.dephosphorylate_five_prime_ends();
+                    Self::prepare_sequence(&mut dephosphorylated);
+                    let seq_id = self.unique_seq_id(
+                        &output_id.unwrap_or_else(|| format!("{input}_dephosphorylated")),
+                    );
+                    self.state
+                        .sequences
+                        .insert(seq_id.clone(), dephosphorylated);
+                    self.add_lineage_node(&seq_id, SequenceOrigin::Derived, Some(&result.op_id));
+                    result.created_seq_ids.push(seq_id.clone());
+                    result.messages.push(format!(
+                        "Dephosphorylated the 5' ends of '{input}' into '{seq_id}'"
+                    ));
+                }
+                Operation::EngineerSilentSite {
+                    seq_id,
+                    feature_id,
+                    enzyme,
+                    mode,
+                    output_id,
+                } => {
+                    parent_seq_ids.push(seq_id.clone());
+                    self.engineer_silent_site(
+                        &mut result,
+                        &seq_id,
+                        feature_id,
+                        &enzyme,
+                        mode,
+                        output_id,
+                    )?;
+                }
+                Operation::ReplaceSubsequence {
+                    seq_id,
+                    find,
+                    replace,
+                    max_replacements,
+                    output_id,
+                } => {
+                    parent_seq_ids.push(seq_id.clone());
+                    self.replace_subsequence(
+                        &mut result,
+                        &seq_id,
+                        &find,
+                        &replace,
+                        max_replacements,
+                        output_id,
+                    )?;
                 }
                 Operation::Pcr {
                     template,
@@ -21387,120 +22475,300 @@ impl GentleEngine {
                     }
 
                     let template_seq = dna.get_forward_string().to_ascii_uppercase();
-                    let template_bytes = template_seq.as_bytes();
-                    let fwd = Self::normalize_dna_text(&forward_primer);
-                    let rev = Self::normalize_dna_text(&reverse_primer);
-                    if fwd.is_empty() || rev.is_empty() {
+                    let amplicon_ranges =
+                        Self::exact_pcr_amplicons(&template_seq, &forward_primer, &reverse_primer)?;
+                    if amplicon_ranges.len() > self.max_fragments_per_container() {
                         return Err(EngineError {
                             code: ErrorCode::InvalidInput,
-                            message: "PCR primers must not be empty".to_string(),
+                            message: format!(
+                                "PCR produced {} amplicons, exceeding max_fragments_per_container={}",
+                                amplicon_ranges.len(),
+                                self.max_fragments_per_container()
+                            ),
 
                             cause_chain: vec![],
                         });
                     }
 
-                    let rev_binding = Self::reverse_complement(&rev);
-                    let fwd_sites = Self::find_all_subsequences(template_bytes, fwd.as_bytes());
-                    if fwd_sites.is_empty() {
+                    let require_unique = unique.unwrap_or(false);
+                    if require_unique && amplicon_ranges.len() != 1 {
                         return Err(EngineError {
                             code: ErrorCode::InvalidInput,
-                            message: "Forward primer not found on template".to_string(),
+                            message: format!(
+                                "PCR unique=true requires exactly one amplicon, found {}",
+                                amplicon_ranges.len()
+                            ),
 
                             cause_chain: vec![],
                         });
                     }
-                    let rev_sites =
-                        Self::find_all_subsequences(template_bytes, rev_binding.as_bytes());
-                    if rev_sites.is_empty() {
+                    if output_id.is_some() && amplicon_ranges.len() != 1 {
                         return Err(EngineError {
-                            code: ErrorCode::InvalidInput,
-                            message: "Reverse primer binding site not found on template"
+                        code: ErrorCode::InvalidInput,
+                        message:
+                            "PCR output_id can only be used when exactly one amplicon is produced"
                                 .to_string(),
-
-                            cause_chain: vec![],
-                        });
-                    }
-
-                    let mut amplicon_ranges: Vec<(usize, usize)> = vec![];
-                    for fwd_pos in &fwd_sites {
-                        for rev_pos in &rev_sites {
-                            if *rev_pos < *fwd_pos {
-                                continue;
-                            }
-                            let amplicon_end = rev_pos + rev_binding.len();
-                            if amplicon_end <= template_seq.len() {
-                                amplicon_ranges.push((*fwd_pos, amplicon_end));
-                            }
-                        }
+                    
+                        cause_chain: vec![],});
                     }
-                    amplicon_ranges.sort_unstable();
-                    amplicon_ranges.dedup();
 
-                    if amplicon_ranges.is_empty() {
-                        return Err(EngineError {
-                            code: ErrorCode::InvalidInput,
-                            message: "No valid forward/reverse primer pair produced an amplicon"
-                                .to_string(),
+                    let default_base = format!("{template}_pcr");
+                    for (i, &(start, end, reversed)) in amplicon_ranges.iter().enumerate() {
+                        let amplicon =
+                            Self::pcr_amplicon_text(&template_seq, (start, end, reversed));
+                        let mut pcr_product =
+                            DNAsequence::from_sequence(&amplicon).map_err(|e| EngineError {
+                                code: ErrorCode::Internal,
+                                message: format!("Could not create PCR product: {e}"),
+
+                                cause_chain: vec![],
+                            })?;
+                        pcr_product.set_circular(false);
+                        Self::prepare_sequence(&mut pcr_product);
 
+                        let requested = if i == 0 { output_id.clone() } else { None };
+                        let seq_id = if let Some(id) = requested {
+                            self.unique_seq_id(&id)
+                        } else if amplicon_ranges.len() == 1 {
+                            self.unique_seq_id(&default_base)
+                        } else {
+                            self.unique_seq_id(&format!("{}_{}", default_base, i + 1))
+                        };
+                        self.state.sequences.insert(seq_id.clone(), pcr_product);
+                        self.add_lineage_node(
+                            &seq_id,
+                            SequenceOrigin::Derived,
+                            Some(&result.op_id),
+                        );
+                        result.created_seq_ids.push(seq_id.clone());
+                        result.messages.push(format!(
+                            "PCR product '{}' created: {}..{} (len {}){}",
+                            seq_id,
+                            start,
+                            end,
+                            end - start,
+                            if reversed {
+                                ", reverse-complemented to start at the forward primer"
+                            } else {
+                                ""
+                            }
+                        ));
+                    }
+                }
+                Operation::NestedPcr {
+                    template,
+                    outer_forward,
+                    outer_reverse,
+                    inner_forward,
+                    inner_reverse,
+                    output_id,
+                } => {
+                    parent_seq_ids.push(template.clone());
+                    let dna = self
+                        .state
+                        .sequences
+                        .get(&template)
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::NotFound,
+                            message: format!("Sequence '{template}' not found"),
+                            cause_chain: vec![],
+                        })?;
+                    if dna.is_circular() {
+                        return Err(EngineError {
+                            code: ErrorCode::Unsupported,
+                            message: "PCR on circular templates is not implemented yet".to_string(),
                             cause_chain: vec![],
                         });
                     }
-                    if amplicon_ranges.len() > self.max_fragments_per_container() {
+
+                    // Each stage must yield exactly one amplicon; the inner pair
+                    // then runs on the (forward-oriented) outer product.
+                    let single_stage_amplicon =
+                        |stage: &str,
+                         template_seq: &str,
+                         forward: &str,
+                         reverse: &str|
+                         -> Result<String, EngineError> {
+                            let amplicons =
+                                Self::exact_pcr_amplicons(template_seq, forward, reverse).map_err(
+                                    |e| EngineError {
+                                        code: e.code,
+                                        message: format!("Nested PCR {stage} stage: {}", e.message),
+                                        cause_chain: e.cause_chain,
+                                    },
+                                )?;
+                            if amplicons.len() != 1 {
+                                return Err(EngineError {
+                                    code: ErrorCode::InvalidInput,
+                                    message: format!(
+                                        "Nested PCR {stage} stage requires exactly one amplicon, found {}",
+                                        amplicons.len()
+                                    ),
+                                    cause_chain: vec![],
+                                });
+                            }
+                            Ok(Self::pcr_amplicon_text(template_seq, amplicons[0]))
+                        };
+                    let template_seq = dna.get_forward_string().to_ascii_uppercase();
+                    let outer_product = single_stage_amplicon(
+                        "outer",
+                        &template_seq,
+                        &outer_forward,
+                        &outer_reverse,
+                    )?;
+                    let inner_product = single_stage_amplicon(
+                        "inner",
+                        &outer_product,
+                        &inner_forward,
+                        &inner_reverse,
+                    )?;
+
+                    let mut pcr_product =
+                        DNAsequence::from_sequence(&inner_product).map_err(|e| EngineError {
+                            code: ErrorCode::Internal,
+                            message: format!("Could not create PCR product: {e}"),
+                            cause_chain: vec![],
+                        })?;
+                    pcr_product.set_circular(false);
+                    Self::prepare_sequence(&mut pcr_product);
+                    let seq_id = self.unique_seq_id(
+                        &output_id.unwrap_or_else(|| format!("{template}_nested_pcr")),
+                    );
+                    self.state.sequences.insert(seq_id.clone(), pcr_product);
+                    self.add_lineage_node(&seq_id, SequenceOrigin::Derived, Some(&result.op_id));
+                    result.created_seq_ids.push(seq_id.clone());
+                    result.messages.push(format!(
+                        "Nested PCR outer amplicon: {} bp",
+                        outer_product.len()
+                    ));
+                    result.messages.push(format!(
+                        "Nested PCR product '{}' created (len {})",
+                        seq_id,
+                        inner_product.len()
+                    ));
+                }
+                Operation::MultiplexPcr {
+                    template,
+                    primers,
+                    max_amplicon_bp,
+                    output_prefix,
+                } => {
+                    parent_seq_ids.push(template.clone());
+                    let dna = self
+                        .state
+                        .sequences
+                        .get(&template)
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::NotFound,
+                            message: format!("Sequence '{template}' not found"),
+                            cause_chain: vec![],
+                        })?;
+                    if dna.is_circular() {
+                        return Err(EngineError {
+                            code: ErrorCode::Unsupported,
+                            message: "PCR on circular templates is not implemented yet".to_string(),
+                            cause_chain: vec![],
+                        });
+                    }
+                    if primers.is_empty() || max_amplicon_bp == 0 {
                         return Err(EngineError {
                             code: ErrorCode::InvalidInput,
-                            message: format!(
-                                "PCR produced {} amplicons, exceeding max_fragments_per_container={}",
-                                amplicon_ranges.len(),
-                                self.max_fragments_per_container()
-                            ),
-
+                            message:
+                                "MultiplexPcr requires at least one primer and max_amplicon_bp > 0"
+                                    .to_string(),
                             cause_chain: vec![],
                         });
                     }
+                    let template_seq = dna.get_forward_string().to_ascii_uppercase();
+                    let template_bytes = template_seq.as_bytes();
 
-                    let require_unique = unique.unwrap_or(false);
-                    if require_unique && amplicon_ranges.len() != 1 {
+                    // (start, primer index) forward sites and (start, end, primer
+                    // index) reverse sites, all on top-strand coordinates.
+                    let mut forward_sites: Vec<(usize, usize)> = vec![];
+                    let mut reverse_sites: Vec<(usize, usize, usize)> = vec![];
+                    for (idx, primer) in primers.iter().enumerate() {
+                        let primer = Self::normalize_dna_text(primer);
+                        if primer.is_empty() {
+                            return Err(EngineError {
+                                code: ErrorCode::InvalidInput,
+                                message: format!("MultiplexPcr primer {} is empty", idx + 1),
+                                cause_chain: vec![],
+                            });
+                        }
+                        let binding = Self::reverse_complement(&primer);
+                        for pos in Self::find_all_subsequences(template_bytes, primer.as_bytes()) {
+                            forward_sites.push((pos, idx));
+                        }
+                        for pos in Self::find_all_subsequences(template_bytes, binding.as_bytes()) {
+                            reverse_sites.push((pos, pos + binding.len(), idx));
+                        }
+                    }
+
+                    // (start, end, forward primer, reverse primer, on_target)
+                    let mut amplicons: Vec<(usize, usize, usize, usize, bool)> = vec![];
+                    for &(start, fwd_idx) in &forward_sites {
+                        for &(rev_start, end, rev_idx) in &reverse_sites {
+                            if rev_start < start || end - start > max_amplicon_bp {
+                                continue;
+                            }
+                            let adjacent = !forward_sites
+                                .iter()
+                                .any(|&(other, _)| other > start && other <= rev_start)
+                                && !reverse_sites.iter().any(|&(_, other_end, _)| {
+                                    other_end >= start && other_end < end
+                                });
+                            amplicons.push((
+                                start,
+                                end,
+                                fwd_idx,
+                                rev_idx,
+                                adjacent && fwd_idx != rev_idx,
+                            ));
+                        }
+                    }
+                    amplicons.sort_unstable();
+                    amplicons.dedup_by_key(|amplicon| (amplicon.0, amplicon.1));
+                    if amplicons.is_empty() {
                         return Err(EngineError {
                             code: ErrorCode::InvalidInput,
                             message: format!(
-                                "PCR unique=true requires exactly one amplicon, found {}",
-                                amplicon_ranges.len()
+                                "MultiplexPcr found no amplicon within max_amplicon_bp={max_amplicon_bp}"
                             ),
-
                             cause_chain: vec![],
                         });
                     }
-                    if output_id.is_some() && amplicon_ranges.len() != 1 {
-                        return Err(EngineError {
-                        code: ErrorCode::InvalidInput,
-                        message:
-                            "PCR output_id can only be used when exactly one amplicon is produced"
-                                .to_string(),
-                    
-                        cause_chain: vec![],});
+                    let limit = self.max_fragments_per_container();
+                    if amplicons.len() > limit {
+                        result.warnings.push(
+                            Warning::new(
+                                WarningCode::ResultTruncated,
+                                format!(
+                                    "MultiplexPcr found {} amplicons; kept the first {} (max_fragments_per_container)",
+                                    amplicons.len(),
+                                    limit
+                                ),
+                            )
+                            .with_context(json!({
+                                "found": amplicons.len(),
+                                "kept": limit,
+                            })),
+                        );
+                        amplicons.truncate(limit);
                     }
 
-                    let default_base = format!("{template}_pcr");
-                    for (i, (start, end)) in amplicon_ranges.iter().enumerate() {
-                        let amplicon = &template_seq[*start..*end];
-                        let mut pcr_product =
-                            DNAsequence::from_sequence(amplicon).map_err(|e| EngineError {
+                    let prefix = output_prefix.unwrap_or_else(|| format!("{template}_mpcr"));
+                    for (i, (start, end, fwd_idx, rev_idx, on_target)) in
+                        amplicons.into_iter().enumerate()
+                    {
+                        let mut pcr_product = DNAsequence::from_sequence(&template_seq[start..end])
+                            .map_err(|e| EngineError {
                                 code: ErrorCode::Internal,
                                 message: format!("Could not create PCR product: {e}"),
-
                                 cause_chain: vec![],
                             })?;
                         pcr_product.set_circular(false);
                         Self::prepare_sequence(&mut pcr_product);
-
-                        let requested = if i == 0 { output_id.clone() } else { None };
-                        let seq_id = if let Some(id) = requested {
-                            self.unique_seq_id(&id)
-                        } else if amplicon_ranges.len() == 1 {
-                            self.unique_seq_id(&default_base)
-                        } else {
-                            self.unique_seq_id(&format!("{}_{}", default_base, i + 1))
-                        };
+                        let seq_id = self.unique_seq_id(&format!("{}_{}", prefix, i + 1));
                         self.state.sequences.insert(seq_id.clone(), pcr_product);
                         self.add_lineage_node(
                             &seq_id,
@@ -21509,12 +22777,36 @@ impl GentleEngine {
                         );
                         result.created_seq_ids.push(seq_id.clone());
                         result.messages.push(format!(
-                            "PCR product '{}' created: {}..{} (len {})",
+                            "Multiplex PCR product '{}' created: {}..{} (len {}), primers {}+{}",
                             seq_id,
                             start,
                             end,
-                            end - start
+                            end - start,
+                            fwd_idx + 1,
+                            rev_idx + 1
                         ));
+                        if !on_target {
+                            result.warnings.push(
+                                Warning::new(
+                                    WarningCode::OffTargetProduct,
+                                    format!(
+                                        "Off-target multiplex amplicon '{}' ({}..{}, primers {}+{})",
+                                        seq_id,
+                                        start,
+                                        end,
+                                        fwd_idx + 1,
+                                        rev_idx + 1
+                                    ),
+                                )
+                                .with_context(json!({
+                                    "seq_id": seq_id,
+                                    "start_0based": start,
+                                    "end_0based_exclusive": end,
+                                    "forward_primer_index": fwd_idx,
+                                    "reverse_primer_index": rev_idx,
+                                })),
+                            );
+                        }
                     }
                 }
                 Operation::PcrAdvanced {
@@ -21552,10 +22844,12 @@ impl GentleEngine {
                     let fwd_variants = Self::expand_primer_variants(
                         &forward_primer,
                         self.max_fragments_per_container(),
+                        self.state.parameters.random_seed,
                     )?;
                     let rev_variants = Self::expand_primer_variants(
                         &reverse_primer,
                         self.max_fragments_per_container(),
+                        self.state.parameters.random_seed,
                     )?;
                     if fwd_variants.is_empty() || rev_variants.is_empty() {
                         return Err(EngineError {
@@ -21796,10 +23090,12 @@ impl GentleEngine {
                     let fwd_variants = Self::expand_primer_variants(
                         &forward_primer,
                         self.max_fragments_per_container(),
+                        self.state.parameters.random_seed,
                     )?;
                     let rev_variants = Self::expand_primer_variants(
                         &reverse_primer,
                         self.max_fragments_per_container(),
+                        self.state.parameters.random_seed,
                     )?;
                     if fwd_variants.is_empty() || rev_variants.is_empty() {
                         return Err(EngineError {
@@ -21970,6 +23266,15 @@ impl GentleEngine {
                         cause_chain: vec![],});
                     }
 
+                    let mut edited = dna.clone();
+                    edited.substitute_bases(
+                        &normalized_mutations
+                            .iter()
+                            .map(|(pos, _ref_b, alt_b)| (*pos, *alt_b))
+                            .collect::<Vec<_>>(),
+                    );
+                    Self::push_cds_integrity_warnings(&mut result, &template, &dna, &edited);
+
                     let default_base = format!("{template}_pcr_mut");
                     for (i, ((fwd_pos, rev_pos), amplicon)) in selected.into_iter().enumerate() {
                         let mut pcr_product =
@@ -22508,6 +23813,7 @@ impl GentleEngine {
                             &source_features,
                             transcript_feature_id,
                             &seq_id,
+                            None,
                         )?;
                         let transcript_token = Self::normalize_id_token(&transcript_id);
                         let transcript_token = if transcript_token.is_empty() {
@@ -22579,19 +23885,22 @@ impl GentleEngine {
                                 result.warnings.push(format!(
                                 "Transcript '{}' resolved CDS context but did not yield a non-empty protein sequence.",
                                 derived_seq_id
-                            ));
+                            ).into());
                             }
                             for warning in derivation.warnings {
-                                result.warnings.push(format!(
-                                    "Transcript '{}': {}",
-                                    derivation.transcript_id, warning
-                                ));
+                                result.warnings.push(
+                                    format!(
+                                        "Transcript '{}': {}",
+                                        derivation.transcript_id, warning
+                                    )
+                                    .into(),
+                                );
                             }
                         } else {
                             result.warnings.push(format!(
                             "Transcript '{}' has no CDS annotation; protein translation was not derived.",
                             derived_seq_id
-                        ));
+                        ).into());
                         }
                     }
                     if result.created_seq_ids.is_empty() {
@@ -22620,7 +23929,9 @@ impl GentleEngine {
                     )?;
                     self.store_exon_skip_plan(&plan)?;
                     result.messages.extend(plan.messages.clone());
-                    result.warnings.extend(plan.warnings.clone());
+                    result
+                        .warnings
+                        .extend(plan.warnings.iter().cloned().map(Warning::from));
                     result.exon_skip_selection_plan = Some(plan);
                 }
                 Operation::MaterializeExonSkippedIsoform {
@@ -22645,7 +23956,19 @@ impl GentleEngine {
                     scope,
                     output_prefix,
                     report_id,
+                    translation_table,
                 } => {
+                    if let Some(table) = translation_table
+                        && u8::try_from(table).ok().and_then(codon_table).is_none()
+                    {
+                        return Err(EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!(
+                                "DeriveProteinSequences translation_table {table} is not a known NCBI genetic code"
+                            ),
+                            cause_chain: vec![],
+                        });
+                    }
                     let source_sequence_upper = self
                         .state
                         .sequences
@@ -22684,7 +24007,7 @@ impl GentleEngine {
                             result.warnings.push(format!(
                                 "DeriveProteinSequences feature_query seq_id '{}' was overridden by op seq_id '{}'",
                                 query.seq_id, seq_id
-                            ));
+                            ).into());
                             query.seq_id = seq_id.clone();
                         }
                         let query_result = self.query_sequence_features(query.clone())?;
@@ -22762,6 +24085,7 @@ impl GentleEngine {
                             &source_features,
                             transcript_feature_id,
                             &seq_id,
+                            translation_table,
                         )?;
                         let representative_cds_feature =
                             Self::collect_matching_cds_features_for_derivation(
@@ -22789,6 +24113,7 @@ impl GentleEngine {
                                 &source_features,
                                 &transcript_id,
                                 &transcript_label,
+                                translation_table,
                             )?,
                         };
                         let Some(derivation) = derivation else {
@@ -22796,16 +24121,19 @@ impl GentleEngine {
                             "Transcript feature n-{} in '{}' could not yield a protein sequence.",
                             transcript_feature_id + 1,
                             seq_id
-                        ));
+                        ).into());
                             continue;
                         };
                         if derivation.protein_length_aa == 0
                             || derivation.protein_sequence.is_empty()
                         {
-                            result.warnings.push(format!(
-                                "Transcript '{}' did not yield a non-empty protein sequence.",
-                                derivation.transcript_id
-                            ));
+                            result.warnings.push(
+                                format!(
+                                    "Transcript '{}' did not yield a non-empty protein sequence.",
+                                    derivation.transcript_id
+                                )
+                                .into(),
+                            );
                             continue;
                         }
                         let transcript_token = Self::normalize_id_token(&transcript_id);
@@ -22872,10 +24200,13 @@ impl GentleEngine {
                             .unwrap_or_default()
                     ));
                         for warning in &derivation.warnings {
-                            result.warnings.push(format!(
-                                "Protein '{}' (transcript '{}'): {}",
-                                protein_seq_id, derivation.transcript_id, warning
-                            ));
+                            result.warnings.push(
+                                format!(
+                                    "Protein '{}' (transcript '{}'): {}",
+                                    protein_seq_id, derivation.transcript_id, warning
+                                )
+                                .into(),
+                            );
                         }
                     }
                     if result.created_seq_ids.is_empty() {
@@ -22903,7 +24234,11 @@ impl GentleEngine {
                         effective_output_prefix: normalized_prefix.clone(),
                         derived_count: report_rows.len(),
                         rows: report_rows,
-                        warnings: result.warnings.clone(),
+                        warnings: result
+                            .warnings
+                            .iter()
+                            .map(|warning| warning.message.clone())
+                            .collect(),
                     };
                     self.upsert_protein_derivation_report(report.clone())?;
                     result.messages.push(format!(
@@ -23112,7 +24447,9 @@ impl GentleEngine {
                         "Stored reverse-translation report '{}' for '{}' -> '{}'.",
                         report.report_id, report.protein_seq_id, report.coding_seq_id
                     ));
-                    result.warnings.extend(warnings);
+                    result
+                        .warnings
+                        .extend(warnings.into_iter().map(Warning::from));
                     result.reverse_translation_report = Some(report);
                 }
                 Operation::ProteaseDigestProteinSequence {
@@ -23132,7 +24469,9 @@ impl GentleEngine {
                             materialize,
                             &result.op_id,
                         )?;
-                    result.warnings.extend(warnings);
+                    result
+                        .warnings
+                        .extend(warnings.into_iter().map(Warning::from));
                     result.created_seq_ids.extend(created_seq_ids);
                     result.messages.push(format!(
                         "Protease digest of '{}' with {} protease(s) found {} cleavage site(s) and {} peptide(s){}.",
@@ -23387,7 +24726,7 @@ impl GentleEngine {
                         result.warnings.push(format!(
                         "Dotplot '{}' reached MAX_DOTPLOT_POINTS ({MAX_DOTPLOT_POINTS}); result was truncated",
                         dotplot_id
-                    ));
+                    ).into());
                     }
                 }
                 Operation::ComputeDotplotOverlay {
@@ -23645,7 +24984,7 @@ impl GentleEngine {
                         dotplot_id,
                         truncated_series_labels.len(),
                         truncated_series_labels.join(", ")
-                    ));
+                    ).into());
                     }
                 }
                 Operation::ComputeFlexibilityTrack {
@@ -23946,7 +25285,7 @@ impl GentleEngine {
                         seq_id,
                         resolved_seed_feature_id,
                         scope.as_str()
-                    ));
+                    ).into());
                     } else {
                         result.messages.push(format!(
                             "Derived {} mRNA sequence(s): {}",
@@ -24001,7 +25340,7 @@ impl GentleEngine {
                         seq_id,
                         resolved_seed_feature_id,
                         scope.as_str()
-                    ));
+                    ).into());
                     } else {
                         let exon_reference_sequence = String::from_utf8(exon_reference_bytes)
                             .map_err(|e| EngineError {
@@ -24127,6 +25466,17 @@ impl GentleEngine {
                     report.cigar
                 ));
                 }
+                Operation::MakeConsensus {
+                    seq_ids,
+                    threshold,
+                    output_id,
+                } => {
+                    parent_seq_ids.extend(seq_ids.iter().cloned());
+                    self.make_consensus(&mut result, &seq_ids, threshold, output_id)?;
+                }
+                Operation::AnnotateDifferences { reference, variant } => {
+                    self.annotate_differences(&mut result, &reference, &variant)?;
+                }
                 Operation::ConfirmConstructReads {
                     expected_seq_id,
                     baseline_seq_id,
@@ -24246,13 +25596,16 @@ impl GentleEngine {
                     ));
                     if !report.warnings.is_empty() {
                         for warning in report.warnings.iter().take(8) {
-                            result.warnings.push(warning.clone());
+                            result.warnings.push(warning.clone().into());
                         }
                         if report.warnings.len() > 8 {
-                            result.warnings.push(format!(
-                                "... {} additional sequencing-trace warning(s) omitted",
-                                report.warnings.len() - 8
-                            ));
+                            result.warnings.push(
+                                format!(
+                                    "... {} additional sequencing-trace warning(s) omitted",
+                                    report.warnings.len() - 8
+                                )
+                                .into(),
+                            );
                         }
                     }
                 }
@@ -24404,7 +25757,9 @@ impl GentleEngine {
                         report.failed_count,
                         report.missing_count
                     ));
-                    result.warnings.extend(report.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.read_acquisition_report = Some(report);
                 }
                 Operation::ReadAcquireInspect {
@@ -24431,7 +25786,9 @@ impl GentleEngine {
                         "Read acquisition cancel requested for '{}'",
                         sra_accession
                     ));
-                    result.warnings.extend(report.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.read_acquisition_report = Some(report);
                 }
                 Operation::InterpretRnaReads {
@@ -24534,7 +25891,9 @@ impl GentleEngine {
                         &control_transcript_fasta_paths,
                         max_control_match_probability,
                     )?;
-                    result.warnings.extend(report.warnings.clone());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.messages.push(format!(
                         "RNA-read isoform preflight for '{}' evaluated {} target transcript(s), {} positive control transcript(s), and {} control group(s); target_pass={}/{} positive_pass={}/{}",
                         report.seq_id,
@@ -24735,7 +26094,9 @@ impl GentleEngine {
                         report.failed_count,
                         report.out_dir
                     ));
-                    result.warnings.extend(report.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.rna_read_batch_map_report = Some(report);
                 }
                 op @ Operation::FindRestrictionSites { .. }
@@ -25145,7 +26506,7 @@ impl GentleEngine {
                         ));
                     }
                     for warning in &report.warnings {
-                        result.warnings.push(warning.clone());
+                        result.warnings.push(warning.clone().into());
                     }
                     result.messages.push(format!(
                         "Alternative-promoter comparison for '{}' collapsed {} transcript-level promoter window(s) into {} DNA-level promoter group(s)",
@@ -25186,7 +26547,7 @@ impl GentleEngine {
                         ));
                     }
                     for warning in &report.warnings {
-                        result.warnings.push(warning.clone());
+                        result.warnings.push(warning.clone().into());
                     }
                     result.messages.push(format!(
                         "Promoter evidence matrix for '{}' summarized {} promoter candidate(s) with {} evidence item(s)",
@@ -25227,7 +26588,7 @@ impl GentleEngine {
                         ));
                     }
                     for warning in &report.warnings {
-                        result.warnings.push(warning.clone());
+                        result.warnings.push(warning.clone().into());
                     }
                     result.messages.push(format!(
                         "Isoform promoter comparison for '{}' summarized {} promoter group(s) with {} differential evidence signature(s)",
@@ -25270,7 +26631,7 @@ impl GentleEngine {
                         ));
                     }
                     for warning in &report.warnings {
-                        result.warnings.push(warning.clone());
+                        result.warnings.push(warning.clone().into());
                     }
                     result.messages.push(format!(
                         "Promoter expression evidence for '{}' assigned {} of {} supplied expression row(s)",
@@ -25305,7 +26666,7 @@ impl GentleEngine {
                         report.seq_id, path, report.present_artifact_count, report.artifact_count
                     ));
                     for warning in &report.warnings {
-                        result.warnings.push(warning.clone());
+                        result.warnings.push(warning.clone().into());
                     }
                     result.promoter_artifact_manifest = Some(report);
                 }
@@ -25371,7 +26732,9 @@ impl GentleEngine {
                             .map(|value| format!(" for filter '{}'", value))
                             .unwrap_or_default()
                     ));
-                    result.warnings.extend(report.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.reporter_catalog = Some(report);
                 }
                 Operation::RecommendReporters {
@@ -25397,7 +26760,9 @@ impl GentleEngine {
                         "Reporter recommender ranked {} candidate(s) and rejected {} by hard constraints",
                         report.recommended_candidate_count, report.rejected_candidate_count
                     ));
-                    result.warnings.extend(report.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(report.warnings.iter().cloned().map(Warning::from));
                     result.reporter_recommendation = Some(report);
                 }
                 Operation::ExportReporterCorpus {
@@ -25413,7 +26778,9 @@ impl GentleEngine {
                         format.as_str(),
                         path
                     ));
-                    result.warnings.extend(export.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(export.warnings.iter().cloned().map(Warning::from));
                     result.reporter_corpus_export = Some(export);
                 }
                 Operation::PlanReporterConstructHandoff {
@@ -25454,7 +26821,9 @@ impl GentleEngine {
                         "Planned reporter construct handoff '{}' for candidate '{}'",
                         plan.status, plan.selected_fragment.candidate_id
                     ));
-                    result.warnings.extend(plan.warnings.iter().cloned());
+                    result
+                        .warnings
+                        .extend(plan.warnings.iter().cloned().map(Warning::from));
                     result.reporter_construct_handoff = Some(plan);
                 }
                 Operation::MaterializeVariantAllele {
@@ -25558,7 +26927,7 @@ impl GentleEngine {
                         result.warnings.push(format!(
                             "Requested target-quality path '{}' already existed without a reusable GENtle comparison bundle; wrote comparison-ready output to '{}' instead",
                             export.requested_path, export.written_path
-                        ));
+                        ).into());
                     }
                     if let Some(bundle_path) = export.bundle_path.as_deref() {
                         result.messages.push(format!(
@@ -25659,7 +27028,7 @@ impl GentleEngine {
                         result.warnings.push(format!(
                         "Report '{}' did not persist score_density_bins; derived bins from retained hits only",
                         export.report_id
-                    ));
+                    ).into());
                     }
                 }
                 Operation::ExportRnaReadAlignmentsTsv {
@@ -25848,7 +27217,7 @@ impl GentleEngine {
                         explicit_record_indices.len(),
                         created.len(),
                         report.report_id
-                    ));
+                    ).into());
                     }
                     let preview = created
                         .iter()
@@ -26010,10 +27379,13 @@ impl GentleEngine {
                         self.resolve_enzymes(&required_re_sites)?
                     };
                     if !missing_enzymes.is_empty() {
-                        result.warnings.push(format!(
-                            "Unknown anchored-region enzymes ignored: {}",
-                            missing_enzymes.join(",")
-                        ));
+                        result.warnings.push(
+                            format!(
+                                "Unknown anchored-region enzymes ignored: {}",
+                                missing_enzymes.join(",")
+                            )
+                            .into(),
+                        );
                     }
 
                     #[derive(Clone)]
@@ -26151,7 +27523,7 @@ impl GentleEngine {
                         "Anchored-region candidates truncated from {} to {} by max_candidates/max_fragments_per_container",
                         candidates.len(),
                         limit
-                    ));
+                    ).into());
                         candidates.truncate(limit);
                     }
 
@@ -26210,7 +27582,7 @@ impl GentleEngine {
                     result.created_seq_ids.push(seq_id.clone());
                     result.warnings.push(
                     "Selection operation is in-silico and may not directly correspond to a unique wet-lab product"
-                        .to_string(),
+                        .into()
                 );
                     result.messages.push(format!(
                         "Selected candidate '{}' from '{}' using criterion '{}'",
@@ -26463,11 +27835,14 @@ impl GentleEngine {
                         } else {
                             rejected += 1;
                             if rejection_warnings_left > 0 {
-                                result.warnings.push(format!(
-                                    "Sequence '{}' rejected by design constraints: {}",
-                                    input,
-                                    reasons.join(", ")
-                                ));
+                                result.warnings.push(
+                                    format!(
+                                        "Sequence '{}' rejected by design constraints: {}",
+                                        input,
+                                        reasons.join(", ")
+                                    )
+                                    .into(),
+                                );
                                 rejection_warnings_left -= 1;
                             }
                         }
@@ -26516,7 +27891,7 @@ impl GentleEngine {
                         result.warnings.push(format!(
                             "{} additional sequence(s) were rejected (warning output truncated)",
                             rejected - 32
-                        ));
+                        ).into());
                     }
 
                     result.messages.push(format!(
@@ -26560,6 +27935,7 @@ impl GentleEngine {
                         feature_strand_relation,
                         limit,
                         &mut result,
+                        on_progress,
                     )?;
                 }
                 Operation::GenerateCandidateSetBetweenAnchors {
@@ -26580,11 +27956,29 @@ impl GentleEngine {
                         step_bp,
                         limit,
                         &mut result,
+                        on_progress,
                     )?;
                 }
                 Operation::DeleteCandidateSet { set_name } => {
                     self.op_delete_candidate_set(set_name, &mut result)?;
                 }
+                Operation::ImportCandidateSetBed {
+                    set_name,
+                    path,
+                    seq_id,
+                } => {
+                    self.op_import_candidate_set_bed(set_name, path, seq_id, &mut result)?;
+                }
+                Operation::ImportCandidateSetCsv {
+                    set_name,
+                    path,
+                    seq_id,
+                } => {
+                    self.op_import_candidate_set_csv(set_name, path, seq_id, &mut result)?;
+                }
+                Operation::StreamCandidateSetJsonl { set_name, path } => {
+                    self.op_stream_candidate_set_jsonl(set_name, path, &mut result)?;
+                }
                 Operation::UpsertGuideSet {
                     guide_set_id,
                     guides,
@@ -26606,10 +28000,17 @@ impl GentleEngine {
                         &mut result,
                     )?;
                 }
-                Operation::GenerateGuideOligos {
+                Operation::RankGuides {
                     guide_set_id,
-                    template_id,
-                    apply_5prime_g_extension,
+                    weights,
+                    output_guide_set_id,
+                } => {
+                    self.op_rank_guides(guide_set_id, weights, output_guide_set_id, &mut result)?;
+                }
+                Operation::GenerateGuideOligos {
+                    guide_set_id,
+                    template_id,
+                    apply_5prime_g_extension,
                     output_oligo_set_id,
                     passed_only,
                 } => {
@@ -26643,12 +28044,14 @@ impl GentleEngine {
                     oligo_set_id,
                     path,
                     include_qc_checklist,
+                    reagents,
                 } => {
                     self.op_export_guide_protocol_text(
                         guide_set_id,
                         oligo_set_id,
                         path,
                         include_qc_checklist,
+                        reagents,
                         &mut result,
                     )?;
                 }
@@ -26676,6 +28079,60 @@ impl GentleEngine {
                     report.coordinate_mode
                 ));
                 }
+                Operation::ExportRestrictionMap {
+                    seq_id,
+                    path,
+                    format,
+                    enzyme_subset,
+                    unique_cutters_only,
+                } => {
+                    self.export_restriction_map(
+                        &seq_id,
+                        &path,
+                        format,
+                        enzyme_subset.as_deref(),
+                        unique_cutters_only,
+                        &mut result,
+                    )?;
+                }
+                Operation::ExportTrackCsv {
+                    seq_id,
+                    track,
+                    window_bp,
+                    step_bp,
+                    path,
+                } => {
+                    self.export_track_csv(&seq_id, track, window_bp, step_bp, &path, &mut result)?;
+                }
+                Operation::ExportMapLegend {
+                    seq_id,
+                    path,
+                    mode,
+                    format,
+                } => {
+                    self.export_map_legend(&seq_id, &path, mode, format, &mut result)?;
+                }
+                Operation::ExportSequenceStats { seq_id, path } => {
+                    self.export_sequence_stats(&seq_id, &path, &mut result)?;
+                }
+                Operation::ExportProteome {
+                    seq_id,
+                    path,
+                    translation_table,
+                } => {
+                    if let Some(table) = translation_table
+                        && u8::try_from(table).ok().and_then(codon_table).is_none()
+                    {
+                        return Err(EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!(
+                                "ExportProteome translation_table {table} is not a known NCBI genetic code"
+                            ),
+                            cause_chain: vec![],
+                        });
+                    }
+                    self.export_proteome(&seq_id, &path, translation_table, &mut result)?;
+                }
                 Operation::ScoreCandidateSetExpression {
                     set_name,
                     metric,
@@ -26688,6 +28145,26 @@ impl GentleEngine {
                         &mut result,
                     )?;
                 }
+                Operation::ScoreCandidateSetTm { set_name, metric } => {
+                    self.op_score_candidate_set_tm(set_name, metric, &mut result)?;
+                }
+                Operation::ScoreCandidateSetSelfStructure { set_name, metric } => {
+                    self.op_score_candidate_set_self_structure(set_name, metric, &mut result)?;
+                }
+                Operation::ScoreCandidateSetMotifCount {
+                    set_name,
+                    metric,
+                    motif,
+                    both_strands,
+                } => {
+                    self.op_score_candidate_set_motif_count(
+                        set_name,
+                        metric,
+                        motif,
+                        both_strands,
+                        &mut result,
+                    )?;
+                }
                 Operation::ScoreCandidateSetDistance {
                     set_name,
                     metric,
@@ -26736,17 +28213,35 @@ impl GentleEngine {
                 } => {
                     self.op_candidate_set_op(op, left_set, right_set, output_set, &mut result)?;
                 }
+                Operation::GuideSetOp {
+                    op,
+                    left_guide_set,
+                    right_guide_set,
+                    output_guide_set,
+                    match_by,
+                } => {
+                    self.op_guide_set_op(
+                        op,
+                        left_guide_set,
+                        right_guide_set,
+                        output_guide_set,
+                        match_by,
+                        &mut result,
+                    )?;
+                }
                 Operation::ScoreCandidateSetWeightedObjective {
                     set_name,
                     metric,
                     objectives,
                     normalize_metrics,
+                    missing_policy,
                 } => {
                     self.op_score_candidate_set_weighted_objective(
                         set_name,
                         metric,
                         objectives,
                         normalize_metrics,
+                        missing_policy,
                         &mut result,
                     )?;
                 }
@@ -26774,6 +28269,7 @@ impl GentleEngine {
                     objectives,
                     max_candidates,
                     tie_break,
+                    epsilon,
                 } => {
                     self.op_pareto_frontier_candidate_set(
                         input_set,
@@ -26781,6 +28277,7 @@ impl GentleEngine {
                         objectives,
                         max_candidates,
                         tie_break,
+                        epsilon,
                         &mut result,
                     )?;
                 }
@@ -26826,6 +28323,12 @@ impl GentleEngine {
                 Operation::DeleteCandidateMacroTemplate { name } => {
                     self.op_delete_candidate_macro_template(name, &mut result)?;
                 }
+                Operation::SaveAnnotationProfile { name, payload } => {
+                    self.op_save_annotation_profile(name, payload, &mut result)?;
+                }
+                Operation::ApplyAnnotationProfile { name, seq_ids } => {
+                    self.op_apply_annotation_profile(name, seq_ids, &mut result, on_progress)?;
+                }
                 Operation::Reverse { input, output_id } => {
                     parent_seq_ids.push(input.clone());
                     let dna = self
@@ -26955,6 +28458,75 @@ impl GentleEngine {
                         .messages
                         .push(format!("Branched '{}' into '{}'", input, seq_id));
                 }
+                Operation::StripAnnotations {
+                    input,
+                    keep_kinds,
+                    output_id,
+                } => {
+                    parent_seq_ids.push(input.clone());
+                    let mut dna = self
+                        .state
+                        .sequences
+                        .get(&input)
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::NotFound,
+                            message: format!("Sequence '{input}' not found"),
+                            cause_chain: vec![],
+                        })?
+                        .clone();
+                    let before = dna.features().len();
+                    dna.features_mut().retain(|feature| {
+                        let kind = feature.kind.to_string();
+                        keep_kinds
+                            .iter()
+                            .any(|keep| keep.trim().eq_ignore_ascii_case(&kind))
+                    });
+                    let kept = dna.features().len();
+
+                    let base = output_id.unwrap_or_else(|| format!("{input}_clean"));
+                    let seq_id = self.unique_seq_id(&base);
+                    self.state.sequences.insert(seq_id.clone(), dna);
+                    self.add_lineage_node(&seq_id, SequenceOrigin::Derived, Some(&result.op_id));
+                    result.created_seq_ids.push(seq_id.clone());
+                    result.messages.push(format!(
+                        "Created '{}' from '{}' without annotations ({} of {} feature(s) kept)",
+                        seq_id, input, kept, before
+                    ));
+                }
+                Operation::RenameSequence { from, to } => {
+                    self.rename_sequence(&mut result, &from, &to)?;
+                }
+                Operation::TagSequence {
+                    seq_id,
+                    add,
+                    remove,
+                } => {
+                    self.tag_sequence(&mut result, &seq_id, &add, &remove)?;
+                }
+                Operation::SetSequenceDescription {
+                    seq_id,
+                    description,
+                } => {
+                    let _ = self.ensure_lineage_node(&seq_id);
+                    let dna = self
+                        .state
+                        .sequences
+                        .get_mut(&seq_id)
+                        .ok_or_else(|| EngineError {
+                            code: ErrorCode::NotFound,
+                            message: format!("Sequence '{seq_id}' not found"),
+                            cause_chain: vec![],
+                        })?;
+                    dna.set_definition(description);
+                    result.changed_seq_ids.push(seq_id.clone());
+                    result.messages.push(match dna.definition() {
+                        Some(text) => format!("Set description of '{seq_id}' to '{text}'"),
+                        None => format!("Cleared description of '{seq_id}'"),
+                    });
+                }
+                Operation::DeleteSequence { seq_id, cascade } => {
+                    self.delete_sequence(&mut result, &seq_id, cascade)?;
+                }
                 Operation::SetDisplayVisibility { target, visible } => {
                     let (name, slot): (&str, &mut bool) = match target {
                         DisplayTarget::SequencePanel => (
@@ -27020,6 +28592,16 @@ impl GentleEngine {
                         "Set linear viewport start_bp={start_bp}, span_bp={span_bp}"
                     ));
                 }
+                Operation::ZoomViewport {
+                    seq_id,
+                    factor,
+                    center_bp,
+                } => {
+                    self.zoom_linear_viewport(&mut result, &seq_id, factor, center_bp)?;
+                }
+                Operation::PanViewport { seq_id, delta_bp } => {
+                    self.pan_linear_viewport(&mut result, &seq_id, delta_bp)?;
+                }
                 Operation::SetTopology { seq_id, circular } => {
                     let _ = self.ensure_lineage_node(&seq_id);
                     let dna = self
@@ -27058,49 +28640,20 @@ impl GentleEngine {
                         .messages
                         .push(format!("Recomputed features for '{seq_id}'"));
                 }
-                Operation::AnnotateTfbs {
+                Operation::EditFeatureQualifier {
                     seq_id,
-                    motifs,
-                    min_llr_bits,
-                    min_llr_quantile,
-                    per_tf_thresholds,
-                    clear_existing,
-                    max_hits,
+                    feature_id,
+                    key,
+                    value,
                 } => {
-                    const DEFAULT_MAX_TFBS_HITS: usize = 500;
-                    let motifs = if motifs.len() == 1
-                        && matches!(motifs[0].trim().to_ascii_uppercase().as_str(), "ALL" | "*")
-                    {
-                        tf_motifs::all_motif_ids()
-                    } else {
-                        motifs
-                    };
-
-                    if motifs.is_empty() {
+                    let key = key.trim().to_string();
+                    if key.is_empty() {
                         return Err(EngineError {
                             code: ErrorCode::InvalidInput,
-                            message: "AnnotateTfbs requires at least one motif".to_string(),
-
+                            message: "EditFeatureQualifier requires a non-empty key".to_string(),
                             cause_chain: vec![],
                         });
                     }
-                    let default_min_llr_bits = min_llr_bits.unwrap_or(f64::NEG_INFINITY);
-                    let default_min_llr_quantile = min_llr_quantile.unwrap_or(0.0);
-                    Self::validate_tf_thresholds(default_min_llr_quantile)?;
-
-                    let mut override_map: HashMap<String, (Option<f64>, Option<f64>)> =
-                        HashMap::new();
-                    for o in &per_tf_thresholds {
-                        let key = o.tf.trim().to_ascii_uppercase();
-                        if key.is_empty() {
-                            continue;
-                        }
-                        if let Some(q) = o.min_llr_quantile {
-                            Self::validate_tf_thresholds(q)?;
-                        }
-                        override_map.insert(key, (o.min_llr_bits, o.min_llr_quantile));
-                    }
-
                     let _ = self.ensure_lineage_node(&seq_id);
                     let dna = self
                         .state
@@ -27109,176 +28662,230 @@ impl GentleEngine {
                         .ok_or_else(|| EngineError {
                             code: ErrorCode::NotFound,
                             message: format!("Sequence '{seq_id}' not found"),
-
                             cause_chain: vec![],
                         })?;
-                    let seq_text = dna.get_forward_string();
-                    let seq_bytes = seq_text.as_bytes();
-
-                    if clear_existing.unwrap_or(true) {
-                        Self::remove_generated_tfbs_features(dna.features_mut());
-                    }
-
-                    let mut added = 0usize;
-                    let max_hits = match max_hits {
-                        Some(0) => None,
-                        Some(v) => Some(v),
-                        None => Some(DEFAULT_MAX_TFBS_HITS),
-                    };
-                    let motif_count = motifs.len();
-                    let mut motifs_scanned = 0usize;
-                    let mut cap_reached = false;
-                    'motif_loop: for (motif_idx, token) in motifs.into_iter().enumerate() {
-                        let token_key = token.trim().to_ascii_uppercase();
-                        if token_key.is_empty() {
-                            continue;
-                        }
-                        let (tf_id, tf_name, _consensus, matrix_counts) =
-                            Self::resolve_tf_motif_for_scoring(&token)?;
-                        let (llr_matrix, true_log_odds_matrix) =
-                            Self::prepare_scoring_matrices(&matrix_counts);
-                        if llr_matrix.is_empty() || llr_matrix.len() > seq_bytes.len() {
-                            result.warnings.push(format!(
-                                "TF '{}' skipped: motif length {} exceeds sequence length {}",
-                                tf_id,
-                                llr_matrix.len(),
-                                seq_bytes.len()
-                            ));
-                            continue;
-                        }
-
-                        let mut eff_bits = default_min_llr_bits;
-                        let mut eff_quantile = default_min_llr_quantile;
-                        let id_key = tf_id.to_ascii_uppercase();
-                        let name_key = tf_name
-                            .as_ref()
-                            .map(|n| n.trim().to_ascii_uppercase())
-                            .unwrap_or_default();
-                        for key in [token_key.as_str(), id_key.as_str(), name_key.as_str()] {
-                            if key.is_empty() {
-                                continue;
-                            }
-                            if let Some((b, q)) = override_map.get(key) {
-                                if let Some(v) = b {
-                                    eff_bits = *v;
-                                }
-                                if let Some(v) = q {
-                                    eff_quantile = *v;
-                                }
-                                break;
-                            }
-                        }
-
-                        motifs_scanned += 1;
-                        let seq_id_for_progress = seq_id.clone();
-                        let tf_id_for_progress = tf_id.clone();
-                        let motif_index = motif_idx + 1;
-                        let hits = Self::scan_tf_scores(
-                            seq_bytes,
-                            &llr_matrix,
-                            &true_log_odds_matrix,
-                            |scanned_steps, total_steps| {
-                                let motif_fraction = if total_steps == 0 {
-                                    1.0
-                                } else {
-                                    (scanned_steps as f64 / total_steps as f64).clamp(0.0, 1.0)
-                                };
-                                let total_fraction = if motif_count == 0 {
-                                    1.0
-                                } else {
-                                    ((motif_index - 1) as f64 + motif_fraction) / motif_count as f64
-                                }
-                                .clamp(0.0, 1.0);
-                                on_progress(OperationProgress::Tfbs(TfbsProgress {
-                                    seq_id: seq_id_for_progress.clone(),
-                                    motif_id: tf_id_for_progress.clone(),
-                                    motif_index,
-                                    motif_count,
-                                    scanned_steps,
-                                    total_steps,
-                                    motif_percent: motif_fraction * 100.0,
-                                    total_percent: total_fraction * 100.0,
-                                    task_kind: Some("annotation".to_string()),
-                                    stage_label: Some("scan".to_string()),
-                                    detail: None,
-                                    stage_percent: Some(motif_fraction * 100.0),
-                                }));
-                            },
-                        );
-                        let mut kept = 0usize;
-                        for (
-                            start,
-                            reverse,
-                            llr_bits,
-                            llr_quantile,
-                            true_log_odds_bits,
-                            true_log_odds_quantile,
-                        ) in hits
-                        {
-                            if llr_bits < eff_bits || llr_quantile < eff_quantile {
-                                continue;
-                            }
-                            let end = start + llr_matrix.len();
-                            dna.features_mut().push(Self::build_tfbs_feature(
-                                start,
-                                end,
-                                reverse,
-                                llr_matrix.len(),
-                                &tf_id,
-                                tf_name.as_deref(),
-                                llr_bits,
-                                llr_quantile,
-                                true_log_odds_bits,
-                                true_log_odds_quantile,
-                            ));
-                            kept += 1;
-                            added += 1;
-                            if let Some(limit) = max_hits
-                                && added >= limit
-                            {
-                                cap_reached = true;
-                                break;
-                            }
+                    let feature =
+                        dna.features_mut()
+                            .get_mut(feature_id)
+                            .ok_or_else(|| EngineError {
+                                code: ErrorCode::NotFound,
+                                message: format!(
+                                    "Feature id '{}' was not found in sequence '{}'",
+                                    feature_id, seq_id
+                                ),
+                                cause_chain: vec![],
+                            })?;
+                    // Setting replaces every value of `key` in place of the
+                    // first one, so qualifier order is otherwise preserved.
+                    let first = feature
+                        .qualifiers
+                        .iter()
+                        .position(|(existing, _)| existing.to_string() == key);
+                    feature
+                        .qualifiers
+                        .retain(|(existing, _)| existing.to_string() != key);
+                    let message = match value {
+                        Some(value) => {
+                            let at = first.unwrap_or(feature.qualifiers.len());
+                            feature
+                                .qualifiers
+                                .insert(at, (key.as_str().into(), Some(value.clone())));
+                            format!(
+                                "Set qualifier '/{key}={value}' on feature {feature_id} of '{seq_id}'"
+                            )
                         }
-                        result.messages.push(format!(
-                            "TF '{}' annotated {} hit(s){}",
-                            tf_id,
-                            kept,
-                            Self::format_tf_threshold_summary(eff_bits, eff_quantile)
-                        ));
-                        on_progress(OperationProgress::Tfbs(TfbsProgress {
-                            seq_id: seq_id.clone(),
-                            motif_id: tf_id,
-                            motif_index,
-                            motif_count,
-                            scanned_steps: 1,
-                            total_steps: 1,
-                            motif_percent: 100.0,
-                            total_percent: (motif_index as f64 / motif_count.max(1) as f64) * 100.0,
-                            task_kind: Some("annotation".to_string()),
-                            stage_label: Some("scan".to_string()),
-                            detail: None,
-                            stage_percent: Some(100.0),
-                        }));
-                        if cap_reached {
-                            if let Some(limit) = max_hits {
-                                result.warnings.push(format!(
-                                "TFBS hit cap ({limit}) reached after scanning {motifs_scanned}/{motif_count} motif(s); skipping remaining motif scans"
-                            ));
-                            }
-                            break 'motif_loop;
+                        None if first.is_some() => format!(
+                            "Removed qualifier '/{key}' from feature {feature_id} of '{seq_id}'"
+                        ),
+                        None => {
+                            format!("Feature {feature_id} of '{seq_id}' has no qualifier '/{key}'")
                         }
-                    }
-                    result.messages.push(format!(
-                        "TFBS motif scan coverage: {motifs_scanned}/{motif_count} motif(s)"
-                    ));
-
+                    };
                     result.changed_seq_ids.push(seq_id.clone());
+                    result.messages.push(message);
+                }
+                Operation::AddFeature {
+                    seq_id,
+                    kind,
+                    start_0based,
+                    end_0based,
+                    strand,
+                    qualifiers,
+                    segments,
+                } => {
+                    self.op_add_feature(
+                        seq_id,
+                        kind,
+                        start_0based,
+                        end_0based,
+                        strand,
+                        qualifiers,
+                        segments,
+                        &mut result,
+                    )?;
+                }
+                Operation::DeleteFeature { seq_id, feature_id } => {
+                    self.op_delete_feature(seq_id, feature_id, &mut result)?;
+                }
+                Operation::TransferFeatures {
+                    from,
+                    to,
+                    min_identity,
+                    flank_bp,
+                } => {
+                    let _ = self.ensure_lineage_node(&to);
+                    let outcome = self.transfer_features_between_sequences(
+                        &from,
+                        &to,
+                        min_identity,
+                        flank_bp,
+                    )?;
+                    if outcome.transferred > 0 {
+                        result.changed_seq_ids.push(to.clone());
+                    }
+                    result
+                        .warnings
+                        .extend(outcome.warnings.into_iter().map(Warning::from));
                     result.messages.push(format!(
-                        "Annotated {} TFBS feature(s) on '{}'",
-                        added, seq_id
+                        "Transferred {} feature(s) from '{}' to '{}' (ambiguous={}, unmatched={}, unsupported={}, already_present={})",
+                        outcome.transferred,
+                        from,
+                        to,
+                        outcome.skipped_ambiguous,
+                        outcome.skipped_unmatched,
+                        outcome.skipped_unsupported,
+                        outcome.skipped_existing
                     ));
                 }
+                Operation::AnnotateTfbs {
+                    seq_id,
+                    motifs,
+                    min_llr_bits,
+                    min_llr_quantile,
+                    per_tf_thresholds,
+                    clear_existing,
+                    max_hits,
+                } => {
+                    self.annotate_tfbs(
+                        &mut result,
+                        seq_id,
+                        motifs,
+                        min_llr_bits,
+                        min_llr_quantile,
+                        per_tf_thresholds,
+                        clear_existing,
+                        max_hits,
+                        on_progress,
+                    )?;
+                }
+                Operation::AnnotateInvertedRepeats {
+                    seq_id,
+                    min_stem,
+                    max_loop,
+                    max_mismatches,
+                    clear_existing,
+                } => {
+                    self.annotate_inverted_repeats(
+                        &mut result,
+                        &seq_id,
+                        min_stem,
+                        max_loop,
+                        max_mismatches,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::AnnotateTandemRepeats {
+                    seq_id,
+                    min_unit,
+                    max_unit,
+                    min_copies,
+                    clear_existing,
+                } => {
+                    self.annotate_tandem_repeats(
+                        &mut result,
+                        &seq_id,
+                        min_unit,
+                        max_unit,
+                        min_copies,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::MaskAmbiguousRegions {
+                    seq_id,
+                    clear_existing,
+                } => {
+                    self.mask_ambiguous_regions(
+                        &mut result,
+                        &seq_id,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::AnnotateSoftMaskedRegions {
+                    seq_id,
+                    clear_existing,
+                } => {
+                    self.annotate_soft_masked_regions(
+                        &mut result,
+                        &seq_id,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::AnnotateSearchHits {
+                    query,
+                    max_mismatches,
+                    both_strands,
+                    clear_existing,
+                } => {
+                    self.annotate_search_hits(
+                        &mut result,
+                        &query,
+                        max_mismatches,
+                        both_strands,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::AnnotateFromLibrary {
+                    seq_id,
+                    library,
+                    min_identity,
+                    clear_existing,
+                } => {
+                    self.annotate_from_library(
+                        &mut result,
+                        &seq_id,
+                        &library,
+                        min_identity,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::AnnotateMethylationSites {
+                    seq_id,
+                    mode,
+                    clear_existing,
+                } => {
+                    self.annotate_methylation_sites(
+                        &mut result,
+                        &seq_id,
+                        &mode,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
+                Operation::AnnotateCpgIslands {
+                    seq_id,
+                    window_bp,
+                    gc_min,
+                    obs_exp_min,
+                    clear_existing,
+                } => {
+                    self.annotate_cpg_islands(
+                        &mut result,
+                        &seq_id,
+                        window_bp,
+                        gc_min,
+                        obs_exp_min,
+                        clear_existing.unwrap_or(true),
+                    )?;
+                }
                 Operation::SetParameter { name, value } => match name.as_str() {
                     "max_fragments_per_container" => {
                         let raw = value.as_u64().ok_or_else(|| {
@@ -27421,6 +29028,64 @@ impl GentleEngine {
                             }
                         }
                     }
+                    "random_seed" | "sampling_seed" => {
+                        let raw = value.as_u64().ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!("SetParameter {name} requires a non-negative integer"),
+
+                            cause_chain: vec![],
+                        })?;
+                        self.state.parameters.random_seed = raw;
+                        result
+                            .messages
+                            .push(format!("Set parameter 'random_seed' to {raw}"));
+                    }
+                    "genome_download_max_attempts" | "download_max_attempts" => {
+                        let raw =
+                            value
+                                .as_u64()
+                                .filter(|raw| *raw >= 1)
+                                .ok_or_else(|| EngineError {
+                                    code: ErrorCode::InvalidInput,
+                                    message: format!(
+                                        "SetParameter {name} requires an integer >= 1"
+                                    ),
+
+                                    cause_chain: vec![],
+                                })?;
+                        self.state.parameters.genome_download_max_attempts = raw as usize;
+                        result.messages.push(format!(
+                            "Set parameter 'genome_download_max_attempts' to {raw}"
+                        ));
+                    }
+                    "genome_download_retry_base_delay_ms" | "download_retry_base_delay_ms" => {
+                        let raw = value.as_u64().ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!(
+                                "SetParameter {name} requires a non-negative integer (milliseconds)"
+                            ),
+
+                            cause_chain: vec![],
+                        })?;
+                        self.state.parameters.genome_download_retry_base_delay_ms = raw;
+                        result.messages.push(format!(
+                            "Set parameter 'genome_download_retry_base_delay_ms' to {raw}"
+                        ));
+                    }
+                    "candidate_scoring_threads" | "scoring_threads" => {
+                        let raw = value.as_u64().ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!(
+                                "SetParameter {name} requires a non-negative integer (0 = auto)"
+                            ),
+
+                            cause_chain: vec![],
+                        })?;
+                        self.state.parameters.candidate_scoring_threads = raw as usize;
+                        result.messages.push(format!(
+                            "Set parameter 'candidate_scoring_threads' to {raw}"
+                        ));
+                    }
                     "feature_details_font_size" | "feature_detail_font_size" => {
                         let raw = value.as_f64().ok_or_else(|| EngineError {
                             code: ErrorCode::InvalidInput,
@@ -27627,6 +29292,49 @@ impl GentleEngine {
                             self.state.display.gc_content_bin_size_bp
                         ));
                     }
+                    "circular_tick_interval_bp" => {
+                        let raw = value.as_u64().ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!("SetParameter {name} requires a non-negative integer"),
+                            cause_chain: vec![],
+                        })?;
+                        self.state.display.circular_tick_interval_bp = raw as usize;
+                        result.messages.push(format!(
+                            "Set parameter 'circular_tick_interval_bp' to {}",
+                            self.state.display.circular_tick_interval_bp
+                        ));
+                    }
+                    "collapse_introns_over_bp" => {
+                        let raw = value.as_u64().ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!("SetParameter {name} requires a non-negative integer"),
+                            cause_chain: vec![],
+                        })?;
+                        self.state.display.collapse_introns_over_bp = raw as usize;
+                        result.messages.push(format!(
+                            "Set parameter 'collapse_introns_over_bp' to {}",
+                            self.state.display.collapse_introns_over_bp
+                        ));
+                    }
+                    "feature_density_bin_bp" => {
+                        let raw = value.as_u64().ok_or_else(|| EngineError {
+                            code: ErrorCode::InvalidInput,
+                            message: format!("SetParameter {name} requires a positive integer"),
+                            cause_chain: vec![],
+                        })?;
+                        if raw == 0 {
+                            return Err(EngineError {
+                                code: ErrorCode::InvalidInput,
+                                message: "feature_density_bin_bp must be >= 1".to_string(),
+                                cause_chain: vec![],
+                            });
+                        }
+                        self.state.display.feature_density_bin_bp = raw as usize;
+                        result.messages.push(format!(
+                            "Set parameter 'feature_density_bin_bp' to {}",
+                            self.state.display.feature_density_bin_bp
+                        ));
+                    }
                     "linear_sequence_helical_max_view_span_bp"
                     | "linear_helical_max_view_span_bp" => {
                         let _raw = value.as_u64().ok_or_else(|| EngineError {
@@ -27824,6 +29532,7 @@ impl GentleEngine {
                     | "show_tfbs"
                     | "show_restriction_enzymes"
                     | "show_restriction_enzyme_sites"
+                    | "show_feature_density_when_zoomed_out"
                     | "tfbs_display_use_llr_bits"
                     | "tfbs_display_use_llr_quantile"
                     | "tfbs_display_use_true_log_odds_bits"
@@ -27867,6 +29576,9 @@ impl GentleEngine {
                             "show_restriction_enzymes" | "show_restriction_enzyme_sites" => {
                                 self.state.display.show_restriction_enzymes = raw
                             }
+                            "show_feature_density_when_zoomed_out" => {
+                                self.state.display.show_feature_density_when_zoomed_out = raw
+                            }
                             "tfbs_display_use_llr_bits" => {
                                 self.state.display.tfbs_display_use_llr_bits = raw
                             }
@@ -28068,6 +29780,37 @@ impl GentleEngine {
                             normalized.join(",")
                         ));
                     }
+                    "feature_color_overrides" => {
+                        let Some(entries) = value.as_object() else {
+                            return Err(EngineError {
+                                code: ErrorCode::InvalidInput,
+                                message: "SetParameter feature_color_overrides requires an object of 'kind:label' or 'label' keys to '#rrggbb' colors".to_string(),
+                                cause_chain: vec![],
+                            });
+                        };
+                        let mut overrides = BTreeMap::new();
+                        for (key, entry) in entries {
+                            let color = entry
+                                .as_str()
+                                .and_then(crate::feature_color::parse_hex_color)
+                                .ok_or_else(|| EngineError {
+                                    code: ErrorCode::InvalidInput,
+                                    message: format!(
+                                        "feature_color_overrides['{key}'] must be a '#rrggbb' color"
+                                    ),
+                                    cause_chain: vec![],
+                                })?;
+                            overrides.insert(
+                                key.trim().to_string(),
+                                crate::feature_color::color_hex(color),
+                            );
+                        }
+                        result.messages.push(format!(
+                            "Set parameter 'feature_color_overrides' to {} override(s)",
+                            overrides.len()
+                        ));
+                        self.state.display.feature_color_overrides = overrides;
+                    }
                     _ => {
                         return Err(EngineError {
                             code: ErrorCode::Unsupported,
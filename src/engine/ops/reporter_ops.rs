@@ -1402,6 +1402,8 @@ mod tests {
             .apply(Operation::LoadFile {
                 path: DEFAULT_REPORTER_BACKBONE_LOAD_PATH.to_string(),
                 as_id: Some(DEFAULT_REPORTER_BACKBONE_SEQ_ID.to_string()),
+                skip_duplicates: None,
+                uppercase: None,
             })
             .expect("load reporter backbone");
         let plan = engine
@@ -0,0 +1,429 @@
+//! Silent restriction-site engineering inside a CDS.
+//!
+//! `EngineerSilentSite` swaps codons of one CDS feature for synonymous
+//! alternatives so that an enzyme's recognition site appears in (or
+//! disappears from) the sequence without changing the encoded protein.
+//! Solutions with fewer changed bases win; ties go to the lowest position.
+//! Recognition sites are scanned on the linear forward sequence in both
+//! orientations.
+
+use super::*;
+
+/// One reading-frame codon of a CDS: forward-strand positions in coding
+/// order and the amino acid it encodes.
+struct SilentSiteCodon {
+    positions: [usize; 3],
+    aa: char,
+}
+
+/// One single-base edit, as (0-based position, old base, new base).
+type SilentSiteEdit = (usize, u8, u8);
+
+impl GentleEngine {
+    fn silent_site_base_matches(pattern: u8, base: u8) -> bool {
+        !IupacCode::from_letter(pattern)
+            .subset(IupacCode::from_letter(base))
+            .is_empty()
+    }
+
+    fn silent_site_window_matches(bases: &[u8], start: usize, pattern: &[u8]) -> bool {
+        start + pattern.len() <= bases.len()
+            && pattern
+                .iter()
+                .zip(&bases[start..start + pattern.len()])
+                .all(|(p, b)| Self::silent_site_base_matches(*p, *b))
+    }
+
+    fn silent_site_occurrences(bases: &[u8], patterns: &[Vec<u8>]) -> Vec<usize> {
+        let site_len = patterns.first().map(Vec::len).unwrap_or(0);
+        if site_len == 0 || site_len > bases.len() {
+            return vec![];
+        }
+        (0..=bases.len() - site_len)
+            .filter(|start| {
+                patterns
+                    .iter()
+                    .any(|pattern| Self::silent_site_window_matches(bases, *start, pattern))
+            })
+            .collect()
+    }
+
+    /// Forward-strand bases a coding-strand codon occupies.
+    fn silent_site_forward_bases(codon: [u8; 3], reverse: bool) -> [u8; 3] {
+        if reverse {
+            codon.map(IupacCode::letter_complement)
+        } else {
+            codon
+        }
+    }
+
+    fn silent_site_codons(
+        dna: &DNAsequence,
+        feature: &gb_io::seq::Feature,
+        reverse: bool,
+        translation_table: usize,
+    ) -> Vec<SilentSiteCodon> {
+        let mut ranges = vec![];
+        collect_location_ranges_usize(&feature.location, &mut ranges);
+        ranges.sort_unstable();
+        let mut coding_positions = ranges
+            .into_iter()
+            .flat_map(|(start, end)| start..end.min(dna.len()))
+            .collect::<Vec<_>>();
+        if reverse {
+            coding_positions.reverse();
+        }
+        let offset = Self::construct_reasoning_feature_codon_start_offset(feature);
+        let bases = dna.forward_bytes();
+        coding_positions
+            .get(offset..)
+            .unwrap_or_default()
+            .chunks_exact(3)
+            .map(|chunk| {
+                let positions = [chunk[0], chunk[1], chunk[2]];
+                let forward = positions.map(|pos| bases[pos].to_ascii_uppercase());
+                let coding = Self::silent_site_forward_bases(forward, reverse);
+                SilentSiteCodon {
+                    positions,
+                    aa: AMINO_ACIDS.codon2aa(coding, Some(translation_table)),
+                }
+            })
+            .collect()
+    }
+
+    /// Synonymous codons for `codon`, as forward-strand bases. The current
+    /// codon always comes first so zero-change choices are stable.
+    fn silent_site_alternatives(
+        codon: &SilentSiteCodon,
+        bases: &[u8],
+        reverse: bool,
+        translation_table: usize,
+    ) -> Vec<[u8; 3]> {
+        let current = codon.positions.map(|pos| bases[pos]);
+        let mut out = vec![current];
+        if codon.aa != UNKNOWN_CODON {
+            out.extend(
+                AMINO_ACIDS
+                    .aa2codons(codon.aa, Some(translation_table))
+                    .into_iter()
+                    .map(|alt| Self::silent_site_forward_bases(alt, reverse))
+                    .filter(|alt| *alt != current),
+            );
+        }
+        out
+    }
+
+    fn silent_site_codon_edits(
+        codon: &SilentSiteCodon,
+        bases: &[u8],
+        alt: [u8; 3],
+    ) -> Vec<SilentSiteEdit> {
+        codon
+            .positions
+            .iter()
+            .zip(alt)
+            .filter(|(pos, base)| bases[**pos] != *base)
+            .map(|(pos, base)| (*pos, bases[*pos], base))
+            .collect()
+    }
+
+    /// Cheapest synonymous edit set that makes one recognition site appear.
+    fn silent_site_introduce(
+        bases: &[u8],
+        codons: &[SilentSiteCodon],
+        patterns: &[Vec<u8>],
+        reverse: bool,
+        translation_table: usize,
+    ) -> Option<Vec<SilentSiteEdit>> {
+        let mut codon_at: HashMap<usize, (usize, usize)> = HashMap::new();
+        for (codon_idx, codon) in codons.iter().enumerate() {
+            for (k, pos) in codon.positions.iter().enumerate() {
+                codon_at.insert(*pos, (codon_idx, k));
+            }
+        }
+        let mut best: Option<(usize, usize, Vec<SilentSiteEdit>)> = None;
+        for pattern in patterns {
+            if pattern.len() > bases.len() {
+                continue;
+            }
+            'window: for start in 0..=bases.len() - pattern.len() {
+                let mut constraints: BTreeMap<usize, Vec<(usize, u8)>> = BTreeMap::new();
+                for (j, site_base) in pattern.iter().enumerate() {
+                    let pos = start + j;
+                    match codon_at.get(&pos) {
+                        Some((codon_idx, k)) => {
+                            constraints
+                                .entry(*codon_idx)
+                                .or_default()
+                                .push((*k, *site_base));
+                        }
+                        None if Self::silent_site_base_matches(*site_base, bases[pos]) => {}
+                        None => continue 'window,
+                    }
+                }
+                if constraints.is_empty() {
+                    continue;
+                }
+                let mut edits = vec![];
+                for (codon_idx, wanted) in constraints {
+                    let codon = &codons[codon_idx];
+                    let Some(choice) =
+                        Self::silent_site_alternatives(codon, bases, reverse, translation_table)
+                            .into_iter()
+                            .filter(|alt| {
+                                wanted.iter().all(|(k, site_base)| {
+                                    Self::silent_site_base_matches(*site_base, alt[*k])
+                                })
+                            })
+                            .map(|alt| Self::silent_site_codon_edits(codon, bases, alt))
+                            .min_by_key(Vec::len)
+                    else {
+                        continue 'window;
+                    };
+                    edits.extend(choice);
+                }
+                let better = match &best {
+                    Some((cost, best_start, _)) => (edits.len(), start) < (*cost, *best_start),
+                    None => true,
+                };
+                if better {
+                    best = Some((edits.len(), start, edits));
+                }
+            }
+        }
+        best.map(|(_, _, mut edits)| {
+            edits.sort_unstable();
+            edits
+        })
+    }
+
+    /// Synonymous edits that destroy every recognition site overlapping the
+    /// CDS, one codon swap at a time. Each swap must lower the total site
+    /// count so no new site is created elsewhere.
+    fn silent_site_remove(
+        bases: &mut [u8],
+        codons: &[SilentSiteCodon],
+        patterns: &[Vec<u8>],
+        reverse: bool,
+        translation_table: usize,
+    ) -> Result<Vec<SilentSiteEdit>, usize> {
+        let site_len = patterns[0].len();
+        let mut edits: Vec<SilentSiteEdit> = vec![];
+        loop {
+            let occurrences = Self::silent_site_occurrences(bases, patterns);
+            let overlapping_codons = |start: usize| {
+                codons
+                    .iter()
+                    .filter(|codon| {
+                        codon
+                            .positions
+                            .iter()
+                            .any(|pos| (start..start + site_len).contains(pos))
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let Some(start) = occurrences
+                .iter()
+                .copied()
+                .find(|start| !overlapping_codons(*start).is_empty())
+            else {
+                break;
+            };
+            let mut best: Option<(usize, usize, Vec<SilentSiteEdit>)> = None;
+            for codon in overlapping_codons(start) {
+                for alt in Self::silent_site_alternatives(codon, bases, reverse, translation_table)
+                    .into_iter()
+                    .skip(1)
+                {
+                    let candidate = Self::silent_site_codon_edits(codon, bases, alt);
+                    let mut trial = bases.to_vec();
+                    for (pos, _, new) in &candidate {
+                        trial[*pos] = *new;
+                    }
+                    if Self::silent_site_occurrences(&trial, patterns).len() >= occurrences.len() {
+                        continue;
+                    }
+                    let first_pos = candidate.iter().map(|(pos, _, _)| *pos).min().unwrap_or(0);
+                    let better = match &best {
+                        Some((cost, best_pos, _)) => {
+                            (candidate.len(), first_pos) < (*cost, *best_pos)
+                        }
+                        None => true,
+                    };
+                    if better {
+                        best = Some((candidate.len(), first_pos, candidate));
+                    }
+                }
+            }
+            let Some((_, _, chosen)) = best else {
+                return Err(start);
+            };
+            for (pos, _, new) in &chosen {
+                bases[*pos] = *new;
+            }
+            edits.extend(chosen);
+        }
+        edits.sort_unstable();
+        Ok(edits)
+    }
+
+    pub(super) fn engineer_silent_site(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        feature_id: usize,
+        enzyme: &str,
+        mode: SiteEngineerMode,
+        output_id: Option<SeqId>,
+    ) -> Result<(), EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let feature = dna.features().get(feature_id).ok_or_else(|| EngineError {
+            code: ErrorCode::NotFound,
+            message: format!("Feature id '{feature_id}' was not found in '{seq_id}'"),
+            cause_chain: vec![],
+        })?;
+        if !feature.kind.to_string().eq_ignore_ascii_case("CDS") {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "EngineerSilentSite requires a CDS feature; feature {feature_id} of '{seq_id}' is '{}'",
+                    feature.kind
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let restriction_enzyme = active_restriction_enzymes()
+            .into_iter()
+            .find(|candidate| candidate.name.eq_ignore_ascii_case(enzyme.trim()))
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Unknown restriction enzyme '{}'", enzyme.trim()),
+                cause_chain: vec![],
+            })?;
+        let site = restriction_enzyme
+            .sequence
+            .to_ascii_uppercase()
+            .into_bytes();
+        let mut patterns = vec![site.clone()];
+        let site_rc = Self::reverse_complement_bytes(&site);
+        if site_rc != site {
+            patterns.push(site_rc);
+        }
+
+        let reverse = feature_is_reverse(feature);
+        let translation_table = Self::construct_reasoning_feature_translation_table(feature);
+        let codons = Self::silent_site_codons(dna, feature, reverse, translation_table);
+        if codons.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("CDS feature {feature_id} of '{seq_id}' has no complete codon"),
+                cause_chain: vec![],
+            });
+        }
+        let mut bases = dna.forward_bytes().to_ascii_uppercase();
+        let edits = match mode {
+            SiteEngineerMode::Introduce => Self::silent_site_introduce(
+                &bases,
+                &codons,
+                &patterns,
+                reverse,
+                translation_table,
+            )
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "No synonymous codon changes in CDS feature {feature_id} of '{seq_id}' create a {} site ({})",
+                    restriction_enzyme.name, restriction_enzyme.sequence
+                ),
+                cause_chain: vec![],
+            })?,
+            SiteEngineerMode::Remove => Self::silent_site_remove(
+                &mut bases,
+                &codons,
+                &patterns,
+                reverse,
+                translation_table,
+            )
+            .map_err(|start| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "No synonymous codon change in CDS feature {feature_id} of '{seq_id}' removes the {} site at {}",
+                    restriction_enzyme.name,
+                    start + 1
+                ),
+                cause_chain: vec![],
+            })?,
+        };
+        if edits.is_empty() {
+            result.warnings.push(match mode {
+                SiteEngineerMode::Introduce => format!(
+                    "CDS feature {feature_id} of '{seq_id}' already contains a {} site; output is an unchanged copy",
+                    restriction_enzyme.name
+                ),
+                SiteEngineerMode::Remove => format!(
+                    "CDS feature {feature_id} of '{seq_id}' has no {} site to remove; output is an unchanged copy",
+                    restriction_enzyme.name
+                ),
+            }.into());
+        }
+        if mode == SiteEngineerMode::Remove {
+            let remaining = Self::silent_site_occurrences(&bases, &patterns).len();
+            if remaining > 0 {
+                result.warnings.push(
+                    format!(
+                        "{} {} site(s) outside CDS feature {} remain in the output",
+                        remaining, restriction_enzyme.name, feature_id
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        let mut edited = dna.clone();
+        if let Some(span) = edited.substitute_bases(
+            &edits
+                .iter()
+                .map(|(pos, _, new)| (*pos, *new))
+                .collect::<Vec<_>>(),
+        ) {
+            // Rescan only around the edits; `prepare_sequence` keeps the result.
+            edited.recompute_sites_in_range(span);
+        }
+        Self::prepare_sequence(&mut edited);
+        let default_id = format!(
+            "{seq_id}_silent_{}",
+            Self::normalize_id_token(&restriction_enzyme.name)
+        );
+        let output_id = self.unique_seq_id(&output_id.unwrap_or(default_id));
+        self.state.sequences.insert(output_id.clone(), edited);
+        self.add_lineage_node(&output_id, SequenceOrigin::Derived, Some(&result.op_id));
+        result.created_seq_ids.push(output_id.clone());
+        let edit_labels = edits
+            .iter()
+            .map(|(pos, old, new)| format!("{}{}{}", *old as char, pos + 1, *new as char))
+            .collect::<Vec<_>>();
+        result.messages.push(format!(
+            "Silent {} of {} site in CDS feature {} of '{}' into '{}': {} base change(s){}",
+            mode.as_str(),
+            restriction_enzyme.name,
+            feature_id,
+            seq_id,
+            output_id,
+            edits.len(),
+            if edit_labels.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", edit_labels.join(", "))
+            }
+        ));
+        Ok(())
+    }
+}
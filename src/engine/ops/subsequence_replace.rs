@@ -0,0 +1,172 @@
+//! Motif search-and-replace on one sequence.
+//!
+//! `ReplaceSubsequence` finds non-overlapping forward-strand occurrences of
+//! an IUPAC `find` pattern (same matching as `search_subsequence`, no
+//! mismatches), replaces the first `max_replacements` with literal bases, and
+//! writes a derived copy. Feature coordinates are carried across each edit
+//! with `remap_location_across_edit`; a feature boundary that falls inside a
+//! replaced occurrence is snapped to the edge of the replacement and warned
+//! about. Matches spanning the origin of circular sequences are not replaced.
+
+use super::*;
+
+impl GentleEngine {
+    fn normalize_replace_pattern(
+        field: &str,
+        raw: &str,
+        allow_empty: bool,
+    ) -> Result<Vec<u8>, EngineError> {
+        let bases = raw
+            .chars()
+            .filter(|ch| !ch.is_whitespace())
+            .collect::<String>()
+            .to_ascii_uppercase()
+            .into_bytes();
+        if (bases.is_empty() && !allow_empty)
+            || !bases.iter().all(|b| IupacCode::is_valid_letter(*b))
+        {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "ReplaceSubsequence {field} must be {}IUPAC DNA, got '{raw}'",
+                    if allow_empty { "" } else { "non-empty " }
+                ),
+                cause_chain: vec![],
+            });
+        }
+        Ok(bases)
+    }
+
+    pub(super) fn replace_subsequence(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        find: &str,
+        replace: &str,
+        max_replacements: Option<usize>,
+        output_id: Option<SeqId>,
+    ) -> Result<(), EngineError> {
+        let find_bases = Self::normalize_replace_pattern("find", find, false)?;
+        let replace_bases = Self::normalize_replace_pattern("replace", replace, true)?;
+        if max_replacements == Some(0) {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "ReplaceSubsequence max_replacements must be >= 1 when set".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+
+        let text = dna.forward_bytes().to_ascii_uppercase();
+        let mut starts = vec![];
+        for (start, _) in Self::scan_subsequence_with_mismatches(&text, &find_bases, 0) {
+            if starts
+                .last()
+                .is_some_and(|last| start < last + find_bases.len())
+            {
+                continue;
+            }
+            starts.push(start);
+        }
+        let total = starts.len();
+        starts.truncate(max_replacements.unwrap_or(usize::MAX));
+
+        let mut seq = dna.clone_seq_record();
+        let mut straddling = BTreeSet::new();
+        for (feature_id, feature) in seq.features.iter().enumerate() {
+            let mut ranges = vec![];
+            collect_location_ranges_usize(&feature.location, &mut ranges);
+            let cut = starts.iter().any(|&start| {
+                let end = start + find_bases.len();
+                ranges
+                    .iter()
+                    .any(|&(from, to)| (start < from && from < end) || (start < to && to < end))
+            });
+            if cut {
+                straddling.insert(feature_id);
+            }
+        }
+        for feature_id in &straddling {
+            result.warnings.push(
+                format!(
+                    "Feature '{}' of '{seq_id}' has a boundary inside a replaced '{}' occurrence; the boundary was moved to the replacement edge",
+                    Self::feature_display_label(&seq.features[*feature_id], *feature_id),
+                    String::from_utf8_lossy(&find_bases)
+                )
+                .into(),
+            );
+        }
+
+        // Right to left, so earlier starts still refer to unedited coordinates.
+        let delta = replace_bases.len() as i64 - find_bases.len() as i64;
+        let mut dropped = vec![];
+        for &start in starts.iter().rev() {
+            let end = start + find_bases.len();
+            seq.seq.splice(start..end, replace_bases.iter().copied());
+            if delta == 0 {
+                continue;
+            }
+            let features = std::mem::take(&mut seq.features);
+            for (feature_id, mut feature) in features.into_iter().enumerate() {
+                let location = Self::remap_location_across_edit(
+                    &feature.location,
+                    start as i64,
+                    end as i64,
+                    delta,
+                    true,
+                );
+                let mut ranges = vec![];
+                if let Some(location) = &location {
+                    collect_location_ranges_usize(location, &mut ranges);
+                }
+                match location {
+                    Some(location) if ranges.iter().all(|(from, to)| from < to) => {
+                        feature.location = location;
+                        seq.features.push(feature);
+                    }
+                    _ => dropped.push(Self::feature_display_label(&feature, feature_id)),
+                }
+            }
+        }
+        for label in dropped {
+            result.warnings.push(
+                format!("Feature '{label}' of '{seq_id}' was removed by the replacement").into(),
+            );
+        }
+        if seq.len.is_some() {
+            seq.len = Some(seq.seq.len());
+        }
+        if starts.is_empty() {
+            result.warnings.push(
+                format!(
+                    "No occurrence of '{}' in '{seq_id}'; output is an unchanged copy",
+                    String::from_utf8_lossy(&find_bases)
+                )
+                .into(),
+            );
+        }
+
+        let mut edited = DNAsequence::from_genbank_seq(seq);
+        Self::prepare_sequence(&mut edited);
+        Self::push_cds_integrity_warnings(result, seq_id, dna, &edited);
+        let output_id = self.unique_seq_id(&output_id.unwrap_or(format!("{seq_id}_replaced")));
+        self.state.sequences.insert(output_id.clone(), edited);
+        self.add_lineage_node(&output_id, SequenceOrigin::Derived, Some(&result.op_id));
+        result.created_seq_ids.push(output_id.clone());
+        result.messages.push(format!(
+            "Replaced {} of {total} occurrence(s) of '{}' with '{}' in '{seq_id}' into '{output_id}'",
+            starts.len(),
+            String::from_utf8_lossy(&find_bases),
+            String::from_utf8_lossy(&replace_bases)
+        ));
+        Ok(())
+    }
+}
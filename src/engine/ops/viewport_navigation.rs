@@ -0,0 +1,103 @@
+//! Programmatic linear-viewport navigation.
+//!
+//! `ZoomViewport` and `PanViewport` adjust the shared
+//! `linear_view_start_bp`/`linear_view_span_bp` relative to the current view
+//! so automation and accessibility clients can navigate without computing
+//! absolute coordinates. Both resolve the stored viewport against the named
+//! sequence first (`span_bp == 0` means the whole sequence) and clamp the
+//! result to its bounds.
+
+use super::*;
+
+impl GentleEngine {
+    /// Current viewport of `seq_id` as `(start, span, sequence_length)`,
+    /// clamped the same way the linear renderer clamps it.
+    fn effective_linear_viewport(
+        &self,
+        seq_id: &str,
+    ) -> Result<(usize, usize, usize), EngineError> {
+        let dna = self
+            .state
+            .sequences
+            .get(seq_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let len = dna.len();
+        if len == 0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Sequence '{seq_id}' is empty and has no viewport"),
+                cause_chain: vec![],
+            });
+        }
+        let span = match self.state.display.linear_view_span_bp {
+            0 => len,
+            span => span.min(len),
+        };
+        let start = self.state.display.linear_view_start_bp.min(len - span);
+        Ok((start, span, len))
+    }
+
+    pub(super) fn zoom_linear_viewport(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        factor: f64,
+        center_bp: Option<usize>,
+    ) -> Result<(), EngineError> {
+        if !factor.is_finite() || factor <= 0.0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("ZoomViewport factor must be a positive number, got {factor}"),
+                cause_chain: vec![],
+            });
+        }
+        let (start, span, len) = self.effective_linear_viewport(seq_id)?;
+        let center = center_bp.unwrap_or(start + span / 2);
+        if center >= len {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "ZoomViewport center_bp {center} is outside sequence '{seq_id}' ({len} bp)"
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let new_span = ((span as f64 / factor).round() as usize).clamp(1, len);
+        // Keep `center` at the same screen fraction; an off-screen center is
+        // brought to the middle instead.
+        let fraction = if (start..start + span).contains(&center) {
+            (center - start) as f64 / span as f64
+        } else {
+            0.5
+        };
+        let offset = (fraction * new_span as f64).round() as usize;
+        let new_start = center.saturating_sub(offset).min(len - new_span);
+        self.state.display.linear_view_start_bp = new_start;
+        self.state.display.linear_view_span_bp = new_span;
+        result.messages.push(format!(
+            "Zoomed linear viewport of '{seq_id}' by {factor} around {center}: start_bp={new_start}, span_bp={new_span}"
+        ));
+        Ok(())
+    }
+
+    pub(super) fn pan_linear_viewport(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        delta_bp: i64,
+    ) -> Result<(), EngineError> {
+        let (start, span, len) = self.effective_linear_viewport(seq_id)?;
+        let max_start = (len - span) as i64;
+        let new_start = (start as i64).saturating_add(delta_bp).clamp(0, max_start) as usize;
+        self.state.display.linear_view_start_bp = new_start;
+        self.state.display.linear_view_span_bp = span;
+        result.messages.push(format!(
+            "Panned linear viewport of '{seq_id}' by {delta_bp} bp: start_bp={new_start}, span_bp={span}"
+        ));
+        Ok(())
+    }
+}
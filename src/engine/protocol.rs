@@ -52,24 +52,24 @@ pub use gentle_protocol::{
     FeatureBedCoordinateMode, FlexibilityModel, GENE_SET_CO_REGULATED_CACHE_SCHEMA,
     GENE_SET_CUTRUN_REGULATORY_SUPPORT_SCHEMA, GENE_SET_DIRECT_LIST_CACHE_SCHEMA,
     GENE_SET_ONTOLOGY_ASSIGNMENT_CACHE_SCHEMA, GENE_SET_PROMOTER_COHORT_SCHEMA,
-    GENE_SET_RESOLUTION_SCHEMA, GeneSetCoRegulatedProducerMetadata, GeneSetCohortRelationship,
-    GeneSetCohortRelationshipFlag, GeneSetCutRunEvaluationState, GeneSetCutRunMemberSupport,
-    GeneSetCutRunRegulatorySupportReport, GeneSetCutRunSupportAggregate, GeneSetProducerFilter,
-    GeneSetProducerKind, GeneSetProducerProvenance, GeneSetProducerQueryMetadata,
-    GeneSetPromoterCohortReport, GeneSetPromoterWindow, GeneSetProvenanceRow,
-    GeneSetRandomProvenance, GeneSetRequest, GeneSetResolutionReport,
-    GeneSetResolutionReviewStatus, GeneSetResolvedMember, GeneSetUnresolvedMember,
-    GenomeAnchorSide, GenomeAnnotationScope, GenomeGeneExtractMode, GenomeTrackImportProgress,
-    GenomeTrackSource, GenomeTrackSubscription, HOST_PROFILE_CATALOG_SCHEMA,
-    HelperConstructProfile, HostLifecycleRole, HostProfileCatalog, HostProfileRecord,
-    HostRouteStep, ORTHOLOG_PROMOTER_COHORT_SCHEMA, ORTHOLOG_PROMOTER_COMPARISON_SCHEMA,
-    ORTHOLOG_RESOURCE_SCHEMA, OrthologAmbiguityPolicy, OrthologCutRunSupportRow,
-    OrthologCutRunSupportStatus, OrthologExpressionAssignment, OrthologMappingRow,
-    OrthologPairwiseTfbsSimilarity, OrthologPromoterCohortReport, OrthologPromoterCohortRequest,
-    OrthologPromoterComparisonReport, OrthologPromoterRole, OrthologPromoterRow, OrthologResource,
-    OrthologSequenceSimilarityRow, OrthologSpeciesAlias, OrthologTfbsPeakSummary,
-    OrthologTfbsSummaryRow, OrthologUnresolvedRow, PairwiseAlignmentMode, PortBindingStatus,
-    PreparedCacheCleanupMode, PreparedCacheCleanupRequest, PrimerDesignBackend,
+    GENE_SET_RESOLUTION_SCHEMA, GeneMatchMode, GeneSetCoRegulatedProducerMetadata,
+    GeneSetCohortRelationship, GeneSetCohortRelationshipFlag, GeneSetCutRunEvaluationState,
+    GeneSetCutRunMemberSupport, GeneSetCutRunRegulatorySupportReport,
+    GeneSetCutRunSupportAggregate, GeneSetProducerFilter, GeneSetProducerKind,
+    GeneSetProducerProvenance, GeneSetProducerQueryMetadata, GeneSetPromoterCohortReport,
+    GeneSetPromoterWindow, GeneSetProvenanceRow, GeneSetRandomProvenance, GeneSetRequest,
+    GeneSetResolutionReport, GeneSetResolutionReviewStatus, GeneSetResolvedMember,
+    GeneSetUnresolvedMember, GenomeAnchorSide, GenomeAnnotationScope, GenomeGeneExtractMode,
+    GenomeTrackImportProgress, GenomeTrackSource, GenomeTrackSubscription, GuideSetMatchKey,
+    HOST_PROFILE_CATALOG_SCHEMA, HelperConstructProfile, HostLifecycleRole, HostProfileCatalog,
+    HostProfileRecord, HostRouteStep, MissingValuePolicy, ORTHOLOG_PROMOTER_COHORT_SCHEMA,
+    ORTHOLOG_PROMOTER_COMPARISON_SCHEMA, ORTHOLOG_RESOURCE_SCHEMA, OrthologAmbiguityPolicy,
+    OrthologCutRunSupportRow, OrthologCutRunSupportStatus, OrthologExpressionAssignment,
+    OrthologMappingRow, OrthologPairwiseTfbsSimilarity, OrthologPromoterCohortReport,
+    OrthologPromoterCohortRequest, OrthologPromoterComparisonReport, OrthologPromoterRole,
+    OrthologPromoterRow, OrthologResource, OrthologSequenceSimilarityRow, OrthologSpeciesAlias,
+    OrthologTfbsPeakSummary, OrthologTfbsSummaryRow, OrthologUnresolvedRow, PairwiseAlignmentMode,
+    PortBindingStatus, PreparedCacheCleanupMode, PreparedCacheCleanupRequest, PrimerDesignBackend,
     PrimerSpecificityCheckMode, PrimerSpecificityPolicy, ProteinResidueGenomicCoordinateBase,
     ProteinResidueGenomicCoordinateMatch, ProteinResidueGenomicCoordinateReport,
     ProteinToDnaHandoffCandidate, ProteinToDnaHandoffCoverage, ProteinToDnaHandoffRankingGoal,
@@ -4257,6 +4257,19 @@ pub struct DisplaySettings {
     pub show_gc_contents: bool,
     #[serde(default = "DisplaySettings::default_gc_content_bin_size_bp")]
     pub gc_content_bin_size_bp: usize,
+    #[serde(default)]
+    pub show_feature_density_when_zoomed_out: bool,
+    #[serde(default = "DisplaySettings::default_feature_density_bin_bp")]
+    pub feature_density_bin_bp: usize,
+    #[serde(default)]
+    pub circular_tick_interval_bp: usize,
+    /// Linear-map threshold above which feature-free stretches are drawn as
+    /// fixed-width breaks; `0` disables collapsing.
+    #[serde(default)]
+    pub collapse_introns_over_bp: usize,
+    /// Pinned map colors keyed by `kind:label` or `label`, values `#rrggbb`.
+    #[serde(default)]
+    pub feature_color_overrides: BTreeMap<String, String>,
     pub show_open_reading_frames: bool,
     pub show_methylation_sites: bool,
     pub linear_view_start_bp: usize,
@@ -4303,6 +4316,10 @@ impl DisplaySettings {
         100
     }
 
+    pub const fn default_feature_density_bin_bp() -> usize {
+        1_000
+    }
+
     pub const fn default_linear_sequence_condensed_max_view_span_bp() -> usize {
         1500
     }
@@ -4362,6 +4379,11 @@ impl Default for DisplaySettings {
             preferred_restriction_enzymes: Self::default_preferred_restriction_enzymes(),
             show_gc_contents: true,
             gc_content_bin_size_bp: Self::default_gc_content_bin_size_bp(),
+            show_feature_density_when_zoomed_out: false,
+            feature_density_bin_bp: Self::default_feature_density_bin_bp(),
+            circular_tick_interval_bp: 0,
+            collapse_introns_over_bp: 0,
+            feature_color_overrides: BTreeMap::new(),
             show_open_reading_frames: false,
             show_methylation_sites: false,
             linear_view_start_bp: 0,
@@ -4398,6 +4420,137 @@ pub struct Workflow {
     pub ops: Vec<Operation>,
 }
 
+/// Stable machine-readable classification of one operation warning.
+///
+/// `Unclassified` covers free-text warnings that have not been migrated to a
+/// dedicated code yet, including every warning loaded from older journals.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningCode {
+    #[default]
+    Unclassified,
+    /// Track/annotation import skipped unparseable or out-of-range records.
+    TrackImportRecordsSkipped,
+    /// Output was capped or cancelled before all results were produced.
+    ResultTruncated,
+    /// A feature or requested interval was clipped at a sequence/chromosome
+    /// boundary.
+    FeatureClipped,
+    /// A simulated product formed outside the intended primer/site layout.
+    OffTargetProduct,
+    /// A loaded record's declared length disagrees with its parsed bases.
+    DeclaredLengthMismatch,
+    /// A CDS did not translate cleanly (partial codon or internal stop).
+    CdsTranslationIssue,
+}
+
+impl WarningCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unclassified => "unclassified",
+            Self::TrackImportRecordsSkipped => "track_import_records_skipped",
+            Self::ResultTruncated => "result_truncated",
+            Self::FeatureClipped => "feature_clipped",
+            Self::OffTargetProduct => "off_target_product",
+            Self::DeclaredLengthMismatch => "declared_length_mismatch",
+            Self::CdsTranslationIssue => "cds_translation_issue",
+        }
+    }
+}
+
+/// One operation warning: stable `code`, human-readable `message`, and optional
+/// structured `context` (counts, ids, paths).
+///
+/// Deserializes from either the structured object or a legacy plain string, so
+/// journals written before warning codes existed still load.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "WarningRepr")]
+pub struct Warning {
+    pub code: WarningCode,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub context: Value,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WarningRepr {
+    Legacy(String),
+    Structured {
+        #[serde(default)]
+        code: WarningCode,
+        message: String,
+        #[serde(default)]
+        context: Value,
+    },
+}
+
+impl From<WarningRepr> for Warning {
+    fn from(repr: WarningRepr) -> Self {
+        match repr {
+            WarningRepr::Legacy(message) => Self::from(message),
+            WarningRepr::Structured {
+                code,
+                message,
+                context,
+            } => Self {
+                code,
+                message,
+                context,
+            },
+        }
+    }
+}
+
+impl Warning {
+    pub fn new(code: WarningCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            context: Value::Null,
+        }
+    }
+
+    pub fn with_context(mut self, context: Value) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<String> for Warning {
+    fn from(message: String) -> Self {
+        Self::new(WarningCode::Unclassified, message)
+    }
+}
+
+impl From<&str> for Warning {
+    fn from(message: &str) -> Self {
+        Self::new(WarningCode::Unclassified, message)
+    }
+}
+
+impl From<Warning> for String {
+    fn from(warning: Warning) -> Self {
+        warning.message
+    }
+}
+
+impl AsRef<str> for Warning {
+    fn as_ref(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// Canonical result payload returned after one operation completes.
 ///
@@ -4407,7 +4560,7 @@ pub struct OpResult {
     pub op_id: OpId,
     pub created_seq_ids: Vec<SeqId>,
     pub changed_seq_ids: Vec<SeqId>,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
     pub messages: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protocol_cartoon_preview: Option<ProtocolCartoonPreviewTelemetry>,
@@ -5254,6 +5407,31 @@ pub enum OperationProgress {
     DbSnpFetch(DbSnpFetchProgress),
     ReadAcquisition(SharedAssetActivityStatus),
     RnaReadInterpret(RnaReadInterpretProgress),
+    /// Sliding-window candidate generation (`GenerateCandidateSet*`).
+    CandidateGeneration {
+        produced: usize,
+        total_windows: usize,
+    },
+    /// Container enumeration: digested members (`DigestContainer`) or ordered
+    /// end pairs tested (`LigationContainer`/`Ligation`).
+    ContainerOp {
+        processed: usize,
+        total: usize,
+    },
+}
+
+/// Pre-flight product count from `GentleEngine::estimate_operation_output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OutputEstimate {
+    /// Enumerable operation creating at most `max_products` sequences;
+    /// `exceeds_limit` flags a bound above `max_fragments_per_container`.
+    Bounded {
+        max_products: usize,
+        exceeds_limit: bool,
+    },
+    /// Product count cannot be enumerated without running the operation.
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -5291,6 +5469,9 @@ pub struct EngineHistorySummary {
 pub struct EngineSequenceSummary {
     pub id: String,
     pub name: Option<String>,
+    /// GenBank DEFINITION text, set via `SetSequenceDescription` or import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     pub length: usize,
     pub circular: bool,
 }
@@ -5341,6 +5522,92 @@ pub struct BlastHitFeatureInput {
     pub query_coverage_percent: Option<f64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Significant BLAST hit of a project sequence against one helper genome
+/// (vector backbone, host genome) from
+/// `GentleEngine::screen_against_helper_genomes`.
+pub struct HelperScreenHit {
+    pub genome_id: String,
+    pub subject_id: String,
+    pub query_start_1based: usize,
+    pub query_end_1based: usize,
+    pub subject_start_1based: usize,
+    pub subject_end_1based: usize,
+    pub alignment_length: usize,
+    pub identity_percent: f64,
+    pub bit_score: f64,
+    pub evalue: f64,
+    pub query_coverage_percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// One overlapping stretch between two annotated features, from
+/// `GentleEngine::feature_overlaps`. Features are identified by their index
+/// in the sequence's feature table; `feature_a < feature_b`.
+pub struct FeatureOverlap {
+    pub feature_a: usize,
+    pub kind_a: String,
+    pub label_a: String,
+    pub feature_b: usize,
+    pub kind_b: String,
+    pub label_b: String,
+    pub overlap_start_0based: usize,
+    pub overlap_end_0based_exclusive: usize,
+    /// The overall span of one feature lies within the span of the other.
+    pub nested: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// One compatible end pair in a `LigationMatrix`: the right end of
+/// `left_seq_id` joins the left end of `right_seq_id`.
+pub struct LigationJunction {
+    pub left_seq_id: SeqId,
+    pub right_seq_id: SeqId,
+    /// `Blunt` for blunt-blunt joins, `Sticky` for matching overhangs.
+    pub kind: LigationProtocol,
+    /// Annealed overhang as top-strand bases; empty for blunt joins.
+    pub overhang: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// End compatibility of a fragment set, from
+/// `GentleEngine::ligation_compatibility`.
+///
+/// `compatible[i][j]` is true when the right end of `seq_ids[i]` can be
+/// ligated to the left end of `seq_ids[j]` under either protocol; `i == j`
+/// means the fragment can circularize.
+pub struct LigationMatrix {
+    pub seq_ids: Vec<SeqId>,
+    pub compatible: Vec<Vec<bool>>,
+    /// Every `true` cell, in row-major order.
+    pub junctions: Vec<LigationJunction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// One junction sealed in a `Ligation` product, with the product interval
+/// covered by its `misc_feature` marker. Circular products also list the
+/// closing junction, last.
+pub struct LigationJunctionMarker {
+    pub junction: LigationJunction,
+    pub start_0based: usize,
+    /// Below `start_0based` when the marker wraps across the origin.
+    pub end_0based_exclusive: usize,
+    #[serde(default)]
+    pub wraps_origin: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// How a `Ligation` product was assembled; recorded as one JSON line per
+/// product in `OpResult.messages`.
+pub struct LigationAssembly {
+    pub schema: String,
+    pub product_seq_id: SeqId,
+    /// Input fragments in product order, left to right.
+    pub fragment_seq_ids: Vec<SeqId>,
+    pub circular: bool,
+    pub junctions: Vec<LigationJunctionMarker>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 /// Provenance bundle describing exactly how a BLAST search was invoked.
@@ -5415,18 +5682,359 @@ pub struct GenomeTrackSyncReport {
     pub errors: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum LigationProtocol {
     Sticky,
     Blunt,
 }
 
+/// Output table format for `ExportRestrictionMap`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RestrictionMapFormat {
+    #[default]
+    Csv,
+    Markdown,
+}
+
+impl RestrictionMapFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Markdown => "markdown",
+        }
+    }
+}
+
+/// Output table format for `ExportMapLegend`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LegendFormat {
+    #[default]
+    Csv,
+    Markdown,
+}
+
+impl LegendFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Markdown => "markdown",
+        }
+    }
+}
+
+/// One stem-loop (inverted repeat) candidate found by
+/// `GentleEngine::find_inverted_repeats`; stems pair reverse-complementarily
+/// across the loop.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct InvertedRepeat {
+    pub left_stem_start_1based: usize,
+    pub left_stem_end_1based: usize,
+    pub right_stem_start_1based: usize,
+    pub right_stem_end_1based: usize,
+    pub stem_length_bp: usize,
+    pub loop_length_bp: usize,
+    pub mismatches: usize,
+}
+
+/// One probe binding site found by `GentleEngine::search_subsequence`.
+///
+/// Coordinates are forward-strand and 1-based inclusive; a hit spanning the
+/// origin of a circular sequence has `end_1based < start_1based`. `strand` is
+/// `+` for query matches and `-` for reverse-complement matches.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct SubsequenceHit {
+    pub seq_id: String,
+    pub start_1based: usize,
+    pub end_1based: usize,
+    pub strand: String,
+    pub mismatches: usize,
+}
+
+/// Reference element set searched by `AnnotateFromLibrary`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FeatureLibrary {
+    /// Built-in common vector elements: origin core, AmpR, lacZ-alpha, phage
+    /// promoters/terminator, lac operator, sequencing primer sites, and
+    /// epitope tags.
+    #[default]
+    CommonVector,
+    /// Caller-supplied elements.
+    Custom { entries: Vec<FeatureLibraryEntry> },
+}
+
+/// One element of a `FeatureLibrary`; `sequence` is IUPAC DNA in the
+/// element's own orientation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct FeatureLibraryEntry {
+    pub name: String,
+    /// GenBank feature kind written for matches, e.g. `promoter` or `CDS`.
+    pub kind: String,
+    pub sequence: String,
+}
+
+/// Metric for `GentleEngine::sequence_distance`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DistanceMetric {
+    /// Substitutions only; both sequences must have the same length.
+    Hamming,
+    /// Substitutions, insertions, and deletions. Only distances up to `max`
+    /// are computed exactly; anything larger is reported as `max + 1`.
+    Levenshtein { max: usize },
+}
+
+/// One CpG island found by `GentleEngine::find_cpg_islands`; the statistics
+/// describe the whole merged island.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct CpgIsland {
+    pub start_1based: usize,
+    pub end_1based: usize,
+    pub length_bp: usize,
+    pub gc_fraction: f64,
+    pub cpg_count: usize,
+    /// Observed/expected CpG ratio, `CpG * length / (C * G)`.
+    pub cpg_obs_exp: f64,
+}
+
+/// One short tandem repeat (microsatellite) found by
+/// `GentleEngine::find_tandem_repeats`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct TandemRepeat {
+    pub unit: String,
+    pub unit_length_bp: usize,
+    pub copies: usize,
+    pub start_1based: usize,
+    pub end_1based: usize,
+}
+
+/// One maximal run of ambiguous (non-ACGT IUPAC) bases reported by
+/// `GentleEngine::ambiguity_report`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct AmbiguousRegion {
+    pub start_1based: usize,
+    pub end_1based: usize,
+    pub length_bp: usize,
+    /// Distinct uppercase IUPAC letters in the run, sorted (e.g. `N`, `NR`).
+    pub letters: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct AmbiguityReport {
+    pub seq_id: String,
+    pub length_bp: usize,
+    pub ambiguous_bp: usize,
+    pub regions: Vec<AmbiguousRegion>,
+}
+
+/// Read-only summary returned by `GentleEngine::sequence_stats` and written
+/// by `ExportSequenceStats`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct SequenceStats {
+    pub seq_id: String,
+    pub length_bp: usize,
+    /// `circular` or `linear`.
+    pub topology: String,
+    /// G+C over canonical (A/C/G/T) bases, in percent; 0 without any.
+    pub gc_percent: f64,
+    /// Count per uppercase base letter, including IUPAC ambiguity codes.
+    pub base_counts: BTreeMap<String, usize>,
+    pub n_count: usize,
+    pub feature_counts_by_kind: BTreeMap<String, usize>,
+    pub orf_count: usize,
+    pub unique_cutter_count: usize,
+}
+
+/// Sequence present in both states of a `ProjectDiff` whose content
+/// fingerprint differs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct ProjectSequenceChange {
+    pub seq_id: SeqId,
+    pub old_fingerprint: String,
+    pub new_fingerprint: String,
+    pub old_length_bp: usize,
+    pub new_length_bp: usize,
+}
+
+/// One top-level `DisplaySettings` field that differs between two states.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ProjectSettingChange {
+    pub key: String,
+    pub old_value: Value,
+    pub new_value: Value,
+}
+
+/// Review-oriented difference returned by `ProjectState::diff`; "added" and
+/// "removed" are relative to the state `diff` is called on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ProjectDiff {
+    pub added_sequences: Vec<SeqId>,
+    pub removed_sequences: Vec<SeqId>,
+    pub changed_sequences: Vec<ProjectSequenceChange>,
+    pub display_changes: Vec<ProjectSettingChange>,
+    pub added_containers: Vec<String>,
+    pub removed_containers: Vec<String>,
+    pub changed_containers: Vec<String>,
+    pub added_arrangements: Vec<String>,
+    pub removed_arrangements: Vec<String>,
+    pub changed_arrangements: Vec<String>,
+}
+
+impl ProjectDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_sequences.is_empty()
+            && self.removed_sequences.is_empty()
+            && self.changed_sequences.is_empty()
+            && self.display_changes.is_empty()
+            && self.added_containers.is_empty()
+            && self.removed_containers.is_empty()
+            && self.changed_containers.is_empty()
+            && self.added_arrangements.is_empty()
+            && self.removed_arrangements.is_empty()
+            && self.changed_arrangements.is_empty()
+    }
+}
+
+/// One lineage edge reached by `GentleEngine::ancestors_of` or
+/// `GentleEngine::descendants_of`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct LineageStep {
+    /// Edges away from the queried sequence, starting at 1.
+    pub depth: usize,
+    pub parent_seq_id: SeqId,
+    pub child_seq_id: SeqId,
+    pub op_id: OpId,
+    pub run_id: RunId,
+    /// Operation variant name from the journal; `None` when the op is not in
+    /// this engine's journal (e.g. a project loaded without its session).
+    pub operation: Option<String>,
+}
+
+/// Windowed per-sequence signal written by `ExportTrackCsv`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackKind {
+    /// G+C over canonical bases in the window.
+    GcFraction,
+    /// (G - C) / (G + C); 0 when the window has neither.
+    GcSkew,
+    /// Primer-style melting temperature of the window, in degrees C.
+    MeltingTemp,
+}
+
+impl TrackKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::GcFraction => "gc_fraction",
+            Self::GcSkew => "gc_skew",
+            Self::MeltingTemp => "melting_temp",
+        }
+    }
+}
+
+/// End-polishing treatment applied by `PolishEnds`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PolishMode {
+    /// Klenow-style fill-in of 5' overhangs.
+    FillIn5Overhang,
+    /// T4-style removal of 3' overhangs.
+    ChewBack3Overhang,
+}
+
+impl PolishMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FillIn5Overhang => "fill_in_5_overhang",
+            Self::ChewBack3Overhang => "chew_back_3_overhang",
+        }
+    }
+}
+
+/// Whether `EngineerSilentSite` creates or destroys a recognition site.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SiteEngineerMode {
+    Introduce,
+    Remove,
+}
+
+impl SiteEngineerMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Introduce => "introduce",
+            Self::Remove => "remove",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ExportFormat {
     GenBank,
     Fasta,
 }
 
+/// Header fields for `SaveFile` GenBank export. Unset fields keep the
+/// sequence's own record values, falling back to `DNA`, division `SYN`, and
+/// today's UTC date.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct GenBankExportOptions {
+    /// LOCUS molecule type, e.g. `DNA`, `RNA`, `mRNA`.
+    pub molecule_type: Option<String>,
+    /// Three-letter GenBank division, e.g. `SYN`, `BCT`, `PLN`.
+    pub division: Option<String>,
+    /// LOCUS date as `YYYY-MM-DD` or `DD-MON-YYYY`.
+    pub date: Option<String>,
+    pub accession: Option<String>,
+    pub definition: Option<String>,
+}
+
+/// Entry selection for `ExportDesignPackage`. Sequence-scoped entries
+/// (GenBank files and map SVGs) cover `seq_ids`, or every project sequence
+/// when it is empty. All flags default to `true`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DesignPackageContents {
+    pub seq_ids: Vec<SeqId>,
+    /// `sequences/<id>.gb` per selected sequence.
+    pub sequences: bool,
+    /// `candidates/<set>.csv` per candidate set.
+    pub candidate_sets: bool,
+    /// `guides/<guide_set_id>.csv` per guide set.
+    pub guide_sets: bool,
+    /// `maps/<id>.svg` per selected sequence (circular map for circular
+    /// sequences, linear otherwise).
+    pub map_svg: bool,
+    /// `provenance_report.md`.
+    pub provenance_report: bool,
+}
+
+impl Default for DesignPackageContents {
+    fn default() -> Self {
+        Self {
+            seq_ids: vec![],
+            sequences: true,
+            candidate_sets: true,
+            guide_sets: true,
+            map_svg: true,
+            provenance_report: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PrimerLibraryMode {
     Enumerate,
@@ -7371,6 +7979,10 @@ pub struct GuideCandidate {
     pub nuclease: String,
     pub cut_offset_from_protospacer_start: usize,
     pub rank: Option<usize>,
+    /// Optional off-target specificity in `[0, 1]` (higher = fewer predicted
+    /// off-target sites), used by `RankGuides` when present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_target_score: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7399,6 +8011,32 @@ pub struct GuidePracticalFilterConfig {
     pub allow_5prime_g_extension: bool,
 }
 
+/// Weights for `RankGuides` composite scores (higher score = better rank).
+///
+/// The score is `gc * (1 - 2 * |gc_fraction - 0.5|)`
+/// `- homopolymer * max(0, max_homopolymer_run - homopolymer_free_run)`
+/// `+ off_target * off_target_score` (the last term only when the guide
+/// carries an off-target score).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuideRankWeights {
+    pub gc: f64,
+    pub homopolymer: f64,
+    pub homopolymer_free_run: usize,
+    pub off_target: f64,
+}
+
+impl Default for GuideRankWeights {
+    fn default() -> Self {
+        Self {
+            gc: 1.0,
+            homopolymer: 0.25,
+            homopolymer_free_run: 3,
+            off_target: 1.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct GuideFilterReason {
@@ -7484,6 +8122,66 @@ pub struct GuideProtocolExportReport {
     pub oligo_set_id: String,
     pub path: String,
     pub guide_count: usize,
+    pub reagent_recipe: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReagentComponent {
+    pub name: String,
+    pub volume_ul: f64,
+}
+
+impl ReagentComponent {
+    fn new(name: &str, volume_ul: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            volume_ul,
+        }
+    }
+}
+
+/// Reagent recipe rendered as per-guide volume tables by
+/// `ExportGuideProtocolText`. Mix components are per reaction (one reaction
+/// per guide); master-mix totals add `overage_fraction` on top.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ReagentPlan {
+    pub recipe_name: String,
+    pub oligo_stock_concentration_um: f64,
+    /// Volume of each (forward and reverse) oligo stock per annealing reaction.
+    pub oligo_volume_ul: f64,
+    pub annealing_mix: Vec<ReagentComponent>,
+    /// Annealed duplex dilution before ligation (e.g. `200` for 1:200).
+    pub duplex_dilution_factor: f64,
+    pub duplex_volume_ul: f64,
+    pub ligation_mix: Vec<ReagentComponent>,
+    pub overage_fraction: f64,
+}
+
+impl Default for ReagentPlan {
+    /// Standard U6 sgRNA cloning: phosphorylate/anneal oligo pairs with T4 PNK,
+    /// then ligate the diluted duplex into a BbsI/BsmBI-cut backbone.
+    fn default() -> Self {
+        Self {
+            recipe_name: "Standard U6 guide cloning (PNK anneal + vector ligation)".to_string(),
+            oligo_stock_concentration_um: 100.0,
+            oligo_volume_ul: 1.0,
+            annealing_mix: vec![
+                ReagentComponent::new("10x T4 DNA ligase buffer", 1.0),
+                ReagentComponent::new("T4 PNK", 0.5),
+                ReagentComponent::new("Nuclease-free water", 6.5),
+            ],
+            duplex_dilution_factor: 200.0,
+            duplex_volume_ul: 1.0,
+            ligation_mix: vec![
+                ReagentComponent::new("Digested U6 backbone (50 ng)", 1.0),
+                ReagentComponent::new("2x Quick ligation buffer", 5.0),
+                ReagentComponent::new("Quick ligase", 1.0),
+                ReagentComponent::new("Nuclease-free water", 3.0),
+            ],
+            overage_fraction: 0.1,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -7927,6 +8625,48 @@ pub struct WorkflowMacroTemplateSummary {
     pub updated_at_unix_ms: u128,
 }
 
+/// TFBS section of an annotation profile; mirrors `AnnotateTfbs` parameters.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct AnnotationProfileTfbs {
+    pub motifs: Vec<String>,
+    pub min_llr_bits: Option<f64>,
+    pub min_llr_quantile: Option<f64>,
+    pub per_tf_thresholds: Vec<TfThresholdOverride>,
+    pub clear_existing: Option<bool>,
+    pub max_hits: Option<usize>,
+}
+
+/// Methylation section of an annotation profile: which methylase site
+/// classes are computed on the target sequences.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct AnnotationProfileMethylation {
+    pub dam: bool,
+    pub dcm: bool,
+}
+
+/// Typed bundle of annotation settings stored by `SaveAnnotationProfile`.
+/// Absent sections are left untouched when the profile is applied.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct AnnotationProfilePayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tfbs: Option<AnnotationProfileTfbs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub methylation: Option<AnnotationProfileMethylation>,
+}
+
+/// Named annotation profile persisted in project metadata.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct AnnotationProfile {
+    pub name: String,
+    pub payload: AnnotationProfilePayload,
+    pub created_at_unix_ms: u128,
+    pub updated_at_unix_ms: u128,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 /// Stored candidate-macro template that expands to candidate-shell commands.
@@ -0,0 +1,141 @@
+//! Re-point genome-anchored sequences at a moved catalog or cache directory.
+//!
+//! Genome-extraction provenance stores the catalog path and cache directory
+//! used at extraction time; `ExtendGenomeAnchor` reuses them. When those files
+//! move, the journaled `RelocateGenomeAnchor` operation rewrites the
+//! references (after checking that the anchored genome is still prepared at
+//! the new location) instead of re-extracting the sequence.
+
+use super::*;
+
+impl GentleEngine {
+    /// Update the catalog/cache references of every genome-extraction
+    /// provenance entry for `seq_id`. At least one of `new_catalog_path` and
+    /// `new_cache_dir` must be given; the omitted one keeps its recorded
+    /// value. The anchored genome must resolve as prepared at the new
+    /// location, otherwise provenance is left untouched.
+    ///
+    /// Runs through `apply` as `RelocateGenomeAnchor`, so the relocation is
+    /// journaled and undoable like other sequence edits.
+    pub fn relocate_genome_anchor(
+        &mut self,
+        seq_id: &str,
+        new_catalog_path: Option<String>,
+        new_cache_dir: Option<String>,
+    ) -> Result<(), EngineError> {
+        self.apply(Operation::RelocateGenomeAnchor {
+            seq_id: seq_id.to_string(),
+            new_catalog_path,
+            new_cache_dir,
+        })
+        .map(|_| ())
+    }
+
+    pub(super) fn apply_genome_anchor_relocation(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        new_catalog_path: Option<String>,
+        new_cache_dir: Option<String>,
+    ) -> Result<(), EngineError> {
+        let new_catalog_path = new_catalog_path
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+        let new_cache_dir = new_cache_dir
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+        if new_catalog_path.is_none() && new_cache_dir.is_none() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Relocating the genome anchor of '{seq_id}' requires a new catalog path or cache directory"
+                ),
+
+                cause_chain: vec![],
+            });
+        }
+        if !self.state.sequences.contains_key(seq_id) {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+
+                cause_chain: vec![],
+            });
+        }
+        let anchor = self.latest_genome_anchor_for_seq(seq_id)?;
+        let catalog_path = new_catalog_path
+            .clone()
+            .or(anchor.catalog_path.clone())
+            .unwrap_or_else(|| default_catalog_discovery_token(false).to_string());
+        let cache_dir = new_cache_dir.clone().or(anchor.cache_dir.clone());
+        let (catalog, _) =
+            Self::open_reference_genome_catalog(Some(&catalog_path)).map_err(|e| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Could not open genome catalog '{}' for relocating '{}': {}",
+                    catalog_path, seq_id, e.message
+                ),
+
+                cause_chain: vec![],
+            })?;
+        let prepared = catalog
+            .is_prepared(&anchor.genome_id, cache_dir.as_deref())
+            .map_err(|e| EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Genome '{}' does not resolve in catalog '{}': {}",
+                    anchor.genome_id, catalog_path, e
+                ),
+
+                cause_chain: vec![],
+            })?;
+        if !prepared {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!(
+                    "Genome '{}' is not prepared at the relocated catalog '{}'{}",
+                    anchor.genome_id,
+                    catalog_path,
+                    cache_dir
+                        .as_deref()
+                        .map(|dir| format!(" (cache '{dir}')"))
+                        .unwrap_or_default()
+                ),
+
+                cause_chain: vec![],
+            });
+        }
+
+        let entries = self
+            .state
+            .metadata
+            .get_mut(PROVENANCE_METADATA_KEY)
+            .and_then(|provenance| provenance.get_mut(GENOME_EXTRACTIONS_METADATA_KEY))
+            .and_then(|entries| entries.as_array_mut())
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry.get("seq_id").and_then(|v| v.as_str()) == Some(seq_id));
+        for entry in entries {
+            let Some(entry) = entry.as_object_mut() else {
+                continue;
+            };
+            if let Some(path) = &new_catalog_path {
+                entry.insert("catalog_path".to_string(), json!(path));
+            }
+            if let Some(dir) = &new_cache_dir {
+                entry.insert("cache_dir".to_string(), json!(dir));
+            }
+        }
+        result.changed_seq_ids.push(seq_id.to_string());
+        result.messages.push(format!(
+            "Relocated genome anchor of '{}' to catalog '{}'{}",
+            seq_id,
+            catalog_path,
+            cache_dir
+                .as_deref()
+                .map(|dir| format!(" (cache '{dir}')"))
+                .unwrap_or_default()
+        ));
+        Ok(())
+    }
+}
@@ -178,6 +178,15 @@ impl GentleEngine {
         out
     }
 
+    /// Repeat every lane entry `replicate_count` times in place
+    /// (`[a, b]` x2 -> `[a, a, b, b]`).
+    fn repeat_lanes(lanes: &[String], replicate_count: usize) -> Vec<String> {
+        lanes
+            .iter()
+            .flat_map(|lane| std::iter::repeat_n(lane.clone(), replicate_count))
+            .collect()
+    }
+
     fn arrangement_ladder_pair(arrangement: &Arrangement) -> Option<(String, String)> {
         match arrangement.ladders.as_slice() {
             [] => None,
@@ -636,6 +645,7 @@ impl GentleEngine {
         name: Option<String>,
         ladders: Option<Vec<String>>,
         lane_role_labels: Option<Vec<String>>,
+        replicate_count: usize,
         created_by_op: Option<&str>,
     ) -> Result<String, EngineError> {
         if container_ids.is_empty() {
@@ -674,6 +684,13 @@ impl GentleEngine {
                 cause_chain: vec![],
             });
         }
+        if replicate_count == 0 {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "CreateArrangementSerial replicate_count must be at least 1".to_string(),
+                cause_chain: vec![],
+            });
+        }
         let arrangement_id = arrangement_id
             .map(|v| v.trim().to_string())
             .filter(|v| !v.is_empty())
@@ -695,15 +712,18 @@ impl GentleEngine {
             arrangement_id: arrangement_id.clone(),
             mode: ArrangementMode::Serial,
             name: name.map(|v| v.trim().to_string()).filter(|v| !v.is_empty()),
-            lane_container_ids,
+            lane_container_ids: Self::repeat_lanes(&lane_container_ids, replicate_count),
             ladders: Self::normalize_serial_gel_ladders_owned(ladders),
-            lane_role_labels: Self::normalize_arrangement_lane_role_labels(
-                &container_ids
-                    .iter()
-                    .map(|id| id.trim().to_string())
-                    .filter(|id| !id.is_empty())
-                    .collect::<Vec<_>>(),
-                lane_role_labels,
+            lane_role_labels: Self::repeat_lanes(
+                &Self::normalize_arrangement_lane_role_labels(
+                    &container_ids
+                        .iter()
+                        .map(|id| id.trim().to_string())
+                        .filter(|id| !id.is_empty())
+                        .collect::<Vec<_>>(),
+                    lane_role_labels,
+                ),
+                replicate_count,
             ),
             default_rack_id: None,
             created_by_op: created_by_op.map(ToString::to_string),
@@ -3703,6 +3723,52 @@ impl GentleEngine {
         Ok(changed)
     }
 
+    /// Create a container from set algebra over two containers' members.
+    ///
+    /// Members are deduplicated and sorted so the result does not depend on
+    /// the input containers' insertion order. Returns `None` for an empty result.
+    pub(super) fn container_set_op(
+        &mut self,
+        op: CandidateSetOperator,
+        left_container: &str,
+        right_container: &str,
+        output_prefix: Option<&str>,
+        created_by_op: &str,
+    ) -> Result<(Option<ContainerId>, Vec<SeqId>), EngineError> {
+        let left: BTreeSet<SeqId> = self
+            .container_members(left_container.trim())?
+            .into_iter()
+            .collect();
+        let right: BTreeSet<SeqId> = self
+            .container_members(right_container.trim())?
+            .into_iter()
+            .collect();
+        let members: Vec<SeqId> = match op {
+            CandidateSetOperator::Union => left.union(&right).cloned().collect(),
+            CandidateSetOperator::Intersect => left.intersection(&right).cloned().collect(),
+            CandidateSetOperator::Subtract => left.difference(&right).cloned().collect(),
+        };
+        let kind = if members.len() > 1 {
+            ContainerKind::Pool
+        } else {
+            ContainerKind::Singleton
+        };
+        let name = output_prefix
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                format!(
+                    "Container {} ({} / {})",
+                    op.as_str(),
+                    left_container.trim(),
+                    right_container.trim()
+                )
+            });
+        let container_id = self.add_container(&members, kind, Some(name), Some(created_by_op));
+        Ok((container_id, members))
+    }
+
     pub(super) fn add_container(
         &mut self,
         members: &[SeqId],
@@ -3846,9 +3912,13 @@ impl GentleEngine {
             return;
         }
         // Gibson apply materializes explicit singleton containers and its
-        // arrangement directly inside the operation handler, so the generic
-        // fallback must stay out of the way here.
-        if matches!(op, Operation::ApplyGibsonAssemblyPlan { .. }) {
+        // arrangement directly inside the operation handler, and directory
+        // imports always create their own pool, so the generic fallback must
+        // stay out of the way here.
+        if matches!(
+            op,
+            Operation::ApplyGibsonAssemblyPlan { .. } | Operation::ImportDirectory { .. }
+        ) {
             return;
         }
         let kind = if matches!(op, Operation::SelectCandidate { .. }) {
@@ -3871,6 +3941,8 @@ impl GentleEngine {
             Operation::Ligation { .. } => Some("Ligation products".to_string()),
             Operation::LigationContainer { .. } => Some("Ligation products".to_string()),
             Operation::Pcr { .. }
+            | Operation::NestedPcr { .. }
+            | Operation::MultiplexPcr { .. }
             | Operation::PcrAdvanced { .. }
             | Operation::PcrMutagenesis { .. } => Some("PCR products".to_string()),
             Operation::ExtractRegion { .. } => Some("Extracted region".to_string()),
@@ -3880,12 +3952,14 @@ impl GentleEngine {
             }
             Operation::ExtractGenomeRegion { .. } => Some("Extracted genome region".to_string()),
             Operation::ExtractGenomeGene { .. } => Some("Extracted genome gene".to_string()),
+            Operation::ExtractGenomeGenes { .. } => Some("Extracted genome genes".to_string()),
             Operation::FetchGenBankAccession { .. } => Some("Fetched GenBank sequence".to_string()),
             Operation::FetchDbSnpRegion { .. } => Some("Fetched dbSNP region".to_string()),
             Operation::FetchUniprotLinkedGenBank { .. } => {
                 Some("Fetched UniProt-linked GenBank sequence".to_string())
             }
             Operation::ExtendGenomeAnchor { .. } => Some("Extended genome anchor".to_string()),
+            Operation::RelocateGenomeAnchor { .. } => Some("Relocated genome anchor".to_string()),
             Operation::ImportGenomeBedTrack { .. } => Some("Imported BED track".to_string()),
             Operation::ImportGenomeBigWigTrack { .. } => Some("Imported BigWig track".to_string()),
             Operation::ImportGenomeVcfTrack { .. } => Some("Imported VCF track".to_string()),
@@ -3900,13 +3974,18 @@ impl GentleEngine {
             Operation::FilterContainerByMolecularWeight { .. } => {
                 Some("Molecular-weight filtered".to_string())
             }
+            Operation::FilterContainerByGc { .. } => Some("GC filtered".to_string()),
             Operation::DeriveTranscriptSequences { .. } => {
                 Some("Derived transcript sequence".to_string())
             }
             Operation::Reverse { .. }
             | Operation::Complement { .. }
             | Operation::ReverseComplement { .. }
-            | Operation::Branch { .. } => Some("Derived sequence".to_string()),
+            | Operation::PolishEnds { .. }
+            | Operation::Dephosphorylate { .. }
+            | Operation::EngineerSilentSite { .. }
+            | Operation::Branch { .. }
+            | Operation::StripAnnotations { .. } => Some("Derived sequence".to_string()),
             _ => None,
         };
         let _ = self.add_container(&result.created_seq_ids, kind, name, Some(&result.op_id));
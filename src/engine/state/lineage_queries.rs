@@ -0,0 +1,84 @@
+//! Read-only ancestry queries over the lineage graph.
+//!
+//! `ancestors_of` and `descendants_of` walk `LineageGraph` edges
+//! breadth-first from one sequence's node. Every edge is reported once and
+//! every node is expanded once, so a malformed graph with cycles still
+//! terminates.
+
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineageDirection {
+    Ancestors,
+    Descendants,
+}
+
+impl GentleEngine {
+    /// Edges leading into `seq_id`, nearest parents first.
+    pub fn ancestors_of(&self, seq_id: &str) -> Vec<LineageStep> {
+        self.walk_lineage(seq_id, LineageDirection::Ancestors)
+    }
+
+    /// Edges leading out of `seq_id`, direct children first.
+    pub fn descendants_of(&self, seq_id: &str) -> Vec<LineageStep> {
+        self.walk_lineage(seq_id, LineageDirection::Descendants)
+    }
+
+    fn walk_lineage(&self, seq_id: &str, direction: LineageDirection) -> Vec<LineageStep> {
+        let lineage = &self.state.lineage;
+        let Some(start) = lineage.seq_to_node.get(seq_id) else {
+            return vec![];
+        };
+        let operation_names = self
+            .journal
+            .iter()
+            .map(|record| {
+                (
+                    record.result.op_id.as_str(),
+                    Self::operation_history_name(&record.op),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let node_seq_id = |node_id: &NodeId| {
+            lineage
+                .nodes
+                .get(node_id)
+                .map(|node| node.seq_id.clone())
+                .unwrap_or_else(|| node_id.clone())
+        };
+
+        let mut steps = vec![];
+        let mut visited_nodes = HashSet::from([start.clone()]);
+        let mut visited_edges = HashSet::new();
+        let mut frontier = vec![start.clone()];
+        let mut depth = 0usize;
+        while !frontier.is_empty() {
+            depth += 1;
+            let mut next = vec![];
+            for node_id in &frontier {
+                for (edge_idx, edge) in lineage.edges.iter().enumerate() {
+                    let (near, far) = match direction {
+                        LineageDirection::Ancestors => (&edge.to_node_id, &edge.from_node_id),
+                        LineageDirection::Descendants => (&edge.from_node_id, &edge.to_node_id),
+                    };
+                    if near != node_id || !visited_edges.insert(edge_idx) {
+                        continue;
+                    }
+                    steps.push(LineageStep {
+                        depth,
+                        parent_seq_id: node_seq_id(&edge.from_node_id),
+                        child_seq_id: node_seq_id(&edge.to_node_id),
+                        op_id: edge.op_id.clone(),
+                        run_id: edge.run_id.clone(),
+                        operation: operation_names.get(edge.op_id.as_str()).cloned(),
+                    });
+                    if visited_nodes.insert(far.clone()) {
+                        next.push(far.clone());
+                    }
+                }
+            }
+            frontier = next;
+        }
+        steps
+    }
+}
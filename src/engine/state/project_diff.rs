@@ -0,0 +1,116 @@
+//! Structural comparison of two project states.
+//!
+//! `ProjectState::diff` is meant for reviewing two saved projects: sequences
+//! are compared by content fingerprint (`DNAsequence::content_sha1`), display
+//! settings field by field, and containers/arrangements by their serialized
+//! records. Lineage and design sidecars are not compared.
+
+use super::*;
+
+impl ProjectState {
+    /// Differences going from `self` to `other`.
+    pub fn diff(&self, other: &ProjectState) -> ProjectDiff {
+        let mut diff = ProjectDiff::default();
+
+        for (seq_id, dna) in &self.sequences {
+            match other.sequences.get(seq_id) {
+                None => diff.removed_sequences.push(seq_id.clone()),
+                Some(other_dna) => {
+                    let old_fingerprint = dna.content_sha1();
+                    let new_fingerprint = other_dna.content_sha1();
+                    if old_fingerprint != new_fingerprint {
+                        diff.changed_sequences.push(ProjectSequenceChange {
+                            seq_id: seq_id.clone(),
+                            old_fingerprint,
+                            new_fingerprint,
+                            old_length_bp: dna.len(),
+                            new_length_bp: other_dna.len(),
+                        });
+                    }
+                }
+            }
+        }
+        diff.added_sequences = other
+            .sequences
+            .keys()
+            .filter(|seq_id| !self.sequences.contains_key(*seq_id))
+            .cloned()
+            .collect();
+        diff.added_sequences.sort();
+        diff.removed_sequences.sort();
+        diff.changed_sequences
+            .sort_by(|left, right| left.seq_id.cmp(&right.seq_id));
+
+        diff.display_changes = Self::diff_display_settings(&self.display, &other.display);
+
+        (
+            diff.added_containers,
+            diff.removed_containers,
+            diff.changed_containers,
+        ) = Self::diff_keyed_records(
+            &self.container_state.containers,
+            &other.container_state.containers,
+        );
+        (
+            diff.added_arrangements,
+            diff.removed_arrangements,
+            diff.changed_arrangements,
+        ) = Self::diff_keyed_records(
+            &self.container_state.arrangements,
+            &other.container_state.arrangements,
+        );
+        diff
+    }
+
+    fn diff_display_settings(
+        old: &DisplaySettings,
+        new: &DisplaySettings,
+    ) -> Vec<ProjectSettingChange> {
+        let to_object = |settings: &DisplaySettings| match serde_json::to_value(settings) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        let old = to_object(old);
+        let new = to_object(new);
+        let keys = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+        keys.into_iter()
+            .filter_map(|key| {
+                let old_value = old.get(key).cloned().unwrap_or(serde_json::Value::Null);
+                let new_value = new.get(key).cloned().unwrap_or(serde_json::Value::Null);
+                (old_value != new_value).then(|| ProjectSettingChange {
+                    key: key.clone(),
+                    old_value,
+                    new_value,
+                })
+            })
+            .collect()
+    }
+
+    /// Sorted (added, removed, changed) keys of two id-keyed record maps.
+    fn diff_keyed_records<T: Serialize>(
+        old: &HashMap<String, T>,
+        new: &HashMap<String, T>,
+    ) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let mut added = new
+            .keys()
+            .filter(|id| !old.contains_key(*id))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut removed = vec![];
+        let mut changed = vec![];
+        for (id, record) in old {
+            match new.get(id) {
+                None => removed.push(id.clone()),
+                Some(other) => {
+                    if serde_json::to_value(record).ok() != serde_json::to_value(other).ok() {
+                        changed.push(id.clone());
+                    }
+                }
+            }
+        }
+        added.sort();
+        removed.sort();
+        changed.sort();
+        (added, removed, changed)
+    }
+}
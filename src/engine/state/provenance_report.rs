@@ -0,0 +1,189 @@
+//! Human-readable (Markdown) audit report of project provenance.
+//!
+//! Combines the genome-extraction provenance records kept in project metadata
+//! (genome, coordinates, source types, SHA1 checksums) with the engine's
+//! operation journal, so an experiment can be reviewed without reading JSON.
+
+use super::*;
+
+impl GentleEngine {
+    fn genome_extraction_provenance_records(&self) -> Vec<GenomeExtractionProvenance> {
+        self.state
+            .metadata
+            .get(PROVENANCE_METADATA_KEY)
+            .and_then(|provenance| provenance.get(GENOME_EXTRACTIONS_METADATA_KEY))
+            .cloned()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default()
+    }
+
+    /// Markdown table cell text: pipes escaped, line breaks flattened.
+    fn provenance_markdown_cell(raw: &str) -> String {
+        raw.replace('|', "\\|").replace(['\r', '\n'], " ")
+    }
+
+    fn format_genome_extraction_provenance_markdown(
+        idx: usize,
+        entry: &GenomeExtractionProvenance,
+    ) -> String {
+        let mut rows: Vec<(&str, String)> = vec![
+            ("Genome", entry.genome_id.clone()),
+            ("Catalog", entry.catalog_path.clone()),
+        ];
+        if let Some(cache_dir) = &entry.cache_dir {
+            rows.push(("Cache dir", cache_dir.clone()));
+        }
+        if let (Some(chromosome), Some(start), Some(end)) =
+            (&entry.chromosome, entry.start_1based, entry.end_1based)
+        {
+            let strand = entry
+                .anchor_strand
+                .or(entry.strand)
+                .map(|strand| format!(" ({strand})"))
+                .unwrap_or_default();
+            rows.push((
+                "Coordinates (1-based)",
+                format!("{chromosome}:{start}-{end}{strand}"),
+            ));
+        }
+        if let Some(verified) = entry.anchor_verified {
+            rows.push(("Anchor verified", verified.to_string()));
+        }
+        if let Some(query) = &entry.gene_query {
+            let occurrence = entry
+                .occurrence
+                .map(|occurrence| format!(" (occurrence {occurrence})"))
+                .unwrap_or_default();
+            rows.push(("Gene query", format!("{query}{occurrence}")));
+        }
+        for (label, value) in [
+            ("Gene name", &entry.gene_name),
+            ("Gene id", &entry.gene_id),
+            ("Gene extract mode", &entry.gene_extract_mode),
+            ("Transcript id", &entry.transcript_id),
+        ] {
+            if let Some(value) = value {
+                rows.push((label, value.clone()));
+            }
+        }
+        for (label, value) in [
+            ("TSS (1-based)", entry.tss_1based),
+            ("Promoter upstream bp", entry.promoter_upstream_bp),
+            ("Promoter downstream bp", entry.promoter_downstream_bp),
+        ] {
+            if let Some(value) = value {
+                rows.push((label, value.to_string()));
+            }
+        }
+        for (label, source_type, source) in [
+            (
+                "Sequence source",
+                &entry.sequence_source_type,
+                &entry.sequence_source,
+            ),
+            (
+                "Annotation source",
+                &entry.annotation_source_type,
+                &entry.annotation_source,
+            ),
+        ] {
+            let text = match (source_type, source) {
+                (Some(kind), Some(source)) => format!("{kind}: {source}"),
+                (Some(kind), None) => kind.clone(),
+                (None, Some(source)) => source.clone(),
+                (None, None) => continue,
+            };
+            rows.push((label, text));
+        }
+        rows.push((
+            "Sequence SHA1",
+            entry
+                .sequence_sha1
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ));
+        rows.push((
+            "Annotation SHA1",
+            entry
+                .annotation_sha1
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ));
+        rows.push((
+            "Recorded at (unix ms)",
+            entry.recorded_at_unix_ms.to_string(),
+        ));
+
+        let mut out = format!(
+            "### {}. `{}` ({})\n\n| Field | Value |\n| --- | --- |\n",
+            idx + 1,
+            entry.seq_id,
+            entry.operation
+        );
+        for (label, value) in rows {
+            out.push_str(&format!(
+                "| {label} | {} |\n",
+                Self::provenance_markdown_cell(&value)
+            ));
+        }
+        out.push('\n');
+        out
+    }
+
+    /// Render genome-extraction provenance plus the operation journal as
+    /// Markdown. Empty sections say so instead of being omitted.
+    pub fn format_provenance_report_markdown(&self) -> String {
+        let extractions = self.genome_extraction_provenance_records();
+        let mut out = String::from("# GENtle provenance report\n\n");
+        out.push_str(&format!(
+            "- Sequences in project: {}\n- Genome extractions: {}\n- Journal operations: {}\n\n",
+            self.state.sequences.len(),
+            extractions.len(),
+            self.journal.len()
+        ));
+
+        out.push_str("## Genome extractions\n\n");
+        if extractions.is_empty() {
+            out.push_str("_No genome-extraction provenance recorded._\n\n");
+        }
+        for (idx, entry) in extractions.iter().enumerate() {
+            out.push_str(&Self::format_genome_extraction_provenance_markdown(
+                idx, entry,
+            ));
+        }
+
+        out.push_str("## Operation journal\n\n");
+        if self.journal.is_empty() {
+            out.push_str("_No operations recorded._\n");
+            return out;
+        }
+        out.push_str("| # | Run | Op id | Operation | Created | Changed | Warnings |\n");
+        out.push_str("| --- | --- | --- | --- | --- | --- | --- |\n");
+        for (idx, record) in self.journal.iter().enumerate() {
+            let list = |ids: &[SeqId]| {
+                if ids.is_empty() {
+                    "-".to_string()
+                } else {
+                    Self::provenance_markdown_cell(&ids.join(", "))
+                }
+            };
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                idx + 1,
+                Self::provenance_markdown_cell(&record.run_id),
+                Self::provenance_markdown_cell(&record.result.op_id),
+                Self::operation_variant_name(&record.op),
+                list(&record.result.created_seq_ids),
+                list(&record.result.changed_seq_ids),
+                record.result.warnings.len()
+            ));
+        }
+        out
+    }
+
+    /// Write `format_provenance_report_markdown` to `path`.
+    pub fn export_provenance_report(&self, path: &str) -> Result<(), EngineError> {
+        let text = self.format_provenance_report_markdown();
+        self.write_text_file(path, &text, "provenance report")
+    }
+}
@@ -0,0 +1,148 @@
+//! Replay-equivalence check behind the adapter-parity promise.
+//!
+//! Every adapter funnels into `GentleEngine::apply`, so parity reduces to
+//! determinism: the same workflow applied to two fresh engines must leave
+//! identical project state. Only compiled for tests or with the
+//! `parity-check` feature (CI).
+
+use super::*;
+
+impl GentleEngine {
+    /// Blank wall-clock fields (`*_unix_ms`, `*_unix_seconds`) so only
+    /// content and list order can differ between runs.
+    fn parity_mask_timestamps(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => serde_json::Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| {
+                        let value = if key.ends_with("_unix_ms") || key.ends_with("_unix_seconds") {
+                            serde_json::Value::Null
+                        } else {
+                            Self::parity_mask_timestamps(value)
+                        };
+                        (key, value)
+                    })
+                    .collect(),
+            ),
+            serde_json::Value::Array(values) => serde_json::Value::Array(
+                values
+                    .into_iter()
+                    .map(Self::parity_mask_timestamps)
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    fn parity_canonical_json<T: Serialize>(label: &str, value: &T) -> Result<String, EngineError> {
+        serde_json::to_value(value)
+            .map(Self::parity_mask_timestamps)
+            .map(Self::sort_json_object_keys)
+            .map(|value| value.to_string())
+            .map_err(|e| EngineError {
+                code: ErrorCode::Internal,
+                message: format!("Could not serialize {label} for parity check: {e}"),
+                cause_chain: vec![],
+            })
+    }
+
+    /// First difference between two states, checked in order: sequences
+    /// (by sorted `seq_id`), metadata (by sorted key), then the remaining
+    /// top-level sections. `None` when both serialize identically.
+    pub(crate) fn parity_first_difference(
+        left: &ProjectState,
+        right: &ProjectState,
+    ) -> Result<Option<String>, EngineError> {
+        let seq_ids = left
+            .sequences
+            .keys()
+            .chain(right.sequences.keys())
+            .collect::<BTreeSet<_>>();
+        for seq_id in seq_ids {
+            let label = format!("sequence '{seq_id}'");
+            let a = left
+                .sequences
+                .get(seq_id)
+                .map(|dna| Self::parity_canonical_json(&label, dna))
+                .transpose()?;
+            let b = right
+                .sequences
+                .get(seq_id)
+                .map(|dna| Self::parity_canonical_json(&label, dna))
+                .transpose()?;
+            if a != b {
+                return Ok(Some(label));
+            }
+        }
+
+        let keys = left
+            .metadata
+            .keys()
+            .chain(right.metadata.keys())
+            .collect::<BTreeSet<_>>();
+        for key in keys {
+            let label = format!("metadata key '{key}'");
+            let a = left
+                .metadata
+                .get(key)
+                .map(|value| Self::parity_canonical_json(&label, value))
+                .transpose()?;
+            let b = right
+                .metadata
+                .get(key)
+                .map(|value| Self::parity_canonical_json(&label, value))
+                .transpose()?;
+            if a != b {
+                return Ok(Some(label));
+            }
+        }
+
+        let sections: [(&str, String, String); 4] = [
+            (
+                "display",
+                Self::parity_canonical_json("display", &left.display)?,
+                Self::parity_canonical_json("display", &right.display)?,
+            ),
+            (
+                "lineage",
+                Self::parity_canonical_json("lineage", &left.lineage)?,
+                Self::parity_canonical_json("lineage", &right.lineage)?,
+            ),
+            (
+                "parameters",
+                Self::parity_canonical_json("parameters", &left.parameters)?,
+                Self::parity_canonical_json("parameters", &right.parameters)?,
+            ),
+            (
+                "container_state",
+                Self::parity_canonical_json("container_state", &left.container_state)?,
+                Self::parity_canonical_json("container_state", &right.container_state)?,
+            ),
+        ];
+        Ok(sections
+            .into_iter()
+            .find(|(_, a, b)| a != b)
+            .map(|(name, _, _)| format!("state section '{name}'")))
+    }
+
+    /// Apply `wf` on two independent fresh engines and require identical
+    /// resulting snapshots. A mismatch names the first differing `seq_id`,
+    /// metadata key, or state section.
+    pub fn assert_parity(wf: &Workflow) -> Result<(), EngineError> {
+        let mut first = GentleEngine::new();
+        first.apply_workflow(wf.clone())?;
+        let mut second = GentleEngine::new();
+        second.apply_workflow(wf.clone())?;
+        match Self::parity_first_difference(first.snapshot(), second.snapshot())? {
+            None => Ok(()),
+            Some(difference) => Err(EngineError {
+                code: ErrorCode::Internal,
+                message: format!(
+                    "Workflow '{}' is not replay-deterministic: {difference} differs between runs",
+                    wf.run_id
+                ),
+                cause_chain: vec![],
+            }),
+        }
+    }
+}
@@ -0,0 +1,329 @@
+//! Project-wide sequence identity changes.
+//!
+//! Sequence ids are referenced from lineage, containers, candidate sets, and
+//! guide sets. `RenameSequence` rewrites every one of those references in the
+//! same operation so a renamed sequence keeps its lineage node, container
+//! memberships, design records, and tags. `DeleteSequence` refuses to orphan
+//! those references unless asked to cascade, in which case it prunes them;
+//! tags are dropped with the sequence either way.
+
+use super::*;
+
+/// Project records that still point at one sequence id.
+#[derive(Debug, Default)]
+struct SequenceDependents {
+    containers: Vec<ContainerId>,
+    candidate_sets: Vec<String>,
+    guide_sets: Vec<String>,
+    lineage_edge_op_ids: Vec<OpId>,
+}
+
+impl SequenceDependents {
+    fn is_empty(&self) -> bool {
+        self.containers.is_empty()
+            && self.candidate_sets.is_empty()
+            && self.guide_sets.is_empty()
+            && self.lineage_edge_op_ids.is_empty()
+    }
+
+    fn describe(&self) -> String {
+        [
+            ("container(s)", &self.containers),
+            ("candidate set(s)", &self.candidate_sets),
+            ("guide set(s)", &self.guide_sets),
+            ("lineage edge(s) from op(s)", &self.lineage_edge_op_ids),
+        ]
+        .into_iter()
+        .filter(|(_, ids)| !ids.is_empty())
+        .map(|(label, ids)| format!("{label} {}", ids.join(", ")))
+        .collect::<Vec<_>>()
+        .join("; ")
+    }
+}
+
+impl GentleEngine {
+    pub(super) fn rename_sequence(
+        &mut self,
+        result: &mut OpResult,
+        from: &str,
+        to: &str,
+    ) -> Result<(), EngineError> {
+        let to = to.trim();
+        if to.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "RenameSequence requires a non-empty target id".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        if !self.state.sequences.contains_key(from) {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{from}' not found"),
+                cause_chain: vec![],
+            });
+        }
+        if self.state.sequences.contains_key(to) {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("Cannot rename '{from}' to '{to}': '{to}' already exists"),
+                cause_chain: vec![],
+            });
+        }
+
+        let dna = self
+            .state
+            .sequences
+            .remove(from)
+            .expect("sequence presence checked above");
+        self.state.sequences.insert(to.to_string(), dna);
+
+        let lineage = &mut self.state.lineage;
+        if let Some(node_id) = lineage.seq_to_node.remove(from) {
+            if let Some(node) = lineage.nodes.get_mut(&node_id) {
+                node.seq_id = to.to_string();
+            }
+            lineage.seq_to_node.insert(to.to_string(), node_id);
+        }
+
+        let containers = &mut self.state.container_state;
+        let mut container_count = 0usize;
+        for container in containers.containers.values_mut() {
+            let mut touched = false;
+            for member in container
+                .members
+                .iter_mut()
+                .filter(|member| *member == from)
+            {
+                *member = to.to_string();
+                touched = true;
+            }
+            container_count += usize::from(touched);
+        }
+        if let Some(container_id) = containers.seq_to_latest_container.remove(from) {
+            containers
+                .seq_to_latest_container
+                .insert(to.to_string(), container_id);
+        }
+
+        let mut candidate_store = self.read_candidate_store();
+        let mut candidate_set_count = 0usize;
+        for set in candidate_store.sets.values_mut() {
+            let mut touched = false;
+            for source in set.source_seq_ids.iter_mut().filter(|id| *id == from) {
+                *source = to.to_string();
+                touched = true;
+            }
+            for candidate in set.candidates.iter_mut().filter(|c| c.seq_id == from) {
+                candidate.seq_id = to.to_string();
+                touched = true;
+            }
+            candidate_set_count += usize::from(touched);
+        }
+        if candidate_set_count > 0 {
+            self.write_candidate_store(candidate_store)?;
+        }
+
+        let mut guide_store = self.read_guide_design_store();
+        let mut guide_set_count = 0usize;
+        for guide_set in guide_store.guide_sets.values_mut() {
+            let mut touched = false;
+            for guide in guide_set.guides.iter_mut().filter(|g| g.seq_id == from) {
+                guide.seq_id = to.to_string();
+                touched = true;
+            }
+            guide_set_count += usize::from(touched);
+        }
+        if guide_set_count > 0 {
+            self.write_guide_design_store(guide_store)?;
+        }
+        self.move_sequence_tags(from, Some(to))?;
+
+        result.changed_seq_ids.push(to.to_string());
+        result.messages.push(format!(
+            "Renamed sequence '{from}' to '{to}' (updated {container_count} container(s), {candidate_set_count} candidate set(s), {guide_set_count} guide set(s))"
+        ));
+        Ok(())
+    }
+
+    fn sequence_dependents(&self, seq_id: &str) -> SequenceDependents {
+        let mut dependents = SequenceDependents::default();
+        for container in self.state.container_state.containers.values() {
+            if !container.members.iter().any(|member| member == seq_id) {
+                continue;
+            }
+            // The singleton container created for the sequence itself is
+            // bookkeeping, not a dependent.
+            let own_singleton = matches!(container.kind, ContainerKind::Singleton)
+                && container.members.iter().all(|member| member == seq_id);
+            if !own_singleton {
+                dependents.containers.push(container.container_id.clone());
+            }
+        }
+        for set in self.read_candidate_store().sets.values() {
+            if set.source_seq_ids.iter().any(|id| id == seq_id)
+                || set.candidates.iter().any(|c| c.seq_id == seq_id)
+            {
+                dependents.candidate_sets.push(set.name.clone());
+            }
+        }
+        for guide_set in self.read_guide_design_store().guide_sets.values() {
+            if guide_set.guides.iter().any(|g| g.seq_id == seq_id) {
+                dependents.guide_sets.push(guide_set.guide_set_id.clone());
+            }
+        }
+        if let Some(node_id) = self.state.lineage.seq_to_node.get(seq_id) {
+            for edge in &self.state.lineage.edges {
+                if (&edge.from_node_id == node_id || &edge.to_node_id == node_id)
+                    && !dependents.lineage_edge_op_ids.contains(&edge.op_id)
+                {
+                    dependents.lineage_edge_op_ids.push(edge.op_id.clone());
+                }
+            }
+        }
+        dependents.containers.sort();
+        dependents.candidate_sets.sort();
+        dependents.guide_sets.sort();
+        dependents
+    }
+
+    pub(super) fn delete_sequence(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        cascade: bool,
+    ) -> Result<(), EngineError> {
+        if !self.state.sequences.contains_key(seq_id) {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        }
+        let dependents = self.sequence_dependents(seq_id);
+        if !cascade && !dependents.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Sequence '{seq_id}' is still referenced by {}; use cascade=true to delete it and prune these references",
+                    dependents.describe()
+                ),
+                cause_chain: vec![],
+            });
+        }
+
+        self.state.sequences.remove(seq_id);
+
+        let lineage = &mut self.state.lineage;
+        let mut pruned_edges = 0usize;
+        if let Some(node_id) = lineage.seq_to_node.remove(seq_id) {
+            lineage.nodes.remove(&node_id);
+            let before = lineage.edges.len();
+            lineage
+                .edges
+                .retain(|edge| edge.from_node_id != node_id && edge.to_node_id != node_id);
+            pruned_edges = before - lineage.edges.len();
+        }
+
+        let containers = &mut self.state.container_state;
+        let mut emptied_containers = vec![];
+        let mut pruned_memberships = 0usize;
+        for container in containers.containers.values_mut() {
+            let before = container.members.len();
+            container.members.retain(|member| member != seq_id);
+            pruned_memberships += before - container.members.len();
+            if before > 0 && container.members.is_empty() {
+                emptied_containers.push(container.container_id.clone());
+            }
+        }
+        containers.seq_to_latest_container.remove(seq_id);
+        for container_id in &emptied_containers {
+            containers.containers.remove(container_id);
+        }
+        for arrangement in containers.arrangements.values_mut() {
+            let mut lane = 0usize;
+            while lane < arrangement.lane_container_ids.len() {
+                if emptied_containers.contains(&arrangement.lane_container_ids[lane]) {
+                    arrangement.lane_container_ids.remove(lane);
+                    if lane < arrangement.lane_role_labels.len() {
+                        arrangement.lane_role_labels.remove(lane);
+                    }
+                } else {
+                    lane += 1;
+                }
+            }
+        }
+        for rack in containers.racks.values_mut() {
+            rack.placements.retain(|placement| {
+                !matches!(
+                    &placement.occupant,
+                    Some(RackOccupant::Container { container_id })
+                        if emptied_containers.contains(container_id)
+                )
+            });
+        }
+
+        let mut candidate_store = self.read_candidate_store();
+        let mut pruned_candidates = 0usize;
+        for set in candidate_store.sets.values_mut() {
+            set.source_seq_ids.retain(|id| id != seq_id);
+            let before = set.candidates.len();
+            set.candidates.retain(|c| c.seq_id != seq_id);
+            pruned_candidates += before - set.candidates.len();
+        }
+        let removed_candidate_sets = dependents
+            .candidate_sets
+            .iter()
+            .filter(|name| {
+                candidate_store
+                    .sets
+                    .get(*name)
+                    .is_some_and(|set| set.candidates.is_empty())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for name in &removed_candidate_sets {
+            candidate_store.sets.remove(name);
+        }
+        if !dependents.candidate_sets.is_empty() {
+            self.write_candidate_store(candidate_store)?;
+        }
+
+        let mut guide_store = self.read_guide_design_store();
+        let mut pruned_guides = 0usize;
+        for guide_set in guide_store.guide_sets.values_mut() {
+            let before = guide_set.guides.len();
+            guide_set.guides.retain(|g| g.seq_id != seq_id);
+            pruned_guides += before - guide_set.guides.len();
+        }
+        let removed_guide_sets = dependents
+            .guide_sets
+            .iter()
+            .filter(|id| {
+                guide_store
+                    .guide_sets
+                    .get(*id)
+                    .is_some_and(|set| set.guides.is_empty())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for id in &removed_guide_sets {
+            guide_store.guide_sets.remove(id);
+        }
+        if !dependents.guide_sets.is_empty() {
+            self.write_guide_design_store(guide_store)?;
+        }
+        self.move_sequence_tags(seq_id, None)?;
+
+        result.messages.push(format!("Deleted sequence '{seq_id}'"));
+        if cascade && !dependents.is_empty() {
+            result.messages.push(format!(
+                "Cascade cleanup for '{seq_id}': {pruned_memberships} container membership(s) ({} emptied container(s) removed), {pruned_candidates} candidate(s) ({} empty candidate set(s) removed), {pruned_guides} guide(s) ({} empty guide set(s) removed), {pruned_edges} lineage edge(s)",
+                emptied_containers.len(),
+                removed_candidate_sets.len(),
+                removed_guide_sets.len()
+            ));
+        }
+        Ok(())
+    }
+}
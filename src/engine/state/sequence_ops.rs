@@ -810,9 +810,67 @@ impl GentleEngine {
                 .filter(|label| !label.is_empty())
                 .map(ToString::to_string);
         }
+        // Technical replicates repeat one container in consecutive lanes;
+        // number them so the gel labels stay distinguishable.
+        let lanes = &arrangement.lane_container_ids;
+        let mut idx = 0usize;
+        while idx < lanes.len() {
+            let run = lanes[idx..]
+                .iter()
+                .take_while(|id| **id == lanes[idx])
+                .count();
+            if run > 1 {
+                for (rep, sample) in samples[idx..idx + run].iter_mut().enumerate() {
+                    sample.name = format!("{} rep{}", sample.name, rep + 1);
+                }
+            }
+            idx += run;
+        }
         Ok((samples, arrangement.ladders.clone()))
     }
 
+    /// Primary sample (first member) of every lane container in one
+    /// arrangement, in lane order, for plate-layout rendering.
+    pub(super) fn plate_samples_from_arrangement(
+        &self,
+        arrangement_id: &str,
+    ) -> Result<Vec<crate::pool_gel::PlateSampleInput>, EngineError> {
+        let arrangement = self
+            .state
+            .container_state
+            .arrangements
+            .get(arrangement_id)
+            .ok_or_else(|| EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Arrangement '{arrangement_id}' not found"),
+                cause_chain: vec![],
+            })?;
+        let mut samples = Vec::with_capacity(arrangement.lane_container_ids.len());
+        for container_id in &arrangement.lane_container_ids {
+            let seq_id = self
+                .container_members(container_id)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("Container '{container_id}' has no members"),
+                    cause_chain: vec![],
+                })?;
+            let label = self
+                .state
+                .sequences
+                .get(&seq_id)
+                .and_then(|dna| dna.name().clone())
+                .unwrap_or_else(|| seq_id.clone());
+            samples.push(crate::pool_gel::PlateSampleInput {
+                container_id: container_id.clone(),
+                seq_id,
+                label,
+            });
+        }
+        Ok(samples)
+    }
+
     /// Resolve one serial-gel layout from direct inputs, containers, or one
     /// stored arrangement, optionally overriding ladder selection.
     pub fn build_serial_gel_layout_for_render(
@@ -887,13 +945,17 @@ impl GentleEngine {
                     members,
                 }]
             };
-        crate::pool_gel::build_serial_gel_layout(&samples, &ladder_names, conditions).map_err(|e| {
-            EngineError {
-                code: ErrorCode::InvalidInput,
-                message: e,
+        crate::pool_gel::build_serial_gel_layout_with_catalog(
+            &samples,
+            &ladder_names,
+            conditions,
+            &self.project_dna_ladder_catalog(),
+        )
+        .map_err(|e| EngineError {
+            code: ErrorCode::InvalidInput,
+            message: e,
 
-                cause_chain: vec![],
-            }
+            cause_chain: vec![],
         })
     }
 
@@ -995,6 +1057,7 @@ impl GentleEngine {
             .map(|(id, dna)| EngineSequenceSummary {
                 id: id.to_string(),
                 name: dna.name().clone(),
+                description: dna.definition().map(str::to_string),
                 length: dna.len(),
                 circular: dna.is_circular(),
             })
@@ -1857,6 +1920,96 @@ impl GentleEngine {
         tokens
     }
 
+    /// `YYYY-MM-DD` or GenBank-style `DD-MON-YYYY` as a LOCUS date.
+    fn parse_genbank_export_date(raw: &str) -> Result<gb_io::seq::Date, EngineError> {
+        const MONTHS: [&str; 12] = [
+            "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+        ];
+        let parts = raw.trim().split('-').collect::<Vec<_>>();
+        let ymd = match parts.as_slice() {
+            [year, month, day] if year.len() == 4 => (
+                year.parse::<i32>().ok(),
+                month.parse::<u32>().ok(),
+                day.parse::<u32>().ok(),
+            ),
+            [day, month, year] => (
+                year.parse::<i32>().ok(),
+                MONTHS
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(month))
+                    .map(|idx| idx as u32 + 1),
+                day.parse::<u32>().ok(),
+            ),
+            _ => (None, None, None),
+        };
+        match ymd {
+            (Some(year), Some(month), Some(day)) => gb_io::seq::Date::from_ymd(year, month, day)
+                .map_err(|_| EngineError {
+                    code: ErrorCode::InvalidInput,
+                    message: format!("GenBank export date '{raw}' is not a valid calendar date"),
+                    cause_chain: vec![],
+                }),
+            _ => Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("GenBank export date '{raw}' must be YYYY-MM-DD or DD-MON-YYYY"),
+                cause_chain: vec![],
+            }),
+        }
+    }
+
+    /// Write GenBank, applying `options` to the LOCUS/header fields when
+    /// given. Without options the stored record header is written as-is.
+    pub(super) fn save_as_genbank(
+        dna: &DNAsequence,
+        path: &str,
+        options: Option<&GenBankExportOptions>,
+    ) -> Result<(), EngineError> {
+        let nonempty = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let date = match options.and_then(|options| nonempty(&options.date)) {
+            Some(raw) => Some(Self::parse_genbank_export_date(&raw)?),
+            None => None,
+        };
+        dna.write_genbank_file_with(path, |seq| {
+            let Some(options) = options else {
+                return Ok(());
+            };
+            seq.molecule_type = nonempty(&options.molecule_type)
+                .or_else(|| nonempty(&seq.molecule_type))
+                .or_else(|| Some("DNA".to_string()));
+            seq.division = nonempty(&options.division)
+                .map(|division| division.to_ascii_uppercase())
+                .or_else(|| Some(seq.division.trim().to_string()).filter(|d| !d.is_empty()))
+                .unwrap_or_else(|| "SYN".to_string());
+            seq.date = date.or(seq.date.take()).or_else(|| {
+                let seconds = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .ok()?
+                    .as_secs();
+                let (year, month, day) =
+                    crate::workflow_examples::civil_from_unix_days((seconds / 86_400) as i64);
+                gb_io::seq::Date::from_ymd(year, month, day).ok()
+            });
+            if let Some(accession) = nonempty(&options.accession) {
+                seq.accession = Some(accession);
+            }
+            if let Some(definition) = nonempty(&options.definition) {
+                seq.definition = Some(definition);
+            }
+            Ok(())
+        })
+        .map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not write GenBank file '{path}': {e}"),
+            cause_chain: vec![],
+        })
+    }
+
     pub(super) fn save_as_fasta(
         seq_id: &str,
         dna: &DNAsequence,
@@ -2047,6 +2200,9 @@ impl GentleEngine {
             Operation::LoadFile { path, .. } => {
                 Self::push_unique_token(&mut summary.file_paths, path);
             }
+            Operation::ImportDirectory { directory, .. } => {
+                Self::push_unique_token(&mut summary.file_paths, directory);
+            }
             Operation::CreateSequenceFromText { output_id, .. } => {
                 if let Some(output_id) = output_id {
                     Self::push_unique_token(&mut summary.sequence_ids, output_id);
@@ -2057,10 +2213,18 @@ impl GentleEngine {
             | Operation::ExportSequenceContextBundle { seq_id, .. }
             | Operation::RenderDotplotSvg { seq_id, .. }
             | Operation::RenderFeatureExpertSvg { seq_id, .. }
+            | Operation::ExportRestrictionMap { seq_id, .. }
+            | Operation::ExportTrackCsv { seq_id, .. }
+            | Operation::ExportMapLegend { seq_id, .. }
+            | Operation::ExportSequenceStats { seq_id, .. }
+            | Operation::ExportProteome { seq_id, .. }
             | Operation::RenderIsoformArchitectureSvg { seq_id, .. }
             | Operation::RenderRnaStructureSvg { seq_id, .. }
+            | Operation::RenderRnaStructureFromDotBracket { seq_id, .. }
+            | Operation::RenderRnaStructureSvgConstrained { seq_id, .. }
             | Operation::ExtendGenomeAnchor { seq_id, .. }
             | Operation::VerifyGenomeAnchor { seq_id, .. }
+            | Operation::RelocateGenomeAnchor { seq_id, .. }
             | Operation::ImportGenomeBedTrack { seq_id, .. }
             | Operation::ImportGenomeBigWigTrack { seq_id, .. }
             | Operation::ImportGenomeVcfTrack { seq_id, .. }
@@ -2070,6 +2234,8 @@ impl GentleEngine {
             | Operation::ImportBlastHitsTrack { seq_id, .. }
             | Operation::GenerateCandidateSet { seq_id, .. }
             | Operation::GenerateCandidateSetBetweenAnchors { seq_id, .. }
+            | Operation::ImportCandidateSetBed { seq_id, .. }
+            | Operation::ImportCandidateSetCsv { seq_id, .. }
             | Operation::DeriveTranscriptSequences { seq_id, .. }
             | Operation::ComputeDotplot { seq_id, .. }
             | Operation::ComputeFlexibilityTrack { seq_id, .. }
@@ -2077,8 +2243,20 @@ impl GentleEngine {
             | Operation::InterpretRnaReads { seq_id, .. }
             | Operation::PreflightRnaReadIsoforms { seq_id, .. }
             | Operation::SetTopology { seq_id, .. }
+            | Operation::SetSequenceDescription { seq_id, .. }
+            | Operation::TagSequence { seq_id, .. }
             | Operation::RecomputeFeatures { seq_id, .. }
-            | Operation::AnnotateTfbs { seq_id, .. } => {
+            | Operation::EditFeatureQualifier { seq_id, .. }
+            | Operation::AddFeature { seq_id, .. }
+            | Operation::DeleteFeature { seq_id, .. }
+            | Operation::AnnotateTfbs { seq_id, .. }
+            | Operation::AnnotateInvertedRepeats { seq_id, .. }
+            | Operation::AnnotateTandemRepeats { seq_id, .. }
+            | Operation::MaskAmbiguousRegions { seq_id, .. }
+            | Operation::AnnotateSoftMaskedRegions { seq_id, .. }
+            | Operation::AnnotateFromLibrary { seq_id, .. }
+            | Operation::AnnotateMethylationSites { seq_id, .. }
+            | Operation::AnnotateCpgIslands { seq_id, .. } => {
                 Self::push_unique_token(&mut summary.sequence_ids, seq_id);
             }
             Operation::FindRestrictionSites { target, .. }
@@ -2151,6 +2329,9 @@ impl GentleEngine {
                     Self::push_unique_token(&mut summary.arrangement_ids, arrangement_id);
                 }
             }
+            Operation::RenderPlateLayoutSvg { arrangement_id, .. } => {
+                Self::push_unique_token(&mut summary.arrangement_ids, arrangement_id);
+            }
             Operation::CreateArrangementSerial { container_ids, .. }
             | Operation::MergeContainersById { container_ids, .. } => {
                 for container_id in container_ids {
@@ -2167,14 +2348,25 @@ impl GentleEngine {
             | Operation::MergeContainers { inputs, .. }
             | Operation::Ligation { inputs, .. }
             | Operation::FilterByMolecularWeight { inputs, .. }
-            | Operation::FilterByDesignConstraints { inputs, .. } => {
+            | Operation::FilterByDesignConstraints { inputs, .. }
+            | Operation::ApplyAnnotationProfile {
+                seq_ids: inputs, ..
+            }
+            | Operation::MakeConsensus {
+                seq_ids: inputs, ..
+            } => {
                 for seq_id in inputs {
                     Self::push_unique_token(&mut summary.sequence_ids, seq_id);
                 }
             }
+            Operation::AnnotateDifferences { reference, variant } => {
+                Self::push_unique_token(&mut summary.sequence_ids, reference);
+                Self::push_unique_token(&mut summary.sequence_ids, variant);
+            }
             Operation::PrepareGenome { genome_id, .. }
             | Operation::ExtractGenomeRegion { genome_id, .. }
-            | Operation::ExtractGenomeGene { genome_id, .. } => {
+            | Operation::ExtractGenomeGene { genome_id, .. }
+            | Operation::ExtractGenomeGenes { genome_id, .. } => {
                 Self::push_unique_token(&mut summary.genome_ids, genome_id);
             }
             Operation::AssessPrimerPairSpecificity {
@@ -2240,13 +2432,20 @@ impl GentleEngine {
             | Operation::FetchUniprotLinkedGenBank { .. } => {}
             Operation::DigestContainer { container_id, .. }
             | Operation::LigationContainer { container_id, .. }
-            | Operation::FilterContainerByMolecularWeight { container_id, .. } => {
+            | Operation::FilterContainerByMolecularWeight { container_id, .. }
+            | Operation::FilterContainerByGc { container_id, .. } => {
                 Self::push_unique_token(&mut summary.container_ids, container_id);
             }
             Operation::Digest { input, .. }
             | Operation::Pcr {
                 template: input, ..
             }
+            | Operation::NestedPcr {
+                template: input, ..
+            }
+            | Operation::MultiplexPcr {
+                template: input, ..
+            }
             | Operation::PcrAdvanced {
                 template: input, ..
             }
@@ -2273,11 +2472,20 @@ impl GentleEngine {
             | Operation::Reverse { input, .. }
             | Operation::Complement { input, .. }
             | Operation::ReverseComplement { input, .. }
-            | Operation::Branch { input, .. } => {
+            | Operation::PolishEnds { input, .. }
+            | Operation::Dephosphorylate { input, .. }
+            | Operation::EngineerSilentSite { seq_id: input, .. }
+            | Operation::ReplaceSubsequence { seq_id: input, .. }
+            | Operation::Branch { input, .. }
+            | Operation::StripAnnotations { input, .. } => {
                 Self::push_unique_token(&mut summary.sequence_ids, input);
             }
             Operation::DeleteCandidateSet { set_name }
+            | Operation::StreamCandidateSetJsonl { set_name, .. }
             | Operation::ScoreCandidateSetExpression { set_name, .. }
+            | Operation::ScoreCandidateSetMotifCount { set_name, .. }
+            | Operation::ScoreCandidateSetTm { set_name, .. }
+            | Operation::ScoreCandidateSetSelfStructure { set_name, .. }
             | Operation::ScoreCandidateSetDistance { set_name, .. }
             | Operation::ScoreCandidateSetWeightedObjective { set_name, .. } => {
                 Self::push_unique_token(&mut summary.candidate_set_ids, set_name);
@@ -2313,6 +2521,33 @@ impl GentleEngine {
             | Operation::ExportGuideProtocolText { guide_set_id, .. } => {
                 Self::push_unique_token(&mut summary.guide_set_ids, guide_set_id);
             }
+            Operation::GuideSetOp {
+                left_guide_set,
+                right_guide_set,
+                output_guide_set,
+                ..
+            } => {
+                Self::push_unique_token(&mut summary.guide_set_ids, left_guide_set);
+                Self::push_unique_token(&mut summary.guide_set_ids, right_guide_set);
+                Self::push_unique_token(&mut summary.guide_set_ids, output_guide_set);
+            }
+            Operation::RankGuides {
+                guide_set_id,
+                output_guide_set_id,
+                ..
+            } => {
+                Self::push_unique_token(&mut summary.guide_set_ids, guide_set_id);
+                if let Some(output_guide_set_id) = output_guide_set_id {
+                    Self::push_unique_token(&mut summary.guide_set_ids, output_guide_set_id);
+                }
+            }
+            Operation::RenameSequence { from, to } => {
+                Self::push_unique_token(&mut summary.sequence_ids, from);
+                Self::push_unique_token(&mut summary.sequence_ids, to);
+            }
+            Operation::DeleteSequence { seq_id, .. } => {
+                Self::push_unique_token(&mut summary.sequence_ids, seq_id);
+            }
             _ => {}
         }
         if let Operation::RenderIsoformArchitectureSvg {
@@ -2405,14 +2640,30 @@ impl GentleEngine {
         let mut push = |path: &str| Self::push_unique_token(&mut paths, path);
         match op {
             Operation::SaveFile { path, .. }
+            | Operation::ExportAll {
+                directory: path, ..
+            }
+            | Operation::ExportDesignPackage { path, .. }
+            | Operation::RenderThumbnails {
+                directory: path, ..
+            }
             | Operation::RenderSequenceSvg { path, .. }
             | Operation::RenderDotplotSvg { path, .. }
             | Operation::RenderTfbsScoreTracksSvg { path, .. }
             | Operation::RenderFeatureExpertSvg { path, .. }
+            | Operation::ExportRestrictionMap { path, .. }
+            | Operation::ExportTrackCsv { path, .. }
+            | Operation::ExportMapLegend { path, .. }
+            | Operation::ExportSequenceStats { path, .. }
+            | Operation::ExportProteome { path, .. }
+            | Operation::StreamCandidateSetJsonl { path, .. }
             | Operation::RenderIsoformArchitectureSvg { path, .. }
             | Operation::RenderRnaStructureSvg { path, .. }
+            | Operation::RenderRnaStructureFromDotBracket { path, .. }
+            | Operation::RenderRnaStructureSvgConstrained { path, .. }
             | Operation::RenderLineageSvg { path }
             | Operation::RenderPoolGelSvg { path, .. }
+            | Operation::RenderPlateLayoutSvg { path, .. }
             | Operation::RenderProteinGelSvg { path, .. }
             | Operation::RenderProteinGelReportsSvg { path, .. }
             | Operation::RenderProteaseDigestGelSvg { path, .. }
@@ -3088,6 +3339,7 @@ impl GentleEngine {
             final_sequences.push(EngineSequenceSummary {
                 id: seq_id,
                 name: dna.name().clone(),
+                description: dna.definition().map(str::to_string),
                 length: dna.len(),
                 circular: dna.is_circular(),
             });
@@ -3411,6 +3663,8 @@ impl GentleEngine {
                 Some("Lab handoff: restriction-cloning PCR".to_string())
             }
             Operation::Pcr { .. }
+            | Operation::NestedPcr { .. }
+            | Operation::MultiplexPcr { .. }
             | Operation::PcrAdvanced { .. }
             | Operation::PcrMutagenesis { .. } => {
                 Some("Lab handoff: PCR product preparation".to_string())
@@ -3612,7 +3866,7 @@ impl GentleEngine {
 
     fn lab_assistant_steps_for_record(record: &OperationRecord) -> Vec<String> {
         match &record.op {
-            Operation::LoadFile { path, as_id } => vec![format!(
+            Operation::LoadFile { path, as_id, .. } => vec![format!(
                 "Use design input `{}` from `{path}`.",
                 as_id.as_deref().unwrap_or("derived sequence ID")
             )],
@@ -3662,6 +3916,37 @@ impl GentleEngine {
                 Self::lab_oligo_display(reverse_primer),
                 Self::lab_expected_output(output_id.as_deref(), &record.result.created_seq_ids)
             )],
+            Operation::NestedPcr {
+                template,
+                outer_forward,
+                outer_reverse,
+                inner_forward,
+                inner_reverse,
+                output_id,
+            } => vec![
+                format!(
+                    "Run outer PCR from template `{template}` with forward primer `{}` and reverse primer `{}`.",
+                    Self::lab_oligo_display(outer_forward),
+                    Self::lab_oligo_display(outer_reverse)
+                ),
+                format!(
+                    "Re-amplify the outer product with inner forward primer `{}` and inner reverse primer `{}`; expected product ID: `{}`.",
+                    Self::lab_oligo_display(inner_forward),
+                    Self::lab_oligo_display(inner_reverse),
+                    Self::lab_expected_output(output_id.as_deref(), &record.result.created_seq_ids)
+                ),
+            ],
+            Operation::MultiplexPcr {
+                template, primers, ..
+            } => vec![format!(
+                "Run multiplex PCR from template `{template}` with primer pool {}; expected product IDs: `{}`.",
+                primers
+                    .iter()
+                    .map(|primer| format!("`{}`", Self::lab_oligo_display(primer)))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Self::lab_join_or_dash(&record.result.created_seq_ids)
+            )],
             Operation::PcrAdvanced {
                 template,
                 forward_primer,
@@ -3937,12 +4222,6 @@ impl GentleEngine {
             .collect()
     }
 
-    pub(super) fn complement_bytes(seq: &[u8]) -> Vec<u8> {
-        seq.iter()
-            .map(|c| IupacCode::letter_complement(*c))
-            .collect()
-    }
-
     pub(super) fn normalize_dna_text(seq: &str) -> String {
         let cleaned = DNAsequence::validate_dna_sequence(seq.as_bytes());
         String::from_utf8_lossy(&cleaned).to_string()
@@ -4635,9 +4914,13 @@ impl GentleEngine {
         String::from_utf8(out).unwrap_or_default()
     }
 
+    /// Expand a degenerate primer. `Sample` mode draws from `SeededRng`
+    /// seeded with `spec.sample_seed` when given, which takes precedence over
+    /// the project's `random_seed`.
     pub(super) fn expand_primer_variants(
         spec: &PcrPrimerSpec,
         cap: usize,
+        project_seed: u64,
     ) -> Result<Vec<String>, EngineError> {
         let normalized = Self::normalize_iupac_text(&spec.sequence)?;
         if normalized.is_empty() {
@@ -4676,13 +4959,10 @@ impl GentleEngine {
                 let target = max_variants.min(total);
                 let mut chosen: Vec<usize> = Vec::with_capacity(target);
                 let mut seen: HashSet<usize> = HashSet::with_capacity(target * 2);
-                let mut state = spec.sample_seed.unwrap_or(0x9E3779B97F4A7C15);
+                let mut rng = SeededRng::new(spec.sample_seed.unwrap_or(project_seed));
 
                 while chosen.len() < target {
-                    state = state
-                        .wrapping_mul(6364136223846793005)
-                        .wrapping_add(1442695040888963407);
-                    let idx = (state as usize) % total;
+                    let idx = rng.below(total);
                     if seen.insert(idx) {
                         chosen.push(idx);
                     }
@@ -4733,17 +5013,25 @@ impl GentleEngine {
     }
 
     pub(crate) fn estimate_primer_tm_c(primer: &[u8]) -> f64 {
+        Self::estimate_primer_tm_with_model_c(primer).0
+    }
+
+    /// Same estimate as [`Self::estimate_primer_tm_c`], also reporting whether
+    /// the nearest-neighbor model was used (`false` means the 2/4 fallback).
+    pub(crate) fn estimate_primer_tm_with_model_c(primer: &[u8]) -> (f64, bool) {
         if primer.is_empty() {
-            return 0.0;
+            return (0.0, false);
         }
         let Some(canonical) = Self::canonical_dna_bases(primer) else {
-            return Self::estimate_primer_tm_wallace_c(primer);
+            return (Self::estimate_primer_tm_wallace_c(primer), false);
         };
         if canonical.len() < 8 {
-            return Self::estimate_primer_tm_wallace_c(&canonical);
+            return (Self::estimate_primer_tm_wallace_c(&canonical), false);
+        }
+        match Self::estimate_primer_tm_nearest_neighbor_c(&canonical) {
+            Some(tm) => (tm, true),
+            None => (Self::estimate_primer_tm_wallace_c(&canonical), false),
         }
-        Self::estimate_primer_tm_nearest_neighbor_c(&canonical)
-            .unwrap_or_else(|| Self::estimate_primer_tm_wallace_c(&canonical))
     }
 
     fn canonical_dna_bases(primer: &[u8]) -> Option<Vec<u8>> {
@@ -7132,50 +7420,215 @@ impl GentleEngine {
         ))
     }
 
-    pub(super) fn right_end_overhangs(dna: &DNAsequence) -> Vec<Vec<u8>> {
-        let mut ret = Vec::new();
-        if !dna.overhang().forward_3.is_empty() {
-            ret.push(dna.overhang().forward_3.clone());
-        }
-        if !dna.overhang().reverse_5.is_empty() {
-            ret.push(dna.overhang().reverse_5.clone());
+    /// Whether `left`'s right end and `right`'s left end join under `protocol`.
+    pub(super) fn ligation_ends_compatible(
+        left: &DNAsequence,
+        right: &DNAsequence,
+        protocol: &LigationProtocol,
+    ) -> bool {
+        let left_end = left.ends().right;
+        let right_end = right.ends().left;
+        let sticky = !left_end.is_blunt();
+        match protocol {
+            LigationProtocol::Sticky => sticky && left_end.ligates_to(&right_end),
+            LigationProtocol::Blunt => !sticky && left_end.ligates_to(&right_end),
+        }
+    }
+
+    /// Top-strand bases restored at a ligation junction: the annealed overhang
+    /// (empty for blunt joins).
+    pub(super) fn ligation_junction_bases(
+        left_end: &SequenceEnd,
+        right_end: &SequenceEnd,
+    ) -> Vec<u8> {
+        match left_end.polarity {
+            OverhangPolarity::Blunt => vec![],
+            OverhangPolarity::FivePrime => right_end.overhang.clone(),
+            OverhangPolarity::ThreePrime => left_end.overhang.clone(),
+        }
+    }
+
+    /// Junction formed by sealing `left_end` (right end of `left_seq_id`) to
+    /// `right_end` (left end of `right_seq_id`).
+    pub(super) fn ligation_junction(
+        left_seq_id: &str,
+        left_end: &SequenceEnd,
+        right_seq_id: &str,
+        right_end: &SequenceEnd,
+    ) -> LigationJunction {
+        LigationJunction {
+            left_seq_id: left_seq_id.to_string(),
+            right_seq_id: right_seq_id.to_string(),
+            kind: if left_end.is_blunt() {
+                LigationProtocol::Blunt
+            } else {
+                LigationProtocol::Sticky
+            },
+            overhang: String::from_utf8_lossy(&Self::ligation_junction_bases(left_end, right_end))
+                .to_string(),
         }
-        ret
     }
 
-    pub(super) fn left_end_overhangs(dna: &DNAsequence) -> Vec<Vec<u8>> {
-        let mut ret = Vec::new();
-        if !dna.overhang().forward_5.is_empty() {
-            ret.push(dna.overhang().forward_5.clone());
-        }
-        if !dna.overhang().reverse_3.is_empty() {
-            ret.push(dna.overhang().reverse_3.clone());
+    /// Marker for `junction` after `left_len` bp of a `product_len` bp
+    /// product: the annealed overhang for sticky joins, the two flanking bases
+    /// for blunt joins. On a circular product the blunt closing junction
+    /// (`left_len == product_len`) wraps across the origin.
+    pub(super) fn ligation_junction_marker(
+        junction: LigationJunction,
+        left_len: usize,
+        product_len: usize,
+    ) -> LigationJunctionMarker {
+        let (start, raw_end) = match junction.kind {
+            LigationProtocol::Sticky => (left_len, left_len + junction.overhang.len()),
+            LigationProtocol::Blunt => (left_len.saturating_sub(1), left_len + 1),
+        };
+        let wraps_origin = raw_end > product_len;
+        LigationJunctionMarker {
+            junction,
+            start_0based: start,
+            end_0based_exclusive: if wraps_origin {
+                raw_end - product_len
+            } else {
+                raw_end
+            },
+            wraps_origin,
         }
-        ret
     }
 
-    pub(super) fn right_end_is_blunt(dna: &DNAsequence) -> bool {
-        dna.overhang().forward_3.is_empty() && dna.overhang().reverse_5.is_empty()
+    /// Generated `misc_feature` over a ligation junction marker.
+    pub(super) fn ligation_junction_feature(
+        marker: &LigationJunctionMarker,
+        product_len: usize,
+    ) -> gb_io::seq::Feature {
+        let junction = &marker.junction;
+        let ends = match junction.kind {
+            LigationProtocol::Sticky => format!("sticky {}", junction.overhang),
+            LigationProtocol::Blunt => "blunt".to_string(),
+        };
+        let start = marker.start_0based as i64;
+        let end = marker.end_0based_exclusive as i64;
+        let location = if marker.wraps_origin {
+            gb_io::seq::Location::Join(vec![
+                gb_io::seq::Location::simple_range(start, product_len as i64),
+                gb_io::seq::Location::simple_range(0, end),
+            ])
+        } else {
+            gb_io::seq::Location::simple_range(start, end)
+        };
+        gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location,
+            qualifiers: vec![
+                ("label".into(), Some("ligation junction".to_string())),
+                (
+                    "note".into(),
+                    Some(format!(
+                        "'{}' + '{}' ({ends})",
+                        junction.left_seq_id, junction.right_seq_id
+                    )),
+                ),
+                (
+                    "gentle_generated".into(),
+                    Some("ligation_junction".to_string()),
+                ),
+            ],
+        }
     }
 
-    pub(super) fn left_end_is_blunt(dna: &DNAsequence) -> bool {
-        dna.overhang().forward_5.is_empty() && dna.overhang().reverse_3.is_empty()
-    }
+    /// Exact-match amplicons for one primer pair on a linear template, as
+    /// `(start, end, reverse_complemented)` top-strand intervals sorted by
+    /// position. Errors when a primer does not anneal or no pair faces inward.
+    pub(super) fn exact_pcr_amplicons(
+        template_seq: &str,
+        forward_primer: &str,
+        reverse_primer: &str,
+    ) -> Result<Vec<(usize, usize, bool)>, EngineError> {
+        let template_bytes = template_seq.as_bytes();
+        let fwd = Self::normalize_dna_text(forward_primer);
+        let rev = Self::normalize_dna_text(reverse_primer);
+        if fwd.is_empty() || rev.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "PCR primers must not be empty".to_string(),
 
-    pub(super) fn sticky_compatible(left: &DNAsequence, right: &DNAsequence) -> bool {
-        let right_opts = Self::right_end_overhangs(left);
-        let left_opts = Self::left_end_overhangs(right);
-        if right_opts.is_empty() || left_opts.is_empty() {
-            return false;
+                cause_chain: vec![],
+            });
         }
-        for r in &right_opts {
-            let rc_r = Self::reverse_complement_bytes(r);
-            let c_r = Self::complement_bytes(r);
-            if left_opts.iter().any(|l| *l == rc_r || *l == c_r) {
-                return true;
-            }
+
+        // Either primer may anneal to the bottom strand (reported
+        // orientation) or, for swapped templates, to the top strand.
+        // Products are always written forward-primer first.
+        let fwd_binding = Self::reverse_complement(&fwd);
+        let rev_binding = Self::reverse_complement(&rev);
+        let fwd_top = Self::find_all_subsequences(template_bytes, fwd.as_bytes());
+        let fwd_bottom = Self::find_all_subsequences(template_bytes, fwd_binding.as_bytes());
+        let rev_top = Self::find_all_subsequences(template_bytes, rev.as_bytes());
+        let rev_bottom = Self::find_all_subsequences(template_bytes, rev_binding.as_bytes());
+        if fwd_top.is_empty() && fwd_bottom.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Forward primer not found on either template strand".to_string(),
+
+                cause_chain: vec![],
+            });
+        }
+        if rev_top.is_empty() && rev_bottom.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Reverse primer binding site not found on either template strand"
+                    .to_string(),
+
+                cause_chain: vec![],
+            });
+        }
+
+        // (start, end, reverse_complemented) on the template top strand.
+        let mut amplicon_ranges: Vec<(usize, usize, bool)> = vec![];
+        let mut facing_away = false;
+        let mut collect =
+            |left_sites: &[usize], right_sites: &[usize], right_len: usize, reversed: bool| {
+                for left_pos in left_sites {
+                    for right_pos in right_sites {
+                        let amplicon_end = right_pos + right_len;
+                        if *right_pos < *left_pos || amplicon_end > template_seq.len() {
+                            facing_away = true;
+                            continue;
+                        }
+                        amplicon_ranges.push((*left_pos, amplicon_end, reversed));
+                    }
+                }
+            };
+        collect(&fwd_top, &rev_bottom, rev_binding.len(), false);
+        collect(&rev_top, &fwd_bottom, fwd_binding.len(), true);
+        amplicon_ranges.sort_unstable();
+        // Both orientations of one template interval are the same duplex.
+        amplicon_ranges.dedup_by_key(|(start, end, _)| (*start, *end));
+
+        if amplicon_ranges.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: if facing_away {
+                    "PCR primers face away from each other; no amplicon is produced".to_string()
+                } else {
+                    "Forward and reverse primers anneal to the same template strand; no amplicon is produced"
+                        .to_string()
+                },
+
+                cause_chain: vec![],
+            });
+        }
+        Ok(amplicon_ranges)
+    }
+
+    /// Top-strand bases of one amplicon, reverse-complemented when the
+    /// forward primer annealed to the top strand's complement.
+    pub(super) fn pcr_amplicon_text(template_seq: &str, amplicon: (usize, usize, bool)) -> String {
+        let (start, end, reversed) = amplicon;
+        if reversed {
+            Self::reverse_complement(&template_seq[start..end])
+        } else {
+            template_seq[start..end].to_string()
         }
-        false
     }
 
     pub(super) fn find_anneal_sites(
@@ -0,0 +1,139 @@
+//! Free-form sequence tags.
+//!
+//! `TagSequence` keeps a set of tags per `seq_id` in project metadata, for
+//! cross-cutting organization that containers (which model physical or
+//! derivation groupings) do not cover. Tags are trimmed and lowercased so
+//! `Backbone` and ` backbone ` are the same tag.
+
+use super::*;
+
+impl GentleEngine {
+    fn normalize_sequence_tag(raw: &str) -> Result<String, EngineError> {
+        let tag = raw.trim().to_lowercase();
+        if tag.is_empty() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: "Sequence tags cannot be empty".to_string(),
+                cause_chain: vec![],
+            });
+        }
+        Ok(tag)
+    }
+
+    fn read_sequence_tag_store(&self) -> SequenceTagStore {
+        let mut store = self
+            .state
+            .metadata
+            .get(SEQUENCE_TAGS_METADATA_KEY)
+            .cloned()
+            .and_then(|v| serde_json::from_value::<SequenceTagStore>(v).ok())
+            .unwrap_or_default();
+        if store.schema.trim().is_empty() {
+            store.schema = SEQUENCE_TAGS_SCHEMA.to_string();
+        }
+        store
+    }
+
+    fn write_sequence_tag_store(&mut self, mut store: SequenceTagStore) -> Result<(), EngineError> {
+        store.tags.retain(|_, tags| !tags.is_empty());
+        if store.tags.is_empty() {
+            self.state.metadata.remove(SEQUENCE_TAGS_METADATA_KEY);
+            return Ok(());
+        }
+        store.schema = SEQUENCE_TAGS_SCHEMA.to_string();
+        store.updated_at_unix_ms = Self::now_unix_ms();
+        let value = serde_json::to_value(store).map_err(|e| EngineError {
+            code: ErrorCode::Internal,
+            message: format!("Could not serialize sequence tag metadata: {e}"),
+            cause_chain: vec![],
+        })?;
+        self.state
+            .metadata
+            .insert(SEQUENCE_TAGS_METADATA_KEY.to_string(), value);
+        Ok(())
+    }
+
+    /// Tags of `seq_id`, sorted; empty for untagged or unknown sequences.
+    pub fn sequence_tags(&self, seq_id: &str) -> Vec<String> {
+        self.read_sequence_tag_store()
+            .tags
+            .remove(seq_id)
+            .map(|tags| tags.into_iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Sequences carrying `tag` (normalized like `TagSequence`), sorted by id.
+    pub fn sequences_with_tag(&self, tag: &str) -> Vec<SeqId> {
+        let tag = tag.trim().to_lowercase();
+        self.read_sequence_tag_store()
+            .tags
+            .into_iter()
+            .filter(|(seq_id, tags)| {
+                tags.contains(&tag) && self.state.sequences.contains_key(seq_id)
+            })
+            .map(|(seq_id, _)| seq_id)
+            .collect()
+    }
+
+    /// Re-key the tags of `from` to `to`, or drop them when `to` is `None`.
+    pub(super) fn move_sequence_tags(
+        &mut self,
+        from: &str,
+        to: Option<&str>,
+    ) -> Result<(), EngineError> {
+        let mut store = self.read_sequence_tag_store();
+        let Some(tags) = store.tags.remove(from) else {
+            return Ok(());
+        };
+        if let Some(to) = to {
+            store.tags.insert(to.to_string(), tags);
+        }
+        self.write_sequence_tag_store(store)
+    }
+
+    pub(super) fn tag_sequence(
+        &mut self,
+        result: &mut OpResult,
+        seq_id: &str,
+        add: &[String],
+        remove: &[String],
+    ) -> Result<(), EngineError> {
+        if !self.state.sequences.contains_key(seq_id) {
+            return Err(EngineError {
+                code: ErrorCode::NotFound,
+                message: format!("Sequence '{seq_id}' not found"),
+                cause_chain: vec![],
+            });
+        }
+        let add = add
+            .iter()
+            .map(|tag| Self::normalize_sequence_tag(tag))
+            .collect::<Result<BTreeSet<_>, _>>()?;
+        let remove = remove
+            .iter()
+            .map(|tag| Self::normalize_sequence_tag(tag))
+            .collect::<Result<BTreeSet<_>, _>>()?;
+        if let Some(tag) = add.intersection(&remove).next() {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!("TagSequence cannot both add and remove tag '{tag}'"),
+                cause_chain: vec![],
+            });
+        }
+
+        let mut store = self.read_sequence_tag_store();
+        let tags = store.tags.entry(seq_id.to_string()).or_default();
+        let added = add
+            .into_iter()
+            .filter(|tag| tags.insert(tag.clone()))
+            .count();
+        let removed = remove.iter().filter(|tag| tags.remove(*tag)).count();
+        let current = tags.iter().cloned().collect::<Vec<_>>();
+        self.write_sequence_tag_store(store)?;
+        result.messages.push(format!(
+            "Tagged '{seq_id}': {added} added, {removed} removed; tags now [{}]",
+            current.join(", ")
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Whole-engine session files.
+//!
+//! `ProjectState::save_to_path` stores only project state; undo/redo
+//! checkpoints stay session-local. `save_session` additionally writes the
+//! journal, the op counter, and the most recent undo/redo checkpoints so a
+//! reloaded engine can keep undoing where the previous process stopped.
+
+use super::*;
+
+const ENGINE_SESSION_SCHEMA: &str = "gentle.engine_session.v1";
+/// Checkpoints kept per stack in a session file; each one is a full
+/// `ProjectState` copy, so the on-disk history is capped tighter than the
+/// in-memory `history_limit`.
+const ENGINE_SESSION_MAX_CHECKPOINTS: usize = 32;
+
+#[derive(Serialize, Deserialize)]
+struct EngineSessionFile {
+    schema: String,
+    state: ProjectState,
+    journal: Vec<OperationRecord>,
+    op_counter: u64,
+    history_limit: usize,
+    #[serde(default)]
+    undo_stack: Vec<EngineHistoryCheckpoint>,
+    #[serde(default)]
+    redo_stack: Vec<EngineHistoryCheckpoint>,
+}
+
+impl GentleEngine {
+    fn session_checkpoint_tail(stack: &[EngineHistoryCheckpoint]) -> Vec<EngineHistoryCheckpoint> {
+        stack[stack.len().saturating_sub(ENGINE_SESSION_MAX_CHECKPOINTS)..].to_vec()
+    }
+
+    /// Persist state, journal, op counter, and bounded undo/redo history.
+    pub fn save_session(&self, path: &str) -> Result<(), EngineError> {
+        let session = EngineSessionFile {
+            schema: ENGINE_SESSION_SCHEMA.to_string(),
+            state: self.state.clone(),
+            journal: self.journal.clone(),
+            op_counter: self.op_counter,
+            history_limit: self.history_limit_or_default(),
+            undo_stack: Self::session_checkpoint_tail(&self.undo_stack),
+            redo_stack: Self::session_checkpoint_tail(&self.redo_stack),
+        };
+        let text = serde_json::to_string(&session).map_err(|e| EngineError {
+            code: ErrorCode::Internal,
+            message: format!("Could not serialize engine session: {e}"),
+            cause_chain: vec![],
+        })?;
+        ProjectState::write_text_file_atomically(Path::new(path), &text)
+    }
+
+    /// Restore an engine written by `save_session`, including its undo/redo
+    /// history.
+    pub fn load_session(path: &str) -> Result<Self, EngineError> {
+        let text = std::fs::read_to_string(path).map_err(|e| EngineError {
+            code: ErrorCode::Io,
+            message: format!("Could not read session file '{path}': {e}"),
+            cause_chain: vec![],
+        })?;
+        let session: EngineSessionFile = serde_json::from_str(&text).map_err(|e| EngineError {
+            code: ErrorCode::InvalidInput,
+            message: format!("Could not parse session JSON '{path}': {e}"),
+            cause_chain: vec![],
+        })?;
+        if session.schema != ENGINE_SESSION_SCHEMA {
+            return Err(EngineError {
+                code: ErrorCode::InvalidInput,
+                message: format!(
+                    "Unsupported session schema '{}' in '{path}' (expected '{ENGINE_SESSION_SCHEMA}')",
+                    session.schema
+                ),
+                cause_chain: vec![],
+            });
+        }
+        let mut engine = Self::from_state(session.state);
+        engine.journal = session.journal;
+        engine.op_counter = engine.op_counter.max(session.op_counter);
+        engine.history_limit = session.history_limit;
+        engine.undo_stack = session.undo_stack;
+        engine.redo_stack = session.redo_stack;
+        Ok(engine)
+    }
+}
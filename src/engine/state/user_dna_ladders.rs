@@ -0,0 +1,215 @@
+//! Project-defined DNA ladders stored alongside the built-in catalog.
+//!
+//! User ladders live in `ProjectState::metadata` and are merged into the
+//! built-in catalog whenever gels are rendered or ladders are inspected, so
+//! they can be referenced by name exactly like the shipped ladders.
+
+use super::*;
+use crate::dna_ladder::{Ladder, LadderCatalog};
+
+impl GentleEngine {
+    pub(super) fn read_user_dna_ladders(&self) -> Vec<DnaLadderInfo> {
+        self.state
+            .metadata
+            .get(USER_DNA_LADDERS_METADATA_KEY)
+            .cloned()
+            .and_then(|v| serde_json::from_value::<DnaLadderCatalog>(v).ok())
+            .map(|catalog| catalog.ladders)
+            .unwrap_or_default()
+    }
+
+    fn write_user_dna_ladders(
+        &mut self,
+        mut ladders: Vec<DnaLadderInfo>,
+    ) -> Result<(), EngineError> {
+        if ladders.is_empty() {
+            self.state.metadata.remove(USER_DNA_LADDERS_METADATA_KEY);
+            return Ok(());
+        }
+        ladders.sort_by(|a, b| a.name.cmp(&b.name));
+        let catalog = DnaLadderCatalog {
+            schema: USER_DNA_LADDERS_SCHEMA.to_string(),
+            ladder_count: ladders.len(),
+            ladders,
+        };
+        let value = serde_json::to_value(catalog).map_err(|e| EngineError {
+            code: ErrorCode::Internal,
+            message: format!("Could not serialize user DNA ladders: {e}"),
+
+            cause_chain: vec![],
+        })?;
+        self.state
+            .metadata
+            .insert(USER_DNA_LADDERS_METADATA_KEY.to_string(), value);
+        Ok(())
+    }
+
+    /// Built-in DNA ladders extended with the ladders defined in this project.
+    pub fn project_dna_ladder_catalog(&self) -> LadderCatalog {
+        let mut catalog = DNA_LADDERS.clone();
+        for info in self.read_user_dna_ladders() {
+            catalog.insert(Ladder::from_bands(
+                &info.name,
+                info.loading_hint,
+                info.bands
+                    .iter()
+                    .map(|band| (band.length_bp, band.relative_strength)),
+            ));
+        }
+        catalog
+    }
+
+    pub(super) fn summarize_dna_ladder_catalog(
+        catalog: &LadderCatalog,
+        name_filter: Option<&str>,
+    ) -> DnaLadderCatalog {
+        let filter = name_filter
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_ascii_lowercase());
+
+        let mut ladders: Vec<DnaLadderInfo> = vec![];
+        for name in catalog.names_sorted() {
+            if let Some(filter_text) = &filter
+                && !name.to_ascii_lowercase().contains(filter_text)
+            {
+                continue;
+            }
+            let Some(ladder) = catalog.get(&name) else {
+                continue;
+            };
+            let bands = ladder
+                .bands()
+                .iter()
+                .map(|band| DnaLadderBandInfo {
+                    length_bp: band.length_bp(),
+                    relative_strength: band.relative_strength,
+                })
+                .collect::<Vec<_>>();
+            ladders.push(DnaLadderInfo {
+                name,
+                loading_hint: ladder.loading_hint(),
+                min_bp: ladder.min_bp(),
+                max_bp: ladder.max_bp(),
+                band_count: bands.len(),
+                bands,
+            });
+        }
+
+        DnaLadderCatalog {
+            schema: "gentle.dna_ladders.v1".to_string(),
+            ladder_count: ladders.len(),
+            ladders,
+        }
+    }
+
+    fn validate_user_dna_ladder(
+        name: &str,
+        bands: &[DnaLadderBandInfo],
+        loading_hint: Option<f64>,
+    ) -> Result<(), EngineError> {
+        let invalid = |message: String| EngineError {
+            code: ErrorCode::InvalidInput,
+            message,
+
+            cause_chain: vec![],
+        };
+        if name.is_empty() {
+            return Err(invalid(
+                "DefineDnaLadder requires a non-empty name".to_string(),
+            ));
+        }
+        if DNA_LADDERS
+            .names_sorted()
+            .iter()
+            .any(|builtin| builtin.eq_ignore_ascii_case(name))
+        {
+            return Err(invalid(format!(
+                "DNA ladder '{name}' is built in and cannot be redefined"
+            )));
+        }
+        if bands.is_empty() {
+            return Err(invalid(format!(
+                "DNA ladder '{name}' needs at least one band"
+            )));
+        }
+        for (idx, band) in bands.iter().enumerate() {
+            if !band.length_bp.is_finite() || band.length_bp <= 0.0 {
+                return Err(invalid(format!(
+                    "Band {} of DNA ladder '{name}' must have a positive length (got {})",
+                    idx + 1,
+                    band.length_bp
+                )));
+            }
+            if let Some(strength) = band.relative_strength
+                && (!strength.is_finite() || strength <= 0.0)
+            {
+                return Err(invalid(format!(
+                    "Band {} of DNA ladder '{name}' must have a positive relative_strength (got {strength})",
+                    idx + 1
+                )));
+            }
+        }
+        let ascending = bands
+            .windows(2)
+            .all(|pair| pair[0].length_bp < pair[1].length_bp);
+        let descending = bands
+            .windows(2)
+            .all(|pair| pair[0].length_bp > pair[1].length_bp);
+        if !ascending && !descending {
+            return Err(invalid(format!(
+                "Bands of DNA ladder '{name}' must be strictly sorted by length"
+            )));
+        }
+        if let Some(hint) = loading_hint
+            && (!hint.is_finite() || hint <= 0.0)
+        {
+            return Err(invalid(format!(
+                "loading_hint of DNA ladder '{name}' must be positive (got {hint})"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Register (or replace) one project-defined DNA ladder.
+    pub(super) fn define_dna_ladder(
+        &mut self,
+        result: &mut OpResult,
+        name: &str,
+        bands: &[DnaLadderBandInfo],
+        loading_hint: Option<f64>,
+    ) -> Result<(), EngineError> {
+        let name = name.trim();
+        Self::validate_user_dna_ladder(name, bands, loading_hint)?;
+        let mut ladders = self.read_user_dna_ladders();
+        let replaced = ladders.iter().any(|ladder| ladder.name == name);
+        ladders.retain(|ladder| ladder.name != name);
+        let bands = bands.to_vec();
+        let band_count = bands.len();
+        let min_bp = bands
+            .iter()
+            .map(|band| band.length_bp.round() as usize)
+            .min();
+        let max_bp = bands
+            .iter()
+            .map(|band| band.length_bp.round() as usize)
+            .max();
+        ladders.push(DnaLadderInfo {
+            name: name.to_string(),
+            loading_hint,
+            min_bp,
+            max_bp,
+            band_count,
+            bands,
+        });
+        self.write_user_dna_ladders(ladders)?;
+        result.messages.push(format!(
+            "{} DNA ladder '{name}' ({} band(s), {}-{} bp)",
+            if replaced { "Redefined" } else { "Defined" },
+            band_count,
+            min_bp.unwrap_or(0),
+            max_bp.unwrap_or(0)
+        ));
+        Ok(())
+    }
+}
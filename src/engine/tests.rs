@@ -2270,6 +2270,247 @@ fn render_probe_region_evidence_svg_uses_report_frame_without_local_alignment_wa
     assert!(!svg.contains("report-local"));
 }
 
+#[test]
+fn test_load_file_warns_when_content_duplicates_existing_sequence() {
+    let td = tempdir().unwrap();
+    let fasta_path = td.path().join("dup.fa");
+    fs::write(&fasta_path, ">dup\nacgtacgtacgtTTGG\n").unwrap();
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("existing".to_string(), seq("ACGTACGTACGTTTGG"));
+    let mut engine = GentleEngine::from_state(state);
+
+    let result = engine
+        .apply(Operation::LoadFile {
+            path: fasta_path.to_string_lossy().to_string(),
+            as_id: Some("dup".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    assert!(
+        result.warnings.iter().any(|w| w
+            .message
+            .contains("duplicates existing sequence 'existing'")),
+        "warnings were: {:?}",
+        result.warnings
+    );
+    assert_eq!(
+        engine.sequence_fingerprint("dup").unwrap(),
+        engine.sequence_fingerprint("existing").unwrap()
+    );
+    assert_eq!(
+        engine.sequence_fingerprint("missing").unwrap_err().code,
+        ErrorCode::NotFound
+    );
+}
+
+#[test]
+fn test_load_file_warns_when_genbank_locus_length_disagrees() {
+    let td = tempdir().unwrap();
+    let gb_path = td.path().join("truncated.gb");
+    fs::write(
+        &gb_path,
+        "\
+LOCUS       TRUNC                   40 bp    DNA     linear   SYN 01-JAN-2000
+DEFINITION  Truncated download.
+ORIGIN
+        1 acgtacgtac gtacgtacgt
+//
+",
+    )
+    .unwrap();
+    let mut engine = GentleEngine::new();
+    let result = engine
+        .apply(Operation::LoadFile {
+            path: gb_path.to_string_lossy().to_string(),
+            as_id: Some("trunc".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    let mismatch = result
+        .warnings
+        .iter()
+        .find(|w| w.code == WarningCode::DeclaredLengthMismatch)
+        .unwrap_or_else(|| panic!("warnings were: {:?}", result.warnings));
+    assert!(
+        mismatch
+            .message
+            .contains("declares 40 bp but contains 20 bp")
+    );
+    assert_eq!(mismatch.context["declared_bp"], 40);
+    assert_eq!(mismatch.context["parsed_bp"], 20);
+    assert_eq!(engine.state().sequences["trunc"].len(), 20);
+
+    let ok_path = td.path().join("complete.gb");
+    fs::write(
+        &ok_path,
+        "\
+LOCUS       COMPLETE                20 bp    DNA     linear   SYN 01-JAN-2000
+ORIGIN
+        1 ttgcatgcaa ccggttaacc
+//
+",
+    )
+    .unwrap();
+    let result = engine
+        .apply(Operation::LoadFile {
+            path: ok_path.to_string_lossy().to_string(),
+            as_id: None,
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    assert!(
+        !result
+            .warnings
+            .iter()
+            .any(|w| w.code == WarningCode::DeclaredLengthMismatch)
+    );
+}
+
+#[test]
+fn test_load_file_keeps_soft_masked_runs_unless_uppercased() {
+    let td = tempdir().unwrap();
+    let fasta_path = td.path().join("masked.fa");
+    fs::write(&fasta_path, ">masked\nACGTgaattcacgtACGTGAATTCACaa\n").unwrap();
+    let path = fasta_path.to_string_lossy().to_string();
+    let mut engine = GentleEngine::new();
+    let reports_runs = |result: &OpResult| {
+        result
+            .messages
+            .iter()
+            .any(|m| m.contains("Kept 2 soft-masked run(s) (12 bp)"))
+    };
+    let result = engine
+        .apply(Operation::LoadFile {
+            path: path.clone(),
+            as_id: Some("masked".to_string()),
+            skip_duplicates: None,
+            uppercase: Some(false),
+        })
+        .unwrap();
+    assert!(reports_runs(&result));
+    let plain_result = engine
+        .apply(Operation::LoadFile {
+            path: path.clone(),
+            as_id: Some("plain".to_string()),
+            skip_duplicates: None,
+            uppercase: Some(true),
+        })
+        .unwrap();
+    assert!(!reports_runs(&plain_result));
+    // Unset behaves like `uppercase=false`.
+    let default_result = engine
+        .apply(Operation::LoadFile {
+            path,
+            as_id: Some("as_read".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    assert!(reports_runs(&default_result));
+
+    let masked = &engine.state().sequences["masked"];
+    assert_eq!(
+        engine.state().sequences["as_read"].get_forward_string(),
+        masked.get_forward_string()
+    );
+    assert_eq!(masked.soft_masked_ranges(), vec![4..14, 26..28]);
+    let plain = &engine.state().sequences["plain"];
+    assert!(plain.soft_masked_ranges().is_empty());
+    assert_eq!(
+        plain.get_forward_string(),
+        masked.get_forward_string().to_ascii_uppercase()
+    );
+
+    let ecori_offsets = |dna: &DNAsequence| {
+        let mut offsets = dna
+            .restriction_enzyme_sites()
+            .iter()
+            .filter(|site| site.enzyme.name == "EcoRI")
+            .map(|site| site.offset)
+            .collect::<Vec<_>>();
+        offsets.sort();
+        offsets
+    };
+    assert_eq!(ecori_offsets(masked).len(), 2);
+    assert_eq!(ecori_offsets(masked), ecori_offsets(plain));
+
+    engine
+        .apply(Operation::AnnotateSoftMaskedRegions {
+            seq_id: "masked".to_string(),
+            clear_existing: None,
+        })
+        .unwrap();
+    let labels = engine.state().sequences["masked"]
+        .features()
+        .iter()
+        .filter(|f| {
+            GentleEngine::feature_qualifier_text(f, "gentle_generated").as_deref()
+                == Some("soft_masked_region")
+        })
+        .filter_map(|f| GentleEngine::feature_qualifier_text(f, "label"))
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["soft-masked 10 bp", "soft-masked 2 bp"]);
+}
+
+#[test]
+fn test_load_file_skip_duplicates_controls_repeated_fasta_import() {
+    let td = tempdir().unwrap();
+    let fasta_path = td.path().join("repeat.fa");
+    fs::write(&fasta_path, ">repeat\nATGCATGCATGCATGC\n").unwrap();
+    let path = fasta_path.to_string_lossy().to_string();
+    let mut engine = GentleEngine::new();
+
+    let first = engine
+        .apply(Operation::LoadFile {
+            path: path.clone(),
+            as_id: Some("repeat".to_string()),
+            skip_duplicates: Some(true),
+            uppercase: None,
+        })
+        .unwrap();
+    assert_eq!(first.created_seq_ids, vec!["repeat".to_string()]);
+    assert!(first.warnings.is_empty());
+
+    let skipped = engine
+        .apply(Operation::LoadFile {
+            path: path.clone(),
+            as_id: Some("repeat".to_string()),
+            skip_duplicates: Some(true),
+            uppercase: None,
+        })
+        .unwrap();
+    assert!(skipped.created_seq_ids.is_empty());
+    assert!(
+        skipped
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("Skipped loading") && w.message.contains("'repeat'"))
+    );
+    assert_eq!(engine.state().sequences.len(), 1);
+
+    let loaded_again = engine
+        .apply(Operation::LoadFile {
+            path,
+            as_id: Some("repeat".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    assert_eq!(loaded_again.created_seq_ids, vec!["repeat_2".to_string()]);
+    assert!(
+        loaded_again
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("duplicates existing sequence 'repeat'"))
+    );
+    assert_eq!(engine.state().sequences.len(), 2);
+}
+
 #[test]
 fn project_microarray_track_uses_vendor_subset_on_tp73_genbank_anchor() {
     let mut engine = GentleEngine::default();
@@ -2277,6 +2518,8 @@ fn project_microarray_track_uses_vendor_subset_on_tp73_genbank_anchor() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73_clariomd_subset".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load TP73 GenBank locus");
     let result = engine
@@ -3511,12 +3754,15 @@ fn assert_fasta_roundtrip(expected: DNAsequence) {
             seq_id: "synth".to_string(),
             path: path_text.clone(),
             format: ExportFormat::Fasta,
+            genbank_options: None,
         })
         .unwrap();
     engine
         .apply(Operation::LoadFile {
             path: path_text,
             as_id: Some("roundtrip".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
 
@@ -3596,6 +3842,36 @@ exit 2
     script_path.display().to_string()
 }
 
+#[cfg(unix)]
+fn install_fake_constrained_rnafold(path: &Path) -> String {
+    let script_path = path.join("fake_constrained_rnafold.sh");
+    let script = r#"#!/bin/sh
+if [ "$1" = "--noPS" ] && [ "$2" = "-C" ] && [ "$3" = "--enforceConstraint" ]; then
+  read seq
+  read constraint
+  echo "$seq"
+  echo "$(echo "$constraint" | tr 'x' '.') ( -3.40)"
+  exit 0
+fi
+if [ "$1" = "--noPS" ] && [ "$#" -eq 1 ]; then
+  read seq
+  echo "$seq"
+  echo "$(echo "$seq" | tr 'ACGU' '....') ( 0.00)"
+  exit 0
+fi
+
+echo "unexpected args: $@" >&2
+exit 2
+"#;
+    std::fs::write(&script_path, script).expect("write fake constrained RNAfold");
+    let mut perms = std::fs::metadata(&script_path)
+        .expect("metadata fake constrained RNAfold")
+        .permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(&script_path, perms).expect("chmod fake constrained RNAfold");
+    script_path.display().to_string()
+}
+
 #[cfg(unix)]
 fn install_fake_bigwig_to_bedgraph(path: &Path, bedgraph_source: &Path) -> String {
     let script_path = path.join("fake_bigwig_to_bedgraph.sh");
@@ -6134,6 +6410,109 @@ fn test_set_linear_viewport() {
     assert_eq!(engine.state().display.linear_view_span_bp, 456);
 }
 
+#[test]
+fn test_zoom_viewport_keeps_center_fixed_on_screen() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("s".to_string(), seq(&"ACGT".repeat(2_500)));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::SetLinearViewport {
+            start_bp: 1_000,
+            span_bp: 4_000,
+        })
+        .unwrap();
+    let screen_fraction = |engine: &GentleEngine, bp: usize| {
+        let display = &engine.state().display;
+        (bp - display.linear_view_start_bp) as f64 / display.linear_view_span_bp as f64
+    };
+    let before = screen_fraction(&engine, 2_000);
+
+    engine
+        .apply(Operation::ZoomViewport {
+            seq_id: "s".to_string(),
+            factor: 2.0,
+            center_bp: Some(2_000),
+        })
+        .unwrap();
+    assert_eq!(engine.state().display.linear_view_span_bp, 2_000);
+    assert_eq!(engine.state().display.linear_view_start_bp, 1_500);
+    assert!((screen_fraction(&engine, 2_000) - before).abs() < 1e-9);
+
+    // Without a center the middle of the view stays put.
+    engine
+        .apply(Operation::ZoomViewport {
+            seq_id: "s".to_string(),
+            factor: 4.0,
+            center_bp: None,
+        })
+        .unwrap();
+    assert_eq!(engine.state().display.linear_view_span_bp, 500);
+    assert_eq!(engine.state().display.linear_view_start_bp, 2_250);
+
+    // Zooming out past the whole sequence clamps to its bounds.
+    engine
+        .apply(Operation::ZoomViewport {
+            seq_id: "s".to_string(),
+            factor: 0.01,
+            center_bp: None,
+        })
+        .unwrap();
+    assert_eq!(engine.state().display.linear_view_span_bp, 10_000);
+    assert_eq!(engine.state().display.linear_view_start_bp, 0);
+
+    let err = engine
+        .apply(Operation::ZoomViewport {
+            seq_id: "s".to_string(),
+            factor: 0.0,
+            center_bp: None,
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+}
+
+#[test]
+fn test_pan_viewport_clamps_at_sequence_end() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("s".to_string(), seq(&"ACGT".repeat(2_500)));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::SetLinearViewport {
+            start_bp: 2_000,
+            span_bp: 1_000,
+        })
+        .unwrap();
+
+    engine
+        .apply(Operation::PanViewport {
+            seq_id: "s".to_string(),
+            delta_bp: 500,
+        })
+        .unwrap();
+    assert_eq!(engine.state().display.linear_view_start_bp, 2_500);
+    assert_eq!(engine.state().display.linear_view_span_bp, 1_000);
+
+    engine
+        .apply(Operation::PanViewport {
+            seq_id: "s".to_string(),
+            delta_bp: 1_000_000,
+        })
+        .unwrap();
+    assert_eq!(engine.state().display.linear_view_start_bp, 9_000);
+    assert_eq!(engine.state().display.linear_view_span_bp, 1_000);
+
+    engine
+        .apply(Operation::PanViewport {
+            seq_id: "s".to_string(),
+            delta_bp: -20_000,
+        })
+        .unwrap();
+    assert_eq!(engine.state().display.linear_view_start_bp, 0);
+}
+
 #[test]
 fn test_engine_history_summary_and_multi_step_undo_redo() {
     let mut engine = GentleEngine::new();
@@ -6229,6 +6608,62 @@ fn test_engine_history_redo_clears_after_new_mutation() {
     );
 }
 
+#[test]
+fn test_engine_session_round_trip_keeps_undo_and_redo_history() {
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::SetDisplayVisibility {
+            target: DisplayTarget::Features,
+            visible: false,
+        })
+        .unwrap();
+    engine
+        .apply(Operation::SetDisplayVisibility {
+            target: DisplayTarget::Tfbs,
+            visible: true,
+        })
+        .unwrap();
+    engine.undo_last_operation().unwrap();
+
+    let tmp = tempdir().expect("tempdir");
+    let path = tmp.path().join("engine.session.json");
+    let path = path.to_string_lossy().to_string();
+    engine.save_session(&path).expect("save session");
+
+    let mut reloaded = GentleEngine::load_session(&path).expect("load session");
+    let summary = reloaded.history_summary();
+    assert_eq!(summary.undo_count, 1);
+    assert_eq!(summary.redo_count, 1);
+    assert_eq!(summary.operation_log_count, 1);
+    assert!(!reloaded.state().display.show_features);
+    assert!(!reloaded.state().display.show_tfbs);
+
+    reloaded.undo_last_operation().unwrap();
+    assert!(reloaded.state().display.show_features);
+    assert_eq!(reloaded.history_summary().operation_log_count, 0);
+    reloaded.redo_last_operation().unwrap();
+    reloaded.redo_last_operation().unwrap();
+    assert!(!reloaded.state().display.show_features);
+    assert!(reloaded.state().display.show_tfbs);
+
+    // Operation ids keep counting from the saved session.
+    let result = reloaded
+        .apply(Operation::SetLinearViewport {
+            start_bp: 0,
+            span_bp: 10,
+        })
+        .unwrap();
+    assert_eq!(result.op_id, "op-3");
+
+    let state_path = tmp.path().join("state.json");
+    let state_path = state_path.to_string_lossy().to_string();
+    reloaded.state().save_to_path(&state_path).unwrap();
+    assert_eq!(
+        GentleEngine::load_session(&state_path).unwrap_err().code,
+        ErrorCode::InvalidInput
+    );
+}
+
 #[test]
 fn test_extract_region() {
     let mut state = ProjectState::default();
@@ -6366,6 +6801,8 @@ fn test_extract_anchored_region_from_feature_boundary() {
         .apply(Operation::LoadFile {
             path: "test_files/pGEX-3X.gb".to_string(),
             as_id: Some("pgex".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
     let res = engine
@@ -6543,176 +6980,911 @@ fn test_ligation_protocol_sticky_uses_overhang_compatibility() {
 }
 
 #[test]
-fn test_workflow_digest_merge_ligation_is_deterministic() {
-    let mut base = ProjectState::default();
-    base.sequences
-        .insert("x".to_string(), seq("ATGGATCCGCATGGATCCGCATGGATCCGC"));
+fn test_ligation_uses_fragment_end_chemistry() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("x".to_string(), seq("TTTGAATTCAAACCCGAATTCGGG"));
+    state.sequences.insert("blunt".to_string(), seq("ACGTACGT"));
+    let mut engine = GentleEngine::from_state(state);
+    let digest_res = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["EcoRI".to_string()],
+            output_prefix: Some("frag".to_string()),
+        })
+        .unwrap();
+    assert_eq!(digest_res.created_seq_ids.len(), 3);
+    let first = digest_res.created_seq_ids[0].clone();
+    let last = digest_res.created_seq_ids[2].clone();
 
-    let run_once = |state: ProjectState| {
-        let mut engine = GentleEngine::from_state(state);
-        let digest = engine
-            .apply(Operation::Digest {
-                input: "x".to_string(),
-                enzymes: vec!["BamHI".to_string(), "EcoRI".to_string()],
-                output_prefix: Some("d".to_string()),
-            })
-            .unwrap();
-        let merge = engine
-            .apply(Operation::MergeContainers {
-                inputs: digest.created_seq_ids.clone(),
-                output_prefix: Some("m".to_string()),
-            })
-            .unwrap();
-        let lig = engine
-            .apply(Operation::Ligation {
-                inputs: merge.created_seq_ids.clone(),
-                circularize_if_possible: false,
-                output_id: None,
-                protocol: LigationProtocol::Sticky,
-                output_prefix: Some("lig".to_string()),
-                unique: Some(false),
-            })
-            .unwrap();
-        lig.created_seq_ids
-    };
+    let lig_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec![first.clone(), last, "blunt".to_string()],
+            circularize_if_possible: false,
+            output_id: None,
+            protocol: LigationProtocol::Sticky,
+            output_prefix: Some("st".to_string()),
+            unique: Some(true),
+        })
+        .unwrap();
+    assert_eq!(lig_res.created_seq_ids, vec!["st_1".to_string()]);
+    assert_eq!(
+        engine.state().sequences["st_1"].get_forward_string(),
+        "TTTGAATTCGGG"
+    );
 
-    let a = run_once(base.clone());
-    let b = run_once(base.clone());
-    assert_eq!(a, b);
-    assert!(!a.is_empty());
-    assert_eq!(a.first().unwrap(), "lig_1");
+    let blunt_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec![first, "blunt".to_string()],
+            circularize_if_possible: false,
+            output_id: None,
+            protocol: LigationProtocol::Blunt,
+            output_prefix: Some("bl".to_string()),
+            unique: Some(true),
+        })
+        .unwrap();
+    assert_eq!(blunt_res.created_seq_ids, vec!["bl_1".to_string()]);
+    assert_eq!(
+        engine.state().sequences["bl_1"].get_forward_string(),
+        "ACGTACGTTTTG"
+    );
 }
 
 #[test]
-fn test_from_state_reseeds_operation_counter_from_existing_project_ops() {
+fn test_ligation_records_fragment_order_and_junction_markers() {
     let mut state = ProjectState::default();
-    state.sequences.insert("seed".to_string(), seq("ATGCATGC"));
-    state.lineage.next_node_counter = 1;
-    state.lineage.nodes.insert(
-        "n-1".to_string(),
-        LineageNode {
-            node_id: "n-1".to_string(),
-            seq_id: "seed".to_string(),
-            created_by_op: Some("op-41".to_string()),
-            origin: SequenceOrigin::ImportedSynthetic,
-            created_at_unix_ms: 1,
-        },
-    );
     state
-        .lineage
-        .seq_to_node
-        .insert("seed".to_string(), "n-1".to_string());
-    state.container_state.containers.insert(
-        "container-1".to_string(),
-        Container {
-            container_id: "container-1".to_string(),
-            kind: ContainerKind::Singleton,
-            name: Some("seed".to_string()),
-            members: vec!["seed".to_string()],
-            declared_contents_exclusive: true,
-            created_by_op: Some("op-57".to_string()),
-            created_at_unix_ms: 1,
-        },
-    );
+        .sequences
+        .insert("x".to_string(), seq("TTTGAATTCAAACCCGAATTCGGG"));
     state
-        .container_state
-        .seq_to_latest_container
-        .insert("seed".to_string(), "container-1".to_string());
-    state.container_state.next_container_counter = 1;
-
+        .sequences
+        .insert("y".to_string(), seq("CCCGAATTCACGTGAATTCGGG"));
+    state
+        .sequences
+        .insert("blunt_a".to_string(), seq("ACGTACGT"));
+    state
+        .sequences
+        .insert("blunt_b".to_string(), seq("GGGGCCCC"));
     let mut engine = GentleEngine::from_state(state);
-    let result = engine
-        .apply(Operation::SetParameter {
-            name: "max_fragments_per_container".to_string(),
-            value: json!(123),
+    let digest_res = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["EcoRI".to_string()],
+            output_prefix: Some("frag".to_string()),
         })
-        .expect("set parameter");
-    assert_eq!(result.op_id, "op-58");
+        .unwrap();
+    let first = digest_res.created_seq_ids[0].clone();
+    let x_middle = digest_res.created_seq_ids[1].clone();
+    let last = digest_res.created_seq_ids[2].clone();
+    let y_middle = engine
+        .apply(Operation::Digest {
+            input: "y".to_string(),
+            enzymes: vec!["EcoRI".to_string()],
+            output_prefix: Some("y_frag".to_string()),
+        })
+        .unwrap()
+        .created_seq_ids[1]
+        .clone();
+    let junction_markers = |dna: &DNAsequence| {
+        dna.features()
+            .iter()
+            .filter(|f| f.qualifier_values("gentle_generated").next() == Some("ligation_junction"))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let assembly_of = |res: &OpResult| {
+        res.messages
+            .iter()
+            .find_map(|m| serde_json::from_str::<LigationAssembly>(m).ok())
+            .expect("ligation assembly message")
+    };
+
+    let lig_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec![last.clone(), first.clone()],
+            circularize_if_possible: false,
+            output_id: Some("joined".to_string()),
+            protocol: LigationProtocol::Sticky,
+            output_prefix: None,
+            unique: Some(true),
+        })
+        .unwrap();
+    let product = &engine.state().sequences["joined"];
+    assert_eq!(product.get_forward_string(), "TTTGAATTCGGG");
+    let markers = junction_markers(product);
+    assert_eq!(markers.len(), 1);
+    assert_eq!(markers[0].kind.to_string(), "misc_feature");
+    // TTTG | AATT | CGGG: the marker covers the annealed EcoRI overhang.
+    assert_eq!(
+        markers[0].location.find_bounds().expect("marker bounds"),
+        (4, 8)
+    );
+
+    let assembly = assembly_of(&lig_res);
+    assert_eq!(assembly.schema, "gentle.ligation_assembly.v1");
+    assert_eq!(assembly.product_seq_id, "joined");
+    assert_eq!(assembly.fragment_seq_ids, vec![first.clone(), last.clone()]);
+    assert!(!assembly.circular);
+    assert_eq!(
+        assembly.junctions,
+        vec![LigationJunctionMarker {
+            junction: LigationJunction {
+                left_seq_id: first,
+                right_seq_id: last,
+                kind: LigationProtocol::Sticky,
+                overhang: "AATT".to_string(),
+            },
+            start_0based: 4,
+            end_0based_exclusive: 8,
+            wraps_origin: false,
+        }]
+    );
+
+    // Circularizing also seals and marks the closing EcoRI junction.
+    let ring_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec![x_middle.clone(), y_middle.clone()],
+            circularize_if_possible: true,
+            output_id: None,
+            protocol: LigationProtocol::Sticky,
+            output_prefix: Some("ring".to_string()),
+            unique: None,
+        })
+        .unwrap();
+    let ring = &engine.state().sequences["ring_1"];
+    assert!(ring.is_circular());
+    assert_eq!(ring.get_forward_string(), "CAAACCCGAATTCACGTGAATT");
+    let bounds = junction_markers(ring)
+        .iter()
+        .map(|f| f.location.find_bounds().expect("marker bounds"))
+        .collect::<Vec<_>>();
+    assert_eq!(bounds, vec![(8, 12), (18, 22)]);
+    let assembly = assembly_of(&ring_res);
+    assert_eq!(assembly.product_seq_id, "ring_1");
+    assert_eq!(
+        assembly.fragment_seq_ids,
+        vec![x_middle.clone(), y_middle.clone()]
+    );
+    assert!(assembly.circular);
+    let closing = &assembly.junctions[1];
+    assert_eq!(closing.junction.left_seq_id, y_middle);
+    assert_eq!(closing.junction.right_seq_id, x_middle);
+    assert_eq!(closing.junction.overhang, "AATT");
+    assert_eq!(
+        (closing.start_0based, closing.end_0based_exclusive),
+        (18, 22)
+    );
+    assert!(!closing.wraps_origin);
+
+    // A blunt closing junction spans the last and first base.
+    let blunt_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec!["blunt_a".to_string(), "blunt_b".to_string()],
+            circularize_if_possible: true,
+            output_id: None,
+            protocol: LigationProtocol::Blunt,
+            output_prefix: Some("blunt_ring".to_string()),
+            unique: None,
+        })
+        .unwrap();
+    let blunt_ring = &engine.state().sequences["blunt_ring_1"];
+    assert!(blunt_ring.is_circular());
+    let markers = junction_markers(blunt_ring);
+    assert_eq!(markers.len(), 2);
+    assert_eq!(
+        markers[0].location,
+        gb_io::seq::Location::simple_range(7, 9)
+    );
+    assert_eq!(
+        markers[1].location,
+        gb_io::seq::Location::Join(vec![
+            gb_io::seq::Location::simple_range(15, 16),
+            gb_io::seq::Location::simple_range(0, 1),
+        ])
+    );
+    let closing = &assembly_of(&blunt_res).junctions[1];
+    assert_eq!(closing.junction.kind, LigationProtocol::Blunt);
+    assert_eq!(
+        (closing.start_0based, closing.end_0based_exclusive),
+        (15, 1)
+    );
+    assert!(closing.wraps_origin);
 }
 
 #[test]
-fn test_design_primer_pairs_persists_report() {
+fn test_ligation_compatibility_pairs_matching_overhangs() {
     let mut state = ProjectState::default();
-    state.sequences.insert(
-        "tpl".to_string(),
-        seq(
-            "ACGTTGCATGTCAGTACGATCGTACGTAGCTAGTCGATCGTACGATCGTAGCTAGCATCGATGCTAGCTAGTACGTAGCATCGATCGTAGCTAGCATGCTAGCTAGTCGATCGATCGTACGATCG",
-        ),
-    );
+    state
+        .sequences
+        .insert("x".to_string(), seq("TTTGAATTCAAAGGATCCCCC"));
     let mut engine = GentleEngine::from_state(state);
-    engine.state_mut().parameters.primer_design_backend = PrimerDesignBackend::Internal;
-    let result = engine
-        .apply(Operation::DesignPrimerPairs {
-            template: "tpl".to_string(),
-            roi_start_0based: 40,
-            roi_end_0based: 80,
-            forward: PrimerDesignSideConstraint {
-                min_length: 20,
-                max_length: 20,
-                location_0based: Some(5),
-                start_0based: None,
-                end_0based: None,
-                min_tm_c: 0.0,
-                max_tm_c: 100.0,
-                min_gc_fraction: 0.0,
-                max_gc_fraction: 1.0,
-                max_anneal_hits: 1000,
-                non_annealing_5prime_tail: None,
-                fixed_5prime: None,
-                fixed_3prime: None,
-                required_motifs: vec![],
-                forbidden_motifs: vec![],
-                locked_positions: vec![],
-            },
-            reverse: PrimerDesignSideConstraint {
-                min_length: 20,
-                max_length: 20,
-                location_0based: Some(90),
-                start_0based: None,
-                end_0based: None,
-                min_tm_c: 0.0,
-                max_tm_c: 100.0,
-                min_gc_fraction: 0.0,
-                max_gc_fraction: 1.0,
-                max_anneal_hits: 1000,
-                non_annealing_5prime_tail: None,
-                fixed_5prime: None,
-                fixed_3prime: None,
-                required_motifs: vec![],
-                forbidden_motifs: vec![],
-                locked_positions: vec![],
-            },
-            pair_constraints: PrimerDesignPairConstraint::default(),
-            min_amplicon_bp: 40,
-            max_amplicon_bp: 150,
-            max_tm_delta_c: Some(100.0),
-            max_pairs: Some(10),
-            report_id: Some("tp73_roi".to_string()),
+    let digest_res = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["EcoRI".to_string(), "BamHI".to_string()],
+            output_prefix: Some("frag".to_string()),
         })
-        .expect("design primer pairs");
-    assert!(
-        result
-            .messages
-            .iter()
-            .any(|line| line.contains("primer-design report"))
+        .unwrap();
+    let ids = digest_res.created_seq_ids.clone();
+    assert_eq!(ids.len(), 3);
+
+    let matrix = engine.ligation_compatibility(&ids).unwrap();
+    assert_eq!(matrix.seq_ids, ids);
+    assert_eq!(
+        matrix.compatible,
+        vec![
+            vec![false, true, false],
+            vec![false, false, true],
+            vec![true, false, false],
+        ]
     );
-    let report = engine
-        .get_primer_design_report("tp73_roi")
-        .expect("report by id");
-    assert_eq!(report.report_id, "tp73_roi");
-    assert_eq!(report.template, "tpl");
-    assert_eq!(report.op_id.as_deref(), Some(result.op_id.as_str()));
-    assert_eq!(report.run_id.as_deref(), Some("interactive"));
-    assert!(!report.pairs.is_empty());
+    let junctions = matrix
+        .junctions
+        .iter()
+        .map(|j| {
+            (
+                j.left_seq_id.as_str(),
+                j.right_seq_id.as_str(),
+                j.kind.clone(),
+                j.overhang.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
     assert_eq!(
-        result.created_seq_ids.len(),
-        report.pair_count.saturating_mul(3)
+        junctions,
+        vec![
+            (
+                ids[0].as_str(),
+                ids[1].as_str(),
+                LigationProtocol::Sticky,
+                "AATT"
+            ),
+            (
+                ids[1].as_str(),
+                ids[2].as_str(),
+                LigationProtocol::Sticky,
+                "GATC"
+            ),
+            (
+                ids[2].as_str(),
+                ids[0].as_str(),
+                LigationProtocol::Blunt,
+                ""
+            ),
+        ]
     );
+
+    let err = engine
+        .ligation_compatibility(&["missing".to_string()])
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::NotFound));
+}
+
+#[test]
+fn test_dephosphorylated_vector_cannot_self_ligate_but_takes_insert() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("vector".to_string(), seq("CCCGAATTCACGTACGTACGTGAATTCGGG"));
+    state
+        .sequences
+        .insert("insert".to_string(), seq("TTTGAATTCAAACCCGAATTCGGG"));
+    let mut circular = seq("ACGTACGT");
+    circular.set_circular(true);
+    state.sequences.insert("circular".to_string(), circular);
+    let mut engine = GentleEngine::from_state(state);
+    let mut digest_middle = |input: &str| {
+        engine
+            .apply(Operation::Digest {
+                input: input.to_string(),
+                enzymes: vec!["EcoRI".to_string()],
+                output_prefix: Some(format!("{input}_frag")),
+            })
+            .unwrap()
+            .created_seq_ids[1]
+            .clone()
+    };
+    let vector = digest_middle("vector");
+    let insert = digest_middle("insert");
     assert!(
-        result
-            .created_seq_ids
-            .iter()
-            .any(|seq_id| seq_id.ends_with("_amplicon"))
+        engine
+            .ligation_compatibility(&[vector.clone()])
+            .unwrap()
+            .compatible[0][0]
+    );
+
+    for output_id in ["vector_cip", "vector_cip_2"] {
+        let res = engine
+            .apply(Operation::Dephosphorylate {
+                input: vector.clone(),
+                output_id: Some(output_id.to_string()),
+            })
+            .unwrap();
+        assert_eq!(res.created_seq_ids, vec![output_id.to_string()]);
+    }
+    let cip = &engine.state().sequences["vector_cip"];
+    let ends = cip.ends();
+    assert!(ends.left.dephosphorylated && ends.right.dephosphorylated);
+    assert_eq!(ends.left.overhang, b"AATT".to_vec());
+    assert_eq!(
+        cip.get_forward_string(),
+        engine.state().sequences[&vector].get_forward_string()
+    );
+
+    // The vector can no longer close on itself; the insert still can.
+    let matrix = engine
+        .ligation_compatibility(&["vector_cip".to_string(), insert.clone()])
+        .unwrap();
+    assert_eq!(matrix.compatible, vec![vec![false, true], vec![true, true]]);
+
+    let err = engine
+        .apply(Operation::Ligation {
+            inputs: vec!["vector_cip".to_string(), "vector_cip_2".to_string()],
+            circularize_if_possible: true,
+            output_id: None,
+            protocol: LigationProtocol::Sticky,
+            output_prefix: Some("self".to_string()),
+            unique: None,
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+    assert!(err.message.contains("dephosphorylated"));
+
+    let res = engine
+        .apply(Operation::Ligation {
+            inputs: vec!["vector_cip".to_string(), insert],
+            circularize_if_possible: true,
+            output_id: None,
+            protocol: LigationProtocol::Sticky,
+            output_prefix: Some("clone".to_string()),
+            unique: None,
+        })
+        .unwrap();
+    assert_eq!(
+        res.created_seq_ids,
+        vec!["clone_1".to_string(), "clone_2".to_string()]
+    );
+    assert!(res.warnings.is_empty());
+    assert!(
+        res.created_seq_ids
+            .iter()
+            .all(|id| engine.state().sequences[id].is_circular())
+    );
+    // Both EcoRI overhangs are restored: the internal one and the one sealed
+    // across the origin.
+    let clone = &engine.state().sequences["clone_1"];
+    assert_eq!(clone.len(), 30);
+    assert_eq!(clone.get_forward_string(), "CACGTACGTACGTGAATTCAAACCCGAATT");
+
+    let err = engine
+        .apply(Operation::Dephosphorylate {
+            input: "circular".to_string(),
+            output_id: None,
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+}
+
+#[test]
+fn test_ligation_circularizes_only_across_compatible_closing_ends() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("x".to_string(), seq("TTTGAATTCAAAGGATCCCCC"));
+    let mut engine = GentleEngine::from_state(state);
+    let eco_ri = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["EcoRI".to_string()],
+            output_prefix: Some("eco".to_string()),
+        })
+        .unwrap()
+        .created_seq_ids[0]
+        .clone();
+    let eco_bam = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["EcoRI".to_string(), "BamHI".to_string()],
+            output_prefix: Some("eco_bam".to_string()),
+        })
+        .unwrap()
+        .created_seq_ids[1]
+        .clone();
+
+    // EcoRI joins EcoRI, but the BamHI end cannot close onto the blunt end.
+    let res = engine
+        .apply(Operation::Ligation {
+            inputs: vec![eco_ri, eco_bam],
+            circularize_if_possible: true,
+            output_id: Some("open".to_string()),
+            protocol: LigationProtocol::Sticky,
+            output_prefix: None,
+            unique: Some(true),
+        })
+        .unwrap();
+    assert!(!engine.state().sequences["open"].is_circular());
+    assert!(
+        res.warnings
+            .iter()
+            .any(|w| w.message.contains("closing ends are not compatible"))
+    );
+}
+
+#[test]
+fn test_estimate_operation_output_counts_sticky_ligation_products() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("x".to_string(), seq("TTTGAATTCAAAGAATTCCCCGAATTCGGG"));
+    state.sequences.insert("blunt".to_string(), seq("ACGTACGT"));
+    let mut engine = GentleEngine::from_state(state);
+    let digest = Operation::Digest {
+        input: "x".to_string(),
+        enzymes: vec!["EcoRI".to_string()],
+        output_prefix: Some("frag".to_string()),
+    };
+    assert_eq!(
+        engine.estimate_operation_output(&digest).unwrap(),
+        OutputEstimate::Bounded {
+            max_products: 4,
+            exceeds_limit: false,
+        }
+    );
+    let fragments = engine.apply(digest).unwrap().created_seq_ids;
+    assert_eq!(fragments.len(), 4);
+
+    // Three fragments carry a sticky right end and three a sticky left end;
+    // the two inner fragments cannot ligate to themselves, and the blunt
+    // insert never pairs under the sticky protocol.
+    let mut inputs = fragments.clone();
+    inputs.push("blunt".to_string());
+    let ligation = Operation::Ligation {
+        inputs,
+        circularize_if_possible: true,
+        output_id: None,
+        protocol: LigationProtocol::Sticky,
+        output_prefix: Some("lig".to_string()),
+        unique: Some(false),
+    };
+    let estimate = engine.estimate_operation_output(&ligation).unwrap();
+    assert_eq!(
+        estimate,
+        OutputEstimate::Bounded {
+            max_products: 7,
+            exceeds_limit: false,
+        }
+    );
+    let products = engine.apply(ligation.clone()).unwrap().created_seq_ids;
+    assert_eq!(products.len(), 7);
+
+    engine.state_mut().parameters.max_fragments_per_container = 5;
+    assert_eq!(
+        engine.estimate_operation_output(&ligation).unwrap(),
+        OutputEstimate::Bounded {
+            max_products: 7,
+            exceeds_limit: true,
+        }
+    );
+    assert_eq!(
+        engine
+            .estimate_operation_output(&Operation::Reverse {
+                input: "x".to_string(),
+                output_id: None,
+            })
+            .unwrap(),
+        OutputEstimate::Unknown
+    );
+}
+
+#[test]
+fn test_polish_ends_fill_in_enables_blunt_ligation() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("x".to_string(), seq("TTTGAATTCAAACCCGAATTCGGG"));
+    state.sequences.insert("blunt".to_string(), seq("ACGTACGT"));
+    let mut engine = GentleEngine::from_state(state);
+    let digest_res = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["EcoRI".to_string()],
+            output_prefix: Some("frag".to_string()),
+        })
+        .unwrap();
+    let first = digest_res.created_seq_ids[0].clone();
+
+    let polish_res = engine
+        .apply(Operation::PolishEnds {
+            input: first.clone(),
+            mode: PolishMode::FillIn5Overhang,
+            output_id: Some("frag_blunt".to_string()),
+        })
+        .unwrap();
+    assert_eq!(polish_res.created_seq_ids, vec!["frag_blunt".to_string()]);
+    assert!(polish_res.warnings.is_empty());
+    let polished = &engine.state().sequences["frag_blunt"];
+    assert_eq!(polished.get_forward_string(), "TTTGAATT");
+    assert!(polished.ends().left.is_blunt());
+    assert!(polished.ends().right.is_blunt());
+
+    let lig_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec!["frag_blunt".to_string(), "blunt".to_string()],
+            circularize_if_possible: false,
+            output_id: None,
+            protocol: LigationProtocol::Blunt,
+            output_prefix: Some("bl".to_string()),
+            unique: Some(false),
+        })
+        .unwrap();
+    assert_eq!(lig_res.created_seq_ids.len(), 2);
+    assert_eq!(
+        engine.state().sequences["bl_1"].get_forward_string(),
+        "TTTGAATTACGTACGT"
+    );
+
+    let chew_res = engine
+        .apply(Operation::PolishEnds {
+            input: first,
+            mode: PolishMode::ChewBack3Overhang,
+            output_id: None,
+        })
+        .unwrap();
+    assert!(
+        chew_res
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("no 3prime overhang"))
+    );
+
+    let mut circular = seq("ACGTACGT");
+    circular.set_circular(true);
+    engine
+        .state_mut()
+        .sequences
+        .insert("ring".to_string(), circular);
+    let err = engine
+        .apply(Operation::PolishEnds {
+            input: "ring".to_string(),
+            mode: PolishMode::FillIn5Overhang,
+            output_id: None,
+        })
+        .unwrap_err();
+    assert!(err.message.contains("circular"));
+}
+
+#[test]
+fn test_replace_subsequence_shifts_features_for_all_or_first_n() {
+    let mut dna = seq("TTGAATTCTTTTTTGAATTCTTTTTTTTTTACGTACGTTT");
+    for (label, start, end) in [("tail", 30, 38), ("between", 10, 12), ("cut", 4, 25)] {
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location: gb_io::seq::Location::simple_range(start, end),
+            qualifiers: vec![("label".into(), Some(label.to_string()))],
+        });
+    }
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let location = |engine: &GentleEngine, seq_id: &str, label: &str| {
+        engine.state().sequences[seq_id]
+            .features()
+            .iter()
+            .find(|feature| {
+                GentleEngine::feature_qualifier_text(feature, "label").as_deref() == Some(label)
+            })
+            .map(|feature| feature.location.clone())
+            .unwrap()
+    };
+
+    let res = engine
+        .apply(Operation::ReplaceSubsequence {
+            seq_id: "s".to_string(),
+            find: "gaattc".to_string(),
+            replace: "GGATCCGG".to_string(),
+            max_replacements: None,
+            output_id: None,
+        })
+        .unwrap();
+    assert_eq!(res.created_seq_ids, vec!["s_replaced".to_string()]);
+    assert!(res.messages[0].contains("Replaced 2 of 2 occurrence(s)"));
+    assert_eq!(
+        engine.state().sequences["s_replaced"].get_forward_string(),
+        "TTGGATCCGGTTTTTTGGATCCGGTTTTTTTTTTACGTACGTTT"
+    );
+    assert_eq!(
+        location(&engine, "s_replaced", "tail"),
+        gb_io::seq::Location::simple_range(34, 42)
+    );
+    assert_eq!(
+        location(&engine, "s_replaced", "between"),
+        gb_io::seq::Location::simple_range(12, 14)
+    );
+    assert_eq!(
+        location(&engine, "s_replaced", "cut"),
+        gb_io::seq::Location::simple_range(2, 29)
+    );
+    assert!(
+        res.warnings
+            .iter()
+            .any(|w| w.message.contains("'cut'") && w.message.contains("boundary"))
+    );
+
+    let res = engine
+        .apply(Operation::ReplaceSubsequence {
+            seq_id: "s".to_string(),
+            find: "GAATTC".to_string(),
+            replace: "GGATCCGG".to_string(),
+            max_replacements: Some(1),
+            output_id: Some("first".to_string()),
+        })
+        .unwrap();
+    assert!(res.messages[0].contains("Replaced 1 of 2 occurrence(s)"));
+    assert_eq!(
+        engine.state().sequences["first"].get_forward_string(),
+        "TTGGATCCGGTTTTTTGAATTCTTTTTTTTTTACGTACGTTT"
+    );
+    assert_eq!(
+        location(&engine, "first", "tail"),
+        gb_io::seq::Location::simple_range(32, 40)
+    );
+    assert_eq!(
+        location(&engine, "first", "between"),
+        gb_io::seq::Location::simple_range(12, 14)
+    );
+    // The source sequence is untouched.
+    assert_eq!(
+        location(&engine, "s", "tail"),
+        gb_io::seq::Location::simple_range(30, 38)
+    );
+}
+
+#[test]
+fn test_engineer_silent_site_introduces_bamhi_without_changing_protein() {
+    let mut dna = seq("TTTTATGGGCTCAAAATAATTTT");
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::simple_range(4, 19),
+        qualifiers: vec![("label".into(), Some("orf".to_string()))],
+    });
+    let mut stuck = seq("TTTTATGTGGTAATTTT");
+    stuck.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::simple_range(4, 13),
+        qualifiers: vec![],
+    });
+    let mut state = ProjectState::default();
+    state.sequences.insert("cds".to_string(), dna);
+    state.sequences.insert("stuck".to_string(), stuck);
+    let mut engine = GentleEngine::from_state(state);
+    let feature_id = engine.state().sequences["cds"]
+        .features()
+        .iter()
+        .position(|feature| feature.kind.to_string() == "CDS")
+        .unwrap();
+
+    let res = engine
+        .apply(Operation::EngineerSilentSite {
+            seq_id: "cds".to_string(),
+            feature_id,
+            enzyme: "bamhi".to_string(),
+            mode: SiteEngineerMode::Introduce,
+            output_id: None,
+        })
+        .unwrap();
+    assert_eq!(res.created_seq_ids, vec!["cds_silent_BamHI".to_string()]);
+    assert!(res.messages[0].contains("2 base change(s) (C10A, A13C)"));
+    let edited = &engine.state().sequences["cds_silent_BamHI"];
+    assert_eq!(edited.get_forward_string(), "TTTTATGGGATCCAAATAATTTT");
+    assert_eq!(
+        edited.features()[feature_id].location,
+        gb_io::seq::Location::simple_range(4, 19)
+    );
+
+    let removed = engine
+        .apply(Operation::EngineerSilentSite {
+            seq_id: "cds_silent_BamHI".to_string(),
+            feature_id,
+            enzyme: "BamHI".to_string(),
+            mode: SiteEngineerMode::Remove,
+            output_id: Some("cds_no_bamhi".to_string()),
+        })
+        .unwrap();
+    assert!(removed.messages[0].contains("1 base change(s) (A10T)"));
+    let cleaned = engine.state().sequences["cds_no_bamhi"].get_forward_string();
+    assert_eq!(cleaned, "TTTTATGGGTTCCAAATAATTTT");
+    assert!(!cleaned.contains("GGATCC"));
+
+    let stuck_feature_id = engine.state().sequences["stuck"]
+        .features()
+        .iter()
+        .position(|feature| feature.kind.to_string() == "CDS")
+        .unwrap();
+    let err = engine
+        .apply(Operation::EngineerSilentSite {
+            seq_id: "stuck".to_string(),
+            feature_id: stuck_feature_id,
+            enzyme: "BamHI".to_string(),
+            mode: SiteEngineerMode::Introduce,
+            output_id: None,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    assert!(err.message.contains("No synonymous codon changes"));
+}
+
+#[test]
+fn test_workflow_digest_merge_ligation_is_deterministic() {
+    let mut base = ProjectState::default();
+    base.sequences
+        .insert("x".to_string(), seq("ATGGATCCGCATGGATCCGCATGGATCCGC"));
+
+    let run_once = |state: ProjectState| {
+        let mut engine = GentleEngine::from_state(state);
+        let digest = engine
+            .apply(Operation::Digest {
+                input: "x".to_string(),
+                enzymes: vec!["BamHI".to_string(), "EcoRI".to_string()],
+                output_prefix: Some("d".to_string()),
+            })
+            .unwrap();
+        let merge = engine
+            .apply(Operation::MergeContainers {
+                inputs: digest.created_seq_ids.clone(),
+                output_prefix: Some("m".to_string()),
+            })
+            .unwrap();
+        let lig = engine
+            .apply(Operation::Ligation {
+                inputs: merge.created_seq_ids.clone(),
+                circularize_if_possible: false,
+                output_id: None,
+                protocol: LigationProtocol::Sticky,
+                output_prefix: Some("lig".to_string()),
+                unique: Some(false),
+            })
+            .unwrap();
+        lig.created_seq_ids
+    };
+
+    let a = run_once(base.clone());
+    let b = run_once(base.clone());
+    assert_eq!(a, b);
+    assert!(!a.is_empty());
+    assert_eq!(a.first().unwrap(), "lig_1");
+}
+
+#[test]
+fn test_from_state_reseeds_operation_counter_from_existing_project_ops() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("seed".to_string(), seq("ATGCATGC"));
+    state.lineage.next_node_counter = 1;
+    state.lineage.nodes.insert(
+        "n-1".to_string(),
+        LineageNode {
+            node_id: "n-1".to_string(),
+            seq_id: "seed".to_string(),
+            created_by_op: Some("op-41".to_string()),
+            origin: SequenceOrigin::ImportedSynthetic,
+            created_at_unix_ms: 1,
+        },
+    );
+    state
+        .lineage
+        .seq_to_node
+        .insert("seed".to_string(), "n-1".to_string());
+    state.container_state.containers.insert(
+        "container-1".to_string(),
+        Container {
+            container_id: "container-1".to_string(),
+            kind: ContainerKind::Singleton,
+            name: Some("seed".to_string()),
+            members: vec!["seed".to_string()],
+            declared_contents_exclusive: true,
+            created_by_op: Some("op-57".to_string()),
+            created_at_unix_ms: 1,
+        },
+    );
+    state
+        .container_state
+        .seq_to_latest_container
+        .insert("seed".to_string(), "container-1".to_string());
+    state.container_state.next_container_counter = 1;
+
+    let mut engine = GentleEngine::from_state(state);
+    let result = engine
+        .apply(Operation::SetParameter {
+            name: "max_fragments_per_container".to_string(),
+            value: json!(123),
+        })
+        .expect("set parameter");
+    assert_eq!(result.op_id, "op-58");
+}
+
+#[test]
+fn test_design_primer_pairs_persists_report() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "tpl".to_string(),
+        seq(
+            "ACGTTGCATGTCAGTACGATCGTACGTAGCTAGTCGATCGTACGATCGTAGCTAGCATCGATGCTAGCTAGTACGTAGCATCGATCGTAGCTAGCATGCTAGCTAGTCGATCGATCGTACGATCG",
+        ),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine.state_mut().parameters.primer_design_backend = PrimerDesignBackend::Internal;
+    let result = engine
+        .apply(Operation::DesignPrimerPairs {
+            template: "tpl".to_string(),
+            roi_start_0based: 40,
+            roi_end_0based: 80,
+            forward: PrimerDesignSideConstraint {
+                min_length: 20,
+                max_length: 20,
+                location_0based: Some(5),
+                start_0based: None,
+                end_0based: None,
+                min_tm_c: 0.0,
+                max_tm_c: 100.0,
+                min_gc_fraction: 0.0,
+                max_gc_fraction: 1.0,
+                max_anneal_hits: 1000,
+                non_annealing_5prime_tail: None,
+                fixed_5prime: None,
+                fixed_3prime: None,
+                required_motifs: vec![],
+                forbidden_motifs: vec![],
+                locked_positions: vec![],
+            },
+            reverse: PrimerDesignSideConstraint {
+                min_length: 20,
+                max_length: 20,
+                location_0based: Some(90),
+                start_0based: None,
+                end_0based: None,
+                min_tm_c: 0.0,
+                max_tm_c: 100.0,
+                min_gc_fraction: 0.0,
+                max_gc_fraction: 1.0,
+                max_anneal_hits: 1000,
+                non_annealing_5prime_tail: None,
+                fixed_5prime: None,
+                fixed_3prime: None,
+                required_motifs: vec![],
+                forbidden_motifs: vec![],
+                locked_positions: vec![],
+            },
+            pair_constraints: PrimerDesignPairConstraint::default(),
+            min_amplicon_bp: 40,
+            max_amplicon_bp: 150,
+            max_tm_delta_c: Some(100.0),
+            max_pairs: Some(10),
+            report_id: Some("tp73_roi".to_string()),
+        })
+        .expect("design primer pairs");
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|line| line.contains("primer-design report"))
+    );
+    let report = engine
+        .get_primer_design_report("tp73_roi")
+        .expect("report by id");
+    assert_eq!(report.report_id, "tp73_roi");
+    assert_eq!(report.template, "tpl");
+    assert_eq!(report.op_id.as_deref(), Some(result.op_id.as_str()));
+    assert_eq!(report.run_id.as_deref(), Some("interactive"));
+    assert!(!report.pairs.is_empty());
+    assert_eq!(
+        result.created_seq_ids.len(),
+        report.pair_count.saturating_mul(3)
+    );
+    assert!(
+        result
+            .created_seq_ids
+            .iter()
+            .any(|seq_id| seq_id.ends_with("_amplicon"))
     );
     assert!(
         result
@@ -7313,7 +8485,7 @@ fn test_design_primer_pairs_auto_backend_falls_back_to_internal() {
         result
             .warnings
             .iter()
-            .any(|line| line.contains("Primer3 backend unavailable"))
+            .any(|line| line.message.contains("Primer3 backend unavailable"))
     );
     let report = engine
         .get_primer_design_report("tp73_roi_auto")
@@ -7376,7 +8548,7 @@ fn test_design_primer_pairs_internal_reports_pair_evaluation_budget_truncation()
         result
             .warnings
             .iter()
-            .any(|line| line.contains("evaluation limit")),
+            .any(|line| line.message.contains("evaluation limit")),
         "expected warning about internal pair-evaluation limit, got {:?}",
         result.warnings
     );
@@ -7449,7 +8621,7 @@ fn test_design_qpcr_assays_internal_reports_pair_evaluation_budget_truncation()
         result
             .warnings
             .iter()
-            .any(|line| line.contains("evaluation limit")),
+            .any(|line| line.message.contains("evaluation limit")),
         "expected warning about internal pair-evaluation limit, got {:?}",
         result.warnings
     );
@@ -7532,6 +8704,8 @@ fn load_tp73_engine_for_transcript_aware_qpcr() -> (GentleEngine, usize, usize)
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
     engine.state_mut().parameters.primer_design_backend = PrimerDesignBackend::Internal;
@@ -10040,6 +11214,70 @@ fn test_pcr_overlap_extension_mutagenesis_insertion_materializes_staged_products
     assert!(!preview.bindings.feature_overrides.is_empty());
 }
 
+#[test]
+fn test_pcr_overlap_extension_mutagenesis_warns_on_cds_frameshift() {
+    let template_seq = "ACGTTGCATGTCAGTACGATCGTACGTAGCTAGTCGATCGTACGATCGTAGCTAGCATCGATGCTAGCTAGTACGTAGCATCGATCGTAGCTAGCATGCTAGCTAGTCGATCGATCGTACGATCG";
+    let mut dna = seq(template_seq);
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::simple_range(30, 99),
+        qualifiers: vec![("label".into(), Some("orf".to_string()))],
+    });
+    let preview = GentleEngine::cds_integrity_edit_preview(&dna, 70, 70, "G").expect("preview");
+    assert_eq!(
+        preview.features()[0].location.find_bounds().unwrap(),
+        (30, 100)
+    );
+    assert!(
+        GentleEngine::check_cds_integrity(&preview)
+            .contains(&"CDS 'orf' is 70 bp, not a multiple of 3 (frameshift)".to_string())
+    );
+
+    let mut state = ProjectState::default();
+    state.sequences.insert("tpl".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let fixed_side = |location_0based: usize, length_bp: usize| PrimerDesignSideConstraint {
+        min_length: length_bp,
+        max_length: length_bp,
+        location_0based: Some(location_0based),
+        start_0based: None,
+        end_0based: None,
+        min_tm_c: 0.0,
+        max_tm_c: 100.0,
+        min_gc_fraction: 0.0,
+        max_gc_fraction: 1.0,
+        max_anneal_hits: 1000,
+        non_annealing_5prime_tail: None,
+        fixed_5prime: None,
+        fixed_3prime: None,
+        required_motifs: vec![],
+        forbidden_motifs: vec![],
+        locked_positions: vec![],
+    };
+    let result = engine
+        .apply(Operation::PcrOverlapExtensionMutagenesis {
+            template: "tpl".to_string(),
+            edit_start_0based: 70,
+            edit_end_0based_exclusive: 70,
+            insert_sequence: "G".to_string(),
+            constraints: OverlapExtensionMutagenesisConstraints {
+                overlap_bp: 16,
+                outer_forward: fixed_side(8, 20),
+                outer_reverse: fixed_side(105, 20),
+                inner_forward: fixed_side(88, 16),
+                inner_reverse: fixed_side(45, 20),
+            },
+            output_prefix: Some("oe_frameshift".to_string()),
+        })
+        .expect("overlap-extension 1 bp insertion");
+    assert!(
+        result.warnings.iter().any(|warning| warning.message
+            == "Edit of 'tpl': CDS 'orf' is 70 bp, not a multiple of 3 (frameshift)"),
+        "{:?}",
+        result.warnings
+    );
+}
+
 #[test]
 fn test_pcr_overlap_extension_mutagenesis_deletion_materializes_staged_products() {
     let template_seq = "ACGTTGCATGTCAGTACGATCGTACGTAGCTAGTCGATCGTACGATCGTAGCTAGCATCGATGCTAGCTAGTACGTAGCATCGATCGTAGCTAGCATGCTAGCTAGTCGATCGATCGTACGATCG";
@@ -10481,6 +11719,931 @@ fn test_lineage_extract_creates_parent_child_edge() {
     );
 }
 
+#[test]
+fn test_rename_sequence_keeps_lineage_containers_and_design_records() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("x".to_string(), seq(&"ATGC".repeat(100)));
+    state.sequences.insert("y".to_string(), seq("ATGCATGC"));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::ExtractRegion {
+            input: "x".to_string(),
+            from: 2,
+            to: 7,
+            output_id: Some("part".to_string()),
+        })
+        .unwrap();
+    engine
+        .apply(Operation::UpsertGuideSet {
+            guide_set_id: "x_guides".to_string(),
+            guides: vec![GuideCandidate {
+                guide_id: "g1".to_string(),
+                seq_id: "x".to_string(),
+                start_0based: 10,
+                end_0based_exclusive: 30,
+                ..GuideCandidate::default()
+            }],
+        })
+        .unwrap();
+    let mut candidates = engine.read_candidate_store();
+    candidates.sets.insert(
+        "x_windows".to_string(),
+        CandidateSet {
+            name: "x_windows".to_string(),
+            source_seq_ids: vec!["x".to_string()],
+            candidates: vec![CandidateRecord {
+                seq_id: "x".to_string(),
+                start_0based: 0,
+                end_0based: 8,
+                ..CandidateRecord::default()
+            }],
+            ..CandidateSet::default()
+        },
+    );
+    engine.write_candidate_store(candidates).unwrap();
+    let x_node = engine.state().lineage.seq_to_node["x"].clone();
+    let x_container = engine.state().container_state.seq_to_latest_container["x"].clone();
+
+    let err = engine
+        .apply(Operation::RenameSequence {
+            from: "x".to_string(),
+            to: "y".to_string(),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+
+    engine
+        .apply(Operation::RenameSequence {
+            from: "x".to_string(),
+            to: "vector".to_string(),
+        })
+        .unwrap();
+    let state = engine.state();
+    assert!(!state.sequences.contains_key("x"));
+    assert!(state.sequences.contains_key("vector"));
+    assert!(!state.lineage.seq_to_node.contains_key("x"));
+    assert_eq!(state.lineage.seq_to_node["vector"], x_node);
+    assert_eq!(state.lineage.nodes[&x_node].seq_id, "vector");
+    let part_node = &state.lineage.seq_to_node["part"];
+    assert!(
+        state
+            .lineage
+            .edges
+            .iter()
+            .any(|e| e.from_node_id == x_node && e.to_node_id == *part_node)
+    );
+    assert_eq!(
+        state.container_state.seq_to_latest_container["vector"],
+        x_container
+    );
+    assert_eq!(
+        state.container_state.containers[&x_container].members,
+        vec!["vector".to_string()]
+    );
+    let candidates = engine.read_candidate_store();
+    assert_eq!(
+        candidates.sets["x_windows"].source_seq_ids,
+        vec!["vector".to_string()]
+    );
+    assert_eq!(candidates.sets["x_windows"].candidates[0].seq_id, "vector");
+    assert_eq!(
+        engine.read_guide_design_store().guide_sets["x_guides"].guides[0].seq_id,
+        "vector"
+    );
+
+    // The renamed id resolves for follow-up operations.
+    engine
+        .apply(Operation::Branch {
+            input: "vector".to_string(),
+            output_id: None,
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_delete_sequence_refuses_dependents_unless_cascading() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("x".to_string(), seq("ATGCATGCATGC"));
+    state.sequences.insert("y".to_string(), seq("GGGGCCCCAAAA"));
+    let mut engine = GentleEngine::from_state(state);
+    engine.reconcile_containers();
+    let x_singleton = engine.state().container_state.seq_to_latest_container["x"].clone();
+    let pool = engine
+        .add_container(
+            &["x".to_string(), "y".to_string()],
+            ContainerKind::Pool,
+            Some("x+y pool".to_string()),
+            None,
+        )
+        .unwrap();
+    let mut candidates = engine.read_candidate_store();
+    candidates.sets.insert(
+        "x_windows".to_string(),
+        CandidateSet {
+            name: "x_windows".to_string(),
+            source_seq_ids: vec!["x".to_string()],
+            candidates: vec![CandidateRecord {
+                seq_id: "x".to_string(),
+                start_0based: 0,
+                end_0based: 4,
+                ..CandidateRecord::default()
+            }],
+            ..CandidateSet::default()
+        },
+    );
+    engine.write_candidate_store(candidates).unwrap();
+
+    let err = engine
+        .apply(Operation::DeleteSequence {
+            seq_id: "x".to_string(),
+            cascade: false,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    assert!(err.message.contains(&pool));
+    assert!(err.message.contains("x_windows"));
+    assert!(!err.message.contains(&x_singleton));
+    assert!(engine.state().sequences.contains_key("x"));
+
+    let result = engine
+        .apply(Operation::DeleteSequence {
+            seq_id: "x".to_string(),
+            cascade: true,
+        })
+        .unwrap();
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.starts_with("Cascade cleanup for 'x'"))
+    );
+    let state = engine.state();
+    assert!(!state.sequences.contains_key("x"));
+    assert!(!state.lineage.seq_to_node.contains_key("x"));
+    assert!(
+        !state
+            .container_state
+            .seq_to_latest_container
+            .contains_key("x")
+    );
+    assert!(!state.container_state.containers.contains_key(&x_singleton));
+    assert_eq!(
+        state.container_state.containers[&pool].members,
+        vec!["y".to_string()]
+    );
+    assert!(!engine.read_candidate_store().sets.contains_key("x_windows"));
+}
+
+#[test]
+fn test_delete_sequence_without_dependents_needs_no_cascade() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("x".to_string(), seq("ATGCATGCATGC"));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::DeleteSequence {
+            seq_id: "x".to_string(),
+            cascade: false,
+        })
+        .unwrap();
+    assert!(engine.state().sequences.is_empty());
+    let err = engine
+        .apply(Operation::DeleteSequence {
+            seq_id: "x".to_string(),
+            cascade: true,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
+#[test]
+fn test_lineage_ancestors_and_descendants_follow_load_digest_ligation() {
+    let td = tempdir().unwrap();
+    let fasta_path = td.path().join("x.fa");
+    fs::write(&fasta_path, ">x\nATGGATCCGCATGGATCCGCATGGATCCGC\n").unwrap();
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::LoadFile {
+            path: fasta_path.to_string_lossy().to_string(),
+            as_id: Some("x".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    let digest_res = engine
+        .apply(Operation::Digest {
+            input: "x".to_string(),
+            enzymes: vec!["BamHI".to_string()],
+            output_prefix: Some("frag".to_string()),
+        })
+        .unwrap();
+    let a = digest_res.created_seq_ids[0].clone();
+    let b = digest_res.created_seq_ids[1].clone();
+    let lig_res = engine
+        .apply(Operation::Ligation {
+            inputs: vec![a.clone(), b.clone()],
+            circularize_if_possible: false,
+            output_id: None,
+            protocol: LigationProtocol::Sticky,
+            output_prefix: Some("st".to_string()),
+            unique: Some(false),
+        })
+        .unwrap();
+    let product = lig_res.created_seq_ids[0].clone();
+
+    let ancestors = engine.ancestors_of(&product);
+    let ligation_steps = ancestors
+        .iter()
+        .filter(|s| s.depth == 1)
+        .collect::<Vec<_>>();
+    assert_eq!(ligation_steps.len(), 2);
+    for step in &ligation_steps {
+        assert_eq!(step.child_seq_id, product);
+        assert_eq!(step.op_id, lig_res.op_id);
+        assert_eq!(step.operation.as_deref(), Some("Ligation"));
+    }
+    let parents = ligation_steps
+        .iter()
+        .map(|s| s.parent_seq_id.clone())
+        .collect::<HashSet<_>>();
+    assert_eq!(parents, HashSet::from([a.clone(), b.clone()]));
+    let digest_steps = ancestors
+        .iter()
+        .filter(|s| s.depth == 2)
+        .collect::<Vec<_>>();
+    assert_eq!(digest_steps.len(), 2);
+    for step in &digest_steps {
+        assert_eq!(step.parent_seq_id, "x");
+        assert_eq!(step.op_id, digest_res.op_id);
+        assert_eq!(step.operation.as_deref(), Some("Digest"));
+    }
+    assert!(ancestors.iter().all(|s| s.depth <= 2));
+    // The loaded root has no parents; its node records the LoadFile op.
+    assert!(engine.ancestors_of("x").is_empty());
+    let root_op = engine.state().lineage.nodes[&engine.state().lineage.seq_to_node["x"]]
+        .created_by_op
+        .clone()
+        .unwrap();
+    assert!(
+        engine
+            .operation_log()
+            .iter()
+            .any(|r| r.result.op_id == root_op && matches!(r.op, Operation::LoadFile { .. }))
+    );
+
+    let descendants = engine.descendants_of("x");
+    assert!(descendants.iter().any(|s| s.depth == 2
+        && s.child_seq_id == product
+        && s.operation.as_deref() == Some("Ligation")));
+    assert!(
+        descendants
+            .iter()
+            .filter(|s| s.depth == 1)
+            .all(|s| s.op_id == digest_res.op_id)
+    );
+    assert!(engine.ancestors_of("missing").is_empty());
+}
+
+#[test]
+fn test_lineage_walk_terminates_on_cyclic_edges() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("x".to_string(), seq("ATGCATGC"));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::Branch {
+            input: "x".to_string(),
+            output_id: Some("y".to_string()),
+        })
+        .unwrap();
+    let lineage = &mut engine.state_mut().lineage;
+    let back_edge = LineageEdge {
+        from_node_id: lineage.seq_to_node["y"].clone(),
+        to_node_id: lineage.seq_to_node["x"].clone(),
+        op_id: "op-cycle".to_string(),
+        run_id: "test".to_string(),
+    };
+    lineage.edges.push(back_edge);
+
+    let ancestors = engine.ancestors_of("x");
+    assert_eq!(ancestors.len(), 2);
+    assert_eq!(ancestors[0].parent_seq_id, "y");
+    assert_eq!(ancestors[0].operation, None);
+    assert_eq!(ancestors[1].parent_seq_id, "x");
+    assert_eq!(ancestors[1].operation.as_deref(), Some("Branch"));
+    assert_eq!(engine.descendants_of("y").len(), 2);
+}
+
+#[test]
+fn test_project_state_diff_reports_mutated_sequence_and_added_container() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("x".to_string(), seq("ATGCATGCATGC"));
+    state.sequences.insert("y".to_string(), seq("GGGGCCCCAAAA"));
+    let base = GentleEngine::from_state(state).state().clone();
+    assert!(base.diff(&base.clone()).is_empty());
+
+    let mut engine = GentleEngine::from_state(base.clone());
+    let mut mutated = engine.state().sequences["x"].clone();
+    mutated.substitute_bases(&[(0, b'C')]);
+    engine
+        .state_mut()
+        .sequences
+        .insert("x".to_string(), mutated);
+    let pool = engine
+        .add_container(
+            &["x".to_string(), "y".to_string()],
+            ContainerKind::Pool,
+            Some("review pool".to_string()),
+            None,
+        )
+        .unwrap();
+    engine.state_mut().display.linear_view_start_bp = 4;
+    let edited = engine.state().clone();
+
+    let diff = base.diff(&edited);
+    assert!(diff.added_sequences.is_empty());
+    assert!(diff.removed_sequences.is_empty());
+    assert_eq!(diff.changed_sequences.len(), 1);
+    let change = &diff.changed_sequences[0];
+    assert_eq!(change.seq_id, "x");
+    assert_ne!(change.old_fingerprint, change.new_fingerprint);
+    assert_eq!(change.old_length_bp, change.new_length_bp);
+    assert_eq!(diff.added_containers, vec![pool.clone()]);
+    assert!(diff.removed_containers.is_empty());
+    assert!(diff.changed_containers.is_empty());
+    assert_eq!(diff.display_changes.len(), 1);
+    assert_eq!(diff.display_changes[0].key, "linear_view_start_bp");
+
+    let reverse = edited.diff(&base);
+    assert_eq!(reverse.removed_containers, vec![pool]);
+    let json = serde_json::to_value(&diff).unwrap();
+    assert_eq!(json["changed_sequences"][0]["seq_id"], "x");
+}
+
+#[test]
+fn test_export_all_writes_one_file_per_sequence_with_template_names() {
+    let mut state = ProjectState::default();
+    let mut vector = seq("ATGCATGCATGC");
+    vector.set_name("pUC 19/backbone".to_string());
+    state.sequences.insert("vec".to_string(), vector);
+    state
+        .sequences
+        .insert("insert".to_string(), seq("GGGGCCCCAAAA"));
+    state
+        .sequences
+        .insert("primer".to_string(), seq("ACGTACGTAC"));
+    let mut engine = GentleEngine::from_state(state);
+    let td = tempdir().unwrap();
+    let out_dir = td.path().join("export");
+
+    let result = engine
+        .apply(Operation::ExportAll {
+            directory: out_dir.to_string_lossy().to_string(),
+            format: ExportFormat::Fasta,
+            filename_template: Some("{id}-{name}".to_string()),
+        })
+        .unwrap();
+    assert_eq!(
+        result
+            .messages
+            .iter()
+            .filter(|m| m.starts_with("Wrote '"))
+            .count(),
+        3
+    );
+    let mut names = fs::read_dir(&out_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "insert-insert.fa".to_string(),
+            "primer-primer.fa".to_string(),
+            "vec-pUC_19_backbone.fa".to_string(),
+        ]
+    );
+    let fasta = fs::read_to_string(out_dir.join("primer-primer.fa")).unwrap();
+    assert!(fasta.starts_with(">primer"));
+    assert!(fasta.contains("ACGTACGTAC"));
+
+    engine
+        .apply(Operation::ExportAll {
+            directory: out_dir.to_string_lossy().to_string(),
+            format: ExportFormat::GenBank,
+            filename_template: None,
+        })
+        .unwrap();
+    assert!(out_dir.join("vec.gb").exists());
+    assert!(out_dir.join("insert.gb").exists());
+    assert!(out_dir.join("primer.gb").exists());
+}
+
+#[test]
+fn test_render_thumbnails_writes_one_bounded_svg_per_sequence() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("vec".to_string(), seq(&"ATGCATGCATGC".repeat(20)));
+    state
+        .sequences
+        .insert("insert".to_string(), seq(&"GGGGCCCCAAAA".repeat(10)));
+    state
+        .sequences
+        .insert("primer/1".to_string(), seq("ACGTACGTACGTACGTACGT"));
+    let mut engine = GentleEngine::from_state(state);
+    let td = tempdir().unwrap();
+    let out_dir = td.path().join("thumbs");
+
+    let result = engine
+        .apply(Operation::RenderThumbnails {
+            directory: out_dir.to_string_lossy().to_string(),
+            mode: RenderSvgMode::Linear,
+            max_width_px: 240,
+        })
+        .unwrap();
+    assert_eq!(
+        result
+            .messages
+            .iter()
+            .filter(|m| m.starts_with("Wrote Linear thumbnail"))
+            .count(),
+        3
+    );
+    let mut names = fs::read_dir(&out_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "insert.svg".to_string(),
+            "primer_1.svg".to_string(),
+            "vec.svg".to_string(),
+        ]
+    );
+    for name in &names {
+        let svg = fs::read_to_string(out_dir.join(name)).unwrap();
+        let root = &svg[svg.find("<svg").unwrap()..];
+        let root = &root[..root.find('>').unwrap()];
+        let width_start = root.find(" width=\"").unwrap() + " width=\"".len();
+        let width_len = root[width_start..].find('"').unwrap();
+        let width: f64 = root[width_start..width_start + width_len].parse().unwrap();
+        assert!(width > 0.0 && width <= 240.0, "{name}: width {width}");
+        assert!(root.contains("viewBox=\"0 0 1200 "));
+    }
+}
+
+/// Member names of a stored (uncompressed) ZIP, in local-header order.
+fn stored_zip_member_names(bytes: &[u8]) -> Vec<String> {
+    let mut names = vec![];
+    let mut offset = 0usize;
+    while bytes.len() >= offset + 30 && bytes[offset..offset + 4] == [0x50, 0x4b, 0x03, 0x04] {
+        let field = |at: usize| u16::from_le_bytes([bytes[offset + at], bytes[offset + at + 1]]);
+        let size = u32::from_le_bytes(bytes[offset + 18..offset + 22].try_into().unwrap()) as usize;
+        let name_len = field(26) as usize;
+        let extra_len = field(28) as usize;
+        let name_start = offset + 30;
+        names.push(String::from_utf8_lossy(&bytes[name_start..name_start + name_len]).to_string());
+        offset = name_start + name_len + extra_len + size;
+    }
+    names
+}
+
+#[test]
+fn test_export_design_package_zips_selected_entries() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("vec".to_string(), seq("ATGCATGCATGCATGCATGC"));
+    state
+        .sequences
+        .insert("insert".to_string(), seq("GGGGCCCCAAAATTTTGGGG"));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "windows".to_string(),
+            seq_id: "vec".to_string(),
+            length_bp: 5,
+            step_bp: 5,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: Some(8),
+        })
+        .unwrap();
+    engine
+        .apply(Operation::UpsertGuideSet {
+            guide_set_id: "g1".to_string(),
+            guides: vec![GuideCandidate {
+                guide_id: "g1_1".to_string(),
+                seq_id: "vec".to_string(),
+                start_0based: 0,
+                end_0based_exclusive: 20,
+                strand: "+".to_string(),
+                protospacer: "ATGCATGCATGCATGCATGC".to_string(),
+                pam: "AGG".to_string(),
+                nuclease: "SpCas9".to_string(),
+                cut_offset_from_protospacer_start: 17,
+                rank: Some(1),
+                off_target_score: None,
+            }],
+        })
+        .unwrap();
+    let td = tempdir().unwrap();
+    let path = td.path().join("handoff").join("design.zip");
+
+    let result = engine
+        .apply(Operation::ExportDesignPackage {
+            path: path.to_string_lossy().to_string(),
+            include: DesignPackageContents {
+                seq_ids: vec!["vec".to_string()],
+                ..DesignPackageContents::default()
+            },
+        })
+        .unwrap();
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.starts_with("Wrote design package with 5 entries"))
+    );
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(
+        stored_zip_member_names(&bytes),
+        vec![
+            "sequences/vec.gb".to_string(),
+            "candidates/windows.csv".to_string(),
+            "guides/g1.csv".to_string(),
+            "maps/vec.svg".to_string(),
+            "provenance_report.md".to_string(),
+        ]
+    );
+    assert!(stored_zip_bytes_contain_text(&bytes, "LOCUS"));
+    assert!(stored_zip_bytes_contain_text(
+        &bytes,
+        "seq_id,start_0based,end_0based,sequence"
+    ));
+    assert!(stored_zip_bytes_contain_text(&bytes, "g1_1,1,vec,0,20,+"));
+    assert!(stored_zip_bytes_contain_text(&bytes, "<svg"));
+    let leftovers = fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(leftovers, vec!["design.zip".to_string()]);
+
+    engine
+        .apply(Operation::ExportDesignPackage {
+            path: path.to_string_lossy().to_string(),
+            include: DesignPackageContents {
+                sequences: false,
+                candidate_sets: false,
+                guide_sets: false,
+                map_svg: false,
+                ..DesignPackageContents::default()
+            },
+        })
+        .unwrap();
+    assert_eq!(
+        stored_zip_member_names(&fs::read(&path).unwrap()),
+        vec!["provenance_report.md".to_string()]
+    );
+
+    let err = engine
+        .apply(Operation::ExportDesignPackage {
+            path: path.to_string_lossy().to_string(),
+            include: DesignPackageContents {
+                seq_ids: vec!["missing".to_string()],
+                ..DesignPackageContents::default()
+            },
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::NotFound));
+}
+
+#[test]
+fn test_import_directory_pools_valid_files_and_skips_garbage() {
+    let td = tempdir().unwrap();
+    let dir = td.path().join("plasmids");
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    fs::copy("test_files/pGEX_3X.fa", dir.join("pgex_fasta.fa")).unwrap();
+    fs::copy("test_files/pGEX-3X.gb", dir.join("pgex_genbank.gb")).unwrap();
+    fs::write(dir.join("broken.gb"), "this is not a sequence file\n").unwrap();
+    fs::write(dir.join("notes.txt"), "ignored\n").unwrap();
+    fs::write(dir.join("nested").join("extra.fa"), ">extra\nACGTACGT\n").unwrap();
+    let mut engine = GentleEngine::new();
+
+    let result = engine
+        .apply(Operation::ImportDirectory {
+            directory: dir.to_string_lossy().to_string(),
+            recursive: false,
+            container_name: Some("pGEX set".to_string()),
+        })
+        .unwrap();
+    assert_eq!(
+        result.created_seq_ids,
+        vec!["pgex_fasta".to_string(), "pgex_genbank".to_string()]
+    );
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.message.starts_with("Skipped '") && w.message.contains("broken.gb"))
+    );
+    assert!(
+        !result
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("notes.txt"))
+    );
+    let state = engine.state();
+    assert!(state.sequences.contains_key("pgex_fasta"));
+    assert!(state.sequences.contains_key("pgex_genbank"));
+    let pools = state
+        .container_state
+        .containers
+        .values()
+        .filter(|c| matches!(c.kind, ContainerKind::Pool))
+        .collect::<Vec<_>>();
+    assert_eq!(pools.len(), 1);
+    assert_eq!(pools[0].name.as_deref(), Some("pGEX set"));
+    assert_eq!(pools[0].members, result.created_seq_ids);
+
+    let recursive = engine
+        .apply(Operation::ImportDirectory {
+            directory: dir.to_string_lossy().to_string(),
+            recursive: true,
+            container_name: None,
+        })
+        .unwrap();
+    assert_eq!(recursive.created_seq_ids.len(), 3);
+    assert!(recursive.created_seq_ids.contains(&"extra".to_string()));
+}
+
+#[test]
+fn test_create_arrangement_serial_replicates_triple_gel_lanes() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("a".to_string(), seq(&"ATGC".repeat(40)));
+    state
+        .sequences
+        .insert("b".to_string(), seq(&"ATGC".repeat(55)));
+    let mut engine = GentleEngine::from_state(state);
+    let mut container_ids: Vec<String> = engine
+        .state()
+        .container_state
+        .containers
+        .keys()
+        .cloned()
+        .collect();
+    container_ids.sort();
+    for (arrangement_id, replicate_count) in [("arr-single", None), ("arr-rep", Some(3))] {
+        engine
+            .apply(Operation::CreateArrangementSerial {
+                container_ids: container_ids.clone(),
+                arrangement_id: Some(arrangement_id.to_string()),
+                name: None,
+                ladders: None,
+                replicate_count,
+            })
+            .unwrap();
+    }
+    let sample_lanes = |layout: &crate::pool_gel::PoolGelLayout| {
+        layout
+            .lanes
+            .iter()
+            .filter(|lane| !lane.is_ladder)
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let single = engine
+        .build_serial_gel_layout_for_render(&[], None, Some("arr-single"), None, None)
+        .unwrap();
+    let replicated = engine
+        .build_serial_gel_layout_for_render(&[], None, Some("arr-rep"), None, None)
+        .unwrap();
+    let single_lanes = sample_lanes(&single);
+    let replicated_lanes = sample_lanes(&replicated);
+    assert_eq!(single_lanes.len(), 2);
+    assert_eq!(replicated_lanes.len(), 3 * single_lanes.len());
+    for (idx, lane) in replicated_lanes.iter().enumerate() {
+        let source = &single_lanes[idx / 3];
+        assert_eq!(lane.name, format!("{} rep{}", source.name, idx % 3 + 1));
+        assert_eq!(
+            lane.bands.iter().map(|band| band.bp).collect::<Vec<_>>(),
+            source.bands.iter().map(|band| band.bp).collect::<Vec<_>>()
+        );
+    }
+
+    let td = tempdir().unwrap();
+    let path = td.path().join("replicates.gel.svg");
+    engine
+        .apply(Operation::RenderPoolGelSvg {
+            inputs: vec![],
+            path: path.to_string_lossy().to_string(),
+            ladders: None,
+            container_ids: None,
+            arrangement_id: Some("arr-rep".to_string()),
+            conditions: None,
+        })
+        .unwrap();
+    assert!(fs::read_to_string(&path).unwrap().contains("rep3"));
+
+    let err = engine
+        .apply(Operation::CreateArrangementSerial {
+            container_ids,
+            arrangement_id: None,
+            name: None,
+            ladders: None,
+            replicate_count: Some(0),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn test_render_plate_layout_svg_assigns_lane_samples_to_wells_in_order() {
+    let mut state = ProjectState::default();
+    for (seq_id, name) in [
+        ("s1", "Clone alpha"),
+        ("s2", "Clone beta"),
+        ("s3", "Clone gamma"),
+    ] {
+        let mut dna = seq("ATGCATGCATGC");
+        dna.set_name(name);
+        state.sequences.insert(seq_id.to_string(), dna);
+    }
+    let mut engine = GentleEngine::from_state(state);
+    let container_ids = ["s3", "s1", "s2"]
+        .iter()
+        .map(|seq_id| engine.state().container_state.seq_to_latest_container[*seq_id].clone())
+        .collect::<Vec<_>>();
+    engine
+        .apply(Operation::CreateArrangementSerial {
+            container_ids,
+            arrangement_id: Some("plate-run".to_string()),
+            name: None,
+            ladders: None,
+            replicate_count: None,
+        })
+        .unwrap();
+
+    let samples = engine.plate_samples_from_arrangement("plate-run").unwrap();
+    let layout = crate::pool_gel::build_plate_layout("plate-run", &samples, 96).unwrap();
+    assert_eq!(layout.plate_count, 1);
+    let assignments = layout
+        .wells
+        .iter()
+        .map(|well| {
+            (
+                well.well.as_str(),
+                well.seq_id.as_str(),
+                well.label.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        assignments,
+        vec![
+            ("A1", "s3", "Clone gamma"),
+            ("A2", "s1", "Clone alpha"),
+            ("A3", "s2", "Clone beta"),
+        ]
+    );
+
+    let td = tempdir().unwrap();
+    let path = td.path().join("plate.svg");
+    engine
+        .apply(Operation::RenderPlateLayoutSvg {
+            arrangement_id: "plate-run".to_string(),
+            path: path.to_string_lossy().to_string(),
+            plate_wells: Some(384),
+        })
+        .unwrap();
+    let svg = fs::read_to_string(&path).unwrap();
+    assert!(svg.contains("Clone gamma"));
+    assert!(svg.contains("plate 1 of 1 (384 wells)"));
+    let err = engine
+        .apply(Operation::RenderPlateLayoutSvg {
+            arrangement_id: "plate-run".to_string(),
+            path: path.to_string_lossy().to_string(),
+            plate_wells: Some(24),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn test_define_dna_ladder_registers_user_ladder_for_gel_rendering() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("frag".to_string(), seq(&"ACGT".repeat(150)));
+    let mut engine = GentleEngine::from_state(state);
+    let band = |length_bp: f64, relative_strength: Option<f64>| DnaLadderBandInfo {
+        length_bp,
+        relative_strength,
+    };
+    engine
+        .apply(Operation::DefineDnaLadder {
+            name: "Lab Mini 3".to_string(),
+            bands: vec![
+                band(250.0, Some(1.0)),
+                band(750.0, Some(2.0)),
+                band(1500.0, None),
+            ],
+            loading_hint: Some(5.0),
+        })
+        .unwrap();
+
+    let catalog = engine.inspect_dna_ladders(Some("lab mini"));
+    assert_eq!(catalog.ladder_count, 1);
+    assert_eq!(catalog.ladders[0].name, "Lab Mini 3");
+    assert_eq!(catalog.ladders[0].band_count, 3);
+    assert_eq!(catalog.ladders[0].min_bp, Some(250));
+    assert_eq!(catalog.ladders[0].max_bp, Some(1500));
+    assert_eq!(catalog.ladders[0].loading_hint, Some(5.0));
+    assert!(
+        GentleEngine::new()
+            .inspect_dna_ladders(Some("lab mini"))
+            .ladders
+            .is_empty()
+    );
+
+    let td = tempdir().unwrap();
+    let path = td.path().join("custom_ladder_gel.svg");
+    engine
+        .apply(Operation::RenderPoolGelSvg {
+            inputs: vec!["frag".to_string()],
+            path: path.to_string_lossy().to_string(),
+            ladders: Some(vec!["Lab Mini 3".to_string()]),
+            container_ids: None,
+            arrangement_id: None,
+            conditions: None,
+        })
+        .unwrap();
+    let svg = fs::read_to_string(&path).unwrap();
+    assert!(svg.contains("Lab Mini 3"));
+    assert!(svg.contains("750 bp"));
+
+    let layout = engine
+        .build_serial_gel_layout_for_render(
+            &["frag".to_string()],
+            None,
+            None,
+            Some(&["Lab Mini 3".to_string()]),
+            None,
+        )
+        .unwrap();
+    assert_eq!(layout.selected_ladders, vec!["Lab Mini 3".to_string()]);
+
+    let export_path = td.path().join("ladders.json");
+    engine
+        .apply(Operation::ExportDnaLadders {
+            path: export_path.to_string_lossy().to_string(),
+            name_filter: Some("lab mini".to_string()),
+        })
+        .unwrap();
+    let exported: DnaLadderCatalog =
+        serde_json::from_str(&fs::read_to_string(&export_path).unwrap()).unwrap();
+    assert_eq!(exported.ladder_count, 1);
+    assert_eq!(exported.ladders[0].name, "Lab Mini 3");
+}
+
+#[test]
+fn test_define_dna_ladder_rejects_unsorted_or_non_positive_bands() {
+    let mut engine = GentleEngine::new();
+    let band = |length_bp: f64| DnaLadderBandInfo {
+        length_bp,
+        relative_strength: None,
+    };
+    let unsorted = engine
+        .apply(Operation::DefineDnaLadder {
+            name: "Bad order".to_string(),
+            bands: vec![band(500.0), band(100.0), band(1000.0)],
+            loading_hint: None,
+        })
+        .unwrap_err();
+    assert!(matches!(unsorted.code, ErrorCode::InvalidInput));
+    assert!(unsorted.message.contains("sorted"));
+
+    let non_positive = engine
+        .apply(Operation::DefineDnaLadder {
+            name: "Bad length".to_string(),
+            bands: vec![band(0.0), band(100.0)],
+            loading_hint: None,
+        })
+        .unwrap_err();
+    assert!(non_positive.message.contains("positive length"));
+    assert!(engine.inspect_dna_ladders(Some("bad")).ladders.is_empty());
+}
+
 #[test]
 fn test_lineage_ligation_has_two_parents() {
     let mut state = ProjectState::default();
@@ -11322,6 +13485,205 @@ fn test_pcr_multiple_amplicons_without_unique() {
     assert_eq!(res.created_seq_ids.len(), 3);
 }
 
+#[test]
+fn test_pcr_anneals_primers_in_either_template_orientation() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("tpl".to_string(), seq("GACTTCAAAAAAAAAAGTCCAT"));
+    let mut engine = GentleEngine::from_state(state);
+    let pcr = |forward: &str, reverse: &str, output_id: &str| Operation::Pcr {
+        template: "tpl".to_string(),
+        forward_primer: forward.to_string(),
+        reverse_primer: reverse.to_string(),
+        output_id: Some(output_id.to_string()),
+        unique: Some(true),
+    };
+
+    engine.apply(pcr("GACTTC", "ATGGAC", "top")).unwrap();
+    assert_eq!(
+        engine.state().sequences["top"].get_forward_string(),
+        "GACTTCAAAAAAAAAAGTCCAT"
+    );
+
+    // Swapped primers only amplify with the forward primer on the bottom
+    // strand; the product is reported starting at the forward primer.
+    let res = engine.apply(pcr("ATGGAC", "GACTTC", "bottom")).unwrap();
+    assert_eq!(res.created_seq_ids, vec!["bottom".to_string()]);
+    assert_eq!(
+        engine.state().sequences["bottom"].get_forward_string(),
+        "ATGGACTTTTTTTTTTGAAGTC"
+    );
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("reverse-complemented"))
+    );
+}
+
+#[test]
+fn test_pcr_rejects_primers_facing_away_or_on_same_strand() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("tpl".to_string(), seq("GACTTCAAAAAAAAAAGTCCAT"));
+    let mut engine = GentleEngine::from_state(state);
+    let pcr = |forward: &str, reverse: &str| Operation::Pcr {
+        template: "tpl".to_string(),
+        forward_primer: forward.to_string(),
+        reverse_primer: reverse.to_string(),
+        output_id: None,
+        unique: None,
+    };
+
+    let err = engine.apply(pcr("GTCCAT", "GAAGTC")).unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    assert!(err.message.contains("face away"), "{}", err.message);
+
+    let err = engine.apply(pcr("GACTTC", "GTCCAT")).unwrap_err();
+    assert!(
+        err.message.contains("same template strand"),
+        "{}",
+        err.message
+    );
+
+    let err = engine.apply(pcr("GACTTC", "CGCGCG")).unwrap_err();
+    assert!(
+        err.message
+            .contains("Reverse primer binding site not found")
+    );
+}
+
+#[test]
+fn test_nested_pcr_returns_inner_subregion_of_outer_amplicon() {
+    let template = "GCTAAAGACAATTACATAACATACACGTCAGCACGAAACTTGTTGGCC";
+    let mut state = ProjectState::default();
+    state.sequences.insert("tpl".to_string(), seq(template));
+    let mut engine = GentleEngine::from_state(state);
+    let nested = |outer_forward: &str, outer_reverse: &str, output_id: &str| Operation::NestedPcr {
+        template: "tpl".to_string(),
+        outer_forward: outer_forward.to_string(),
+        outer_reverse: outer_reverse.to_string(),
+        inner_forward: "TACATAACAT".to_string(),
+        inner_reverse: "TTTCGTGCTG".to_string(),
+        output_id: Some(output_id.to_string()),
+    };
+
+    let res = engine
+        .apply(nested("GCTAAAGACA", "GGCCAACAAG", "nested"))
+        .unwrap();
+    assert_eq!(res.created_seq_ids, vec!["nested".to_string()]);
+    assert_eq!(
+        engine.state().sequences["nested"].get_forward_string(),
+        &template[12..38]
+    );
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("outer amplicon: 48 bp"))
+    );
+
+    // Swapped outer primers give a reverse-oriented outer product; the inner
+    // pair still resolves to the same sub-region.
+    engine
+        .apply(nested("GGCCAACAAG", "GCTAAAGACA", "nested_swapped"))
+        .unwrap();
+    assert_eq!(
+        engine.state().sequences["nested_swapped"].get_forward_string(),
+        &template[12..38]
+    );
+}
+
+#[test]
+fn test_nested_pcr_fails_when_a_stage_has_no_product() {
+    let template = "GCTAAAGACAATTACATAACATACACGTCAGCACGAAACTTGTTGGCC";
+    let mut state = ProjectState::default();
+    state.sequences.insert("tpl".to_string(), seq(template));
+    let mut engine = GentleEngine::from_state(state);
+
+    let err = engine
+        .apply(Operation::NestedPcr {
+            template: "tpl".to_string(),
+            outer_forward: "CACGAAACTT".to_string(),
+            outer_reverse: "TGTCTTTAGC".to_string(),
+            inner_forward: "TACATAACAT".to_string(),
+            inner_reverse: "TTTCGTGCTG".to_string(),
+            output_id: None,
+        })
+        .unwrap_err();
+    assert!(err.message.contains("outer stage"), "{}", err.message);
+    assert!(err.message.contains("face away"), "{}", err.message);
+
+    // The inner forward primer lies outside the outer amplicon.
+    let err = engine
+        .apply(Operation::NestedPcr {
+            template: "tpl".to_string(),
+            outer_forward: "TACATAACAT".to_string(),
+            outer_reverse: "GGCCAACAAG".to_string(),
+            inner_forward: "GCTAAAGACA".to_string(),
+            inner_reverse: "TTTCGTGCTG".to_string(),
+            output_id: None,
+        })
+        .unwrap_err();
+    assert!(err.message.contains("inner stage"), "{}", err.message);
+    assert_eq!(engine.state().sequences.len(), 1);
+}
+
+#[test]
+fn test_multiplex_pcr_flags_off_target_read_through_amplicon() {
+    // Primer 1 primes forward at 0, primer 3 forward at 60, and primer 2
+    // binds the bottom strand twice (40..50 and 100..110).
+    let template = "GATCCTAGGATTTCCTCATGCAATTCAAAACCATGTCCGTATGCGGTCAAAATGTAGGCGCAGTTCAGGCAAATAGTAAACCATTTTACGGAGGATACCAATGCGGTCAAAATTC";
+    let mut state = ProjectState::default();
+    state.sequences.insert("tpl".to_string(), seq(template));
+    let mut engine = GentleEngine::from_state(state);
+    let multiplex = |max_amplicon_bp: usize| Operation::MultiplexPcr {
+        template: "tpl".to_string(),
+        primers: vec![
+            "GATCCTAGGA".to_string(),
+            "TTGACCGCAT".to_string(),
+            "CAGTTCAGGC".to_string(),
+        ],
+        max_amplicon_bp,
+        output_prefix: Some("mpx".to_string()),
+    };
+
+    let res = engine.apply(multiplex(150)).unwrap();
+    assert_eq!(
+        res.created_seq_ids,
+        vec![
+            "mpx_1".to_string(),
+            "mpx_2".to_string(),
+            "mpx_3".to_string()
+        ]
+    );
+    let sequences = &engine.state().sequences;
+    assert_eq!(sequences["mpx_1"].get_forward_string(), &template[0..50]);
+    assert_eq!(sequences["mpx_2"].get_forward_string(), &template[0..110]);
+    assert_eq!(sequences["mpx_3"].get_forward_string(), &template[60..110]);
+    let off_target = res
+        .warnings
+        .iter()
+        .filter(|w| w.code == WarningCode::OffTargetProduct)
+        .collect::<Vec<_>>();
+    assert_eq!(off_target.len(), 1);
+    assert!(off_target[0].message.contains("'mpx_2'"));
+
+    // A tighter size window drops the read-through product entirely.
+    let res = engine.apply(multiplex(60)).unwrap();
+    assert_eq!(res.created_seq_ids.len(), 2);
+    assert!(res.warnings.is_empty());
+
+    engine.state_mut().parameters.max_fragments_per_container = 2;
+    let res = engine.apply(multiplex(150)).unwrap();
+    assert_eq!(res.created_seq_ids.len(), 2);
+    assert!(
+        res.warnings
+            .iter()
+            .any(|w| w.code == WarningCode::ResultTruncated)
+    );
+}
+
 #[test]
 fn test_pcr_advanced_inserts_5prime_tail() {
     let mut state = ProjectState::default();
@@ -11460,6 +13822,66 @@ fn test_pcr_mutagenesis_single_snp_success() {
     );
 }
 
+#[test]
+fn test_pcr_mutagenesis_warns_when_snp_creates_premature_stop() {
+    let mut dna = seq("ATGAAACCCGGGTTT");
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::simple_range(0, 15),
+        qualifiers: vec![("label".into(), Some("orf".to_string()))],
+    });
+    assert!(GentleEngine::check_cds_integrity(&dna).is_empty());
+    let mut state = ProjectState::default();
+    state.sequences.insert("tpl".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let res = engine
+        .apply(Operation::PcrMutagenesis {
+            template: "tpl".to_string(),
+            forward_primer: PcrPrimerSpec {
+                sequence: "ATGTAA".to_string(),
+                anneal_len: Some(6),
+                max_mismatches: Some(1),
+                require_3prime_exact_bases: Some(2),
+                library_mode: None,
+                max_variants: None,
+                sample_seed: None,
+            },
+            reverse_primer: PcrPrimerSpec {
+                sequence: "AAACCC".to_string(),
+                anneal_len: Some(6),
+                max_mismatches: Some(0),
+                require_3prime_exact_bases: Some(4),
+                library_mode: None,
+                max_variants: None,
+                sample_seed: None,
+            },
+            mutations: vec![SnpMutationSpec {
+                zero_based_position: 3,
+                reference: "A".to_string(),
+                alternate: "T".to_string(),
+            }],
+            output_id: Some("nonsense".to_string()),
+            unique: Some(true),
+            require_all_mutations: Some(true),
+        })
+        .expect("nonsense mutagenesis");
+    assert_eq!(
+        engine
+            .state()
+            .sequences
+            .get("nonsense")
+            .unwrap()
+            .get_forward_string(),
+        "ATGTAACCCGGGTTT"
+    );
+    assert!(
+        res.warnings.iter().any(|warning| warning.message
+            == "Edit of 'tpl': CDS 'orf' has a premature stop codon at codon 2 (position 4)"),
+        "{:?}",
+        res.warnings
+    );
+}
+
 #[test]
 fn test_pcr_mutagenesis_fails_when_requested_snp_not_introduced() {
     let mut state = ProjectState::default();
@@ -11574,6 +13996,71 @@ fn test_pcr_advanced_degenerate_primer_sample_mode() {
     assert_eq!(res.created_seq_ids.len(), 2);
 }
 
+#[test]
+fn test_project_random_seed_makes_primer_sampling_reproducible() {
+    let spec = PcrPrimerSpec {
+        sequence: "NNNNNNAAA".to_string(),
+        anneal_len: Some(9),
+        max_mismatches: Some(6),
+        require_3prime_exact_bases: Some(3),
+        library_mode: Some(PrimerLibraryMode::Sample),
+        max_variants: Some(5),
+        sample_seed: None,
+    };
+    let sampled = |seed: u64| GentleEngine::expand_primer_variants(&spec, 1000, seed).unwrap();
+    assert_eq!(sampled(7), sampled(7));
+    assert_ne!(sampled(7), sampled(8));
+    // An explicit `sample_seed` seeds the same generator and takes
+    // precedence over the project seed.
+    let explicit = PcrPrimerSpec {
+        sample_seed: Some(7),
+        ..spec.clone()
+    };
+    assert_eq!(
+        GentleEngine::expand_primer_variants(&explicit, 1000, 8).unwrap(),
+        sampled(7)
+    );
+
+    let run = || {
+        let mut state = ProjectState::default();
+        state
+            .sequences
+            .insert("tpl".to_string(), seq("CCCATGAAACCCGGGTTTGGG"));
+        let mut engine = GentleEngine::from_state(state);
+        engine
+            .apply(Operation::SetParameter {
+                name: "random_seed".to_string(),
+                value: serde_json::json!(7),
+            })
+            .unwrap();
+        assert_eq!(engine.state().parameters.random_seed, 7);
+        let res = engine
+            .apply(Operation::PcrAdvanced {
+                template: "tpl".to_string(),
+                forward_primer: spec.clone(),
+                reverse_primer: PcrPrimerSpec {
+                    sequence: "CCCAAA".to_string(),
+                    anneal_len: Some(6),
+                    max_mismatches: Some(0),
+                    require_3prime_exact_bases: Some(4),
+                    library_mode: None,
+                    max_variants: None,
+                    sample_seed: None,
+                },
+                output_id: None,
+                unique: Some(false),
+            })
+            .unwrap();
+        res.created_seq_ids
+            .iter()
+            .map(|id| engine.state().sequences[id].get_forward_string())
+            .collect::<Vec<_>>()
+    };
+    let first = run();
+    assert!(!first.is_empty());
+    assert_eq!(first, run());
+}
+
 #[test]
 fn test_load_file_operation() {
     let mut engine = GentleEngine::new();
@@ -11581,6 +14068,8 @@ fn test_load_file_operation() {
         .apply(Operation::LoadFile {
             path: "test_files/pGEX_3X.fa".to_string(),
             as_id: Some("pgex".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
     assert_eq!(res.created_seq_ids, vec!["pgex".to_string()]);
@@ -12143,6 +14632,8 @@ fn test_load_file_operation_genbank_region_anchor_enables_bed_import() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
     assert_eq!(res.created_seq_ids, vec!["tp73".to_string()]);
@@ -12312,6 +14803,8 @@ fn test_load_file_operation_fasta_synthetic_oligo_metadata() {
         .apply(Operation::LoadFile {
             path: path.display().to_string(),
             as_id: Some("oligo".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
     assert_eq!(res.created_seq_ids, vec!["oligo".to_string()]);
@@ -12336,6 +14829,8 @@ fn test_load_file_operation_fasta_dsdna_with_overhang_metadata() {
         .apply(Operation::LoadFile {
             path: path.display().to_string(),
             as_id: Some("sticky".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
 
@@ -13111,6 +15606,7 @@ fn test_ensembl_gene_import_negative_strand_derives_gene_oriented_protein() {
             scope: None,
             output_prefix: Some("toy_negative_protein".to_string()),
             report_id: Some("toy_negative_protein_report".to_string()),
+            translation_table: None,
         })
         .expect("derive protein from negative-strand Ensembl import");
     assert_eq!(derive.created_seq_ids.len(), 1);
@@ -14361,6 +16857,171 @@ fn test_render_rna_structure_svg_operation() {
     assert!(svg.contains("......"));
 }
 
+#[cfg(unix)]
+#[test]
+fn test_render_rna_structure_from_dot_bracket_skips_rnafold() {
+    let td = tempdir().unwrap();
+    let fake_rnapkin = install_fake_rnapkin(td.path());
+    let missing_rnafold = td.path().join("missing_rnafold").display().to_string();
+    let _fold_guard = EnvVarGuard::set("GENTLE_RNAFOLD_BIN", &missing_rnafold);
+    let _rnapkin_guard = EnvVarGuard::set("GENTLE_RNAPKIN_BIN", &fake_rnapkin);
+
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "hairpin".to_string(),
+        synth_oligo("molecule=rna", b"GGGAAAUCCC"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let output = td.path().join("hairpin.structure.svg");
+    let output_text = output.display().to_string();
+
+    let res = engine
+        .apply(Operation::RenderRnaStructureFromDotBracket {
+            seq_id: "hairpin".to_string(),
+            dot_bracket: "(((....)))".to_string(),
+            path: output_text.clone(),
+        })
+        .unwrap();
+
+    assert!(res.messages.iter().any(|m| m.contains("dot-bracket")));
+    let svg = std::fs::read_to_string(&output_text).unwrap();
+    assert!(svg.contains("(((....)))"));
+
+    let missing_rnapkin = td.path().join("missing_rnapkin").display().to_string();
+    let _missing_guard = EnvVarGuard::set("GENTLE_RNAPKIN_BIN", &missing_rnapkin);
+    let err = engine
+        .apply(Operation::RenderRnaStructureFromDotBracket {
+            seq_id: "hairpin".to_string(),
+            dot_bracket: "(((....)))".to_string(),
+            path: output_text,
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::Unsupported));
+    assert!(err.message.contains("requires rnapkin"));
+    assert!(err.message.contains("GENTLE_RNAPKIN_BIN"));
+}
+
+#[test]
+fn test_render_rna_structure_from_dot_bracket_rejects_invalid_structures() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "hairpin".to_string(),
+        synth_oligo("molecule=rna", b"GGGAAAUCCC"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let tmp = tempdir().unwrap();
+    let output = tmp.path().join("invalid.svg").display().to_string();
+
+    for dot_bracket in ["((((...)))", "(((...)))"] {
+        let err = engine
+            .apply(Operation::RenderRnaStructureFromDotBracket {
+                seq_id: "hairpin".to_string(),
+                dot_bracket: dot_bracket.to_string(),
+                path: output.clone(),
+            })
+            .unwrap_err();
+        assert!(matches!(err.code, ErrorCode::InvalidInput), "{dot_bracket}");
+        assert!(err.message.contains("dot-bracket"));
+    }
+    assert!(!Path::new(&output).exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_render_rna_structure_svg_constrained_forces_pair() {
+    let td = tempdir().unwrap();
+    let fake_rnafold = install_fake_constrained_rnafold(td.path());
+    let fake_rnapkin = install_fake_rnapkin(td.path());
+    let _fold_guard = EnvVarGuard::set("GENTLE_RNAFOLD_BIN", &fake_rnafold);
+    let _rnapkin_guard = EnvVarGuard::set("GENTLE_RNAPKIN_BIN", &fake_rnapkin);
+
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "hairpin".to_string(),
+        synth_oligo("molecule=rna", b"GGGAAAUCCC"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let unconstrained = td.path().join("free.svg").display().to_string();
+    let constrained = td.path().join("forced.svg").display().to_string();
+
+    engine
+        .apply(Operation::RenderRnaStructureSvg {
+            seq_id: "hairpin".to_string(),
+            path: unconstrained.clone(),
+        })
+        .unwrap();
+    let res = engine
+        .apply(Operation::RenderRnaStructureSvgConstrained {
+            seq_id: "hairpin".to_string(),
+            path: constrained.clone(),
+            constraints: RnaStructureConstraints {
+                forced_pairs: vec![(1, 10)],
+                unpaired_positions: vec![5],
+            },
+        })
+        .unwrap();
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("constraint=(...x....)"))
+    );
+    let free_svg = std::fs::read_to_string(unconstrained).unwrap();
+    let forced_svg = std::fs::read_to_string(constrained).unwrap();
+    assert!(free_svg.contains(".........."));
+    assert!(forced_svg.contains("(........)"));
+    assert_ne!(free_svg, forced_svg);
+}
+
+#[test]
+fn test_render_rna_structure_svg_constrained_rejects_invalid_constraints() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "hairpin".to_string(),
+        synth_oligo("molecule=rna", b"GGGAAAUCCC"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let tmp = tempdir().unwrap();
+    let output = tmp.path().join("invalid.svg").display().to_string();
+    let cases = [
+        RnaStructureConstraints {
+            forced_pairs: vec![(1, 11)],
+            unpaired_positions: vec![],
+        },
+        RnaStructureConstraints {
+            forced_pairs: vec![(1, 10)],
+            unpaired_positions: vec![10],
+        },
+        RnaStructureConstraints {
+            forced_pairs: vec![(1, 6), (3, 9)],
+            unpaired_positions: vec![],
+        },
+        RnaStructureConstraints {
+            forced_pairs: vec![(4, 4)],
+            unpaired_positions: vec![],
+        },
+    ];
+    for constraints in cases {
+        let err = engine
+            .apply(Operation::RenderRnaStructureSvgConstrained {
+                seq_id: "hairpin".to_string(),
+                path: output.clone(),
+                constraints: constraints.clone(),
+            })
+            .unwrap_err();
+        assert!(
+            matches!(err.code, ErrorCode::InvalidInput),
+            "{constraints:?}: {}",
+            err.message
+        );
+        assert!(
+            err.message.starts_with("Invalid RNA structure constraint:"),
+            "{}",
+            err.message
+        );
+    }
+    assert!(!std::path::Path::new(&output).exists());
+}
+
 #[test]
 fn test_render_rna_structure_svg_requires_rna_biotype() {
     let mut state = ProjectState::default();
@@ -14391,6 +17052,7 @@ fn test_save_file_operation_genbank() {
             seq_id: "s".to_string(),
             path: path_text.clone(),
             format: ExportFormat::GenBank,
+            genbank_options: None,
         })
         .unwrap();
     assert!(res.changed_seq_ids.contains(&"s".to_string()));
@@ -14398,6 +17060,217 @@ fn test_save_file_operation_genbank() {
     assert!(text.contains("LOCUS"));
 }
 
+#[test]
+fn test_set_sequence_description_round_trips_through_genbank() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), seq("ATGCCAGGTTAA"));
+    let mut engine = GentleEngine::from_state(state);
+    let result = engine
+        .apply(Operation::SetSequenceDescription {
+            seq_id: "s".to_string(),
+            description: "  pUC19 backbone, miniprep 2026-10-16 ".to_string(),
+        })
+        .unwrap();
+    assert!(result.changed_seq_ids.contains(&"s".to_string()));
+    let summary = engine.summarize_state();
+    assert_eq!(
+        summary.sequences[0].description.as_deref(),
+        Some("pUC19 backbone, miniprep 2026-10-16")
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let path_text = dir.path().join("s.gb").display().to_string();
+    engine
+        .apply(Operation::SaveFile {
+            seq_id: "s".to_string(),
+            path: path_text.clone(),
+            format: ExportFormat::GenBank,
+            genbank_options: None,
+        })
+        .unwrap();
+    let text = std::fs::read_to_string(&path_text).unwrap();
+    assert!(text.contains("DEFINITION  pUC19 backbone, miniprep 2026-10-16"));
+
+    engine
+        .apply(Operation::LoadFile {
+            path: path_text,
+            as_id: Some("reloaded".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    assert_eq!(
+        engine.state().sequences["reloaded"].definition(),
+        Some("pUC19 backbone, miniprep 2026-10-16")
+    );
+
+    engine
+        .apply(Operation::SetSequenceDescription {
+            seq_id: "s".to_string(),
+            description: " ".to_string(),
+        })
+        .unwrap();
+    assert_eq!(engine.state().sequences["s"].definition(), None);
+    let err = engine
+        .apply(Operation::SetSequenceDescription {
+            seq_id: "missing".to_string(),
+            description: "x".to_string(),
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::NotFound));
+}
+
+#[test]
+fn test_tag_sequence_normalizes_tags_and_supports_queries() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("a".to_string(), seq("ATGCCA"));
+    state.sequences.insert("b".to_string(), seq("GGATCC"));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::TagSequence {
+            seq_id: "a".to_string(),
+            add: vec![
+                " Backbone ".to_string(),
+                "kanR".to_string(),
+                "backbone".to_string(),
+            ],
+            remove: vec![],
+        })
+        .unwrap();
+    engine
+        .apply(Operation::TagSequence {
+            seq_id: "b".to_string(),
+            add: vec!["BACKBONE".to_string()],
+            remove: vec![],
+        })
+        .unwrap();
+    assert_eq!(
+        engine.sequence_tags("a"),
+        vec!["backbone".to_string(), "kanr".to_string()]
+    );
+    assert_eq!(
+        engine.sequences_with_tag("Backbone"),
+        vec!["a".to_string(), "b".to_string()]
+    );
+    assert_eq!(engine.sequences_with_tag("kanr"), vec!["a".to_string()]);
+
+    engine
+        .apply(Operation::TagSequence {
+            seq_id: "a".to_string(),
+            add: vec![],
+            remove: vec!["BACKBONE".to_string(), "missing".to_string()],
+        })
+        .unwrap();
+    assert_eq!(engine.sequences_with_tag("backbone"), vec!["b".to_string()]);
+
+    engine
+        .apply(Operation::RenameSequence {
+            from: "a".to_string(),
+            to: "a2".to_string(),
+        })
+        .unwrap();
+    assert_eq!(engine.sequences_with_tag("kanr"), vec!["a2".to_string()]);
+
+    let err = engine
+        .apply(Operation::TagSequence {
+            seq_id: "b".to_string(),
+            add: vec!["x".to_string()],
+            remove: vec![" X".to_string()],
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+    let err = engine
+        .apply(Operation::TagSequence {
+            seq_id: "b".to_string(),
+            add: vec!["  ".to_string()],
+            remove: vec![],
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+}
+
+#[test]
+fn test_save_file_genbank_with_explicit_locus_options() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), seq("ATGCCAGGTTAA"));
+    let mut engine = GentleEngine::from_state(state);
+    let dir = tempfile::tempdir().unwrap();
+    let path_text = dir.path().join("s.gb").display().to_string();
+    engine
+        .apply(Operation::SaveFile {
+            seq_id: "s".to_string(),
+            path: path_text.clone(),
+            format: ExportFormat::GenBank,
+            genbank_options: Some(GenBankExportOptions {
+                molecule_type: Some("mRNA".to_string()),
+                division: Some("pln".to_string()),
+                date: Some("2024-03-05".to_string()),
+                accession: Some("AB123456".to_string()),
+                definition: Some("Test construct".to_string()),
+            }),
+        })
+        .unwrap();
+    let text = std::fs::read_to_string(&path_text).unwrap();
+    let locus = text
+        .lines()
+        .find(|line| line.starts_with("LOCUS"))
+        .unwrap()
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    assert!(locus.contains(&"mRNA"), "{locus:?}");
+    assert!(locus.contains(&"PLN"), "{locus:?}");
+    assert_eq!(locus.last(), Some(&"05-MAR-2024"));
+
+    let parsed = gb_io::reader::parse_file(&path_text).unwrap();
+    assert_eq!(parsed.len(), 1);
+    let record = &parsed[0];
+    assert_eq!(record.molecule_type.as_deref(), Some("mRNA"));
+    assert_eq!(record.division, "PLN");
+    let date = record.date.as_ref().unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (2024, 3, 5));
+    assert_eq!(record.accession.as_deref(), Some("AB123456"));
+    assert_eq!(record.definition.as_deref(), Some("Test construct"));
+    // The stored sequence keeps its own header.
+    assert_ne!(engine.state().sequences["s"].molecule_type(), Some("mRNA"));
+
+    // Unset fields fall back to the sequence's values, then DNA/SYN.
+    engine
+        .apply(Operation::SaveFile {
+            seq_id: "s".to_string(),
+            path: path_text.clone(),
+            format: ExportFormat::GenBank,
+            genbank_options: Some(GenBankExportOptions {
+                date: Some("05-Mar-2024".to_string()),
+                ..GenBankExportOptions::default()
+            }),
+        })
+        .unwrap();
+    let record = gb_io::reader::parse_file(&path_text).unwrap().remove(0);
+    assert_eq!(record.division, "SYN");
+    assert_eq!(
+        record.molecule_type.as_deref(),
+        Some(
+            engine.state().sequences["s"]
+                .molecule_type()
+                .unwrap_or("DNA")
+        )
+    );
+    assert_eq!(record.date.map(|date| date.month()), Some(3));
+
+    let err = engine
+        .apply(Operation::SaveFile {
+            seq_id: "s".to_string(),
+            path: path_text,
+            format: ExportFormat::GenBank,
+            genbank_options: Some(GenBankExportOptions {
+                date: Some("March 5".to_string()),
+                ..GenBankExportOptions::default()
+            }),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
 #[test]
 fn test_set_topology_operation() {
     let mut state = ProjectState::default();
@@ -14428,6 +17301,409 @@ fn test_recompute_features_operation() {
     assert!(res.changed_seq_ids.contains(&"s".to_string()));
 }
 
+#[test]
+fn test_recompute_features_reuses_cache_until_sequence_changes() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("s".to_string(), seq("ATGAAAGAATTCCCCGGGTTT"));
+    let mut engine = GentleEngine::from_state(state);
+    let stats =
+        |engine: &GentleEngine| engine.state().sequences["s"].computed_feature_cache_stats();
+    let recompute = |engine: &mut GentleEngine| {
+        engine
+            .apply(Operation::RecomputeFeatures {
+                seq_id: "s".to_string(),
+            })
+            .unwrap();
+    };
+    let td = tempdir().unwrap();
+    let svg_path = td.path().join("s.svg").to_string_lossy().to_string();
+
+    recompute(&mut engine);
+    let before = stats(&engine);
+    engine
+        .apply(Operation::RenderSequenceSvg {
+            seq_id: "s".to_string(),
+            mode: RenderSvgMode::Linear,
+            path: svg_path,
+            viewport: None,
+        })
+        .unwrap();
+    recompute(&mut engine);
+    let after = stats(&engine);
+    assert_eq!(after.misses, before.misses);
+    assert_eq!(after.hits, before.hits + 1);
+
+    engine
+        .apply(Operation::SetTopology {
+            seq_id: "s".to_string(),
+            circular: true,
+        })
+        .unwrap();
+    recompute(&mut engine);
+    assert_eq!(stats(&engine).misses, after.misses + 1);
+}
+
+#[test]
+fn test_edit_feature_qualifier_sets_overwrites_and_removes_label() {
+    let mut dna = seq("ATGAAACCCGGGTTT");
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "misc_feature".into(),
+        location: gb_io::seq::Location::simple_range(2, 9),
+        qualifiers: vec![
+            ("note".into(), Some("keep me".to_string())),
+            ("gene".into(), Some("demo".to_string())),
+        ],
+    });
+    let feature_id = dna.features().len() - 1;
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let qualifiers = |engine: &GentleEngine| {
+        engine.state().sequences["s"].features()[feature_id]
+            .qualifiers
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone().unwrap_or_default()))
+            .collect::<Vec<_>>()
+    };
+    let edit = |engine: &mut GentleEngine, value: Option<&str>| {
+        engine.apply(Operation::EditFeatureQualifier {
+            seq_id: "s".to_string(),
+            feature_id,
+            key: "label".to_string(),
+            value: value.map(str::to_string),
+        })
+    };
+
+    let res = edit(&mut engine, Some("first")).unwrap();
+    assert_eq!(res.changed_seq_ids, vec!["s".to_string()]);
+    assert_eq!(
+        qualifiers(&engine),
+        vec![
+            ("note".to_string(), "keep me".to_string()),
+            ("gene".to_string(), "demo".to_string()),
+            ("label".to_string(), "first".to_string()),
+        ]
+    );
+
+    edit(&mut engine, Some("second")).unwrap();
+    assert_eq!(
+        qualifiers(&engine),
+        vec![
+            ("note".to_string(), "keep me".to_string()),
+            ("gene".to_string(), "demo".to_string()),
+            ("label".to_string(), "second".to_string()),
+        ]
+    );
+
+    edit(&mut engine, None).unwrap();
+    assert_eq!(
+        qualifiers(&engine),
+        vec![
+            ("note".to_string(), "keep me".to_string()),
+            ("gene".to_string(), "demo".to_string()),
+        ]
+    );
+
+    let err = engine
+        .apply(Operation::EditFeatureQualifier {
+            seq_id: "s".to_string(),
+            feature_id: feature_id + 1,
+            key: "label".to_string(),
+            value: Some("x".to_string()),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
+#[test]
+fn test_add_joined_feature_and_delete_feature() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("s".to_string(), seq(&"ATGAAACCCGGGTTT".repeat(4)));
+    let mut engine = GentleEngine::from_state(state);
+    let base_count = engine.state().sequences["s"].features().len();
+
+    engine
+        .apply(Operation::AddFeature {
+            seq_id: "s".to_string(),
+            kind: "CDS".to_string(),
+            start_0based: 3,
+            end_0based: 40,
+            strand: Some('-'),
+            qualifiers: HashMap::from([
+                ("label".to_string(), "split".to_string()),
+                ("gene".to_string(), "demo".to_string()),
+            ]),
+            segments: Some(vec![
+                SequenceRange0Based {
+                    start_0based: 3,
+                    end_0based_exclusive: 12,
+                },
+                SequenceRange0Based {
+                    start_0based: 20,
+                    end_0based_exclusive: 40,
+                },
+            ]),
+        })
+        .unwrap();
+    engine
+        .apply(Operation::AddFeature {
+            seq_id: "s".to_string(),
+            kind: "misc_feature".to_string(),
+            start_0based: 50,
+            end_0based: 55,
+            strand: None,
+            qualifiers: HashMap::new(),
+            segments: None,
+        })
+        .unwrap();
+    let features = engine.state().sequences["s"].features();
+    assert_eq!(features.len(), base_count + 2);
+    let joined = &features[base_count];
+    assert_eq!(joined.kind.to_string(), "CDS");
+    assert_eq!(
+        joined.location,
+        gb_io::seq::Location::Complement(Box::new(gb_io::seq::Location::Join(vec![
+            gb_io::seq::Location::simple_range(3, 12),
+            gb_io::seq::Location::simple_range(20, 40),
+        ])))
+    );
+    assert_eq!(
+        joined
+            .qualifiers
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone().unwrap_or_default()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("gene".to_string(), "demo".to_string()),
+            ("label".to_string(), "split".to_string()),
+        ]
+    );
+
+    for (start, end, segments) in [
+        (55, 61, None),
+        (
+            3,
+            40,
+            Some(vec![
+                SequenceRange0Based {
+                    start_0based: 20,
+                    end_0based_exclusive: 40,
+                },
+                SequenceRange0Based {
+                    start_0based: 3,
+                    end_0based_exclusive: 12,
+                },
+            ]),
+        ),
+    ] {
+        let err = engine
+            .apply(Operation::AddFeature {
+                seq_id: "s".to_string(),
+                kind: "misc_feature".to_string(),
+                start_0based: start,
+                end_0based: end,
+                strand: None,
+                qualifiers: HashMap::new(),
+                segments,
+            })
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+    }
+
+    engine
+        .apply(Operation::DeleteFeature {
+            seq_id: "s".to_string(),
+            feature_id: base_count,
+        })
+        .unwrap();
+    let features = engine.state().sequences["s"].features();
+    assert_eq!(features.len(), base_count + 1);
+    // The later feature shifted down into the deleted index.
+    assert_eq!(features[base_count].kind.to_string(), "misc_feature");
+    let err = engine
+        .apply(Operation::DeleteFeature {
+            seq_id: "s".to_string(),
+            feature_id: base_count + 1,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
+#[test]
+fn test_strip_annotations_drops_all_or_all_but_cds() {
+    let mut dna = seq("ATGAAACCCGGGTTTTAGCCGG");
+    dna.set_circular(true);
+    for (kind, start, end) in [("CDS", 0, 18), ("misc_feature", 3, 9), ("gene", 0, 18)] {
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: kind.into(),
+            location: gb_io::seq::Location::simple_range(start, end),
+            qualifiers: vec![("label".into(), Some(kind.to_string()))],
+        });
+    }
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+
+    let res = engine
+        .apply(Operation::StripAnnotations {
+            input: "s".to_string(),
+            keep_kinds: vec![],
+            output_id: None,
+        })
+        .unwrap();
+    assert_eq!(res.created_seq_ids, vec!["s_clean".to_string()]);
+    let bare = &engine.state().sequences["s_clean"];
+    assert!(bare.features().is_empty());
+    assert!(bare.is_circular());
+    assert_eq!(
+        bare.get_forward_string(),
+        engine.state().sequences["s"].get_forward_string()
+    );
+    assert_eq!(engine.state().sequences["s"].features().len(), 3);
+    let lineage = &engine.state().lineage;
+    let node = lineage.seq_to_node.get("s_clean").unwrap();
+    assert!(matches!(
+        lineage.nodes.get(node).unwrap().origin,
+        SequenceOrigin::Derived
+    ));
+
+    engine
+        .apply(Operation::StripAnnotations {
+            input: "s".to_string(),
+            keep_kinds: vec!["cds".to_string()],
+            output_id: Some("s_cds".to_string()),
+        })
+        .unwrap();
+    let kinds = engine.state().sequences["s_cds"]
+        .features()
+        .iter()
+        .map(|feature| feature.kind.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["CDS".to_string()]);
+}
+
+const FEATURE_TRANSFER_TEST_SEQUENCE: &str = "GCTAAAGACAATTACATAACATACACGTCAGCACGAAACTTGTTGGCCCAGTGTGAATCGCTTAAGGGTTAAGTAAGTGTGATGCATACGCCTTTACTTGCTGTGTCCACCCCATCGGAC";
+
+fn feature_transfer_test_source() -> DNAsequence {
+    let mut dna = seq(FEATURE_TRANSFER_TEST_SEQUENCE);
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "misc_feature".into(),
+        location: gb_io::seq::Location::simple_range(20, 60),
+        qualifiers: vec![("label".into(), Some("probe".to_string()))],
+    });
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::Complement(Box::new(gb_io::seq::Location::simple_range(
+            70, 100,
+        ))),
+        qualifiers: vec![("label".into(), Some("orf_rev".to_string()))],
+    });
+    dna
+}
+
+fn feature_transfer_snp_variant() -> String {
+    let mut variant = FEATURE_TRANSFER_TEST_SEQUENCE.as_bytes().to_vec();
+    variant[40] = if variant[40] == b'A' { b'C' } else { b'A' };
+    String::from_utf8(variant).unwrap()
+}
+
+#[test]
+fn test_transfer_features_onto_single_snp_variant() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("ref".to_string(), feature_transfer_test_source());
+    state
+        .sequences
+        .insert("variant".to_string(), seq(&feature_transfer_snp_variant()));
+    let mut engine = GentleEngine::from_state(state);
+    let res = engine
+        .apply(Operation::TransferFeatures {
+            from: "ref".to_string(),
+            to: "variant".to_string(),
+            min_identity: 0.9,
+            flank_bp: 5,
+        })
+        .unwrap();
+    assert!(res.changed_seq_ids.contains(&"variant".to_string()));
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+    let variant = engine.state().sequences.get("variant").unwrap();
+    let probe = variant
+        .features()
+        .iter()
+        .find(|feature| first_qualifier(feature, "label").as_deref() == Some("probe"))
+        .expect("transferred probe feature");
+    assert_eq!(probe.location, gb_io::seq::Location::simple_range(20, 60));
+    assert_eq!(
+        first_qualifier(probe, "note").as_deref(),
+        Some("transferred")
+    );
+    let orf = variant
+        .features()
+        .iter()
+        .find(|feature| first_qualifier(feature, "label").as_deref() == Some("orf_rev"))
+        .expect("transferred reverse feature");
+    assert_eq!(
+        orf.location,
+        gb_io::seq::Location::Complement(Box::new(gb_io::seq::Location::simple_range(70, 100)))
+    );
+
+    let strict = engine
+        .apply(Operation::TransferFeatures {
+            from: "ref".to_string(),
+            to: "variant".to_string(),
+            min_identity: 1.0,
+            flank_bp: 0,
+        })
+        .unwrap();
+    assert!(
+        strict
+            .warnings
+            .iter()
+            .any(|warning| warning.message.contains("'probe'")
+                && warning.message.contains("no match"))
+    );
+}
+
+#[test]
+fn test_transfer_features_skips_ambiguous_matches_with_warning() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("ref".to_string(), feature_transfer_test_source());
+    let probe_bases = &FEATURE_TRANSFER_TEST_SEQUENCE[20..60];
+    state.sequences.insert(
+        "dup".to_string(),
+        seq(&format!("{probe_bases}TTTTTTTTTT{probe_bases}")),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let res = engine
+        .apply(Operation::TransferFeatures {
+            from: "ref".to_string(),
+            to: "dup".to_string(),
+            min_identity: 0.95,
+            flank_bp: 0,
+        })
+        .unwrap();
+    assert!(res.warnings.iter().any(
+        |warning| warning.message.contains("'probe'") && warning.message.contains("ambiguous")
+    ));
+    assert!(
+        engine
+            .state()
+            .sequences
+            .get("dup")
+            .unwrap()
+            .features()
+            .iter()
+            .all(|feature| first_qualifier(feature, "label").as_deref() != Some("probe"))
+    );
+}
+
 #[test]
 fn test_prepare_scoring_matrices_avoid_negative_infinity() {
     let matrix = vec![[10.0, 0.0, 0.0, 0.0], [5.0, 0.0, 0.0, 0.0]];
@@ -14499,6 +17775,86 @@ fn test_annotate_tfbs_adds_scored_features() {
     }));
 }
 
+#[test]
+fn test_annotation_profile_saves_tfbs_settings_and_applies_to_two_sequences() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("a".to_string(), seq("TTTACGTAAACGTGGG"));
+    state
+        .sequences
+        .insert("b".to_string(), seq("GGGACGTCCCACGTTT"));
+    state.sequences.insert("c".to_string(), seq("TTTTTTTT"));
+    let mut engine = GentleEngine::from_state(state);
+    let payload = AnnotationProfilePayload {
+        tfbs: Some(AnnotationProfileTfbs {
+            motifs: vec!["ACGT".to_string()],
+            min_llr_bits: Some(0.0),
+            min_llr_quantile: Some(0.0),
+            clear_existing: Some(true),
+            ..AnnotationProfileTfbs::default()
+        }),
+        methylation: Some(AnnotationProfileMethylation {
+            dam: true,
+            dcm: false,
+        }),
+    };
+    engine
+        .apply(Operation::SaveAnnotationProfile {
+            name: " promoter_scan ".to_string(),
+            payload: payload.clone(),
+        })
+        .unwrap();
+    let stored = engine.get_annotation_profile("promoter_scan").unwrap();
+    assert_eq!(stored.payload, payload);
+    assert_eq!(engine.list_annotation_profiles().len(), 1);
+    assert!(
+        engine
+            .state()
+            .metadata
+            .contains_key(ANNOTATION_PROFILES_METADATA_KEY)
+    );
+
+    let res = engine
+        .apply(Operation::ApplyAnnotationProfile {
+            name: "promoter_scan".to_string(),
+            seq_ids: vec!["a".to_string(), "b".to_string()],
+        })
+        .unwrap();
+    assert_eq!(res.changed_seq_ids, vec!["a".to_string(), "b".to_string()]);
+    let tfbs_count = |engine: &GentleEngine, seq_id: &str| {
+        engine.state().sequences[seq_id]
+            .features()
+            .iter()
+            .filter(|f| {
+                f.qualifier_values("gentle_generated")
+                    .any(|v| v.eq_ignore_ascii_case("tfbs"))
+            })
+            .count()
+    };
+    assert!(tfbs_count(&engine, "a") > 0);
+    assert!(tfbs_count(&engine, "b") > 0);
+    assert_eq!(tfbs_count(&engine, "c"), 0);
+    let mode = engine.state().sequences["b"].methylation_mode();
+    assert!(mode.dam() && !mode.dcm());
+
+    let err = engine
+        .apply(Operation::ApplyAnnotationProfile {
+            name: "promoter_scan".to_string(),
+            seq_ids: vec!["c".to_string(), "missing".to_string()],
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+    assert_eq!(tfbs_count(&engine, "c"), 0);
+    let err = engine
+        .apply(Operation::SaveAnnotationProfile {
+            name: "empty".to_string(),
+            payload: AnnotationProfilePayload::default(),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
 #[test]
 fn test_annotate_tfbs_progress_reaches_completion() {
     let mut state = ProjectState::default();
@@ -15986,12 +19342,11 @@ fn test_derive_transcript_sequences_reverse_strand_adds_translation_context_warn
         GentleEngine::feature_qualifier_text(cds, "translation_table_source").as_deref(),
         Some("organelle_vertebrate_mitochondrial_default")
     );
-    assert!(
-        !result
-            .warnings
-            .iter()
-            .any(|warning| warning.contains("Mitochondrial context was detected"))
-    );
+    assert!(!result.warnings.iter().any(|warning| {
+        warning
+            .message
+            .contains("Mitochondrial context was detected")
+    }));
 }
 
 #[test]
@@ -16201,12 +19556,11 @@ fn test_derive_transcript_sequences_uses_invertebrate_mitochondrial_default() {
         GentleEngine::feature_qualifier_text(cds, "translation_table_source").as_deref(),
         Some("organelle_invertebrate_mitochondrial_default")
     );
-    assert!(
-        !result
-            .warnings
-            .iter()
-            .any(|warning| warning.contains("Mitochondrial context was detected"))
-    );
+    assert!(!result.warnings.iter().any(|warning| {
+        warning
+            .message
+            .contains("Mitochondrial context was detected")
+    }));
 }
 
 #[test]
@@ -16255,7 +19609,7 @@ fn test_derive_transcript_sequences_uses_bundled_mouse_speed_profile_reference()
         !result
             .warnings
             .iter()
-            .any(|warning| warning.contains("rat codon-preference proxy"))
+            .any(|warning| warning.message.contains("rat codon-preference proxy"))
     );
 }
 
@@ -16288,6 +19642,7 @@ fn test_derive_protein_sequences_creates_first_class_protein_sequence() {
             scope: None,
             output_prefix: Some("prot".to_string()),
             report_id: None,
+            translation_table: None,
         })
         .expect("derive protein");
     assert_eq!(result.created_seq_ids.len(), 1);
@@ -16327,6 +19682,51 @@ fn test_derive_protein_sequences_creates_first_class_protein_sequence() {
     );
 }
 
+#[test]
+fn test_derive_protein_sequences_honors_translation_table_override() {
+    let mut dna = DNAsequence::from_sequence("TTTATGTGATGGAAATAAGGG").expect("sequence");
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "mRNA".into(),
+        location: gb_io::seq::Location::simple_range(3, 18),
+        qualifiers: vec![("transcript_id".into(), Some("TX_MT".to_string()))],
+    });
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let derive = |engine: &mut GentleEngine, translation_table: Option<usize>| {
+        engine.apply(Operation::DeriveProteinSequences {
+            seq_id: "s".to_string(),
+            feature_ids: vec![0],
+            feature_query: None,
+            scope: None,
+            output_prefix: None,
+            report_id: None,
+            translation_table,
+        })
+    };
+
+    let standard = derive(&mut engine, None).expect("standard code");
+    let standard_protein =
+        engine.state().sequences[&standard.created_seq_ids[0]].get_forward_string();
+    assert!(!standard_protein.contains('W'));
+
+    let mito = derive(&mut engine, Some(2)).expect("vertebrate mitochondrial code");
+    let protein = &engine.state().sequences[&mito.created_seq_ids[0]];
+    assert_eq!(protein.get_forward_string(), "MWWK");
+    let feature = protein
+        .features()
+        .iter()
+        .find(|feature| feature.kind.to_string().eq_ignore_ascii_case("Protein"))
+        .expect("protein feature");
+    assert_eq!(
+        GentleEngine::feature_qualifier_text(feature, "translation_table_source").as_deref(),
+        Some("explicit_operation_override")
+    );
+
+    let err = derive(&mut engine, Some(7)).unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
 #[test]
 fn test_derive_protein_sequences_falls_back_to_inferred_orf_without_cds() {
     let mut dna = DNAsequence::from_sequence("TTTATGAAACCCTAAGGG").expect("sequence");
@@ -16355,6 +19755,7 @@ fn test_derive_protein_sequences_falls_back_to_inferred_orf_without_cds() {
             scope: None,
             output_prefix: Some("prot".to_string()),
             report_id: None,
+            translation_table: None,
         })
         .expect("derive protein without cds");
     assert_eq!(result.created_seq_ids.len(), 1);
@@ -16377,7 +19778,7 @@ fn test_derive_protein_sequences_falls_back_to_inferred_orf_without_cds() {
         result
             .warnings
             .iter()
-            .any(|warning| warning.contains("inferred a forward ORF"))
+            .any(|warning| warning.message.contains("inferred a forward ORF"))
     );
 }
 
@@ -16410,6 +19811,7 @@ fn test_derive_protein_sequences_persists_protein_derivation_report() {
             scope: None,
             output_prefix: Some("prot".to_string()),
             report_id: None,
+            translation_table: None,
         })
         .expect("derive protein");
 
@@ -16477,6 +19879,7 @@ fn test_render_protease_digest_gel_from_report_uses_latest_created_protein_id()
             scope: None,
             output_prefix: Some("prot".to_string()),
             report_id: Some("prot_report".to_string()),
+            translation_table: None,
         })
         .expect("first protein derivation");
     let second = engine
@@ -16487,6 +19890,7 @@ fn test_render_protease_digest_gel_from_report_uses_latest_created_protein_id()
             scope: None,
             output_prefix: Some("prot".to_string()),
             report_id: Some("prot_report".to_string()),
+            translation_table: None,
         })
         .expect("second protein derivation");
     assert_ne!(first.created_seq_ids[0], second.created_seq_ids[0]);
@@ -16523,6 +19927,8 @@ fn test_isoform_protein_gel_demo_loads_derives_and_renders() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73_locus".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load bundled tp73 locus");
     let loaded = engine
@@ -16568,6 +19974,7 @@ fn test_isoform_protein_gel_demo_loads_derives_and_renders() {
             scope: None,
             output_prefix: Some("tp73_isoform_protein".to_string()),
             report_id: Some("isoform_protein_gel_demo".to_string()),
+            translation_table: None,
         })
         .expect("derive proteins from demo transcript features");
     assert_eq!(derive_result.created_seq_ids.len(), 13);
@@ -16628,6 +20035,8 @@ fn test_grouped_isoform_protein_gel_renders_report_columns() {
             .apply(Operation::LoadFile {
                 path: "test_files/tp73.ncbi.gb".to_string(),
                 as_id: Some(seq_id.to_string()),
+                skip_duplicates: None,
+                uppercase: None,
             })
             .expect("load bundled transcript-rich locus fixture");
 
@@ -16662,6 +20071,7 @@ fn test_grouped_isoform_protein_gel_renders_report_columns() {
                 scope: None,
                 output_prefix: Some(prefix.to_string()),
                 report_id: Some(report_id.to_string()),
+                translation_table: None,
             })
             .expect("derive fixture proteins for grouped gel");
         assert_eq!(derive_result.created_seq_ids.len(), 2);
@@ -16703,6 +20113,8 @@ fn test_isoform_protein_2d_gel_demo_loads_derives_and_renders() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73_locus".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load bundled tp73 locus");
     let loaded = engine
@@ -16748,6 +20160,7 @@ fn test_isoform_protein_2d_gel_demo_loads_derives_and_renders() {
             scope: None,
             output_prefix: Some("tp73_isoform_protein".to_string()),
             report_id: Some("isoform_protein_2d_gel_demo".to_string()),
+            translation_table: None,
         })
         .expect("derive proteins from demo transcript features");
     assert_eq!(derive_result.created_seq_ids.len(), 13);
@@ -16967,6 +20380,7 @@ fn build_protein_to_dna_handoff_reasoning_prefers_transcript_native_reuse_over_r
             scope: None,
             output_prefix: Some("handoff_protein".to_string()),
             report_id: None,
+            translation_table: None,
         })
         .expect("derive protein")
         .created_seq_ids[0]
@@ -17725,6 +21139,7 @@ fn test_render_sequence_svg_operation() {
             seq_id: "s".to_string(),
             mode: RenderSvgMode::Linear,
             path: path_text.clone(),
+            viewport: None,
         })
         .unwrap();
     assert!(res.messages.iter().any(|m| m.contains("SVG")));
@@ -17745,6 +21160,7 @@ fn test_visual_benchmark_dense_plasmid_sequence_svg_operation() {
             seq_id: "dense_plasmid".to_string(),
             mode: RenderSvgMode::Linear,
             path: linear_path.display().to_string(),
+            viewport: None,
         })
         .expect("render dense plasmid linear svg");
     engine
@@ -17752,6 +21168,7 @@ fn test_visual_benchmark_dense_plasmid_sequence_svg_operation() {
             seq_id: "dense_plasmid".to_string(),
             mode: RenderSvgMode::Circular,
             path: circular_path.display().to_string(),
+            viewport: None,
         })
         .expect("render dense plasmid circular svg");
 
@@ -17840,12 +21257,62 @@ fn test_render_sequence_svg_operation_honors_linear_viewport() {
             seq_id: "s".to_string(),
             mode: RenderSvgMode::Linear,
             path: path_text.clone(),
+            viewport: None,
         })
         .unwrap();
     let text = std::fs::read_to_string(path_text).unwrap();
     assert!(text.contains("41..60 (20 bp view of 160 bp)"));
 }
 
+#[test]
+fn test_render_sequence_svg_explicit_viewport_clips_features() {
+    let mut dna = seq(&"ATGC".repeat(100));
+    for (label, start, end) in [("left_marker", 10, 40), ("right_marker", 300, 340)] {
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: "misc_feature".into(),
+            location: gb_io::seq::Location::simple_range(start, end),
+            qualifiers: vec![("label".into(), Some(label.to_string()))],
+        });
+    }
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let td = tempdir().unwrap();
+    let render = |engine: &mut GentleEngine, name: &str, viewport| {
+        let path = td.path().join(name).display().to_string();
+        engine
+            .apply(Operation::RenderSequenceSvg {
+                seq_id: "s".to_string(),
+                mode: RenderSvgMode::Linear,
+                path: path.clone(),
+                viewport,
+            })
+            .unwrap();
+        std::fs::read_to_string(path).unwrap()
+    };
+
+    let clipped = render(&mut engine, "clipped.svg", Some((0, 100)));
+    assert!(clipped.contains("1..100 (100 bp view of 400 bp)"));
+    assert!(clipped.contains("left_marker"));
+    assert!(!clipped.contains("right_marker"));
+    // The explicit viewport does not leak into the shared display settings.
+    assert_eq!(engine.state().display.linear_view_span_bp, 0);
+
+    let full = render(&mut engine, "full.svg", None);
+    assert!(full.contains("left_marker"));
+    assert!(full.contains("right_marker"));
+
+    let err = engine
+        .apply(Operation::RenderSequenceSvg {
+            seq_id: "s".to_string(),
+            mode: RenderSvgMode::Linear,
+            path: td.path().join("bad.svg").display().to_string(),
+            viewport: Some((10, 0)),
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+}
+
 #[test]
 fn test_render_dotplot_svg_operation() {
     let mut state = ProjectState::default();
@@ -18270,6 +21737,7 @@ fn test_render_lineage_svg_includes_arrangement_nodes() {
             arrangement_id: Some("arr-viz".to_string()),
             name: Some("Digest run".to_string()),
             ladders: Some(vec!["NEB 100bp DNA Ladder".to_string()]),
+            replicate_count: None,
         })
         .unwrap();
 
@@ -18662,6 +22130,7 @@ fn test_create_arrangement_serial_operation() {
             arrangement_id: Some("arr-test".to_string()),
             name: Some("Digest run".to_string()),
             ladders: Some(vec!["NEB 1kb DNA Ladder".to_string()]),
+            replicate_count: None,
         })
         .unwrap();
     assert!(
@@ -18763,6 +22232,7 @@ fn create_arrangement_serial_auto_creates_default_rack_small_tube() {
             arrangement_id: Some("arr-rack".to_string()),
             name: Some("Rack demo".to_string()),
             ladders: Some(vec!["NEB 100bp DNA Ladder".to_string()]),
+            replicate_count: None,
         })
         .expect("create arrangement");
     assert!(
@@ -18985,6 +22455,7 @@ fn move_rack_sample_within_same_block_shifts_neighbors() {
             arrangement_id: Some("arr-move".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -19053,6 +22524,7 @@ fn move_rack_arrangement_blocks_preserves_block_order_and_shifts_neighbors() {
             arrangement_id: Some("arr-a".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr a");
     engine
@@ -19061,6 +22533,7 @@ fn move_rack_arrangement_blocks_preserves_block_order_and_shifts_neighbors() {
             arrangement_id: Some("arr-b".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr b");
     engine
@@ -19069,6 +22542,7 @@ fn move_rack_arrangement_blocks_preserves_block_order_and_shifts_neighbors() {
             arrangement_id: Some("arr-c".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr c");
     let rack_id = engine
@@ -19160,6 +22634,7 @@ fn move_rack_single_arrangement_block_shifts_later_neighbor_after_drop_target()
             arrangement_id: Some("arr-a".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr a");
     engine
@@ -19168,6 +22643,7 @@ fn move_rack_single_arrangement_block_shifts_later_neighbor_after_drop_target()
             arrangement_id: Some("arr-b".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr b");
     let rack_id = engine
@@ -19244,6 +22720,7 @@ fn move_rack_samples_preserves_selected_order_within_one_arrangement_block() {
             arrangement_id: Some("arr-a".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr a");
     let rack_id = engine
@@ -19317,6 +22794,7 @@ fn set_custom_rack_profile_reflows_placements_and_marks_snapshot_custom() {
             arrangement_id: Some("arr-custom".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -19407,6 +22885,7 @@ fn set_rack_fill_direction_column_major_reflows_placements() {
             arrangement_id: Some("arr-fill".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -19484,6 +22963,7 @@ fn apply_rack_template_plate_edge_avoidance_sets_column_major_and_blocks_perimet
             arrangement_id: Some("arr-template".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -19578,6 +23058,7 @@ fn set_rack_blocked_coordinates_reflows_and_skips_reserved_slots() {
             arrangement_id: Some("arr-blocked".to_string()),
             name: None,
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -19655,6 +23136,7 @@ fn place_arrangement_on_existing_rack_appends_second_block_and_exports_labels()
             arrangement_id: Some("arr-a".to_string()),
             name: Some("A".to_string()),
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr a");
     engine
@@ -19663,6 +23145,7 @@ fn place_arrangement_on_existing_rack_appends_second_block_and_exports_labels()
             arrangement_id: Some("arr-b".to_string()),
             name: Some("B".to_string()),
             ladders: None,
+            replicate_count: None,
         })
         .expect("arr b");
     let rack_a = engine
@@ -19782,6 +23265,7 @@ fn export_rack_fabrication_isometric_svg_and_openscad_include_template_markers()
             arrangement_id: Some("arr-rack".to_string()),
             name: Some("Rack demo".to_string()),
             ladders: Some(vec!["1 kb Ladder".to_string()]),
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -19864,6 +23348,7 @@ fn export_cell_culture_six_well_plate_uses_plate_geometry() {
             arrangement_id: Some("arr-six-well".to_string()),
             name: Some("6-well culture layout".to_string()),
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     engine
@@ -19977,6 +23462,7 @@ fn export_storage_rack_hero_svg_uses_generic_top_down_slots() {
             arrangement_id: Some("arr-storage".to_string()),
             name: Some("Storage layout".to_string()),
             ladders: None,
+            replicate_count: None,
         })
         .expect("create arrangement");
     engine
@@ -20033,6 +23519,7 @@ fn export_rack_carrier_labels_and_simulation_json_include_template_and_arrangeme
             arrangement_id: Some("arr-rack".to_string()),
             name: Some("Rack demo".to_string()),
             ladders: Some(vec!["1 kb Ladder".to_string()]),
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -20194,6 +23681,7 @@ fn export_rack_carrier_label_presets_change_visible_sections() {
             arrangement_id: Some("arr-rack".to_string()),
             name: Some("Preset demo".to_string()),
             ladders: Some(vec!["1 kb Ladder".to_string()]),
+            replicate_count: None,
         })
         .expect("create arrangement");
     let rack_id = engine
@@ -21215,7 +24703,7 @@ fn assert_odt_mimetype_first(bytes: &[u8]) {
 
 #[test]
 fn test_inspect_dna_ladders() {
-    let catalog = GentleEngine::inspect_dna_ladders(None);
+    let catalog = GentleEngine::new().inspect_dna_ladders(None);
     assert_eq!(catalog.schema, "gentle.dna_ladders.v1");
     assert!(catalog.ladder_count > 0);
     assert_eq!(catalog.ladder_count, catalog.ladders.len());
@@ -21478,6 +24966,7 @@ fn test_save_file_operation_fasta() {
             seq_id: "s".to_string(),
             path: path_text.clone(),
             format: ExportFormat::Fasta,
+            genbank_options: None,
         })
         .unwrap();
     assert!(res.changed_seq_ids.contains(&"s".to_string()));
@@ -21501,6 +24990,7 @@ fn test_save_file_operation_fasta_canonicalizes_peptide_to_protein_metadata() {
             seq_id: "pep".to_string(),
             path: path_text.clone(),
             format: ExportFormat::Fasta,
+            genbank_options: None,
         })
         .expect("save peptide fasta");
     let text = std::fs::read_to_string(path_text).expect("read peptide fasta");
@@ -21529,6 +25019,7 @@ fn test_save_file_operation_fasta_includes_synthetic_metadata() {
             seq_id: "s".to_string(),
             path: path_text.clone(),
             format: ExportFormat::Fasta,
+            genbank_options: None,
         })
         .unwrap();
     let text = std::fs::read_to_string(path_text).unwrap();
@@ -21551,6 +25042,7 @@ fn test_render_sequence_svg_operation_circular() {
             seq_id: "s".to_string(),
             mode: RenderSvgMode::Circular,
             path: path_text.clone(),
+            viewport: None,
         })
         .unwrap();
     assert!(res.messages.iter().any(|m| m.contains("SVG")));
@@ -21855,6 +25347,8 @@ fn test_containers_created_on_load_and_digest() {
         .apply(Operation::LoadFile {
             path: "test_files/pGEX_3X.fa".to_string(),
             as_id: Some("pgex".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
     let seq_id = load.created_seq_ids.first().unwrap().clone();
@@ -21986,6 +25480,8 @@ fn test_digest_container_and_state_summary_include_containers() {
         .apply(Operation::LoadFile {
             path: "test_files/pGEX_3X.fa".to_string(),
             as_id: Some("pgex".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .unwrap();
     let cid = engine
@@ -22014,6 +25510,57 @@ fn test_digest_container_and_state_summary_include_containers() {
     );
 }
 
+#[test]
+fn test_digest_container_reports_progress_and_honors_cancellation() {
+    let mut state = ProjectState::default();
+    let members = (0..600)
+        .map(|idx| format!("member_{idx}"))
+        .collect::<Vec<_>>();
+    for member in &members {
+        state
+            .sequences
+            .insert(member.clone(), seq("AAAAAAGAATTCAAAAAA"));
+    }
+    state.container_state.containers.insert(
+        "pool".to_string(),
+        Container {
+            container_id: "pool".to_string(),
+            kind: ContainerKind::Pool,
+            name: None,
+            members: members.clone(),
+            declared_contents_exclusive: true,
+            created_by_op: None,
+            created_at_unix_ms: 0,
+        },
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let mut events: Vec<(usize, usize)> = vec![];
+    let res = engine
+        .apply_with_progress(
+            Operation::DigestContainer {
+                container_id: "pool".to_string(),
+                enzymes: vec!["EcoRI".to_string()],
+                output_prefix: Some("dig".to_string()),
+            },
+            |progress| {
+                if let OperationProgress::ContainerOp { processed, total } = progress {
+                    events.push((processed, total));
+                }
+                events.len() < 2
+            },
+        )
+        .unwrap();
+    assert_eq!(events, vec![(256, 600), (512, 600), (512, 600)]);
+    assert!(
+        res.warnings
+            .iter()
+            .any(|w| w.code == WarningCode::ResultTruncated
+                && w.message.contains("cancelled after 512 of 600 member(s)"))
+    );
+    assert!(!res.created_seq_ids.is_empty());
+    assert!(res.created_seq_ids.len() < 2 * members.len());
+}
+
 #[test]
 fn test_set_container_declared_contents_exclusive_updates_summary() {
     let mut state = ProjectState::default();
@@ -22060,6 +25607,236 @@ fn test_set_container_declared_contents_exclusive_updates_summary() {
     assert!(!row.declared_contents_exclusive);
 }
 
+#[test]
+fn test_container_set_op_union_intersect_subtract_members() {
+    let mut state = ProjectState::default();
+    for seq_id in ["seq_a", "seq_b", "seq_c", "seq_d"] {
+        state.sequences.insert(
+            seq_id.to_string(),
+            DNAsequence::from_sequence("ATGC").unwrap(),
+        );
+    }
+    for (container_id, members) in [
+        ("container-1", vec!["seq_c", "seq_a", "seq_b"]),
+        ("container-2", vec!["seq_d", "seq_b", "seq_c"]),
+    ] {
+        state.container_state.containers.insert(
+            container_id.to_string(),
+            Container {
+                container_id: container_id.to_string(),
+                kind: ContainerKind::Pool,
+                name: None,
+                members: members.into_iter().map(str::to_string).collect(),
+                declared_contents_exclusive: true,
+                created_by_op: None,
+                created_at_unix_ms: 0,
+            },
+        );
+    }
+    let mut engine = GentleEngine::from_state(state);
+    let existing: HashSet<ContainerId> = engine
+        .state()
+        .container_state
+        .containers
+        .keys()
+        .cloned()
+        .collect();
+
+    let mut run = |op: CandidateSetOperator, prefix: &str| {
+        let result = engine
+            .apply(Operation::ContainerSetOp {
+                op,
+                left_container: "container-1".to_string(),
+                right_container: "container-2".to_string(),
+                output_prefix: Some(prefix.to_string()),
+            })
+            .unwrap();
+        assert!(result.created_seq_ids.is_empty());
+        let container = engine
+            .state()
+            .container_state
+            .containers
+            .values()
+            .find(|container| {
+                !existing.contains(&container.container_id)
+                    && container.name.as_deref() == Some(prefix)
+            })
+            .expect("set-op container")
+            .clone();
+        container.members
+    };
+    assert_eq!(
+        run(CandidateSetOperator::Union, "union"),
+        vec!["seq_a", "seq_b", "seq_c", "seq_d"]
+    );
+    assert_eq!(
+        run(CandidateSetOperator::Intersect, "intersect"),
+        vec!["seq_b", "seq_c"]
+    );
+    assert_eq!(
+        run(CandidateSetOperator::Subtract, "subtract"),
+        vec!["seq_a"]
+    );
+}
+
+#[test]
+fn test_filter_container_by_gc_keeps_members_inside_window() {
+    let mut state = ProjectState::default();
+    for (seq_id, sequence) in [
+        ("at_only", "AATTAATT"),
+        ("half_gc", "ATGCATGC"),
+        ("two_thirds_gc", "GCGCAT"),
+        ("gc_only", "GGCCGGCC"),
+        ("all_n", "NNNNNNNN"),
+    ] {
+        state.sequences.insert(seq_id.to_string(), seq(sequence));
+    }
+    state.container_state.containers.insert(
+        "container-1".to_string(),
+        Container {
+            container_id: "container-1".to_string(),
+            kind: ContainerKind::Pool,
+            name: None,
+            members: ["at_only", "half_gc", "two_thirds_gc", "gc_only", "all_n"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            declared_contents_exclusive: true,
+            created_by_op: None,
+            created_at_unix_ms: 0,
+        },
+    );
+    let mut engine = GentleEngine::from_state(state);
+
+    let result = engine
+        .apply(Operation::FilterContainerByGc {
+            container_id: "container-1".to_string(),
+            gc_min: 0.5,
+            gc_max: 0.7,
+            unique: false,
+            output_prefix: Some("gc_window".to_string()),
+        })
+        .unwrap();
+    assert_eq!(result.created_seq_ids, vec!["gc_window_1", "gc_window_2"]);
+    assert_eq!(
+        engine.state().sequences["gc_window_1"].get_forward_string(),
+        "ATGCATGC"
+    );
+    assert_eq!(
+        engine.state().sequences["gc_window_2"].get_forward_string(),
+        "GCGCAT"
+    );
+    assert!(result.warnings.iter().any(|w| {
+        w.message
+            .contains("'all_n' rejected by design constraints: gc_not_computable")
+    }));
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.contains("kept 2 of 5 sequence(s)"))
+    );
+
+    let err = engine
+        .apply(Operation::FilterContainerByGc {
+            container_id: "container-1".to_string(),
+            gc_min: 0.5,
+            gc_max: 0.7,
+            unique: true,
+            output_prefix: None,
+        })
+        .unwrap_err();
+    assert!(err.message.contains("exactly one match"));
+
+    let err = engine
+        .apply(Operation::FilterContainerByGc {
+            container_id: "container-1".to_string(),
+            gc_min: 0.8,
+            gc_max: 0.2,
+            unique: false,
+            output_prefix: None,
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+}
+
+#[test]
+fn test_validate_genome_catalog_reports_broken_entry_without_failing() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    let fasta = root.join("toy.fa");
+    let gtf = root.join("toy.gtf");
+    fs::write(&fasta, ">chr1\nACGTACGTACGT\n").unwrap();
+    fs::write(
+        &gtf,
+        "chr1\tsrc\tgene\t1\t12\t.\t+\t.\tgene_id \"GENE1\"; gene_name \"GENE1\";\n",
+    )
+    .unwrap();
+    let cache_dir = root.join("cache");
+    let catalog_path = root.join("catalog.json");
+    let catalog_json = format!(
+        r#"{{
+  "GoodGenome": {{
+    "sequence_local": "{}",
+    "annotations_local": "{}",
+    "cache_dir": "{}"
+  }},
+  "BrokenGenome": {{
+    "sequence_local": "{}",
+    "annotations_remote": "https://",
+    "cache_dir": "{}"
+  }}
+}}"#,
+        fasta.display(),
+        gtf.display(),
+        cache_dir.display(),
+        root.join("missing.fa").display(),
+        cache_dir.display()
+    );
+    fs::write(&catalog_path, catalog_json).unwrap();
+    let catalog_path_str = catalog_path.to_string_lossy().to_string();
+
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::PrepareGenome {
+            genome_id: "GoodGenome".to_string(),
+            catalog_path: Some(catalog_path_str.clone()),
+            cache_dir: None,
+            timeout_seconds: None,
+        })
+        .unwrap();
+
+    let report = GentleEngine::validate_genome_catalog(Some(&catalog_path_str)).unwrap();
+    assert_eq!(report.genome_count, 2);
+    let good = report
+        .genomes
+        .iter()
+        .find(|genome| genome.genome_id == "GoodGenome")
+        .expect("good genome row");
+    assert!(good.prepared);
+    assert!(good.issues.is_empty(), "issues were: {:?}", good.issues);
+    let broken = report
+        .genomes
+        .iter()
+        .find(|genome| genome.genome_id == "BrokenGenome")
+        .expect("broken genome row");
+    assert!(!broken.prepared);
+    assert_eq!(broken.issues.len(), 2, "issues were: {:?}", broken.issues);
+    assert!(
+        broken
+            .issues
+            .iter()
+            .any(|issue| issue.contains("missing.fa"))
+    );
+    assert!(
+        broken
+            .issues
+            .iter()
+            .any(|issue| issue.contains("annotation URL"))
+    );
+    assert_eq!(report.issue_count, 2);
+}
+
 #[test]
 fn test_prepare_genome_and_extract_region_operations() {
     let td = tempdir().unwrap();
@@ -22144,6 +25921,7 @@ fn test_prepare_genome_and_extract_region_operations() {
             genome_id: "ToyGenome".to_string(),
             gene_query: "MYGENE".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -22204,6 +25982,272 @@ fn test_prepare_genome_and_extract_region_operations() {
     }));
 }
 
+#[test]
+fn test_export_provenance_report_lists_genome_extractions_and_journal() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    let empty_path = root.join("empty_provenance.md");
+    GentleEngine::new()
+        .export_provenance_report(empty_path.to_string_lossy().as_ref())
+        .expect("export empty report");
+    let empty = fs::read_to_string(&empty_path).unwrap();
+    assert!(empty.contains("_No genome-extraction provenance recorded._"));
+    assert!(empty.contains("_No operations recorded._"));
+
+    let fasta_gz = root.join("toy.fa.gz");
+    let ann_gz = root.join("toy.gtf.gz");
+    write_gzip(&fasta_gz, ">chr1\nACGT\nACGT\nACGT\n");
+    write_gzip(
+        &ann_gz,
+        "chr1\tsrc\tgene\t1\t12\t.\t+\t.\tgene_id \"GENE1\"; gene_name \"MYGENE\";\n",
+    );
+    let catalog_path = root.join("catalog.json");
+    fs::write(
+        &catalog_path,
+        format!(
+            r#"{{"ToyGenome": {{"sequence_remote": "{}", "annotations_remote": "{}", "cache_dir": "{}"}}}}"#,
+            file_url(&fasta_gz),
+            file_url(&ann_gz),
+            root.join("cache").display()
+        ),
+    )
+    .unwrap();
+    let catalog_path_str = catalog_path.to_string_lossy().to_string();
+
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::PrepareGenome {
+            genome_id: "ToyGenome".to_string(),
+            catalog_path: Some(catalog_path_str.clone()),
+            cache_dir: None,
+            timeout_seconds: None,
+        })
+        .unwrap();
+    engine
+        .apply(Operation::ExtractGenomeRegion {
+            genome_id: "ToyGenome".to_string(),
+            chromosome: "chr1".to_string(),
+            start_1based: 3,
+            end_1based: 10,
+            output_id: Some("toy_slice".to_string()),
+            annotation_scope: None,
+            max_annotation_features: None,
+            include_genomic_annotation: None,
+            catalog_path: Some(catalog_path_str.clone()),
+            cache_dir: None,
+        })
+        .unwrap();
+    engine
+        .apply(Operation::ExtractGenomeGene {
+            genome_id: "ToyGenome".to_string(),
+            gene_query: "MYGENE".to_string(),
+            occurrence: None,
+            match_mode: None,
+            output_id: Some("toy_gene".to_string()),
+            extract_mode: None,
+            promoter_upstream_bp: None,
+            annotation_scope: None,
+            max_annotation_features: None,
+            include_genomic_annotation: None,
+            catalog_path: Some(catalog_path_str),
+            cache_dir: None,
+        })
+        .unwrap();
+
+    let report_path = root.join("reports").join("provenance.md");
+    engine
+        .export_provenance_report(report_path.to_string_lossy().as_ref())
+        .expect("export report");
+    let report = fs::read_to_string(&report_path).unwrap();
+    assert!(report.contains("- Genome extractions: 2"));
+    assert!(report.contains("### 1. `toy_slice` (ExtractGenomeRegion)"));
+    assert!(report.contains("### 2. `toy_gene` (ExtractGenomeGene)"));
+    assert!(report.contains("| Coordinates (1-based) | chr1:3-10"));
+    assert!(report.contains("| Coordinates (1-based) | chr1:1-12"));
+    assert!(report.contains("| Gene query | MYGENE |"));
+    assert!(!report.contains("_No genome-extraction provenance recorded._"));
+    for operation in ["PrepareGenome", "ExtractGenomeRegion", "ExtractGenomeGene"] {
+        assert!(
+            report.contains(&format!("| {operation} |")),
+            "journal row for {operation} missing:\n{report}"
+        );
+    }
+}
+
+#[test]
+fn test_select_genome_gene_matches_supports_each_match_mode() {
+    let gene = |name: &str, id: &str, start: usize| GenomeGeneRecord {
+        chromosome: "chr17".to_string(),
+        start_1based: start,
+        end_1based: start + 99,
+        strand: Some('+'),
+        gene_id: Some(id.to_string()),
+        gene_name: Some(name.to_string()),
+        biotype: None,
+    };
+    let genes = vec![
+        gene("TP53", "ENSG0001", 100),
+        gene("TP53BP1", "ENSG0002", 1_000),
+        gene("TP53I3", "ENSG0003", 2_000),
+        gene("ATP5F1", "ENSG0004", 3_000),
+    ];
+    let names = |query: &str, mode: Option<GeneMatchMode>| {
+        let (matches, label) =
+            GentleEngine::select_genome_gene_matches(&genes, query, mode).expect("matches");
+        (
+            matches
+                .iter()
+                .map(|record| record.gene_name.clone().unwrap_or_default())
+                .collect::<Vec<_>>(),
+            label,
+        )
+    };
+
+    assert_eq!(names("tp53", None), (vec!["TP53".to_string()], "exact"));
+    assert_eq!(names("53bp", None), (vec!["TP53BP1".to_string()], "fuzzy"));
+    assert_eq!(
+        names("TP53", Some(GeneMatchMode::Exact)),
+        (vec!["TP53".to_string()], "exact")
+    );
+    assert!(names("53bp", Some(GeneMatchMode::Exact)).0.is_empty());
+    assert_eq!(
+        names("tp53", Some(GeneMatchMode::Contains)).0,
+        vec!["TP53", "TP53BP1", "TP53I3"]
+    );
+    assert_eq!(
+        names("^tp53(bp1|i3)$", Some(GeneMatchMode::Regex)),
+        (vec!["TP53BP1".to_string(), "TP53I3".to_string()], "regex")
+    );
+    assert_eq!(
+        names("^ensg000[14]$", Some(GeneMatchMode::Regex)).0,
+        vec!["TP53", "ATP5F1"]
+    );
+    let err = GentleEngine::select_genome_gene_matches(&genes, "tp53(", Some(GeneMatchMode::Regex))
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn test_extract_genome_genes_batch_collects_missing_queries() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    let fasta_gz = root.join("toy.fa.gz");
+    let ann_gz = root.join("toy.gtf.gz");
+    write_gzip(&fasta_gz, ">chr1\nACGTACGTAC\nTTTTGGGGCC\nAAAACCCCGG\n");
+    write_gzip(
+        &ann_gz,
+        concat!(
+            "chr1\tsrc\tgene\t1\t10\t.\t+\t.\tgene_id \"GENE1\"; gene_name \"ALPHA\";\n",
+            "chr1\tsrc\tgene\t11\t20\t.\t-\t.\tgene_id \"GENE2\"; gene_name \"BETA\";\n",
+            "chrZ\tsrc\tgene\t1\t5\t.\t+\t.\tgene_id \"GENE3\"; gene_name \"GAMMA\";\n",
+        ),
+    );
+    let cache_dir = root.join("cache");
+    let catalog_path = root.join("catalog.json");
+    let catalog_json = format!(
+        r#"{{
+  "ToyGenome": {{
+    "description": "toy genome",
+    "sequence_remote": "{}",
+    "annotations_remote": "{}",
+    "cache_dir": "{}"
+  }}
+}}"#,
+        file_url(&fasta_gz),
+        file_url(&ann_gz),
+        cache_dir.display()
+    );
+    fs::write(&catalog_path, catalog_json).unwrap();
+    let catalog_path_str = catalog_path.to_string_lossy().to_string();
+
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::PrepareGenome {
+            genome_id: "ToyGenome".to_string(),
+            catalog_path: Some(catalog_path_str.clone()),
+            cache_dir: None,
+            timeout_seconds: None,
+        })
+        .unwrap();
+    let result = engine
+        .apply(Operation::ExtractGenomeGenes {
+            genome_id: "ToyGenome".to_string(),
+            gene_queries: vec![
+                "ALPHA".to_string(),
+                "BETA".to_string(),
+                "GAMMA".to_string(),
+                "NO_SUCH_GENE".to_string(),
+            ],
+            match_mode: None,
+            output_prefix: Some("batch".to_string()),
+            catalog_path: Some(catalog_path_str.clone()),
+            cache_dir: None,
+        })
+        .unwrap();
+    assert_eq!(
+        result.created_seq_ids,
+        vec!["batch_alpha".to_string(), "batch_beta".to_string()]
+    );
+    assert_eq!(
+        engine
+            .state()
+            .sequences
+            .get("batch_alpha")
+            .unwrap()
+            .get_forward_string(),
+        "ACGTACGTAC"
+    );
+    assert_eq!(
+        engine
+            .state()
+            .sequences
+            .get("batch_beta")
+            .unwrap()
+            .get_forward_string(),
+        "TTTTGGGGCC"
+    );
+    let missing_warnings = result
+        .warnings
+        .iter()
+        .filter(|warning| warning.message.contains("NO_SUCH_GENE"))
+        .count();
+    assert_eq!(missing_warnings, 1, "warnings were: {:?}", result.warnings);
+    assert!(
+        result.warnings.iter().any(|warning| warning
+            .message
+            .contains("Could not load gene interval chrZ:1-5")),
+        "warnings were: {:?}",
+        result.warnings
+    );
+    assert!(result.messages.iter().any(|message| message
+        == "Extracted 2 of 4 gene(s) from 'ToyGenome' (1 not found, 1 failed to load)"));
+    let extractions = engine
+        .state()
+        .metadata
+        .get("provenance")
+        .and_then(|v| v.get("genome_extractions"))
+        .and_then(|v| v.as_array())
+        .expect("genome_extractions array");
+    assert_eq!(extractions.len(), 2);
+    assert!(extractions.iter().all(|entry| {
+        entry.get("operation").and_then(|v| v.as_str()) == Some("ExtractGenomeGenes")
+    }));
+
+    let exact = engine
+        .apply(Operation::ExtractGenomeGenes {
+            genome_id: "ToyGenome".to_string(),
+            gene_queries: vec!["gene2".to_string(), "ALPH".to_string()],
+            match_mode: Some(GeneMatchMode::Exact),
+            output_prefix: Some("exact".to_string()),
+            catalog_path: Some(catalog_path_str),
+            cache_dir: None,
+        })
+        .unwrap();
+    assert_eq!(exact.created_seq_ids, vec!["exact_beta".to_string()]);
+    assert!(exact.messages.iter().any(|message| message
+        == "Extracted 1 of 2 gene(s) from 'ToyGenome' (1 not found, 0 failed to load)"));
+}
+
 #[test]
 fn test_extract_genome_gene_reports_alias_guidance_for_contig_mismatch() {
     let td = tempdir().unwrap();
@@ -22251,6 +26295,7 @@ fn test_extract_genome_gene_reports_alias_guidance_for_contig_mismatch() {
             genome_id: "ToyGenome".to_string(),
             gene_query: "MYGENE".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -22324,6 +26369,7 @@ fn test_extract_genome_gene_attaches_transcript_features_from_annotation() {
             genome_id: "ToyGenome".to_string(),
             gene_query: "MYGENE".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -22479,6 +26525,7 @@ fn test_extract_genome_gene_exon_concat_respects_negative_strand_orientation() {
             genome_id: "ToyGenome".to_string(),
             gene_query: "NEG1".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene_neg".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -22585,6 +26632,7 @@ fn test_extract_genome_gene_coding_with_promoter_extends_plus_strand_from_first_
             genome_id: "ToyGenome".to_string(),
             gene_query: "MYGENE".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene_promoter".to_string()),
             extract_mode: Some(GenomeGeneExtractMode::CodingWithPromoter),
             promoter_upstream_bp: Some(2),
@@ -22683,6 +26731,7 @@ fn test_extract_genome_gene_coding_with_promoter_extends_minus_strand_on_five_pr
             genome_id: "ToyGenome".to_string(),
             gene_query: "NEG1".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene_neg_promoter".to_string()),
             extract_mode: Some(GenomeGeneExtractMode::CodingWithPromoter),
             promoter_upstream_bp: Some(2),
@@ -22765,6 +26814,7 @@ fn test_reverse_strand_gene_extraction_can_seed_full_promoter_slice_via_region_e
             genome_id: "ToyGenome".to_string(),
             gene_query: "NEG1".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("neg1_gene".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -23130,6 +27180,7 @@ fn test_extract_genome_gene_include_annotation_false_disables_projection() {
             genome_id: "ToyGenome".to_string(),
             gene_query: "MYGENE".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene_no_annotation".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -23213,6 +27264,7 @@ fn test_extract_genome_gene_annotation_cap_falls_back_to_core() {
             genome_id: "ToyGenome".to_string(),
             gene_query: "MYGENE".to_string(),
             occurrence: None,
+            match_mode: None,
             output_id: Some("toy_gene_cap_fallback".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -23488,7 +27540,7 @@ fn test_extract_genome_region_full_scope_feature_cap_falls_back_to_core() {
         result
             .warnings
             .iter()
-            .any(|w| w.contains("fell back to core"))
+            .any(|w| w.message.contains("fell back to core"))
     );
     let telemetry = result
         .genome_annotation_projection
@@ -23685,6 +27737,121 @@ fn test_extend_genome_anchor_plus_strand_adds_lineage_and_provenance() {
     );
 }
 
+#[test]
+fn test_relocate_genome_anchor_allows_extension_after_catalog_move() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    let fasta_gz = root.join("toy.fa.gz");
+    let ann_gz = root.join("toy.gtf.gz");
+    write_gzip(&fasta_gz, ">chr1\nACGT\nACGT\nACGT\n");
+    write_gzip(
+        &ann_gz,
+        "chr1\tsrc\tgene\t1\t12\t.\t+\t.\tgene_id \"GENE1\"; gene_name \"MYGENE\";\n",
+    );
+    let cache_dir = root.join("cache");
+    let catalog_path = root.join("catalog.json");
+    let catalog_json = format!(
+        r#"{{
+  "ToyGenome": {{
+    "description": "toy genome",
+    "sequence_remote": "{}",
+    "annotations_remote": "{}",
+    "cache_dir": "{}"
+  }}
+}}"#,
+        file_url(&fasta_gz),
+        file_url(&ann_gz),
+        cache_dir.display()
+    );
+    fs::write(&catalog_path, catalog_json).unwrap();
+
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::PrepareGenome {
+            genome_id: "ToyGenome".to_string(),
+            catalog_path: Some(catalog_path.to_string_lossy().to_string()),
+            cache_dir: None,
+            timeout_seconds: None,
+        })
+        .unwrap();
+    engine
+        .apply(Operation::ExtractGenomeRegion {
+            genome_id: "ToyGenome".to_string(),
+            chromosome: "chr1".to_string(),
+            start_1based: 3,
+            end_1based: 10,
+            output_id: Some("toy_slice".to_string()),
+            annotation_scope: None,
+            max_annotation_features: None,
+            include_genomic_annotation: None,
+            catalog_path: Some(catalog_path.to_string_lossy().to_string()),
+            cache_dir: None,
+        })
+        .unwrap();
+
+    let moved_dir = root.join("moved");
+    fs::create_dir_all(&moved_dir).unwrap();
+    let moved_catalog = moved_dir.join("catalog.json");
+    fs::rename(&catalog_path, &moved_catalog).unwrap();
+    let extend = Operation::ExtendGenomeAnchor {
+        seq_id: "toy_slice".to_string(),
+        side: GenomeAnchorSide::FivePrime,
+        length_bp: 2,
+        output_id: Some("toy_slice_ext5".to_string()),
+        catalog_path: None,
+        cache_dir: None,
+        prepared_genome_id: None,
+    };
+    assert!(engine.apply(extend.clone()).is_err());
+
+    let err = engine
+        .relocate_genome_anchor("toy_slice", None, None)
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    let err = engine
+        .relocate_genome_anchor(
+            "toy_slice",
+            Some(root.join("missing.json").to_string_lossy().to_string()),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    assert_eq!(
+        engine
+            .latest_genome_anchor_for_seq("toy_slice")
+            .unwrap()
+            .catalog_path
+            .as_deref(),
+        Some(catalog_path.to_string_lossy().as_ref())
+    );
+
+    engine
+        .relocate_genome_anchor(
+            "toy_slice",
+            Some(moved_catalog.to_string_lossy().to_string()),
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        engine
+            .latest_genome_anchor_for_seq("toy_slice")
+            .unwrap()
+            .catalog_path
+            .as_deref(),
+        Some(moved_catalog.to_string_lossy().as_ref())
+    );
+    assert!(matches!(
+        engine.operation_log().last().map(|record| &record.op),
+        Some(Operation::RelocateGenomeAnchor { seq_id, .. }) if seq_id == "toy_slice"
+    ));
+    let extended = engine.apply(extend).unwrap();
+    assert_eq!(extended.created_seq_ids, vec!["toy_slice_ext5".to_string()]);
+    assert_eq!(
+        engine.state().sequences["toy_slice_ext5"].get_forward_string(),
+        "ACGTACGTAC"
+    );
+}
+
 #[test]
 fn test_extend_genome_anchor_reverse_strand_respects_5prime_and_3prime_physical_direction() {
     let td = tempdir().unwrap();
@@ -23948,7 +28115,7 @@ fn test_extend_genome_anchor_uses_compatible_prepared_assembly_fallback() {
         extended
             .warnings
             .iter()
-            .any(|w| w.contains("compatible prepared genome"))
+            .any(|w| w.message.contains("compatible prepared genome"))
     );
     let extended_seq = engine
         .state()
@@ -24214,7 +28381,128 @@ fn test_extend_genome_anchor_warns_when_clipped_at_chromosome_start() {
         result
             .warnings
             .iter()
-            .any(|w| w.contains("clipped at chromosome start position 1"))
+            .any(|w| w.message.contains("clipped at chromosome start position 1"))
+    );
+}
+
+#[test]
+fn test_extend_genome_anchor_both_sides_reports_clamped_five_prime() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    let fasta = root.join("toy.fa");
+    let gtf = root.join("toy.gtf");
+    fs::write(&fasta, ">chr1\nACGTACGTACGT\n").unwrap();
+    fs::write(
+        &gtf,
+        "chr1\tsrc\tgene\t1\t12\t.\t+\t.\tgene_id \"GENE1\"; gene_name \"GENE1\";\n",
+    )
+    .unwrap();
+    let catalog_path = root.join("catalog.json");
+    let cache_dir = root.join("cache");
+    let catalog_json = format!(
+        r#"{{
+  "ToyGenome": {{
+    "sequence_local": "{}",
+    "annotations_local": "{}",
+    "cache_dir": "{}"
+  }}
+}}"#,
+        fasta.display(),
+        gtf.display(),
+        cache_dir.display()
+    );
+    fs::write(&catalog_path, catalog_json).unwrap();
+    let catalog_path_str = catalog_path.to_string_lossy().to_string();
+
+    let mut state = ProjectState::default();
+    state.sequences.insert("anch".to_string(), seq("CGTAC"));
+    state.metadata.insert(
+        PROVENANCE_METADATA_KEY.to_string(),
+        serde_json::json!({
+            GENOME_EXTRACTIONS_METADATA_KEY: [
+                {
+                    "seq_id": "anch",
+                    "recorded_at_unix_ms": 1,
+                    "operation": "ExtractGenomeRegion",
+                    "genome_id": "ToyGenome",
+                    "catalog_path": catalog_path_str,
+                    "cache_dir": null,
+                    "chromosome": "chr1",
+                    "start_1based": 2,
+                    "end_1based": 6,
+                    "gene_query": null,
+                    "occurrence": null,
+                    "gene_id": null,
+                    "gene_name": null,
+                    "strand": null,
+                    "anchor_strand": "+",
+                    "sequence_source_type": "local",
+                    "annotation_source_type": "local",
+                    "sequence_source": "local",
+                    "annotation_source": "local",
+                    "sequence_sha1": null,
+                    "annotation_sha1": null
+                }
+            ]
+        }),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::PrepareGenome {
+            genome_id: "ToyGenome".to_string(),
+            catalog_path: Some(catalog_path.to_string_lossy().to_string()),
+            cache_dir: None,
+            timeout_seconds: None,
+        })
+        .unwrap();
+
+    let result = engine
+        .apply(Operation::ExtendGenomeAnchor {
+            seq_id: "anch".to_string(),
+            side: GenomeAnchorSide::Both,
+            length_bp: 3,
+            output_id: Some("anch_both".to_string()),
+            catalog_path: None,
+            cache_dir: None,
+            prepared_genome_id: None,
+        })
+        .unwrap();
+    assert_eq!(result.created_seq_ids, vec!["anch_both".to_string()]);
+    assert_eq!(
+        engine
+            .state()
+            .sequences
+            .get("anch_both")
+            .unwrap()
+            .get_forward_string(),
+        "ACGTACGTA"
+    );
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.contains("Added 1 bp on 5' and 3 bp on 3'")),
+        "messages were: {:?}",
+        result.messages
+    );
+    assert!(result.warnings.iter().any(|w| {
+        w.message
+            .contains("5' extension for 'anch' clipped at chromosome start")
+    }));
+    let extractions = engine
+        .state()
+        .metadata
+        .get(PROVENANCE_METADATA_KEY)
+        .and_then(|v| v.get(GENOME_EXTRACTIONS_METADATA_KEY))
+        .and_then(|v| v.as_array())
+        .expect("genome_extractions array");
+    assert_eq!(
+        extractions
+            .iter()
+            .filter(|entry| entry.get("operation").and_then(|v| v.as_str())
+                == Some("ExtendGenomeAnchor"))
+            .count(),
+        1
     );
 }
 
@@ -24290,7 +28578,7 @@ fn test_verify_genome_anchor_records_unverified_status_in_provenance() {
         result
             .warnings
             .iter()
-            .any(|w| w.contains("is unverified against")),
+            .any(|w| w.message.contains("is unverified against")),
         "expected unverified warning in operation result"
     );
     let anchor_summary = engine
@@ -24406,7 +28694,12 @@ fn test_import_genome_bed_track_supports_plain_and_gzip() {
         })
         .unwrap();
     assert!(plain.changed_seq_ids.contains(&"toy_slice".to_string()));
-    assert!(plain.warnings.iter().any(|w| w.contains("BED line")));
+    assert!(
+        plain
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("BED line"))
+    );
 
     let dna_plain = engine.state().sequences.get("toy_slice").unwrap();
     let generated_plain: Vec<_> = dna_plain
@@ -24441,7 +28734,11 @@ fn test_import_genome_bed_track_supports_plain_and_gzip() {
         })
         .unwrap();
     assert!(gz.changed_seq_ids.contains(&"toy_slice".to_string()));
-    assert!(gz.warnings.iter().any(|w| w.contains("score column")));
+    assert!(
+        gz.warnings
+            .iter()
+            .any(|w| w.message.contains("score column"))
+    );
 
     let dna_gz = engine.state().sequences.get("toy_slice").unwrap();
     let generated_gz: Vec<_> = dna_gz
@@ -24649,7 +28946,12 @@ fn test_import_genome_bigwig_track_uses_converter_and_filters_scores() {
         })
         .unwrap();
     assert!(result.changed_seq_ids.contains(&"toy_slice".to_string()));
-    assert!(result.warnings.iter().any(|w| w.contains("bedGraph line")));
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("bedGraph line"))
+    );
 
     let dna = engine.state().sequences.get("toy_slice").unwrap();
     let bed_features: Vec<_> = dna
@@ -26353,14 +30655,19 @@ fn test_import_genome_vcf_track_supports_multiallelic_and_qual_filters() {
         })
         .unwrap();
     assert!(plain.changed_seq_ids.contains(&"toy_slice".to_string()));
-    assert!(plain.warnings.iter().any(|w| w.contains("VCF line")));
     assert!(
         plain
             .warnings
             .iter()
-            .any(|w| w.contains("did not match anchor chromosome"))
+            .any(|w| w.message.contains("VCF line"))
     );
-    assert!(plain.warnings.iter().any(|w| w.contains("chr2")));
+    assert!(
+        plain
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("did not match anchor chromosome"))
+    );
+    assert!(plain.warnings.iter().any(|w| w.message.contains("chr2")));
 
     let dna_plain = engine.state().sequences.get("toy_slice").unwrap();
     let plain_features: Vec<_> = dna_plain
@@ -26395,7 +30702,7 @@ fn test_import_genome_vcf_track_supports_multiallelic_and_qual_filters() {
         filtered
             .warnings
             .iter()
-            .any(|w| w.contains("QUAL-based score filters"))
+            .any(|w| w.message.contains("QUAL-based score filters"))
     );
 
     let dna_filtered = engine.state().sequences.get("toy_slice").unwrap();
@@ -26599,7 +30906,7 @@ fn test_import_genome_vcf_track_can_cancel_via_progress_callback() {
         result
             .warnings
             .iter()
-            .any(|w| w.contains("import cancelled"))
+            .any(|w| w.message.contains("import cancelled"))
     );
     let dna = engine.state().sequences.get("toy_slice").unwrap();
     let features: Vec<_> = dna
@@ -26685,6 +30992,7 @@ fn test_prepare_helper_genome_via_genbank_accession_and_extract() {
             genome_id: "Helper pUC19".to_string(),
             gene_query: "bla".to_string(),
             occurrence: Some(1),
+            match_mode: None,
             output_id: Some("helper_bla".to_string()),
             extract_mode: None,
             promoter_upstream_bp: None,
@@ -26893,7 +31201,7 @@ fn test_extract_helper_region_skips_mcs_annotation_when_motif_not_unique() {
         extract
             .warnings
             .iter()
-            .any(|line| line.contains("expected exactly one")),
+            .any(|line| line.message.contains("expected exactly one")),
         "missing uniqueness warning: {:?}",
         extract.warnings
     );
@@ -27122,6 +31430,7 @@ fn test_sync_tracked_genome_track_subscriptions_only_applies_to_new_anchors() {
             min_score: None,
             max_score: None,
             clear_existing: true,
+            enabled: true,
         })
         .unwrap();
     assert!(inserted);
@@ -27211,6 +31520,118 @@ fn test_sync_tracked_genome_track_subscriptions_only_applies_to_new_anchors() {
     assert_eq!(anch2_features, 1);
 }
 
+#[test]
+fn test_genome_track_subscriptions_dedup_by_key_and_sync_skips_disabled() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("anch1".to_string(), seq("ACGTACGTACGT"));
+    state.metadata.insert(
+        PROVENANCE_METADATA_KEY.to_string(),
+        serde_json::json!({
+            GENOME_EXTRACTIONS_METADATA_KEY: [
+                {
+                    "seq_id": "anch1",
+                    "recorded_at_unix_ms": 1,
+                    "operation": "ExtractGenomeRegion",
+                    "genome_id": "ToyGenome",
+                    "catalog_path": "synthetic",
+                    "chromosome": "chr1",
+                    "start_1based": 1,
+                    "end_1based": 12,
+                    "anchor_strand": "+"
+                }
+            ]
+        }),
+    );
+    let td = tempdir().unwrap();
+    let peaks = td.path().join("peaks.bed");
+    let other = td.path().join("other.bed");
+    std::fs::write(&peaks, "chr1\t0\t4\tpeak1\t100\t+\n").unwrap();
+    std::fs::write(&other, "chr1\t4\t8\tpeak2\t50\t+\n").unwrap();
+    let peaks_path = peaks.to_string_lossy().to_string();
+
+    // Legacy metadata: duplicates differing only by clear_existing, no `enabled`.
+    state.metadata.insert(
+        GENOME_TRACK_SUBSCRIPTIONS_METADATA_KEY.to_string(),
+        serde_json::json!([
+            {"source": "bed", "path": peaks_path, "track_name": "peaks", "clear_existing": true},
+            {"source": "bed", "path": peaks_path, "track_name": "peaks", "clear_existing": false}
+        ]),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let listed = engine.list_genome_track_subscriptions();
+    assert_eq!(listed.len(), 1);
+    assert!(listed[0].enabled);
+    assert!(!listed[0].clear_existing);
+
+    let peaks_subscription = GenomeTrackSubscription {
+        source: GenomeTrackSource::Bed,
+        path: peaks_path.clone(),
+        track_name: Some("peaks".to_string()),
+        clear_existing: true,
+        ..GenomeTrackSubscription::default()
+    };
+    assert!(
+        engine
+            .add_genome_track_subscription(peaks_subscription.clone())
+            .unwrap()
+    );
+    assert!(
+        !engine
+            .add_genome_track_subscription(peaks_subscription)
+            .unwrap()
+    );
+    assert!(
+        engine
+            .add_genome_track_subscription(GenomeTrackSubscription {
+                source: GenomeTrackSource::Bed,
+                path: other.to_string_lossy().to_string(),
+                track_name: Some("other".to_string()),
+                ..GenomeTrackSubscription::default()
+            })
+            .unwrap()
+    );
+    let listed = engine.list_genome_track_subscriptions();
+    assert_eq!(listed.len(), 2);
+    let peaks_index = listed
+        .iter()
+        .position(|subscription| subscription.path == peaks_path)
+        .unwrap();
+    assert!(listed[peaks_index].clear_existing);
+
+    let disabled = engine
+        .set_genome_track_subscription_enabled(peaks_index, false)
+        .unwrap();
+    assert!(!disabled.enabled);
+    let listed = engine.list_genome_track_subscriptions();
+    assert_eq!(listed.len(), 2);
+    assert!(!listed[peaks_index].enabled);
+    assert_eq!(
+        engine
+            .set_genome_track_subscription_enabled(5, true)
+            .unwrap_err()
+            .code,
+        ErrorCode::InvalidInput
+    );
+
+    let report = engine
+        .sync_tracked_genome_track_subscriptions(false)
+        .unwrap();
+    assert_eq!(report.subscriptions_considered, 1);
+    assert_eq!(report.applied_imports, 1);
+    assert_eq!(report.failed_imports, 0);
+
+    engine
+        .set_genome_track_subscription_enabled(peaks_index, true)
+        .unwrap();
+    let report = engine
+        .sync_tracked_genome_track_subscriptions(false)
+        .unwrap();
+    assert_eq!(report.subscriptions_considered, 2);
+    assert_eq!(report.applied_imports, 2);
+}
+
 #[test]
 fn test_sync_tracked_genome_track_subscriptions_does_not_persist_empty_known_anchor_key() {
     let mut engine = GentleEngine::new();
@@ -27265,6 +31686,9 @@ fn test_import_blast_hits_track_operation_adds_features_and_clears_previous() {
             ],
             track_name: Some("blast_hits_demo".to_string()),
             clear_existing: Some(true),
+            min_identity_percent: None,
+            max_evalue: None,
+            min_bit_score: None,
             blast_provenance: Some(BlastInvocationProvenance {
                 genome_id: "grch38".to_string(),
                 query_label: "query".to_string(),
@@ -27288,7 +31712,12 @@ fn test_import_blast_hits_track_operation_adds_features_and_clears_previous() {
         })
         .unwrap();
     assert!(first.changed_seq_ids.contains(&"query".to_string()));
-    assert!(first.warnings.iter().any(|w| w.contains("was clamped")));
+    assert!(
+        first
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("was clamped"))
+    );
     assert!(
         first
             .messages
@@ -27330,6 +31759,9 @@ fn test_import_blast_hits_track_operation_adds_features_and_clears_previous() {
             }],
             track_name: Some("blast_hits_demo".to_string()),
             clear_existing: Some(true),
+            min_identity_percent: None,
+            max_evalue: None,
+            min_bit_score: None,
             blast_provenance: None,
         })
         .unwrap();
@@ -27351,6 +31783,70 @@ fn test_import_blast_hits_track_operation_adds_features_and_clears_previous() {
     );
 }
 
+#[test]
+fn test_import_blast_hits_track_applies_quality_thresholds() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("query".to_string(), seq("ACGTACGTACGTACGTACGTACGTACGT"));
+    let mut engine = GentleEngine::from_state(state);
+    let hit = |subject_id: &str, identity_percent: f64, bit_score: f64, evalue: f64| {
+        BlastHitFeatureInput {
+            subject_id: subject_id.to_string(),
+            query_start_1based: 1,
+            query_end_1based: 10,
+            subject_start_1based: 100,
+            subject_end_1based: 109,
+            identity_percent,
+            bit_score,
+            evalue,
+            query_coverage_percent: Some(100.0),
+        }
+    };
+
+    let result = engine
+        .apply(Operation::ImportBlastHitsTrack {
+            seq_id: "query".to_string(),
+            hits: vec![
+                hit("good", 99.0, 60.0, 1e-10),
+                hit("low_identity", 80.0, 60.0, 1e-10),
+                hit("high_evalue", 99.0, 60.0, 0.5),
+                hit("low_bit_score", 99.0, 12.0, 1e-10),
+            ],
+            track_name: None,
+            clear_existing: None,
+            min_identity_percent: Some(90.0),
+            max_evalue: Some(1e-3),
+            min_bit_score: Some(20.0),
+            blast_provenance: None,
+        })
+        .unwrap();
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.contains("BLAST hit thresholds skipped 3 hit(s)")
+                && m.contains("identity<90=1")
+                && m.contains("evalue>0.001=1")
+                && m.contains("bit_score<20=1")),
+        "messages were: {:?}",
+        result.messages
+    );
+
+    let dna = engine.state().sequences.get("query").unwrap();
+    let subjects: Vec<String> = dna
+        .features()
+        .iter()
+        .filter(|f| GentleEngine::is_generated_blast_hit_feature(f))
+        .filter_map(|f| {
+            f.qualifier_values("blast_subject_id")
+                .next()
+                .map(|v| v.to_string())
+        })
+        .collect();
+    assert_eq!(subjects, vec!["good".to_string()]);
+}
+
 #[test]
 fn test_resolve_blast_options_for_request_uses_project_legacy_and_request_layers() {
     let mut state = ProjectState::default();
@@ -27475,6 +31971,208 @@ fn test_apply_blast_thresholds_filters_hits_and_enforces_unique_best_hit() {
     assert!(err.message.contains("unique_best_hit"));
 }
 
+#[test]
+fn test_feature_overlaps_reports_partial_and_nested_pairs() {
+    let feature = |kind: &str, location: gb_io::seq::Location, label: &str| gb_io::seq::Feature {
+        kind: kind.into(),
+        location,
+        qualifiers: vec![("label".into(), Some(label.to_string()))],
+    };
+    let mut dna = seq(&"ACGT".repeat(50));
+    dna.features_mut().push(feature(
+        "source",
+        gb_io::seq::Location::simple_range(0, 200),
+        "whole",
+    ));
+    dna.features_mut().push(feature(
+        "gene",
+        gb_io::seq::Location::simple_range(10, 60),
+        "geneA",
+    ));
+    dna.features_mut().push(feature(
+        "gene",
+        gb_io::seq::Location::simple_range(50, 90),
+        "geneB",
+    ));
+    dna.features_mut().push(feature(
+        "promoter",
+        gb_io::seq::Location::simple_range(20, 30),
+        "inner",
+    ));
+    dna.features_mut().push(feature(
+        "CDS",
+        gb_io::seq::Location::Join(vec![
+            gb_io::seq::Location::simple_range(100, 120),
+            gb_io::seq::Location::simple_range(160, 180),
+        ]),
+        "split",
+    ));
+    dna.features_mut().push(feature(
+        "misc_feature",
+        gb_io::seq::Location::simple_range(130, 150),
+        "in_intron",
+    ));
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let engine = GentleEngine::from_state(state);
+
+    let overlaps = engine.feature_overlaps("s").unwrap();
+    let rows = overlaps
+        .iter()
+        .map(|row| {
+            (
+                row.label_a.as_str(),
+                row.label_b.as_str(),
+                row.overlap_start_0based,
+                row.overlap_end_0based_exclusive,
+                row.nested,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            ("geneA", "inner", 20, 30, true),
+            ("geneA", "geneB", 50, 60, false),
+        ]
+    );
+    assert_eq!(overlaps[0].feature_a, 1);
+    assert_eq!(overlaps[0].kind_b, "promoter");
+    assert!(matches!(
+        engine.feature_overlaps("missing").unwrap_err().code,
+        ErrorCode::NotFound
+    ));
+}
+
+#[test]
+fn test_screen_against_helper_genomes_reports_backbone_hit_with_stubbed_blast() {
+    let backbone = "TTGACAGCTAGCTCAGTCCTAGGTATAATGCTAGCGAATTCGAGCTCGGTACCCGGGGATCC";
+    let insert = format!("ATGGCCAAAGGTGAAGAACTGTTC{backbone}GGCCATCACCATCACCATCACTAA");
+    let mut state = ProjectState::default();
+    state.sequences.insert("insert".to_string(), seq(&insert));
+    let engine = GentleEngine::from_state(state);
+    let helpers = vec!["pUC19".to_string(), "Ecoli_K12".to_string()];
+
+    let mut searched = vec![];
+    let hits = engine
+        .screen_against_helper_genomes_with("insert", &helpers, |genome_id, query| {
+            searched.push(genome_id.to_string());
+            let mut report = demo_blast_report();
+            report.genome_id = genome_id.to_string();
+            report.query_length = query.len();
+            report.hits.clear();
+            if genome_id == "pUC19" {
+                let start = query.find(backbone).expect("stub finds backbone");
+                let hit = |evalue: f64, bit_score: f64, len: usize| BlastHit {
+                    subject_id: "pUC19".to_string(),
+                    identity_percent: 100.0,
+                    alignment_length: len,
+                    mismatches: 0,
+                    gap_opens: 0,
+                    query_start: start + 1,
+                    query_end: start + len,
+                    subject_start: 396,
+                    subject_end: 395 + len,
+                    evalue,
+                    bit_score,
+                    query_coverage_percent: Some(100.0 * len as f64 / query.len() as f64),
+                };
+                report.hits.push(hit(2e-3, 24.0, 14));
+                report.hits.push(hit(3e-25, 115.0, backbone.len()));
+            } else {
+                report.hits.push(BlastHit {
+                    subject_id: "NC_000913.3".to_string(),
+                    identity_percent: 88.0,
+                    alignment_length: 17,
+                    mismatches: 2,
+                    gap_opens: 0,
+                    query_start: 5,
+                    query_end: 21,
+                    subject_start: 1_000,
+                    subject_end: 1_016,
+                    evalue: 0.4,
+                    bit_score: 20.0,
+                    query_coverage_percent: Some(15.0),
+                });
+            }
+            report.hit_count = report.hits.len();
+            Ok(report)
+        })
+        .expect("screen");
+
+    assert_eq!(searched, helpers);
+    assert_eq!(hits.len(), 1);
+    let hit = &hits[0];
+    assert_eq!(hit.genome_id, "pUC19");
+    assert_eq!(hit.query_start_1based, 25);
+    assert_eq!(hit.query_end_1based, 24 + backbone.len());
+    assert_eq!(hit.alignment_length, backbone.len());
+
+    let err = engine
+        .screen_against_helper_genomes_with("missing", &helpers, |_, _| Ok(demo_blast_report()))
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+    let err = engine
+        .screen_against_helper_genomes_with("insert", &[], |_, _| Ok(demo_blast_report()))
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn test_save_to_path_is_byte_stable_across_reload() {
+    let mut engine = GentleEngine::new();
+    engine
+        .apply(Operation::LoadFile {
+            path: "test_files/pGEX-3X.gb".to_string(),
+            as_id: Some("pgex".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
+        })
+        .unwrap();
+    for (idx, text) in ["ATGAATTCGGATCCAAGCTT", "GGATCCGAATTCAAGCTTGG", "ACGT"]
+        .iter()
+        .enumerate()
+    {
+        engine
+            .apply(Operation::CreateSequenceFromText {
+                sequence_text: text.to_string(),
+                output_id: Some(format!("frag_{idx}")),
+                name: None,
+                circular: false,
+            })
+            .unwrap();
+    }
+    let mut state = engine.state().clone();
+    for key in ["zeta", "alpha", "mid", "beta"] {
+        state
+            .metadata
+            .insert(key.to_string(), json!({"b": 1, "a": {"y": 2, "x": 3}}));
+    }
+    let mut sites = seq("TTGAATTCGGATCCAAGCTTCTGCAGTT");
+    *sites.restriction_enzymes_mut() = active_restriction_enzymes();
+    sites.update_computed_features();
+    assert!(sites.restriction_enzyme_groups().len() > 1);
+    state.sequences.insert("sites".to_string(), sites);
+
+    let td = tempdir().unwrap();
+    let first = td.path().join("first.gentle.json");
+    let again = td.path().join("again.gentle.json");
+    let reloaded = td.path().join("reloaded.gentle.json");
+    state.save_to_path(first.to_str().unwrap()).unwrap();
+    state.save_to_path(again.to_str().unwrap()).unwrap();
+    ProjectState::load_from_path(first.to_str().unwrap())
+        .unwrap()
+        .save_to_path(reloaded.to_str().unwrap())
+        .unwrap();
+
+    let first_text = fs::read_to_string(&first).unwrap();
+    assert_eq!(first_text, fs::read_to_string(&again).unwrap());
+    assert_eq!(first_text, fs::read_to_string(&reloaded).unwrap());
+    let positions =
+        ["\"alpha\"", "\"beta\"", "\"mid\"", "\"zeta\""].map(|key| first_text.find(key).unwrap());
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
 #[test]
 fn test_candidate_store_save_externalizes_and_load_hydrates() {
     let mut state = ProjectState::default();
@@ -27663,6 +32361,77 @@ fn test_candidate_store_save_replaces_sidecar_and_removes_stale_files() {
     );
 }
 
+#[test]
+fn test_stream_candidate_set_jsonl_matches_sidecar_records_byte_for_byte() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("seqA".to_string(), seq("ACGTTGCAACGGTACCATGCAAGT"));
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "windows".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 6,
+            step_bp: 1,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: Some(64),
+        })
+        .expect("generate candidates");
+    engine
+        .apply(Operation::ScoreCandidateSetTm {
+            set_name: "windows".to_string(),
+            metric: "tm".to_string(),
+        })
+        .expect("score tm");
+
+    let td = tempdir().expect("tempdir");
+    let project_path = td.path().join("stream.gentle.json");
+    engine
+        .state()
+        .save_to_path(project_path.to_string_lossy().as_ref())
+        .expect("save project");
+    let sidecar_dir = ProjectState::candidate_store_sidecar_dir(&project_path);
+    let records_path = std::fs::read_dir(&sidecar_dir)
+        .expect("sidecar dir")
+        .map(|entry| entry.expect("entry").path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+        .expect("records file");
+
+    let stream_path = td.path().join("windows.jsonl");
+    let result = engine
+        .apply(Operation::StreamCandidateSetJsonl {
+            set_name: "windows".to_string(),
+            path: stream_path.to_string_lossy().to_string(),
+        })
+        .expect("stream candidates");
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.contains("Streamed 19 candidate(s)"))
+    );
+    let streamed = std::fs::read(&stream_path).expect("read streamed file");
+    assert_eq!(
+        streamed,
+        std::fs::read(&records_path).expect("read sidecar")
+    );
+    assert_eq!(streamed.iter().filter(|b| **b == b'\n').count(), 19);
+
+    let err = engine
+        .apply(Operation::StreamCandidateSetJsonl {
+            set_name: "missing".to_string(),
+            path: stream_path.to_string_lossy().to_string(),
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
 #[test]
 fn test_candidate_generation_regex_anchor_and_filter_quantile_edges() {
     let mut state = ProjectState::default();
@@ -27894,6 +32663,58 @@ fn test_generate_candidate_set_between_two_sequence_anchors() {
     );
 }
 
+#[test]
+fn test_generate_candidate_set_reports_progress_and_honors_cancellation() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("long".to_string(), seq(&"ACGTTGCA".repeat(1000)));
+    let mut engine = GentleEngine::from_state(state);
+    let mut events: Vec<(usize, usize)> = vec![];
+    let res = engine
+        .apply_with_progress(
+            Operation::GenerateCandidateSet {
+                set_name: "cancelled".to_string(),
+                seq_id: "long".to_string(),
+                length_bp: 20,
+                step_bp: 1,
+                feature_kinds: vec![],
+                feature_label_regex: None,
+                max_distance_bp: None,
+                feature_geometry_mode: None,
+                feature_boundary_mode: None,
+                feature_strand_relation: None,
+                limit: None,
+            },
+            |progress| {
+                if let OperationProgress::CandidateGeneration {
+                    produced,
+                    total_windows,
+                } = progress
+                {
+                    events.push((produced, total_windows));
+                }
+                events.len() < 2
+            },
+        )
+        .unwrap();
+    let total_windows = 8000 - 20 + 1;
+    assert!(events.len() >= 2);
+    assert!(events.iter().all(|(_, total)| *total == total_windows));
+    assert!(
+        res.warnings
+            .iter()
+            .any(|w| w.message.contains("was cancelled") && w.message.contains("truncated"))
+    );
+    let summary = engine
+        .list_candidate_sets()
+        .into_iter()
+        .find(|set| set.name == "cancelled")
+        .expect("partial candidate set");
+    assert!(summary.candidate_count > 0);
+    assert!(summary.candidate_count < total_windows);
+}
+
 #[test]
 fn test_candidate_generation_feature_parts_ignores_multipart_gaps() {
     let mut state = ProjectState::default();
@@ -28193,6 +33014,158 @@ fn test_candidate_distance_feature_strand_relation_prefers_matching_strand_featu
     assert_eq!(minus_opposite, 0.0);
 }
 
+#[test]
+fn test_candidate_scoring_parallel_matches_serial_metric_maps() {
+    let mut state = ProjectState::default();
+    let bases = (0..1500)
+        .map(|i| b"ACGT"[(i * 7 + i / 11) % 4] as char)
+        .collect::<String>();
+    let mut dna = DNAsequence::from_sequence(&bases).expect("sequence");
+    for (start, end) in [(100, 180), (640, 700), (1200, 1290)] {
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: "gene".into(),
+            location: gb_io::seq::Location::simple_range(start, end),
+            qualifiers: vec![("label".into(), Some(format!("GENE_{start}")))],
+        });
+    }
+    state.sequences.insert("seqA".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "windows".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 20,
+            step_bp: 1,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: Some(5000),
+        })
+        .expect("generate windows");
+
+    let mut score_with_threads = |threads: u64| {
+        engine
+            .apply(Operation::SetParameter {
+                name: "candidate_scoring_threads".to_string(),
+                value: serde_json::json!(threads),
+            })
+            .expect("set thread cap");
+        engine
+            .apply(Operation::ScoreCandidateSetDistance {
+                set_name: "windows".to_string(),
+                metric: "dist".to_string(),
+                feature_kinds: vec!["gene".to_string()],
+                feature_label_regex: None,
+                feature_geometry_mode: Some(CandidateFeatureGeometryMode::FeatureBoundaries),
+                feature_boundary_mode: None,
+                feature_strand_relation: None,
+            })
+            .expect("score distance");
+        engine
+            .apply(Operation::ScoreCandidateSetExpression {
+                set_name: "windows".to_string(),
+                metric: "score".to_string(),
+                expression: "dist * 2 - 1".to_string(),
+            })
+            .expect("score expression");
+        let (page, _, _) = engine
+            .inspect_candidate_set_page("windows", 5000, 0)
+            .expect("inspect windows");
+        page.candidates
+            .iter()
+            .map(|candidate| (candidate.start_0based, candidate.metrics.clone()))
+            .collect::<Vec<_>>()
+    };
+    let serial = score_with_threads(1);
+    let parallel = score_with_threads(4);
+    let auto = score_with_threads(0);
+    assert!(serial.len() > 1000);
+    assert!(
+        serial
+            .iter()
+            .all(|(_, metrics)| metrics.contains_key("score"))
+    );
+    assert_eq!(serial, parallel);
+    assert_eq!(serial, auto);
+}
+
+#[test]
+fn test_feature_distance_index_matches_naive_scan_with_fewer_comparisons() {
+    let len = 200_000usize;
+    let mut dna = DNAsequence::from_sequence(&"ACGT".repeat(len / 4)).expect("sequence");
+    let mut rng = SeededRng::new(2398);
+    for idx in 0..4_000usize {
+        let start = rng.below(len - 2_000);
+        let span = 1 + rng.below(1_500);
+        let range = gb_io::seq::Location::simple_range(start as i64, (start + span) as i64);
+        let location = if idx % 3 == 0 {
+            gb_io::seq::Location::Complement(Box::new(range))
+        } else {
+            range
+        };
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: if idx % 2 == 0 { "gene" } else { "CDS" }.into(),
+            location,
+            qualifiers: vec![("label".into(), Some(format!("F{idx}")))],
+        });
+    }
+    let kind_filter = vec!["GENE".to_string()];
+    let label_regex = Regex::new("^F1").expect("regex");
+    for geometry in [
+        CandidateFeatureGeometryMode::FeatureSpan,
+        CandidateFeatureGeometryMode::FeatureBoundaries,
+    ] {
+        let targets = GentleEngine::collect_feature_distance_targets(
+            &dna,
+            geometry,
+            CandidateFeatureBoundaryMode::Any,
+        );
+        for (kinds, regex, strand) in [
+            (&[][..], None, CandidateFeatureStrandRelation::Any),
+            (
+                &kind_filter[..],
+                None,
+                CandidateFeatureStrandRelation::Opposite,
+            ),
+            (
+                &[][..],
+                Some(&label_regex),
+                CandidateFeatureStrandRelation::Same,
+            ),
+        ] {
+            let index = GentleEngine::build_feature_distance_index(&targets, kinds, regex, strand);
+            let mut indexed_comparisons = 0usize;
+            let mut naive_comparisons = 0usize;
+            for start in (0..len - 25).step_by(997) {
+                let end = start + 25;
+                let naive = GentleEngine::nearest_feature_distance(
+                    start, end, &targets, kinds, regex, strand,
+                );
+                naive_comparisons += targets.len();
+                let indexed = index.nearest_distance_counted(start, end, &mut indexed_comparisons);
+                assert_eq!(indexed, naive, "window {start}..{end}");
+            }
+            assert!(
+                indexed_comparisons * 100 < naive_comparisons,
+                "indexed={indexed_comparisons} naive={naive_comparisons}"
+            );
+        }
+    }
+    assert_eq!(
+        GentleEngine::build_feature_distance_index(
+            &[],
+            &[],
+            None,
+            CandidateFeatureStrandRelation::Any
+        )
+        .nearest_distance(0, 10),
+        None
+    );
+}
+
 #[test]
 fn test_candidate_optimizer_weighted_topk_and_pareto() {
     let mut state = ProjectState::default();
@@ -28235,6 +33208,7 @@ fn test_candidate_optimizer_weighted_topk_and_pareto() {
                 },
             ],
             normalize_metrics: Some(true),
+            missing_policy: None,
         })
         .expect("score weighted objective");
 
@@ -28270,6 +33244,7 @@ fn test_candidate_optimizer_weighted_topk_and_pareto() {
             ],
             max_candidates: None,
             tie_break: Some(CandidateTieBreakPolicy::SeqStartEnd),
+            epsilon: None,
         })
         .expect("pareto frontier selection");
     let pareto_summary = engine
@@ -28280,6 +33255,539 @@ fn test_candidate_optimizer_weighted_topk_and_pareto() {
     assert_eq!(pareto_summary.candidate_count, 1);
 }
 
+#[test]
+fn test_seeded_tie_break_follows_project_random_seed() {
+    let top_starts = |seed: u64| {
+        let mut state = ProjectState::default();
+        state
+            .sequences
+            .insert("polyA".to_string(), seq(&"A".repeat(64)));
+        let mut engine = GentleEngine::from_state(state);
+        engine
+            .apply(Operation::SetParameter {
+                name: "random_seed".to_string(),
+                value: serde_json::json!(seed),
+            })
+            .unwrap();
+        engine
+            .apply(Operation::GenerateCandidateSet {
+                set_name: "cand".to_string(),
+                seq_id: "polyA".to_string(),
+                length_bp: 2,
+                step_bp: 2,
+                feature_kinds: vec![],
+                feature_label_regex: None,
+                max_distance_bp: None,
+                feature_geometry_mode: None,
+                feature_boundary_mode: None,
+                feature_strand_relation: None,
+                limit: Some(64),
+            })
+            .unwrap();
+        // Every window scores gc_fraction=0, so the cut is decided by ties.
+        engine
+            .apply(Operation::TopKCandidateSet {
+                input_set: "cand".to_string(),
+                output_set: "cand_top".to_string(),
+                metric: "gc_fraction".to_string(),
+                k: 3,
+                direction: Some(CandidateObjectiveDirection::Maximize),
+                tie_break: Some(CandidateTieBreakPolicy::Seeded),
+            })
+            .unwrap();
+        let (page, _, _) = engine
+            .inspect_candidate_set_page("cand_top", 10, 0)
+            .unwrap();
+        page.candidates
+            .iter()
+            .map(|candidate| candidate.start_0based)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(top_starts(7), top_starts(7));
+    assert_eq!(top_starts(7), vec![16, 38, 56]);
+    assert_eq!(top_starts(8), vec![22, 34, 36]);
+}
+
+#[test]
+fn test_weighted_objective_missing_value_policies() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("GCATGA").expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "cand".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 2,
+            step_bp: 2,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: Some(64),
+        })
+        .expect("generate candidates");
+    // Only the first two of three candidates carry the objective metric.
+    let mut store = engine.read_candidate_store();
+    let set = store.sets.get_mut("cand").unwrap();
+    assert_eq!(set.candidates.len(), 3);
+    set.candidates[0].metrics.insert("score".to_string(), 1.0);
+    set.candidates[1].metrics.insert("score".to_string(), 3.0);
+    engine.write_candidate_store(store).unwrap();
+
+    let score = |engine: &mut GentleEngine, missing_policy: Option<MissingValuePolicy>| {
+        engine.apply(Operation::ScoreCandidateSetWeightedObjective {
+            set_name: "cand".to_string(),
+            metric: "objective".to_string(),
+            objectives: vec![CandidateWeightedObjectiveTerm {
+                metric: "score".to_string(),
+                weight: 1.0,
+                direction: CandidateObjectiveDirection::Maximize,
+            }],
+            normalize_metrics: Some(false),
+            missing_policy,
+        })
+    };
+    let objective_of_third = |engine: &GentleEngine| {
+        engine.read_candidate_store().sets["cand"].candidates[2]
+            .metrics
+            .get("objective")
+            .copied()
+    };
+
+    let err = score(&mut engine, None).unwrap_err();
+    assert!(err.message.contains("missing metric 'score'"));
+
+    let res = score(&mut engine, Some(MissingValuePolicy::TreatAsZero)).unwrap();
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("'treat_as_zero' affected 1 candidate(s)"))
+    );
+    assert_eq!(objective_of_third(&engine), Some(0.0));
+
+    let res = score(&mut engine, Some(MissingValuePolicy::TreatAsWorst)).unwrap();
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("'treat_as_worst' affected 1 candidate(s)"))
+    );
+    assert_eq!(objective_of_third(&engine), Some(1.0));
+
+    let res = score(&mut engine, Some(MissingValuePolicy::SkipCandidate)).unwrap();
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("'skip_candidate' affected 1 candidate(s)"))
+    );
+    assert_eq!(objective_of_third(&engine), None);
+    assert_eq!(
+        engine.read_candidate_store().sets["cand"].candidates[1]
+            .metrics
+            .get("objective")
+            .copied(),
+        Some(3.0)
+    );
+}
+
+#[test]
+fn test_pareto_frontier_epsilon_dominance_thins_clustered_frontier() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("GCATGAACGTTAGC").expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "cand".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 2,
+            step_bp: 2,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: Some(64),
+        })
+        .expect("generate candidates");
+    // Two tight clusters at the frontier extremes plus one balanced candidate;
+    // every point is strictly non-dominated.
+    let points = [
+        (1.0, 0.0),
+        (0.99, 0.01),
+        (0.98, 0.02),
+        (0.0, 1.0),
+        (0.01, 0.99),
+        (0.02, 0.98),
+        (0.5, 0.5),
+    ];
+    let mut store = engine.read_candidate_store();
+    let set = store.sets.get_mut("cand").unwrap();
+    assert_eq!(set.candidates.len(), points.len());
+    for (candidate, (a, b)) in set.candidates.iter_mut().zip(points) {
+        candidate.metrics.insert("obj_a".to_string(), a);
+        candidate.metrics.insert("obj_b".to_string(), b);
+    }
+    engine.write_candidate_store(store).unwrap();
+
+    let frontier = |engine: &mut GentleEngine, output_set: &str, epsilon: Option<f64>| {
+        engine
+            .apply(Operation::ParetoFrontierCandidateSet {
+                input_set: "cand".to_string(),
+                output_set: output_set.to_string(),
+                objectives: vec![
+                    CandidateObjectiveSpec {
+                        metric: "obj_a".to_string(),
+                        direction: CandidateObjectiveDirection::Maximize,
+                    },
+                    CandidateObjectiveSpec {
+                        metric: "obj_b".to_string(),
+                        direction: CandidateObjectiveDirection::Maximize,
+                    },
+                ],
+                max_candidates: None,
+                tie_break: Some(CandidateTieBreakPolicy::SeqStartEnd),
+                epsilon,
+            })
+            .expect("pareto frontier");
+        engine.read_candidate_store().sets[output_set]
+            .candidates
+            .iter()
+            .map(|candidate| candidate.start_0based)
+            .collect::<Vec<_>>()
+    };
+
+    let strict = frontier(&mut engine, "strict", Some(0.0));
+    assert_eq!(strict.len(), 7);
+    let thinned = frontier(&mut engine, "thinned", Some(0.05));
+    // Tie-break order keeps the first member of each cluster.
+    assert_eq!(thinned, vec![0, 6, 12]);
+
+    let err = engine
+        .apply(Operation::ParetoFrontierCandidateSet {
+            input_set: "cand".to_string(),
+            output_set: "bad".to_string(),
+            objectives: vec![CandidateObjectiveSpec {
+                metric: "obj_a".to_string(),
+                direction: CandidateObjectiveDirection::Maximize,
+            }],
+            max_candidates: None,
+            tie_break: None,
+            epsilon: Some(-0.1),
+        })
+        .unwrap_err();
+    assert!(err.message.contains("epsilon"));
+}
+
+#[test]
+fn test_import_candidate_set_bed_and_csv_compute_metrics() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("GGGGCCCCATATATATGCGC").expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let td = tempdir().unwrap();
+    let bed_path = td.path().join("regions.bed");
+    fs::write(
+        &bed_path,
+        "track name=regions\nseqA\t0\t8\tgc_block\nseqA\t8\t16\tat_block\nseqA\t12\t20\n",
+    )
+    .unwrap();
+    let res = engine
+        .apply(Operation::ImportCandidateSetBed {
+            set_name: "bed_regions".to_string(),
+            path: bed_path.to_string_lossy().to_string(),
+            seq_id: "seqA".to_string(),
+        })
+        .unwrap();
+    assert!(res.messages.iter().any(|m| m.contains("3 candidate(s)")));
+    let (page, total, _) = engine
+        .inspect_candidate_set_page("bed_regions", 10, 0)
+        .unwrap();
+    assert_eq!(total, 3);
+    assert_eq!(page.candidates[0].sequence, "GGGGCCCC");
+    assert_eq!(
+        page.candidates[0].metrics.get("gc_fraction").copied(),
+        Some(1.0)
+    );
+    assert_eq!(
+        page.candidates[1].metrics.get("gc_fraction").copied(),
+        Some(0.0)
+    );
+    assert_eq!(
+        page.candidates[2].metrics.get("length_bp").copied(),
+        Some(8.0)
+    );
+
+    let csv_path = td.path().join("regions.csv");
+    fs::write(
+        &csv_path,
+        "name,start_0based,end_0based\nin,4,12\nout,15,40\n",
+    )
+    .unwrap();
+    let res = engine
+        .apply(Operation::ImportCandidateSetCsv {
+            set_name: "csv_regions".to_string(),
+            path: csv_path.to_string_lossy().to_string(),
+            seq_id: "seqA".to_string(),
+        })
+        .unwrap();
+    assert!(
+        res.messages
+            .iter()
+            .any(|m| m.contains("1 candidate(s), 1 out-of-bounds record(s) skipped"))
+    );
+    assert!(
+        res.warnings
+            .iter()
+            .any(|w| w.message.contains("Skipped 1 record(s)"))
+    );
+    let (page, total, _) = engine
+        .inspect_candidate_set_page("csv_regions", 10, 0)
+        .unwrap();
+    assert_eq!(total, 1);
+    assert_eq!(page.candidates[0].start_0based, 4);
+}
+
+#[test]
+fn test_score_candidate_set_motif_count_handles_both_strands() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("GAATTCGGTCTCAAGAGACC").expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "whole".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 20,
+            step_bp: 1,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: None,
+        })
+        .expect("generate candidates");
+    let mut count = |metric: &str, motif: &str, both_strands: bool| {
+        engine
+            .apply(Operation::ScoreCandidateSetMotifCount {
+                set_name: "whole".to_string(),
+                metric: metric.to_string(),
+                motif: motif.to_string(),
+                both_strands,
+            })
+            .expect("score motif count");
+        engine.read_candidate_store().sets["whole"].candidates[0].metrics[metric]
+    };
+
+    // EcoRI site is palindromic: the reverse strand adds no extra sites.
+    assert_eq!(count("ecori_fwd", "GAATTC", false), 1.0);
+    assert_eq!(count("ecori_both", "GAATTC", true), 1.0);
+    assert_eq!(count("ecori_iupac", "gawttc", false), 1.0);
+    // BsaI site is not: GAGACC on the top strand is GGTCTC on the bottom one.
+    assert_eq!(count("bsai_fwd", "GGTCTC", false), 1.0);
+    assert_eq!(count("bsai_both", "GGTCTC", true), 2.0);
+    // Overlapping matches are all counted.
+    assert_eq!(count("aa", "AA", false), 2.0);
+}
+
+#[test]
+fn test_score_candidate_set_tm_ranks_gc_rich_above_at_rich() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("GCGGCCGCGGCGCCGGCGCCATATTAATATATTTAATATAATTA")
+            .expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "cand".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 20,
+            step_bp: 20,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: None,
+        })
+        .expect("generate candidates");
+    let res = engine
+        .apply(Operation::ScoreCandidateSetTm {
+            set_name: "cand".to_string(),
+            metric: "tm_c".to_string(),
+        })
+        .expect("score tm");
+    assert!(res.warnings.is_empty());
+    let store = engine.read_candidate_store();
+    let candidates = &store.sets["cand"].candidates;
+    assert_eq!(candidates.len(), 2);
+    let gc_rich = candidates[0].metrics["tm_c"];
+    let at_rich = candidates[1].metrics["tm_c"];
+    assert!(gc_rich > at_rich + 20.0, "gc={gc_rich} at={at_rich}");
+    assert_eq!(
+        gc_rich,
+        GentleEngine::estimate_primer_tm_c(candidates[0].sequence.as_bytes())
+    );
+
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "short".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 6,
+            step_bp: 20,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: None,
+        })
+        .expect("generate short candidates");
+    let res = engine
+        .apply(Operation::ScoreCandidateSetTm {
+            set_name: "short".to_string(),
+            metric: "tm_c".to_string(),
+        })
+        .expect("score short tm");
+    assert!(res.warnings.iter().any(|w| w.message.contains("Wallace")));
+    // GCGGCC: six G/C bases at 4 degrees each.
+    assert_eq!(
+        engine.read_candidate_store().sets["short"].candidates[0].metrics["tm_c"],
+        24.0
+    );
+}
+
+#[test]
+fn test_score_candidate_set_self_structure_penalizes_palindromes() {
+    let mut state = ProjectState::default();
+    // A 16 bp self-complementary block followed by a low-structure block.
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("GACCTAGGCCTAGGTCAACAGACAAAGACACA").expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "cand".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 16,
+            step_bp: 16,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: None,
+        })
+        .expect("generate candidates");
+    engine
+        .apply(Operation::ScoreCandidateSetSelfStructure {
+            set_name: "cand".to_string(),
+            metric: "self_structure".to_string(),
+        })
+        .expect("score self structure");
+    let store = engine.read_candidate_store();
+    let candidates = &store.sets["cand"].candidates;
+    assert_eq!(candidates[0].sequence, "GACCTAGGCCTAGGTC");
+    let palindrome = candidates[0].metrics["self_structure"];
+    let plain = candidates[1].metrics["self_structure"];
+    assert_eq!(palindrome, 8.0);
+    assert!(plain < palindrome, "plain={plain}");
+}
+
+#[test]
+fn test_capabilities_lists_supported_queries() {
+    let capabilities = GentleEngine::capabilities();
+    assert!(!capabilities.supported_queries.is_empty());
+    for name in [
+        "inspect_candidate_set_page",
+        "sequence_genome_anchor_summary",
+    ] {
+        assert!(
+            capabilities
+                .supported_queries
+                .iter()
+                .any(|query| query == name),
+            "missing query '{name}'"
+        );
+    }
+}
+
+#[test]
+fn test_operation_record_loads_legacy_string_warnings() {
+    let mut engine = GentleEngine::from_state(ProjectState::default());
+    engine
+        .apply(Operation::DeleteCandidateSet {
+            set_name: "cand".to_string(),
+        })
+        .expect("delete missing candidate set");
+    let record = engine
+        .operation_log()
+        .last()
+        .expect("journal record")
+        .clone();
+    let mut value = serde_json::to_value(&record).expect("serialize record");
+    assert_eq!(value["result"]["warnings"][0]["code"], "unclassified");
+    value["result"]["warnings"] = serde_json::json!(["Candidate set 'cand' was not present"]);
+    let loaded: OperationRecord = serde_json::from_value(value).expect("load legacy record");
+    assert_eq!(loaded.result.warnings.len(), 1);
+    assert_eq!(loaded.result.warnings[0].code, WarningCode::Unclassified);
+    assert_eq!(
+        loaded.result.warnings[0].message,
+        "Candidate set 'cand' was not present"
+    );
+}
+
+#[test]
+fn test_candidate_generation_truncation_warning_carries_code() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "seqA".to_string(),
+        DNAsequence::from_sequence("ACGTACGTACGTACGTACGT").expect("sequence"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let res = engine
+        .apply(Operation::GenerateCandidateSet {
+            set_name: "cand".to_string(),
+            seq_id: "seqA".to_string(),
+            length_bp: 4,
+            step_bp: 1,
+            feature_kinds: vec![],
+            feature_label_regex: None,
+            max_distance_bp: None,
+            feature_geometry_mode: None,
+            feature_boundary_mode: None,
+            feature_strand_relation: None,
+            limit: Some(2),
+        })
+        .expect("generate candidates");
+    let truncated = res
+        .warnings
+        .iter()
+        .find(|warning| warning.code == WarningCode::ResultTruncated)
+        .expect("truncation warning");
+    assert!(truncated.message.contains("truncated at limit=2"));
+    assert_eq!(truncated.context["limit"], 2);
+}
+
 #[test]
 fn test_topk_tie_break_policy_is_deterministic() {
     let mut state = ProjectState::default();
@@ -28510,6 +34018,7 @@ fn test_guide_design_filter_generate_and_export() {
                     nuclease: "SpCas9".to_string(),
                     cut_offset_from_protospacer_start: 17,
                     rank: Some(1),
+                    off_target_score: None,
                 },
                 GuideCandidate {
                     guide_id: "g2".to_string(),
@@ -28522,6 +34031,7 @@ fn test_guide_design_filter_generate_and_export() {
                     nuclease: "SpCas9".to_string(),
                     cut_offset_from_protospacer_start: 17,
                     rank: Some(2),
+                    off_target_score: None,
                 },
                 GuideCandidate {
                     guide_id: "g3".to_string(),
@@ -28534,6 +34044,7 @@ fn test_guide_design_filter_generate_and_export() {
                     nuclease: "SpCas9".to_string(),
                     cut_offset_from_protospacer_start: 17,
                     rank: Some(3),
+                    off_target_score: None,
                 },
             ],
         })
@@ -28603,6 +34114,7 @@ fn test_guide_design_filter_generate_and_export() {
             oligo_set_id: Some("tp73_oligos".to_string()),
             path: protocol_path.clone(),
             include_qc_checklist: Some(true),
+            reagents: None,
         })
         .expect("export guide protocol");
 
@@ -28615,6 +34127,428 @@ fn test_guide_design_filter_generate_and_export() {
     assert!(protocol.contains("GENtle Guide Oligo Protocol"));
 }
 
+#[test]
+fn test_guide_protocol_reagent_table_scales_with_guide_count() {
+    let guide = |index: usize| GuideCandidate {
+        guide_id: format!("g{index}"),
+        seq_id: "tp73".to_string(),
+        start_0based: 100 * index,
+        end_0based_exclusive: 100 * index + 20,
+        strand: "+".to_string(),
+        protospacer: format!(
+            "GACCTGTTGACGATGT{}",
+            ["TCCA", "ACCA", "GCCA", "CCCA"][index % 4]
+        ),
+        pam: "AGG".to_string(),
+        nuclease: "SpCas9".to_string(),
+        cut_offset_from_protospacer_start: 17,
+        rank: Some(index),
+        off_target_score: None,
+    };
+    let dir = tempdir().expect("tempdir");
+    let export = |guide_count: usize, reagents: Option<ReagentPlan>| -> String {
+        let mut engine = GentleEngine::from_state(ProjectState::default());
+        engine
+            .apply(Operation::UpsertGuideSet {
+                guide_set_id: "guides".to_string(),
+                guides: (1..=guide_count).map(&guide).collect(),
+            })
+            .expect("upsert guide set");
+        engine
+            .apply(Operation::GenerateGuideOligos {
+                guide_set_id: "guides".to_string(),
+                template_id: "lenti_bsmbi_u6_default".to_string(),
+                apply_5prime_g_extension: Some(true),
+                output_oligo_set_id: Some("oligos".to_string()),
+                passed_only: Some(false),
+            })
+            .expect("generate guide oligos");
+        let path = dir
+            .path()
+            .join(format!("protocol_{guide_count}.txt"))
+            .display()
+            .to_string();
+        engine
+            .apply(Operation::ExportGuideProtocolText {
+                guide_set_id: "guides".to_string(),
+                oligo_set_id: Some("oligos".to_string()),
+                path: path.clone(),
+                include_qc_checklist: Some(false),
+                reagents,
+            })
+            .expect("export guide protocol");
+        fs::read_to_string(path).expect("read protocol")
+    };
+    let master_mix_total = |text: &str, component: &str| -> String {
+        text.lines()
+            .find(|line| line.starts_with(&format!("{component}\t")))
+            .and_then(|line| line.split('\t').nth(2))
+            .expect("master mix row")
+            .to_string()
+    };
+
+    let two = export(2, None);
+    let four = export(4, None);
+    assert!(two.contains("Reagent plan: Standard U6 guide cloning"));
+    assert!(two.contains("Guides: 2 (master mixes sized for 2.20 reactions, 10% overage)"));
+    assert!(two.contains("g2\t1.0\t1.0\t8.0\t10.0"));
+    assert!(!two.contains("g3\t"));
+    assert!(four.contains("g4\t1.0\t1.0\t8.0\t10.0"));
+    assert_eq!(master_mix_total(&two, "T4 PNK"), "1.1");
+    assert_eq!(master_mix_total(&four, "T4 PNK"), "2.2");
+    assert_eq!(master_mix_total(&two, "2x Quick ligation buffer"), "11.0");
+    assert_eq!(master_mix_total(&four, "2x Quick ligation buffer"), "22.0");
+    assert!(!two.contains("QC checklist"));
+
+    let custom = export(
+        3,
+        Some(ReagentPlan {
+            recipe_name: "Minimal".to_string(),
+            annealing_mix: vec![ReagentComponent {
+                name: "Annealing buffer".to_string(),
+                volume_ul: 2.0,
+            }],
+            overage_fraction: 0.0,
+            ..ReagentPlan::default()
+        }),
+    );
+    assert!(custom.contains("Reagent plan: Minimal"));
+    assert_eq!(master_mix_total(&custom, "Annealing buffer"), "6.0");
+    assert!(custom.contains("g3\t1.0\t1.0\t2.0\t4.0"));
+}
+
+#[test]
+fn test_rank_guides_breaks_score_ties_by_guide_id() {
+    let guide = |guide_id: &str, protospacer: &str, off_target_score: Option<f64>| GuideCandidate {
+        guide_id: guide_id.to_string(),
+        seq_id: "tp73".to_string(),
+        start_0based: 100,
+        end_0based_exclusive: 120,
+        strand: "+".to_string(),
+        protospacer: protospacer.to_string(),
+        pam: "AGG".to_string(),
+        nuclease: "SpCas9".to_string(),
+        cut_offset_from_protospacer_start: 17,
+        rank: None,
+        off_target_score,
+    };
+    let mut engine = GentleEngine::from_state(ProjectState::default());
+    engine
+        .apply(Operation::UpsertGuideSet {
+            guide_set_id: "tp73_guides".to_string(),
+            guides: vec![
+                guide("tied_b", "GACCTGTTGACGATGTTCCA", None),
+                guide("at_rich", "ATATATATATATATATATAT", None),
+                guide("homopolymer", "GGGGGGCCATATATATATAT", None),
+                guide("tied_a", "GACCTGTTGACGATGTTCCA", None),
+                guide("specific", "GACCTGTTGACGATGTTCCA", Some(0.9)),
+            ],
+        })
+        .expect("upsert guide set");
+
+    let ranked_ids = |engine: &GentleEngine, guide_set_id: &str| {
+        let (set, _, _) = engine
+            .inspect_guide_set_page(guide_set_id, 100, 0)
+            .expect("ranked set");
+        set.guides
+            .iter()
+            .map(|guide| (guide.guide_id.clone(), guide.rank))
+            .collect::<Vec<_>>()
+    };
+    let expected = vec![
+        ("specific".to_string(), Some(1)),
+        ("tied_a".to_string(), Some(2)),
+        ("tied_b".to_string(), Some(3)),
+        ("homopolymer".to_string(), Some(4)),
+        ("at_rich".to_string(), Some(5)),
+    ];
+
+    let result = engine
+        .apply(Operation::RankGuides {
+            guide_set_id: "tp73_guides".to_string(),
+            weights: GuideRankWeights::default(),
+            output_guide_set_id: Some("tp73_ranked".to_string()),
+        })
+        .expect("rank guides");
+    assert!(
+        result
+            .messages
+            .iter()
+            .any(|m| m.contains("Ranked 5 guide(s)") && m.contains("1 with off-target score"))
+    );
+    assert_eq!(ranked_ids(&engine, "tp73_ranked"), expected);
+
+    engine
+        .apply(Operation::RankGuides {
+            guide_set_id: "tp73_ranked".to_string(),
+            weights: GuideRankWeights::default(),
+            output_guide_set_id: None,
+        })
+        .expect("re-rank in place");
+    assert_eq!(ranked_ids(&engine, "tp73_ranked"), expected);
+    assert!(
+        ranked_ids(&engine, "tp73_guides")
+            .iter()
+            .all(|(_, rank)| rank.is_none())
+    );
+}
+
+#[test]
+fn test_guide_set_op_union_intersect_subtract_preserve_ranks() {
+    let guide = |guide_id: &str, start: usize, rank: Option<usize>| GuideCandidate {
+        guide_id: guide_id.to_string(),
+        seq_id: "tp73".to_string(),
+        start_0based: start,
+        end_0based_exclusive: start + 20,
+        strand: "+".to_string(),
+        protospacer: "GACCTGTTGACGATGTTCCA".to_string(),
+        pam: "AGG".to_string(),
+        nuclease: "SpCas9".to_string(),
+        cut_offset_from_protospacer_start: 17,
+        rank,
+        off_target_score: None,
+    };
+    let mut engine = GentleEngine::from_state(ProjectState::default());
+    engine
+        .apply(Operation::UpsertGuideSet {
+            guide_set_id: "region_a".to_string(),
+            guides: vec![
+                guide("a1", 100, Some(2)),
+                guide("shared", 200, Some(1)),
+                guide("a3", 300, None),
+            ],
+        })
+        .unwrap();
+    engine
+        .apply(Operation::UpsertGuideSet {
+            guide_set_id: "region_b".to_string(),
+            guides: vec![
+                guide("shared", 200, Some(5)),
+                guide("b2", 400, Some(1)),
+                guide("b_same_site", 100, Some(3)),
+            ],
+        })
+        .unwrap();
+    let run = |engine: &mut GentleEngine,
+               op: CandidateSetOperator,
+               output: &str,
+               match_by: GuideSetMatchKey|
+     -> Result<Vec<(String, Option<usize>)>, EngineError> {
+        engine.apply(Operation::GuideSetOp {
+            op,
+            left_guide_set: "region_a".to_string(),
+            right_guide_set: "region_b".to_string(),
+            output_guide_set: output.to_string(),
+            match_by,
+        })?;
+        let store = engine.read_guide_design_store();
+        Ok(store.guide_sets[output]
+            .guides
+            .iter()
+            .map(|guide| (guide.guide_id.clone(), guide.rank))
+            .collect())
+    };
+    let ids = |rows: &[(&str, Option<usize>)]| {
+        rows.iter()
+            .map(|(id, rank)| (id.to_string(), *rank))
+            .collect::<Vec<_>>()
+    };
+
+    let union = run(
+        &mut engine,
+        CandidateSetOperator::Union,
+        "merged",
+        GuideSetMatchKey::GuideId,
+    )
+    .unwrap();
+    assert_eq!(
+        union,
+        ids(&[
+            ("b2", Some(1)),
+            ("shared", Some(1)),
+            ("a1", Some(2)),
+            ("b_same_site", Some(3)),
+            ("a3", None),
+        ])
+    );
+    let intersect = run(
+        &mut engine,
+        CandidateSetOperator::Intersect,
+        "common",
+        GuideSetMatchKey::GuideId,
+    )
+    .unwrap();
+    assert_eq!(intersect, ids(&[("shared", Some(1))]));
+    let subtract = run(
+        &mut engine,
+        CandidateSetOperator::Subtract,
+        "a_only",
+        GuideSetMatchKey::GuideId,
+    )
+    .unwrap();
+    assert_eq!(subtract, ids(&[("a1", Some(2)), ("a3", None)]));
+
+    let subtract_by_site = run(
+        &mut engine,
+        CandidateSetOperator::Subtract,
+        "a_only_sites",
+        GuideSetMatchKey::Coordinates,
+    )
+    .unwrap();
+    assert_eq!(subtract_by_site, ids(&[("a3", None)]));
+    let union_by_site = run(
+        &mut engine,
+        CandidateSetOperator::Union,
+        "merged_sites",
+        GuideSetMatchKey::Coordinates,
+    )
+    .unwrap();
+    assert_eq!(
+        union_by_site,
+        ids(&[
+            ("b2", Some(1)),
+            ("shared", Some(1)),
+            ("a1", Some(2)),
+            ("a3", None),
+        ])
+    );
+
+    let replaced = engine
+        .apply(Operation::GuideSetOp {
+            op: CandidateSetOperator::Intersect,
+            left_guide_set: "region_a".to_string(),
+            right_guide_set: "region_b".to_string(),
+            output_guide_set: "common".to_string(),
+            match_by: GuideSetMatchKey::GuideId,
+        })
+        .unwrap();
+    assert!(
+        replaced
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("replaced existing guide set"))
+    );
+    let err = engine
+        .apply(Operation::GuideSetOp {
+            op: CandidateSetOperator::Subtract,
+            left_guide_set: "common".to_string(),
+            right_guide_set: "region_b".to_string(),
+            output_guide_set: "nothing".to_string(),
+            match_by: GuideSetMatchKey::GuideId,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    let err = engine
+        .apply(Operation::GuideSetOp {
+            op: CandidateSetOperator::Union,
+            left_guide_set: "region_a".to_string(),
+            right_guide_set: "no_such_set".to_string(),
+            output_guide_set: "x".to_string(),
+            match_by: GuideSetMatchKey::GuideId,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
+#[test]
+fn test_assert_parity_replays_hash_backed_set_workflow() {
+    let guide = |guide_id: &str, start: usize, rank: Option<usize>| GuideCandidate {
+        guide_id: guide_id.to_string(),
+        seq_id: "target".to_string(),
+        start_0based: start,
+        end_0based_exclusive: start + 20,
+        strand: "+".to_string(),
+        protospacer: "GACCTGTTGACGATGTTCCA".to_string(),
+        pam: "AGG".to_string(),
+        nuclease: "SpCas9".to_string(),
+        cut_offset_from_protospacer_start: 17,
+        rank,
+        off_target_score: None,
+    };
+    let windows = |set_name: &str, step_bp: usize| Operation::GenerateCandidateSet {
+        set_name: set_name.to_string(),
+        seq_id: "target".to_string(),
+        length_bp: 6,
+        step_bp,
+        feature_kinds: vec![],
+        feature_label_regex: None,
+        max_distance_bp: None,
+        feature_geometry_mode: None,
+        feature_boundary_mode: None,
+        feature_strand_relation: None,
+        limit: Some(64),
+    };
+    // Set algebra and unranked guides go through hash-keyed stores, the usual
+    // place for iteration order to leak into persisted lists.
+    let wf = Workflow {
+        run_id: "parity".to_string(),
+        ops: vec![
+            Operation::CreateSequenceFromText {
+                sequence_text: "ATGACCGGTTAACCGGTTAAGGCCTTAAGGCCATGCATGCAAGCTT".to_string(),
+                output_id: Some("target".to_string()),
+                name: None,
+                circular: false,
+            },
+            windows("every_3", 3),
+            windows("every_4", 4),
+            Operation::CandidateSetOp {
+                op: CandidateSetOperator::Union,
+                left_set: "every_3".to_string(),
+                right_set: "every_4".to_string(),
+                output_set: "merged".to_string(),
+            },
+            Operation::UpsertGuideSet {
+                guide_set_id: "left".to_string(),
+                guides: vec![
+                    guide("z", 0, None),
+                    guide("m", 5, None),
+                    guide("a", 9, None),
+                ],
+            },
+            Operation::UpsertGuideSet {
+                guide_set_id: "right".to_string(),
+                guides: vec![guide("q", 12, None), guide("b", 15, Some(1))],
+            },
+            Operation::GuideSetOp {
+                op: CandidateSetOperator::Union,
+                left_guide_set: "left".to_string(),
+                right_guide_set: "right".to_string(),
+                output_guide_set: "all".to_string(),
+                match_by: GuideSetMatchKey::GuideId,
+            },
+        ],
+    };
+    GentleEngine::assert_parity(&wf).unwrap();
+
+    let mut left = ProjectState::default();
+    left.sequences.insert("a".to_string(), seq("ACGT"));
+    left.sequences.insert("b".to_string(), seq("ACGT"));
+    let mut right = left.clone();
+    assert_eq!(
+        GentleEngine::parity_first_difference(&left, &right).unwrap(),
+        None
+    );
+    right.sequences.insert("b".to_string(), seq("ACGA"));
+    right
+        .metadata
+        .insert("extra".to_string(), json!({"created_at_unix_ms": 1}));
+    assert_eq!(
+        GentleEngine::parity_first_difference(&left, &right).unwrap(),
+        Some("sequence 'b'".to_string())
+    );
+    right.sequences.insert("b".to_string(), seq("ACGT"));
+    assert_eq!(
+        GentleEngine::parity_first_difference(&left, &right).unwrap(),
+        Some("metadata key 'extra'".to_string())
+    );
+    left.metadata
+        .insert("extra".to_string(), json!({"created_at_unix_ms": 2}));
+    assert_eq!(
+        GentleEngine::parity_first_difference(&left, &right).unwrap(),
+        None
+    );
+}
+
 #[test]
 fn test_guide_set_duplicate_ids_rejected() {
     let mut engine = GentleEngine::from_state(ProjectState::default());
@@ -28633,6 +34567,7 @@ fn test_guide_set_duplicate_ids_rejected() {
                     nuclease: "SpCas9".to_string(),
                     cut_offset_from_protospacer_start: 17,
                     rank: Some(1),
+                    off_target_score: None,
                 },
                 GuideCandidate {
                     guide_id: "dup_1".to_string(),
@@ -28645,6 +34580,7 @@ fn test_guide_set_duplicate_ids_rejected() {
                     nuclease: "SpCas9".to_string(),
                     cut_offset_from_protospacer_start: 17,
                     rank: Some(2),
+                    off_target_score: None,
                 },
             ],
         })
@@ -29653,6 +35589,183 @@ fn test_build_splicing_expert_view_accepts_misc_rna_seed_feature() {
     assert!(!view.transcripts.is_empty());
 }
 
+#[test]
+fn test_consensus_uses_iupac_codes_below_threshold() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("s1".to_string(), seq("ACGTACGTAAGGCCTTACGT"));
+    state
+        .sequences
+        .insert("s2".to_string(), seq("ACGTACGTAGGGCCTTACGT"));
+    state
+        .sequences
+        .insert("s3".to_string(), seq("ACGTACGTAAGGCCTTACTT"));
+    // One extra G: a gap in every other row, so the column is dropped.
+    state
+        .sequences
+        .insert("s4".to_string(), seq("ACGTACGTAAGGGCCTTACGT"));
+    let mut engine = GentleEngine::from_state(state);
+    let ids = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        engine.consensus(&ids(&["s1", "s2", "s3"]), 0.9).unwrap(),
+        "ACGTACGTARGGCCTTACKT"
+    );
+    assert_eq!(
+        engine.consensus(&ids(&["s1", "s2", "s3"]), 0.6).unwrap(),
+        "ACGTACGTAAGGCCTTACGT"
+    );
+    assert_eq!(
+        engine
+            .consensus(&ids(&["s1", "s2", "s3", "s4"]), 0.9)
+            .unwrap(),
+        "ACGTACGTARGGCCTTACKT"
+    );
+    assert!(matches!(
+        engine.consensus(&ids(&["s1"]), 0.9).unwrap_err().code,
+        ErrorCode::InvalidInput
+    ));
+    assert!(matches!(
+        engine.consensus(&ids(&["s1", "s2"]), 0.5).unwrap_err().code,
+        ErrorCode::InvalidInput
+    ));
+
+    let res = engine
+        .apply(Operation::MakeConsensus {
+            seq_ids: ids(&["s1", "s2", "s3"]),
+            threshold: 0.9,
+            output_id: None,
+        })
+        .unwrap();
+    assert_eq!(res.created_seq_ids, vec!["consensus".to_string()]);
+    assert_eq!(
+        engine.state().sequences["consensus"].get_forward_string(),
+        "ACGTACGTARGGCCTTACKT"
+    );
+}
+
+#[test]
+fn test_sequence_distance_hamming_and_levenshtein() {
+    let mut state = ProjectState::default();
+    state.sequences.insert("a".to_string(), seq("ACGTACGTAC"));
+    state
+        .sequences
+        .insert("same".to_string(), seq("acgtacgtac"));
+    state.sequences.insert("snp".to_string(), seq("ACGTTCGTAC"));
+    state
+        .sequences
+        .insert("ins".to_string(), seq("ACGTAACGTAC"));
+    state.sequences.insert("del".to_string(), seq("ACGACGTAC"));
+    state
+        .sequences
+        .insert("far".to_string(), seq("TTTTTTTTTTTTTTTT"));
+    let engine = GentleEngine::from_state(state);
+    let lev = |max| DistanceMetric::Levenshtein { max };
+
+    assert_eq!(
+        engine
+            .sequence_distance("a", "same", DistanceMetric::Hamming)
+            .unwrap(),
+        0
+    );
+    assert_eq!(engine.sequence_distance("a", "same", lev(3)).unwrap(), 0);
+    assert_eq!(
+        engine
+            .sequence_distance("a", "snp", DistanceMetric::Hamming)
+            .unwrap(),
+        1
+    );
+    assert_eq!(engine.sequence_distance("a", "snp", lev(3)).unwrap(), 1);
+    assert_eq!(engine.sequence_distance("a", "ins", lev(3)).unwrap(), 1);
+    assert_eq!(engine.sequence_distance("del", "a", lev(3)).unwrap(), 1);
+    assert_eq!(engine.sequence_distance("ins", "del", lev(3)).unwrap(), 2);
+    assert_eq!(engine.sequence_distance("ins", "del", lev(1)).unwrap(), 2);
+    assert_eq!(engine.sequence_distance("a", "far", lev(4)).unwrap(), 5);
+    assert_eq!(engine.sequence_distance("a", "snp", lev(0)).unwrap(), 1);
+
+    assert!(matches!(
+        engine
+            .sequence_distance("a", "ins", DistanceMetric::Hamming)
+            .unwrap_err()
+            .code,
+        ErrorCode::InvalidInput
+    ));
+    assert!(matches!(
+        engine
+            .sequence_distance("a", "missing", lev(3))
+            .unwrap_err()
+            .code,
+        ErrorCode::NotFound
+    ));
+}
+
+#[test]
+fn test_annotate_differences_marks_snps_and_deletion() {
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "ref".to_string(),
+        seq("ATGACCGTTAGCTAGGCATCGATTCCAGTACGGTCA"),
+    );
+    // G7A, deletion of A18, T29C.
+    state.sequences.insert(
+        "var".to_string(),
+        seq("ATGACCATTAGCTAGGCTCGATTCCAGCACGGTCA"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let variations = |engine: &GentleEngine| {
+        engine.state().sequences["var"]
+            .features()
+            .iter()
+            .filter(|feature| feature.kind.to_string() == "variation")
+            .map(|feature| {
+                (
+                    feature.location.clone(),
+                    first_qualifier(feature, "note").unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let res = engine
+        .apply(Operation::AnnotateDifferences {
+            reference: "ref".to_string(),
+            variant: "var".to_string(),
+        })
+        .unwrap();
+    assert_eq!(res.changed_seq_ids, vec!["var".to_string()]);
+    assert_eq!(
+        variations(&engine),
+        vec![
+            (gb_io::seq::Location::simple_range(6, 7), "G7A".to_string()),
+            (
+                gb_io::seq::Location::simple_range(16, 18),
+                "18delA".to_string()
+            ),
+            (
+                gb_io::seq::Location::simple_range(27, 28),
+                "T29C".to_string()
+            ),
+        ]
+    );
+
+    // Re-running does not duplicate features.
+    let res = engine
+        .apply(Operation::AnnotateDifferences {
+            reference: "ref".to_string(),
+            variant: "var".to_string(),
+        })
+        .unwrap();
+    assert!(res.changed_seq_ids.is_empty());
+    assert_eq!(variations(&engine).len(), 3);
+    assert!(
+        engine.state().sequences["ref"]
+            .features()
+            .iter()
+            .all(|feature| feature.kind.to_string() != "variation")
+    );
+}
+
 #[test]
 fn test_align_sequences_global_sets_structured_result() {
     let mut state = ProjectState::default();
@@ -33316,6 +39429,8 @@ fn test_interpret_rna_reads_accepts_reverse_strand_demo_ncrna_seed() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
 
@@ -34014,6 +40129,8 @@ fn test_seed_filter_keeps_deleted_demo_subsequences_and_rejects_random() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
     let mut seed_filter = RnaReadSeedFilterConfig::default();
@@ -34159,6 +40276,8 @@ fn test_seed_filter_cross_species_and_close_family_specificity_sets() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
     let seed_filter = RnaReadSeedFilterConfig::default();
@@ -34385,6 +40504,8 @@ fn test_rna_read_preflight_uses_tp53_tp63_variant_controls() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
     let feature_id = {
@@ -44480,6 +50601,878 @@ fn export_construct_reasoning_graph_json_writes_pretty_payload() {
     assert!(text.contains("\"graph_id\": \"graph_export_demo\""));
 }
 
+#[test]
+fn test_export_restriction_map_reports_manual_digest_fragment_sizes() {
+    let mut state = ProjectState::default();
+    // EcoRI cuts after bases 5 and 25, BamHI after base 16 (32 bp, linear).
+    state.sequences.insert(
+        "construct".to_string(),
+        seq("AAAAGAATTCAAAAAGGATCCAAAGAATTCAA"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let dir = tempdir().expect("tempdir");
+    let csv_path = dir.path().join("map.csv");
+    let md_path = dir.path().join("map.md");
+
+    let result = engine
+        .apply(Operation::ExportRestrictionMap {
+            seq_id: "construct".to_string(),
+            path: csv_path.to_string_lossy().to_string(),
+            format: RestrictionMapFormat::Csv,
+            enzyme_subset: Some(vec![
+                "ecori".to_string(),
+                "BamHI".to_string(),
+                "NotI".to_string(),
+            ]),
+            unique_cutters_only: false,
+        })
+        .expect("export csv map");
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("'NotI' does not cut"))
+    );
+    let csv = fs::read_to_string(&csv_path).expect("read csv map");
+    assert_eq!(
+        csv,
+        "enzyme,recognition_site,number_of_cuts,cut_positions,fragment_sizes_bp\n\
+         BamHI,GGATCC,1,16,16;16\n\
+         EcoRI,GAATTC,2,5;25,5;20;7\n"
+    );
+
+    engine
+        .apply(Operation::ExportRestrictionMap {
+            seq_id: "construct".to_string(),
+            path: md_path.to_string_lossy().to_string(),
+            format: RestrictionMapFormat::Markdown,
+            enzyme_subset: Some(vec!["EcoRI".to_string(), "BamHI".to_string()]),
+            unique_cutters_only: true,
+        })
+        .expect("export markdown map");
+    let markdown = fs::read_to_string(&md_path).expect("read markdown map");
+    assert!(markdown.contains("32 bp, linear"));
+    assert!(markdown.contains("| BamHI | GGATCC | 1 | 16 | 16, 16 |"));
+    assert!(!markdown.contains("EcoRI"));
+}
+
+#[test]
+fn test_non_cutters_lists_enzymes_without_sites() {
+    let mut state = ProjectState::default();
+    // EcoRI and BamHI sites only.
+    state.sequences.insert(
+        "insert".to_string(),
+        seq("AAAAGAATTCAAAAAGGATCCAAAGAATTCAA"),
+    );
+    let engine = GentleEngine::from_state(state);
+
+    let subset = ["ecori", "NotI", "BamHI", "hindiii"].map(str::to_string);
+    assert_eq!(
+        engine.non_cutters("insert", Some(&subset)).unwrap(),
+        vec!["HindIII".to_string(), "NotI".to_string()]
+    );
+
+    let all = engine.non_cutters("insert", None).unwrap();
+    assert!(all.iter().any(|name| name == "NotI"));
+    assert!(!all.iter().any(|name| name == "EcoRI" || name == "BamHI"));
+
+    let err = engine
+        .non_cutters("insert", Some(&["NoSuchEnzyme".to_string()]))
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    let err = engine.non_cutters("missing", None).unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+}
+
+#[test]
+fn test_export_track_csv_matches_hand_computed_windows() {
+    let mut state = ProjectState::default();
+    // Windows of 4 bp every 3 bp: GGGG (1-4), GCCA (4-7), AAAT (7-10).
+    state.sequences.insert("s".to_string(), seq("GGGGCCAAAT"));
+    let mut engine = GentleEngine::from_state(state);
+    let dir = tempdir().expect("tempdir");
+    let export = |engine: &mut GentleEngine, track: TrackKind, window_bp: usize, step_bp: usize| {
+        let path = dir.path().join(format!("{}.csv", track.as_str()));
+        engine
+            .apply(Operation::ExportTrackCsv {
+                seq_id: "s".to_string(),
+                track,
+                window_bp,
+                step_bp,
+                path: path.to_string_lossy().to_string(),
+            })
+            .map(|_| fs::read_to_string(&path).expect("read track csv"))
+    };
+
+    let gc = export(&mut engine, TrackKind::GcFraction, 4, 3).expect("gc track");
+    let gc_lines = gc.lines().collect::<Vec<_>>();
+    assert_eq!(gc_lines[0], "start_1based,end_1based,gc_fraction");
+    assert_eq!(gc_lines[1], "1,4,1.000000");
+    assert_eq!(gc_lines[2], "4,7,0.750000");
+    assert_eq!(gc_lines[3], "7,10,0.000000");
+    assert_eq!(gc_lines.len(), 4);
+
+    let skew = export(&mut engine, TrackKind::GcSkew, 4, 3).expect("skew track");
+    let skew_lines = skew.lines().collect::<Vec<_>>();
+    assert_eq!(skew_lines[1], "1,4,1.000000");
+    assert_eq!(skew_lines[2], "4,7,-0.333333");
+    assert_eq!(skew_lines[3], "7,10,0.000000");
+
+    let tm = export(&mut engine, TrackKind::MeltingTemp, 4, 3).expect("tm track");
+    let tm_lines = tm.lines().collect::<Vec<_>>();
+    assert_eq!(
+        tm_lines[1],
+        format!("1,4,{:.6}", GentleEngine::estimate_primer_tm_c(b"GGGG"))
+    );
+    assert_eq!(
+        tm_lines[3],
+        format!("7,10,{:.6}", GentleEngine::estimate_primer_tm_c(b"AAAT"))
+    );
+
+    let err = export(&mut engine, TrackKind::GcFraction, 11, 1).unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+    let err = export(&mut engine, TrackKind::GcFraction, 4, 0).unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn test_export_map_legend_lists_every_drawn_feature() {
+    let state = crate::test_support::dense_plasmid_visual_benchmark_state();
+    let mut engine = GentleEngine::from_state(state);
+    let tmp = tempdir().expect("tempdir");
+    let attr = |tag: &str, name: &str| {
+        let needle = format!("{name}=\"");
+        tag.find(&needle).map(|idx| {
+            let rest = &tag[idx + needle.len()..];
+            rest[..rest.find('"').unwrap_or(rest.len())].to_string()
+        })
+    };
+    for mode in [RenderSvgMode::Linear, RenderSvgMode::Circular] {
+        let svg_path = tmp.path().join(format!("{mode:?}.svg"));
+        let legend_path = tmp.path().join(format!("{mode:?}.csv"));
+        engine
+            .apply(Operation::RenderSequenceSvg {
+                seq_id: "dense_plasmid".to_string(),
+                mode,
+                path: svg_path.display().to_string(),
+                viewport: None,
+            })
+            .expect("render svg");
+        engine
+            .apply(Operation::ExportMapLegend {
+                seq_id: "dense_plasmid".to_string(),
+                path: legend_path.display().to_string(),
+                mode,
+                format: LegendFormat::Csv,
+            })
+            .expect("export legend");
+        let svg = fs::read_to_string(&svg_path).expect("read svg");
+        let legend = fs::read_to_string(&legend_path).expect("read legend");
+        let mut lines = legend.lines();
+        assert_eq!(
+            lines.next(),
+            Some("name,kind,start_1based,end_1based,strand,color")
+        );
+        let rows = lines
+            .map(|line| {
+                let fields = line.rsplitn(6, ',').collect::<Vec<_>>();
+                (fields[4].to_string(), fields[0].to_string())
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = svg
+            .split('<')
+            .filter(|tag| tag.contains("data-gentle-role=\"feature-block\""))
+            .collect::<Vec<_>>();
+        assert!(!blocks.is_empty(), "{mode:?} map drew no features");
+        assert!(rows.len() >= blocks.len());
+        for block in blocks {
+            let kind = attr(block, "data-gentle-feature-kind").expect("kind attr");
+            let color = attr(block, "fill")
+                .filter(|fill| fill != "none")
+                .or_else(|| attr(block, "stroke"))
+                .expect("color attr");
+            assert!(
+                rows.contains(&(kind.clone(), color.clone())),
+                "{mode:?} feature {kind} {color} missing from legend {rows:?}"
+            );
+        }
+    }
+
+    let md_path = tmp.path().join("legend.md");
+    engine
+        .apply(Operation::ExportMapLegend {
+            seq_id: "dense_plasmid".to_string(),
+            path: md_path.display().to_string(),
+            mode: RenderSvgMode::Circular,
+            format: LegendFormat::Markdown,
+        })
+        .expect("export markdown legend");
+    let md = fs::read_to_string(&md_path).expect("read markdown legend");
+    assert!(md.starts_with("# Map legend: dense_plasmid (circular)"));
+    assert!(md.contains("| Feature | Kind | Start | End | Strand | Color |"));
+}
+
+#[test]
+fn test_sequence_stats_counts_bases_features_orfs_and_cutters() {
+    let mut state = ProjectState::default();
+    // One 110-codon forward ORF (the only reverse-strand ATG sits at the
+    // 5' end), two Ns and a single EcoRI site.
+    let sequence = format!("CCATG{}TAANNGAATTC", "GCC".repeat(110));
+    let mut dna = seq(&sequence);
+    for (kind, from, to) in [
+        ("CDS", 2, 338),
+        ("gene", 2, 338),
+        ("misc_feature", 0, 2),
+        ("misc_feature", 340, 348),
+    ] {
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: kind.into(),
+            location: gb_io::seq::Location::simple_range(from, to),
+            qualifiers: vec![],
+        });
+    }
+    state.sequences.insert("stats".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+
+    let stats = engine.sequence_stats("stats").expect("sequence stats");
+    assert_eq!(stats.length_bp, sequence.len());
+    assert_eq!(stats.topology, "linear");
+    assert_eq!(stats.n_count, 2);
+    assert_eq!(stats.base_counts.get("C"), Some(&223));
+    assert_eq!(stats.base_counts.get("G"), Some(&112));
+    assert_eq!(stats.base_counts.values().sum::<usize>(), sequence.len());
+    let canonical = (sequence.len() - 2) as f64;
+    assert!((stats.gc_percent - 335.0 / canonical * 100.0).abs() < 1e-9);
+    assert_eq!(stats.feature_counts_by_kind.get("CDS"), Some(&1));
+    assert_eq!(stats.feature_counts_by_kind.get("gene"), Some(&1));
+    assert_eq!(stats.feature_counts_by_kind.get("misc_feature"), Some(&2));
+    assert_eq!(stats.orf_count, 1);
+    let (unique_cutters, _) = engine
+        .restriction_map_rows("stats", None, true)
+        .expect("unique cutters");
+    assert!(unique_cutters.iter().any(|row| row.enzyme == "EcoRI"));
+    assert_eq!(stats.unique_cutter_count, unique_cutters.len());
+
+    let tmp = tempdir().expect("tempdir");
+    let path = tmp.path().join("stats.json");
+    engine
+        .apply(Operation::ExportSequenceStats {
+            seq_id: "stats".to_string(),
+            path: path.display().to_string(),
+        })
+        .expect("export sequence stats");
+    let written: SequenceStats =
+        serde_json::from_str(&fs::read_to_string(&path).expect("read stats")).expect("parse");
+    assert_eq!(written, stats);
+    assert_eq!(
+        engine.sequence_stats("missing").unwrap_err().code,
+        ErrorCode::NotFound
+    );
+}
+
+#[test]
+fn test_export_proteome_translates_simple_and_joined_reverse_cds() {
+    // CDS a: ATG AAA TAG TGG TAA (internal stop -> "MK" + warning).
+    // CDS b: complement(join(17..23, 28..34)) splices to ATG TTT GGC TAA -> "MFG".
+    let mut dna = seq("ATGAAATAGTGGTAACCTTAGCCGGGGGAAACATCC");
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::simple_range(0, 15),
+        qualifiers: vec![("label".into(), Some("geneA".to_string()))],
+    });
+    dna.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::Complement(Box::new(gb_io::seq::Location::Join(vec![
+            gb_io::seq::Location::simple_range(17, 23),
+            gb_io::seq::Location::simple_range(28, 34),
+        ]))),
+        qualifiers: vec![("gene".into(), Some("geneB".to_string()))],
+    });
+    let mut state = ProjectState::default();
+    state.sequences.insert("s".to_string(), dna);
+    let mut engine = GentleEngine::from_state(state);
+    let td = tempdir().unwrap();
+    let path = td.path().join("proteome.faa");
+    let result = engine
+        .apply(Operation::ExportProteome {
+            seq_id: "s".to_string(),
+            path: path.to_string_lossy().to_string(),
+            translation_table: None,
+        })
+        .unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        ">geneA\nMK\n>geneB\nMFG\n"
+    );
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].code, WarningCode::CdsTranslationIssue);
+    assert!(result.warnings[0].message.contains("internal stop codon"));
+    assert!(result.messages[0].contains("Wrote 2 protein(s)"));
+
+    // GFF-style `phase` and `translation_table` qualifiers: ATG TGA AAA TAA
+    // after skipping one base reads "MWK" under the vertebrate mito code.
+    let mut mito = seq("CATGTGAAAATAA");
+    mito.features_mut().push(gb_io::seq::Feature {
+        kind: "CDS".into(),
+        location: gb_io::seq::Location::simple_range(0, 13),
+        qualifiers: vec![
+            ("label".into(), Some("geneC".to_string())),
+            ("phase".into(), Some("1".to_string())),
+            ("translation_table".into(), Some("2".to_string())),
+        ],
+    });
+    engine
+        .state_mut()
+        .sequences
+        .insert("mito".to_string(), mito);
+    let result = engine
+        .apply(Operation::ExportProteome {
+            seq_id: "mito".to_string(),
+            path: path.to_string_lossy().to_string(),
+            translation_table: None,
+        })
+        .unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), ">geneC\nMWK\n");
+    assert!(result.warnings.is_empty());
+
+    let err = engine
+        .apply(Operation::ExportProteome {
+            seq_id: "s".to_string(),
+            path: path.to_string_lossy().to_string(),
+            translation_table: Some(99),
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+    assert!(err.message.contains("translation_table 99"));
+}
+
+#[test]
+fn test_find_inverted_repeats_reports_perfect_and_mismatched_stems() {
+    let mut state = ProjectState::default();
+    // Stem CAGTCG / CGACTG around a TTTT loop; the second copy has one
+    // unpaired stem position (CGTCTG).
+    state
+        .sequences
+        .insert("perfect".to_string(), seq("AAAACAGTCGTTTTCGACTGAAAA"));
+    state
+        .sequences
+        .insert("mismatch".to_string(), seq("AAAACAGTCGTTTTCGTCTGAAAA"));
+    let mut engine = GentleEngine::from_state(state);
+
+    let perfect = engine
+        .find_inverted_repeats("perfect", 6, 4, 0)
+        .expect("scan perfect");
+    assert_eq!(
+        perfect,
+        vec![InvertedRepeat {
+            left_stem_start_1based: 5,
+            left_stem_end_1based: 10,
+            right_stem_start_1based: 15,
+            right_stem_end_1based: 20,
+            stem_length_bp: 6,
+            loop_length_bp: 4,
+            mismatches: 0,
+        }]
+    );
+
+    assert!(
+        engine
+            .find_inverted_repeats("mismatch", 6, 4, 0)
+            .expect("strict scan")
+            .is_empty()
+    );
+    let tolerant = engine
+        .find_inverted_repeats("mismatch", 6, 4, 1)
+        .expect("tolerant scan");
+    assert_eq!(tolerant.len(), 1);
+    assert_eq!(tolerant[0].stem_length_bp, 6);
+    assert_eq!(tolerant[0].mismatches, 1);
+
+    let res = engine
+        .apply(Operation::AnnotateInvertedRepeats {
+            seq_id: "perfect".to_string(),
+            min_stem: 6,
+            max_loop: 4,
+            max_mismatches: 0,
+            clear_existing: None,
+        })
+        .expect("annotate");
+    assert_eq!(res.changed_seq_ids, vec!["perfect".to_string()]);
+    let features = engine.state().sequences["perfect"]
+        .features()
+        .iter()
+        .filter(|feature| {
+            GentleEngine::feature_qualifier_text(feature, "gentle_generated").as_deref()
+                == Some("inverted_repeat")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(features.len(), 1);
+    assert_eq!(features[0].kind.to_string(), "misc_feature");
+    assert_eq!(
+        features[0].location,
+        gb_io::seq::Location::simple_range(4, 20)
+    );
+}
+
+#[test]
+fn test_search_subsequence_reports_exact_mismatch_and_reverse_hits() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("exact".to_string(), seq("TTTTACGTTGCAAGTTTT"));
+    state
+        .sequences
+        .insert("one_mm".to_string(), seq("GGGGACGTAGCAAGGGGG"));
+    state
+        .sequences
+        .insert("rev".to_string(), seq("CCCCCTTGCAACGTCCCC"));
+    let mut engine = GentleEngine::from_state(state);
+    let hit = |seq_id: &str, strand: &str, mismatches: usize| SubsequenceHit {
+        seq_id: seq_id.to_string(),
+        start_1based: 5,
+        end_1based: 14,
+        strand: strand.to_string(),
+        mismatches,
+    };
+
+    assert_eq!(
+        engine.search_subsequence("ACGTTGCAAG", 0, false),
+        vec![hit("exact", "+", 0)]
+    );
+    assert_eq!(
+        engine.search_subsequence("acgttgcaag", 1, true),
+        vec![
+            hit("exact", "+", 0),
+            hit("one_mm", "+", 1),
+            hit("rev", "-", 0)
+        ]
+    );
+    // IUPAC query positions absorb the one_mm substitution.
+    assert_eq!(
+        engine.search_subsequence("ACGTWGCAAG", 0, false),
+        vec![hit("exact", "+", 0), hit("one_mm", "+", 0)]
+    );
+    assert!(engine.search_subsequence("ACGTXGCAAG", 1, true).is_empty());
+
+    let res = engine
+        .apply(Operation::AnnotateSearchHits {
+            query: "ACGTTGCAAG".to_string(),
+            max_mismatches: 1,
+            both_strands: true,
+            clear_existing: None,
+        })
+        .unwrap();
+    assert_eq!(
+        res.changed_seq_ids,
+        vec!["exact".to_string(), "one_mm".to_string(), "rev".to_string()]
+    );
+    let rev_features = engine.state().sequences["rev"]
+        .features()
+        .iter()
+        .filter(|feature| {
+            GentleEngine::feature_qualifier_text(feature, "gentle_generated").as_deref()
+                == Some("search_hit")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rev_features.len(), 1);
+    assert_eq!(rev_features[0].kind.to_string(), "misc_binding");
+    assert_eq!(
+        rev_features[0].location,
+        gb_io::seq::Location::Complement(Box::new(gb_io::seq::Location::simple_range(4, 14)))
+    );
+}
+
+#[test]
+fn test_search_subsequence_long_query_and_circular_origin() {
+    let probe = "ACGTTGCAAGTCCGATAGGCTTACGGATCCATGCAAGTCGATCGGATTACGCTAGCTAGGCATCGATG";
+    assert!(probe.len() > 64);
+    let mut mutated = probe.as_bytes().to_vec();
+    mutated[40] = b'C';
+    mutated[41] = b'A';
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "long".to_string(),
+        seq(&format!("TT{}TT", String::from_utf8(mutated).unwrap())),
+    );
+    let mut circular = seq("GCAAGTTTTTTTTTTACGTT");
+    circular.set_circular(true);
+    state.sequences.insert("circ".to_string(), circular);
+    let engine = GentleEngine::from_state(state);
+
+    assert!(engine.search_subsequence(probe, 1, false).is_empty());
+    let hits = engine.search_subsequence(probe, 2, false);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].seq_id, "long");
+    assert_eq!(hits[0].mismatches, 2);
+
+    let hits = engine.search_subsequence("ACGTTGCAAG", 0, false);
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].seq_id, "circ");
+    assert_eq!((hits[0].start_1based, hits[0].end_1based), (16, 5));
+}
+
+#[test]
+fn test_annotate_from_library_finds_embedded_t7_promoter() {
+    // T7 promoter on the top strand, then a one-mismatch copy on the bottom strand.
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "backbone".to_string(),
+        seq("CCCCTAATACGACTCACTATAGCCCCCCCCCTATAGTGAGTCGTATTTCCCC"),
+    );
+    let mut engine = GentleEngine::from_state(state);
+    let library_features = |engine: &GentleEngine| {
+        engine.state().sequences["backbone"]
+            .features()
+            .iter()
+            .filter(|feature| {
+                GentleEngine::feature_qualifier_text(feature, "gentle_generated").as_deref()
+                    == Some("library_feature")
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let res = engine
+        .apply(Operation::AnnotateFromLibrary {
+            seq_id: "backbone".to_string(),
+            library: FeatureLibrary::CommonVector,
+            min_identity: 0.9,
+            clear_existing: None,
+        })
+        .unwrap();
+    assert_eq!(res.changed_seq_ids, vec!["backbone".to_string()]);
+    let features = library_features(&engine);
+    assert_eq!(features.len(), 2);
+    assert!(features.iter().all(|f| f.kind.to_string() == "promoter"));
+    assert_eq!(
+        GentleEngine::feature_qualifier_text(&features[0], "label").as_deref(),
+        Some("T7 promoter #1")
+    );
+    assert_eq!(
+        features[0].location,
+        gb_io::seq::Location::simple_range(4, 22)
+    );
+    assert_eq!(
+        GentleEngine::feature_qualifier_text(&features[1], "label").as_deref(),
+        Some("T7 promoter #2")
+    );
+    assert_eq!(
+        GentleEngine::feature_qualifier_text(&features[1], "mismatches").as_deref(),
+        Some("1")
+    );
+    assert_eq!(
+        features[1].location,
+        gb_io::seq::Location::Complement(Box::new(gb_io::seq::Location::simple_range(30, 48)))
+    );
+
+    // Exact matching keeps only the top-strand copy, replacing earlier hits.
+    engine
+        .apply(Operation::AnnotateFromLibrary {
+            seq_id: "backbone".to_string(),
+            library: FeatureLibrary::CommonVector,
+            min_identity: 1.0,
+            clear_existing: None,
+        })
+        .unwrap();
+    let features = library_features(&engine);
+    assert_eq!(features.len(), 1);
+    assert_eq!(
+        GentleEngine::feature_qualifier_text(&features[0], "label").as_deref(),
+        Some("T7 promoter")
+    );
+
+    let err = engine
+        .apply(Operation::AnnotateFromLibrary {
+            seq_id: "backbone".to_string(),
+            library: FeatureLibrary::CommonVector,
+            min_identity: 0.0,
+            clear_existing: None,
+        })
+        .unwrap_err();
+    assert!(matches!(err.code, ErrorCode::InvalidInput));
+}
+
+#[test]
+fn test_annotate_methylation_sites_per_mode() {
+    let mut state = ProjectState::default();
+    // Dam GATC at 3..6, Dcm CCAGG at 9..13, CpG at 16..17 (1-based).
+    state
+        .sequences
+        .insert("s".to_string(), seq("AAGATCAACCAGGAACGAAA"));
+    let mut engine = GentleEngine::from_state(state);
+    let methylation_features = |engine: &GentleEngine| {
+        engine.state().sequences["s"]
+            .features()
+            .iter()
+            .filter(|feature| {
+                GentleEngine::feature_qualifier_text(feature, "gentle_generated").as_deref()
+                    == Some("methylation_site")
+            })
+            .map(|feature| {
+                assert_eq!(feature.kind.to_string(), "modified_base");
+                let (from, _) = feature.location.find_bounds().unwrap();
+                (
+                    from,
+                    GentleEngine::feature_qualifier_text(feature, "mod_base").unwrap(),
+                    GentleEngine::feature_qualifier_text(feature, "note").unwrap(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let cases: [(bool, bool, bool, Vec<(i64, &str)>); 4] = [
+        (true, false, false, vec![(3, "m6a")]),
+        (false, true, false, vec![(9, "m5c")]),
+        (false, false, true, vec![(15, "m5c")]),
+        (true, true, true, vec![(3, "m6a"), (9, "m5c"), (15, "m5c")]),
+    ];
+    for (dam, dcm, cpg, expected) in cases {
+        let mut mode = MethylationMode::default();
+        mode.set_dam(dam);
+        mode.set_dcm(dcm);
+        mode.set_cpg(cpg);
+        engine
+            .apply(Operation::AnnotateMethylationSites {
+                seq_id: "s".to_string(),
+                mode: mode.clone(),
+                clear_existing: None,
+            })
+            .unwrap();
+        let features = methylation_features(&engine);
+        assert_eq!(
+            features
+                .iter()
+                .map(|(from, mod_base, _)| (*from, mod_base.as_str()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(
+            features
+                .iter()
+                .all(|(_, _, note)| note.ends_with(&format!("(mode={})", mode.describe())))
+        );
+    }
+    let notes = methylation_features(&engine)
+        .into_iter()
+        .map(|(_, _, note)| note)
+        .collect::<Vec<_>>();
+    assert!(notes[0].starts_with("dam site GATC at 3..6"));
+    assert!(notes[1].starts_with("dcm site CCWGG at 9..13"));
+    assert!(notes[2].starts_with("cpg site CG at 16..17"));
+
+    // Re-annotating without clearing adds nothing for already-marked bases.
+    let mut cpg_only = MethylationMode::default();
+    cpg_only.set_cpg(true);
+    let res = engine
+        .apply(Operation::AnnotateMethylationSites {
+            seq_id: "s".to_string(),
+            mode: cpg_only,
+            clear_existing: Some(false),
+        })
+        .unwrap();
+    assert!(res.messages[0].starts_with("Annotated 0 methylated base(s)"));
+    assert_eq!(methylation_features(&engine).len(), 3);
+
+    let err = engine
+        .apply(Operation::AnnotateMethylationSites {
+            seq_id: "s".to_string(),
+            mode: MethylationMode::default(),
+            clear_existing: None,
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn test_find_cpg_islands_merges_windows_over_cpg_rich_cores() {
+    let at_flank = "ATTATAAT".repeat(75);
+    let cpg_core = "CGCGGCTACG".repeat(25);
+    let mut state = ProjectState::default();
+    state.sequences.insert(
+        "islands".to_string(),
+        seq(&format!(
+            "{at_flank}{cpg_core}{at_flank}{cpg_core}{at_flank}"
+        )),
+    );
+    state
+        .sequences
+        .insert("at_only".to_string(), seq(&"ATTATAAT".repeat(100)));
+    let mut engine = GentleEngine::from_state(state);
+
+    let islands = engine.find_cpg_islands("islands", 200, 0.5, 0.6).unwrap();
+    assert_eq!(islands.len(), 2);
+    let cores = [(601usize, 850usize), (1451, 1700)];
+    for (island, (core_start, core_end)) in islands.iter().zip(cores) {
+        // Qualifying windows reach into the flanks but not across them.
+        assert!(island.start_1based < core_start && island.start_1based > core_start - 200);
+        assert!(island.end_1based > core_end && island.end_1based < core_end + 200);
+        assert_eq!(
+            island.length_bp,
+            island.end_1based - island.start_1based + 1
+        );
+        assert!(island.gc_fraction >= 0.5);
+        assert!(island.cpg_obs_exp >= 0.6);
+        assert!(island.cpg_count >= 75);
+    }
+    assert!(
+        engine
+            .find_cpg_islands("at_only", 200, 0.5, 0.6)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        engine
+            .find_cpg_islands("islands", 200, 1.5, 0.6)
+            .unwrap_err()
+            .code,
+        ErrorCode::InvalidInput
+    );
+
+    engine
+        .apply(Operation::AnnotateCpgIslands {
+            seq_id: "islands".to_string(),
+            window_bp: 200,
+            gc_min: 0.5,
+            obs_exp_min: 0.6,
+            clear_existing: None,
+        })
+        .unwrap();
+    let island_features = engine.state().sequences["islands"]
+        .features()
+        .iter()
+        .filter(|feature| {
+            GentleEngine::feature_qualifier_text(feature, "gentle_generated").as_deref()
+                == Some("cpg_island")
+        })
+        .map(|feature| feature.location.find_bounds().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        island_features,
+        islands
+            .iter()
+            .map(|island| (island.start_1based as i64 - 1, island.end_1based as i64))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_find_tandem_repeats_reports_cag_and_atgc_units() {
+    let mut state = ProjectState::default();
+    let sequence = format!("TT{}TT{}TT", "CAG".repeat(5), "ATGC".repeat(4));
+    state.sequences.insert("str".to_string(), seq(&sequence));
+    let mut engine = GentleEngine::from_state(state);
+
+    let repeats = engine
+        .find_tandem_repeats("str", 1, 6, 3)
+        .expect("scan tandem repeats");
+    assert_eq!(
+        repeats,
+        vec![
+            TandemRepeat {
+                unit: "CAG".to_string(),
+                unit_length_bp: 3,
+                copies: 5,
+                start_1based: 3,
+                end_1based: 17,
+            },
+            TandemRepeat {
+                unit: "ATGC".to_string(),
+                unit_length_bp: 4,
+                copies: 4,
+                start_1based: 20,
+                end_1based: 35,
+            },
+        ]
+    );
+    assert_eq!(engine.find_tandem_repeats("str", 1, 6, 5).unwrap().len(), 1);
+    assert_eq!(
+        engine.find_tandem_repeats("str", 0, 2, 3).unwrap_err().code,
+        ErrorCode::InvalidInput
+    );
+
+    engine
+        .apply(Operation::AnnotateTandemRepeats {
+            seq_id: "str".to_string(),
+            min_unit: 2,
+            max_unit: 4,
+            min_copies: 3,
+            clear_existing: None,
+        })
+        .expect("annotate tandem repeats");
+    let labels = engine.state().sequences["str"]
+        .features()
+        .iter()
+        .filter(|feature| feature.kind.to_string() == "repeat_region")
+        .filter_map(|feature| GentleEngine::feature_qualifier_text(feature, "label"))
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["(CAG)5".to_string(), "(ATGC)4".to_string()]);
+}
+
+#[test]
+fn test_ambiguity_report_and_mask_cover_n_runs_and_lone_iupac_bases() {
+    let mut state = ProjectState::default();
+    state
+        .sequences
+        .insert("gappy".to_string(), seq("ACGTNNNNACGTRACGTnn"));
+    let mut engine = GentleEngine::from_state(state);
+
+    let report = engine.ambiguity_report("gappy").expect("ambiguity report");
+    assert_eq!(report.length_bp, 19);
+    assert_eq!(report.ambiguous_bp, 7);
+    assert_eq!(
+        report.regions,
+        vec![
+            AmbiguousRegion {
+                start_1based: 5,
+                end_1based: 8,
+                length_bp: 4,
+                letters: "N".to_string(),
+            },
+            AmbiguousRegion {
+                start_1based: 13,
+                end_1based: 13,
+                length_bp: 1,
+                letters: "R".to_string(),
+            },
+            AmbiguousRegion {
+                start_1based: 18,
+                end_1based: 19,
+                length_bp: 2,
+                letters: "N".to_string(),
+            },
+        ]
+    );
+
+    for _ in 0..2 {
+        engine
+            .apply(Operation::MaskAmbiguousRegions {
+                seq_id: "gappy".to_string(),
+                clear_existing: None,
+            })
+            .expect("mask ambiguous regions");
+    }
+    let masked = engine.state().sequences["gappy"]
+        .features()
+        .iter()
+        .filter(|feature| feature.kind.to_string() == "misc_feature")
+        .map(|feature| {
+            (
+                GentleEngine::feature_qualifier_text(feature, "label").unwrap_or_default(),
+                feature.location.find_bounds().expect("bounds"),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        masked,
+        vec![
+            ("ambiguous 4 bp".to_string(), (4, 8)),
+            ("ambiguous 1 bp".to_string(), (12, 13)),
+            ("ambiguous 2 bp".to_string(), (17, 19)),
+        ]
+    );
+}
+
 #[test]
 fn test_find_restriction_sites_operation_supports_inline_sequence_targets() {
     let mut engine = GentleEngine::default();
@@ -48,9 +48,9 @@ use crate::{
         DotplotOverlayQuerySpec, DotplotOverlayXAxisMode, EditableStatus, Engine, EvidenceClass,
         ExonSkipReturnKind, ExonSkipSelectionCriterion, FactAtom, FactBasis, FactExpression,
         FactSubject, FactSubjectKind, FactTruth, FeatureBedCoordinateMode, FeatureExpertTarget,
-        FeatureExpertView, FlexibilityModel, GUIDE_DESIGN_METADATA_KEY, GeneSetCohortRelationship,
-        GeneSetProducerFilter, GeneSetPromoterCohortReport, GeneSetRequest,
-        GeneSetResolutionReport, GeneSetResolutionReviewStatus, GenomeAnchorSide,
+        FeatureExpertView, FlexibilityModel, GUIDE_DESIGN_METADATA_KEY, GeneMatchMode,
+        GeneSetCohortRelationship, GeneSetProducerFilter, GeneSetPromoterCohortReport,
+        GeneSetRequest, GeneSetResolutionReport, GeneSetResolutionReviewStatus, GenomeAnchorSide,
         GenomeAnnotationScope, GenomeGeneExtractMode, GenomeTrackSource, GenomeTrackSubscription,
         GentleEngine, GuideCandidate, GuideOligoExportFormat, GuideOligoPlateFormat,
         GuidePracticalFilterConfig, InlineSequenceTopology, LabAssistantInstructionsFormat,
@@ -1573,6 +1573,7 @@ pub enum ShellCommand {
         genome_id: String,
         gene_query: String,
         occurrence: Option<usize>,
+        match_mode: Option<GeneMatchMode>,
         output_id: Option<String>,
         extract_mode: Option<GenomeGeneExtractMode>,
         promoter_upstream_bp: Option<usize>,
@@ -3118,8 +3119,9 @@ fn parse_candidate_tie_break_policy(raw: &str) -> Result<CandidateTieBreakPolicy
         "sequence_lexicographic" | "sequence" | "lexicographic" => {
             Ok(CandidateTieBreakPolicy::SequenceLexicographic)
         }
+        "seeded" | "random" => Ok(CandidateTieBreakPolicy::Seeded),
         other => Err(format!(
-            "Unsupported tie-break policy '{other}' (expected seq_start_end|seq_end_start|length_ascending|length_descending|sequence_lexicographic)"
+            "Unsupported tie-break policy '{other}' (expected seq_start_end|seq_end_start|length_ascending|length_descending|sequence_lexicographic|seeded)"
         )),
     }
 }
@@ -8660,6 +8662,7 @@ impl ShellCommand {
                 genome_id,
                 gene_query,
                 occurrence,
+                match_mode,
                 output_id,
                 extract_mode,
                 promoter_upstream_bp,
@@ -8680,6 +8683,9 @@ impl ShellCommand {
                 let occ = occurrence
                     .map(|v| v.to_string())
                     .unwrap_or_else(|| "-".to_string());
+                let match_mode = match_mode
+                    .map(|value| value.as_str().to_string())
+                    .unwrap_or_else(|| "exact_then_contains(default)".to_string());
                 let output = output_id.clone().unwrap_or_else(|| "-".to_string());
                 let extract_mode = extract_mode
                     .map(|value| value.as_str().to_string())
@@ -8718,7 +8724,7 @@ impl ShellCommand {
                     })
                     .unwrap_or_else(|| "-".to_string());
                 format!(
-                    "extract {label} gene '{gene_query}' from '{genome_id}' (occurrence={occ}, output='{output}', extract_mode={extract_mode}, promoter_upstream_bp={promoter_upstream_bp}, annotation_scope={scope}, max_annotation_features={max_features}, include_genomic_annotation={include_annotation}, rmsk_index={repeat_materialization}, catalog='{catalog}', cache='{cache}')"
+                    "extract {label} gene '{gene_query}' from '{genome_id}' (occurrence={occ}, match_mode={match_mode}, output='{output}', extract_mode={extract_mode}, promoter_upstream_bp={promoter_upstream_bp}, annotation_scope={scope}, max_annotation_features={max_features}, include_genomic_annotation={include_annotation}, rmsk_index={repeat_materialization}, catalog='{catalog}', cache='{cache}')"
                 )
             }
             Self::ReferenceExtractPromoter {
@@ -8970,6 +8976,7 @@ impl ShellCommand {
                 let side_label = match side {
                     GenomeAnchorSide::FivePrime => "5'",
                     GenomeAnchorSide::ThreePrime => "3'",
+                    GenomeAnchorSide::Both => "5' and 3'",
                 };
                 format!(
                     "extend {label}-anchored sequence '{seq_id}' on {side_label} by {length_bp} bp (output='{output}', catalog='{catalog}', cache='{cache}', prepared_genome_id='{prepared}')"
@@ -12162,6 +12169,19 @@ impl ShellCommand {
                 "primer3_executable" | "primer3_backend_executable" | "primer3_path" => {
                     format!("set primer3 executable path to {}", value_json)
                 }
+                "random_seed" | "sampling_seed" => {
+                    format!("set project sampling seed to {}", value_json)
+                }
+                "genome_download_max_attempts" | "download_max_attempts" => {
+                    format!("set genome source download attempts to {}", value_json)
+                }
+                "genome_download_retry_base_delay_ms" | "download_retry_base_delay_ms" => {
+                    format!("set genome download retry base delay to {} ms", value_json)
+                }
+                "candidate_scoring_threads" | "scoring_threads" => format!(
+                    "set candidate metric scoring thread cap to {} (0 = auto, 1 = serial)",
+                    value_json
+                ),
                 "linear_sequence_letter_layout_mode" | "linear_helical_letter_layout_mode" => {
                     format!(
                         "set adaptive linear DNA letter mode '{}' (auto|standard|helical|condensed_10_row)",
@@ -12409,7 +12429,7 @@ fn maybe_materialize_repeats_for_first_created_sequence(
         op_result.warnings.push(format!(
             "Requested UCSC rmsk repeat materialization from '{}', but the extraction did not create a sequence.",
             rmsk_index_path
-        ));
+        ).into());
         return Ok(None);
     };
     let repeat_result = engine
@@ -13961,8 +13981,9 @@ fn parse_anchor_side(value: &str) -> Result<GenomeAnchorSide, String> {
         "3" | "3p" | "3prime" | "3'" | "three_prime" | "three-prime" => {
             Ok(GenomeAnchorSide::ThreePrime)
         }
+        "both" | "5p3p" => Ok(GenomeAnchorSide::Both),
         _ => Err(format!(
-            "Unknown anchor side '{}'; expected 5p or 3p",
+            "Unknown anchor side '{}'; expected 5p, 3p, or both",
             value
         )),
     }
@@ -22391,7 +22412,7 @@ fn annotated_introspection_capability_descriptors() -> Vec<Value> {
         ),
         catalog_read_operation_descriptor(
             "inspect_dna_ladders",
-            "Inspect built-in DNA ladder definitions without project-state preconditions.",
+            "Inspect built-in and project-defined DNA ladder definitions.",
         ),
         catalog_read_operation_descriptor(
             "inspect_rna_ladders",
@@ -23851,6 +23872,7 @@ fn annotated_introspection_capability_descriptors() -> Vec<Value> {
             vec![
                 json!({"name": "GENE_QUERY", "required": true, "subject_kind": "other", "detail": "gene symbol/name/id query"}),
                 json!({"name": "--occurrence", "required": false, "subject_kind": "other", "detail": "1-based matching gene occurrence"}),
+                json!({"name": "--match-mode", "required": false, "subject_kind": "other", "detail": "exact|contains|regex (default: exact, then contains)"}),
                 json!({"name": "--catalog", "required": false, "subject_kind": "other", "detail": "optional reference-genome catalog path"}),
                 json!({"name": "--cache-dir", "required": false, "subject_kind": "other", "detail": "optional reference-genome cache root"}),
             ],
@@ -23863,6 +23885,7 @@ fn annotated_introspection_capability_descriptors() -> Vec<Value> {
             vec![
                 json!({"name": "GENE_QUERY", "required": true, "subject_kind": "other", "detail": "feature/gene name/id query"}),
                 json!({"name": "--occurrence", "required": false, "subject_kind": "other", "detail": "1-based matching feature occurrence"}),
+                json!({"name": "--match-mode", "required": false, "subject_kind": "other", "detail": "exact|contains|regex (default: exact, then contains)"}),
                 json!({"name": "--catalog", "required": false, "subject_kind": "other", "detail": "optional helper-genome catalog path"}),
                 json!({"name": "--cache-dir", "required": false, "subject_kind": "other", "detail": "optional helper-genome cache root"}),
             ],
@@ -30082,12 +30105,13 @@ fn parse_reference_command(tokens: &[String], helper_mode: bool) -> Result<Shell
         "extract-gene" => {
             if tokens.len() < 4 {
                 return Err(format!(
-                    "{label} extract-gene requires GENOME_ID QUERY [--occurrence N] [--output-id ID] [--extract-mode gene|coding_with_promoter] [--promoter-upstream-bp N] [--annotation-scope none|core|full] [--max-annotation-features N] [--include-genomic-annotation|--no-include-genomic-annotation] [--rmsk-index PATH] [--max-repeat-features N] [--append-repeat-features] [--catalog PATH] [--cache-dir PATH]"
+                    "{label} extract-gene requires GENOME_ID QUERY [--occurrence N] [--match-mode exact|contains|regex] [--output-id ID] [--extract-mode gene|coding_with_promoter] [--promoter-upstream-bp N] [--annotation-scope none|core|full] [--max-annotation-features N] [--include-genomic-annotation|--no-include-genomic-annotation] [--rmsk-index PATH] [--max-repeat-features N] [--append-repeat-features] [--catalog PATH] [--cache-dir PATH]"
                 ));
             }
             let genome_id = tokens[2].clone();
             let gene_query = tokens[3].clone();
             let mut occurrence: Option<usize> = None;
+            let mut match_mode: Option<GeneMatchMode> = None;
             let mut output_id: Option<String> = None;
             let mut extract_mode: Option<GenomeGeneExtractMode> = None;
             let mut promoter_upstream_bp: Option<usize> = None;
@@ -30112,6 +30136,20 @@ fn parse_reference_command(tokens: &[String], helper_mode: bool) -> Result<Shell
                         }
                         occurrence = Some(value);
                     }
+                    "--match-mode" => {
+                        let raw = parse_option_path(tokens, &mut idx, "--match-mode", label)?;
+                        let mode = match raw.trim().to_ascii_lowercase().as_str() {
+                            "exact" => GeneMatchMode::Exact,
+                            "contains" => GeneMatchMode::Contains,
+                            "regex" => GeneMatchMode::Regex,
+                            other => {
+                                return Err(format!(
+                                    "Invalid --match-mode value '{other}' for {label} extract-gene (expected exact|contains|regex)"
+                                ));
+                            }
+                        };
+                        match_mode = Some(mode);
+                    }
                     "--output-id" => {
                         output_id = Some(parse_option_path(tokens, &mut idx, "--output-id", label)?)
                     }
@@ -30241,6 +30279,7 @@ fn parse_reference_command(tokens: &[String], helper_mode: bool) -> Result<Shell
                 genome_id,
                 gene_query,
                 occurrence,
+                match_mode,
                 output_id,
                 extract_mode,
                 promoter_upstream_bp,
@@ -30781,7 +30820,7 @@ fn parse_reference_command(tokens: &[String], helper_mode: bool) -> Result<Shell
         "extend-anchor" => {
             if tokens.len() < 5 {
                 return Err(format!(
-                    "{label} extend-anchor requires SEQ_ID 5p|3p LENGTH_BP [--output-id ID] [--catalog PATH] [--cache-dir PATH] [--prepared-genome GENOME_ID]"
+                    "{label} extend-anchor requires SEQ_ID 5p|3p|both LENGTH_BP [--output-id ID] [--catalog PATH] [--cache-dir PATH] [--prepared-genome GENOME_ID]"
                 ));
             }
             let seq_id = tokens[2].clone();
@@ -40051,6 +40090,7 @@ pub fn parse_shell_tokens(tokens: &[String]) -> Result<ShellCommand, String> {
                                 min_score,
                                 max_score,
                                 clear_existing,
+                                enabled: true,
                             };
                             Ok(ShellCommand::TracksTrackedAdd { subscription })
                         }
@@ -42976,6 +43016,7 @@ fn execute_candidates_analysis_command(
                     metric: metric.clone(),
                     objectives: objectives.clone(),
                     normalize_metrics: Some(*normalize_metrics),
+                    missing_policy: None,
                 })
                 .map_err(|e| e.to_string())?;
             let after = engine
@@ -43054,6 +43095,7 @@ fn execute_candidates_analysis_command(
                     objectives: objectives.clone(),
                     max_candidates: *max_candidates,
                     tie_break: Some(*tie_break),
+                    epsilon: None,
                 })
                 .map_err(|e| e.to_string())?;
             let after = engine
@@ -43097,6 +43139,7 @@ fn execute_arrangement_command(
                         arrangement_id: arrangement_id.clone(),
                         name: name.clone(),
                         ladders: ladders.clone(),
+                        replicate_count: None,
                     })
                     .map_err(|e| e.to_string())?
             }),
@@ -43185,7 +43228,7 @@ fn execute_pool_gel_and_ladder_command(
             state_changed: false,
             output: match molecule {
                 LadderMolecule::Dna => {
-                    serde_json::to_value(GentleEngine::inspect_dna_ladders(name_filter.as_deref()))
+                    serde_json::to_value(engine.inspect_dna_ladders(name_filter.as_deref()))
                         .map_err(|e| format!("Could not serialize DNA ladders catalog: {e}"))?
                 }
                 LadderMolecule::Rna => {
@@ -46677,6 +46720,9 @@ fn execute_reference_and_track_command(
                     hits: hit_inputs,
                     track_name: track_name.clone(),
                     clear_existing: Some(*clear_existing),
+                    min_identity_percent: None,
+                    max_evalue: None,
+                    min_bit_score: None,
                     blast_provenance: Some(crate::engine::BlastInvocationProvenance {
                         genome_id: report.genome_id.clone(),
                         query_label: target_seq_id.clone(),
@@ -46757,6 +46803,7 @@ fn execute_reference_and_track_command(
             genome_id,
             gene_query,
             occurrence,
+            match_mode,
             output_id,
             extract_mode,
             promoter_upstream_bp,
@@ -46774,6 +46821,7 @@ fn execute_reference_and_track_command(
                     genome_id: genome_id.clone(),
                     gene_query: gene_query.clone(),
                     occurrence: *occurrence,
+                    match_mode: *match_mode,
                     output_id: output_id.clone(),
                     extract_mode: *extract_mode,
                     promoter_upstream_bp: *promoter_upstream_bp,
@@ -48457,6 +48505,7 @@ fn execute_guides_command(
                     oligo_set_id: oligo_set_id.clone(),
                     path: path.clone(),
                     include_qc_checklist: Some(*include_qc_checklist),
+                    reagents: None,
                 })
                 .map_err(|e| e.to_string())?;
             let after = engine
@@ -55000,6 +55049,8 @@ fn execute_shell_command_with_options_inner(
                 .apply(Operation::LoadFile {
                     path: path.clone(),
                     as_id: as_id.clone(),
+                    skip_duplicates: None,
+                    uppercase: None,
                 })
                 .map_err(|e| e.to_string())?;
             ShellRunResult {
@@ -55042,6 +55093,7 @@ fn execute_shell_command_with_options_inner(
                     seq_id: seq_id.clone(),
                     mode: mode.clone(),
                     path: output.clone(),
+                    viewport: None,
                 })
                 .map_err(|e| e.to_string())?;
             ShellRunResult {
@@ -55234,6 +55286,7 @@ fn execute_shell_command_with_options_inner(
                     arrangement_id: arrangement_id.clone(),
                     name: name.clone(),
                     ladders: ladders.clone(),
+                    replicate_count: None,
                 })
                 .map_err(|e| e.to_string())?;
             ShellRunResult {
@@ -56212,6 +56265,7 @@ fn execute_shell_command_with_options_inner(
                     metric: metric.clone(),
                     objectives: objectives.clone(),
                     normalize_metrics: Some(*normalize_metrics),
+                    missing_policy: None,
                 })
                 .map_err(|e| e.to_string())?;
             let after = engine
@@ -56290,6 +56344,7 @@ fn execute_shell_command_with_options_inner(
                     objectives: objectives.clone(),
                     max_candidates: *max_candidates,
                     tie_break: Some(*tie_break),
+                    epsilon: None,
                 })
                 .map_err(|e| e.to_string())?;
             let after = engine
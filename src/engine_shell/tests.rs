@@ -2572,6 +2572,7 @@ fn execute_introspect_readiness_covers_protease_and_protein_gel_operations() {
             scope: None,
             output_prefix: Some("cds_protein".to_string()),
             report_id: Some("cds_protein_report".to_string()),
+            translation_table: None,
         })
         .expect("derive protein report");
     assert_eq!(
@@ -13497,6 +13498,7 @@ fn execute_guides_commands_end_to_end() {
             nuclease: "SpCas9".to_string(),
             cut_offset_from_protospacer_start: 17,
             rank: Some(1),
+            off_target_score: None,
         },
         GuideCandidate {
             guide_id: "g2".to_string(),
@@ -13509,6 +13511,7 @@ fn execute_guides_commands_end_to_end() {
             nuclease: "SpCas9".to_string(),
             cut_offset_from_protospacer_start: 17,
             rank: Some(2),
+            off_target_score: None,
         },
     ])
     .expect("serialize guides");
@@ -16994,6 +16997,8 @@ fn execute_primers_seeded_tp73_as2_cdna_qpcr_uses_probe_based_defaults() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
     let tp73_as2_feature_id = engine
@@ -17063,6 +17068,8 @@ fn execute_primers_seed_qpcr_from_splicing_round_trips_into_design_qpcr() {
         .apply(Operation::LoadFile {
             path: "test_files/tp73.ncbi.gb".to_string(),
             as_id: Some("tp73".to_string()),
+            skip_duplicates: None,
+            uppercase: None,
         })
         .expect("load tp73 fixture");
     let tp73_as3_feature_id = engine
@@ -18443,6 +18450,7 @@ fn execute_tracks_tracked_add_and_list() {
         min_score: Some(1.0),
         max_score: Some(10.0),
         clear_existing: true,
+        enabled: true,
     };
     let add = execute_shell_command(
         &mut engine,
@@ -20886,6 +20894,7 @@ fn execute_introspect_readiness_and_effects_cover_guide_sets() {
             nuclease: "SpCas9".to_string(),
             cut_offset_from_protospacer_start: 17,
             rank: Some(1),
+            off_target_score: None,
         },
         GuideCandidate {
             guide_id: "g2".to_string(),
@@ -20898,6 +20907,7 @@ fn execute_introspect_readiness_and_effects_cover_guide_sets() {
             nuclease: "SpCas9".to_string(),
             cut_offset_from_protospacer_start: 17,
             rank: Some(2),
+            off_target_score: None,
         },
     ])
     .expect("serialize guides");
@@ -29816,6 +29826,26 @@ fn parse_helpers_extract_gene() {
     }
 }
 
+#[test]
+fn parse_genomes_extract_gene_with_match_mode() {
+    let cmd = parse_shell_line("genomes extract-gene ToyGenome '^TP53$' --match-mode regex")
+        .expect("parse genomes extract-gene with match mode");
+    match cmd {
+        ShellCommand::ReferenceExtractGene {
+            gene_query,
+            match_mode,
+            ..
+        } => {
+            assert_eq!(gene_query, "^TP53$".to_string());
+            assert_eq!(match_mode, Some(GeneMatchMode::Regex));
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+    let err = parse_shell_line("genomes extract-gene ToyGenome TP53 --match-mode glob")
+        .expect_err("unknown match mode");
+    assert!(err.contains("expected exact|contains|regex"));
+}
+
 #[test]
 fn parse_genomes_extract_gene_with_scope_and_cap() {
     let cmd = parse_shell_line(
@@ -2,7 +2,7 @@
 
 use crate::{
     protease::{Protease, normalize_protease_name_token},
-    restriction_enzyme::RestrictionEnzyme,
+    restriction_enzyme::{RestrictionEndKind, RestrictionEnzyme},
 };
 use anyhow::{Result, anyhow};
 use std::fs;
@@ -16,6 +16,30 @@ const GOLDEN_GATE_TYPE_IIS_RESTRICTION_ENZYME_NAMES: &[&str] = &[
     "BsaI", "Eco31I", "Eco31", "BsmBI", "Esp3I", "BbsI", "AarI", "SapI", "BtgZI", "BsmAI", "BfuAI",
 ];
 
+/// Property filter for `Enzymes::filter`; unset fields match every enzyme.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnzymeFilter {
+    /// Blunt, 5' overhang, or 3' overhang ends.
+    pub end_kind: Option<RestrictionEndKind>,
+    /// Recognition-sequence length in bp, counting `N` positions.
+    pub recognition_len: Option<usize>,
+    /// Whether the recognition sequence equals its reverse complement.
+    pub palindromic: Option<bool>,
+}
+
+impl EnzymeFilter {
+    pub fn matches(&self, enzyme: &RestrictionEnzyme) -> bool {
+        self.end_kind
+            .is_none_or(|kind| enzyme.end_geometry().kind() == kind)
+            && self
+                .recognition_len
+                .is_none_or(|len| enzyme.sequence.len() == len)
+            && self
+                .palindromic
+                .is_none_or(|palindromic| enzyme.is_palindromic() == palindromic)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub struct Enzymes {
@@ -92,6 +116,14 @@ impl Enzymes {
             .collect()
     }
 
+    /// Restriction enzymes matching `pred`, in catalog order.
+    pub fn filter(&self, pred: EnzymeFilter) -> Vec<&RestrictionEnzyme> {
+        self.restriction_enzymes
+            .iter()
+            .filter(|re| pred.matches(re))
+            .collect()
+    }
+
     fn recompute_derived_fields(&mut self) {
         self.max_re_length = self
             .restriction_enzymes
@@ -221,6 +253,57 @@ mod tests {
         assert!(enzymes.proteases.iter().any(|e| e.name == "Clostripain"));
     }
 
+    #[test]
+    fn filter_selects_blunt_cutters() {
+        let enzymes = Enzymes::default();
+        let blunt = enzymes.filter(EnzymeFilter {
+            end_kind: Some(RestrictionEndKind::Blunt),
+            ..Default::default()
+        });
+        let names = blunt.iter().map(|re| re.name.as_str()).collect::<Vec<_>>();
+        assert!(names.contains(&"SmaI"));
+        assert!(names.contains(&"EcoRV"));
+        assert!(names.contains(&"AluI"));
+        assert!(!names.contains(&"EcoRI"));
+        assert!(!names.contains(&"XmaI"));
+        assert!(blunt.iter().all(|re| re.overlap == 0));
+    }
+
+    #[test]
+    fn filter_selects_six_cutters_and_palindromes() {
+        let enzymes = Enzymes::default();
+        let six = enzymes.filter(EnzymeFilter {
+            recognition_len: Some(6),
+            ..Default::default()
+        });
+        let names = six.iter().map(|re| re.name.as_str()).collect::<Vec<_>>();
+        assert!(names.contains(&"EcoRI"));
+        assert!(names.contains(&"BspMI"));
+        assert!(!names.contains(&"AluI"));
+        assert!(!names.contains(&"EcoNI"));
+        assert!(six.iter().all(|re| re.sequence.len() == 6));
+
+        let blunt_six_palindromes = enzymes.filter(EnzymeFilter {
+            end_kind: Some(RestrictionEndKind::Blunt),
+            recognition_len: Some(6),
+            palindromic: Some(true),
+        });
+        let names = blunt_six_palindromes
+            .iter()
+            .map(|re| re.name.as_str())
+            .collect::<Vec<_>>();
+        assert!(names.contains(&"SmaI"));
+        assert!(names.contains(&"HpaI"));
+        assert!(!names.contains(&"AluI"));
+        // ACCTGC is not its own reverse complement (GCAGGT).
+        let non_palindromic = enzymes.filter(EnzymeFilter {
+            palindromic: Some(false),
+            ..Default::default()
+        });
+        assert!(non_palindromic.iter().any(|re| re.name == "BspMI"));
+        assert!(non_palindromic.iter().all(|re| re.name != "EcoRI"));
+    }
+
     #[test]
     fn golden_gate_type_iis_preset_prefers_common_catalog_names() {
         let mk = |name: &str, sequence: &str, cut: isize, overlap: isize| {
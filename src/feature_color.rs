@@ -0,0 +1,163 @@
+//! Deterministic feature color assignment shared by map renderers.
+//!
+//! Features without a semantic color (CDS, gene, promoter, repeat class, ...)
+//! get a palette color from a stable hash of their kind and label, so the GUI
+//! maps, the SVG exports and `ExportMapLegend` agree and a feature keeps its
+//! color across renders and sessions. `DisplaySettings::feature_color_overrides`
+//! can pin colors explicitly.
+
+use eframe::egui::Color32;
+use gb_io::seq::Feature;
+use std::collections::BTreeMap;
+
+/// Categorical palette for hashed feature colors.
+pub const FEATURE_COLOR_PALETTE: [Color32; 12] = [
+    Color32::from_rgb(31, 119, 180),
+    Color32::from_rgb(255, 127, 14),
+    Color32::from_rgb(44, 160, 44),
+    Color32::from_rgb(214, 39, 40),
+    Color32::from_rgb(148, 103, 189),
+    Color32::from_rgb(140, 86, 75),
+    Color32::from_rgb(227, 119, 194),
+    Color32::from_rgb(188, 189, 34),
+    Color32::from_rgb(23, 190, 207),
+    Color32::from_rgb(57, 59, 121),
+    Color32::from_rgb(99, 121, 57),
+    Color32::from_rgb(165, 81, 148),
+];
+
+/// Palette color for a feature `kind` and `label`.
+///
+/// Kind matching is case-insensitive and the label is trimmed; the hash is
+/// FNV-1a so the result does not depend on process or toolchain.
+pub fn assign_color(kind: &str, label: &str) -> Color32 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let key = kind
+        .trim()
+        .bytes()
+        .map(|byte| byte.to_ascii_uppercase())
+        .chain(std::iter::once(0x1f))
+        .chain(label.trim().bytes());
+    for byte in key {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    FEATURE_COLOR_PALETTE[(hash % FEATURE_COLOR_PALETTE.len() as u64) as usize]
+}
+
+/// Label used as the color key: the first non-empty naming qualifier, so all
+/// renderers hash the same text regardless of how they caption the feature.
+pub fn feature_color_label(feature: &Feature) -> String {
+    [
+        "label",
+        "name",
+        "standard_name",
+        "gene",
+        "gene_name",
+        "locus_tag",
+        "product",
+        "note",
+    ]
+    .iter()
+    .find_map(|key| {
+        feature
+            .qualifier_values(key)
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+            .map(str::to_string)
+    })
+    .unwrap_or_default()
+}
+
+/// Hashed palette color for `feature`.
+pub fn assign_feature_color(feature: &Feature) -> Color32 {
+    assign_color(&feature.kind.to_string(), &feature_color_label(feature))
+}
+
+/// User override for `feature`, if any.
+///
+/// Keys are `kind:label` (kind case-insensitive) or a bare `label`; the
+/// `kind:label` form wins. Values are `#rrggbb` hex colors.
+pub fn feature_color_override(
+    overrides: &BTreeMap<String, String>,
+    feature: &Feature,
+) -> Option<Color32> {
+    if overrides.is_empty() {
+        return None;
+    }
+    let kind = feature.kind.to_string();
+    let label = feature_color_label(feature);
+    let scoped = overrides.iter().find_map(|(key, value)| {
+        let (key_kind, key_label) = key.split_once(':')?;
+        (key_kind.trim().eq_ignore_ascii_case(kind.trim()) && key_label.trim() == label)
+            .then_some(value)
+    });
+    scoped
+        .or_else(|| overrides.get(&label))
+        .and_then(|value| parse_hex_color(value))
+}
+
+/// Parse `#rrggbb` (leading `#` optional).
+pub fn parse_hex_color(value: &str) -> Option<Color32> {
+    let hex = value.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |idx: usize| u8::from_str_radix(&hex[idx..idx + 2], 16).ok();
+    Some(Color32::from_rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// `#rrggbb` form of `color`, as used in SVG exports.
+pub fn color_hex(color: Color32) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gb_io::seq::Location;
+
+    fn feature(kind: &str, label: &str) -> Feature {
+        Feature {
+            kind: kind.to_string().into(),
+            location: Location::simple_range(0, 10),
+            qualifiers: vec![("label".into(), Some(label.to_string()))],
+        }
+    }
+
+    #[test]
+    fn assign_color_is_stable_and_keyed_by_kind_and_label() {
+        assert_eq!(
+            assign_color("rep_origin", "ori"),
+            assign_color("REP_ORIGIN", " ori ")
+        );
+        assert_eq!(
+            assign_feature_color(&feature("rep_origin", "ori")),
+            assign_color("rep_origin", "ori")
+        );
+        let distinct = ["ori", "f1 ori", "AmpR", "lacZ alpha", "M13 fwd", "T7"]
+            .iter()
+            .map(|label| assign_color("misc_feature", label))
+            .collect::<std::collections::HashSet<_>>();
+        assert!(distinct.len() > 1);
+    }
+
+    #[test]
+    fn scoped_override_beats_label_override_and_hex_round_trips() {
+        let ori = feature("rep_origin", "ori");
+        let mut overrides = BTreeMap::new();
+        overrides.insert("ori".to_string(), "#00ff00".to_string());
+        assert_eq!(
+            feature_color_override(&overrides, &ori),
+            Some(Color32::from_rgb(0, 255, 0))
+        );
+        overrides.insert("REP_ORIGIN:ori".to_string(), "123456".to_string());
+        let color = feature_color_override(&overrides, &ori).expect("override");
+        assert_eq!(color_hex(color), "#123456");
+        assert_eq!(
+            feature_color_override(&overrides, &feature("rep_origin", "f1")),
+            None
+        );
+        assert_eq!(parse_hex_color("#12345"), None);
+    }
+}
@@ -52,7 +52,7 @@ use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Output, Stdio};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tempfile::NamedTempFile;
 
 pub const DEFAULT_GENOME_CATALOG_PATH: &str = "assets/genomes.json";
@@ -79,6 +79,9 @@ const DEFAULT_NCBI_EFETCH_ENDPOINT: &str =
 const NCBI_EFETCH_ENV_VAR: &str = "GENTLE_NCBI_EFETCH_URL";
 const HTTP_RETRY_ATTEMPTS: usize = 4;
 const HTTP_RETRY_BASE_BACKOFF_MS: u64 = 1000;
+pub const DEFAULT_DOWNLOAD_RETRY_ATTEMPTS: usize = 3;
+pub const DEFAULT_DOWNLOAD_RETRY_BASE_DELAY_MS: u64 = 2000;
+const DOWNLOAD_RETRY_WAIT_TICK_MS: u64 = 250;
 const HTTP_CONNECT_TIMEOUT_SECS: u64 = 20;
 const HTTP_READ_TIMEOUT_SECS: u64 = 120;
 const BLASTN_OUTFMT_FIELDS: &str =
@@ -571,6 +574,28 @@ pub struct HelperVectorCatalogDoctorIssue {
     pub field: Option<String>,
 }
 
+/// Offline source/preparation validation report for one genome catalog.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GenomeCatalogReport {
+    pub schema: String,
+    pub catalog_path: String,
+    pub genome_count: usize,
+    pub issue_count: usize,
+    #[serde(default)]
+    pub genomes: Vec<GenomeCatalogValidationEntry>,
+}
+
+/// Per-genome source resolution and prepared status in a catalog report.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GenomeCatalogValidationEntry {
+    pub genome_id: String,
+    pub prepared: bool,
+    pub sequence_source: Option<String>,
+    pub annotation_source: Option<String>,
+    #[serde(default)]
+    pub issues: Vec<String>,
+}
+
 /// Compact helper/vector card report for GUI/CLI/agent inspection.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct HelperVectorCardReport {
@@ -2721,6 +2746,34 @@ pub struct PrepareGenomeProgress {
     pub step_label: Option<String>,
 }
 
+/// Retry policy for the source-download step of genome preparation.
+///
+/// A failed download of an HTTP(S) source is retried up to `max_attempts`
+/// times in total, waiting `base_delay_ms * 2^(n-1)` before retry `n`.
+/// Local sources and caller cancellations are never retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadRetryPolicy {
+    pub max_attempts: usize,
+    pub base_delay_ms: u64,
+}
+
+impl Default for DownloadRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_DOWNLOAD_RETRY_ATTEMPTS,
+            base_delay_ms: DEFAULT_DOWNLOAD_RETRY_BASE_DELAY_MS,
+        }
+    }
+}
+
+impl DownloadRetryPolicy {
+    /// Backoff to wait after failed attempt `failed_attempt` (1-based).
+    pub fn backoff_after_attempt(&self, failed_attempt: usize) -> Duration {
+        let exponent = failed_attempt.saturating_sub(1).min(16) as u32;
+        Duration::from_millis(self.base_delay_ms.saturating_mul(1u64 << exponent))
+    }
+}
+
 /// Best-effort persisted status for one active or recently failed prepare run.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PrepareGenomeActivityStatus {
@@ -3172,6 +3225,93 @@ impl GenomeCatalog {
         }
     }
 
+    /// Validate that every entry's declared sources resolve, without downloading.
+    ///
+    /// Remote sources are only checked for URL well-formedness; local and
+    /// `file://` sources must exist on disk. Issues are collected per genome
+    /// instead of failing on the first broken entry.
+    pub fn validate_sources(
+        &self,
+        catalog_path: impl Into<String>,
+        cache_dir_override: Option<&str>,
+    ) -> GenomeCatalogReport {
+        let mut genomes = vec![];
+        for genome_id in self.list_genomes_unfiltered() {
+            let Some(entry) = self.entries.get(&genome_id) else {
+                continue;
+            };
+            let mut issues = vec![];
+            let mut resolved = [None, None];
+            for (slot, (kind, local, remote)) in [
+                (
+                    "sequence",
+                    entry.sequence_local.as_ref(),
+                    entry.sequence_remote.as_ref(),
+                ),
+                (
+                    "annotation",
+                    entry.annotations_local.as_ref(),
+                    entry.annotations_remote.as_ref(),
+                ),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                match self.resolve_source_with_type(&genome_id, kind, local, remote, entry) {
+                    Ok(resolution) => {
+                        if let Some(issue) = self.validate_resolved_source(entry, kind, &resolution)
+                        {
+                            issues.push(issue);
+                        }
+                        resolved[slot] = Some(resolution.source);
+                    }
+                    Err(e) => issues.push(e),
+                }
+            }
+            let prepared = self
+                .is_prepared(&genome_id, cache_dir_override)
+                .unwrap_or(false);
+            let [sequence_source, annotation_source] = resolved;
+            genomes.push(GenomeCatalogValidationEntry {
+                genome_id,
+                prepared,
+                sequence_source,
+                annotation_source,
+                issues,
+            });
+        }
+        GenomeCatalogReport {
+            schema: "gentle.genome_catalog_validation.v1".to_string(),
+            catalog_path: catalog_path.into(),
+            genome_count: genomes.len(),
+            issue_count: genomes.iter().map(|genome| genome.issues.len()).sum(),
+            genomes,
+        }
+    }
+
+    fn validate_resolved_source(
+        &self,
+        entry: &GenomeCatalogEntry,
+        kind: &str,
+        resolution: &SourceResolution,
+    ) -> Option<String> {
+        let source = resolution.source.trim();
+        if is_http_source(source) {
+            return match reqwest::Url::parse(source) {
+                Ok(url) if url.host_str().is_some_and(|host| !host.is_empty()) => None,
+                Ok(_) => Some(format!("{kind} URL '{source}' has no host")),
+                Err(e) => Some(format!("{kind} URL '{source}' is malformed: {e}")),
+            };
+        }
+        if source.contains("://") && !source.starts_with("file://") {
+            return Some(format!(
+                "{kind} source '{source}' uses an unsupported URL scheme"
+            ));
+        }
+        let path = self.resolve_local_path(entry, source);
+        (!path.exists()).then(|| format!("{kind} source path '{}' does not exist", path.display()))
+    }
+
     /// Return compact helper/vector cards as a pure structured-field projection.
     pub fn helper_vector_cards(
         &self,
@@ -4627,6 +4767,25 @@ impl GenomeCatalog {
             genome_id,
             cache_dir_override,
             PrepareGenomeMode::PrepareOrReuse,
+            &DownloadRetryPolicy::default(),
+            on_progress,
+        )
+    }
+
+    /// Same as [`Self::prepare_genome_once_with_progress`], with an explicit
+    /// retry/backoff policy for source downloads.
+    pub fn prepare_genome_once_with_retry_and_progress(
+        &self,
+        genome_id: &str,
+        cache_dir_override: Option<&str>,
+        download_retry: &DownloadRetryPolicy,
+        on_progress: &mut dyn FnMut(PrepareGenomeProgress) -> bool,
+    ) -> Result<PrepareGenomeReport, String> {
+        self.prepare_genome_once_with_progress_options(
+            genome_id,
+            cache_dir_override,
+            PrepareGenomeMode::PrepareOrReuse,
+            download_retry,
             on_progress,
         )
     }
@@ -4643,6 +4802,7 @@ impl GenomeCatalog {
             genome_id,
             cache_dir_override,
             PrepareGenomeMode::ReindexCachedFiles,
+            &DownloadRetryPolicy::default(),
             on_progress,
         )
     }
@@ -4661,6 +4821,25 @@ impl GenomeCatalog {
             genome_id,
             cache_dir_override,
             PrepareGenomeMode::RefreshFromSources,
+            &DownloadRetryPolicy::default(),
+            on_progress,
+        )
+    }
+
+    /// Same as [`Self::reinstall_genome_once_with_progress`], with an explicit
+    /// retry/backoff policy for source downloads.
+    pub fn reinstall_genome_once_with_retry_and_progress(
+        &self,
+        genome_id: &str,
+        cache_dir_override: Option<&str>,
+        download_retry: &DownloadRetryPolicy,
+        on_progress: &mut dyn FnMut(PrepareGenomeProgress) -> bool,
+    ) -> Result<PrepareGenomeReport, String> {
+        self.prepare_genome_once_with_progress_options(
+            genome_id,
+            cache_dir_override,
+            PrepareGenomeMode::RefreshFromSources,
+            download_retry,
             on_progress,
         )
     }
@@ -4904,6 +5083,7 @@ impl GenomeCatalog {
         genome_id: &str,
         cache_dir_override: Option<&str>,
         mode: PrepareGenomeMode,
+        download_retry: &DownloadRetryPolicy,
         on_progress: &mut dyn FnMut(PrepareGenomeProgress) -> bool,
     ) -> Result<PrepareGenomeReport, String> {
         let entry = self.entry(genome_id)?;
@@ -5399,6 +5579,7 @@ impl GenomeCatalog {
                 materialize_source_with_progress(
                     &sequence_source,
                     &sequence_path,
+                    download_retry,
                     |done, total| {
                         on_progress(prepare_genome_progress(
                             genome_id,
@@ -5459,6 +5640,7 @@ impl GenomeCatalog {
                 materialize_source_with_progress(
                     &annotation_source,
                     &annotation_path,
+                    download_retry,
                     |done, total| {
                         on_progress(prepare_genome_progress(
                             genome_id,
@@ -5703,6 +5885,64 @@ impl GenomeCatalog {
         end_1based: usize,
         cache_dir_override: Option<&str>,
     ) -> Result<String, String> {
+        let (sequence_path, _, index_entry) = self.locate_sequence_region(
+            genome_id,
+            chromosome,
+            start_1based,
+            end_1based,
+            cache_dir_override,
+        )?;
+        read_fasta_slice(
+            &sequence_path,
+            &index_entry,
+            start_1based as u64,
+            end_1based as u64,
+        )
+    }
+
+    /// Like `get_sequence_region_with_cache`, but maps the region of the
+    /// prepared FASTA instead of reading it into memory.
+    #[cfg(feature = "mmap-backing")]
+    pub fn map_sequence_region_with_cache(
+        &self,
+        genome_id: &str,
+        chromosome: &str,
+        start_1based: usize,
+        end_1based: usize,
+        cache_dir_override: Option<&str>,
+    ) -> Result<crate::mapped_sequence::MappedFasta, String> {
+        let (sequence_path, record, index_entry) = self.locate_sequence_region(
+            genome_id,
+            chromosome,
+            start_1based,
+            end_1based,
+            cache_dir_override,
+        )?;
+        let layout = crate::mapped_sequence::FastaRecordLayout {
+            length: index_entry.length,
+            offset: index_entry.offset,
+            line_bases: index_entry.line_bases,
+            line_bytes: index_entry.line_bytes,
+        };
+        crate::mapped_sequence::MappedFasta::open(
+            &sequence_path,
+            &record,
+            layout,
+            (start_1based - 1)..end_1based,
+        )
+        .map_err(|e| format!("Could not map '{}': {e}", sequence_path.display()))
+    }
+
+    /// Validate a 1-based region and resolve the prepared FASTA, record name
+    /// and index entry holding it.
+    fn locate_sequence_region(
+        &self,
+        genome_id: &str,
+        chromosome: &str,
+        start_1based: usize,
+        end_1based: usize,
+        cache_dir_override: Option<&str>,
+    ) -> Result<(PathBuf, String, FastaIndexEntry), String> {
         if start_1based == 0 {
             return Err("Coordinates must be 1-based (start >= 1)".to_string());
         }
@@ -5724,9 +5964,9 @@ impl GenomeCatalog {
         let index = load_fasta_index(Path::new(&manifest.fasta_index_path))?;
         let chr_candidates = chromosome_lookup_candidates(chromosome);
 
-        let index_entry = chr_candidates
+        let (record, index_entry) = chr_candidates
             .iter()
-            .find_map(|name| index.get(name))
+            .find_map(|name| index.get_key_value(name))
             .ok_or_else(|| {
                 let mut tried_aliases = chr_candidates.clone();
                 tried_aliases.sort_unstable();
@@ -5834,12 +6074,11 @@ FASTA index='{}'.{}{}",
             ));
         }
 
-        read_fasta_slice(
-            Path::new(&manifest.sequence_path),
-            index_entry,
-            start_1based as u64,
-            end_1based as u64,
-        )
+        Ok((
+            PathBuf::from(&manifest.sequence_path),
+            record.clone(),
+            index_entry.clone(),
+        ))
     }
 
     /// List prepared chromosome/contig lengths, sorted by descending size.
@@ -9389,6 +9628,7 @@ fn append_path_suffix(path: &Path, suffix: &str) -> PathBuf {
 fn download_http_source_with_resume<F>(
     source: &str,
     download_path: &Path,
+    policy: &DownloadRetryPolicy,
     on_progress: &mut F,
 ) -> Result<Option<u64>, String>
 where
@@ -9413,8 +9653,9 @@ where
         .map(|m| m.len())
         .unwrap_or(0);
     let mut last_error: Option<String> = None;
+    let max_attempts = policy.max_attempts.max(1);
 
-    for attempt in 1..=HTTP_RETRY_ATTEMPTS {
+    for attempt in 1..=max_attempts {
         let mut request = client.get(source);
         if resume_from > 0 {
             request = request.header(reqwest::header::RANGE, format!("bytes={resume_from}-"));
@@ -9425,12 +9666,11 @@ where
                 let retryable = is_retryable_http_error(&err);
                 let msg = format!(
                     "request failed at offset {} (attempt {}/{}): {}",
-                    resume_from, attempt, HTTP_RETRY_ATTEMPTS, err
+                    resume_from, attempt, max_attempts, err
                 );
                 last_error = Some(msg.clone());
-                if retryable && attempt < HTTP_RETRY_ATTEMPTS {
-                    let delay_ms = HTTP_RETRY_BASE_BACKOFF_MS.saturating_mul(1 << (attempt - 1));
-                    thread::sleep(Duration::from_millis(delay_ms));
+                if retryable && attempt < max_attempts {
+                    wait_for_download_retry(policy, attempt, || on_progress(resume_from, None))?;
                     continue;
                 }
                 return Err(format!(
@@ -9455,12 +9695,11 @@ where
                 status.as_u16(),
                 resume_from,
                 attempt,
-                HTTP_RETRY_ATTEMPTS
+                max_attempts
             );
             last_error = Some(msg.clone());
-            if retryable && attempt < HTTP_RETRY_ATTEMPTS {
-                let delay_ms = HTTP_RETRY_BASE_BACKOFF_MS.saturating_mul(1 << (attempt - 1));
-                thread::sleep(Duration::from_millis(delay_ms));
+            if retryable && attempt < max_attempts {
+                wait_for_download_retry(policy, attempt, || on_progress(resume_from, None))?;
                 continue;
             }
             return Err(format!(
@@ -9573,13 +9812,12 @@ where
         if let Some(err) = copy_error {
             let msg = format!(
                 "{} (offset {} attempt {}/{})",
-                err, final_done, attempt, HTTP_RETRY_ATTEMPTS
+                err, final_done, attempt, max_attempts
             );
             last_error = Some(msg.clone());
-            if attempt < HTTP_RETRY_ATTEMPTS {
+            if attempt < max_attempts {
                 resume_from = final_done;
-                let delay_ms = HTTP_RETRY_BASE_BACKOFF_MS.saturating_mul(1 << (attempt - 1));
-                thread::sleep(Duration::from_millis(delay_ms));
+                wait_for_download_retry(policy, attempt, || on_progress(resume_from, None))?;
                 continue;
             }
             return Err(format!(
@@ -9592,13 +9830,12 @@ where
             if final_done < total {
                 let msg = format!(
                     "incomplete download for '{}' (got {} of {} bytes at attempt {}/{})",
-                    source, final_done, total, attempt, HTTP_RETRY_ATTEMPTS
+                    source, final_done, total, attempt, max_attempts
                 );
                 last_error = Some(msg.clone());
-                if attempt < HTTP_RETRY_ATTEMPTS {
+                if attempt < max_attempts {
                     resume_from = final_done;
-                    let delay_ms = HTTP_RETRY_BASE_BACKOFF_MS.saturating_mul(1 << (attempt - 1));
-                    thread::sleep(Duration::from_millis(delay_ms));
+                    wait_for_download_retry(policy, attempt, || on_progress(resume_from, None))?;
                     continue;
                 }
                 return Err(format!(
@@ -9612,10 +9849,9 @@ where
                     source, final_done, total
                 );
                 last_error = Some(msg.clone());
-                if attempt < HTTP_RETRY_ATTEMPTS {
+                if attempt < max_attempts {
                     resume_from = 0;
-                    let delay_ms = HTTP_RETRY_BASE_BACKOFF_MS.saturating_mul(1 << (attempt - 1));
-                    thread::sleep(Duration::from_millis(delay_ms));
+                    wait_for_download_retry(policy, attempt, || on_progress(resume_from, None))?;
                     continue;
                 }
                 return Err(format!(
@@ -9643,6 +9879,27 @@ where
     ))
 }
 
+/// Wait out the backoff after failed attempt `failed_attempt`, polling `poll`
+/// every few hundred milliseconds so a caller timeout or cancellation
+/// interrupts a pending retry instead of sleeping past it.
+fn wait_for_download_retry(
+    policy: &DownloadRetryPolicy,
+    failed_attempt: usize,
+    mut poll: impl FnMut() -> bool,
+) -> Result<(), String> {
+    let wait_until = Instant::now() + policy.backoff_after_attempt(failed_attempt);
+    loop {
+        if !poll() {
+            return Err(prepare_cancelled_error("download retry backoff"));
+        }
+        let remaining = wait_until.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Ok(());
+        }
+        thread::sleep(remaining.min(Duration::from_millis(DOWNLOAD_RETRY_WAIT_TICK_MS)));
+    }
+}
+
 struct ProgressReader<R, F> {
     inner: R,
     callback: F,
@@ -9676,6 +9933,7 @@ impl<R: Read, F: FnMut(u64) -> bool> Read for ProgressReader<R, F> {
 fn materialize_source_with_progress<F>(
     source: &str,
     destination: &Path,
+    download_retry: &DownloadRetryPolicy,
     mut on_progress: F,
 ) -> Result<(), String>
 where
@@ -9694,8 +9952,12 @@ where
     if is_http_source(source) {
         if is_gzip_source(source) {
             let compressed_path = append_path_suffix(destination, ".download.part");
-            let compressed_total =
-                download_http_source_with_resume(source, &compressed_path, &mut on_progress)?;
+            let compressed_total = download_http_source_with_resume(
+                source,
+                &compressed_path,
+                download_retry,
+                &mut on_progress,
+            )?;
             let compressed_size = compressed_total
                 .or_else(|| fs::metadata(&compressed_path).ok().map(|m| m.len()))
                 .unwrap_or(0);
@@ -9734,7 +9996,7 @@ where
         }
 
         let downloaded_total =
-            download_http_source_with_resume(source, &tmp_path, &mut on_progress)?;
+            download_http_source_with_resume(source, &tmp_path, download_retry, &mut on_progress)?;
         let done = downloaded_total
             .or_else(|| fs::metadata(&tmp_path).ok().map(|m| m.len()))
             .unwrap_or(0);
@@ -13506,6 +13768,97 @@ mod tests {
         assert!(is_prepare_cancelled_error(&err));
     }
 
+    /// Serve `responses` as `(status, body)` to successive connections on a
+    /// local port; `None` when this environment rejects localhost binds.
+    fn spawn_download_stub_server(responses: Vec<(u16, &'static str)>) -> Option<String> {
+        let listener = match std::net::TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                eprintln!("skipping download stub server test: {err}");
+                return None;
+            }
+            Err(err) => panic!("bind download stub server: {err}"),
+        };
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut buf = [0u8; 2048];
+                let _ = stream.read(&mut buf);
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        Some(format!("http://{addr}/toy.fa"))
+    }
+
+    #[test]
+    fn test_download_retry_succeeds_on_third_attempt_within_one_retry_loop() {
+        let Some(source) =
+            spawn_download_stub_server(vec![(503, "busy"), (503, "busy"), (200, "ACGTACGT")])
+        else {
+            return;
+        };
+        let td = tempdir().unwrap();
+        let path = td.path().join("toy.fa.part");
+        let policy = DownloadRetryPolicy {
+            max_attempts: 3,
+            base_delay_ms: 1,
+        };
+        let mut polls = 0usize;
+        let total = download_http_source_with_resume(&source, &path, &policy, &mut |_, _| {
+            polls += 1;
+            true
+        })
+        .unwrap();
+        assert_eq!(total, Some(8));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "ACGTACGT");
+        assert!(polls >= 2, "backoff waits should poll the callback");
+
+        let Some(source) = spawn_download_stub_server(vec![(503, "busy"); 3]) else {
+            return;
+        };
+        let err = download_http_source_with_resume(
+            &source,
+            &path,
+            &DownloadRetryPolicy {
+                max_attempts: 2,
+                base_delay_ms: 1,
+            },
+            &mut |_, _| true,
+        )
+        .unwrap_err();
+        assert!(err.contains("HTTP 503"), "{err}");
+        assert!(err.contains("attempt 2/2"), "{err}");
+        assert!(err.contains("retries exhausted"), "{err}");
+    }
+
+    #[test]
+    fn test_download_retry_backoff_stops_when_callback_cancels() {
+        let started = Instant::now();
+        let mut calls = 0usize;
+        let err = wait_for_download_retry(
+            &DownloadRetryPolicy {
+                max_attempts: 5,
+                base_delay_ms: 60_000,
+            },
+            1,
+            || {
+                calls += 1;
+                calls < 2
+            },
+        )
+        .unwrap_err();
+        assert!(is_prepare_cancelled_error(&err));
+        assert_eq!(calls, 2);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
     #[test]
     fn test_prepare_activity_status_is_visible_during_progress_and_persists_cancelled_state() {
         let td = tempdir().unwrap();
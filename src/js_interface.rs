@@ -443,6 +443,11 @@ fn execute_agent_plan_impl(
 #[serde]
 fn load_dna(#[string] path: &str) -> Result<DNAsequence, JsAnyhow> {
     let mut dna = dna_sequence::load_from_file(path).map_err(deno_core::anyhow::Error::from)?;
+    if let Some((declared_bp, parsed_bp)) = dna.reconcile_declared_length() {
+        eprintln!(
+            "W load_dna: '{path}' declares {declared_bp} bp but contains {parsed_bp} bp; the file may be truncated"
+        );
+    }
 
     // Add default enzymes and stuff
     *dna.restriction_enzymes_mut() = active_restriction_enzymes();
@@ -518,10 +523,10 @@ fn state_summary(#[serde] state: ProjectState) -> Result<EngineStateSummary, JsA
 #[serde]
 fn inspect_dna_ladders(
     #[string] name_filter: &str,
+    #[serde] state: Option<ProjectState>,
 ) -> Result<crate::engine::DnaLadderCatalog, JsAnyhow> {
-    Ok(GentleEngine::inspect_dna_ladders(empty_to_none(
-        name_filter,
-    )))
+    let engine = GentleEngine::from_state(state.unwrap_or_default());
+    Ok(engine.inspect_dna_ladders(empty_to_none(name_filter)))
 }
 
 #[op2]
@@ -539,8 +544,12 @@ fn inspect_rna_ladders(
 fn export_dna_ladders(
     #[string] path: &str,
     #[string] name_filter: &str,
+    #[serde] state: Option<ProjectState>,
 ) -> Result<crate::engine::DnaLadderExportReport, JsAnyhow> {
-    GentleEngine::export_dna_ladders(path, empty_to_none(name_filter)).map_err(JsAnyhow::from)
+    let engine = GentleEngine::from_state(state.unwrap_or_default());
+    engine
+        .export_dna_ladders(path, empty_to_none(name_filter))
+        .map_err(JsAnyhow::from)
 }
 
 #[op2]
@@ -1001,11 +1010,14 @@ impl JavaScriptInterface {
           	function save_project(state,path) {return Deno.core.ops.save_project(state,path)}
 	          	function capabilities() {return Deno.core.ops.capabilities()}
 	          	function state_summary(state) {return Deno.core.ops.state_summary(state)}
-	          	function inspect_dna_ladders(name_filter) {
-	          		return Deno.core.ops.inspect_dna_ladders(name_filter ?? "");
+	          	function inspect_dna_ladders(name_filter, state) {
+	          		return Deno.core.ops.inspect_dna_ladders(
+	          			name_filter ?? "",
+	          			(state === undefined ? null : state)
+	          		);
 	          	}
-		          	function list_dna_ladders(name_filter) {
-		          		return inspect_dna_ladders(name_filter);
+		          	function list_dna_ladders(name_filter, state) {
+		          		return inspect_dna_ladders(name_filter, state);
 		          	}
 		          	function inspect_rna_ladders(name_filter) {
 		          		return Deno.core.ops.inspect_rna_ladders(name_filter ?? "");
@@ -1013,8 +1025,12 @@ impl JavaScriptInterface {
 		          	function list_rna_ladders(name_filter) {
 		          		return inspect_rna_ladders(name_filter);
 		          	}
-		          	function export_dna_ladders(path, name_filter) {
-		          		return Deno.core.ops.export_dna_ladders(path, name_filter ?? "");
+		          	function export_dna_ladders(path, name_filter, state) {
+		          		return Deno.core.ops.export_dna_ladders(
+		          			path,
+		          			name_filter ?? "",
+		          			(state === undefined ? null : state)
+		          		);
 		          	}
 		          	function export_rna_ladders(path, name_filter) {
 		          		return Deno.core.ops.export_rna_ladders(path, name_filter ?? "");
@@ -1297,9 +1313,9 @@ impl JavaScriptInterface {
           			? "three_prime"
           			: ((rawSide === "5" || rawSide === "5p" || rawSide === "5prime" || rawSide === "5'" || rawSide === "five_prime" || rawSide === "five-prime")
           				? "five_prime"
-          				: null);
+          				: ((rawSide === "both" || rawSide === "5p3p") ? "both" : null));
           		if (!sideValue) {
-          			throw new Error("extend_genome_anchor side must be 5p or 3p");
+          			throw new Error("extend_genome_anchor side must be 5p, 3p, or both");
           		}
           		return apply_operation(state, {
           			ExtendGenomeAnchor: {
@@ -51,6 +51,8 @@ pub mod enzymes;
 pub mod exon_frame;
 /// Overlay-discoverable external-service provider configuration catalogs.
 pub mod external_service_providers;
+/// Deterministic feature color assignment shared by map renderers.
+pub mod feature_color;
 /// Expert-view data contracts for feature-centric deep-inspection UIs.
 pub mod feature_expert;
 /// Feature location/strand utilities used across render and engine code.
@@ -84,6 +86,9 @@ pub mod linear_base_routing;
 pub mod lua_interface;
 /// Main sequence-window GUI controller and interaction orchestration.
 pub mod main_area_dna;
+/// Memory-mapped FASTA regions backing genome-extracted `DNAsequence`s.
+#[cfg(feature = "mmap-backing")]
+pub mod mapped_sequence;
 /// MCP stdio server adapter exposing tool execution and capability discovery.
 pub mod mcp_server;
 /// Methylation-site detection and state helpers.
@@ -134,6 +139,8 @@ pub mod restriction_enzyme;
 pub mod rna_structure;
 /// Shared wheel/key/cursor interaction policy for scroll, pan, and zoom.
 pub mod scroll_input_policy;
+/// Deterministic seeded RNG shared by all engine sampling paths.
+pub mod seeded_rng;
 /// Sequence-row abstraction shared by specialized row renderers.
 pub mod sequence_rows;
 /// Blank-row renderer implementation.
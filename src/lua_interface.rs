@@ -40,6 +40,11 @@ impl LuaInterface {
     pub fn load_dna(path: &str) -> LuaResult<DNAsequence> {
         let mut dna = dna_sequence::load_from_file(path)
             .map_err(|e| LuaError::RuntimeError(e.to_string()))?;
+        if let Some((declared_bp, parsed_bp)) = dna.reconcile_declared_length() {
+            eprintln!(
+                "W load_dna: '{path}' declares {declared_bp} bp but contains {parsed_bp} bp; the file may be truncated"
+            );
+        }
 
         // Add default enzymes and stuff
         *dna.restriction_enzymes_mut() = active_restriction_enzymes();
@@ -85,8 +90,12 @@ impl LuaInterface {
         println!("  - save_project(state, filename): Saves a GENtle project JSON");
         println!("  - capabilities(): Returns engine capabilities");
         println!("  - state_summary(project): Returns sequence/container summary");
-        println!("  - inspect_dna_ladders([name_filter]): Returns built-in DNA ladder catalog");
-        println!("  - export_dna_ladders(output_json, [name_filter]): Writes ladder catalog JSON");
+        println!(
+            "  - inspect_dna_ladders([name_filter], [project]): Returns built-in and project DNA ladders"
+        );
+        println!(
+            "  - export_dna_ladders(output_json, [name_filter], [project]): Writes ladder catalog JSON"
+        );
         println!("  - inspect_rna_ladders([name_filter]): Returns built-in RNA ladder catalog");
         println!(
             "  - export_rna_ladders(output_json, [name_filter]): Writes RNA ladder catalog JSON"
@@ -611,23 +620,39 @@ impl LuaInterface {
 
     fn inspect_dna_ladders(
         name_filter: Option<String>,
+        state: Option<ProjectState>,
     ) -> LuaResult<crate::engine::DnaLadderCatalog> {
         let name_filter = name_filter
             .as_deref()
             .map(str::trim)
             .filter(|v| !v.is_empty());
-        Ok(GentleEngine::inspect_dna_ladders(name_filter))
+        let engine = GentleEngine::from_state(state.unwrap_or_default());
+        Ok(engine.inspect_dna_ladders(name_filter))
     }
 
     fn export_dna_ladders(
         output_json: String,
         name_filter: Option<String>,
+        state: Option<ProjectState>,
     ) -> LuaResult<crate::engine::DnaLadderExportReport> {
         let name_filter = name_filter
             .as_deref()
             .map(str::trim)
             .filter(|v| !v.is_empty());
-        GentleEngine::export_dna_ladders(&output_json, name_filter).map_err(Self::engine_err)
+        let engine = GentleEngine::from_state(state.unwrap_or_default());
+        engine
+            .export_dna_ladders(&output_json, name_filter)
+            .map_err(Self::engine_err)
+    }
+
+    /// Optional project argument of the ladder helpers (`nil` = no project).
+    fn optional_project(lua: &Lua, state: Value) -> LuaResult<Option<ProjectState>> {
+        if matches!(state, Value::Nil) {
+            return Ok(None);
+        }
+        lua.from_value(state)
+            .map(Some)
+            .map_err(|e| Self::err(&format!("Invalid project value: {e}")))
     }
 
     fn inspect_rna_ladders(
@@ -866,8 +891,9 @@ impl LuaInterface {
         self.lua.globals().set(
             "inspect_dna_ladders",
             self.lua
-                .create_function(|lua, name_filter: Option<String>| {
-                    let ladders = Self::inspect_dna_ladders(name_filter)?;
+                .create_function(|lua, (name_filter, state): (Option<String>, Value)| {
+                    let state = Self::optional_project(lua, state)?;
+                    let ladders = Self::inspect_dna_ladders(name_filter, state)?;
                     lua.to_value(&ladders)
                 })?,
         )?;
@@ -875,8 +901,9 @@ impl LuaInterface {
         self.lua.globals().set(
             "list_dna_ladders",
             self.lua
-                .create_function(|lua, name_filter: Option<String>| {
-                    let ladders = Self::inspect_dna_ladders(name_filter)?;
+                .create_function(|lua, (name_filter, state): (Option<String>, Value)| {
+                    let state = Self::optional_project(lua, state)?;
+                    let ladders = Self::inspect_dna_ladders(name_filter, state)?;
                     lua.to_value(&ladders)
                 })?,
         )?;
@@ -884,8 +911,9 @@ impl LuaInterface {
         self.lua.globals().set(
             "export_dna_ladders",
             self.lua.create_function(
-                |lua, (output_json, name_filter): (String, Option<String>)| {
-                    let report = Self::export_dna_ladders(output_json, name_filter)?;
+                |lua, (output_json, name_filter, state): (String, Option<String>, Value)| {
+                    let state = Self::optional_project(lua, state)?;
+                    let report = Self::export_dna_ladders(output_json, name_filter, state)?;
                     lua.to_value(&report)
                 },
             )?,
@@ -1740,6 +1768,7 @@ impl LuaInterface {
                             genome_id,
                             gene_query,
                             occurrence,
+                            match_mode: None,
                             output_id,
                             extract_mode: parsed_extract_mode,
                             promoter_upstream_bp,
@@ -1783,7 +1812,12 @@ impl LuaInterface {
                         "3" | "3p" | "3prime" | "3'" | "three_prime" | "three-prime" => {
                             GenomeAnchorSide::ThreePrime
                         }
-                        _ => return Err(Self::err("extend_genome_anchor side must be 5p or 3p")),
+                        "both" | "5p3p" => GenomeAnchorSide::Both,
+                        _ => {
+                            return Err(Self::err(
+                                "extend_genome_anchor side must be 5p, 3p, or both",
+                            ));
+                        }
                     };
                     let state: ProjectState = lua
                         .from_value(state)
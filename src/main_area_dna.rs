@@ -10076,6 +10076,13 @@ impl MainAreaDna {
         display.set_preferred_restriction_enzymes(settings.preferred_restriction_enzymes.clone());
         display.set_show_gc_contents(settings.show_gc_contents);
         display.set_gc_content_bin_size_bp(settings.gc_content_bin_size_bp);
+        display.set_show_feature_density_when_zoomed_out(
+            settings.show_feature_density_when_zoomed_out,
+        );
+        display.set_feature_density_bin_bp(settings.feature_density_bin_bp);
+        display.set_circular_tick_interval_bp(settings.circular_tick_interval_bp);
+        display.set_collapse_introns_over_bp(settings.collapse_introns_over_bp);
+        display.set_feature_color_overrides(settings.feature_color_overrides.clone());
         display.set_show_open_reading_frames(settings.show_open_reading_frames);
         display.set_suppress_open_reading_frames_for_genome_anchor(suppress_orf_for_anchor);
         display.set_suppress_cds_features_for_gene_annotations(suppress_cds_for_gene_annotations);
@@ -19287,7 +19294,12 @@ impl MainAreaDna {
         let warnings = if result.warnings.is_empty() {
             "-".to_string()
         } else {
-            result.warnings.join(" | ")
+            result
+                .warnings
+                .iter()
+                .map(|warning| warning.message.as_str())
+                .collect::<Vec<_>>()
+                .join(" | ")
         };
         let messages = if result.messages.is_empty() {
             "-".to_string()
@@ -20142,6 +20154,7 @@ impl MainAreaDna {
             seq_id,
             path,
             format,
+            genbank_options: None,
         });
     }
 
@@ -20169,7 +20182,12 @@ impl MainAreaDna {
         } else {
             RenderSvgMode::Linear
         };
-        self.apply_operation_with_feedback(Operation::RenderSequenceSvg { seq_id, mode, path });
+        self.apply_operation_with_feedback(Operation::RenderSequenceSvg {
+            seq_id,
+            mode,
+            path,
+            viewport: None,
+        });
     }
 
     fn sanitize_export_name_component(raw: &str, fallback: &str) -> String {
@@ -3405,7 +3405,7 @@ impl MainAreaDna {
     }
 
     pub(super) fn summarize_status_items(
-        items: &[String],
+        items: &[impl AsRef<str>],
         max_visible: usize,
         separator: &str,
     ) -> String {
@@ -3413,7 +3413,11 @@ impl MainAreaDna {
             return "-".to_string();
         }
         let max_visible = max_visible.max(1);
-        let visible = items.iter().take(max_visible).cloned().collect::<Vec<_>>();
+        let visible = items
+            .iter()
+            .take(max_visible)
+            .map(|item| item.as_ref())
+            .collect::<Vec<_>>();
         if items.len() > max_visible {
             format!(
                 "{} (+{} more)",
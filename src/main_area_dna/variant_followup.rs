@@ -1971,6 +1971,8 @@ impl MainAreaDna {
         let result = self.apply_operation_with_feedback_and_result(Operation::LoadFile {
             path,
             as_id: Some(seq_id.clone()),
+            skip_duplicates: None,
+            uppercase: None,
         });
         if result.is_none() {
             return Err(self.op_status.clone());
@@ -0,0 +1,219 @@
+//! Memory-mapped FASTA regions backing genome-extracted `DNAsequence`s.
+//!
+//! `MappedFasta` maps a prepared genome FASTA and addresses one region of one
+//! record through the `.fai` geometry (byte offset of the first base, bases
+//! per line, bytes per line). Opening does not scan the file, and reads only
+//! touch the pages holding the requested bases, so a region of a huge
+//! chromosome can be referenced without materializing the chromosome.
+//!
+//! `DNAsequence::from_mapped_fasta` wraps a region; `len`, `get_range_safe`
+//! and the computed-feature refresh read from the map, while any edit first
+//! copies the bases into an owned buffer (copy-on-write). Bases are returned
+//! uppercase, matching `GenomeCatalog::get_sequence_region_with_cache`.
+
+use anyhow::{Result, anyhow};
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    hash::{Hash, Hasher},
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+/// Line geometry of one FASTA record, as stored in its `.fai` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FastaRecordLayout {
+    /// Record length in bases.
+    pub length: u64,
+    /// Byte offset of the first base.
+    pub offset: u64,
+    /// Bases per full line.
+    pub line_bases: u64,
+    /// Bytes per full line, including the line terminator.
+    pub line_bytes: u64,
+}
+
+#[derive(Debug)]
+pub struct MappedFasta {
+    map: Mmap,
+    path: PathBuf,
+    record: String,
+    layout: FastaRecordLayout,
+    region: Range<usize>,
+}
+
+impl MappedFasta {
+    /// Map `path` and address `region` (0-based, end-exclusive) of the record
+    /// laid out as `layout`. The region must lie inside the record and the
+    /// file must be long enough to hold it.
+    pub fn open(
+        path: &Path,
+        record: &str,
+        layout: FastaRecordLayout,
+        region: Range<usize>,
+    ) -> Result<Self> {
+        if layout.line_bases == 0 || layout.line_bytes < layout.line_bases {
+            return Err(anyhow!(
+                "Invalid FASTA line layout for '{record}' ({} bases / {} bytes per line)",
+                layout.line_bases,
+                layout.line_bytes
+            ));
+        }
+        if region.start > region.end || region.end as u64 > layout.length {
+            return Err(anyhow!(
+                "Region {}..{} is outside record '{record}' ({} bp)",
+                region.start,
+                region.end,
+                layout.length
+            ));
+        }
+        let file = File::open(path)?;
+        // SAFETY: the map is read-only; callers must not truncate or rewrite
+        // the prepared FASTA while a mapped sequence is alive (the same
+        // contract the `.fai` readers rely on when they seek into it later).
+        let map = unsafe { Mmap::map(&file)? };
+        let mapped = Self {
+            map,
+            path: path.to_path_buf(),
+            record: record.to_string(),
+            layout,
+            region,
+        };
+        if !mapped.is_empty() && mapped.file_offset(mapped.len() - 1) >= mapped.map.len() {
+            return Err(anyhow!(
+                "FASTA '{}' is shorter than its index entry for '{record}'",
+                path.display()
+            ));
+        }
+        Ok(mapped)
+    }
+
+    pub fn record(&self) -> &str {
+        &self.record
+    }
+
+    /// Mapped region within the record, 0-based and end-exclusive.
+    pub fn region(&self) -> Range<usize> {
+        self.region.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.region.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    /// File offset of region position `pos`.
+    fn file_offset(&self, pos: usize) -> usize {
+        let pos = (self.region.start + pos) as u64;
+        let row = pos / self.layout.line_bases;
+        let col = pos % self.layout.line_bases;
+        (self.layout.offset + row * self.layout.line_bytes + col) as usize
+    }
+
+    /// Uppercase bases of `range` (region coordinates, clamped to the
+    /// region), copied line by line.
+    pub fn read_range(&self, range: Range<usize>) -> Vec<u8> {
+        let end = range.end.min(self.len());
+        let mut out = Vec::with_capacity(end.saturating_sub(range.start));
+        let line_bases = self.layout.line_bases as usize;
+        let mut pos = range.start;
+        while pos < end {
+            let col = (self.region.start + pos) % line_bases;
+            let take = (line_bases - col).min(end - pos);
+            let file_pos = self.file_offset(pos);
+            out.extend(
+                self.map[file_pos..file_pos + take]
+                    .iter()
+                    .map(u8::to_ascii_uppercase),
+            );
+            pos += take;
+        }
+        out
+    }
+
+    /// Copy every base of the region into an owned buffer.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.read_range(0..self.len())
+    }
+
+    /// Hash what identifies the mapped bases (file, record, layout and
+    /// region) without reading them.
+    pub fn hash_identity<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+        self.record.hash(state);
+        self.layout.hash(state);
+        self.region.hash(state);
+        self.map.len().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two records with 60-base lines; returns the path and the layout of
+    /// `chrT`, whose bases mix cases like a soft-masked assembly.
+    fn write_fixture(
+        dir: &Path,
+        len: usize,
+        line_end: &str,
+    ) -> (PathBuf, FastaRecordLayout, Vec<u8>) {
+        let path = dir.join("genome.fa");
+        let bases = (0..len)
+            .map(|i| b"ACGTacgtNNGC"[(i * 7 + i / 13) % 12])
+            .collect::<Vec<_>>();
+        let mut text =
+            format!(">decoy short record{line_end}ACGT{line_end}>chrT synthetic{line_end}");
+        let offset = text.len() as u64;
+        for line in bases.chunks(60) {
+            text.push_str(std::str::from_utf8(line).unwrap());
+            text.push_str(line_end);
+        }
+        std::fs::write(&path, text).unwrap();
+        let layout = FastaRecordLayout {
+            length: len as u64,
+            offset,
+            line_bases: 60,
+            line_bytes: 60 + line_end.len() as u64,
+        };
+        (path, layout, bases.to_ascii_uppercase())
+    }
+
+    #[test]
+    fn region_reads_match_record_bases() {
+        let td = tempfile::tempdir().unwrap();
+        for line_end in ["\n", "\r\n"] {
+            let (path, layout, bases) = write_fixture(td.path(), 25_007, line_end);
+            let whole = MappedFasta::open(&path, "chrT", layout, 0..25_007).unwrap();
+            assert_eq!(whole.to_vec(), bases);
+
+            let region = MappedFasta::open(&path, "chrT", layout, 119..12_345).unwrap();
+            assert_eq!(region.len(), 12_226);
+            assert_eq!(region.to_vec(), bases[119..12_345]);
+            for range in [0..1, 0..61, 1..119, 5_000..5_121, 12_200..12_226] {
+                assert_eq!(
+                    region.read_range(range.clone()),
+                    bases[119 + range.start..119 + range.end]
+                );
+            }
+            assert_eq!(region.read_range(12_220..20_000), bases[12_339..12_345]);
+        }
+    }
+
+    #[test]
+    fn regions_outside_the_record_or_file_are_rejected() {
+        let td = tempfile::tempdir().unwrap();
+        let (path, layout, _) = write_fixture(td.path(), 1_000, "\n");
+        let err = MappedFasta::open(&path, "chrT", layout, 900..1_001).unwrap_err();
+        assert!(err.to_string().contains("outside record"));
+        let stale = FastaRecordLayout {
+            length: 5_000,
+            ..layout
+        };
+        let err = MappedFasta::open(&path, "chrT", stale, 0..5_000).unwrap_err();
+        assert!(err.to_string().contains("shorter than its index entry"));
+    }
+}
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 pub struct MethylationMode {
     dcm: bool,
     dam: bool,
+    #[serde(default)]
+    cpg: bool,
 }
 
 impl MethylationMode {
@@ -31,6 +33,76 @@ impl MethylationMode {
     pub fn set_dam(&mut self, dam: bool) {
         self.dam = dam;
     }
+
+    pub fn cpg(&self) -> bool {
+        self.cpg
+    }
+
+    pub fn set_cpg(&mut self, cpg: bool) {
+        self.cpg = cpg;
+    }
+
+    /// Enabled site classes joined with `+` (e.g. `dam+dcm`), or `none`.
+    pub fn describe(&self) -> String {
+        let enabled = [("dam", self.dam), ("dcm", self.dcm), ("cpg", self.cpg)]
+            .into_iter()
+            .filter(|(_, on)| *on)
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        if enabled.is_empty() {
+            "none".to_string()
+        } else {
+            enabled.join("+")
+        }
+    }
+}
+
+/// Recognition-site class of one methylation site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MethylationSiteKind {
+    /// Dam: N6-adenine in `GATC`.
+    Dam,
+    /// Dcm: C5-cytosine (inner C) in `CCWGG`.
+    Dcm,
+    /// CpG: C5-cytosine in `CG`.
+    Cpg,
+}
+
+impl MethylationSiteKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dam => "dam",
+            Self::Dcm => "dcm",
+            Self::Cpg => "cpg",
+        }
+    }
+
+    /// Recognition motif as written on the top strand.
+    pub fn motif(self) -> &'static str {
+        match self {
+            Self::Dam => "GATC",
+            Self::Dcm => "CCWGG",
+            Self::Cpg => "CG",
+        }
+    }
+
+    /// GenBank `/mod_base` abbreviation of the methylated base.
+    pub fn mod_base(self) -> &'static str {
+        match self {
+            Self::Dam => "m6a",
+            Self::Dcm | Self::Cpg => "m5c",
+        }
+    }
+}
+
+/// One methylation site with its motif span and methylated top-strand base
+/// (all 0-based).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MethylationSite {
+    pub kind: MethylationSiteKind,
+    pub motif_start: usize,
+    pub motif_end: usize,
+    pub methylated_base: usize,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -68,11 +140,56 @@ impl MethylationSites {
                     ret.sites.push(pos + 1);
                 }
             }
+
+            if mode.cpg()
+                && Self::get_nucleotide(sequence, pos) == 'C'
+                && Self::get_nucleotide(sequence, pos + 1) == 'G'
+            {
+                ret.sites.push(pos);
+            }
         }
         ret.last_mode = mode;
         ret
     }
 
+    /// Every site of the classes enabled in `mode`, ordered by motif start
+    /// then class. Unlike `new_from_sequence`, overlapping classes are all
+    /// reported.
+    pub fn find_sites(sequence: &[u8], mode: &MethylationMode) -> Vec<MethylationSite> {
+        let mut ret = vec![];
+        for pos in 0..sequence.len() {
+            let base = |offset: usize| Self::get_nucleotide(sequence, pos + offset);
+            if mode.dam() && [base(0), base(1), base(2), base(3)] == ['G', 'A', 'T', 'C'] {
+                ret.push(MethylationSite {
+                    kind: MethylationSiteKind::Dam,
+                    motif_start: pos,
+                    motif_end: pos + 4,
+                    methylated_base: pos + 1,
+                });
+            }
+            if mode.dcm()
+                && [base(0), base(1), base(3), base(4)] == ['C', 'C', 'G', 'G']
+                && matches!(base(2), 'A' | 'T')
+            {
+                ret.push(MethylationSite {
+                    kind: MethylationSiteKind::Dcm,
+                    motif_start: pos,
+                    motif_end: pos + 5,
+                    methylated_base: pos + 1,
+                });
+            }
+            if mode.cpg() && [base(0), base(1)] == ['C', 'G'] {
+                ret.push(MethylationSite {
+                    kind: MethylationSiteKind::Cpg,
+                    motif_start: pos,
+                    motif_end: pos + 2,
+                    methylated_base: pos,
+                });
+            }
+        }
+        ret
+    }
+
     #[inline(always)]
     fn get_nucleotide(sequence: &[u8], pos: usize) -> char {
         sequence
@@ -102,9 +219,32 @@ mod tests {
         let mode = MethylationMode {
             dcm: true,
             dam: true,
+            cpg: false,
         };
         let sites = MethylationSites::new_from_sequence(sequence, mode.to_owned());
         assert_eq!(sites.sites(), &[1, 5, 8]);
         assert_eq!(sites.last_mode(), mode);
     }
+
+    #[test]
+    fn test_find_sites_reports_each_enabled_class() {
+        let mut mode = MethylationMode::both();
+        mode.set_cpg(true);
+        let sites = MethylationSites::find_sites(b"CGATCGCCTGG", &mode);
+        let summary = sites
+            .iter()
+            .map(|site| (site.kind, site.motif_start, site.methylated_base))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (MethylationSiteKind::Cpg, 0, 0),
+                (MethylationSiteKind::Dam, 1, 2),
+                (MethylationSiteKind::Cpg, 4, 4),
+                (MethylationSiteKind::Dcm, 6, 7),
+            ]
+        );
+        assert_eq!(mode.describe(), "dam+dcm+cpg");
+        assert_eq!(MethylationMode::default().describe(), "none");
+    }
 }
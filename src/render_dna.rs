@@ -4,6 +4,7 @@ use crate::{
     dna_display::{DnaDisplay, TfbsDisplayCriteria, VcfDisplayCriteria},
     dna_sequence::DNAsequence,
     exon_frame::ExonLengthFrameCue,
+    feature_color::{assign_feature_color, feature_color_override},
     feature_location::collect_location_ranges_usize,
     render_dna_circular::RenderDnaCircular,
     render_dna_linear::RenderDnaLinear,
@@ -13,7 +14,7 @@ use crate::{
 use eframe::egui::{self, Color32, PointerState, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
 use gb_io::seq::Feature;
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     fmt::Debug,
     panic::{AssertUnwindSafe, catch_unwind},
     path::Path,
@@ -430,10 +431,19 @@ impl RenderDna {
             "VARIATION" => Color32::from_rgb(225, 127, 15),
             "TFBS" | "TF_BINDING_SITE" | "PROTEIN_BIND" => Color32::from_rgb(35, 120, 35),
             "TRACK" => Color32::from_rgb(85, 85, 85),
-            _ => Color32::GRAY,
+            _ => assign_feature_color(feature),
         }
     }
 
+    /// `feature_color` with user overrides from
+    /// `DisplaySettings::feature_color_overrides` applied first.
+    pub fn feature_color_with_overrides(
+        feature: &Feature,
+        overrides: &BTreeMap<String, String>,
+    ) -> Color32 {
+        feature_color_override(overrides, feature).unwrap_or_else(|| Self::feature_color(feature))
+    }
+
     pub fn is_cds_feature(feature: &Feature) -> bool {
         feature.kind.to_string().eq_ignore_ascii_case("CDS")
     }
@@ -18,7 +18,7 @@ use eframe::egui::{
 };
 use gb_io::seq::Feature;
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     sync::{Arc, LazyLock, RwLock},
 };
 
@@ -665,40 +665,90 @@ impl RenderDnaCircular {
         point
     }
 
+    /// Smallest "nice" interval (1/2/5 x 10^n bp) that keeps the ruler at
+    /// ten or fewer ticks.
+    fn auto_tick_interval_bp(sequence_length: i64) -> i64 {
+        let mut magnitude: i64 = 1;
+        loop {
+            for factor in [1, 2, 5] {
+                let interval = factor * magnitude;
+                if sequence_length <= interval * 10 {
+                    return interval;
+                }
+            }
+            magnitude *= 10;
+        }
+    }
+
+    /// Configured interval, or the automatic one when unset or when the
+    /// configured spacing would draw more than `MAX_TICKS` ticks.
+    fn tick_interval_bp(&self) -> i64 {
+        const MAX_TICKS: i64 = 100;
+        let configured = self
+            .display
+            .read()
+            .map(|display| display.circular_tick_interval_bp())
+            .unwrap_or(0) as i64;
+        if configured > 0 && self.sequence_length / configured <= MAX_TICKS {
+            configured
+        } else {
+            Self::auto_tick_interval_bp(self.sequence_length)
+        }
+    }
+
+    /// Tick positions for one ruler turn, skipping the origin.
+    fn tick_positions(sequence_length: i64, interval: i64) -> Vec<i64> {
+        if interval <= 0 {
+            return vec![];
+        }
+        (1..)
+            .map(|idx| idx * interval)
+            .take_while(|pos| *pos < sequence_length)
+            .collect()
+    }
+
+    /// Anchor for an upright tick label placed inside the ring, so the text
+    /// grows away from the backbone at any angle.
+    fn tick_label_align(angle_deg: f32) -> Align2 {
+        let t = angle_deg.to_radians();
+        let (cos, sin) = (t.cos(), t.sin());
+        let x = if cos > 0.3 {
+            egui::Align::Max
+        } else if cos < -0.3 {
+            egui::Align::Min
+        } else {
+            egui::Align::Center
+        };
+        let y = if sin > 0.3 {
+            egui::Align::Max
+        } else if sin < -0.3 {
+            egui::Align::Min
+        } else {
+            egui::Align::Center
+        };
+        Align2([x, y])
+    }
+
     /// Draws the backbone of the circular DNA with tick marks
     fn draw_backbone(&mut self, painter: &egui::Painter) {
         painter.circle_stroke(self.center.to_owned(), self.radius, BLACK_1.to_owned());
-        let mut tick: i64 = 1;
-        while tick * 10 < self.sequence_length {
-            tick *= 10;
-        }
-
         let font_tick = FontId {
             size: 9.0,
             family: FontFamily::Monospace,
         };
 
-        let mut pos = tick; // Skip 0 point
-        while pos < self.sequence_length {
+        for pos in Self::tick_positions(self.sequence_length, self.tick_interval_bp()) {
             let p1 = self.pos2xy(pos, self.radius);
             let p2 = self.pos2xy(pos, self.radius * 0.87);
             let p3 = self.pos2xy(pos, self.radius * 0.85);
             painter.line_segment([p1, p2], BLACK_1.to_owned());
-
-            let align = if pos > self.sequence_length / 2 {
-                Align2::LEFT_CENTER
-            } else {
-                Align2::RIGHT_CENTER
-            };
             painter.text(
                 p3,
-                align,
+                Self::tick_label_align(self.angle(pos)),
                 format!("{pos}"),
                 font_tick.to_owned(),
                 Color32::BLACK,
             );
-
-            pos += tick;
         }
     }
 
@@ -715,6 +765,7 @@ impl RenderDnaCircular {
             tfbs_display_criteria,
             vcf_display_criteria,
             hidden_feature_kinds,
+            feature_color_overrides,
         ) = {
             let display = self.display.read().expect("Display lock poisoned");
             (
@@ -727,6 +778,7 @@ impl RenderDnaCircular {
                 display.tfbs_display_criteria(),
                 display.vcf_display_criteria(),
                 display.hidden_feature_kinds().clone(),
+                display.feature_color_overrides().clone(),
             )
         };
         let features = self
@@ -747,6 +799,7 @@ impl RenderDnaCircular {
                 tfbs_display_criteria,
                 vcf_display_criteria.clone(),
                 &hidden_feature_kinds,
+                &feature_color_overrides,
             );
             if let Some(mut fp) = fp_opt {
                 fp.feature_number = feature_number;
@@ -1082,6 +1135,7 @@ impl RenderDnaCircular {
         tfbs_display_criteria: TfbsDisplayCriteria,
         vcf_display_criteria: VcfDisplayCriteria,
         hidden_feature_kinds: &BTreeSet<String>,
+        feature_color_overrides: &BTreeMap<String, String>,
     ) -> Option<FeaturePosition> {
         if !Self::draw_feature(
             feature,
@@ -1121,7 +1175,8 @@ impl RenderDnaCircular {
             .max()
             .unwrap_or(feature_from);
 
-        let feature_color = RenderDna::feature_color(feature);
+        let feature_color =
+            RenderDna::feature_color_with_overrides(feature, feature_color_overrides);
         let (intron_arch_color, intron_arch_width, intron_arch_lift_factor) =
             Self::intron_arch_style(feature, feature_color);
         let mut ret: FeaturePosition = FeaturePosition {
@@ -1802,6 +1857,62 @@ mod tests {
         assert!(fp.outer > fp.inner);
     }
 
+    #[test]
+    fn tick_interval_auto_selects_nice_spacing_and_honors_override() {
+        let mut renderer =
+            test_renderer_with_feature(make_test_feature(Location::simple_range(100, 400)), 10_000);
+        assert_eq!(renderer.tick_interval_bp(), 1_000);
+        assert_eq!(
+            RenderDnaCircular::tick_positions(10_000, renderer.tick_interval_bp()).len(),
+            9
+        );
+        assert_eq!(RenderDnaCircular::auto_tick_interval_bp(4_361), 500);
+        assert_eq!(RenderDnaCircular::auto_tick_interval_bp(15_000), 2_000);
+
+        renderer
+            .display
+            .write()
+            .expect("display")
+            .set_circular_tick_interval_bp(2_500);
+        renderer.sequence_length = 10_000;
+        assert_eq!(renderer.tick_interval_bp(), 2_500);
+        assert_eq!(
+            RenderDnaCircular::tick_positions(10_000, renderer.tick_interval_bp()),
+            vec![2_500, 5_000, 7_500]
+        );
+
+        renderer
+            .display
+            .write()
+            .expect("display")
+            .set_circular_tick_interval_bp(1);
+        assert_eq!(renderer.tick_interval_bp(), 1_000);
+    }
+
+    #[test]
+    fn tick_labels_stay_inside_ring_at_every_angle() {
+        assert_eq!(
+            RenderDnaCircular::tick_label_align(270.0),
+            Align2::CENTER_TOP
+        );
+        assert_eq!(
+            RenderDnaCircular::tick_label_align(0.0),
+            Align2::RIGHT_CENTER
+        );
+        assert_eq!(
+            RenderDnaCircular::tick_label_align(90.0),
+            Align2::CENTER_BOTTOM
+        );
+        assert_eq!(
+            RenderDnaCircular::tick_label_align(180.0),
+            Align2::LEFT_CENTER
+        );
+        assert_eq!(
+            RenderDnaCircular::tick_label_align(315.0),
+            Align2::RIGHT_TOP
+        );
+    }
+
     #[test]
     fn feature_label_offsets_start_at_center_then_expand() {
         let offsets = RenderDnaCircular::segment_candidate_offsets(10, 3);
@@ -25,7 +25,7 @@ use eframe::egui::{
     self, Align2, Color32, FontFamily, FontId, PointerState, Pos2, Rect, Stroke, StrokeKind, Vec2,
 };
 use gb_io::seq::Feature;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{Arc, RwLock};
 
 const BASELINE_STROKE: f32 = 2.0;
@@ -43,6 +43,9 @@ const LABEL_ROW_HEIGHT: f32 = 12.0;
 const LABEL_CHAR_WIDTH: f32 = 6.5;
 const ORF_HEIGHT: f32 = 6.0;
 const GC_STRIP_HEIGHT: f32 = 6.0;
+const FEATURE_DENSITY_STRIP_HEIGHT: f32 = 10.0;
+const FEATURE_DENSITY_STRIP_OFFSET: f32 = 18.0;
+const FEATURE_DENSITY_MIN_VIEW_SPAN_BP: usize = 100_000;
 const METHYLATION_TICK: f32 = 10.0;
 const RE_LABEL_BASE_OFFSET: f32 = 76.0;
 const RE_SITE_MAX_BP_PER_PX: f32 = 200.0;
@@ -88,6 +91,8 @@ const CONSTRUCT_REASONING_TRACK_MARGIN: f32 = 14.0;
 const CONSTRUCT_REASONING_TRACK_GAP: f32 = 10.0;
 const CONSTRUCT_REASONING_LABEL_FONT_SIZE: f32 = 8.5;
 const CONSTRUCT_REASONING_LABEL_MAX_BP_PER_PX: f32 = 20.0;
+const INTRON_BREAK_WIDTH_PX: f32 = 14.0;
+const INTRON_BREAK_MAX_WIDTH_FRACTION: f32 = 0.5;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct LinearViewport {
@@ -96,6 +101,15 @@ struct LinearViewport {
     span: usize,
 }
 
+/// A collapsed stretch `[from, to)` drawn as a fixed-width break at `x1..x2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct IntronBreakGlyph {
+    from: usize,
+    to: usize,
+    x1: f32,
+    x2: f32,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct LinearDetailLevel {
     show_feature_labels: bool,
@@ -152,6 +166,20 @@ impl FeaturePosition {
     }
 }
 
+/// Summed feature coverage of one heatmap window; `to` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FeatureDensityBin {
+    from: usize,
+    to: usize,
+    coverage_bp: usize,
+}
+
+impl FeatureDensityBin {
+    fn mean_depth(&self) -> f32 {
+        self.coverage_bp as f32 / self.to.saturating_sub(self.from).max(1) as f32
+    }
+}
+
 #[derive(Debug, Clone)]
 struct ConstructReasoningOverlayPosition {
     evidence_id: String,
@@ -245,6 +273,8 @@ pub struct RenderDnaLinear {
     area: Rect,
     baseline_y: f32,
     features: Vec<FeaturePosition>,
+    feature_density_bins: Vec<FeatureDensityBin>,
+    collapsed_ranges: Vec<(usize, usize)>,
     restriction_enzyme_sites: Vec<RestrictionEnzymePosition>,
     selected_feature_number: Option<usize>,
     selected_enzyme: Option<RestrictionEnzymePosition>,
@@ -333,6 +363,8 @@ impl RenderDnaLinear {
             area: Rect::NOTHING,
             baseline_y: 0.0,
             features: vec![],
+            feature_density_bins: vec![],
+            collapsed_ranges: vec![],
             restriction_enzyme_sites: vec![],
             selected_feature_number: None,
             selected_enzyme: None,
@@ -496,6 +528,9 @@ impl RenderDnaLinear {
         if viewport.span == 0 {
             return self.area.left();
         }
+        if !self.collapsed_ranges.is_empty() {
+            return self.collapsed_bp_to_x(bp, viewport);
+        }
         let frac = (bp.saturating_sub(viewport.start)) as f32 / viewport.span as f32;
         self.area.left() + frac * self.area.width()
     }
@@ -504,11 +539,138 @@ impl RenderDnaLinear {
         if viewport.span == 0 {
             return 0;
         }
+        if !self.collapsed_ranges.is_empty() {
+            return self.collapsed_x_to_bp(x, viewport);
+        }
         let frac = ((x - self.area.left()) / self.area.width().max(1.0)).clamp(0.0, 1.0);
         let bp = viewport.start + (frac * viewport.span as f32).floor() as usize;
         bp.min(viewport.end.saturating_sub(1))
     }
 
+    /// Pixels per retained base when `collapsed_ranges` are drawn as breaks.
+    fn collapsed_px_per_bp(&self, viewport: LinearViewport) -> f32 {
+        let collapsed_bp: usize = self
+            .collapsed_ranges
+            .iter()
+            .map(|(from, to)| to - from)
+            .sum();
+        let kept_bp = viewport.span.saturating_sub(collapsed_bp).max(1);
+        let kept_px =
+            self.area.width() - self.collapsed_ranges.len() as f32 * INTRON_BREAK_WIDTH_PX;
+        kept_px.max(1.0) / kept_bp as f32
+    }
+
+    fn collapsed_bp_to_x(&self, bp: usize, viewport: LinearViewport) -> f32 {
+        let bp = bp.max(viewport.start);
+        let px_per_bp = self.collapsed_px_per_bp(viewport);
+        let mut x = self.area.left();
+        let mut cursor = viewport.start;
+        for &(from, to) in &self.collapsed_ranges {
+            if bp <= from {
+                break;
+            }
+            x += from.saturating_sub(cursor) as f32 * px_per_bp;
+            if bp < to {
+                return x + (bp - from) as f32 / (to - from) as f32 * INTRON_BREAK_WIDTH_PX;
+            }
+            x += INTRON_BREAK_WIDTH_PX;
+            cursor = to;
+        }
+        x + bp.saturating_sub(cursor) as f32 * px_per_bp
+    }
+
+    fn collapsed_x_to_bp(&self, x: f32, viewport: LinearViewport) -> usize {
+        let px_per_bp = self.collapsed_px_per_bp(viewport);
+        let last_bp = viewport.end.saturating_sub(1);
+        let mut left = self.area.left();
+        let mut cursor = viewport.start;
+        for &(from, to) in &self.collapsed_ranges {
+            let break_x = left + from.saturating_sub(cursor) as f32 * px_per_bp;
+            if x < break_x {
+                break;
+            }
+            if x < break_x + INTRON_BREAK_WIDTH_PX {
+                let frac = (x - break_x) / INTRON_BREAK_WIDTH_PX;
+                let bp = from + (frac * (to - from) as f32).floor() as usize;
+                return bp.min(last_bp);
+            }
+            left = break_x + INTRON_BREAK_WIDTH_PX;
+            cursor = to;
+        }
+        let bp = cursor + ((x - left).max(0.0) / px_per_bp).floor() as usize;
+        bp.min(last_bp)
+    }
+
+    /// Feature-free stretches of the viewport longer than
+    /// `collapse_introns_over_bp`. `gene` and `source` spans do not count as
+    /// coverage, so introns between exon segments qualify. Empty when the
+    /// setting is off, bases are drawn, or no feature is in view; if the
+    /// breaks would take more than half the width only the longest are kept.
+    fn intron_collapse_ranges(&self, viewport: LinearViewport) -> Vec<(usize, usize)> {
+        let threshold = self
+            .display
+            .read()
+            .map(|display| display.collapse_introns_over_bp())
+            .unwrap_or(0);
+        if threshold == 0 || viewport.span == 0 || self.should_draw_sequence_bases(viewport) {
+            return vec![];
+        }
+        let mut ranges = vec![];
+        if let Ok(dna) = self.dna.read() {
+            for feature in dna.features() {
+                let kind = feature.kind.to_string();
+                if kind.eq_ignore_ascii_case("gene") || kind.eq_ignore_ascii_case("source") {
+                    continue;
+                }
+                collect_location_ranges_usize(&feature.location, &mut ranges);
+            }
+        }
+        let mut covered = ranges
+            .into_iter()
+            .filter_map(|(start, end)| {
+                Self::range_overlap(start, end, viewport.start, viewport.end)
+            })
+            .collect::<Vec<_>>();
+        if covered.is_empty() {
+            return vec![];
+        }
+        covered.sort_unstable();
+        let mut gaps = vec![];
+        let mut cursor = viewport.start;
+        for (start, end) in covered
+            .into_iter()
+            .chain(std::iter::once((viewport.end, viewport.end)))
+        {
+            if start > cursor && start - cursor > threshold {
+                gaps.push((cursor, start));
+            }
+            cursor = cursor.max(end);
+        }
+        let max_breaks =
+            (self.area.width() * INTRON_BREAK_MAX_WIDTH_FRACTION / INTRON_BREAK_WIDTH_PX) as usize;
+        if gaps.len() > max_breaks {
+            gaps.sort_by_key(|&(from, to)| (std::cmp::Reverse(to - from), from));
+            gaps.truncate(max_breaks);
+            gaps.sort_unstable();
+        }
+        gaps
+    }
+
+    fn intron_break_glyphs(&self, viewport: LinearViewport) -> Vec<IntronBreakGlyph> {
+        self.collapsed_ranges
+            .iter()
+            .map(|&(from, to)| {
+                let x1 = self.bp_to_x(from, viewport);
+                IntronBreakGlyph {
+                    from,
+                    to,
+                    x1,
+                    x2: x1 + INTRON_BREAK_WIDTH_PX,
+                }
+            })
+            .collect()
+    }
+
     fn normalize_pos(&self, pos: isize) -> usize {
         if self.sequence_length == 0 {
             return 0;
@@ -528,6 +690,50 @@ impl RenderDnaLinear {
         (start < end).then_some((start, end))
     }
 
+    /// Bin `ranges` (0-based, end-exclusive) into `bin_bp` windows aligned to
+    /// multiples of `bin_bp`, clipped to the viewport.
+    fn feature_density_bins(
+        ranges: &[(usize, usize)],
+        viewport: LinearViewport,
+        bin_bp: usize,
+    ) -> Vec<FeatureDensityBin> {
+        let bin_bp = bin_bp.max(1);
+        if viewport.end <= viewport.start {
+            return vec![];
+        }
+        let first_bin = viewport.start / bin_bp;
+        let mut bins: Vec<FeatureDensityBin> = (first_bin..viewport.end.div_ceil(bin_bp))
+            .map(|idx| FeatureDensityBin {
+                from: (idx * bin_bp).max(viewport.start),
+                to: ((idx + 1) * bin_bp).min(viewport.end),
+                coverage_bp: 0,
+            })
+            .collect();
+        for &(start, end) in ranges {
+            let Some((start, end)) = Self::range_overlap(start, end, viewport.start, viewport.end)
+            else {
+                continue;
+            };
+            for bin in &mut bins[start / bin_bp - first_bin..=(end - 1) / bin_bp - first_bin] {
+                if let Some((from, to)) = Self::range_overlap(start, end, bin.from, bin.to) {
+                    bin.coverage_bp += to - from;
+                }
+            }
+        }
+        bins
+    }
+
+    fn feature_density_active(&self, viewport: LinearViewport) -> bool {
+        viewport.span > FEATURE_DENSITY_MIN_VIEW_SPAN_BP
+            && self
+                .display
+                .read()
+                .map(|display| {
+                    display.show_features() && display.show_feature_density_when_zoomed_out()
+                })
+                .unwrap_or(false)
+    }
+
     fn bp_per_px(&self, viewport: LinearViewport) -> f32 {
         if viewport.span == 0 {
             return 0.0;
@@ -985,10 +1191,17 @@ impl RenderDnaLinear {
 
     fn layout_features(&mut self, viewport: LinearViewport) {
         self.features.clear();
+        self.feature_density_bins.clear();
+        self.collapsed_ranges.clear();
         self.baseline_y = self.area.center().y;
         if self.sequence_length == 0 {
             return;
         }
+        self.collapsed_ranges = self.intron_collapse_ranges(viewport);
+        // Wide views collect feature coverage for the heatmap strip instead of
+        // laying out individual glyphs.
+        let feature_density_active = self.feature_density_active(viewport);
+        let mut feature_density_ranges: Vec<(usize, usize)> = vec![];
         let vertical_offset_px = self.linear_view_vertical_offset_px();
         let bp_per_px = self.bp_per_px(viewport);
         let low_value_feature_min_width_px = Self::low_value_feature_min_width_px(bp_per_px);
@@ -1077,6 +1290,11 @@ impl RenderDnaLinear {
                 50_000,
                 BTreeSet::new(),
             ));
+        let feature_color_overrides: BTreeMap<String, String> = self
+            .display
+            .read()
+            .map(|display| display.feature_color_overrides().clone())
+            .unwrap_or_default();
         let features = self
             .dna
             .read()
@@ -1128,6 +1346,14 @@ impl RenderDnaLinear {
             if to <= from {
                 continue;
             }
+            if feature_density_active {
+                let before = feature_density_ranges.len();
+                collect_location_ranges_usize(&feature.location, &mut feature_density_ranges);
+                if feature_density_ranges.len() == before {
+                    feature_density_ranges.push((from, to));
+                }
+                continue;
+            }
 
             let label = RenderDna::feature_name(feature);
             let is_mcs = RenderDna::is_mcs_feature(feature);
@@ -1265,7 +1491,7 @@ impl RenderDnaLinear {
                 x2,
                 label,
                 kind_upper: kind.clone(),
-                color: RenderDna::feature_color(feature),
+                color: RenderDna::feature_color_with_overrides(feature, &feature_color_overrides),
                 is_pointy: RenderDna::is_feature_pointy(feature),
                 is_reverse: feature_is_reverse(feature),
                 is_regulatory: RenderDna::is_regulatory_feature(feature) || is_array_track,
@@ -1276,6 +1502,16 @@ impl RenderDnaLinear {
             });
         }
 
+        if feature_density_active {
+            let bin_bp = self
+                .display
+                .read()
+                .map(|display| display.feature_density_bin_bp())
+                .unwrap_or(1_000);
+            self.feature_density_bins =
+                Self::feature_density_bins(&feature_density_ranges, viewport, bin_bp);
+        }
+
         let mut feature_lanes_top: Vec<f32> = vec![];
         let mut feature_lanes_bottom: Vec<f32> = vec![];
         let mut feature_lanes_regulatory_top: Vec<f32> = vec![];
@@ -1813,7 +2049,12 @@ impl RenderDnaLinear {
         let show_sequence_bases = self.should_draw_sequence_bases(viewport);
         let draw_tick_marks = !show_sequence_bases || self.should_draw_backbone(viewport);
         let y = self.baseline_y();
-        let tick = Self::tick_step(viewport.span);
+        let collapsed_bp: usize = self
+            .collapsed_ranges
+            .iter()
+            .map(|(from, to)| to - from)
+            .sum();
+        let tick = Self::tick_step(viewport.span.saturating_sub(collapsed_bp).max(1));
         let font = FontId {
             size: 9.0,
             family: FontFamily::Monospace,
@@ -1825,6 +2066,14 @@ impl RenderDnaLinear {
             ((viewport.start / tick) + 1) * tick
         };
         while pos < viewport.end {
+            if let Some(&(_, to)) = self
+                .collapsed_ranges
+                .iter()
+                .find(|(from, to)| (*from..*to).contains(&pos))
+            {
+                pos = to.div_ceil(tick) * tick;
+                continue;
+            }
             let x = self.bp_to_x(pos, viewport);
             if draw_tick_marks {
                 painter.line_segment(
@@ -1845,6 +2094,44 @@ impl RenderDnaLinear {
         }
     }
 
+    /// Break mark over the backbone for each collapsed stretch, labelled with
+    /// the true coordinates on either side.
+    fn draw_intron_breaks(&self, painter: &egui::Painter, viewport: LinearViewport) {
+        let y = self.baseline_y();
+        let font = FontId {
+            size: 9.0,
+            family: FontFamily::Monospace,
+        };
+        let stroke = Stroke::new(1.5_f32, Color32::DARK_GRAY);
+        for glyph in self.intron_break_glyphs(viewport) {
+            painter.rect_filled(
+                Rect::from_min_max(
+                    Pos2::new(glyph.x1, y - BASELINE_STROKE),
+                    Pos2::new(glyph.x2, y + BASELINE_STROKE),
+                ),
+                0.0,
+                Color32::WHITE,
+            );
+            for x in [glyph.x1 + 3.0, glyph.x2 - 7.0] {
+                painter.line_segment([Pos2::new(x, y + 6.0), Pos2::new(x + 4.0, y - 6.0)], stroke);
+            }
+            painter.text(
+                Pos2::new(glyph.x1, y - 8.0),
+                Align2::RIGHT_BOTTOM,
+                glyph.from.to_string(),
+                font.clone(),
+                Color32::DARK_GRAY,
+            );
+            painter.text(
+                Pos2::new(glyph.x2, y - 8.0),
+                Align2::LEFT_BOTTOM,
+                glyph.to.to_string(),
+                font.clone(),
+                Color32::DARK_GRAY,
+            );
+        }
+    }
+
     fn should_draw_sequence_bases(&self, viewport: LinearViewport) -> bool {
         self.sequence_base_render_status(viewport).bases_visible()
     }
@@ -2532,6 +2819,39 @@ impl RenderDnaLinear {
         }
     }
 
+    fn draw_feature_density(&self, painter: &egui::Painter, viewport: LinearViewport) {
+        let max_depth = self
+            .feature_density_bins
+            .iter()
+            .map(FeatureDensityBin::mean_depth)
+            .fold(0.0_f32, f32::max);
+        if max_depth <= 0.0 {
+            return;
+        }
+        let y2 = self.baseline_y() - FEATURE_DENSITY_STRIP_OFFSET;
+        let y1 = y2 - FEATURE_DENSITY_STRIP_HEIGHT;
+        for bin in &self.feature_density_bins {
+            let x1 = self.bp_to_x(bin.from, viewport);
+            let x2 = self.bp_to_x(bin.to, viewport).max(x1 + 1.0);
+            let intensity = (bin.mean_depth() / max_depth).clamp(0.0, 1.0);
+            let shade = 255 - (intensity * 200.0) as u8;
+            painter.rect_filled(
+                Rect::from_min_max(Pos2::new(x1, y1), Pos2::new(x2, y2)),
+                0.0,
+                Color32::from_rgb(shade, shade, 255),
+            );
+        }
+        painter.rect_stroke(
+            Rect::from_min_max(
+                Pos2::new(self.bp_to_x(viewport.start, viewport), y1),
+                Pos2::new(self.bp_to_x(viewport.end, viewport), y2),
+            ),
+            0.0,
+            Stroke::new(1.0, Color32::GRAY),
+            StrokeKind::Inside,
+        );
+    }
+
     fn can_reuse_cached_gc_contents(
         gc_content_bin_size_bp: usize,
         cached_regions_empty: bool,
@@ -3156,10 +3476,12 @@ impl RenderDnaLinear {
         self.draw_methylation_sites(&painter, viewport, detail);
         self.draw_backbone(&painter, viewport);
         self.draw_bp_ticks(&painter, viewport);
+        self.draw_intron_breaks(&painter, viewport);
         self.draw_sequence_bases(&painter, viewport);
         self.draw_open_reading_frames(&painter, viewport, detail);
         self.draw_construct_reasoning_overlay(&painter, viewport);
         self.draw_features(&painter, detail);
+        self.draw_feature_density(&painter, viewport);
         self.draw_restriction_enzyme_sites(&painter, viewport, detail);
     }
 }
@@ -3301,6 +3623,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn feature_density_heatmap_replaces_glyphs_above_span_threshold() {
+        let features = vec![
+            make_test_feature_with_kind("gene", Location::simple_range(0, 1_000)),
+            make_test_feature_with_kind("gene", Location::simple_range(500, 1_500)),
+            make_test_feature_with_kind("gene", Location::simple_range(150_000, 150_400)),
+        ];
+        let mut renderer = test_renderer_with_features(features, 200_000);
+        {
+            let mut display = renderer.display.write().expect("display");
+            display.set_show_feature_density_when_zoomed_out(true);
+            display.set_feature_density_bin_bp(1_000);
+        }
+
+        let wide = LinearViewport {
+            start: 0,
+            end: 200_000,
+            span: 200_000,
+        };
+        renderer.layout_features(wide);
+        assert!(renderer.features.is_empty());
+        assert_eq!(renderer.feature_density_bins.len(), 200);
+        assert_eq!(renderer.feature_density_bins[0].coverage_bp, 1_500);
+        assert_eq!(renderer.feature_density_bins[1].coverage_bp, 500);
+        assert_eq!(renderer.feature_density_bins[2].coverage_bp, 0);
+        assert_eq!(renderer.feature_density_bins[150].coverage_bp, 400);
+
+        let narrow = LinearViewport {
+            start: 0,
+            end: 20_000,
+            span: 20_000,
+        };
+        renderer.layout_features(narrow);
+        assert!(renderer.feature_density_bins.is_empty());
+        assert_eq!(renderer.features.len(), 2);
+
+        renderer
+            .display
+            .write()
+            .expect("display")
+            .set_show_feature_density_when_zoomed_out(false);
+        renderer.layout_features(wide);
+        assert!(renderer.feature_density_bins.is_empty());
+        assert_eq!(renderer.features.len(), 3);
+    }
+
+    #[test]
+    fn intron_collapse_keeps_exons_proportional_and_adds_break_glyph() {
+        let transcript = make_test_feature(Location::Join(vec![
+            Location::simple_range(100, 200),
+            Location::simple_range(20_200, 20_500),
+        ]));
+        let mut renderer = test_renderer_with_feature(transcript, 20_600);
+        renderer
+            .display
+            .write()
+            .expect("display")
+            .set_collapse_introns_over_bp(1_000);
+        let viewport = LinearViewport {
+            start: 0,
+            end: 20_600,
+            span: 20_600,
+        };
+        renderer.layout_features(viewport);
+
+        assert_eq!(renderer.collapsed_ranges, vec![(200, 20_200)]);
+        let glyphs = renderer.intron_break_glyphs(viewport);
+        assert_eq!(glyphs.len(), 1);
+        assert_eq!((glyphs[0].from, glyphs[0].to), (200, 20_200));
+        assert!((glyphs[0].x2 - glyphs[0].x1 - INTRON_BREAK_WIDTH_PX).abs() < 0.01);
+
+        let exon_rects = &renderer.features[0].exon_rects;
+        assert_eq!(exon_rects.len(), 2);
+        assert!(exon_rects[0].right() <= glyphs[0].x1 + 0.01);
+        assert!(exon_rects[1].left() >= glyphs[0].x2 - 0.01);
+        let ratio = exon_rects[1].width() / exon_rects[0].width();
+        assert!((ratio - 3.0).abs() < 0.05, "exon width ratio {ratio}");
+        let px_per_bp = (1200.0 - INTRON_BREAK_WIDTH_PX) / 600.0;
+        assert!((exon_rects[0].width() - 100.0 * px_per_bp).abs() < 0.5);
+
+        // Positions right of the break map back to their true coordinates.
+        let x = renderer.bp_to_x(20_300, viewport);
+        assert_eq!(renderer.x_to_bp(x + 0.01, viewport), 20_300);
+
+        renderer
+            .display
+            .write()
+            .expect("display")
+            .set_collapse_introns_over_bp(0);
+        renderer.layout_features(viewport);
+        assert!(renderer.collapsed_ranges.is_empty());
+        assert!(renderer.intron_break_glyphs(viewport).is_empty());
+    }
+
     #[test]
     fn visible_feature_bounds_are_available_after_layout() {
         let feature = make_test_feature(Location::simple_range(150, 280));
@@ -3,7 +3,8 @@
 use crate::{
     dna_display::DnaDisplay,
     dna_sequence::DNAsequence,
-    engine::DisplaySettings,
+    engine::{DisplaySettings, RenderSvgMode},
+    feature_color::{assign_feature_color, color_hex, feature_color_override},
     feature_location::{collect_location_ranges_usize, feature_is_reverse},
     gc_contents::GcContents,
     repeat_features::{is_repeat_feature, repeat_feature_display},
@@ -66,7 +67,7 @@ struct FeatureVm {
     to: usize,
     label: String,
     legend_line: Option<String>,
-    color: &'static str,
+    color: String,
     kind_attr: String,
     kind_role: FeatureKindRole,
     is_gene: bool,
@@ -80,6 +81,20 @@ struct FeatureVm {
     prefers_functional_host_anchor: bool,
 }
 
+/// One feature as drawn by `export_linear_svg` / `export_circular_svg`, so a
+/// separately exported legend stays in sync with the image.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapLegendEntry {
+    pub name: String,
+    /// Same token as the SVG `data-gentle-feature-kind` attribute.
+    pub kind: String,
+    pub start_1based: usize,
+    pub end_1based: usize,
+    pub is_reverse: bool,
+    /// SVG fill/stroke color (`#rrggbb`).
+    pub color: String,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum FeatureKindRole {
     Other,
@@ -497,7 +512,16 @@ fn circular_title_text(dna: &DNAsequence) -> String {
     }
 }
 
-fn feature_color(feature: &Feature) -> &'static str {
+/// Color the SVG exports assign to `feature`, with user overrides from
+/// `DisplaySettings::feature_color_overrides` applied first.
+pub fn feature_color(feature: &Feature, display: &DisplaySettings) -> String {
+    if let Some(color) = feature_color_override(&display.feature_color_overrides, feature) {
+        return color_hex(color);
+    }
+    builtin_feature_color(feature)
+}
+
+fn builtin_feature_color(feature: &Feature) -> String {
     if is_vcf_track_feature(feature) {
         let class = vcf_variant_class(feature)
             .unwrap_or_else(|| "OTHER".to_string())
@@ -508,31 +532,33 @@ fn feature_color(feature: &Feature) -> &'static str {
             "DEL" => "#b42d2d",
             "SV" => "#5a5a1e",
             _ => "#5a5a5a",
-        };
+        }
+        .to_string();
     }
     if let Some(repeat) = repeat_feature_display(feature) {
-        return repeat.class.color_hex();
+        return repeat.class.color_hex().to_string();
     }
     if is_regulatory_feature(feature) {
         let regulatory_class = feature_qualifier_text(feature, "regulatory_class")
             .unwrap_or_default()
             .to_ascii_lowercase();
         if regulatory_class.contains("silencer") || regulatory_class.contains("repressor") {
-            return "#be3232";
+            return "#be3232".to_string();
         }
         if regulatory_class.contains("enhancer") || regulatory_class.contains("activator") {
-            return "#2d9641";
+            return "#2d9641".to_string();
         }
     }
-    match feature.kind.to_string().to_ascii_uppercase().as_str() {
+    let color = match feature.kind.to_string().to_ascii_uppercase().as_str() {
         "CDS" => "#1f4fcc",
         "GENE" => "#1f4fcc",
         "MRNA" => "#b4640a",
         "PROMOTER" => "#43aaa1",
         "VARIATION" => "#e17f0f",
         "TFBS" | "TF_BINDING_SITE" | "PROTEIN_BIND" => "#238023",
-        _ => "#6e6e6e",
-    }
+        _ => return color_hex(assign_feature_color(feature)),
+    };
+    color.to_string()
 }
 
 fn feature_pointy(feature: &Feature) -> bool {
@@ -965,7 +991,7 @@ fn collect_features(
             to,
             label,
             legend_line,
-            color: feature_color(feature),
+            color: feature_color(feature, display),
             kind_attr: svg_attr_token(&kind),
             kind_role: feature_kind_role(feature),
             is_gene: kind == "GENE",
@@ -1126,7 +1152,7 @@ fn linear_transcription_direction_glyph(
     let tick = Path::new()
         .set("d", shaft)
         .set("fill", "none")
-        .set("stroke", feature.color)
+        .set("stroke", feature.color.clone())
         .set("stroke-width", 2)
         .set("stroke-linecap", "round")
         .set("stroke-linejoin", "round")
@@ -1150,7 +1176,7 @@ fn linear_transcription_direction_glyph(
         .close();
     let arrow = Path::new()
         .set("d", tri)
-        .set("fill", feature.color)
+        .set("fill", feature.color.clone())
         .set("stroke", "none")
         .set("data-gentle-role", "linear-transcription-start-arrow")
         .set("data-gentle-feature-kind", feature.kind_attr.as_str());
@@ -1616,7 +1642,7 @@ pub fn export_linear_svg(dna: &DNAsequence, display: &DisplaySettings) -> String
                         .set("y1", baseline - VARIATION_MARKER_OVERSHOOT_PX)
                         .set("x2", x)
                         .set("y2", baseline + VARIATION_MARKER_OVERSHOOT_PX)
-                        .set("stroke", f.color)
+                        .set("stroke", f.color.clone())
                         .set("stroke-width", VARIATION_MARKER_STROKE_WIDTH)
                         .set("data-gentle-role", "variation-marker-line")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -1627,7 +1653,7 @@ pub fn export_linear_svg(dna: &DNAsequence, display: &DisplaySettings) -> String
                         .set("cy", baseline)
                         .set("r", VARIATION_MARKER_RADIUS)
                         .set("fill", "#ffffff")
-                        .set("stroke", f.color)
+                        .set("stroke", f.color.clone())
                         .set("stroke-width", 1.5)
                         .set("data-gentle-role", "variation-marker-dot")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -1685,7 +1711,7 @@ pub fn export_linear_svg(dna: &DNAsequence, display: &DisplaySettings) -> String
                     .set("y", y - half_height)
                     .set("width", x2 - x1)
                     .set("height", block_height)
-                    .set("fill", f.color)
+                    .set("fill", f.color.clone())
                     .set("data-gentle-role", "feature-block")
                     .set("data-gentle-feature-kind", f.kind_attr.as_str()),
             );
@@ -1708,7 +1734,7 @@ pub fn export_linear_svg(dna: &DNAsequence, display: &DisplaySettings) -> String
                 doc = doc.add(
                     Path::new()
                         .set("d", data)
-                        .set("fill", f.color)
+                        .set("fill", f.color.clone())
                         .set("data-gentle-role", "feature-direction-head")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
                 );
@@ -2421,7 +2447,7 @@ fn append_circular_narrative_legend(mut doc: Document, features: &[FeatureVm]) -
                 .set("y", y - 9.0)
                 .set("width", 10)
                 .set("height", 10)
-                .set("fill", feature.color),
+                .set("fill", feature.color.clone()),
         );
         for (idx, chunk) in wrapped.iter().enumerate() {
             doc = doc.add(
@@ -2438,6 +2464,33 @@ fn append_circular_narrative_legend(mut doc: Document, features: &[FeatureVm]) -
     doc
 }
 
+/// Features the SVG export for `mode` draws, in drawing order.
+pub fn map_legend_entries(
+    dna: &DNAsequence,
+    display: &DisplaySettings,
+    mode: RenderSvgMode,
+) -> Vec<MapLegendEntry> {
+    if !display.show_features {
+        return vec![];
+    }
+    let viewport = normalize_linear_export_viewport(dna, display);
+    let view_span_bp = match mode {
+        RenderSvgMode::Linear => viewport.span_bp,
+        RenderSvgMode::Circular => dna.len(),
+    };
+    collect_features(dna, display, view_span_bp, viewport)
+        .into_iter()
+        .map(|feature| MapLegendEntry {
+            name: feature.label,
+            kind: feature.kind_attr,
+            start_1based: feature.from + 1,
+            end_1based: feature.to.max(feature.from + 1),
+            is_reverse: feature.is_reverse,
+            color: feature.color,
+        })
+        .collect()
+}
+
 pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> String {
     let len = dna.len();
     let cx = W * 0.56;
@@ -2566,7 +2619,7 @@ pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> Stri
                         .set("y1", inner_y)
                         .set("x2", outer_x)
                         .set("y2", outer_y)
-                        .set("stroke", f.color)
+                        .set("stroke", f.color.clone())
                         .set("stroke-width", CIRCULAR_VARIATION_MARKER_STROKE_WIDTH)
                         .set("data-gentle-role", "variation-marker-line")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -2577,7 +2630,7 @@ pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> Stri
                         .set("cy", outer_y)
                         .set("r", CIRCULAR_VARIATION_MARKER_RADIUS)
                         .set("fill", "#ffffff")
-                        .set("stroke", f.color)
+                        .set("stroke", f.color.clone())
                         .set("stroke-width", 2)
                         .set("data-gentle-role", "variation-marker-dot")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -2602,7 +2655,7 @@ pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> Stri
                     Path::new()
                         .set("d", path_d)
                         .set("fill", "none")
-                        .set("stroke", f.color)
+                        .set("stroke", f.color.clone())
                         .set("stroke-width", CIRCULAR_FEATURE_STROKE_WIDTH)
                         .set("data-gentle-role", "feature-block")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -2626,7 +2679,7 @@ pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> Stri
                     Path::new()
                         .set("d", shaft)
                         .set("fill", "none")
-                        .set("stroke", f.color)
+                        .set("stroke", f.color.clone())
                         .set("stroke-width", 2)
                         .set("stroke-linecap", "round")
                         .set("stroke-linejoin", "round")
@@ -2651,7 +2704,7 @@ pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> Stri
                 doc = doc.add(
                     Path::new()
                         .set("d", tri)
-                        .set("fill", f.color)
+                        .set("fill", f.color.clone())
                         .set("stroke", "none")
                         .set("data-gentle-role", "transcription-start-arrow")
                         .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -2673,7 +2726,7 @@ pub fn export_circular_svg(dna: &DNAsequence, display: &DisplaySettings) -> Stri
                             .set("y1", leader_start_y)
                             .set("x2", leader_end_x)
                             .set("y2", leader_end_y)
-                            .set("stroke", f.color)
+                            .set("stroke", f.color.clone())
                             .set("stroke-width", 2)
                             .set("data-gentle-role", "functional-annotation-leader")
                             .set("data-gentle-feature-kind", f.kind_attr.as_str()),
@@ -3455,6 +3508,44 @@ mod tests {
         assert_eq!(bla[0].to, 300);
     }
 
+    #[test]
+    fn feature_colors_are_stable_across_renders_and_overrides_win() {
+        let mut dna = DNAsequence::from_sequence(&"ACGT".repeat(100)).expect("valid DNA");
+        dna.features_mut().push(gb_io::seq::Feature {
+            kind: "rep_origin".into(),
+            location: Location::simple_range(40, 160),
+            qualifiers: vec![("label".into(), Some("ori".to_string()))],
+        });
+        let ori = dna.features()[0].clone();
+        let expected = color_hex(assign_feature_color(&ori));
+
+        let first = map_legend_entries(&dna, &DisplaySettings::default(), RenderSvgMode::Circular);
+        let second = map_legend_entries(&dna, &DisplaySettings::default(), RenderSvgMode::Linear);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].color, expected);
+        assert_eq!(second[0].color, expected);
+        assert_eq!(
+            color_hex(crate::render_dna::RenderDna::feature_color(&ori)),
+            expected
+        );
+        assert!(export_circular_svg(&dna, &DisplaySettings::default()).contains(&expected));
+
+        let mut display = DisplaySettings::default();
+        display
+            .feature_color_overrides
+            .insert("rep_origin:ori".to_string(), "#123456".to_string());
+        let overridden = map_legend_entries(&dna, &display, RenderSvgMode::Circular);
+        assert_eq!(overridden[0].color, "#123456");
+        assert!(export_linear_svg(&dna, &display).contains("#123456"));
+        assert_eq!(
+            crate::render_dna::RenderDna::feature_color_with_overrides(
+                &ori,
+                &display.feature_color_overrides
+            ),
+            eframe::egui::Color32::from_rgb(0x12, 0x34, 0x56)
+        );
+    }
+
     #[test]
     fn circular_svg_functional_misc_feature_uses_host_anchor_leader() {
         let mut dna = DNAsequence::from_sequence(&"ATGC".repeat(200)).expect("sequence");
@@ -1,9 +1,17 @@
 //! Restriction-enzyme site model and cut geometry utilities.
 
-use crate::dna_sequence::DNAsequence;
+use crate::{dna_sequence::DNAsequence, iupac_code::IupacCode};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// End type of a cut, without the overhang length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RestrictionEndKind {
+    Blunt,
+    FivePrimeOverhang,
+    ThreePrimeOverhang,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RestrictionEndGeometry {
     Blunt,
@@ -12,6 +20,14 @@ pub enum RestrictionEndGeometry {
 }
 
 impl RestrictionEndGeometry {
+    pub fn kind(self) -> RestrictionEndKind {
+        match self {
+            Self::Blunt => RestrictionEndKind::Blunt,
+            Self::FivePrimeOverhang(_) => RestrictionEndKind::FivePrimeOverhang,
+            Self::ThreePrimeOverhang(_) => RestrictionEndKind::ThreePrimeOverhang,
+        }
+    }
+
     pub fn kind_label(self) -> &'static str {
         match self {
             Self::Blunt => "blunt",
@@ -143,7 +159,7 @@ pub struct RestrictionEnzymeSite {
 
 impl RestrictionEnzyme {
     pub fn check_palimdromic(&mut self) {
-        self.is_palindromic = self.sequence == self.get_sequence_rc();
+        self.is_palindromic = self.sequence.eq_ignore_ascii_case(&self.get_sequence_rc());
     }
 
     #[inline(always)]
@@ -151,55 +167,78 @@ impl RestrictionEnzyme {
         self.is_palindromic
     }
 
+    /// IUPAC-aware reverse complement of the recognition sequence.
     fn get_sequence_rc(&self) -> String {
-        // TODO cache this?
-        let rc = self.sequence.as_bytes();
-        let rc = match std::str::from_utf8(rc) {
-            Ok(rc) => rc,
-            Err(_) => panic!("RestrictionEnzyme::check_palimdromic: non-utf8 char"),
-        };
-        rc.to_string()
+        self.sequence
+            .bytes()
+            .rev()
+            .map(|letter| IupacCode::from_letter(letter).complement().to_letter() as char)
+            .collect()
     }
 
     pub fn get_sites(
         &self,
         seq: &DNAsequence,
         max_sites: Option<usize>,
+    ) -> Vec<RestrictionEnzymeSite> {
+        let ret = self.get_sites_at(seq, 0..seq.len());
+        if let Some(max) = max_sites
+            && max < ret.len()
+        {
+            return vec![];
+        }
+        ret
+    }
+
+    /// Whether `bases` (same length as the recognition sequence) are a site.
+    /// Degenerate recognition letters accept each base they stand for; an
+    /// ambiguous base in `bases` matches only when every base it could be is
+    /// accepted, so `N` in the sequence matches only `N` in the site.
+    fn recognizes(recognition: &[IupacCode], bases: &[u8]) -> bool {
+        recognition.iter().zip(bases).all(|(site, base)| {
+            let base = IupacCode::from_letter(*base);
+            !base.is_empty() && base.subset(*site) == base
+        })
+    }
+
+    /// Sites whose recognition sequence starts at one of `starts`, in the
+    /// given order. Starts that cannot hold a full recognition sequence
+    /// (past the end of a linear molecule) are skipped.
+    pub fn get_sites_at(
+        &self,
+        seq: &DNAsequence,
+        starts: impl IntoIterator<Item = usize>,
     ) -> Vec<RestrictionEnzymeSite> {
         // TODO reverse-complement if required
         let mut ret = vec![];
-        let recognition_len = self.sequence.len();
-        let seq_len = if seq.is_circular() {
-            seq.len()
-        } else {
-            if seq.len() < recognition_len {
-                return ret;
+        let recognition = self
+            .sequence
+            .bytes()
+            .map(IupacCode::from_letter)
+            .collect::<Vec<_>>();
+        let recognition_len = recognition.len();
+        if recognition_len == 0 || (!seq.is_circular() && seq.len() < recognition_len) {
+            return ret;
+        }
+        for start in starts {
+            if start >= seq.len() || (!seq.is_circular() && start + recognition_len > seq.len()) {
+                continue;
             }
-            seq.len() - recognition_len + 1
-        };
-        for start in 0..seq_len {
             let range = std::ops::Range {
                 start,
                 end: start + recognition_len,
             };
             let s = seq.get_range_safe(range); // Safe for circular
-            if let Some(s) = s {
-                let s = std::str::from_utf8(&s).unwrap().to_uppercase(); // TODO do this once?
-                if s == self.sequence {
-                    // TODO IUPAC
-                    ret.push(RestrictionEnzymeSite {
-                        offset: start as isize,
-                        enzyme: self.to_owned(),
-                        forward_strand: true,
-                    });
-                }
+            if let Some(s) = s
+                && Self::recognizes(&recognition, &s)
+            {
+                ret.push(RestrictionEnzymeSite {
+                    offset: start as isize,
+                    enzyme: self.to_owned(),
+                    forward_strand: true,
+                });
             }
         }
-        if let Some(max) = max_sites
-            && max < ret.len()
-        {
-            return vec![];
-        }
         ret
     }
 
@@ -321,6 +360,52 @@ mod tests {
         assert!(sites[1].forward_strand);
     }
 
+    #[test]
+    fn degenerate_recognition_sites_match_every_constituent_base() {
+        let mk = |name: &str, sequence: &str| {
+            let mut re = RestrictionEnzyme {
+                name: name.to_string(),
+                sequence: sequence.to_string(),
+                note: None,
+                cut: 1,
+                overlap: 1,
+                is_palindromic: false,
+            };
+            re.check_palimdromic();
+            re
+        };
+        let offsets = |re: &RestrictionEnzyme, seq: &str| {
+            re.get_sites(&DNAsequence::from_sequence(seq).unwrap(), None)
+                .iter()
+                .map(|site| site.offset)
+                .collect::<Vec<_>>()
+        };
+
+        // W = A/T: CCAGG and CCTGG cut, CCCGG and an ambiguous CCSGG do not.
+        let bst_ni = mk("BstNI", "CCWGG");
+        assert!(bst_ni.is_palindromic());
+        assert_eq!(
+            offsets(&bst_ni, "CCAGGTTCCTGGAACCCGGACCAGGCCSGG"),
+            vec![0, 7, 20]
+        );
+        // NNN accepts any bases; an N in the sequence still has to sit on an
+        // N of the site.
+        let dra_iii = mk("DraIII", "CACNNNGTG");
+        assert!(dra_iii.is_palindromic());
+        assert_eq!(
+            offsets(&dra_iii, "CACGTAGTGAACACAAAGTGTTCANGTAGTGCACTTTGTGG"),
+            vec![0, 11, 31]
+        );
+        // Y = C/T, R = A/G.
+        let ava_i = mk("AvaI", "CYCGRG");
+        assert!(ava_i.is_palindromic());
+        assert_eq!(offsets(&ava_i, "CTCGAGCCCGGGCTCGGGCACGAG"), vec![0, 6, 12]);
+        // Overlapping sites are all reported.
+        let sau96_i = mk("Sau96I", "GGNCC");
+        assert_eq!(offsets(&sau96_i, "GGACCGGTCCGGGGCC"), vec![0, 5, 11]);
+        assert_eq!(offsets(&sau96_i, "ggtcc"), vec![0]);
+    }
+
     #[test]
     fn recessed_end_offsets_use_midpoint_for_blunt_cutters() {
         let re = RestrictionEnzyme {
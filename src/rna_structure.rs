@@ -26,6 +26,8 @@ pub struct RnaStructureTextReport {
     pub structure: String,
     #[serde(default)]
     pub mfe_kcal_per_mol: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,6 +51,80 @@ pub struct RnaStructureSvgReport {
     pub structure: String,
     #[serde(default)]
     pub mfe_kcal_per_mol: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+}
+
+/// Hard base-pair constraints passed to RNAfold.
+///
+/// Positions are 1-based. Forced pairs must not cross each other and no
+/// position may appear in more than one pair or in both lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RnaStructureConstraints {
+    #[serde(default)]
+    pub forced_pairs: Vec<(usize, usize)>,
+    #[serde(default)]
+    pub unpaired_positions: Vec<usize>,
+}
+
+impl RnaStructureConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.forced_pairs.is_empty() && self.unpaired_positions.is_empty()
+    }
+
+    /// Translate the constraints into RNAfold's dot-bracket constraint line
+    /// (`(`/`)` forced pair, `x` unpaired, `.` unconstrained).
+    pub fn to_rnafold_constraint_line(
+        &self,
+        sequence_length: usize,
+    ) -> Result<String, RnaStructureError> {
+        let invalid = |message: String| RnaStructureError::InvalidConstraint { message };
+        let mut symbols = vec!['.'; sequence_length];
+        let mut claim = |position: usize, symbol: char| -> Result<(), RnaStructureError> {
+            if position == 0 || position > sequence_length {
+                return Err(invalid(format!(
+                    "position {position} is outside 1..={sequence_length}"
+                )));
+            }
+            let slot = &mut symbols[position - 1];
+            if *slot != '.' {
+                return Err(invalid(format!(
+                    "position {position} is constrained more than once"
+                )));
+            }
+            *slot = symbol;
+            Ok(())
+        };
+        let mut pairs = Vec::with_capacity(self.forced_pairs.len());
+        for (left, right) in &self.forced_pairs {
+            let (open, close) = if left <= right {
+                (*left, *right)
+            } else {
+                (*right, *left)
+            };
+            if open == close {
+                return Err(invalid(format!("position {open} cannot pair with itself")));
+            }
+            claim(open, '(')?;
+            claim(close, ')')?;
+            pairs.push((open, close));
+        }
+        for position in &self.unpaired_positions {
+            claim(*position, 'x')?;
+        }
+        for (idx, (a_open, a_close)) in pairs.iter().enumerate() {
+            for (b_open, b_close) in pairs.iter().skip(idx + 1) {
+                let crossing = (a_open < b_open && b_open < a_close && a_close < b_close)
+                    || (b_open < a_open && a_open < b_close && b_close < a_close);
+                if crossing {
+                    return Err(invalid(format!(
+                        "forced pairs ({a_open},{a_close}) and ({b_open},{b_close}) cross"
+                    )));
+                }
+            }
+        }
+        Ok(symbols.into_iter().collect())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +133,12 @@ pub enum RnaStructureError {
         molecule_type: Option<String>,
     },
     EmptySequence,
+    InvalidStructure {
+        message: String,
+    },
+    InvalidConstraint {
+        message: String,
+    },
     ToolNotFound {
         executable: String,
     },
@@ -81,6 +163,12 @@ impl fmt::Display for RnaStructureError {
                 molecule_type
             ),
             Self::EmptySequence => write!(f, "RNA sequence is empty"),
+            Self::InvalidStructure { message } => {
+                write!(f, "Invalid dot-bracket structure: {message}")
+            }
+            Self::InvalidConstraint { message } => {
+                write!(f, "Invalid RNA structure constraint: {message}")
+            }
             Self::ToolNotFound { executable } => write!(
                 f,
                 "Could not find RNA structure executable '{}'. Install RNAfold/rnapkin or set {} / {}",
@@ -245,10 +333,20 @@ fn parse_rnafold_structure(stdout: &str) -> Result<(String, Option<f64>), RnaStr
     Ok((structure, mfe_kcal_per_mol))
 }
 
-fn fold_rna_sequence(sequence: &str) -> Result<RnaFoldResult, RnaStructureError> {
+fn fold_rna_sequence(
+    sequence: &str,
+    constraint: Option<&str>,
+) -> Result<RnaFoldResult, RnaStructureError> {
     let executable = rnafold_executable();
-    let args = vec!["--noPS".to_string()];
-    let input = format!("{sequence}\n");
+    let mut args = vec!["--noPS".to_string()];
+    let input = match constraint {
+        Some(constraint) => {
+            args.push("-C".to_string());
+            args.push("--enforceConstraint".to_string());
+            format!("{sequence}\n{constraint}\n")
+        }
+        None => format!("{sequence}\n"),
+    };
     let output = run_tool(&executable, &args, Some(&input))?;
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -274,9 +372,23 @@ fn fold_rna_sequence(sequence: &str) -> Result<RnaFoldResult, RnaStructureError>
     })
 }
 
-pub fn inspect_text(dna: &DNAsequence) -> Result<RnaStructureTextReport, RnaStructureError> {
+fn resolve_constraint_line(
+    sequence: &str,
+    constraints: Option<&RnaStructureConstraints>,
+) -> Result<Option<String>, RnaStructureError> {
+    constraints
+        .filter(|constraints| !constraints.is_empty())
+        .map(|constraints| constraints.to_rnafold_constraint_line(sequence.chars().count()))
+        .transpose()
+}
+
+pub fn inspect_text(
+    dna: &DNAsequence,
+    constraints: Option<&RnaStructureConstraints>,
+) -> Result<RnaStructureTextReport, RnaStructureError> {
     let sequence = ensure_rna_input(dna)?;
-    let fold = fold_rna_sequence(&sequence)?;
+    let constraint = resolve_constraint_line(&sequence, constraints)?;
+    let fold = fold_rna_sequence(&sequence, constraint.as_deref())?;
 
     Ok(RnaStructureTextReport {
         tool: "RNAfold".to_string(),
@@ -287,14 +399,56 @@ pub fn inspect_text(dna: &DNAsequence) -> Result<RnaStructureTextReport, RnaStru
         stderr: fold.stderr,
         structure: fold.structure,
         mfe_kcal_per_mol: fold.mfe_kcal_per_mol,
+        constraint,
     })
 }
 
-pub fn render_svg(
-    dna: &DNAsequence,
-    output_path: &str,
-) -> Result<RnaStructureSvgReport, RnaStructureError> {
-    let sequence = ensure_rna_input(dna)?;
+/// Validate a dot-bracket structure against the expected sequence length and
+/// return its base pairs as 0-based `(open, close)` index tuples.
+pub fn parse_dot_bracket_pairs(
+    structure: &str,
+    sequence_length: usize,
+) -> Result<Vec<(usize, usize)>, RnaStructureError> {
+    let structure = structure.trim();
+    let length = structure.chars().count();
+    if length != sequence_length {
+        return Err(RnaStructureError::InvalidStructure {
+            message: format!(
+                "structure length {length} does not match sequence length {sequence_length}"
+            ),
+        });
+    }
+    let mut open = vec![];
+    let mut pairs = vec![];
+    for (idx, symbol) in structure.chars().enumerate() {
+        match symbol {
+            '.' => {}
+            '(' => open.push(idx),
+            ')' => {
+                let Some(partner) = open.pop() else {
+                    return Err(RnaStructureError::InvalidStructure {
+                        message: format!("unmatched ')' at position {}", idx + 1),
+                    });
+                };
+                pairs.push((partner, idx));
+            }
+            other => {
+                return Err(RnaStructureError::InvalidStructure {
+                    message: format!("unsupported symbol '{other}' at position {}", idx + 1),
+                });
+            }
+        }
+    }
+    if let Some(unclosed) = open.last() {
+        return Err(RnaStructureError::InvalidStructure {
+            message: format!("unmatched '(' at position {}", unclosed + 1),
+        });
+    }
+    pairs.sort_unstable();
+    Ok(pairs)
+}
+
+fn prepare_svg_output_path(output_path: &str) -> Result<String, RnaStructureError> {
     let output_path = output_path.trim();
     if output_path.is_empty() {
         return Err(RnaStructureError::Io {
@@ -309,15 +463,29 @@ pub fn render_svg(
             message: format!("Could not create RNA structure output directory: {e}"),
         })?;
     }
+    Ok(output_path.to_string())
+}
 
-    let fold = fold_rna_sequence(&sequence)?;
+#[derive(Debug, Clone)]
+struct RnapkinRenderResult {
+    executable: String,
+    args: Vec<String>,
+    stdout: String,
+    stderr: String,
+}
+
+fn draw_structure_with_rnapkin(
+    sequence: &str,
+    structure: &str,
+    output_path: &str,
+) -> Result<RnapkinRenderResult, RnaStructureError> {
     let mut rnapkin_input = tempfile::NamedTempFile::new().map_err(|e| RnaStructureError::Io {
         message: format!("Could not create temporary rnapkin input: {e}"),
     })?;
     writeln!(rnapkin_input, "{sequence}").map_err(|e| RnaStructureError::Io {
         message: format!("Could not write temporary rnapkin sequence: {e}"),
     })?;
-    writeln!(rnapkin_input, "{}", fold.structure).map_err(|e| RnaStructureError::Io {
+    writeln!(rnapkin_input, "{structure}").map_err(|e| RnaStructureError::Io {
         message: format!("Could not write temporary rnapkin structure: {e}"),
     })?;
     rnapkin_input.flush().map_err(|e| RnaStructureError::Io {
@@ -350,19 +518,73 @@ pub fn render_svg(
         });
     }
 
-    Ok(RnaStructureSvgReport {
-        tool: "rnapkin".to_string(),
+    Ok(RnapkinRenderResult {
         executable,
-        sequence_length: dna.len(),
-        output_path: output_path.to_string(),
-        command: args,
+        args,
         stdout,
         stderr,
+    })
+}
+
+pub fn render_svg(
+    dna: &DNAsequence,
+    output_path: &str,
+    constraints: Option<&RnaStructureConstraints>,
+) -> Result<RnaStructureSvgReport, RnaStructureError> {
+    let sequence = ensure_rna_input(dna)?;
+    let constraint = resolve_constraint_line(&sequence, constraints)?;
+    let output_path = prepare_svg_output_path(output_path)?;
+    let fold = fold_rna_sequence(&sequence, constraint.as_deref())?;
+    let drawn = draw_structure_with_rnapkin(&sequence, &fold.structure, &output_path)?;
+
+    Ok(RnaStructureSvgReport {
+        tool: "rnapkin".to_string(),
+        executable: drawn.executable,
+        sequence_length: dna.len(),
+        output_path,
+        command: drawn.args,
+        stdout: drawn.stdout,
+        stderr: drawn.stderr,
         fold_executable: fold.executable,
         fold_command: fold.args,
         fold_stdout: fold.stdout,
         fold_stderr: fold.stderr,
         structure: fold.structure,
         mfe_kcal_per_mol: fold.mfe_kcal_per_mol,
+        constraint,
+    })
+}
+
+/// Render a caller-provided dot-bracket structure without running RNAfold.
+///
+/// Drawing still requires the rnapkin executable. The structure is validated
+/// for balanced brackets and for matching the sequence length before rnapkin
+/// is invoked; fold fields stay empty.
+pub fn render_svg_from_dot_bracket(
+    dna: &DNAsequence,
+    dot_bracket: &str,
+    output_path: &str,
+) -> Result<RnaStructureSvgReport, RnaStructureError> {
+    let sequence = ensure_rna_input(dna)?;
+    let structure = dot_bracket.trim().to_string();
+    parse_dot_bracket_pairs(&structure, sequence.chars().count())?;
+    let output_path = prepare_svg_output_path(output_path)?;
+    let drawn = draw_structure_with_rnapkin(&sequence, &structure, &output_path)?;
+
+    Ok(RnaStructureSvgReport {
+        tool: "rnapkin".to_string(),
+        executable: drawn.executable,
+        sequence_length: dna.len(),
+        output_path,
+        command: drawn.args,
+        stdout: drawn.stdout,
+        stderr: drawn.stderr,
+        fold_executable: String::new(),
+        fold_command: vec![],
+        fold_stdout: String::new(),
+        fold_stderr: String::new(),
+        structure,
+        mfe_kcal_per_mol: None,
+        constraint: None,
     })
 }
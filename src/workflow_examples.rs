@@ -2050,7 +2050,7 @@ fn current_utc_review_date() -> Option<(i32, u32, u32)> {
     Some(civil_from_unix_days((seconds / 86_400) as i64))
 }
 
-fn civil_from_unix_days(days_since_epoch: i64) -> (i32, u32, u32) {
+pub(crate) fn civil_from_unix_days(days_since_epoch: i64) -> (i32, u32, u32) {
     let z = days_since_epoch + 719_468;
     let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
     let doe = z - era * 146_097;
@@ -71,6 +71,102 @@ const PREEXISTING_UNDOCUMENTED_ARRAYS_SUBCOMMANDS: &[UndocumentedArraysSubcomman
     },
 ];
 
+/// Public `GentleEngine` methods taking `&self` that are not protocol queries:
+/// file writers and renderers, long-running jobs, and accessors or helpers the
+/// operations build on. Every other such method must be listed in
+/// `public_engine_query_names`.
+const NON_QUERY_ENGINE_METHOD_PREFIXES: &[&str] = &[
+    "align_",
+    "audit_",
+    "blast_",
+    "build_",
+    "collect_",
+    "compare_",
+    "compute_",
+    "construct_reasoning_",
+    "export_",
+    "format_",
+    "import_",
+    "plan_",
+    "planning_",
+    "preflight_",
+    "prepare_",
+    "project_",
+    "read_acquisition_",
+    "render_",
+    "resolve_",
+    "run_",
+    "save_",
+    "seed_",
+    "suggest_",
+    "test_cdna_",
+    "write_",
+];
+
+const NON_QUERY_ENGINE_METHODS: &[&str] = &[
+    "assess_primer_pair_specificity",
+    "evaluate_fact_expression",
+    "find_restriction_sites",
+    "history_summary",
+    "lineage_macro_instances",
+    "operation_log",
+    "primer3_preflight_report",
+    "redo_available",
+    "restriction_cloning_vector_enzyme_suggestions",
+    "show_cutrun_dataset_status",
+    "state",
+    "undo_available",
+];
+
+/// Names of the `pub fn`s inside `impl GentleEngine` blocks of the engine
+/// sources, each with whether it takes `&self`.
+fn public_engine_methods() -> BTreeMap<String, bool> {
+    fn collect_sources(dir: &std::path::Path, out: &mut Vec<std::path::PathBuf>) {
+        for entry in std::fs::read_dir(dir).expect("read engine source dir") {
+            let path = entry.expect("engine source entry").path();
+            if path.is_dir() {
+                collect_sources(&path, out);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                out.push(path);
+            }
+        }
+    }
+    let src = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut files = vec![src.join("engine.rs")];
+    collect_sources(&src.join("engine"), &mut files);
+    let mut methods = BTreeMap::new();
+    for file in files {
+        let text = std::fs::read_to_string(&file).expect("read engine source");
+        let mut in_engine_impl = false;
+        let mut lines = text.lines().peekable();
+        while let Some(line) = lines.next() {
+            if line.starts_with("impl") {
+                in_engine_impl = line.starts_with("impl GentleEngine ");
+                continue;
+            }
+            let Some(rest) = line.strip_prefix("    pub fn ") else {
+                continue;
+            };
+            if !in_engine_impl {
+                continue;
+            }
+            let name = rest
+                .split(|ch: char| ch == '(' || ch == '<')
+                .next()
+                .unwrap_or_default();
+            let receiver = match rest.split_once('(') {
+                Some((_, args)) if !args.trim().is_empty() => args.trim().to_string(),
+                _ => lines
+                    .peek()
+                    .map(|next| next.trim().to_string())
+                    .unwrap_or_default(),
+            };
+            *methods.entry(name.to_string()).or_default() |= receiver.starts_with("&self");
+        }
+    }
+    methods
+}
+
 fn glossary_commands() -> Vec<GlossaryCommand> {
     let glossary: Glossary =
         serde_json::from_str(include_str!("../docs/glossary.json")).expect("parse glossary");
@@ -932,3 +1028,38 @@ fn shell_alias_registry_mutation_tags_match_contract() {
         );
     }
 }
+
+#[test]
+fn public_engine_query_names_match_engine_methods() {
+    let methods = public_engine_methods();
+    let listed = gentle_protocol::public_engine_query_names()
+        .iter()
+        .map(|name| (*name).to_string())
+        .collect::<BTreeSet<_>>();
+    let stale = listed
+        .iter()
+        .filter(|name| !methods.contains_key(*name))
+        .cloned()
+        .collect::<Vec<_>>();
+    assert!(
+        stale.is_empty(),
+        "query names without an engine method: {stale:?}"
+    );
+    let unlisted = methods
+        .iter()
+        .filter(|(_, takes_self)| **takes_self)
+        .map(|(name, _)| name)
+        .filter(|name| !listed.contains(*name))
+        .filter(|name| !NON_QUERY_ENGINE_METHODS.contains(&name.as_str()))
+        .filter(|name| {
+            !NON_QUERY_ENGINE_METHOD_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    assert!(
+        unlisted.is_empty(),
+        "public &self engine methods missing from public_engine_query_names: {unlisted:?}"
+    );
+}